@@ -0,0 +1,82 @@
+// Recomputes the winners of an ended lottery from account dumps and checks them against the on-chain winners.
+//
+// Usage : verify_draw <LOTTERY_ACCOUNT_DUMP> [RANDOMNESS_REQUEST_ACCOUNT_DUMP] [--lottery-account <PUBKEY>]
+//   dumps -> `solana account <ADDRESS> --output json --output-file <FILE>` or the raw account data.
+//   "--lottery-account" is required when the lottery dump is raw data (json dumps have the address).
+// Exit code -> 0 winners match, 1 winners mismatch, 2 error.
+
+#[cfg(not(target_os = "solana"))]
+fn main() {
+    use {
+        sol_lottery::verify::{
+            parse_account_dump,
+            verify_draw,
+            VerifyError
+        },
+        solana_program::pubkey::Pubkey,
+        std::{
+            env,
+            fs,
+            process::exit,
+            str::FromStr
+        }
+    };
+
+    const USAGE: &str = "usage : verify_draw <LOTTERY_ACCOUNT_DUMP> [RANDOMNESS_REQUEST_ACCOUNT_DUMP] [--lottery-account <PUBKEY>]";
+
+    fn fail(message: &str) -> ! {
+        eprintln!("error : {}", message);
+        exit(2);
+    }
+
+    let mut dump_paths: Vec<String> = Vec::new();
+    let mut lottery_account: Option<Pubkey> = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lottery-account" => {
+                let pubkey = args.next().unwrap_or_else(|| fail(USAGE));
+                lottery_account = Some(
+                    Pubkey::from_str(&pubkey).unwrap_or_else(|_| fail("invalid lottery account address."))
+                );
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                exit(0);
+            },
+            _ => dump_paths.push(arg)
+        };
+    };
+
+    if dump_paths.is_empty() == true || dump_paths.len() > 2 {
+        fail(USAGE);
+    };
+
+    let read_dump = |path: &String| {
+        let dump = fs::read(path).unwrap_or_else(|e| fail(&format!("failed to read \"{}\" -> {}", path, e)));
+        parse_account_dump(&dump).unwrap_or_else(|e| fail(&format!("\"{}\" -> {}", path, e)))
+    };
+
+    let lottery_account_dump = read_dump(&dump_paths[0]);
+    let randomness_request_account_dump = dump_paths.get(1).map(read_dump);
+
+    let lottery_account = lottery_account
+        .or(lottery_account_dump.pubkey)
+        .unwrap_or_else(|| fail(&VerifyError::LotteryAccountNotFound.to_string()));
+
+    let draw_verification = verify_draw(
+        &lottery_account,
+        &lottery_account_dump.data,
+        randomness_request_account_dump.as_ref().map(|dump| dump.data.as_slice())
+    ).unwrap_or_else(|e| fail(&e.to_string()));
+
+    println!("{}", draw_verification);
+
+    if draw_verification.is_matched() == false {
+        exit(1);
+    };
+}
+
+#[cfg(target_os = "solana")]
+fn main() {}
@@ -25,7 +25,7 @@ pub enum LotteryError {
     InvalidTime,
     #[error("invalid funding amount.")]
     InvalidFundAmount,
-    #[error("only 'TOKEN_STANDARD_PROGRAM' supported.")]
+    #[error("only 'TOKEN_STANDARD_PROGRAM' & 'TOKEN_2022_PROGRAM' supported.")]
     OnlyTokenStandardProgram,
     #[error("invalid usdc-mint account.")]
     InvalidUsdcMintAccount,
@@ -124,7 +124,175 @@ pub enum LotteryError {
     #[error("this instruction must be the last instruction in the transaction")]
     MustBeTheLastIx,
     #[error("invalid sysvar-instruction")]
-    InvalidSysvarInstructionAccount
+    InvalidSysvarInstructionAccount,
+    #[error("lottery has no randomness commitment.")]
+    RandomnessCommitmentNotFound,
+    #[error("secret does not match the randomness commitment.")]
+    InvalidSecret,
+    #[error("secret is already revealed.")]
+    SecretAlreadyRevealed,
+    #[error("creator's secret is not revealed yet.")]
+    SecretNotRevealed,
+    #[error("secret reveal is timed out.")]
+    SecretRevealTimedOut,
+    #[error("invalid randomness source for this lottery.")]
+    InvalidRandomnessSource,
+    #[error("invalid randomness-request account.")]
+    InvalidRandomnessRequestAccount,
+    #[error("randomness is already requested.")]
+    RandomnessAlreadyRequested,
+    #[error("invalid vrf oracle authority.")]
+    InvalidOracleAuthority,
+    #[error("invalid randomness proof.")]
+    InvalidRandomnessProof,
+    #[error("randomness is already fulfilled.")]
+    RandomnessAlreadyFulfilled,
+    #[error("randomness is not fulfilled yet.")]
+    RandomnessNotFulfilled,
+    #[error("randomness fulfilment is timed out.")]
+    RandomnessFulfilmentTimedOut,
+    #[error("invalid sysvar-slot-hashes")]
+    InvalidSysvarSlotHashesAccount,
+    #[error("invalid prize table.")]
+    InvalidPrizeTable,
+    #[error("invalid prize mint account.")]
+    InvalidPrizeMintAccount,
+    #[error("maximum number of prize mints exceeded.")]
+    MaxPrizeMintsExceeded,
+    #[error("invalid lottery's prize associated token account.")]
+    InvalidLotteryPrizeAssociatedTokenAccount,
+    #[error("invalid amount of prize mint accounts.")]
+    InvalidAmountOfPrizeMintAccounts,
+    #[error("invalid nft prizes.")]
+    InvalidNftPrizes,
+    #[error("invalid nft prize mint account.")]
+    InvalidNftPrizeMintAccount,
+    #[error("invalid amount of nft prize accounts.")]
+    InvalidAmountOfNftPrizeAccounts,
+    #[error("invalid prize-vault account.")]
+    InvalidPrizeVaultAccount,
+    #[error("invalid amount of prize-vault accounts.")]
+    InvalidAmountOfPrizeVaultAccounts,
+    #[error("invalid pool creator's share.")]
+    InvalidPoolCreatorShare,
+    #[error("invalid amount of pool accounts.")]
+    InvalidAmountOfPoolAccounts,
+    #[error("invalid lottery type.")]
+    InvalidLotteryType,
+    #[error("invalid jackpot share.")]
+    InvalidJackpotShare,
+    #[error("invalid lottery series account.")]
+    InvalidLotterySeriesAccount,
+    #[error("invalid series jackpot vault account.")]
+    InvalidSeriesJackpotVaultAccount,
+    #[error("invalid amount of lottery series accounts.")]
+    InvalidAmountOfLotterySeriesAccounts,
+    #[error("lottery already joined a series.")]
+    LotterySeriesAlreadyJoined,
+    #[error("series jackpot was not won in this round.")]
+    JackpotNotWon,
+    #[error("series jackpot already claimed.")]
+    JackpotAlreadyClaimed,
+    #[error("invalid token program account.")]
+    InvalidTokenProgramAccount,
+    #[error("mint extension is not supported.")]
+    UnsupportedMintExtension,
+    #[error("invalid oracle price.")]
+    InvalidOraclePrice,
+    #[error("maximum amount to spend exceeded.")]
+    MaximumSpendExceeded,
+    #[error("invalid sol-payments-vault account.")]
+    InvalidSolPaymentsVaultAccount,
+    #[error("invalid amount of sol-payments accounts.")]
+    InvalidAmountOfSolPaymentsAccounts,
+
+    #[error("lottery's schedule can't be updated.")]
+    LotteryScheduleLocked,
+
+    #[error("invalid maximum total tickets, it can't be lower than the minimum tickets required to be sold.")]
+    InvalidMaximumTotalTickets,
+
+    #[error("maximum total tickets of the lottery exceeded.")]
+    MaximumTotalTicketsExceeded,
+
+    #[error("lottery series has no round template.")]
+    RoundTemplateNotFound,
+    #[error("prize mint of the series' round template can't be changed.")]
+    SeriesPrizeMintLocked,
+    #[error("invalid series prize vault account.")]
+    InvalidSeriesPrizeVaultAccount,
+    #[error("invalid previous round account.")]
+    InvalidPreviousRoundAccount,
+    #[error("previous round of the series has not ended yet.")]
+    PreviousRoundNotEnded,
+
+    #[error("invalid settlement bounty, its amount can't be zero.")]
+    InvalidSettlementBounty,
+    #[error("invalid amount of settlement bounty accounts.")]
+    InvalidAmountOfSettlementBountyAccounts,
+    #[error("lottery has no settlement bounty to pay.")]
+    SettlementBountyNotFound,
+    #[error("settlement bounty already paid.")]
+    SettlementBountyAlreadyPaid,
+
+    #[error("prizes of the lottery can't be distributed, winners withdraw them.")]
+    PrizesNotDistributable,
+    #[error("invalid amount of winners accounts.")]
+    InvalidAmountOfWinnersAccounts,
+    #[error("invalid winner associated token account.")]
+    InvalidWinnerAssociatedTokenAccount,
+
+    #[error("prizes never expire, the claim window is not set.")]
+    PrizesNeverExpire,
+    #[error("claim window of the prizes has not closed yet.")]
+    ClaimWindowNotClosed,
+    #[error("unclaimed prizes already reclaimed.")]
+    UnclaimedPrizesAlreadyReclaimed,
+    #[error("invalid reclaimer of the unclaimed prizes.")]
+    InvalidUnclaimedPrizesReclaimer,
+    #[error("creator's funds, protocol fees & prizes of the lottery must be withdrawn & its arbitrary token account closed first.")]
+    SucceedLotteryNotSettled,
+
+    #[error("prizes of the lottery are not withdrawn (or refunded) yet.")]
+    ArbitraryTokenAccountStillInUse,
+
+    #[error("user account is sponsored by another sponsor.")]
+    SponsorMismatch,
+    #[error("invalid sponsor usdc token account.")]
+    InvalidSponsorUsdcTokenAccount,
+    #[error("invalid amount of sponsor accounts.")]
+    InvalidAmountOfSponsorAccounts,
+
+    #[error("user doesn't hold enough tickets to transfer.")]
+    InsufficientTicketsToTransfer,
+    #[error("invalid receiver of the tickets.")]
+    InvalidTicketsReceiver,
+
+    #[error("ticket tokens are already enabled.")]
+    TicketTokensAlreadyEnabled,
+    #[error("ticket tokens are not enabled.")]
+    TicketTokensNotEnabled,
+    #[error("invalid ticket mint account.")]
+    InvalidTicketMintAccount,
+    #[error("invalid ticket token account.")]
+    InvalidTicketTokenAccount,
+    #[error("invalid amount of ticket token accounts.")]
+    InvalidAmountOfTicketTokenAccounts,
+    #[error("ticket tokens of the lottery are not refunded yet.")]
+    TicketTokensOutstanding,
+
+    #[error("invalid sol treasury account.")]
+    InvalidSolTreasuryAccount,
+    #[error("sol payments of the lottery are not refunded yet.")]
+    SolPaymentsNotRefunded,
+
+    #[error("invalid amount of randomness-request accounts.")]
+    InvalidAmountOfRandomnessRequestAccounts,
+    #[error("config account is already migrated.")]
+    ConfigAccountAlreadyMigrated,
+
+    #[error("invalid series usdc vault account.")]
+    InvalidSeriesUsdcVaultAccount
 }
 
 impl From<LotteryError> for ProgramError {
@@ -12,9 +12,31 @@ use {
             Instruction
         }
     },
+
+    spl_associated_token_account::{
+        get_associated_token_address_with_program_id,
+        ID as ASSOCIATED_TOKEN_PROGRAM_ID
+    },
+
+    spl_token_2022::ID as TOKEN_2022_PROGRAM_ID,
+
+    spl_token::ID as TOKEN_STANDARD_PROGRAM_ID,
     
     crate::{
         types::*,
+        state::{
+            RandomnessSource,
+            RoundTemplate,
+            SettlementBounty,
+            UnclaimedPrizesReceiver
+        },
+        pda::{
+            get_prize_vault_account_pda,
+            get_series_prize_vault_account_pda,
+            get_sol_payments_vault_account_pda,
+            get_ticket_mint_account_pda
+        },
+        vrf::VRF_PROOF_LENGTH,
         program::ID as LOTTERY_PROGRAM_ID
     }
 };
@@ -46,6 +68,10 @@ pub enum Instructions {
 
     /// Create And Initialize lottery Account
     /// 
+    /// Every mint can belong to the standard token program or to the token-2022 program, token-2022 mints with
+    /// a transfer-hook or the non-transferable extension are rejected. With a transfer fee the lottery records the
+    /// fund & the creation fee it actually receives (the equal split is checked on the received fund)
+    /// 
     /// Accounts Expected By This Instruction :
     ///     0. `[w]` lottery account pda
     ///     1. `[s]` authority of the lottery account
@@ -57,9 +83,18 @@ pub enum Instructions {
     ///     7. `[w]` lottery-account's associated arbitrary-token account
     ///     8. `[w]` funding-account's arbitrary-token account 
     ///     9. `[]` standard token program account
-    ///    10. `[]` associated token program account 
-    ///    11. `[]` system-program account
-    ///    12. `[]` config account
+    ///    10. `[]` token-2022 program account
+    ///    11. `[]` associated token program account 
+    ///    12. `[]` system-program account
+    ///    13. `[]` config account
+    ///     per nft prize (in the "nft_prizes" order) :
+    ///    14 + 3i. `[]` nft prize mint account (0 decimals, supply of 1, no transfer fee)
+    ///    15 + 3i. `[w]` lottery-account's associated nft-token account
+    ///    16 + 3i. `[w]` funding-account's nft-token account
+    ///     then (sol prize lotteries) :
+    ///     `[w]` prize-vault pda
+    /// 
+    /// A settlement bounty is paid by the funding account (lamports or usdc) on top of the rents & the creation fee
     CreateAndInitializeLotteryAccount {
         fund_amount: u64,
         winners_count: u8,
@@ -68,6 +103,14 @@ pub enum Instructions {
         minimum_tickets_amount_required_to_be_sold: u32,
         ticket_price: u64,
         maximum_number_of_tickets_per_user: Option<u32>,
+        randomness_commitment: Option<[u8; 32]>, // sha256(lottery_account || secret), revealed by "RevealLotterySecret", required for "VrfOracle"
+        randomness_source: RandomnessSource,
+        prize_table: Option<Vec<u16>>, // basis-point share of the fund per rank (1st, 2nd, ...), must add up to 10_000, "None" -> equal split
+        nft_prizes: Option<Vec<Pubkey>>, // nft mint per rank (1st, 2nd, ...), one per winner, "fund_amount" can be 0 with nft prizes
+        sol_prize_amount: u64, // lamports escrowed in the prize-vault pda & split like the fund, "fund_amount" can be 0 with a sol prize
+        pool_creator_share: Option<u16>, // pool lottery -> the ticket sales (minus protocol fees) are the prize, creator keeps this basis-point share, "fund_amount" can be 0
+        maximum_total_tickets: Option<u32>, // cap on the tickets of all users (>= "minimum_tickets_amount_required_to_be_sold"), sold out -> ended before "ending_time"
+        settlement_bounty: Option<SettlementBounty>, // reward of whoever settles the lottery after its end ("EndLotteryAndPickWinners" or "SettleFailedLottery")
         lottery_description: String
     },
 
@@ -84,6 +127,8 @@ pub enum Instructions {
 
     /// Buy Ticket/s For Specific lottery
     /// 
+    /// A token-2022 transfer fee of the usdc mint is paid on top of the tickets price
+    /// 
     /// Accounts Expected By this Instruction :
     ///     0. `[]` config account
     ///     1. `[w]` user account
@@ -94,7 +139,12 @@ pub enum Instructions {
     ///     6. `[w]` funding's usdc token account
     ///     7. `[]` usdc mint account
     ///     8. `[]` system program account
-    ///     9. `[]` standard token program account
+    ///     9. `[]` token program account of the usdc mint (standard or token-2022)
+    ///     then (tokenized tickets, see "EnableTicketTokens") :
+    ///    10. `[w]` ticket-mint pda
+    ///    11. `[w]` ticket associated token account of the user_account's authority
+    ///    12. `[]` standard token program account
+    ///    13. `[]` associated token program account
     BuyTicket {
         tickets_amount: u32,
         expected_token_price_per_ticket: u64
@@ -112,15 +162,35 @@ pub enum Instructions {
 
     /// End successfull lottery and pick winners *<everyone can call this instruction>*
     /// 
+    /// If the lottery has a randomness commitment, the creator's secret must be revealed first
+    /// (unless "MAX_TIME_TO_REVEAL_SECRET" is passed since the ending time, then only the oracle's randomness is used)
+    /// 
+    /// "PythPriceFeeds" lotteries mix the three prices (with their confidences & publish times), the most recent slot-hash
+    /// and the lottery account, every input is recorded in "random_numbers_info"
+    /// 
     /// Accounts Expected By This Instruction :
     ///     0. `[w]` lottery account
     ///     1. `[]` config account
     ///     2. `[]` SOL pyth price feed account
     ///     3. `[]` BTC pyth price feed account
     ///     4. `[]` ETH pyth price feed account
+    ///     5. `[]` sysvar instructions account
+    ///     6. `[]` sysvar slot-hashes account (only read for "PythPriceFeeds" lotteries)
+    ///     then ("VrfOracle" lotteries) :
+    ///     `[]` randomness-request account
+    ///     then (series rounds, a won jackpot is reserved in the series) :
+    ///     `[w]` lottery-series account
+    ///     `[]` series jackpot vault (lottery-series account's associated usdc token account)
+    ///     then (lotteries with a settlement bounty, paid to the caller) :
+    ///     `[w]` bounty receiver account (lamports account, or usdc token account for a usdc bounty)
+    ///     then (usdc bounty) :
+    ///     `[]` usdc mint account
+    ///     `[w]` lottery's associated usdc token account
+    ///     `[]` token program account of the usdc mint (standard or token-2022)
     EndLotteryAndPickWinners,
 
     /// lottery's creator (owner) will be able to withdraw the -> total_tickets_usdc - protocol_fee
+    /// (pool lotteries -> only the creator's share of the pool, the rest is the winners' prize)
     /// 
     /// Accounts Expected By This Instruction :
     ///     0. `[w]` lottery account
@@ -129,11 +199,21 @@ pub enum Instructions {
     ///     3. `[w]` lottery's associated usdc token account
     ///     4. `[w]` fund-receiver usdc token account
     ///     5. `[]` usdc mint account 
-    ///     6. `[]` standard token program account 
+    ///     6. `[]` token program account of the usdc mint (standard or token-2022)
+    ///     then (series rounds, the series' "jackpot_share" of the withdrawal goes to the jackpot) :
+    ///     `[]` lottery-series account
+    ///     `[w]` series jackpot vault (lottery-series account's associated usdc token account)
+    ///     then (tickets paid in SOL, their protocol fee goes to the config's sol treasury) :
+    ///     `[w]` sol-payments vault pda
+    ///     `[w]` fund-receiver sol lamports account
+    ///     `[w]` sol treasury account
     WithdrawSucceedLottery,
 
     /// Winners will be able to get their prize
     /// 
+    /// The nft token accounts are closed, the prize token accounts are closed by the last winner if no rounding dust is left
+    /// (their rent_exempts go to the lottery account, refunded when it's closed)
+    /// 
     /// Accounts Expected By This Instruction :
     ///     0. `[w]` lottery account
     ///     1. `[]` user account
@@ -142,10 +222,26 @@ pub enum Instructions {
     ///     4. `[w]` fund-receiver arbitrary token account
     ///     5. `[]` arbitrary mint account 
     ///     6. `[]` standard token program account 
-    ///     7. `[]` config account
+    ///     7. `[]` token-2022 program account
+    ///     8. `[]` config account
+    ///     per lottery's prize mint (in the lottery's "prize_mints" order) :
+    ///     9 + 3i. `[]` prize mint account
+    ///    10 + 3i. `[w]` lottery's associated prize token account
+    ///    11 + 3i. `[w]` fund-receiver prize token account
+    ///     then per winning rank of the user (nft prize lotteries, in the lottery's "winners" order) :
+    ///     `[]` nft prize mint account of the rank
+    ///     `[w]` lottery's associated nft token account
+    ///     `[w]` fund-receiver nft token account
+    ///     then (sol prize lotteries) :
+    ///     `[w]` prize-vault pda
+    ///     `[w]` fund-receiver sol prize lamports account
+    ///     then (pool lotteries) :
+    ///     `[]` usdc mint account
+    ///     `[w]` lottery's associated usdc token account
+    ///     `[w]` fund-receiver usdc token account
     WithdrawLotterysWinners,
 
-    /// Users can claim their tickets rent exempt after lottery ended successfuly (non-winner users)
+    /// Users can claim their tickets rent exempt after lottery ended successfuly (non-winner users, winners once their prizes are withdrawn)
     /// 
     /// Accounts Expected By This Instruction :
     ///     0. `[w]` lottery account
@@ -158,6 +254,9 @@ pub enum Instructions {
 
     /// Lottery's owner (creator) can withdraw their funds if lottery fails
     /// 
+    /// Token accounts of token-2022 mints with a transfer fee can only be closed once their withheld fees
+    /// are harvested to the mint (anyone can do it with "HarvestWithheldTokensToMint")
+    /// 
     /// Accounts Expected By This Instruction :
     ///     0. `[]` config account
     ///     1. `[w]` lottery account
@@ -170,6 +269,18 @@ pub enum Instructions {
     ///     8. `[w]` fund-receiver arbitrary token account
     ///     9. `[w]` fund-receiver rent_exempt lamports account
     ///    10. `[]` standard token program account
+    ///    11. `[]` token-2022 program account
+    ///     per lottery's prize mint (in the lottery's "prize_mints" order, the prize token accounts are closed) :
+    ///    12 + 3i. `[]` prize mint account
+    ///    13 + 3i. `[w]` lottery's associated prize token account
+    ///    14 + 3i. `[w]` fund-receiver prize token account
+    ///     then per nft prize (in the lottery's "nft_prizes" order, the nft token accounts are closed) :
+    ///     `[]` nft prize mint account
+    ///     `[w]` lottery's associated nft token account
+    ///     `[w]` fund-receiver nft token account
+    ///     then (sol prize lotteries, the prize-vault is closed) :
+    ///     `[w]` prize-vault pda
+    ///     `[w]` fund-receiver sol prize lamports account
     WithdrawFailedLottery,
 
     /// Users can withdraw their funds and close their accounts if lottery fails
@@ -184,10 +295,27 @@ pub enum Instructions {
     ///     6. `[w]` fund-receiver usdc token account
     ///     7. `[w]` fund-receiver tickets_rent_exempt lamports account
     ///     8. `[w]` fund-receiver rent_exempt lamports account
-    ///     9. `[]` standard token program account
+    ///     9. `[]` token program account of the usdc mint (standard or token-2022)
+    ///     then (tickets paid in SOL, refunded in SOL) :
+    ///    10. `[w]` sol-payments vault pda
+    ///    11. `[w]` fund-receiver sol lamports account
+    ///     then (tickets bought by "BuyTicketFor", refunded to the sponsor) :
+    ///     `[w]` sponsor's usdc token account
+    ///     then (optional, tokenized tickets, the ticket tokens are burned & refunded to the fund-receiver) :
+    ///     `[w]` ticket-mint pda
+    ///     `[w]` ticket token account of the user_account's authority
+    ///     `[]` standard token program account
     WithdrawAndCloseFailedUser,
 
     /// Lottery owner(creator) can close the lottery & lottery_associated_usdc_token accounts to reclaim rent_exempts 
+    /// (only after "WithdrawFailedLottery", which returns the arbitrary, every prize mint, every nft prize & the sol prize)
+    /// A successful lottery can be closed after "WithdrawSucceedLottery", the protocol fees claim, the winners' withdrawals
+    /// & "CloseLotteryArbitraryTokenAccount" (or "ReclaimUnclaimedPrizes"), every prize & nft token account must be closed
+    /// 
+    /// A token-2022 usdc mint with a transfer fee -> the withheld fees of the usdc token account must be harvested first
+    /// 
+    /// The ticket tokens of a failed lottery must be refunded first, the usdc of the ones not refunded
+    /// "MAX_TIME_TO_REFUND_TICKET_TOKENS" after the ending time is swept to the treasury
     /// 
     /// Accounts Expected By This Instruction : 
     ///     0. `[]` config account
@@ -197,7 +325,12 @@ pub enum Instructions {
     ///     4. `[w]` lottery's associated usdc token account
     ///     5. `[w]` fund-receiver usdc token account
     ///     6. `[w]` fund-receiver rent_exempt lamports account
-    ///     7. `[]` standard token program account  
+    ///     7. `[]` token program account of the usdc mint (standard or token-2022)
+    ///     8. `[w]` sol-payments vault pda (closed if a ticket was paid in SOL)
+    ///     then (successful sol prize lotteries, the prize-vault is closed) :
+    ///     9. `[w]` prize-vault pda
+    ///     then (failed lotteries with ticket tokens not refunded in time) :
+    ///     9. `[w]` treasury account
     CloseLotteryAccountAndUsdcTokenAccount,
 
     /// Change the lottery_fee_creation amount
@@ -233,7 +366,7 @@ pub enum Instructions {
     ///     1. `[s]` config account authority
     ///     2. `[w]` treasury (usdc token account)
     ///     3. `[]` usdc mint account
-    ///     4. `[]` standard token program account
+    ///     4. `[]` token program account of the usdc mint (standard or token-2022)
     ///     5. 5..5+N `[w]` N lottery account
     ///     6. 5+N.. `[w]` N lotteries's associated usdc token accounts (atleast instruction MUST have one token-account)
     ClaimProtocolFees {
@@ -320,7 +453,455 @@ pub enum Instructions {
     ///     1. `[s]` config authority account
     ChangeMaxLotteryDescriptionLength {
         new_length: u64
-    }
+    },
+
+    /// Lottery's creator reveals the secret committed at creation time, the secret will be mixed with the oracle's randomness
+    /// (only before "ending_time + MAX_TIME_TO_REVEAL_SECRET")
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[s]` lottery account authority
+    ///     2. `[]` config account
+    ///     3. `[]` randomness-request account ("VrfOracle" lotteries, must not be requested yet)
+    RevealLotterySecret {
+        secret: [u8; 32]
+    },
+
+    /// Change the vrf oracle authority (the oracle's ed25519 public key) which fulfils the randomness requests
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` config account
+    ///     1. `[s]` config authority account
+    ///     2. `[]` new vrf oracle authority
+    ChangeVrfOracleAuthority,
+
+    /// Request randomness for a successful "VrfOracle" lottery after its ending time *<everyone can call this instruction>*
+    /// 
+    /// The request seed is derived from data fixed at the ending time : the lottery account, its ticket ledger & the creator's secret,
+    /// so it is requested once the secret is revealed (or the reveal is timed out) and doesn't depend on when it is requested
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` randomness-request account pda
+    ///     1. `[]` lottery account
+    ///     2. `[w,s]` funding account for rent
+    ///     3. `[]` system-program account
+    ///     4. `[]` config account
+    RequestRandomness,
+
+    /// Fulfil a randomness request with the oracle's proof *<everyone can call this instruction>*
+    /// 
+    /// The proof is the oracle's ECVRF-EDWARDS25519-SHA512-TAI proof (RFC 9381) over "randomness_request_account || request_seed" (a single valid output),
+    /// it is accepted until the fulfilment deadline (after the deadline the lottery is failed and the tickets are refunded)
+    /// 
+    /// The oracle can't pick the winners, but it knows them before answering and can abort a draw by not fulfilling it :
+    /// the lottery is then failed and every ticket is refunded, withholding cancels the draw but never changes its winners
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` randomness-request account
+    ///     1. `[w]` lottery account
+    ///     2. `[]` config account
+    FulfilRandomness {
+        proof: [u8; VRF_PROOF_LENGTH]
+    },
+
+    /// Lottery's owner (creator) can add an extra prize mint to the lottery before its starting time
+    /// 
+    /// The prize amount is split between the winners like the main prize (prize table or equal shares),
+    /// the usdc mint & the lottery's arbitrary mint can't be added (max "MAX_PRIZE_MINTS_PER_LOTTERY" prize mints),
+    /// a token-2022 transfer fee is deducted from the recorded prize amount
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[s]` lottery account authority
+    ///     2. `[w,s]` funding account
+    ///     3. `[]` prize mint account
+    ///     4. `[w]` lottery's associated prize token account
+    ///     5. `[w]` funding prize token account
+    ///     6. `[]` token program account of the prize mint (standard or token-2022)
+    ///     7. `[]` associated token program account
+    ///     8. `[]` system program account
+    ///     9. `[]` config account
+    AddPrizeMint {
+        prize_amount: u64
+    },
+
+    /// Create a lottery series, its rounds share a rolling jackpot held by the series' jackpot vault
+    /// 
+    /// Each round sends "jackpot_share" of its creator's withdrawal to the vault, the round's winners
+    /// win the jackpot unless one of the "house_slots" is drawn, an unwon jackpot rolls over to the next round
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery-series account pda
+    ///     1. `[s]` authority of the lottery-series account
+    ///     2. `[w,s]` funding account for rents
+    ///     3. `[]` USDC mint account
+    ///     4. `[w]` series jackpot vault (lottery-series account's associated usdc token account)
+    ///     5. `[]` token program account of the usdc mint (standard or token-2022)
+    ///     6. `[]` associated token program account
+    ///     7. `[]` system-program account
+    ///     8. `[]` config account
+    CreateLotterySeries {
+        jackpot_share: u16, // basis points of the creator's revenue of each round
+        house_slots: u32,
+        series_description: String
+    },
+
+    /// Lottery's owner (the series' authority) can make the lottery a round of the series before its starting time
+    /// 
+    /// The lottery becomes the series' last round, the previous one must have ended (or end before the lottery starts)
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[s]` lottery account authority (the series' authority)
+    ///     2. `[w,s]` funding account
+    ///     3. `[w]` lottery-series account
+    ///     4. `[]` system program account
+    ///     5. `[]` config account
+    ///     then (the series has a round already) :
+    ///     6. `[]` previous round's lottery account
+    JoinLotterySeries,
+
+    /// Winners of a round that won the series jackpot will be able to get their share of it (split like the fund)
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[]` user account
+    ///     2. `[s]` user account authority
+    ///     3. `[w]` lottery-series account
+    ///     4. `[w]` series jackpot vault (lottery-series account's associated usdc token account)
+    ///     5. `[w]` fund-receiver usdc token account
+    ///     6. `[]` usdc mint account
+    ///     7. `[]` token program account of the usdc mint (standard or token-2022)
+    ///     8. `[]` config account
+    WithdrawSeriesJackpot,
+
+    /// Buy Ticket/s For Specific lottery paying in SOL, the usdc tickets price is converted by the config's SOL/USD price feed
+    /// 
+    /// The usdc equivalent is recorded as the user's tickets value, a failed lottery refunds the paid lamports,
+    /// not supported by pool lotteries & series rounds (their prizes come from the usdc ticket sales) & tokenized lotteries
+    /// 
+    /// Only SOL is supported, paying with other tokens needs a price feed per mint
+    /// 
+    /// Accounts Expected By this Instruction :
+    ///     0. `[]` config account
+    ///     1. `[w]` user account
+    ///     2. `[s]` authority of the user-account
+    ///     3. `[w,s]` funding account for rent and lamports of the tickets
+    ///     4. `[w]` lottery account
+    ///     5. `[w]` sol-payments vault pda
+    ///     6. `[]` usdc mint account
+    ///     7. `[]` SOL price feed account (pyth "PriceUpdateV2")
+    ///     8. `[]` system program account
+    BuyTicketWithOracleConversion {
+        tickets_amount: u32,
+        expected_token_price_per_ticket: u64,
+        maximum_lamports_to_spend: u64 // slippage protection
+    },
+
+    /// Lottery's owner (creator) can cancel the lottery before its starting time
+    /// 
+    /// The arbitrary tokens, every prize mint, every nft prize & the sol prize are returned, the creation fee is refunded
+    /// minus the config's "lottery_cancellation_penalty" (sent to the treasury), every token account & the lottery account are closed
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[]` config account
+    ///     1. `[w]` lottery account
+    ///     2. `[s]` lottery authority
+    ///     3. `[]` usdc mint account
+    ///     4. `[]` arbitrary mint account
+    ///     5. `[w]` lottery's associated usdc token account
+    ///     6. `[w]` lottery's associated arbitrary token account
+    ///     7. `[w]` fund-receiver usdc token account
+    ///     8. `[w]` fund-receiver arbitrary token account
+    ///     9. `[w]` treasury account
+    ///    10. `[w]` fund-receiver rent_exempt lamports account
+    ///    11. `[]` standard token program account
+    ///    12. `[]` token-2022 program account
+    ///     per lottery's prize mint (in the lottery's "prize_mints" order) :
+    ///    13 + 3i. `[]` prize mint account
+    ///    14 + 3i. `[w]` lottery's associated prize token account
+    ///    15 + 3i. `[w]` fund-receiver prize token account
+    ///     then per nft prize (in the lottery's "nft_prizes" order) :
+    ///     `[]` nft prize mint account
+    ///     `[w]` lottery's associated nft token account
+    ///     `[w]` fund-receiver nft token account
+    ///     then (sol prize lotteries) :
+    ///     `[w]` prize-vault pda
+    ///     `[w]` fund-receiver sol prize lamports account
+    CancelLottery,
+
+    /// Change the penalty (USDC) kept by the treasury from the creation fee of a cancelled lottery
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` config account
+    ///     1. `[s]` config authority account
+    ChangeLotteryCancellationPenalty {
+        new_penalty: u64
+    },
+
+    /// Lottery's owner (creator) can update the lottery's schedule
+    /// 
+    /// Before the starting time both times can change, after it only the ending time can be extended
+    /// & only while fewer than "minimum_tickets_amount_required_to_be_sold" tickets are sold
+    /// (the duration is still bounded by the config's "maximum_time_for_lottery_account")
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[s]` lottery-account's authority
+    ///     2. `[]` config account
+    UpdateLotterySchedule {
+        new_starting_time: Time,
+        new_ending_time: Time
+    },
+
+    /// Series' authority can set the template of the rounds started by "StartNextRound" (its prize mint can't change later)
+    /// 
+    /// The series' prize vault is activated, anyone can fund it with the template's prize mint
+    /// The rents & the lottery-creation-fee of the rounds started by "StartNextRound" are paid by the series-prize-vault pda,
+    /// it's funded with lamports & its associated usdc token account (series' usdc vault) with usdc
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery-series account
+    ///     1. `[s]` authority of the lottery-series account
+    ///     2. `[w,s]` funding account for rents
+    ///     3. `[]` prize mint account
+    ///     4. `[]` series-prize-vault pda
+    ///     5. `[w]` series' prize vault (series-prize-vault pda's associated prize token account)
+    ///     6. `[]` token program account of the prize mint (standard or token-2022)
+    ///     7. `[]` associated token program account
+    ///     8. `[]` system-program account
+    ///     9. `[]` config account
+    SetLotterySeriesRoundTemplate {
+        round_template: RoundTemplate
+    },
+
+    /// Anyone can start the next round of a series once its previous round has ended
+    /// 
+    /// The round is a lottery account of the series' authority (seeded by the series & the round number), it starts
+    /// right away, joins the series & its prize is pulled from the series' prize vault. The series-prize-vault pda pays
+    /// the rents (its lamports) & the lottery-creation-fee (series' usdc vault), both pre-funded by the series' authority
+    /// which gets the rents back when the round is closed. The caller pays nothing but the transaction fee
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery-series account
+    ///     1. `[w]` round's lottery account pda
+    ///     2. `[]` USDC mint account
+    ///     3. `[w]` round's associated usdc token account
+    ///     4. `[w]` series' usdc vault (series-prize-vault pda's associated usdc token account)
+    ///     5. `[]` prize mint account (template's prize mint)
+    ///     6. `[w]` round's associated prize token account
+    ///     7. `[w]` series-prize-vault pda
+    ///     8. `[w]` series' prize vault
+    ///     9. `[]` standard token program account
+    ///    10. `[]` token-2022 program account
+    ///    11. `[]` associated token program account
+    ///    12. `[]` system-program account
+    ///    13. `[]` config account
+    ///     then (every round but the series' first) :
+    ///    14. `[]` previous round's lottery account
+    StartNextRound,
+
+    /// Settle a failed lottery & get its settlement bounty *<everyone can call this instruction>*
+    /// 
+    /// Refunds are still withdrawn by the creator & the users, only the bounty is paid
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[]` config account
+    ///     2. `[w]` bounty receiver account (lamports account, or usdc token account for a usdc bounty)
+    ///     then (usdc bounty) :
+    ///     3. `[]` usdc mint account
+    ///     4. `[w]` lottery's associated usdc token account
+    ///     5. `[]` token program account of the usdc mint (standard or token-2022)
+    SettleFailedLottery,
+
+    /// Pay the unclaimed prizes of the winners *<everyone can call this instruction>*
+    /// 
+    /// Missing associated token accounts of the winners are created, already withdrawn winners are skipped.
+    /// Lotteries with prizes besides the fund (prize mints, nft prizes, sol prize, pool) are withdrawn by the winners
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[w,s]` funding account for the rent of the winners' associated token accounts
+    ///     2. `[w]` lottery's associated arbitrary token account
+    ///     3. `[]` arbitrary mint account
+    ///     4. `[]` standard token program account
+    ///     5. `[]` token-2022 program account
+    ///     6. `[]` associated token program account
+    ///     7. `[]` system-program account
+    ///     8. `[]` config account
+    ///     then per winner :
+    ///     9. `[]` winner's user account
+    ///    10. `[]` authority of the winner's user account
+    ///    11. `[w]` authority's associated arbitrary token account
+    DistributePrizes,
+
+    /// Change the claim window of the prizes & who reclaims the prizes left unclaimed after it
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` config account
+    ///     1. `[s]` config authority account
+    ChangeUnclaimedPrizesPolicy {
+        prize_claim_window: u32, // in seconds after the lottery's "ending_time", "0" -> prizes never expire
+        unclaimed_prizes_receiver: UnclaimedPrizesReceiver
+    },
+
+    /// Lottery's creator (or the treasury's authority, per config policy) sweeps the prizes left unclaimed after the claim window
+    /// 
+    /// The whole arbitrary & prize mint balances are swept, the arbitrary & prize token accounts are closed,
+    /// the unclaimed ranks' nft prizes (their token accounts closed), sol prize & pool prize are swept. Winners can't withdraw anymore
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[s]` reclaimer account (lottery's authority or treasury's authority)
+    ///     2. `[w]` fund-receiver rent_exempt lamports account
+    ///     3. `[w]` lottery's associated arbitrary token account
+    ///     4. `[w]` fund-receiver arbitrary token account
+    ///     5. `[]` arbitrary mint account
+    ///     6. `[]` standard token program account
+    ///     7. `[]` token-2022 program account
+    ///     8. `[]` config account
+    ///     9. `[]` treasury account
+    ///     then per prize mint :
+    ///     `[]` prize mint account
+    ///     `[w]` lottery's associated prize token account
+    ///     `[w]` fund-receiver prize token account
+    ///     then per unclaimed rank (nft prize lotteries) :
+    ///     `[]` nft prize mint account
+    ///     `[w]` lottery's associated nft token account
+    ///     `[w]` fund-receiver nft token account
+    ///     then (sol prize lotteries) :
+    ///     `[w]` prize-vault pda
+    ///     `[w]` fund-receiver sol prize lamports account
+    ///     then (pool lotteries) :
+    ///     `[]` usdc mint account
+    ///     `[w]` lottery's associated usdc token account
+    ///     `[w]` fund-receiver usdc token account
+    ReclaimUnclaimedPrizes,
+
+    /// Lottery owner(creator) can close the lottery's associated arbitrary token account to reclaim its rent_exempt
+    /// after every winner withdrew its prize (a failed lottery's one is closed by "WithdrawFailedLottery"), the rounding dust of the prizes is swept
+    /// & the prize token accounts left open by the winners are closed
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[s]` lottery account authority
+    ///     2. `[w]` lottery's associated arbitrary token account
+    ///     3. `[w]` fund-receiver arbitrary token account
+    ///     4. `[w]` fund-receiver rent_exempt lamports account
+    ///     5. `[]` arbitrary mint account
+    ///     6. `[]` standard token program account
+    ///     7. `[]` token-2022 program account
+    ///     8. `[]` config account
+    ///     per lottery's prize mint (in the lottery's "prize_mints" order) :
+    ///     9 + 3i. `[]` prize mint account
+    ///    10 + 3i. `[w]` lottery's associated prize token account
+    ///    11 + 3i. `[w]` fund-receiver prize token account
+    CloseLotteryArbitraryTokenAccount,
+
+    /// Buy Ticket/s For Specific lottery on behalf of another wallet (the beneficiary)
+    /// The sponsor pays the usdc tokens & the rent_exempts, the beneficiary's user account is created if missing
+    /// The tickets (& their prizes) belong to the beneficiary, if the lottery fails the sponsored tickets are refunded to the sponsor
+    /// 
+    /// A token-2022 transfer fee of the usdc mint is paid on top of the tickets price, not supported by tokenized lotteries
+    /// 
+    /// Accounts Expected By this Instruction :
+    ///     0. `[]` config account
+    ///     1. `[w]` beneficiary's user account
+    ///     2. `[]` beneficiary (authority of the user-account)
+    ///     3. `[w,s]` sponsor, funding account for rent and usdc tokens
+    ///     4. `[w]` lottery account
+    ///     5. `[w]` lottery's usdc associated token account
+    ///     6. `[w]` sponsor's usdc token account
+    ///     7. `[]` usdc mint account
+    ///     8. `[]` system program account
+    ///     9. `[]` token program account of the usdc mint (standard or token-2022)
+    BuyTicketFor {
+        tickets_amount: u32,
+        expected_token_price_per_ticket: u64
+    },
+
+    /// User can transfer some of its tickets to another user of the same lottery (before the lottery ends)
+    /// The sender's last tickets are moved with their prorated tickets value & rent_exempt, the receiver's
+    /// "maximum_number_of_tickets_per_user" is respected. Splitting a ticket-range costs the sender its rent_exempt
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[]` config account
+    ///     1. `[w]` lottery account
+    ///     2. `[w]` sender's user account
+    ///     3. `[w,s]` authority of the sender's user account
+    ///     4. `[w]` receiver's user account
+    ///     5. `[]` system program account
+    TransferTickets {
+        tickets_amount: u32
+    },
+
+    /// Lottery owner(creator) can tokenize the tickets of the lottery (before the lottery starts)
+    /// "BuyTicket" mints ticket tokens (spl, no decimals) of the ticket-mint pda to the buyer instead of
+    /// registering the tickets, the tokens are tradable until they are redeemed by "RedeemTicketTokens"
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` lottery account
+    ///     1. `[w,s]` lottery_account's authority, funding account for rent
+    ///     2. `[w]` ticket-mint pda
+    ///     3. `[]` standard token program account
+    ///     4. `[]` system program account
+    ///     5. `[]` config account
+    EnableTicketTokens,
+
+    /// User can burn its ticket tokens to register the tickets in its user account (before the lottery ends)
+    /// The tickets take the prorated value of the outstanding ticket tokens, the user pays the ticket-range rent_exempt
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[]` config account
+    ///     1. `[w]` user account
+    ///     2. `[w,s]` authority of the user-account
+    ///     3. `[w]` lottery account
+    ///     4. `[w]` ticket-mint pda
+    ///     5. `[w]` ticket token account of the user_account's authority
+    ///     6. `[]` standard token program account
+    ///     7. `[]` system program account
+    RedeemTicketTokens {
+        tickets_amount: u32
+    },
+
+    /// Holder of ticket tokens can refund them when the lottery fails, no user account is needed
+    /// Every ticket token of the holder's token account is burned & refunded at the prorated value of the outstanding ticket tokens
+    /// (after "MAX_TIME_TO_REFUND_TICKET_TOKENS" since the ending time, closing the lottery sweeps the unrefunded ones to the treasury)
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[]` config account
+    ///     1. `[w]` lottery account
+    ///     2. `[s]` ticket tokens holder (owner of the ticket token account)
+    ///     3. `[w]` ticket-mint pda
+    ///     4. `[w]` ticket token account of the holder
+    ///     5. `[]` standard token program account
+    ///     6. `[]` usdc mint account
+    ///     7. `[w]` lottery's associated usdc token account
+    ///     8. `[w]` fund receiver usdc token account
+    ///     9. `[]` token program account of the usdc mint (standard or token-2022)
+    RefundTicketTokens,
+
+    /// Change the sol treasury (lamports account) which receives the protocol fee of the tickets paid in SOL
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` config account
+    ///     1. `[s]` config authority account
+    ///     2. `[]` new sol treasury
+    ChangeSolTreasury,
+
+    /// Migrate the config account to the current config layout, after a program upgrade which appended config fields
+    /// (the instructions reading the config fail until it is migrated)
+    /// 
+    /// The appended fields start at their defaults (the sol treasury starts as the config authority),
+    /// the funding account tops up the rent_exempt of the reallocated config account
+    /// 
+    /// Accounts Expected By This Instruction :
+    ///     0. `[w]` config account
+    ///     1. `[s]` config authority account
+    ///     2. `[w,s]` funding account for rent
+    ///     3. `[]` system-program account
+    MigrateConfigAccount
 }
 
 impl Instructions {
@@ -351,9 +932,17 @@ pub fn instruction_create_and_initialize_lottery_account(
     minimum_tickets_amount_required_to_be_sold: u32,
     ticket_price: u64,
     maximum_number_of_tickets_per_user: Option<u32>,
+    randomness_commitment: Option<[u8; 32]>,
+    randomness_source: RandomnessSource,
+    prize_table: Option<Vec<u16>>,
+    nft_prizes_accounts: Option<Vec<(Pubkey, Pubkey, Pubkey)>>,
+    sol_prize_amount: u64,
+    pool_creator_share: Option<u16>,
+    maximum_total_tickets: Option<u32>,
+    settlement_bounty: Option<SettlementBounty>,
     lottery_description: String
 ) -> Instruction {
-    let accounts_meta = vec![
+    let mut accounts_meta = vec![
         AccountMeta::new(lottery_account, false),
         AccountMeta::new_readonly(lottery_account_authority_account, true),
         AccountMeta::new(funding_account, true),
@@ -364,11 +953,38 @@ pub fn instruction_create_and_initialize_lottery_account(
         AccountMeta::new(lottery_associated_arbitrary_token_account, false),
         AccountMeta::new(funding_arbitrary_token_account, false),
         AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
         AccountMeta::new_readonly(associated_token_program_account, false),
         AccountMeta::new_readonly(system_program, false),
         AccountMeta::new_readonly(config_account, false)
     ];
 
+    let nft_prizes = nft_prizes_accounts.map(|nft_prizes_accounts| {
+        nft_prizes_accounts
+            .into_iter()
+            .map(|(
+                nft_prize_mint_account,
+                lottery_associated_nft_token_account,
+                funding_nft_token_account
+            )| {
+                accounts_meta.push(AccountMeta::new_readonly(nft_prize_mint_account, false));
+                accounts_meta.push(AccountMeta::new(lottery_associated_nft_token_account, false));
+                accounts_meta.push(AccountMeta::new(funding_nft_token_account, false));
+
+                nft_prize_mint_account
+            })
+            .collect::<Vec<Pubkey>>()
+    });
+
+    if sol_prize_amount > 0 {
+        let (prize_vault_account, _) = get_prize_vault_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(prize_vault_account, false));
+    };
+
     let instruction_data = Instructions::CreateAndInitializeLotteryAccount {
         fund_amount,
         winners_count,
@@ -377,6 +993,14 @@ pub fn instruction_create_and_initialize_lottery_account(
         minimum_tickets_amount_required_to_be_sold,
         ticket_price,
         maximum_number_of_tickets_per_user,
+        randomness_commitment,
+        randomness_source,
+        prize_table,
+        nft_prizes,
+        sol_prize_amount,
+        pool_creator_share,
+        maximum_total_tickets,
+        settlement_bounty,
         lottery_description
     };
 
@@ -425,9 +1049,10 @@ pub fn instruction_buy_ticket(
     system_program_account: Pubkey,
     standard_token_program_account: Pubkey,
     tickets_amount: u32,
-    expected_token_price_per_ticket: u64
+    expected_token_price_per_ticket: u64,
+    is_tickets_tokenized: bool
 ) -> Instruction {
-    let accounts_meta = vec![
+    let mut accounts_meta = vec![
         AccountMeta::new_readonly(config_account, false),
         AccountMeta::new(user_account, false),
         AccountMeta::new_readonly(user_account_authority_account, true),
@@ -440,6 +1065,27 @@ pub fn instruction_buy_ticket(
         AccountMeta::new_readonly(standard_token_program_account, false)
     ];
 
+    if is_tickets_tokenized == true {
+        let (ticket_mint_account, _) = get_ticket_mint_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(ticket_mint_account, false));
+        accounts_meta.push(
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    &user_account_authority_account,
+                    &ticket_mint_account,
+                    &TOKEN_STANDARD_PROGRAM_ID
+                ),
+                false
+            )
+        );
+        accounts_meta.push(AccountMeta::new_readonly(TOKEN_STANDARD_PROGRAM_ID, false));
+        accounts_meta.push(AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false));
+    };
+
     let instruction_data = Instructions::BuyTicket {
         tickets_amount,
         expected_token_price_per_ticket
@@ -478,17 +1124,50 @@ pub fn instruction_end_lottery_and_pick_winners(
     config_account: Pubkey,
     sol_price_feed_account: Pubkey,
     btc_price_feed_account: Pubkey,
-    eth_price_feed_account: Pubkey
+    eth_price_feed_account: Pubkey,
+    randomness_request_account: Option<Pubkey>, // "VrfOracle" lotteries
+    lottery_series_accounts: Option<(Pubkey, Pubkey)>, // (lottery series account, series jackpot vault)
+    bounty_receiver_account: Option<Pubkey>,
+    usdc_bounty_accounts: Option<(Pubkey, Pubkey)> // (usdc mint, token program of the usdc mint)
 ) -> Instruction {
-    let accounts_meta = vec![
+    let mut accounts_meta = vec![
         AccountMeta::new(lottery_account, false),
         AccountMeta::new_readonly(config_account, false),
         AccountMeta::new_readonly(sol_price_feed_account, false),
         AccountMeta::new_readonly(btc_price_feed_account, false),
         AccountMeta::new_readonly(eth_price_feed_account, false),
-        AccountMeta::new_readonly(solana_program::sysvar::instructions::ID, false)
+        AccountMeta::new_readonly(solana_program::sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(solana_program::sysvar::slot_hashes::ID, false)
     ];
 
+    if let Some(randomness_request_account) = randomness_request_account {
+        accounts_meta.push(AccountMeta::new_readonly(randomness_request_account, false));
+    };
+
+    if let Some((lottery_series_account, series_jackpot_vault_account)) = lottery_series_accounts {
+        accounts_meta.push(AccountMeta::new(lottery_series_account, false));
+        accounts_meta.push(AccountMeta::new_readonly(series_jackpot_vault_account, false));
+    };
+
+    if let Some(bounty_receiver_account) = bounty_receiver_account {
+        accounts_meta.push(AccountMeta::new(bounty_receiver_account, false));
+    };
+
+    if let Some((usdc_mint_account, usdc_token_program_account)) = usdc_bounty_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(usdc_mint_account, false));
+        accounts_meta.push(
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    &lottery_account,
+                    &usdc_mint_account,
+                    &usdc_token_program_account
+                ),
+                false
+            )
+        );
+        accounts_meta.push(AccountMeta::new_readonly(usdc_token_program_account, false));
+    };
+
     let instruction_data = Instructions::EndLotteryAndPickWinners;
 
     Instruction::new_with_borsh(
@@ -498,26 +1177,23 @@ pub fn instruction_end_lottery_and_pick_winners(
     )
 }
 
-pub fn instruction_withdraw_succeed_lottery(
+pub fn instruction_reveal_lottery_secret(
     lottery_account: Pubkey,
+    lottery_account_authority_account: Pubkey,
     config_account: Pubkey,
-    lottery_authority_account: Pubkey,
-    lottery_associated_usdc_token_account: Pubkey,
-    fund_receiver_usdc_token_account: Pubkey,
-    usdc_mint_account: Pubkey,
-    standard_token_program_account: Pubkey
+    randomness_request_account: Option<Pubkey>, // "VrfOracle" lotteries
+    secret: [u8; 32]
 ) -> Instruction {
-    let accounts_meta = vec![
+    let mut accounts_meta = vec![
         AccountMeta::new(lottery_account, false),
-        AccountMeta::new_readonly(config_account, false),
-        AccountMeta::new_readonly(lottery_authority_account, true),
-        AccountMeta::new(lottery_associated_usdc_token_account, false),
-        AccountMeta::new(fund_receiver_usdc_token_account, false),
-        AccountMeta::new_readonly(usdc_mint_account, false),
-        AccountMeta::new_readonly(standard_token_program_account, false)
+        AccountMeta::new_readonly(lottery_account_authority_account, true),
+        AccountMeta::new_readonly(config_account, false)
     ];
+    if let Some(randomness_request_account) = randomness_request_account {
+        accounts_meta.push(AccountMeta::new_readonly(randomness_request_account, false));
+    };
 
-    let instruction_data = Instructions::WithdrawSucceedLottery;
+    let instruction_data = Instructions::RevealLotterySecret { secret };
 
     Instruction::new_with_borsh(
         LOTTERY_PROGRAM_ID,
@@ -526,28 +1202,22 @@ pub fn instruction_withdraw_succeed_lottery(
     )
 }
 
-pub fn instruction_withdraw_lottery_winners(
+pub fn instruction_request_randomness(
+    randomness_request_account: Pubkey,
     lottery_account: Pubkey,
-    user_account: Pubkey,
-    user_account_authority_account: Pubkey,
-    lottery_associated_arbitrary_token_account: Pubkey,
-    fund_receiver_arbitrary_token_account: Pubkey,
-    arbitrary_mint_account: Pubkey,
-    standard_token_program_account: Pubkey,
+    funding_account: Pubkey,
+    system_program_account: Pubkey,
     config_account: Pubkey
 ) -> Instruction {
     let accounts_meta = vec![
-        AccountMeta::new(lottery_account, false),
-        AccountMeta::new_readonly(user_account, false),
-        AccountMeta::new_readonly(user_account_authority_account, true),
-        AccountMeta::new(lottery_associated_arbitrary_token_account, false),
-        AccountMeta::new(fund_receiver_arbitrary_token_account, false),
-        AccountMeta::new_readonly(arbitrary_mint_account, false),
-        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new(randomness_request_account, false),
+        AccountMeta::new_readonly(lottery_account, false),
+        AccountMeta::new(funding_account, true),
+        AccountMeta::new_readonly(system_program_account, false),
         AccountMeta::new_readonly(config_account, false)
     ];
 
-    let instruction_data = Instructions::WithdrawLotterysWinners;
+    let instruction_data = Instructions::RequestRandomness;
 
     Instruction::new_with_borsh(
         LOTTERY_PROGRAM_ID,
@@ -556,7 +1226,150 @@ pub fn instruction_withdraw_lottery_winners(
     )
 }
 
-pub fn instruction_withdraw_and_close_succeed_user(
+pub fn instruction_fulfil_randomness(
+    randomness_request_account: Pubkey,
+    lottery_account: Pubkey,
+    config_account: Pubkey,
+    proof: [u8; VRF_PROOF_LENGTH]
+) -> Instruction {
+    let accounts_meta = vec![
+        AccountMeta::new(randomness_request_account, false),
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    let instruction_data = Instructions::FulfilRandomness { proof };
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_withdraw_succeed_lottery(
+    lottery_account: Pubkey,
+    config_account: Pubkey,
+    lottery_authority_account: Pubkey,
+    lottery_associated_usdc_token_account: Pubkey,
+    fund_receiver_usdc_token_account: Pubkey,
+    usdc_mint_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    lottery_series_account: Option<Pubkey>,
+    sol_payments_accounts: Option<(Pubkey, Pubkey)> // (fund-receiver sol account, sol treasury)
+) -> Instruction {
+    let mut accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(config_account, false),
+        AccountMeta::new_readonly(lottery_authority_account, true),
+        AccountMeta::new(lottery_associated_usdc_token_account, false),
+        AccountMeta::new(fund_receiver_usdc_token_account, false),
+        AccountMeta::new_readonly(usdc_mint_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false)
+    ];
+
+    if let Some(lottery_series_account) = lottery_series_account {
+        accounts_meta.push(AccountMeta::new_readonly(lottery_series_account, false));
+        accounts_meta.push(
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    &lottery_series_account,
+                    &usdc_mint_account,
+                    &standard_token_program_account
+                ),
+                false
+            )
+        );
+    };
+
+    if let Some((
+        fund_receiver_sol_account,
+        sol_treasury_account
+    )) = sol_payments_accounts {
+        let (sol_payments_vault_account, _) = get_sol_payments_vault_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(sol_payments_vault_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_sol_account, false));
+        accounts_meta.push(AccountMeta::new(sol_treasury_account, false));
+    };
+
+    let instruction_data = Instructions::WithdrawSucceedLottery;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_withdraw_lottery_winners(
+    lottery_account: Pubkey,
+    user_account: Pubkey,
+    user_account_authority_account: Pubkey,
+    lottery_associated_arbitrary_token_account: Pubkey,
+    fund_receiver_arbitrary_token_account: Pubkey,
+    arbitrary_mint_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    config_account: Pubkey,
+    prize_mints_accounts: Vec<(Pubkey, Pubkey, Pubkey)>,
+    fund_receiver_sol_prize_account: Option<Pubkey>,
+    pool_prize_accounts: Option<(Pubkey, Pubkey, Pubkey)>
+) -> Instruction {
+    let mut accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(user_account, false),
+        AccountMeta::new_readonly(user_account_authority_account, true),
+        AccountMeta::new(lottery_associated_arbitrary_token_account, false),
+        AccountMeta::new(fund_receiver_arbitrary_token_account, false),
+        AccountMeta::new_readonly(arbitrary_mint_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    for (
+        prize_mint_account,
+        lottery_associated_prize_token_account,
+        fund_receiver_prize_token_account
+    ) in prize_mints_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(prize_mint_account, false));
+        accounts_meta.push(AccountMeta::new(lottery_associated_prize_token_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_prize_token_account, false));
+    };
+
+    if let Some(fund_receiver_sol_prize_account) = fund_receiver_sol_prize_account {
+        let (prize_vault_account, _) = get_prize_vault_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(prize_vault_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_sol_prize_account, false));
+    };
+
+    if let Some((
+        usdc_mint_account,
+        lottery_associated_usdc_token_account,
+        fund_receiver_usdc_token_account
+    )) = pool_prize_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(usdc_mint_account, false));
+        accounts_meta.push(AccountMeta::new(lottery_associated_usdc_token_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_usdc_token_account, false));
+    };
+
+    let instruction_data = Instructions::WithdrawLotterysWinners;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_withdraw_and_close_succeed_user(
     lottery_account: Pubkey,
     user_account: Pubkey,
     user_account_authority_account: Pubkey,
@@ -593,9 +1406,11 @@ pub fn instruction_withdraw_failed_lottery(
     fund_receiver_usdc_token_account: Pubkey,
     fund_receiver_arbitrary_token_account: Pubkey,
     fund_receiver_refunded_rent_exempt: Pubkey,
-    standard_token_program_account: Pubkey
+    standard_token_program_account: Pubkey,
+    prize_mints_accounts: Vec<(Pubkey, Pubkey, Pubkey)>,
+    fund_receiver_sol_prize_account: Option<Pubkey>
 ) -> Instruction {
-    let accounts_meta = vec![
+    let mut accounts_meta = vec![
         AccountMeta::new_readonly(config_account, false),
         AccountMeta::new(lottery_account, false),
         AccountMeta::new_readonly(lottery_authority_account, true),
@@ -606,9 +1421,30 @@ pub fn instruction_withdraw_failed_lottery(
         AccountMeta::new(fund_receiver_usdc_token_account, false),
         AccountMeta::new(fund_receiver_arbitrary_token_account, false),
         AccountMeta::new(fund_receiver_refunded_rent_exempt, false),
-        AccountMeta::new_readonly(standard_token_program_account, false)
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false)
     ];
 
+    for (
+        prize_mint_account,
+        lottery_associated_prize_token_account,
+        fund_receiver_prize_token_account
+    ) in prize_mints_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(prize_mint_account, false));
+        accounts_meta.push(AccountMeta::new(lottery_associated_prize_token_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_prize_token_account, false));
+    };
+
+    if let Some(fund_receiver_sol_prize_account) = fund_receiver_sol_prize_account {
+        let (prize_vault_account, _) = get_prize_vault_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(prize_vault_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_sol_prize_account, false));
+    };
+
     let instruction_data = Instructions::WithdrawFailedLottery;
 
     Instruction::new_with_borsh(
@@ -628,9 +1464,12 @@ pub fn instruction_withdraw_and_close_failed_user(
     fund_receiver_usdc_token_account: Pubkey,
     fund_receiver_tickets_rent_exempt_account: Pubkey,
     fund_receiver_rent_exempt_account: Pubkey,
-    standard_token_program_account: Pubkey
+    standard_token_program_account: Pubkey,
+    fund_receiver_sol_account: Option<Pubkey>,
+    sponsor_usdc_token_account: Option<Pubkey>,
+    ticket_token_account: Option<Pubkey>
 ) -> Instruction {
-    let accounts_meta = vec![
+    let mut accounts_meta = vec![
         AccountMeta::new_readonly(config_account, false),
         AccountMeta::new(lottery_account, false),
         AccountMeta::new(user_account, false),
@@ -643,6 +1482,31 @@ pub fn instruction_withdraw_and_close_failed_user(
         AccountMeta::new_readonly(standard_token_program_account, false)
     ];
 
+    if let Some(fund_receiver_sol_account) = fund_receiver_sol_account {
+        let (sol_payments_vault_account, _) = get_sol_payments_vault_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(sol_payments_vault_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_sol_account, false));
+    };
+
+    if let Some(sponsor_usdc_token_account) = sponsor_usdc_token_account {
+        accounts_meta.push(AccountMeta::new(sponsor_usdc_token_account, false));
+    };
+
+    if let Some(ticket_token_account) = ticket_token_account {
+        let (ticket_mint_account, _) = get_ticket_mint_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(ticket_mint_account, false));
+        accounts_meta.push(AccountMeta::new(ticket_token_account, false));
+        accounts_meta.push(AccountMeta::new_readonly(TOKEN_STANDARD_PROGRAM_ID, false));
+    };
+
     let instruction_data = Instructions::WithdrawAndCloseFailedUser;
 
     Instruction::new_with_borsh(
@@ -660,9 +1524,11 @@ pub fn instruction_close_lottery_account_and_usdc_token_account(
     lottery_associated_usdc_token_account: Pubkey,
     fund_receiver_usdc_token_account: Pubkey,
     fund_receiver_rent_exempt_account: Pubkey,
-    standard_token_program_account: Pubkey
+    standard_token_program_account: Pubkey,
+    has_sol_prize: bool,
+    treasury_account: Option<Pubkey> // failed lotteries with ticket tokens not refunded in time
 ) -> Instruction {
-    let accounts_meta = vec![
+    let mut accounts_meta = vec![
         AccountMeta::new_readonly(config_account, false),
         AccountMeta::new(lottery_account, false),
         AccountMeta::new_readonly(lottery_account_authority_account, true),
@@ -670,9 +1536,29 @@ pub fn instruction_close_lottery_account_and_usdc_token_account(
         AccountMeta::new(lottery_associated_usdc_token_account, false),
         AccountMeta::new(fund_receiver_usdc_token_account, false),
         AccountMeta::new(fund_receiver_rent_exempt_account, false),
-        AccountMeta::new_readonly(standard_token_program_account, false)
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new(
+            get_sol_payments_vault_account_pda(
+                &lottery_account,
+                &LOTTERY_PROGRAM_ID
+            ).unwrap().0,
+            false
+        )
     ];
 
+    if has_sol_prize == true {
+        let (prize_vault_account, _) = get_prize_vault_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(prize_vault_account, false));
+    };
+
+    if let Some(treasury_account) = treasury_account {
+        accounts_meta.push(AccountMeta::new(treasury_account, false));
+    };
+
     let instruction_data = Instructions::CloseLotteryAccountAndUsdcTokenAccount;
 
     Instruction::new_with_borsh(
@@ -681,3 +1567,735 @@ pub fn instruction_close_lottery_account_and_usdc_token_account(
         accounts_meta
     )
 }
+
+pub fn instruction_add_prize_mint(
+    lottery_account: Pubkey,
+    lottery_account_authority_account: Pubkey,
+    funding_account: Pubkey,
+    prize_mint_account: Pubkey,
+    lottery_associated_prize_token_account: Pubkey,
+    funding_prize_token_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    associated_token_program_account: Pubkey,
+    system_program_account: Pubkey,
+    config_account: Pubkey,
+    prize_amount: u64
+) -> Instruction {
+    let accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(lottery_account_authority_account, true),
+        AccountMeta::new(funding_account, true),
+        AccountMeta::new_readonly(prize_mint_account, false),
+        AccountMeta::new(lottery_associated_prize_token_account, false),
+        AccountMeta::new(funding_prize_token_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(associated_token_program_account, false),
+        AccountMeta::new_readonly(system_program_account, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    let instruction_data = Instructions::AddPrizeMint { prize_amount };
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_create_lottery_series(
+    lottery_series_account: Pubkey,
+    lottery_series_authority_account: Pubkey,
+    funding_account: Pubkey,
+    usdc_mint_account: Pubkey,
+    series_jackpot_vault_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    associated_token_program_account: Pubkey,
+    system_program_account: Pubkey,
+    config_account: Pubkey,
+    jackpot_share: u16,
+    house_slots: u32,
+    series_description: String
+) -> Instruction {
+    let accounts_meta = vec![
+        AccountMeta::new(lottery_series_account, false),
+        AccountMeta::new_readonly(lottery_series_authority_account, true),
+        AccountMeta::new(funding_account, true),
+        AccountMeta::new_readonly(usdc_mint_account, false),
+        AccountMeta::new(series_jackpot_vault_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(associated_token_program_account, false),
+        AccountMeta::new_readonly(system_program_account, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    let instruction_data = Instructions::CreateLotterySeries {
+        jackpot_share,
+        house_slots,
+        series_description
+    };
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_join_lottery_series(
+    lottery_account: Pubkey,
+    lottery_account_authority_account: Pubkey,
+    funding_account: Pubkey,
+    lottery_series_account: Pubkey,
+    system_program_account: Pubkey,
+    config_account: Pubkey,
+    previous_round_account: Option<Pubkey>
+) -> Instruction {
+    let mut accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(lottery_account_authority_account, true),
+        AccountMeta::new(funding_account, true),
+        AccountMeta::new(lottery_series_account, false),
+        AccountMeta::new_readonly(system_program_account, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    if let Some(previous_round_account) = previous_round_account {
+        accounts_meta.push(AccountMeta::new_readonly(previous_round_account, false));
+    };
+
+    let instruction_data = Instructions::JoinLotterySeries;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_withdraw_series_jackpot(
+    lottery_account: Pubkey,
+    user_account: Pubkey,
+    user_account_authority_account: Pubkey,
+    lottery_series_account: Pubkey,
+    series_jackpot_vault_account: Pubkey,
+    fund_receiver_usdc_token_account: Pubkey,
+    usdc_mint_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    config_account: Pubkey
+) -> Instruction {
+    let accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(user_account, false),
+        AccountMeta::new_readonly(user_account_authority_account, true),
+        AccountMeta::new(lottery_series_account, false),
+        AccountMeta::new(series_jackpot_vault_account, false),
+        AccountMeta::new(fund_receiver_usdc_token_account, false),
+        AccountMeta::new_readonly(usdc_mint_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    let instruction_data = Instructions::WithdrawSeriesJackpot;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_buy_ticket_with_oracle_conversion(
+    config_account: Pubkey,
+    user_account: Pubkey,
+    user_account_authority_account: Pubkey,
+    funding_account: Pubkey,
+    lottery_account: Pubkey,
+    usdc_mint_account: Pubkey,
+    sol_price_feed_account: Pubkey,
+    system_program_account: Pubkey,
+    tickets_amount: u32,
+    expected_token_price_per_ticket: u64,
+    maximum_lamports_to_spend: u64
+) -> Instruction {
+    let (sol_payments_vault_account, _) = get_sol_payments_vault_account_pda(
+        &lottery_account,
+        &LOTTERY_PROGRAM_ID
+    ).unwrap();
+
+    let accounts_meta = vec![
+        AccountMeta::new_readonly(config_account, false),
+        AccountMeta::new(user_account, false),
+        AccountMeta::new_readonly(user_account_authority_account, true),
+        AccountMeta::new(funding_account, true),
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new(sol_payments_vault_account, false),
+        AccountMeta::new_readonly(usdc_mint_account, false),
+        AccountMeta::new_readonly(sol_price_feed_account, false),
+        AccountMeta::new_readonly(system_program_account, false)
+    ];
+
+    let instruction_data = Instructions::BuyTicketWithOracleConversion {
+        tickets_amount,
+        expected_token_price_per_ticket,
+        maximum_lamports_to_spend
+    };
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_cancel_lottery(
+    config_account: Pubkey,
+    lottery_account: Pubkey,
+    lottery_authority_account: Pubkey,
+    usdc_mint_account: Pubkey,
+    arbitrary_mint_account: Pubkey,
+    lottery_associated_usdc_token_account: Pubkey,
+    lottery_associated_arbitrary_token_account: Pubkey,
+    fund_receiver_usdc_token_account: Pubkey,
+    fund_receiver_arbitrary_token_account: Pubkey,
+    treasury_account: Pubkey,
+    fund_receiver_refunded_rent_exempt: Pubkey,
+    standard_token_program_account: Pubkey,
+    prize_mints_accounts: Vec<(Pubkey, Pubkey, Pubkey)>,
+    fund_receiver_sol_prize_account: Option<Pubkey>
+) -> Instruction {
+    let mut accounts_meta = vec![
+        AccountMeta::new_readonly(config_account, false),
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(lottery_authority_account, true),
+        AccountMeta::new_readonly(usdc_mint_account, false),
+        AccountMeta::new_readonly(arbitrary_mint_account, false),
+        AccountMeta::new(lottery_associated_usdc_token_account, false),
+        AccountMeta::new(lottery_associated_arbitrary_token_account, false),
+        AccountMeta::new(fund_receiver_usdc_token_account, false),
+        AccountMeta::new(fund_receiver_arbitrary_token_account, false),
+        AccountMeta::new(treasury_account, false),
+        AccountMeta::new(fund_receiver_refunded_rent_exempt, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false)
+    ];
+
+    for (
+        prize_mint_account,
+        lottery_associated_prize_token_account,
+        fund_receiver_prize_token_account
+    ) in prize_mints_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(prize_mint_account, false));
+        accounts_meta.push(AccountMeta::new(lottery_associated_prize_token_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_prize_token_account, false));
+    };
+
+    if let Some(fund_receiver_sol_prize_account) = fund_receiver_sol_prize_account {
+        let (prize_vault_account, _) = get_prize_vault_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(prize_vault_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_sol_prize_account, false));
+    };
+
+    let instruction_data = Instructions::CancelLottery;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_update_lottery_schedule(
+    lottery_account: Pubkey,
+    lottery_account_authority_account: Pubkey,
+    config_account: Pubkey,
+    new_starting_time: Time,
+    new_ending_time: Time
+) -> Instruction {
+    let accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(lottery_account_authority_account, true),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    let instruction_data = Instructions::UpdateLotterySchedule {
+        new_starting_time,
+        new_ending_time
+    };
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_set_lottery_series_round_template(
+    lottery_series_account: Pubkey,
+    lottery_series_authority_account: Pubkey,
+    funding_account: Pubkey,
+    prize_token_program_account: Pubkey,
+    associated_token_program_account: Pubkey,
+    system_program_account: Pubkey,
+    config_account: Pubkey,
+    round_template: RoundTemplate
+) -> Instruction {
+    let (series_prize_vault_pda, _) = get_series_prize_vault_account_pda(
+        &lottery_series_account,
+        &LOTTERY_PROGRAM_ID
+    ).unwrap();
+
+    let accounts_meta = vec![
+        AccountMeta::new(lottery_series_account, false),
+        AccountMeta::new_readonly(lottery_series_authority_account, true),
+        AccountMeta::new(funding_account, true),
+        AccountMeta::new_readonly(round_template.prize_mint, false),
+        AccountMeta::new_readonly(series_prize_vault_pda, false),
+        AccountMeta::new(
+            get_associated_token_address_with_program_id(
+                &series_prize_vault_pda,
+                &round_template.prize_mint,
+                &prize_token_program_account
+            ),
+            false
+        ),
+        AccountMeta::new_readonly(prize_token_program_account, false),
+        AccountMeta::new_readonly(associated_token_program_account, false),
+        AccountMeta::new_readonly(system_program_account, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    let instruction_data = Instructions::SetLotterySeriesRoundTemplate {
+        round_template
+    };
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_start_next_round(
+    lottery_series_account: Pubkey,
+    lottery_account: Pubkey,
+    usdc_mint_account: Pubkey,
+    lottery_associated_usdc_token_account: Pubkey,
+    series_usdc_vault_account: Pubkey,
+    prize_mint_account: Pubkey,
+    lottery_associated_prize_token_account: Pubkey,
+    series_prize_vault_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    associated_token_program_account: Pubkey,
+    system_program_account: Pubkey,
+    config_account: Pubkey,
+    previous_round_account: Option<Pubkey>
+) -> Instruction {
+    let (series_prize_vault_pda, _) = get_series_prize_vault_account_pda(
+        &lottery_series_account,
+        &LOTTERY_PROGRAM_ID
+    ).unwrap();
+
+    let mut accounts_meta = vec![
+        AccountMeta::new(lottery_series_account, false),
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(usdc_mint_account, false),
+        AccountMeta::new(lottery_associated_usdc_token_account, false),
+        AccountMeta::new(series_usdc_vault_account, false),
+        AccountMeta::new_readonly(prize_mint_account, false),
+        AccountMeta::new(lottery_associated_prize_token_account, false),
+        AccountMeta::new(series_prize_vault_pda, false),
+        AccountMeta::new(series_prize_vault_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+        AccountMeta::new_readonly(associated_token_program_account, false),
+        AccountMeta::new_readonly(system_program_account, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    if let Some(previous_round_account) = previous_round_account {
+        accounts_meta.push(AccountMeta::new_readonly(previous_round_account, false));
+    };
+
+    let instruction_data = Instructions::StartNextRound;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_settle_failed_lottery(
+    lottery_account: Pubkey,
+    config_account: Pubkey,
+    bounty_receiver_account: Pubkey,
+    usdc_bounty_accounts: Option<(Pubkey, Pubkey)> // (usdc mint, token program of the usdc mint)
+) -> Instruction {
+    let mut accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(config_account, false),
+        AccountMeta::new(bounty_receiver_account, false)
+    ];
+
+    if let Some((usdc_mint_account, usdc_token_program_account)) = usdc_bounty_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(usdc_mint_account, false));
+        accounts_meta.push(
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    &lottery_account,
+                    &usdc_mint_account,
+                    &usdc_token_program_account
+                ),
+                false
+            )
+        );
+        accounts_meta.push(AccountMeta::new_readonly(usdc_token_program_account, false));
+    };
+
+    let instruction_data = Instructions::SettleFailedLottery;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_distribute_prizes(
+    lottery_account: Pubkey,
+    funding_account: Pubkey,
+    lottery_associated_arbitrary_token_account: Pubkey,
+    arbitrary_mint_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    associated_token_program_account: Pubkey,
+    system_program_account: Pubkey,
+    config_account: Pubkey,
+    winners_accounts: Vec<(Pubkey, Pubkey, Pubkey)> // (user account, user-account's authority, authority's associated arbitrary token account)
+) -> Instruction {
+    let mut accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new(funding_account, true),
+        AccountMeta::new(lottery_associated_arbitrary_token_account, false),
+        AccountMeta::new_readonly(arbitrary_mint_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+        AccountMeta::new_readonly(associated_token_program_account, false),
+        AccountMeta::new_readonly(system_program_account, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    for (
+        user_account,
+        user_account_authority_account,
+        fund_receiver_arbitrary_token_account
+    ) in winners_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(user_account, false));
+        accounts_meta.push(AccountMeta::new_readonly(user_account_authority_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_arbitrary_token_account, false));
+    };
+
+    let instruction_data = Instructions::DistributePrizes;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_reclaim_unclaimed_prizes(
+    lottery_account: Pubkey,
+    reclaimer_account: Pubkey,
+    fund_receiver_rent_exempt_account: Pubkey,
+    lottery_associated_arbitrary_token_account: Pubkey,
+    fund_receiver_arbitrary_token_account: Pubkey,
+    arbitrary_mint_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    config_account: Pubkey,
+    treasury_account: Pubkey,
+    prize_mints_accounts: Vec<(Pubkey, Pubkey, Pubkey)>,
+    nft_prizes_accounts: Vec<(Pubkey, Pubkey, Pubkey)>, // per unclaimed rank
+    fund_receiver_sol_prize_account: Option<Pubkey>,
+    pool_prize_accounts: Option<(Pubkey, Pubkey, Pubkey)>
+) -> Instruction {
+    let mut accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(reclaimer_account, true),
+        AccountMeta::new(fund_receiver_rent_exempt_account, false),
+        AccountMeta::new(lottery_associated_arbitrary_token_account, false),
+        AccountMeta::new(fund_receiver_arbitrary_token_account, false),
+        AccountMeta::new_readonly(arbitrary_mint_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+        AccountMeta::new_readonly(config_account, false),
+        AccountMeta::new_readonly(treasury_account, false)
+    ];
+
+    for (
+        prize_mint_account,
+        lottery_associated_prize_token_account,
+        fund_receiver_prize_token_account
+    ) in prize_mints_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(prize_mint_account, false));
+        accounts_meta.push(AccountMeta::new(lottery_associated_prize_token_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_prize_token_account, false));
+    };
+
+    for (
+        nft_prize_mint_account,
+        lottery_associated_nft_token_account,
+        fund_receiver_nft_token_account
+    ) in nft_prizes_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(nft_prize_mint_account, false));
+        accounts_meta.push(AccountMeta::new(lottery_associated_nft_token_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_nft_token_account, false));
+    };
+
+    if let Some(fund_receiver_sol_prize_account) = fund_receiver_sol_prize_account {
+        let (prize_vault_account, _) = get_prize_vault_account_pda(
+            &lottery_account,
+            &LOTTERY_PROGRAM_ID
+        ).unwrap();
+
+        accounts_meta.push(AccountMeta::new(prize_vault_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_sol_prize_account, false));
+    };
+
+    if let Some((
+        usdc_mint_account,
+        lottery_associated_usdc_token_account,
+        fund_receiver_usdc_token_account
+    )) = pool_prize_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(usdc_mint_account, false));
+        accounts_meta.push(AccountMeta::new(lottery_associated_usdc_token_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_usdc_token_account, false));
+    };
+
+    let instruction_data = Instructions::ReclaimUnclaimedPrizes;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_close_lottery_arbitrary_token_account(
+    lottery_account: Pubkey,
+    lottery_account_authority_account: Pubkey,
+    lottery_associated_arbitrary_token_account: Pubkey,
+    fund_receiver_arbitrary_token_account: Pubkey,
+    fund_receiver_rent_exempt_account: Pubkey,
+    arbitrary_mint_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    config_account: Pubkey,
+    prize_mints_accounts: Vec<(Pubkey, Pubkey, Pubkey)>
+) -> Instruction {
+    let mut accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(lottery_account_authority_account, true),
+        AccountMeta::new(lottery_associated_arbitrary_token_account, false),
+        AccountMeta::new(fund_receiver_arbitrary_token_account, false),
+        AccountMeta::new(fund_receiver_rent_exempt_account, false),
+        AccountMeta::new_readonly(arbitrary_mint_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    for (
+        prize_mint_account,
+        lottery_associated_prize_token_account,
+        fund_receiver_prize_token_account
+    ) in prize_mints_accounts {
+        accounts_meta.push(AccountMeta::new_readonly(prize_mint_account, false));
+        accounts_meta.push(AccountMeta::new(lottery_associated_prize_token_account, false));
+        accounts_meta.push(AccountMeta::new(fund_receiver_prize_token_account, false));
+    };
+
+    let instruction_data = Instructions::CloseLotteryArbitraryTokenAccount;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_buy_ticket_for(
+    config_account: Pubkey,
+    user_account: Pubkey,
+    beneficiary_account: Pubkey,
+    sponsor_account: Pubkey,
+    lottery_account: Pubkey,
+    lottery_usdc_associated_token_account: Pubkey,
+    sponsor_usdc_token_account: Pubkey,
+    usdc_mint_account: Pubkey,
+    system_program_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    tickets_amount: u32,
+    expected_token_price_per_ticket: u64
+) -> Instruction {
+    let accounts_meta = vec![
+        AccountMeta::new_readonly(config_account, false),
+        AccountMeta::new(user_account, false),
+        AccountMeta::new_readonly(beneficiary_account, false),
+        AccountMeta::new(sponsor_account, true),
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new(lottery_usdc_associated_token_account, false),
+        AccountMeta::new(sponsor_usdc_token_account, false),
+        AccountMeta::new_readonly(usdc_mint_account, false),
+        AccountMeta::new_readonly(system_program_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false)
+    ];
+
+    let instruction_data = Instructions::BuyTicketFor {
+        tickets_amount,
+        expected_token_price_per_ticket
+    };
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_transfer_tickets(
+    config_account: Pubkey,
+    lottery_account: Pubkey,
+    sender_user_account: Pubkey,
+    sender_user_account_authority_account: Pubkey,
+    receiver_user_account: Pubkey,
+    system_program_account: Pubkey,
+    tickets_amount: u32
+) -> Instruction {
+    let accounts_meta = vec![
+        AccountMeta::new_readonly(config_account, false),
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new(sender_user_account, false),
+        AccountMeta::new(sender_user_account_authority_account, true),
+        AccountMeta::new(receiver_user_account, false),
+        AccountMeta::new_readonly(system_program_account, false)
+    ];
+
+    let instruction_data = Instructions::TransferTickets {
+        tickets_amount
+    };
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_enable_ticket_tokens(
+    lottery_account: Pubkey,
+    lottery_account_authority_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    system_program_account: Pubkey,
+    config_account: Pubkey
+) -> Instruction {
+    let (ticket_mint_account, _) = get_ticket_mint_account_pda(
+        &lottery_account,
+        &LOTTERY_PROGRAM_ID
+    ).unwrap();
+
+    let accounts_meta = vec![
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new(lottery_account_authority_account, true),
+        AccountMeta::new(ticket_mint_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(system_program_account, false),
+        AccountMeta::new_readonly(config_account, false)
+    ];
+
+    let instruction_data = Instructions::EnableTicketTokens;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_redeem_ticket_tokens(
+    config_account: Pubkey,
+    user_account: Pubkey,
+    user_account_authority_account: Pubkey,
+    lottery_account: Pubkey,
+    user_ticket_token_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    system_program_account: Pubkey,
+    tickets_amount: u32
+) -> Instruction {
+    let (ticket_mint_account, _) = get_ticket_mint_account_pda(
+        &lottery_account,
+        &LOTTERY_PROGRAM_ID
+    ).unwrap();
+
+    let accounts_meta = vec![
+        AccountMeta::new_readonly(config_account, false),
+        AccountMeta::new(user_account, false),
+        AccountMeta::new(user_account_authority_account, true),
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new(ticket_mint_account, false),
+        AccountMeta::new(user_ticket_token_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(system_program_account, false)
+    ];
+
+    let instruction_data = Instructions::RedeemTicketTokens {
+        tickets_amount
+    };
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
+
+pub fn instruction_refund_ticket_tokens(
+    config_account: Pubkey,
+    lottery_account: Pubkey,
+    holder_account: Pubkey,
+    holder_ticket_token_account: Pubkey,
+    standard_token_program_account: Pubkey,
+    usdc_mint_account: Pubkey,
+    lottery_associated_usdc_token_account: Pubkey,
+    fund_receiver_usdc_token_account: Pubkey,
+    usdc_token_program_account: Pubkey
+) -> Instruction {
+    let (ticket_mint_account, _) = get_ticket_mint_account_pda(
+        &lottery_account,
+        &LOTTERY_PROGRAM_ID
+    ).unwrap();
+
+    let accounts_meta = vec![
+        AccountMeta::new_readonly(config_account, false),
+        AccountMeta::new(lottery_account, false),
+        AccountMeta::new_readonly(holder_account, true),
+        AccountMeta::new(ticket_mint_account, false),
+        AccountMeta::new(holder_ticket_token_account, false),
+        AccountMeta::new_readonly(standard_token_program_account, false),
+        AccountMeta::new_readonly(usdc_mint_account, false),
+        AccountMeta::new(lottery_associated_usdc_token_account, false),
+        AccountMeta::new(fund_receiver_usdc_token_account, false),
+        AccountMeta::new_readonly(usdc_token_program_account, false)
+    ];
+
+    let instruction_data = Instructions::RefundTicketTokens;
+
+    Instruction::new_with_borsh(
+        LOTTERY_PROGRAM_ID,
+        &instruction_data,
+        accounts_meta
+    )
+}
@@ -1,9 +1,13 @@
 pub mod error;
 pub mod instruction;
+pub mod pda;
 pub mod processor;
 pub mod state;
 pub mod program;
 pub mod types;
+pub mod vrf;
+#[cfg(not(target_os = "solana"))]
+pub mod verify;
 
 #[cfg(not(feature = "no-entrypoint"))]
 pub mod entrypoint;
\ No newline at end of file
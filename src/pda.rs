@@ -0,0 +1,63 @@
+// Addresses of the program-owned vaults & mints of the lotteries & series, shared by the instruction builders & the processor
+use {
+    crate::state::{
+        PRIZE_VAULT_ACCOUNT_SEED,
+        SERIES_PRIZE_VAULT_ACCOUNT_SEED,
+        SOL_PAYMENTS_VAULT_ACCOUNT_SEED,
+        TICKET_MINT_ACCOUNT_SEED
+    },
+
+    solana_program::pubkey::Pubkey
+};
+
+pub fn get_prize_vault_account_pda(
+    lottery_account: &Pubkey,
+    program_id: &Pubkey
+) -> Option<(Pubkey, u8)> {
+    Pubkey::try_find_program_address(
+        &[
+            PRIZE_VAULT_ACCOUNT_SEED.as_bytes(),
+            lottery_account.to_bytes().as_slice()
+        ],
+        program_id
+    )
+}
+
+pub fn get_ticket_mint_account_pda(
+    lottery_account: &Pubkey,
+    program_id: &Pubkey
+) -> Option<(Pubkey, u8)> {
+    Pubkey::try_find_program_address(
+        &[
+            TICKET_MINT_ACCOUNT_SEED.as_bytes(),
+            lottery_account.to_bytes().as_slice()
+        ],
+        program_id
+    )
+}
+
+pub fn get_sol_payments_vault_account_pda(
+    lottery_account: &Pubkey,
+    program_id: &Pubkey
+) -> Option<(Pubkey, u8)> {
+    Pubkey::try_find_program_address(
+        &[
+            SOL_PAYMENTS_VAULT_ACCOUNT_SEED.as_bytes(),
+            lottery_account.to_bytes().as_slice()
+        ],
+        program_id
+    )
+}
+
+pub fn get_series_prize_vault_account_pda(
+    lottery_series_account: &Pubkey,
+    program_id: &Pubkey
+) -> Option<(Pubkey, u8)> {
+    Pubkey::try_find_program_address(
+        &[
+            SERIES_PRIZE_VAULT_ACCOUNT_SEED.as_bytes(),
+            lottery_series_account.to_bytes().as_slice()
+        ],
+        program_id
+    )
+}
@@ -2,16 +2,40 @@ use {
     crate::{
         error::LotteryError,
         instruction::Instructions,
+        pda::{
+            get_prize_vault_account_pda,
+            get_series_prize_vault_account_pda,
+            get_sol_payments_vault_account_pda,
+            get_ticket_mint_account_pda
+        },
         state::{
             Config,
             Lottery,
+            LotterySeries,
             LotteryState,
+            RandomNumbersInfo,
+            RandomnessRequest,
+            RandomnessSource,
+            RoundTemplate,
+            SeriesJackpot,
+            SettlementBounty,
+            UnclaimedPrizesReceiver,
             User,
             CONFIG_ACCOUNT_SEED,
             LOTTERY_ACCOUNT_SEED,
+            LOTTERY_SERIES_ACCOUNT_SEED,
+            PRIZE_MINT_INFO_LENGTH,
+            PRIZE_VAULT_ACCOUNT_SEED,
+            RANDOMNESS_REQUEST_ACCOUNT_SEED,
+            SERIES_JACKPOT_LENGTH,
+            SERIES_PRIZE_VAULT_ACCOUNT_SEED,
+            SOL_PAYMENTS_VAULT_ACCOUNT_SEED,
+            TICKET_MINT_ACCOUNT_SEED,
+            TICKET_RANGE_LENGTH,
             USER_ACCOUNT_SEED
         },
-        types::*
+        types::*,
+        vrf::VRF_PROOF_LENGTH
     },
 
     borsh::{
@@ -73,25 +97,41 @@ use {
     },
 
     spl_associated_token_account::{
-        get_associated_token_address,
+        get_associated_token_address_with_program_id,
         instruction::create_associated_token_account_idempotent,
         ID as ASSOCIATED_TOKEN_PROGRAM_ID
     },
 
-    spl_token::{
+    spl_token_2022::{
+        extension::{
+            non_transferable::NonTransferable,
+            transfer_fee::TransferFeeConfig,
+            transfer_hook::TransferHook,
+            BaseStateWithExtensions,
+            StateWithExtensions
+        },
         instruction::{
             transfer_checked as transfer_spl_checked,
-            close_account as close_token_account
+            close_account as close_token_account,
+            initialize_mint2,
+            mint_to_checked,
+            burn_checked
         },
         state::{
             Account as TokenAccount,
             Mint as MintAccount
-        }
+        },
+        ID as TOKEN_2022_PROGRAM_ID
     },
+
+    spl_token::ID as TOKEN_STANDARD_PROGRAM_ID,
     
     std::mem::size_of
 };
 
+// SOL has 9 decimals (lamports)
+const LAMPORTS_DECIMALS: u8 = 9;
+
 // Initial authority
 // - mainnet and devnet
 #[cfg(feature = "onchain_authority")]
@@ -214,6 +254,14 @@ impl Processor {
         minimum_tickets_amount_required_to_be_sold: u32,
         ticket_price: u64,
         maximum_number_of_tickets_per_user: Option<u32>,
+        randomness_commitment: Option<[u8; HASH_BYTES]>,
+        randomness_source: RandomnessSource,
+        prize_table: Option<Vec<BasisPoints>>,
+        nft_prizes: Option<Vec<NftPrizeMint>>,
+        sol_prize_amount: u64,
+        pool_creator_share: Option<BasisPoints>,
+        maximum_total_tickets: Option<u32>,
+        settlement_bounty: Option<SettlementBounty>,
         lottery_description: String
     ) -> ProgramResult {
         let accounts_info = &mut accounts_info.iter();
@@ -228,6 +276,7 @@ impl Processor {
         let lottery_associated_arbitrary_token_account_info = next_account_info(accounts_info)?;
         let funding_arbitrary_token_account_info = next_account_info(accounts_info)?;
         let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let token_2022_program_account_info = next_account_info(accounts_info)?;
         let associated_token_program_account_info = next_account_info(accounts_info)?;
         let system_program_account_info = next_account_info(accounts_info)?;
         let config_global_account_info = next_account_info(accounts_info)?;
@@ -322,22 +371,98 @@ impl Processor {
             );
         };
 
-        if fund_amount == 0 {
+        // nft prizes, a sol prize or the pool of the ticket sales can be the only prizes of the lottery
+        if fund_amount == 0 && nft_prizes.is_none() == true && sol_prize_amount == 0 && pool_creator_share.is_none() == true {
             return Err(
                 LotteryError::InvalidFundAmount.into()
             );
         };
 
-        if fund_amount % (winners_count as u64) != 0 {
+        Lottery::validate_randomness_commitment(
+            randomness_source,
+            &randomness_commitment
+        )?;
+
+        // an equal split must be exact, a prize table hands its rounding dust to the 1st rank
+        Lottery::validate_prize_table(
+            &prize_table,
+            winners_count
+        )?;
+
+        Lottery::validate_nft_prizes(
+            &nft_prizes,
+            winners_count,
+            &config_account.usdc_mint_account,
+            arbitrary_mint_account_info.key
+        )?;
+
+        Lottery::validate_pool_creator_share(&pool_creator_share)?;
+
+        Lottery::validate_maximum_total_tickets(
+            &maximum_total_tickets,
+            minimum_tickets_amount_required_to_be_sold
+        )?;
+
+        Lottery::validate_settlement_bounty(&settlement_bounty)?;
+
+        // handle token programs & mint extensions
+        check_accounts_key_to_be_identical(
+            usdc_mint_account_info.key,
+            &config_account.usdc_mint_account,
+            LotteryError::InvalidUsdcMintAccount.into()
+        )?;
+
+        let usdc_token_program_account_info = get_token_program_account_info_of_mint(
+            usdc_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+        let arbitrary_token_program_account_info = get_token_program_account_info_of_mint(
+            arbitrary_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+
+        check_mint_extensions(usdc_mint_account_info)?;
+        check_mint_extensions(arbitrary_mint_account_info)?;
+
+        //  token-2022 transfer fees -> the lottery records the amounts it actually receives
+        let received_lottery_creation_fee = config_account.lottery_creation_fee
+            .checked_sub(get_transfer_fee(usdc_mint_account_info, config_account.lottery_creation_fee)?)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        let received_fund_amount = fund_amount
+            .checked_sub(get_transfer_fee(arbitrary_mint_account_info, fund_amount)?)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        let received_settlement_bounty = match settlement_bounty {
+            Some(SettlementBounty::Usdc(usdc_bounty)) => Some(
+                SettlementBounty::Usdc(
+                    usdc_bounty
+                        .checked_sub(get_transfer_fee(usdc_mint_account_info, usdc_bounty)?)
+                        .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+                )
+            ),
+            _ => settlement_bounty
+        };
+
+        if prize_table.is_none() == true && received_fund_amount % (winners_count as u64) != 0 {
             return Err(
                 LotteryError::WinnersAndFundAmountMismatch.into()
             );
         };
 
+        let (
+            prize_vault_pda_addr,
+            prize_vault_pda_canonical_bump
+        ) = get_prize_vault_account_pda(
+            lottery_account_info.key,
+            program_id
+        ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?;
+
         let mut lottery_account = Lottery::new(
             lottery_pda_canonical_bump,
-            fund_amount,
-            config_account.lottery_creation_fee,
+            received_fund_amount,
+            received_lottery_creation_fee,
             winners_count,
             starting_time,
             ending_time,
@@ -347,6 +472,15 @@ impl Processor {
             *arbitrary_mint_account_info.key,
             *lottery_account_authority_account_info.key,
             maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes.clone(),
+            sol_prize_amount,
+            prize_vault_pda_canonical_bump,
+            pool_creator_share,
+            maximum_total_tickets,
+            received_settlement_bounty,
             lottery_description.clone()
         );
 
@@ -355,7 +489,7 @@ impl Processor {
             .unwrap()
             .len();
 
-        let space_needed_per_winner = size_of::<Pubkey>() + size_of::<bool>();
+        let space_needed_per_winner = size_of::<Pubkey>() + size_of::<Rank>() + size_of::<bool>();
         let total_space_needed_for_winners = winners_count as usize * space_needed_per_winner;
 
         let total_data_size = data_size + total_space_needed_for_winners;
@@ -385,19 +519,30 @@ impl Processor {
         )?;
         sol_log("Lottery account initialized.");
 
+        // a lamports bounty is held by the lottery account itself
+        if let Some(SettlementBounty::Lamports(lamports_bounty)) = settlement_bounty {
+            invoke(
+                &transfer_lamports(
+                    funding_account_info.key,
+                    lottery_account_info.key,
+                    lamports_bounty
+                ),
+                &[
+                    funding_account_info.clone(),
+                    lottery_account_info.clone()
+                ]
+            )?;
+            sol_log("Settlement bounty transfered.");
+        };
+
         // handle usdc ata
         //  create lottery's associated usdc token account
-        check_accounts_key_to_be_identical(
-            usdc_mint_account_info.key,
-            &config_account.usdc_mint_account,
-            LotteryError::InvalidUsdcMintAccount.into()
-        )?;
-
         //  validate lottery's arbitrary asscoiated token account
         check_accounts_key_to_be_identical(
-            &get_associated_token_address(
+            &get_associated_token_address_with_program_id(
                 lottery_account_info.key,
-                usdc_mint_account_info.key
+                usdc_mint_account_info.key,
+                usdc_token_program_account_info.key
             ),
             lottery_associated_usdc_token_account_info.key,
             LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
@@ -408,7 +553,7 @@ impl Processor {
                 funding_account_info.key,
                 lottery_account_info.key,
                 usdc_mint_account_info.key,
-                standard_token_program_account_info.key
+                usdc_token_program_account_info.key
             ),
             &[
                 funding_account_info.clone(),
@@ -416,19 +561,17 @@ impl Processor {
                 lottery_account_info.clone(),
                 usdc_mint_account_info.clone(),
                 system_program_account_info.clone(),
-                standard_token_program_account_info.clone()
+                usdc_token_program_account_info.clone()
             ]
         )?;
         sol_log("Lottery's usdc token account activated.");
 
         //  transfer 'lottery-creation-fee' to the newly created usdc-associated-token-account
-        let MintAccount { decimals, .. } = MintAccount::unpack(
-            &usdc_mint_account_info.data.try_borrow().unwrap()
-        )?;
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
 
         invoke(
             &transfer_spl_checked(
-                standard_token_program_account_info.key,
+                usdc_token_program_account_info.key,
                 funding_usdc_token_account_info.key,
                 usdc_mint_account_info.key,
                 lottery_associated_usdc_token_account_info.key,
@@ -446,12 +589,36 @@ impl Processor {
         )?;
         sol_log("Lottery's creation-fee transfered.");
 
+        //  a usdc bounty is held by the lottery's usdc token account, it's paid before any withdrawal
+        if let Some(SettlementBounty::Usdc(usdc_bounty)) = settlement_bounty {
+            invoke(
+                &transfer_spl_checked(
+                    usdc_token_program_account_info.key,
+                    funding_usdc_token_account_info.key,
+                    usdc_mint_account_info.key,
+                    lottery_associated_usdc_token_account_info.key,
+                    funding_account_info.key,
+                    &[],
+                    usdc_bounty,
+                    decimals
+                )?,
+                &[
+                    funding_usdc_token_account_info.clone(),
+                    usdc_mint_account_info.clone(),
+                    lottery_associated_usdc_token_account_info.clone(),
+                    funding_account_info.clone()
+                ]
+            )?;
+            sol_log("Settlement bounty transfered.");
+        };
+
         // handle arbitrary ata
         //  validate lottery's arbitrary asscoiated token account
         check_accounts_key_to_be_identical(
-            &get_associated_token_address(
+            &get_associated_token_address_with_program_id(
                 lottery_account_info.key,
-                arbitrary_mint_account_info.key
+                arbitrary_mint_account_info.key,
+                arbitrary_token_program_account_info.key
             ),
             lottery_associated_arbitrary_token_account_info.key,
             LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount.into()
@@ -463,7 +630,7 @@ impl Processor {
                 funding_account_info.key,
                 lottery_account_info.key,
                 arbitrary_mint_account_info.key,
-                standard_token_program_account_info.key
+                arbitrary_token_program_account_info.key
             ),
             &[
                 funding_account_info.clone(),
@@ -471,19 +638,17 @@ impl Processor {
                 lottery_account_info.clone(),
                 arbitrary_mint_account_info.clone(),
                 system_program_account_info.clone(),
-                standard_token_program_account_info.clone()
+                arbitrary_token_program_account_info.clone()
             ]
         )?;
         sol_log("Lottery's arbitrary token account activated.");
 
         //  transfer 'lottery-creation-fee' to the newly created usdc-associated-token-account
-        let MintAccount { decimals, .. } = MintAccount::unpack(
-            &arbitrary_mint_account_info.data.try_borrow().unwrap()
-        )?;
+        let MintAccount { decimals, .. } = unpack_mint_account(arbitrary_mint_account_info)?;
 
         invoke(
             &transfer_spl_checked(
-                standard_token_program_account_info.key,
+                arbitrary_token_program_account_info.key,
                 funding_arbitrary_token_account_info.key,
                 arbitrary_mint_account_info.key,
                 lottery_associated_arbitrary_token_account_info.key,
@@ -501,6 +666,143 @@ impl Processor {
         )?;
         sol_log("Funds transfered.");
 
+        // escrow nft prizes -> (nft prize mint account, lottery's associated nft token account, funding nft token account) per rank
+        let nft_prizes = nft_prizes.unwrap_or_default();
+        let remaining_accounts_info = accounts_info.as_slice();
+        if remaining_accounts_info.len() < nft_prizes.len() * 3 {
+            return Err(
+                LotteryError::InvalidAmountOfNftPrizeAccounts.into()
+            );
+        };
+        let (
+            nft_prizes_accounts_info,
+            prize_vault_accounts_info
+        ) = remaining_accounts_info.split_at(nft_prizes.len() * 3);
+
+        for (
+            nft_prize_mint,
+            nft_prize_accounts_info
+        ) in nft_prizes.iter().zip(nft_prizes_accounts_info.chunks(3)) {
+            let nft_prize_mint_account_info = &nft_prize_accounts_info[0];
+            let lottery_associated_nft_token_account_info = &nft_prize_accounts_info[1];
+            let funding_nft_token_account_info = &nft_prize_accounts_info[2];
+
+            check_accounts_key_to_be_identical(
+                nft_prize_mint_account_info.key,
+                nft_prize_mint,
+                LotteryError::InvalidNftPrizeMintAccount.into()
+            )?;
+
+            let nft_token_program_account_info = get_token_program_account_info_of_mint(
+                nft_prize_mint_account_info,
+                standard_token_program_account_info,
+                token_2022_program_account_info
+            )?;
+
+            let MintAccount { decimals, supply, .. } = unpack_mint_account(nft_prize_mint_account_info)?;
+            Lottery::validate_nft_prize_mint(decimals, supply)?;
+
+            //  a transfer fee would eat the single token of the nft
+            check_mint_extensions(nft_prize_mint_account_info)?;
+            if get_transfer_fee(nft_prize_mint_account_info, 1)? != 0 {
+                return Err(
+                    LotteryError::UnsupportedMintExtension.into()
+                );
+            };
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_account_info.key,
+                    nft_prize_mint_account_info.key,
+                    nft_token_program_account_info.key
+                ),
+                lottery_associated_nft_token_account_info.key,
+                LotteryError::InvalidLotteryPrizeAssociatedTokenAccount.into()
+            )?;
+
+            invoke(
+                &create_associated_token_account_idempotent(
+                    funding_account_info.key,
+                    lottery_account_info.key,
+                    nft_prize_mint_account_info.key,
+                    nft_token_program_account_info.key
+                ),
+                &[
+                    funding_account_info.clone(),
+                    lottery_associated_nft_token_account_info.clone(),
+                    lottery_account_info.clone(),
+                    nft_prize_mint_account_info.clone(),
+                    system_program_account_info.clone(),
+                    nft_token_program_account_info.clone()
+                ]
+            )?;
+
+            invoke(
+                &transfer_spl_checked(
+                    nft_token_program_account_info.key,
+                    funding_nft_token_account_info.key,
+                    nft_prize_mint_account_info.key,
+                    lottery_associated_nft_token_account_info.key,
+                    funding_account_info.key,
+                    &[],
+                    1,
+                    decimals
+                )?,
+                &[
+                    funding_nft_token_account_info.clone(),
+                    nft_prize_mint_account_info.clone(),
+                    lottery_associated_nft_token_account_info.clone(),
+                    funding_account_info.clone()
+                ]
+            )?;
+        };
+        if nft_prizes.is_empty() == false {
+            sol_log("Nft prizes transfered.");
+        };
+
+        // escrow the sol prize -> (prize-vault pda), its rent-exempt lamports are paid on top of the prize
+        if prize_vault_accounts_info.len() != if sol_prize_amount > 0 { 1 } else { 0 } {
+            return Err(
+                LotteryError::InvalidAmountOfPrizeVaultAccounts.into()
+            );
+        };
+
+        if sol_prize_amount > 0 {
+            let prize_vault_account_info = &prize_vault_accounts_info[0];
+
+            check_accounts_key_to_be_identical(
+                &prize_vault_pda_addr,
+                prize_vault_account_info.key,
+                LotteryError::InvalidPrizeVaultAccount.into()
+            )?;
+
+            create_pda_account(
+                prize_vault_account_info,
+                funding_account_info,
+                0,
+                program_id,
+                &[
+                    PRIZE_VAULT_ACCOUNT_SEED.as_bytes(),
+                    &lottery_account_info.key.to_bytes(),
+                    &[ prize_vault_pda_canonical_bump ]
+                ]
+            )?;
+            sol_log("Prize-vault account created.");
+
+            invoke(
+                &transfer_lamports(
+                    funding_account_info.key,
+                    prize_vault_account_info.key,
+                    sol_prize_amount
+                ),
+                &[
+                    funding_account_info.clone(),
+                    prize_vault_account_info.clone()
+                ]
+            )?;
+            sol_log("Sol prize transfered.");
+        };
+
         Ok(())
     }
 
@@ -654,6 +956,11 @@ impl Processor {
             &config_account.usdc_mint_account,
             LotteryError::InvalidUsdcMintAccount.into()
         )?;
+
+        check_token_program_account_of_mint(
+            standard_token_program_account_info,
+            usdc_mint_account_info
+        )?;
         
         // handle lottery account
         Lottery::validate_lottery_account(lottery_account_info, program_id)?;
@@ -682,9 +989,10 @@ impl Processor {
         };
 
         check_accounts_key_to_be_identical(
-            &get_associated_token_address(
+            &get_associated_token_address_with_program_id(
                 lottery_account_info.key,
-                usdc_mint_account_info.key
+                usdc_mint_account_info.key,
+                standard_token_program_account_info.key
             ),
             lottery_usdc_associated_token_account_info.key,
             LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
@@ -704,6 +1012,157 @@ impl Processor {
             tickets_amount
         )?; 
 
+        // validate lottery's total tickets amount
+        lottery_account.validate_total_tickets_amount(tickets_amount)?;
+
+        // ticket tokens -> (ticket-mint pda, associated ticket token account of the user-account's authority, standard token program, associated token program)
+        let ticket_token_accounts_info = accounts_info.as_slice();
+        if ticket_token_accounts_info.len() != if lottery_account.is_tickets_tokenized == true { 4 } else { 0 } {
+            return Err(
+                LotteryError::InvalidAmountOfTicketTokenAccounts.into()
+            );
+        };
+
+        //  handle transfering fee & updating lottery accounts
+        let total_tickets_price = calculate_fee_and_update_lottery_account(
+            &config_account,
+            &lottery_account,
+            lottery_account_info,
+            tickets_amount
+        )?;
+
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+        //  token-2022 transfer fees are paid by the buyer, the lottery receives the full tickets price
+        let total_tickets_price_including_transfer_fee = get_amount_including_transfer_fee(
+            usdc_mint_account_info,
+            total_tickets_price
+        )?;
+
+        invoke(
+            &transfer_spl_checked(
+                standard_token_program_account_info.key,
+                funding_usdc_token_account_info.key,
+                usdc_mint_account_info.key,
+                lottery_usdc_associated_token_account_info.key,
+                funding_account_info.key,
+                &[],
+                total_tickets_price_including_transfer_fee,
+                decimals
+            )?,
+            &[
+                funding_usdc_token_account_info.clone(),
+                usdc_mint_account_info.clone(),
+                lottery_usdc_associated_token_account_info.clone(),
+                funding_account_info.clone()
+            ]
+        )?;
+        sol_log("Tickets total price in USDC transfered to the lottery.");
+
+        // tokenized tickets -> ticket tokens are minted to the buyer, "RedeemTicketTokens" adds them to the ticket ledger
+        if lottery_account.is_tickets_tokenized == true {
+            let ticket_mint_account_info = &ticket_token_accounts_info[0];
+            let buyer_ticket_token_account_info = &ticket_token_accounts_info[1];
+            let ticket_token_program_account_info = &ticket_token_accounts_info[2];
+            let associated_token_program_account_info = &ticket_token_accounts_info[3];
+
+            check_accounts_key_to_be_identical(
+                &get_ticket_mint_account_pda(
+                    lottery_account_info.key,
+                    program_id
+                ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?.0,
+                ticket_mint_account_info.key,
+                LotteryError::InvalidTicketMintAccount.into()
+            )?;
+
+            check_accounts_key_to_be_identical(
+                ticket_token_program_account_info.key,
+                &TOKEN_STANDARD_PROGRAM_ID,
+                LotteryError::InvalidTokenProgramAccount.into()
+            )?;
+
+            if associated_token_program_account_info.key != &ASSOCIATED_TOKEN_PROGRAM_ID {
+                return Err(
+                    ProgramError::IncorrectProgramId
+                );
+            };
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    user_account_authority_account_info.key,
+                    ticket_mint_account_info.key,
+                    ticket_token_program_account_info.key
+                ),
+                buyer_ticket_token_account_info.key,
+                LotteryError::InvalidTicketTokenAccount.into()
+            )?;
+
+            invoke(
+                &create_associated_token_account_idempotent(
+                    funding_account_info.key,
+                    user_account_authority_account_info.key,
+                    ticket_mint_account_info.key,
+                    ticket_token_program_account_info.key
+                ),
+                &[
+                    funding_account_info.clone(),
+                    buyer_ticket_token_account_info.clone(),
+                    user_account_authority_account_info.clone(),
+                    ticket_mint_account_info.clone(),
+                    system_program_account_info.clone(),
+                    ticket_token_program_account_info.clone()
+                ]
+            )?;
+
+            invoke_signed(
+                &mint_to_checked(
+                    ticket_token_program_account_info.key,
+                    ticket_mint_account_info.key,
+                    buyer_ticket_token_account_info.key,
+                    ticket_mint_account_info.key,
+                    &[],
+                    tickets_amount as u64,
+                    0
+                )?,
+                &[
+                    ticket_mint_account_info.clone(),
+                    buyer_ticket_token_account_info.clone()
+                ],
+                &[
+                    &[
+                        TICKET_MINT_ACCOUNT_SEED.as_bytes(),
+                        &lottery_account_info.key.to_bytes(),
+                        &[ lottery_account.ticket_mint_canonical_bump ]
+                    ]
+                ]
+            )?;
+            sol_log("Ticket tokens minted.");
+
+            // the protocol fee is already updated by "calculate_fee_and_update_lottery_account"
+            let mut lottery_account = Lottery::deserialize(
+                &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+            )?;
+
+            lottery_account.ticket_tokens_outstanding = lottery_account.ticket_tokens_outstanding
+                .checked_add(tickets_amount)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            lottery_account.ticket_tokens_value = lottery_account.ticket_tokens_value
+                .checked_add(total_tickets_price)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            lottery_account.serialize(
+                &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+            )?;
+
+            solana_program::msg!(
+                "Total-Ticket-Tokens => {} - Total-Tokens-Transfered => {} USDC",
+                tickets_amount,
+                spl_token::amount_to_ui_amount(total_tickets_price, decimals)
+            );
+
+            return Ok(());
+        };
+
         let old_total_tickets_acquired = user_account.total_tickets_acquired;
         let new_total_tickets_acquired = old_total_tickets_acquired
             .checked_add(tickets_amount)
@@ -720,18 +1179,6 @@ impl Processor {
             size_of::<u32>()
         );
 
-        //  handle transfering fee & updating lottery accounts
-        let total_tickets_price = calculate_fee_and_update_lottery_account(
-            &config_account,
-            &lottery_account,
-            lottery_account_info,
-            tickets_amount
-        )?;
-
-        let MintAccount { decimals, .. } = MintAccount::unpack(
-            &usdc_mint_account_info.data.try_borrow().unwrap()
-        )?;
-
         let old_total_tickets_value = user_account.total_tickets_value;
         let new_total_tickets_value = old_total_tickets_value.checked_add(
             total_tickets_price
@@ -748,28 +1195,25 @@ impl Processor {
             size_of::<u64>()
         );
 
-        invoke(
-            &transfer_spl_checked(
-                standard_token_program_account_info.key,
-                funding_usdc_token_account_info.key,
-                usdc_mint_account_info.key,
-                lottery_usdc_associated_token_account_info.key,
-                funding_account_info.key,
-                &[],
-                total_tickets_price,
-                decimals
-            )?,
-            &[
-                funding_usdc_token_account_info.clone(),
-                usdc_mint_account_info.clone(),
-                lottery_usdc_associated_token_account_info.clone(),
-                funding_account_info.clone()
-            ]
-        )?;
-        sol_log("Tickets total price in USDC transfered to the lottery.");
+        let old_total_ticket_ranges_acquired = user_account.total_ticket_ranges_acquired;
+        let new_total_ticket_ranges_acquired = old_total_ticket_ranges_acquired
+            .checked_add(1)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        // update user_accounts's total_ticket_ranges_acquired field
+        sol_memcpy(
+            user_account_info
+                .data
+                .try_borrow_mut()
+                .unwrap()
+                .get_mut(93..97)
+                .unwrap(),
+            new_total_ticket_ranges_acquired.to_le_bytes().as_slice(),
+            size_of::<u32>()
+        );
 
+        // a single ticket-range is added per purchase, whatever the tickets amount is
         let rent_sysvar_account = Rent::get()?;
-        let space_needed = (tickets_amount as usize).checked_mul(pubkey::PUBKEY_BYTES).unwrap();
+        let space_needed = TICKET_RANGE_LENGTH;
         let rent_exempt = (
             rent_sysvar_account.lamports_per_byte_year
                 .checked_mul(space_needed as u64)
@@ -817,6 +1261,7 @@ impl Processor {
             lottery_account_info,
             lottery_account.initial_bytes,
             lottery_account.tickets_total_amount,
+            lottery_account.ticket_ranges_total_amount,
             tickets_amount,
             *user_account_info.key
         );
@@ -830,7 +1275,278 @@ impl Processor {
         Ok(())
     }
 
-    pub fn process_change_lottery_ticket_price(
+    pub fn process_buy_ticket_with_oracle_conversion(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        tickets_amount: u32,
+        expected_token_price_per_ticket: u64,
+        maximum_lamports_to_spend: u64
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let user_account_info = next_account_info(accounts_info)?;
+        let user_account_authority_account_info = next_account_info(accounts_info)?;
+        let funding_account_info = next_account_info(accounts_info)?;
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let sol_payments_vault_account_info = next_account_info(accounts_info)?;
+        let usdc_mint_account_info = next_account_info(accounts_info)?;
+        let sol_price_feed_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(user_account_authority_account_info)?;
+
+        Lottery::check_max_tickets_per_instruction(tickets_amount)?;
+
+        // validate user account pda
+        User::validate_user_account(
+            user_account_info,
+            program_id,
+            lottery_account_info.key,
+            user_account_authority_account_info.key
+        )?;
+
+        let mut user_account = User::deserialize(
+            &mut &user_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        if tickets_amount == 0 {
+            return Err(
+                LotteryError::InvalidTicketAmount.into()
+            );
+        };
+
+        let clock = Clock::get()?;
+        let current_time = clock.unix_timestamp;
+
+        // handle config account
+        Config::validate_config_account(config_global_account_info, program_id)?;
+
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // check is_pause flag
+        config_account.check_is_pause()?;
+
+        check_accounts_key_to_be_identical(
+            usdc_mint_account_info.key,
+            &config_account.usdc_mint_account,
+            LotteryError::InvalidUsdcMintAccount.into()
+        )?;
+
+        config_account.validate_sol_price_feed_account(sol_price_feed_account_info)?;
+
+        // handle lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // protecting the user against front-running
+        if expected_token_price_per_ticket != lottery_account.ticket_price {
+            return Err(
+                LotteryError::ExpectedTicketPriceViolated.into()
+            );
+        };
+
+        if lottery_account.is_started_and_not_ended(current_time) == false {
+            return Err(
+                LotteryError::InvalidLotteryState.into()
+            );
+        };
+
+        // the prizes of pool lotteries & the jackpots of series rounds come from the usdc ticket sales,
+        // ticket tokens of tokenized lotteries are only minted by "BuyTicket"
+        if
+            lottery_account.pool_creator_share.is_some() == true ||
+            lottery_account.series_jackpot.is_some() == true ||
+            lottery_account.is_tickets_tokenized == true
+        {
+            return Err(
+                LotteryError::InvalidLotteryType.into()
+            );
+        };
+
+        if user_account.authority == lottery_account.authority {
+            return Err(
+                LotteryError::InvalidUser.into()
+            );
+        };
+
+        // validate user's holding tickets amount
+        user_account.validate_user_holding_tickets_amount(
+            &lottery_account.maximum_number_of_tickets_per_user,
+            tickets_amount
+        )?;
+
+        // validate lottery's total tickets amount
+        lottery_account.validate_total_tickets_amount(tickets_amount)?;
+
+        // convert the tickets price (USDC) to lamports
+        let total_tickets_price = (tickets_amount as u64)
+            .checked_mul(lottery_account.ticket_price)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        let sol_price = get_price(
+            sol_price_feed_account_info,
+            VerificationLevel::Full,
+            config_account.maximum_time_of_price_feed_age,
+            &config_account.get_sol_price_feed_id(),
+            &clock
+        )?;
+
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+        let total_tickets_lamports = convert_usdc_to_lamports(
+            total_tickets_price,
+            decimals,
+            &sol_price
+        )?;
+
+        // protecting the user against price moves
+        if total_tickets_lamports > maximum_lamports_to_spend {
+            return Err(
+                LotteryError::MaximumSpendExceeded.into()
+            );
+        };
+
+        let protocol_fee = calculate_protocol_fee(
+            &config_account,
+            total_tickets_lamports
+        )?;
+
+        // handle sol-payments-vault, created by the first purchase in SOL
+        let (
+            sol_payments_vault_pda_addr,
+            sol_payments_vault_pda_canonical_bump
+        ) = get_sol_payments_vault_account_pda(
+            lottery_account_info.key,
+            program_id
+        ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?;
+
+        check_accounts_key_to_be_identical(
+            &sol_payments_vault_pda_addr,
+            sol_payments_vault_account_info.key,
+            LotteryError::InvalidSolPaymentsVaultAccount.into()
+        )?;
+
+        if sol_payments_vault_account_info.owner != program_id {
+            create_pda_account(
+                sol_payments_vault_account_info,
+                funding_account_info,
+                0,
+                program_id,
+                &[
+                    SOL_PAYMENTS_VAULT_ACCOUNT_SEED.as_bytes(),
+                    &lottery_account_info.key.to_bytes(),
+                    &[ sol_payments_vault_pda_canonical_bump ]
+                ]
+            )?;
+            sol_log("Sol-payments-vault account created.");
+        };
+
+        invoke(
+            &transfer_lamports(
+                funding_account_info.key,
+                sol_payments_vault_account_info.key,
+                total_tickets_lamports
+            ),
+            &[
+                funding_account_info.clone(),
+                sol_payments_vault_account_info.clone()
+            ]
+        )?;
+        sol_log("Tickets total price in SOL transfered to the sol-payments-vault.");
+
+        // update the lottery account
+        lottery_account.sol_payments_amount = lottery_account.sol_payments_amount
+            .checked_add(total_tickets_lamports)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        lottery_account.sol_protocol_fee = lottery_account.sol_protocol_fee
+            .checked_add(protocol_fee)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        // a single ticket-range is added per purchase, whatever the tickets amount is
+        let rent_sysvar_account = Rent::get()?;
+        let space_needed = TICKET_RANGE_LENGTH;
+        let rent_exempt = (
+            rent_sysvar_account.lamports_per_byte_year
+                .checked_mul(space_needed as u64)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+        ).checked_mul(rent_sysvar_account.exemption_threshold as u64).ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        // update the user account, the tickets value is recorded in USDC
+        user_account.total_tickets_acquired = user_account.total_tickets_acquired
+            .checked_add(tickets_amount)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_tickets_value = user_account.total_tickets_value
+            .checked_add(total_tickets_price)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_converted_tickets_value = user_account.total_converted_tickets_value
+            .checked_add(total_tickets_price)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_tickets_lamports = user_account.total_tickets_lamports
+            .checked_add(total_tickets_lamports)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_ticket_ranges_acquired = user_account.total_ticket_ranges_acquired
+            .checked_add(1)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_rent_exempt_paied = user_account.total_rent_exempt_paied
+            .checked_add(rent_exempt)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        user_account.serialize(
+            &mut &mut user_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        invoke(
+            &transfer_lamports(
+                funding_account_info.key,
+                lottery_account_info.key,
+                rent_exempt
+            ),
+            &[
+                funding_account_info.clone(),
+                lottery_account_info.clone()
+            ]
+        )?;
+        sol_log("Rent-exempt lamports transfered to the lottery account.");
+
+        let lottery_old_data_size = lottery_account_info.data_len();
+        let lottery_new_data_size = lottery_old_data_size.checked_add(space_needed).unwrap();
+
+        lottery_account_info
+            .realloc(lottery_new_data_size, false)
+            .map_err::<ProgramError, _>(|_| LotteryError::ReallocationFailed.into())?;
+
+        // add tickets to the lottery account
+        Lottery::add_ticket(
+            lottery_account_info,
+            lottery_account.initial_bytes,
+            lottery_account.tickets_total_amount,
+            lottery_account.ticket_ranges_total_amount,
+            tickets_amount,
+            *user_account_info.key
+        );
+
+        solana_program::msg!(
+            "Total-Tikcets => {} - Total-Lamports-Transfered => {} SOL",
+            tickets_amount,
+            solana_program::native_token::lamports_to_sol(total_tickets_lamports)
+        );
+
+        Ok(())
+    }
+
+    pub fn process_change_lottery_ticket_price(
         accounts_info: &[AccountInfo],
         program_id: &Pubkey,
         new_ticket_price: u64
@@ -899,23 +1615,25 @@ impl Processor {
         Ok(())
     }
 
-    pub fn process_end_lottery_and_pick_winners(
+    pub fn process_update_lottery_schedule(
         program_id: &Pubkey,
-        accounts_info: &[AccountInfo]
+        accounts_info: &[AccountInfo],
+        new_starting_time: Time,
+        new_ending_time: Time
     ) -> ProgramResult {
         let accounts_info = &mut accounts_info.iter();
 
         let lottery_account_info = next_account_info(accounts_info)?;
+        let lottery_account_authority_account_info = next_account_info(accounts_info)?;
         let config_global_account_info = next_account_info(accounts_info)?;
-        let sol_price_feed_account_info = next_account_info(accounts_info)?;
-        let btc_price_feed_account_info = next_account_info(accounts_info)?;
-        let eth_price_feed_account_info = next_account_info(accounts_info)?;
-        let sysvar_instruction_account_info = next_account_info(accounts_info)?;
 
-        let current_time = (Clock::get()?).unix_timestamp;
+        check_account_is_signer(lottery_account_authority_account_info)?;
 
         // validate config account
-        Config::validate_config_account(config_global_account_info, program_id)?;
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
 
         let config_account = Config::deserialize(
             &mut &config_global_account_info.data.try_borrow().unwrap()[..]
@@ -924,14 +1642,7 @@ impl Processor {
         // check is_pause flag
         config_account.check_is_pause()?;
 
-        // validate price feed accounts
-        config_account.validate_price_feed_accounts(
-            sol_price_feed_account_info,
-            btc_price_feed_account_info,
-            eth_price_feed_account_info
-        )?;
-
-        // validate lottery account
+        // validate lottery-account
         Lottery::validate_lottery_account(
             lottery_account_info,
             program_id
@@ -940,35 +1651,142 @@ impl Processor {
         let mut lottery_account = Lottery::deserialize(
             &mut &lottery_account_info.data.try_borrow().unwrap()[..]
         )?;
-        if lottery_account.is_ended_successfuly == true {
-            return Err(
-                LotteryError::LotteryAlreadyEnded.into()
-            );
-        };
 
-        if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
-            return Err(
-                LotteryError::LotteryWasNotSuccessfull.into()
-            );
-        };
+        check_accounts_key_to_be_identical(
+            &lottery_account.authority,
+            lottery_account_authority_account_info.key,
+            LotteryError::InvalidLotteryAccountAuthority.into()
+        )?;
 
-        // check that this instruction is a transaction-level instruction
-        if get_stack_height() != TRANSACTION_LEVEL_STACK_HEIGHT {
+        let current_time = (Clock::get()?).unix_timestamp;
+
+        if lottery_account.is_not_started(current_time) == true {
+            // before the start -> the whole schedule can be moved
+            if !(
+                new_starting_time > current_time &&
+                new_ending_time > new_starting_time
+            ) {
+                return Err(
+                    LotteryError::InvalidTime.into()
+                );
+            };
+        } else if lottery_account.is_started_and_not_ended(current_time) == true {
+            // after the start -> only undersubscribed lotteries can be extended, protecting the buyers
+            if lottery_account.tickets_total_amount >= lottery_account.minimum_tickets_amount_required_to_be_sold {
+                return Err(
+                    LotteryError::LotteryScheduleLocked.into()
+                );
+            };
+
+            if !(
+                new_starting_time == lottery_account.starting_time &&
+                new_ending_time > lottery_account.ending_time
+            ) {
+                return Err(
+                    LotteryError::InvalidTime.into()
+                );
+            };
+        } else {
             return Err(
-                LotteryError::MustBeTransactionLevelIx.into()
+                LotteryError::InvalidLotteryState.into()
             );
         };
 
-        // validate sysvar instruction account
-        if sysvar_instruction_account_info.key != &solana_program::sysvar::instructions::ID {
+        if new_ending_time
+            .checked_sub(new_starting_time)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())? > (config_account.maximum_time_for_lottery_account as i64)
+        {
             return Err(
-                LotteryError::InvalidSysvarInstructionAccount.into()
+                LotteryError::MaximumTimeExceed.into()
             );
         };
 
-        // check that this instruction is the last instruction in the transaction
-        const NEXT_INSTRUCTION_RELATIVE_INDEX: i64 = 1;
-        if let Ok(_) = get_instruction_relative(
+        solana_program::msg!(
+            "Lottery's schedule updated => starting_time: {} -> {}, ending_time: {} -> {}",
+            lottery_account.starting_time,
+            new_starting_time,
+            lottery_account.ending_time,
+            new_ending_time
+        );
+
+        // update the lottery account
+        lottery_account.starting_time = new_starting_time;
+        lottery_account.ending_time = new_ending_time;
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        Ok(())
+    }
+
+    pub fn process_end_lottery_and_pick_winners(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let sol_price_feed_account_info = next_account_info(accounts_info)?;
+        let btc_price_feed_account_info = next_account_info(accounts_info)?;
+        let eth_price_feed_account_info = next_account_info(accounts_info)?;
+        let sysvar_instruction_account_info = next_account_info(accounts_info)?;
+        let sysvar_slot_hashes_account_info = next_account_info(accounts_info)?;
+
+        let current_time = (Clock::get()?).unix_timestamp;
+
+        // validate config account
+        Config::validate_config_account(config_global_account_info, program_id)?;
+
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // check is_pause flag
+        config_account.check_is_pause()?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(
+            lottery_account_info,
+            program_id
+        )?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+        if lottery_account.is_ended_successfuly == true {
+            return Err(
+                LotteryError::LotteryAlreadyEnded.into()
+            );
+        };
+
+        if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
+            return Err(
+                LotteryError::LotteryWasNotSuccessfull.into()
+            );
+        };
+
+        // creator's secret must be revealed before picking the winners
+        lottery_account.check_secret_is_revealed(current_time)?;
+
+        // check that this instruction is a transaction-level instruction
+        if get_stack_height() != TRANSACTION_LEVEL_STACK_HEIGHT {
+            return Err(
+                LotteryError::MustBeTransactionLevelIx.into()
+            );
+        };
+
+        // validate sysvar instruction account
+        if sysvar_instruction_account_info.key != &solana_program::sysvar::instructions::ID {
+            return Err(
+                LotteryError::InvalidSysvarInstructionAccount.into()
+            );
+        };
+
+        // check that this instruction is the last instruction in the transaction
+        const NEXT_INSTRUCTION_RELATIVE_INDEX: i64 = 1;
+        if let Ok(_) = get_instruction_relative(
             NEXT_INSTRUCTION_RELATIVE_INDEX,
             sysvar_instruction_account_info
         ) {
@@ -977,44 +1795,111 @@ impl Processor {
             );
         };
 
-        // choose price feed account
-        let selected_price_feed_index = (current_time % 3) as usize;
-        let verification_level = VerificationLevel::Full;
-        let price_feed_max_age = config_account.maximum_time_of_price_feed_age;
-
-        let selected_price_feed_data = if selected_price_feed_index == 0 {
-            get_price(
-                sol_price_feed_account_info,
-                verification_level,
-                price_feed_max_age,
-                &config_account.get_sol_price_feed_id(),
-                &Clock::get()?
-            )?
-        } else if selected_price_feed_index == 1 {
-            get_price(
-                btc_price_feed_account_info,
-                verification_level,
-                price_feed_max_age,
-                &config_account.get_btc_price_feed_id(),
-                &Clock::get()?
-            )?
-        } else {
-            get_price(
-                eth_price_feed_account_info,
-                verification_level,
-                price_feed_max_age,
-                &config_account.get_eth_price_feed_id(),
-                &Clock::get()?
-            )?
+        // "VrfOracle" lotteries -> (randomness-request account), then the series & settlement bounty accounts
+        let remaining_accounts_info = accounts_info.as_slice();
+        let randomness_request_accounts_amount = if lottery_account.randomness_source == RandomnessSource::VrfOracle { 1 } else { 0 };
+        if remaining_accounts_info.len() < randomness_request_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfRandomnessRequestAccounts.into()
+            );
+        };
+        let (
+            randomness_request_accounts_info,
+            remaining_accounts_info
+        ) = remaining_accounts_info.split_at(randomness_request_accounts_amount);
+
+        // get the entropy of the lottery's randomness source
+        let (
+            randomness,
+            random_numbers_info
+        ) = match lottery_account.randomness_source {
+            RandomnessSource::PythPriceFeeds => {
+                // validate price feed accounts
+                config_account.validate_price_feed_accounts(
+                    sol_price_feed_account_info,
+                    btc_price_feed_account_info,
+                    eth_price_feed_account_info
+                )?;
+
+                let verification_level = VerificationLevel::Full;
+                let price_feed_max_age = config_account.maximum_time_of_price_feed_age;
+                let clock = Clock::get()?;
+
+                // every price feed is mixed, nobody can predict the seed from a single price
+                let mut price_feeds: [RandomNumberInfo; 3] = Default::default();
+                for (index, (price_feed_account_info, price_feed_id)) in [
+                    (sol_price_feed_account_info, config_account.get_sol_price_feed_id()),
+                    (btc_price_feed_account_info, config_account.get_btc_price_feed_id()),
+                    (eth_price_feed_account_info, config_account.get_eth_price_feed_id())
+                ].iter().enumerate() {
+                    let price_feed_data = get_price(
+                        price_feed_account_info,
+                        verification_level,
+                        price_feed_max_age,
+                        price_feed_id,
+                        &clock
+                    )?;
+
+                    price_feeds[index] = (
+                        *price_feed_account_info.key,
+                        price_feed_data.publish_time,
+                        price_feed_data.price,
+                        price_feed_data.conf
+                    );
+                };
+
+                let random_numbers_info = RandomNumbersInfo {
+                    price_feeds,
+                    slot_hash: get_most_recent_slot_hash(sysvar_slot_hashes_account_info)?
+                };
+
+                (
+                    random_numbers_info.get_price_feeds_randomness(lottery_account_info.key),
+                    random_numbers_info
+                )
+            },
+            RandomnessSource::VrfOracle => {
+                let randomness_request_account_info = &randomness_request_accounts_info[0];
+
+                // validate randomness-request account
+                RandomnessRequest::validate_randomness_request_account(
+                    randomness_request_account_info,
+                    program_id
+                )?;
+
+                let randomness_request = RandomnessRequest::deserialize(
+                    &mut &randomness_request_account_info.data.try_borrow().unwrap()[..]
+                )?;
+
+                check_accounts_key_to_be_identical(
+                    &randomness_request.lottery,
+                    lottery_account_info.key,
+                    LotteryError::InvalidRandomnessRequestAccount.into()
+                )?;
+
+                (
+                    randomness_request.get_randomness()?.to_vec(),
+                    RandomNumbersInfo {
+                        price_feeds: [
+                            (
+                                *randomness_request_account_info.key,
+                                randomness_request.fulfilled_at,
+                                i64::default(),
+                                u64::default()
+                            ),
+                            RandomNumberInfo::default(),
+                            RandomNumberInfo::default()
+                        ],
+                        slot_hash: SlotHashInfo::default()
+                    }
+                )
+            }
         };
 
-        // generate SHA256-hash of the selected price
-        let sha256_hash: [u8; 32] = sha256(
-            selected_price_feed_data
-                .price
-                .to_le_bytes()
-                .as_ref()
-        ).to_bytes();
+        // generate SHA256-hash of the randomness (mixed with the creator's secret if revealed)
+        let sha256_hash: [u8; 32] = lottery_account.get_winners_seed(
+            &randomness
+        );
 
         // randomly select winners
         lottery_account.pick_winners(
@@ -1022,19 +1907,75 @@ impl Processor {
             lottery_account_info
         )?;
 
+        // series rounds -> (lottery series account, series jackpot vault), then the settlement bounty accounts
+        let lottery_series_accounts_amount = if lottery_account.series_jackpot.is_some() { 2 } else { 0 };
+        if remaining_accounts_info.len() < lottery_series_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfLotterySeriesAccounts.into()
+            );
+        };
+        let (
+            lottery_series_accounts_info,
+            settlement_bounty_accounts_info
+        ) = remaining_accounts_info.split_at(lottery_series_accounts_amount);
+
+        // series rounds -> the jackpot is won by the winners unless a house slot is drawn
+        lottery_account.draw_series_jackpot(&sha256_hash)?;
+
+        if let Some(SeriesJackpot { series, .. }) = lottery_account.series_jackpot {
+            let lottery_series_account_info = &lottery_series_accounts_info[0];
+            let series_jackpot_vault_account_info = &lottery_series_accounts_info[1];
+
+            LotterySeries::validate_lottery_series_account(
+                lottery_series_account_info,
+                program_id
+            )?;
+
+            check_accounts_key_to_be_identical(
+                &series,
+                lottery_series_account_info.key,
+                LotteryError::InvalidLotterySeriesAccount.into()
+            )?;
+
+            check_token_program_id(series_jackpot_vault_account_info.owner)?;
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_series_account_info.key,
+                    &config_account.usdc_mint_account,
+                    series_jackpot_vault_account_info.owner
+                ),
+                series_jackpot_vault_account_info.key,
+                LotteryError::InvalidSeriesJackpotVaultAccount.into()
+            )?;
+
+            let mut lottery_series_account = LotterySeries::deserialize(
+                &mut &lottery_series_account_info.data.try_borrow().unwrap()[..]
+            )?;
+
+            // a won jackpot is fixed now, later rounds & contributions can't change it
+            let TokenAccount { amount, .. } = unpack_token_account(series_jackpot_vault_account_info)?;
+            lottery_account.reserve_series_jackpot_amount(
+                &mut lottery_series_account,
+                amount
+            )?;
+
+            lottery_series_account.serialize(
+                &mut &mut lottery_series_account_info.data.try_borrow_mut().unwrap()[..]
+            )?;
+        };
+
         lottery_account.is_ended_successfuly = true;
 
-        lottery_account.random_numbers_info = (
-            if selected_price_feed_index == 0 {
-                *sol_price_feed_account_info.key
-            } else if selected_price_feed_index == 1 {
-                *btc_price_feed_account_info.key
-            } else {
-                *eth_price_feed_account_info.key
-            },
-            selected_price_feed_data.publish_time,
-            selected_price_feed_data.price
-        );
+        lottery_account.random_numbers_info = random_numbers_info;
+
+        // the caller settled the lottery -> settlement bounty
+        pay_settlement_bounty(
+            &mut lottery_account,
+            lottery_account_info,
+            &config_account.usdc_mint_account,
+            settlement_bounty_accounts_info
+        )?;
 
         lottery_account.serialize(
             &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
@@ -1045,6 +1986,104 @@ impl Processor {
         Ok(())
     }
 
+    pub fn process_reveal_lottery_secret(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        secret: [u8; HASH_BYTES]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let lottery_account_authority_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(lottery_account_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(
+            lottery_account_info,
+            program_id
+        )?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &lottery_account.authority,
+            lottery_account_authority_account_info.key,
+            LotteryError::InvalidLotteryAccountAuthority.into()
+        )?;
+
+        // secret can only be revealed after the ending time and before picking the winners
+        let current_time = (Clock::get()?).unix_timestamp;
+        if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
+            return Err(
+                LotteryError::LotteryWasNotSuccessfull.into()
+            );
+        };
+
+        if lottery_account.is_ended_successfuly == true {
+            return Err(
+                LotteryError::LotteryAlreadyEnded.into()
+            );
+        };
+
+        // after the deadline the winners may already be picked without the secret
+        if lottery_account.is_secret_reveal_timed_out(current_time) == true {
+            return Err(
+                LotteryError::SecretRevealTimedOut.into()
+            );
+        };
+
+        // the request seed includes the secret, it can't change once the randomness is requested
+        if lottery_account.randomness_source == RandomnessSource::VrfOracle {
+            let randomness_request_account_info = next_account_info(accounts_info)?;
+
+            let randomness_request_pda_addr = Pubkey::try_find_program_address(
+                &[
+                    RANDOMNESS_REQUEST_ACCOUNT_SEED.as_bytes(),
+                    lottery_account_info.key.to_bytes().as_slice()
+                ],
+                program_id
+            ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?.0;
+
+            check_accounts_key_to_be_identical(
+                &randomness_request_pda_addr,
+                randomness_request_account_info.key,
+                LotteryError::InvalidRandomnessRequestAccount.into()
+            )?;
+
+            if check_account_is_raw(randomness_request_account_info).is_err() {
+                return Err(
+                    LotteryError::RandomnessAlreadyRequested.into()
+                );
+            };
+        };
+
+        lottery_account.validate_secret(lottery_account_info.key, &secret)?;
+
+        lottery_account.revealed_secret = secret;
+        lottery_account.is_secret_revealed = true;
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        sol_log("Lottery's secret revealed.");
+
+        Ok(())
+    }
+
     pub fn process_withdraw_succeed_lottery(
         program_id: &Pubkey,
         accounts_info: &[AccountInfo]
@@ -1063,7 +2102,7 @@ impl Processor {
 
         let current_time = (Clock::get()?).unix_timestamp;
 
-        let lottery_account = Lottery::deserialize(
+        let mut lottery_account = Lottery::deserialize(
             &mut &lottery_account_info.data.try_borrow().unwrap()[..]
         )?;
 
@@ -1079,6 +2118,11 @@ impl Processor {
             usdc_mint_account_info.key
         )?;
 
+        check_token_program_account_of_mint(
+            standard_token_program_account_info,
+            usdc_mint_account_info
+        )?;
+
         // validate lottery account
         Lottery::validate_lottery_account(lottery_account_info, program_id)?;
         // validate lottery state
@@ -1108,30 +2152,114 @@ impl Processor {
 
         // validate lottery associated usdc token account 
         check_accounts_key_to_be_identical(
-            &get_associated_token_address(
+            &get_associated_token_address_with_program_id(
                 lottery_account_info.key,
-                usdc_mint_account_info.key
+                usdc_mint_account_info.key,
+                usdc_mint_account_info.owner
             ),
             lottery_associated_usdc_token_account_info.key,
             LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
         )?;
 
-        let MintAccount { decimals, .. } = MintAccount::unpack(
-            &usdc_mint_account_info.data.try_borrow().unwrap()
-        )?;
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
 
-        let TokenAccount { amount, .. } = TokenAccount::unpack(
-            &lottery_associated_usdc_token_account_info.data.try_borrow().unwrap()
-        )?; 
+        let TokenAccount { amount, .. } = unpack_token_account(lottery_associated_usdc_token_account_info)?; 
 
-        let total_protocol_fee_per_ticket = lottery_account.protocol_fee;
-        let lottery_creation_fee = lottery_account.lottery_creation_fee;
-        let total_protocol_fee = total_protocol_fee_per_ticket.checked_add(
-            lottery_creation_fee
-        ).ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        // pool lotteries -> only the creator's share of the pool, the rest stays for the winners
+        let usdc_to_withdraw = if lottery_account.pool_creator_share.is_some() == true {
+            let pool_amount = lottery_account.get_pool_amount(amount)?;
+
+            lottery_account.serialize(
+                &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+            )?;
+
+            lottery_account.get_pool_creator_amount(pool_amount)?
+        } else {
+            let total_protocol_fee_per_ticket = lottery_account.protocol_fee;
+            let lottery_creation_fee = lottery_account.lottery_creation_fee;
+            let total_protocol_fee = total_protocol_fee_per_ticket.checked_add(
+                lottery_creation_fee
+            ).ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            amount
+                .checked_sub(total_protocol_fee)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+        };
+
+        // series rounds -> (lottery series account, series jackpot vault), the series' share of the revenue goes to the jackpot
+        let remaining_accounts_info = accounts_info.as_slice();
+        let lottery_series_accounts_amount = if lottery_account.series_jackpot.is_some() { 2 } else { 0 };
+        if remaining_accounts_info.len() < lottery_series_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfLotterySeriesAccounts.into()
+            );
+        };
+        let (
+            lottery_series_accounts_info,
+            sol_payments_accounts_info
+        ) = remaining_accounts_info.split_at(lottery_series_accounts_amount);
+
+        let jackpot_contribution = match lottery_account.series_jackpot {
+            Some(SeriesJackpot { series, jackpot_share, .. }) => {
+                let lottery_series_account_info = &lottery_series_accounts_info[0];
+                let series_jackpot_vault_account_info = &lottery_series_accounts_info[1];
+
+                check_accounts_key_to_be_identical(
+                    &series,
+                    lottery_series_account_info.key,
+                    LotteryError::InvalidLotterySeriesAccount.into()
+                )?;
+
+                check_accounts_key_to_be_identical(
+                    &get_associated_token_address_with_program_id(
+                        lottery_series_account_info.key,
+                        usdc_mint_account_info.key,
+                        usdc_mint_account_info.owner
+                    ),
+                    series_jackpot_vault_account_info.key,
+                    LotteryError::InvalidSeriesJackpotVaultAccount.into()
+                )?;
+
+                let jackpot_contribution = LotterySeries::get_jackpot_contribution(
+                    jackpot_share,
+                    usdc_to_withdraw
+                );
 
-        let usdc_to_withdraw = amount
-            .checked_sub(total_protocol_fee)
+                invoke_signed(
+                    &transfer_spl_checked(
+                        standard_token_program_account_info.key,
+                        lottery_associated_usdc_token_account_info.key,
+                        usdc_mint_account_info.key,
+                        series_jackpot_vault_account_info.key,
+                        lottery_account_info.key,
+                        &[],
+                        jackpot_contribution,
+                        decimals
+                    )?,
+                    &[
+                        lottery_associated_usdc_token_account_info.clone(),
+                        usdc_mint_account_info.clone(),
+                        series_jackpot_vault_account_info.clone(),
+                        lottery_account_info.clone()
+                    ],
+                    &[
+                        &[
+                            LOTTERY_ACCOUNT_SEED.as_bytes(),
+                            &lottery_authority_account_info.key.to_bytes(),
+                            get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                            &[ lottery_account.canonical_bump ]
+                        ]
+                    ]
+                )?;
+                sol_log("Series jackpot contribution transfered.");
+
+                jackpot_contribution
+            },
+            None => 0
+        };
+
+        let usdc_to_withdraw = usdc_to_withdraw
+            .checked_sub(jackpot_contribution)
             .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
 
         invoke_signed(
@@ -1162,6 +2290,61 @@ impl Processor {
         )?;
         sol_log("Funds withdrawed successfully.");
 
+        // sol payments -> (sol-payments-vault pda, fund-receiver sol lamports account, sol treasury)
+        //  tickets paid in SOL go to the creator, their protocol fee to the config's sol treasury
+        if sol_payments_accounts_info.len() != if lottery_account.sol_payments_amount > 0 { 3 } else { 0 } {
+            return Err(
+                LotteryError::InvalidAmountOfSolPaymentsAccounts.into()
+            );
+        };
+
+        if lottery_account.sol_payments_amount > 0 {
+            let sol_payments_vault_account_info = &sol_payments_accounts_info[0];
+            let fund_receiver_sol_account_info = &sol_payments_accounts_info[1];
+            let sol_treasury_account_info = &sol_payments_accounts_info[2];
+
+            check_accounts_key_to_be_identical(
+                &get_sol_payments_vault_account_pda(
+                    lottery_account_info.key,
+                    program_id
+                ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?.0,
+                sol_payments_vault_account_info.key,
+                LotteryError::InvalidSolPaymentsVaultAccount.into()
+            )?;
+
+            let config_account = Config::deserialize(
+                &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+            )?;
+
+            check_accounts_key_to_be_identical(
+                sol_treasury_account_info.key,
+                &config_account.sol_treasury,
+                LotteryError::InvalidSolTreasuryAccount.into()
+            )?;
+
+            let sol_protocol_fee = lottery_account.sol_protocol_fee;
+            let lamports_to_withdraw = lottery_account.sol_payments_amount
+                .checked_sub(sol_protocol_fee)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            let sol_payments_vault_old_balance = sol_payments_vault_account_info.lamports();
+            **sol_payments_vault_account_info.try_borrow_mut_lamports()? = sol_payments_vault_old_balance
+                .checked_sub(lottery_account.sol_payments_amount)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            let fund_receiver_sol_account_old_balance = fund_receiver_sol_account_info.lamports();
+            **fund_receiver_sol_account_info.try_borrow_mut_lamports()? = fund_receiver_sol_account_old_balance
+                .checked_add(lamports_to_withdraw)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            let sol_treasury_old_balance = sol_treasury_account_info.lamports();
+            **sol_treasury_account_info.try_borrow_mut_lamports()? = sol_treasury_old_balance
+                .checked_add(sol_protocol_fee)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            sol_log("SOL payments withdrawed successfully.");
+        };
+
         // update the lottery account
         let mut lottery_account_data = lottery_account_info
             .data
@@ -1188,6 +2371,7 @@ impl Processor {
         let fund_receiver_arbitrary_token_account_info = next_account_info(accounts_info)?;
         let arbitrary_mint_account_info = next_account_info(accounts_info)?;
         let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let token_2022_program_account_info = next_account_info(accounts_info)?;
         let config_global_account_info = next_account_info(accounts_info)?;
 
         check_account_is_signer(user_account_authority_account_info)?;
@@ -1238,27 +2422,36 @@ impl Processor {
 
         // validate lottery associated arbitrary token account
         check_accounts_key_to_be_identical(
-            &get_associated_token_address(
+            &get_associated_token_address_with_program_id(
                 lottery_account_info.key,
-                arbitrary_mint_account_info.key
+                arbitrary_mint_account_info.key,
+                arbitrary_mint_account_info.owner
             ),
             lottery_associated_arbitrary_token_account_info.key,
             LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount.into()
         )?;
 
-        // validate user_account as winner 
-        let w_count = lottery_account.get_winner_info(user_account_info.key)?;
-        let arbitrary_token_per_winner = lottery_account.fund_amount / (lottery_account.winners_count as u64);
-        let tokens_amount_to_transfer = (w_count as u64) * arbitrary_token_per_winner;
+        // validate user_account as winner, each rank pays its own prize
+        let winning_ranks = lottery_account.get_winner_info(user_account_info.key)?;
+        let mut tokens_amount_to_transfer = 0u64;
+        for rank in winning_ranks.iter().copied() {
+            tokens_amount_to_transfer = tokens_amount_to_transfer
+                .checked_add(lottery_account.get_prize_amount(rank)?)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        };
 
-        let MintAccount { decimals, .. } = MintAccount::unpack(
-            &arbitrary_mint_account_info.data.try_borrow().unwrap()
+        let MintAccount { decimals, .. } = unpack_mint_account(arbitrary_mint_account_info)?;
+
+        let arbitrary_token_program_account_info = get_token_program_account_info_of_mint(
+            arbitrary_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
         )?;
 
         // transfer funds
         invoke_signed(
             &transfer_spl_checked(
-                standard_token_program_account_info.key,
+                arbitrary_token_program_account_info.key,
                 lottery_associated_arbitrary_token_account_info.key,
                 arbitrary_mint_account_info.key,
                 fund_receiver_arbitrary_token_account_info.key,
@@ -1284,6 +2477,351 @@ impl Processor {
         )?;
         sol_log("Congratulation! funds withdrawed successfully.");
 
+        // the prize token accounts emptied by the withdrawal are closed, their rent-exempt lamports go to the lottery account (refunded when it's closed)
+        let is_every_winner_withdrawed = lottery_account.winners.iter().all(|(_, _, is_withdrawed)| *is_withdrawed == true);
+        let mut closed_prize_token_accounts_amount = 0u16;
+
+        // prize mints -> (prize mint account, lottery's associated prize token account, fund-receiver prize token account) per prize mint
+        let remaining_accounts_info = accounts_info.as_slice();
+        if remaining_accounts_info.len() < lottery_account.prize_mints.len() * 3 {
+            return Err(
+                LotteryError::InvalidAmountOfPrizeMintAccounts.into()
+            );
+        };
+        let (
+            prize_mints_accounts_info,
+            nft_prizes_accounts_info
+        ) = remaining_accounts_info.split_at(lottery_account.prize_mints.len() * 3);
+
+        for (
+            (prize_mint, prize_amount),
+            prize_mint_accounts_info
+        ) in lottery_account.prize_mints.iter().zip(prize_mints_accounts_info.chunks(3)) {
+            let prize_mint_account_info = &prize_mint_accounts_info[0];
+            let lottery_associated_prize_token_account_info = &prize_mint_accounts_info[1];
+            let fund_receiver_prize_token_account_info = &prize_mint_accounts_info[2];
+
+            check_accounts_key_to_be_identical(
+                prize_mint_account_info.key,
+                prize_mint,
+                LotteryError::InvalidPrizeMintAccount.into()
+            )?;
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_account_info.key,
+                    prize_mint_account_info.key,
+                    prize_mint_account_info.owner
+                ),
+                lottery_associated_prize_token_account_info.key,
+                LotteryError::InvalidLotteryPrizeAssociatedTokenAccount.into()
+            )?;
+
+            let mut prize_tokens_amount_to_transfer = 0u64;
+            for rank in winning_ranks.iter().copied() {
+                prize_tokens_amount_to_transfer = prize_tokens_amount_to_transfer
+                    .checked_add(lottery_account.get_prize_share(*prize_amount, rank)?)
+                    .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            };
+
+            let MintAccount { decimals, .. } = unpack_mint_account(prize_mint_account_info)?;
+
+            let prize_token_program_account_info = get_token_program_account_info_of_mint(
+                prize_mint_account_info,
+                standard_token_program_account_info,
+                token_2022_program_account_info
+            )?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    prize_token_program_account_info.key,
+                    lottery_associated_prize_token_account_info.key,
+                    prize_mint_account_info.key,
+                    fund_receiver_prize_token_account_info.key,
+                    lottery_account_info.key,
+                    &[], 
+                    prize_tokens_amount_to_transfer,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_prize_token_account_info.clone(),
+                    prize_mint_account_info.clone(),
+                    fund_receiver_prize_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[
+                    &[
+                        LOTTERY_ACCOUNT_SEED.as_bytes(),
+                        &lottery_account.authority.to_bytes(),
+                        get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                        &[ lottery_account.canonical_bump ]
+                    ]
+                ]
+            )?;
+
+            // the last winner leaves no rounding dust -> closed here, otherwise by "CloseLotteryArbitraryTokenAccount"
+            if is_every_winner_withdrawed == true {
+                let TokenAccount { amount: prize_token_balance, .. } = unpack_token_account(lottery_associated_prize_token_account_info)?;
+
+                if prize_token_balance == 0 {
+                    invoke_signed(
+                        &close_token_account(
+                            prize_token_program_account_info.key,
+                            lottery_associated_prize_token_account_info.key,
+                            lottery_account_info.key,
+                            lottery_account_info.key,
+                            &[]
+                        )?,
+                        &[
+                            lottery_associated_prize_token_account_info.clone(),
+                            lottery_account_info.clone()
+                        ],
+                        &[
+                            &[
+                                LOTTERY_ACCOUNT_SEED.as_bytes(),
+                                &lottery_account.authority.to_bytes(),
+                                get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                                &[ lottery_account.canonical_bump ]
+                            ]
+                        ]
+                    )?;
+
+                    closed_prize_token_accounts_amount += 1;
+                };
+            };
+        };
+        if lottery_account.prize_mints.is_empty() == false {
+            sol_log("Prize mints withdrawed successfully.");
+        };
+
+        // nft prizes -> (nft prize mint account, lottery's associated nft token account, fund-receiver nft token account) per winning rank
+        let nft_prizes = lottery_account.nft_prizes.clone().unwrap_or_default();
+        let nft_prizes_accounts_amount = if nft_prizes.is_empty() == true { 0 } else { winning_ranks.len() * 3 };
+        if nft_prizes_accounts_info.len() < nft_prizes_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfNftPrizeAccounts.into()
+            );
+        };
+        let (
+            nft_prizes_accounts_info,
+            prize_vault_accounts_info
+        ) = nft_prizes_accounts_info.split_at(nft_prizes_accounts_amount);
+
+        for (
+            rank,
+            nft_prize_accounts_info
+        ) in winning_ranks.iter().copied().zip(nft_prizes_accounts_info.chunks(3)) {
+            let nft_prize_mint_account_info = &nft_prize_accounts_info[0];
+            let lottery_associated_nft_token_account_info = &nft_prize_accounts_info[1];
+            let fund_receiver_nft_token_account_info = &nft_prize_accounts_info[2];
+
+            check_accounts_key_to_be_identical(
+                nft_prize_mint_account_info.key,
+                nft_prizes
+                    .get(rank as usize)
+                    .ok_or::<ProgramError>(LotteryError::WinnerNotFound.into())?,
+                LotteryError::InvalidNftPrizeMintAccount.into()
+            )?;
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_account_info.key,
+                    nft_prize_mint_account_info.key,
+                    nft_prize_mint_account_info.owner
+                ),
+                lottery_associated_nft_token_account_info.key,
+                LotteryError::InvalidLotteryPrizeAssociatedTokenAccount.into()
+            )?;
+
+            let nft_token_program_account_info = get_token_program_account_info_of_mint(
+                nft_prize_mint_account_info,
+                standard_token_program_account_info,
+                token_2022_program_account_info
+            )?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    nft_token_program_account_info.key,
+                    lottery_associated_nft_token_account_info.key,
+                    nft_prize_mint_account_info.key,
+                    fund_receiver_nft_token_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    1,
+                    0
+                )?,
+                &[
+                    lottery_associated_nft_token_account_info.clone(),
+                    nft_prize_mint_account_info.clone(),
+                    fund_receiver_nft_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[
+                    &[
+                        LOTTERY_ACCOUNT_SEED.as_bytes(),
+                        &lottery_account.authority.to_bytes(),
+                        get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                        &[ lottery_account.canonical_bump ]
+                    ]
+                ]
+            )?;
+
+            invoke_signed(
+                &close_token_account(
+                    nft_token_program_account_info.key,
+                    lottery_associated_nft_token_account_info.key,
+                    lottery_account_info.key,
+                    lottery_account_info.key,
+                    &[]
+                )?,
+                &[
+                    lottery_associated_nft_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[
+                    &[
+                        LOTTERY_ACCOUNT_SEED.as_bytes(),
+                        &lottery_account.authority.to_bytes(),
+                        get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                        &[ lottery_account.canonical_bump ]
+                    ]
+                ]
+            )?;
+
+            closed_prize_token_accounts_amount += 1;
+        };
+        if nft_prizes.is_empty() == false {
+            sol_log("Nft prizes withdrawed successfully.");
+        };
+
+        if closed_prize_token_accounts_amount > 0 {
+            lottery_account.prize_token_accounts_amount = lottery_account.prize_token_accounts_amount
+                .checked_sub(closed_prize_token_accounts_amount)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            sol_log("Prize token accounts closed & rent exempt lamports moved to the lottery account.");
+        };
+
+        // sol prize -> (prize-vault pda, fund-receiver lamports account)
+        let prize_vault_accounts_amount = if lottery_account.sol_prize_amount > 0 { 2 } else { 0 };
+        if prize_vault_accounts_info.len() < prize_vault_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfPrizeVaultAccounts.into()
+            );
+        };
+        let (
+            prize_vault_accounts_info,
+            pool_accounts_info
+        ) = prize_vault_accounts_info.split_at(prize_vault_accounts_amount);
+
+        if lottery_account.sol_prize_amount > 0 {
+            let prize_vault_account_info = &prize_vault_accounts_info[0];
+            let fund_receiver_sol_prize_account_info = &prize_vault_accounts_info[1];
+
+            lottery_account.validate_prize_vault_account(
+                prize_vault_account_info,
+                lottery_account_info.key,
+                program_id
+            )?;
+
+            let mut lamports_to_transfer = 0u64;
+            for rank in winning_ranks.iter().copied() {
+                lamports_to_transfer = lamports_to_transfer
+                    .checked_add(lottery_account.get_prize_share(lottery_account.sol_prize_amount, rank)?)
+                    .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            };
+
+            let prize_vault_old_balance = prize_vault_account_info.lamports();
+            let fund_receiver_sol_prize_account_old_balance = fund_receiver_sol_prize_account_info.lamports();
+
+            **prize_vault_account_info.try_borrow_mut_lamports()? = prize_vault_old_balance
+                .checked_sub(lamports_to_transfer)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            **fund_receiver_sol_prize_account_info.try_borrow_mut_lamports()? = fund_receiver_sol_prize_account_old_balance
+                .checked_add(lamports_to_transfer)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            sol_log("Sol prize withdrawed successfully.");
+        };
+
+        // pool -> (usdc mint account, lottery's associated usdc token account, fund-receiver usdc token account)
+        if pool_accounts_info.len() != if lottery_account.pool_creator_share.is_some() { 3 } else { 0 } {
+            return Err(
+                LotteryError::InvalidAmountOfPoolAccounts.into()
+            );
+        };
+
+        if lottery_account.pool_creator_share.is_some() == true {
+            let usdc_mint_account_info = &pool_accounts_info[0];
+            let lottery_associated_usdc_token_account_info = &pool_accounts_info[1];
+            let fund_receiver_usdc_token_account_info = &pool_accounts_info[2];
+
+            // validate usdc mint account
+            compare_usdc_mint_account_with_config_global_account_info(
+                config_global_account_info,
+                usdc_mint_account_info.key
+            )?;
+
+            // validate lottery associated usdc token account
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_account_info.key,
+                    usdc_mint_account_info.key,
+                    usdc_mint_account_info.owner
+                ),
+                lottery_associated_usdc_token_account_info.key,
+                LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
+            )?;
+
+            let TokenAccount { amount, .. } = unpack_token_account(lottery_associated_usdc_token_account_info)?;
+
+            let pool_amount = lottery_account.get_pool_amount(amount)?;
+            let pool_prize_amount = lottery_account.get_pool_prize_amount(pool_amount)?;
+
+            let mut usdc_to_transfer = 0u64;
+            for rank in winning_ranks.iter().copied() {
+                usdc_to_transfer = usdc_to_transfer
+                    .checked_add(lottery_account.get_prize_share(pool_prize_amount, rank)?)
+                    .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            };
+
+            let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+            let usdc_token_program_account_info = get_token_program_account_info_of_mint(
+                usdc_mint_account_info,
+                standard_token_program_account_info,
+                token_2022_program_account_info
+            )?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    usdc_token_program_account_info.key,
+                    lottery_associated_usdc_token_account_info.key,
+                    usdc_mint_account_info.key,
+                    fund_receiver_usdc_token_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    usdc_to_transfer,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_usdc_token_account_info.clone(),
+                    usdc_mint_account_info.clone(),
+                    fund_receiver_usdc_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[
+                    &[
+                        LOTTERY_ACCOUNT_SEED.as_bytes(),
+                        &lottery_account.authority.to_bytes(),
+                        get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                        &[ lottery_account.canonical_bump ]
+                    ]
+                ]
+            )?;
+            sol_log("Pool prize withdrawed successfully.");
+        };
+
         lottery_account.serialize(
             &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
         )?;
@@ -1347,9 +2885,9 @@ impl Processor {
             );
         };
 
-        // validate that user in not one of the winners
-        for (winner, _) in lottery_account.winners.iter() {
-            if user_account_info.key == winner {
+        // validate that user in not one of the winners (with a prize to withdraw)
+        for (winner, _, is_withdrawed) in lottery_account.winners.iter() {
+            if user_account_info.key == winner && *is_withdrawed == false {
                 return Err(
                     LotteryError::UserIsOneOfTheWinners.into()
                 );
@@ -1361,10 +2899,10 @@ impl Processor {
         )?;
 
         if user_account.total_tickets_acquired > 0 {
-            // reduce lottery account size
-            let reduce_size = user_account.total_tickets_acquired
-                .checked_mul(pubkey::PUBKEY_BYTES as u32)
-                .ok_or::<ProgramError>(LotteryError::Overflow.into())? as usize;
+            // reduce lottery account size by the user's ticket-ranges
+            let reduce_size = (user_account.total_ticket_ranges_acquired as usize)
+                .checked_mul(TICKET_RANGE_LENGTH)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
 
             let lottery_account_old_data_size = lottery_account_info.data_len();
             let lottery_account_new_data_size = lottery_account_old_data_size
@@ -1419,6 +2957,7 @@ impl Processor {
         let fund_receiver_arbitrary_token_account_info = next_account_info(accounts_info)?;
         let fund_receiver_refunded_rent_exempt = next_account_info(accounts_info)?;
         let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let token_2022_program_account_info = next_account_info(accounts_info)?;
 
         check_account_is_signer(lottery_authority_account_info)?;
 
@@ -1473,18 +3012,20 @@ impl Processor {
         )?;
 
         check_accounts_key_to_be_identical(
-            &get_associated_token_address(
+            &get_associated_token_address_with_program_id(
                 lottery_account_info.key,
-                usdc_mint_account_info.key
+                usdc_mint_account_info.key,
+                usdc_mint_account_info.owner
             ),
             lottery_associated_usdc_token_account_info.key,
             LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
         )?;
 
         check_accounts_key_to_be_identical(
-            &get_associated_token_address(
+            &get_associated_token_address_with_program_id(
                 lottery_account_info.key,
-                arbitrary_mint_account_info.key
+                arbitrary_mint_account_info.key,
+                arbitrary_mint_account_info.owner
             ),
             lottery_associated_arbitrary_token_account_info.key,
             LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount.into()
@@ -1492,13 +3033,17 @@ impl Processor {
 
         // transfer lottery's creation fee to the fund_receiver account
         let usdc_token_amount = lottery_account.lottery_creation_fee;
-        let MintAccount { decimals: usdc_token_decimals, .. } = MintAccount::unpack(
-            &usdc_mint_account_info.data.try_borrow().unwrap()
+        let MintAccount { decimals: usdc_token_decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+        let usdc_token_program_account_info = get_token_program_account_info_of_mint(
+            usdc_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
         )?;
 
         invoke_signed(
             &transfer_spl_checked(
-                standard_token_program_account_info.key,
+                usdc_token_program_account_info.key,
                 lottery_associated_usdc_token_account_info.key,
                 usdc_mint_account_info.key,
                 fund_receiver_usdc_token_account_info.key,
@@ -1524,66 +3069,19 @@ impl Processor {
         )?;
         sol_log("Creation_fee refunded.");
 
-        // transfer all tokens in associated_arbitrary_token_account to the fund_receiver
-        let TokenAccount { amount: arbitrary_token_amount, .. } = TokenAccount::unpack(
-            &lottery_associated_arbitrary_token_account_info.data.try_borrow().unwrap()
-        )?;
-        let MintAccount { decimals: arbitrary_token_decimals, .. } = MintAccount::unpack(
-            &arbitrary_mint_account_info.data.try_borrow().unwrap()
-        )?;
-
-        invoke_signed(
-            &transfer_spl_checked(
-                standard_token_program_account_info.key,
-                lottery_associated_arbitrary_token_account_info.key,
-                arbitrary_mint_account_info.key,
-                fund_receiver_arbitrary_token_account_info.key,
-                lottery_account_info.key,
-                &[],
-                arbitrary_token_amount,
-                arbitrary_token_decimals
-            )?,
-            &[
-                lottery_associated_arbitrary_token_account_info.clone(),
-                arbitrary_mint_account_info.clone(),
-                fund_receiver_arbitrary_token_account_info.clone(),
-                lottery_account_info.clone()
-            ],
-            &[
-                &[
-                    LOTTERY_ACCOUNT_SEED.as_bytes(),
-                    &lottery_authority_account_info.key.to_bytes(),
-                    get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
-                    &[ lottery_account.canonical_bump ]
-                ]
-            ]
-        )?;
-        sol_log("arbitrary_tokens refunded.");
-
-        // close associated_arbitrary_token_account and send refund_lamports to the 
-        invoke_signed(
-            &close_token_account(
-                standard_token_program_account_info.key,
-                lottery_associated_arbitrary_token_account_info.key,
-                fund_receiver_refunded_rent_exempt.key,
-                lottery_account_info.key,
-                &[]
-            )?,
-            &[
-                lottery_associated_arbitrary_token_account_info.clone(),
-                fund_receiver_refunded_rent_exempt.clone(),
-                lottery_account_info.clone()
-            ],
-            &[
-                &[
-                    LOTTERY_ACCOUNT_SEED.as_bytes(),
-                    &lottery_authority_account_info.key.to_bytes(),
-                    get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
-                    &[ lottery_account.canonical_bump ]
-                ]
-            ]
+        // refund the arbitrary tokens, every prize mint, every nft prize & the sol prize
+        refund_lottery_prizes(
+            program_id,
+            &lottery_account,
+            lottery_account_info,
+            arbitrary_mint_account_info,
+            lottery_associated_arbitrary_token_account_info,
+            fund_receiver_arbitrary_token_account_info,
+            fund_receiver_refunded_rent_exempt,
+            standard_token_program_account_info,
+            token_2022_program_account_info,
+            accounts_info.as_slice()
         )?;
-        sol_log("Arbitrary_token_account closed & rent_exempt_lamports refunded.");
 
         // update the lottery account
         let mut lottery_account_data = lottery_account_info
@@ -1632,6 +3130,11 @@ impl Processor {
             usdc_mint_account_info.key
         )?;
 
+        check_token_program_account_of_mint(
+            standard_token_program_account_info,
+            usdc_mint_account_info
+        )?;
+
         // validate user account
         User::validate_user_account(
             user_account_info,
@@ -1650,7 +3153,7 @@ impl Processor {
             program_id
         )?;
 
-        let lottery_account = Lottery::deserialize(
+        let mut lottery_account = Lottery::deserialize(
             &mut &lottery_account_info.data.try_borrow().unwrap()[..]
         )?;
 
@@ -1662,45 +3165,176 @@ impl Processor {
         };
 
         check_accounts_key_to_be_identical(
-            &get_associated_token_address(
+            &get_associated_token_address_with_program_id(
                 lottery_account_info.key,
-                usdc_mint_account_info.key
+                usdc_mint_account_info.key,
+                usdc_mint_account_info.owner
             ),
             lottery_associated_usdc_token_account_info.key,
             LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
         )?;
 
-        if user_account.total_tickets_acquired > 0 {
-            // reduce lottery account size
-            let reduce_size = user_account.total_tickets_acquired
-                .checked_mul(pubkey::PUBKEY_BYTES as u32)
-                .ok_or::<ProgramError>(LotteryError::Overflow.into())? as usize;
+        // remaining accounts -> sol payments accounts, the sponsor's usdc token account, then the ticket token accounts
+        let remaining_accounts_info = accounts_info.as_slice();
+        let sol_payments_accounts_amount = if user_account.total_tickets_lamports > 0 { 2 } else { 0 };
 
-            let lottery_account_old_data_size = lottery_account_info.data_len();
-            let lottery_account_new_data_size = lottery_account_old_data_size
-                .checked_sub(reduce_size)
-                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        if remaining_accounts_info.len() < sol_payments_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfSolPaymentsAccounts.into()
+            );
+        };
 
-            lottery_account_info.realloc(
-                lottery_account_new_data_size,
-                false
-            )?;
-            sol_log("Lottery account data size reduced.");
+        let (
+            sol_payments_accounts_info,
+            remaining_accounts_info
+        ) = remaining_accounts_info.split_at(sol_payments_accounts_amount);
 
-            // transfer user_account's usdc_tokens to the fund_receiver account
-            let MintAccount { decimals, .. } = MintAccount::unpack(
-                &usdc_mint_account_info.data.try_borrow().unwrap()
-            )?;
+        // sponsored tickets -> (sponsor's usdc token account)
+        let sponsor_accounts_amount = if user_account.total_sponsored_tickets_value > 0 { 1 } else { 0 };
 
-            invoke_signed(
-                &transfer_spl_checked(
-                    standard_token_program_account_info.key,
-                    lottery_associated_usdc_token_account_info.key,
-                    usdc_mint_account_info.key,
-                    fund_receiver_usdc_token_account_info.key,
-                    lottery_account_info.key,
-                    &[],
-                    user_account.total_tickets_value,
+        if remaining_accounts_info.len() < sponsor_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfSponsorAccounts.into()
+            );
+        };
+
+        let (
+            sponsor_accounts_info,
+            ticket_token_accounts_info
+        ) = remaining_accounts_info.split_at(sponsor_accounts_amount);
+
+        // ticket tokens (optional) -> (ticket-mint pda, user's ticket token account, standard token program)
+        if ticket_token_accounts_info.is_empty() == false && (
+            lottery_account.is_tickets_tokenized == false || ticket_token_accounts_info.len() != 3
+        ) {
+            return Err(
+                LotteryError::InvalidAmountOfTicketTokenAccounts.into()
+            );
+        };
+
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+        if ticket_token_accounts_info.len() == 3 {
+            let ticket_mint_account_info = &ticket_token_accounts_info[0];
+            let user_ticket_token_account_info = &ticket_token_accounts_info[1];
+            let ticket_token_program_account_info = &ticket_token_accounts_info[2];
+
+            check_accounts_key_to_be_identical(
+                ticket_token_program_account_info.key,
+                &TOKEN_STANDARD_PROGRAM_ID,
+                LotteryError::InvalidTokenProgramAccount.into()
+            )?;
+
+            check_accounts_key_to_be_identical(
+                &get_ticket_mint_account_pda(
+                    lottery_account_info.key,
+                    program_id
+                ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?.0,
+                ticket_mint_account_info.key,
+                LotteryError::InvalidTicketMintAccount.into()
+            )?;
+
+            let TokenAccount { owner, mint, amount, .. } = unpack_token_account(user_ticket_token_account_info)?;
+            if owner != *user_account_authority_account_info.key || mint != *ticket_mint_account_info.key {
+                return Err(
+                    LotteryError::InvalidTicketTokenAccount.into()
+                );
+            };
+
+            if amount > 0 {
+                // the holder's ticket tokens are burned and refunded at their value
+                let tickets_value = lottery_account.take_ticket_tokens(
+                    u32::try_from(amount).map_err::<ProgramError, _>(|_| LotteryError::Overflow.into())?
+                )?;
+
+                invoke(
+                    &burn_checked(
+                        ticket_token_program_account_info.key,
+                        user_ticket_token_account_info.key,
+                        ticket_mint_account_info.key,
+                        user_account_authority_account_info.key,
+                        &[],
+                        amount,
+                        0
+                    )?,
+                    &[
+                        user_ticket_token_account_info.clone(),
+                        ticket_mint_account_info.clone(),
+                        user_account_authority_account_info.clone()
+                    ]
+                )?;
+                sol_log("Ticket tokens burned.");
+
+                let lottery_literal_seed = get_lottery_literal_seed(&lottery_account.lottery_description);
+
+                invoke_signed(
+                    &transfer_spl_checked(
+                        standard_token_program_account_info.key,
+                        lottery_associated_usdc_token_account_info.key,
+                        usdc_mint_account_info.key,
+                        fund_receiver_usdc_token_account_info.key,
+                        lottery_account_info.key,
+                        &[],
+                        tickets_value,
+                        decimals
+                    )?,
+                    &[
+                        lottery_associated_usdc_token_account_info.clone(),
+                        usdc_mint_account_info.clone(),
+                        fund_receiver_usdc_token_account_info.clone(),
+                        lottery_account_info.clone()
+                    ],
+                    &[
+                        &[
+                            LOTTERY_ACCOUNT_SEED.as_bytes(),
+                            &lottery_account.authority.to_bytes(),
+                            lottery_literal_seed.as_slice(),
+                            &[ lottery_account.canonical_bump ]
+                        ]
+                    ]
+                )?;
+                sol_log("Ticket tokens refunded.");
+
+                lottery_account.serialize(
+                    &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+                )?;
+            };
+        };
+
+        if user_account.total_tickets_acquired > 0 {
+            // reduce lottery account size by the user's ticket-ranges
+            let reduce_size = (user_account.total_ticket_ranges_acquired as usize)
+                .checked_mul(TICKET_RANGE_LENGTH)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            let lottery_account_old_data_size = lottery_account_info.data_len();
+            let lottery_account_new_data_size = lottery_account_old_data_size
+                .checked_sub(reduce_size)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            lottery_account_info.realloc(
+                lottery_account_new_data_size,
+                false
+            )?;
+            sol_log("Lottery account data size reduced.");
+
+            // transfer user_account's usdc_tokens to the fund_receiver account
+            // (tickets paid in SOL are refunded in SOL, sponsored tickets are refunded to the sponsor)
+            let usdc_to_refund = user_account.total_tickets_value
+                .checked_sub(user_account.total_converted_tickets_value)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+                .checked_sub(user_account.total_sponsored_tickets_value)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    standard_token_program_account_info.key,
+                    lottery_associated_usdc_token_account_info.key,
+                    usdc_mint_account_info.key,
+                    fund_receiver_usdc_token_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    usdc_to_refund,
                     decimals
                 )?,
                 &[
@@ -1720,6 +3354,47 @@ impl Processor {
             )?;
             sol_log("USDC tokens transfered.");
 
+            if user_account.total_sponsored_tickets_value > 0 {
+                let sponsor_usdc_token_account_info = &sponsor_accounts_info[0];
+
+                let TokenAccount { owner, mint, .. } = unpack_token_account(sponsor_usdc_token_account_info)?;
+                if user_account.sponsor != Some(owner) || mint != *usdc_mint_account_info.key {
+                    return Err(
+                        LotteryError::InvalidSponsorUsdcTokenAccount.into()
+                    );
+                };
+
+                let lottery_literal_seed = get_lottery_literal_seed(&lottery_account.lottery_description);
+
+                invoke_signed(
+                    &transfer_spl_checked(
+                        standard_token_program_account_info.key,
+                        lottery_associated_usdc_token_account_info.key,
+                        usdc_mint_account_info.key,
+                        sponsor_usdc_token_account_info.key,
+                        lottery_account_info.key,
+                        &[],
+                        user_account.total_sponsored_tickets_value,
+                        decimals
+                    )?,
+                    &[
+                        lottery_associated_usdc_token_account_info.clone(),
+                        usdc_mint_account_info.clone(),
+                        sponsor_usdc_token_account_info.clone(),
+                        lottery_account_info.clone()
+                    ],
+                    &[
+                        &[
+                            LOTTERY_ACCOUNT_SEED.as_bytes(),
+                            &lottery_account.authority.to_bytes(),
+                            lottery_literal_seed.as_slice(),
+                            &[ lottery_account.canonical_bump ]
+                        ]
+                    ]
+                )?;
+                sol_log("Sponsored tickets refunded to the sponsor.");
+            };
+
             // transfer tickets_rent_exempt lamports to the fund_receiver account
             let lottery_account_old_balance = lottery_account_info.lamports();
             let fund_receiver_tickets_rent_exempt_account_old_balance = fund_receiver_tickets_rent_exempt_account_info.lamports();
@@ -1735,6 +3410,43 @@ impl Processor {
             sol_log("Tickets rent-exempt lamprots transfered.");
         };
 
+        // sol payments -> (sol-payments-vault pda, fund-receiver sol lamports account)
+        if user_account.total_tickets_lamports > 0 {
+            let sol_payments_vault_account_info = &sol_payments_accounts_info[0];
+            let fund_receiver_sol_account_info = &sol_payments_accounts_info[1];
+
+            check_accounts_key_to_be_identical(
+                &get_sol_payments_vault_account_pda(
+                    lottery_account_info.key,
+                    program_id
+                ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?.0,
+                sol_payments_vault_account_info.key,
+                LotteryError::InvalidSolPaymentsVaultAccount.into()
+            )?;
+
+            let sol_payments_vault_old_balance = sol_payments_vault_account_info.lamports();
+            let fund_receiver_sol_account_old_balance = fund_receiver_sol_account_info.lamports();
+
+            **sol_payments_vault_account_info.try_borrow_mut_lamports()? = sol_payments_vault_old_balance
+                .checked_sub(user_account.total_tickets_lamports)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            **fund_receiver_sol_account_info.try_borrow_mut_lamports()? = fund_receiver_sol_account_old_balance
+                .checked_add(user_account.total_tickets_lamports)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            // the vault is closed once every sol payment is refunded
+            lottery_account.sol_payments_amount = lottery_account.sol_payments_amount
+                .checked_sub(user_account.total_tickets_lamports)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            lottery_account.serialize(
+                &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+            )?;
+
+            sol_log("SOL payments refunded.");
+        };
+
         // close user account
         User::close_user_account(
             user_account_info,
@@ -1759,6 +3471,7 @@ impl Processor {
         let fund_receiver_usdc_token_account_info = next_account_info(accounts_info)?;
         let fund_receiver_rent_exempt_account_info = next_account_info(accounts_info)?;
         let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let sol_payments_vault_account_info = next_account_info(accounts_info)?;
 
         check_account_is_signer(lottery_account_authority_account_info)?;
 
@@ -1777,13 +3490,18 @@ impl Processor {
             usdc_mint_account_info.key
         )?;
 
+        check_token_program_account_of_mint(
+            standard_token_program_account_info,
+            usdc_mint_account_info
+        )?;
+
         // validate lottery account
         Lottery::validate_lottery_account(
             lottery_account_info,
             program_id
         )?;
 
-        let lottery_account = Lottery::deserialize(
+        let mut lottery_account = Lottery::deserialize(
             &mut &lottery_account_info.data.try_borrow().unwrap()[..]
         )?;
 
@@ -1796,28 +3514,100 @@ impl Processor {
 
         // validate lottery state
         let current_time = (Clock::get()?).unix_timestamp;
-        if lottery_account.get_lottery_state(current_time) != LotteryState::Failed {
-            return Err(
-                LotteryError::InvalidLotteryState.into()
-            );
-        };
-
-        if lottery_account.is_creator_withdrawed_when_lottery_was_failed == false {
-            return Err(
-                LotteryError::FirstCloseLotteryArbitrartAssociatedTokenAccount.into()
-            );
+        let lottery_state = lottery_account.get_lottery_state(current_time);
+        match lottery_state {
+            LotteryState::Failed => {
+                if lottery_account.is_creator_withdrawed_when_lottery_was_failed == false {
+                    return Err(
+                        LotteryError::FirstCloseLotteryArbitrartAssociatedTokenAccount.into()
+                    );
+                };
+
+                // the usdc of the ticket tokens is refunded to their holders (the ones not refunded in time are swept to the treasury)
+                if lottery_account.ticket_tokens_outstanding > 0 && lottery_account.is_ticket_tokens_refund_timed_out(current_time) == false {
+                    return Err(
+                        LotteryError::TicketTokensOutstanding.into()
+                    );
+                };
+
+                // the tickets paid in SOL are refunded to their users
+                if lottery_account.sol_payments_amount > 0 {
+                    return Err(
+                        LotteryError::SolPaymentsNotRefunded.into()
+                    );
+                };
+            },
+            // the usdc left after the creator's withdrawal & the protocol fees is swept, the prizes must be withdrawn (or reclaimed) & their token accounts closed
+            LotteryState::Successful => {
+                if
+                    lottery_account.is_creator_withdrawed_when_lottery_was_successful == false ||
+                    lottery_account.is_protocol_fee_claimed == false ||
+                    lottery_account.winners.iter().any(|(_, _, is_withdrawed)| *is_withdrawed == false) == true ||
+                    lottery_account.is_arbitrary_token_account_closed == false ||
+                    lottery_account.prize_token_accounts_amount > 0
+                {
+                    return Err(
+                        LotteryError::SucceedLotteryNotSettled.into()
+                    );
+                };
+
+                // winners of a series jackpot claim it through the lottery account
+                if let Some(series_jackpot @ SeriesJackpot { is_won: true, .. }) = lottery_account.series_jackpot {
+                    if lottery_account.winners.iter().any(|(_, rank, _)| series_jackpot.is_rank_claimed(*rank) == false) == true {
+                        return Err(
+                            LotteryError::SucceedLotteryNotSettled.into()
+                        );
+                    };
+                };
+            },
+            _ => {
+                return Err(
+                    LotteryError::InvalidLotteryState.into()
+                );
+            }
         };
 
         // validate lottery associated usdc token account
         check_accounts_key_to_be_identical(
-            &get_associated_token_address(
+            &get_associated_token_address_with_program_id(
                 lottery_account_info.key,
-                usdc_mint_account_info.key
+                usdc_mint_account_info.key,
+                usdc_mint_account_info.owner
             ),
             lottery_associated_usdc_token_account_info.key,
             LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
         )?;
 
+        // validate sol-payments vault account
+        check_accounts_key_to_be_identical(
+            &get_sol_payments_vault_account_pda(
+                lottery_account_info.key,
+                program_id
+            ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?.0,
+            sol_payments_vault_account_info.key,
+            LotteryError::InvalidSolPaymentsVaultAccount.into()
+        )?;
+
+        // sol prize of a successful lottery -> (prize-vault pda), a failed lottery's prize-vault is closed by "WithdrawFailedLottery"
+        let remaining_accounts_info = accounts_info.as_slice();
+        let prize_vault_accounts_amount = if lottery_state == LotteryState::Successful && lottery_account.sol_prize_amount > 0 { 1 } else { 0 };
+        if remaining_accounts_info.len() < prize_vault_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfPrizeVaultAccounts.into()
+            );
+        };
+        let (
+            prize_vault_accounts_info,
+            ticket_tokens_accounts_info
+        ) = remaining_accounts_info.split_at(prize_vault_accounts_amount);
+
+        // ticket tokens of a failed lottery not refunded in time -> (treasury)
+        if ticket_tokens_accounts_info.len() != if lottery_state == LotteryState::Failed && lottery_account.ticket_tokens_outstanding > 0 { 1 } else { 0 } {
+            return Err(
+                LotteryError::InvalidAmountOfTicketTokenAccounts.into()
+            );
+        };
+
         // validate lottery account "initial_bytes" field
         let current_data_size: u64 = lottery_account_info
             .data_len()
@@ -1830,15 +3620,57 @@ impl Processor {
             );
         };
 
+        // sweep the usdc of the ticket tokens left unrefunded after the refund window to the treasury
+        if ticket_tokens_accounts_info.is_empty() == false {
+            let treasury_account_info = &ticket_tokens_accounts_info[0];
+
+            let config_account = Config::deserialize(
+                &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+            )?;
+
+            check_accounts_key_to_be_identical(
+                treasury_account_info.key,
+                &config_account.treasury,
+                LotteryError::InvalidTreasuryAccount.into()
+            )?;
+
+            let tickets_value = lottery_account.take_ticket_tokens(lottery_account.ticket_tokens_outstanding)?;
+            let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    standard_token_program_account_info.key,
+                    lottery_associated_usdc_token_account_info.key,
+                    usdc_mint_account_info.key,
+                    treasury_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    tickets_value,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_usdc_token_account_info.clone(),
+                    usdc_mint_account_info.clone(),
+                    treasury_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[
+                    &[
+                        LOTTERY_ACCOUNT_SEED.as_bytes(),
+                        &lottery_account.authority.to_bytes(),
+                        get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                        &[ lottery_account.canonical_bump ]
+                    ]
+                ]
+            )?;
+            sol_log("USDC of the unrefunded ticket tokens transfered to the treasury.");
+        };
+
         // transfer all usdc tokens if exists
-        let TokenAccount { amount: usdc_token_balance, .. } = TokenAccount::unpack(
-            &lottery_associated_usdc_token_account_info.data.try_borrow().unwrap()
-        )?;
+        let TokenAccount { amount: usdc_token_balance, .. } = unpack_token_account(lottery_associated_usdc_token_account_info)?;
 
         if usdc_token_balance > 0 {
-            let MintAccount { decimals, .. } = MintAccount::unpack(
-                &usdc_mint_account_info.data.try_borrow().unwrap()
-            )?;
+            let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
 
             invoke_signed(
                 &transfer_spl_checked(
@@ -1894,6 +3726,38 @@ impl Processor {
         )?;
         sol_log("Usdc token account closed & rent exempt lamports refunded.");
 
+        // close the prize-vault, every rank's sol prize is withdrawn (or reclaimed) -> its rent_exempt & the rounding dust are left
+        if prize_vault_accounts_info.is_empty() == false {
+            let prize_vault_account_info = &prize_vault_accounts_info[0];
+
+            lottery_account.validate_prize_vault_account(
+                prize_vault_account_info,
+                lottery_account_info.key,
+                program_id
+            )?;
+
+            let fund_receiver_rent_exempt_account_old_balance = fund_receiver_rent_exempt_account_info.lamports();
+            **fund_receiver_rent_exempt_account_info.try_borrow_mut_lamports()? = fund_receiver_rent_exempt_account_old_balance
+                .checked_add(prize_vault_account_info.lamports())
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            **prize_vault_account_info.try_borrow_mut_lamports()? = 0;
+
+            sol_log("Prize-vault closed & rent exempt lamports refunded.");
+        };
+
+        // close the sol-payments vault (created by the first ticket paid in SOL), the sol payments are withdrawn (or refunded) -> its rent_exempt is left
+        if sol_payments_vault_account_info.owner == program_id {
+            let fund_receiver_rent_exempt_account_old_balance = fund_receiver_rent_exempt_account_info.lamports();
+            **fund_receiver_rent_exempt_account_info.try_borrow_mut_lamports()? = fund_receiver_rent_exempt_account_old_balance
+                .checked_add(sol_payments_vault_account_info.lamports())
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            **sol_payments_vault_account_info.try_borrow_mut_lamports()? = 0;
+
+            sol_log("Sol-payments vault closed & rent exempt lamports refunded.");
+        };
+
         // close lottery_account and reclaim the rent_exempt_lamports
         Lottery::close_lottery_account(
             lottery_account_info,
@@ -1904,17 +3768,27 @@ impl Processor {
         Ok(())
     }
 
-    pub fn process_change_fee_of_lottery_creation(
+    pub fn process_cancel_lottery(
         program_id: &Pubkey,
-        accounts_info: &[AccountInfo],
-        new_fee: u64
+        accounts_info: &[AccountInfo]
     ) -> ProgramResult {
         let accounts_info = &mut accounts_info.iter();
 
         let config_global_account_info = next_account_info(accounts_info)?;
-        let config_account_authority_account_info = next_account_info(accounts_info)?;
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let lottery_authority_account_info = next_account_info(accounts_info)?;
+        let usdc_mint_account_info = next_account_info(accounts_info)?;
+        let arbitrary_mint_account_info = next_account_info(accounts_info)?;
+        let lottery_associated_usdc_token_account_info = next_account_info(accounts_info)?;
+        let lottery_associated_arbitrary_token_account_info = next_account_info(accounts_info)?;
+        let fund_receiver_usdc_token_account_info = next_account_info(accounts_info)?;
+        let fund_receiver_arbitrary_token_account_info = next_account_info(accounts_info)?;
+        let treasury_account_info = next_account_info(accounts_info)?;
+        let fund_receiver_refunded_rent_exempt = next_account_info(accounts_info)?;
+        let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let token_2022_program_account_info = next_account_info(accounts_info)?;
 
-        check_account_is_signer(config_account_authority_account_info)?;
+        check_account_is_signer(lottery_authority_account_info)?;
 
         // validate config account
         Config::validate_config_account(
@@ -1922,68 +3796,268 @@ impl Processor {
             program_id
         )?;
 
-        // validate authority account
-        check_accounts_key_to_be_identical(
-            &get_config_account_authority(config_global_account_info),
-            config_account_authority_account_info.key,
-            LotteryError::InvalidConfigAuthority.into()
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
         )?;
 
-        // update the lottery account
-        sol_memcpy(
-            config_global_account_info
-                .data
-                .try_borrow_mut()
-                .unwrap()
-                .get_mut(50..58)
-                .unwrap(),
-            &new_fee.to_le_bytes(),
-            std::mem::size_of::<u64>()
-        );
-        
-        sol_log("Config account updated.");
-
-        Ok(())
-    }
-
-    pub fn process_change_config_account_authority(
-        program_id: &Pubkey,
-        accounts_info: &[AccountInfo]
-    ) -> ProgramResult {
-        let accounts_info = &mut accounts_info.iter();
+        // check is_pause flag
+        config_account.check_is_pause()?;
 
-        let config_global_account_info = next_account_info(accounts_info)?;
-        let config_account_authority_account_info = next_account_info(accounts_info)?;
-        let new_authority_account_info = next_account_info(accounts_info)?;
+        check_accounts_key_to_be_identical(
+            usdc_mint_account_info.key,
+            &config_account.usdc_mint_account,
+            LotteryError::InvalidUsdcMintAccount.into()
+        )?;
 
-        check_account_is_signer(config_account_authority_account_info)?;
+        check_accounts_key_to_be_identical(
+            treasury_account_info.key,
+            &config_account.treasury,
+            LotteryError::InvalidTreasuryAccount.into()
+        )?;
 
-        // validate config account
-        Config::validate_config_account(
-            config_global_account_info,
+        // validate lottery account
+        Lottery::validate_lottery_account(
+            lottery_account_info,
             program_id
         )?;
-        
-        // validate authority account
+
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
         check_accounts_key_to_be_identical(
-            &get_config_account_authority(config_global_account_info),
-            config_account_authority_account_info.key,
-            LotteryError::InvalidConfigAuthority.into()
+            &lottery_account.authority,
+            lottery_authority_account_info.key,
+            LotteryError::InvalidLotteryAccountAuthority.into()
         )?;
 
-        if new_authority_account_info.key == &get_config_account_authority(config_global_account_info) {
+        // no tickets can be sold before the starting time
+        let current_time = (Clock::get()?).unix_timestamp;
+        if lottery_account.is_not_started(current_time) == false {
             return Err(
-                LotteryError::InvalidNewConfigAccountAuthority.into()
+                LotteryError::InvalidLotteryState.into()
             );
         };
 
-        // update the lottery account
-        sol_memcpy(
-            config_global_account_info
-                .data
-                .try_borrow_mut()
-                .unwrap()
-                .get_mut(10..42)
+        check_accounts_key_to_be_identical(
+            &lottery_account.arbitrary_mint_account_address,
+            arbitrary_mint_account_info.key,
+            LotteryError::InvalidArbitraryMintAccount.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                usdc_mint_account_info.key,
+                usdc_mint_account_info.owner
+            ),
+            lottery_associated_usdc_token_account_info.key,
+            LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                arbitrary_mint_account_info.key,
+                arbitrary_mint_account_info.owner
+            ),
+            lottery_associated_arbitrary_token_account_info.key,
+            LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount.into()
+        )?;
+
+        // refund the arbitrary tokens, every prize mint, every nft prize & the sol prize
+        refund_lottery_prizes(
+            program_id,
+            &lottery_account,
+            lottery_account_info,
+            arbitrary_mint_account_info,
+            lottery_associated_arbitrary_token_account_info,
+            fund_receiver_arbitrary_token_account_info,
+            fund_receiver_refunded_rent_exempt,
+            standard_token_program_account_info,
+            token_2022_program_account_info,
+            accounts_info.as_slice()
+        )?;
+
+        // the cancellation penalty goes to the treasury, the rest of the usdc token account to the fund_receiver
+        let usdc_token_program_account_info = get_token_program_account_info_of_mint(
+            usdc_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+
+        let TokenAccount { amount: usdc_token_balance, .. } = unpack_token_account(lottery_associated_usdc_token_account_info)?;
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+        let cancellation_penalty = config_account.lottery_cancellation_penalty
+            .min(lottery_account.lottery_creation_fee)
+            .min(usdc_token_balance);
+        let usdc_token_amount = usdc_token_balance
+            .checked_sub(cancellation_penalty)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        let lottery_literal_seed = get_lottery_literal_seed(&lottery_account.lottery_description);
+        let lottery_account_signer_seeds: &[&[u8]] = &[
+            LOTTERY_ACCOUNT_SEED.as_bytes(),
+            &lottery_authority_account_info.key.to_bytes(),
+            lottery_literal_seed.as_slice(),
+            &[ lottery_account.canonical_bump ]
+        ];
+
+        if cancellation_penalty > 0 {
+            invoke_signed(
+                &transfer_spl_checked(
+                    usdc_token_program_account_info.key,
+                    lottery_associated_usdc_token_account_info.key,
+                    usdc_mint_account_info.key,
+                    treasury_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    cancellation_penalty,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_usdc_token_account_info.clone(),
+                    usdc_mint_account_info.clone(),
+                    treasury_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ lottery_account_signer_seeds ]
+            )?;
+            sol_log("Cancellation penalty transfered to the treasury.");
+        };
+
+        if usdc_token_amount > 0 {
+            invoke_signed(
+                &transfer_spl_checked(
+                    usdc_token_program_account_info.key,
+                    lottery_associated_usdc_token_account_info.key,
+                    usdc_mint_account_info.key,
+                    fund_receiver_usdc_token_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    usdc_token_amount,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_usdc_token_account_info.clone(),
+                    usdc_mint_account_info.clone(),
+                    fund_receiver_usdc_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ lottery_account_signer_seeds ]
+            )?;
+            sol_log("Creation_fee refunded.");
+        };
+
+        // close usdc_associated_token_account and reclaim the rent_exempt_lamports
+        invoke_signed(
+            &close_token_account(
+                usdc_token_program_account_info.key,
+                lottery_associated_usdc_token_account_info.key,
+                fund_receiver_refunded_rent_exempt.key,
+                lottery_account_info.key,
+                &[]
+            )?,
+            &[
+                lottery_associated_usdc_token_account_info.clone(),
+                fund_receiver_refunded_rent_exempt.clone(),
+                lottery_account_info.clone()
+            ],
+            &[ lottery_account_signer_seeds ]
+        )?;
+        sol_log("Usdc token account closed & rent exempt lamports refunded.");
+
+        // close lottery_account and reclaim the rent_exempt_lamports
+        Lottery::close_lottery_account(
+            lottery_account_info,
+            fund_receiver_refunded_rent_exempt
+        )?;
+        sol_log("Lottery account cancelled & closed.");
+
+        Ok(())
+    }
+
+    pub fn process_change_fee_of_lottery_creation(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        new_fee: u64
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let config_account_authority_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(config_account_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // validate authority account
+        check_accounts_key_to_be_identical(
+            &get_config_account_authority(config_global_account_info),
+            config_account_authority_account_info.key,
+            LotteryError::InvalidConfigAuthority.into()
+        )?;
+
+        // update the lottery account
+        sol_memcpy(
+            config_global_account_info
+                .data
+                .try_borrow_mut()
+                .unwrap()
+                .get_mut(50..58)
+                .unwrap(),
+            &new_fee.to_le_bytes(),
+            std::mem::size_of::<u64>()
+        );
+        
+        sol_log("Config account updated.");
+
+        Ok(())
+    }
+
+    pub fn process_change_config_account_authority(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let config_account_authority_account_info = next_account_info(accounts_info)?;
+        let new_authority_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(config_account_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+        
+        // validate authority account
+        check_accounts_key_to_be_identical(
+            &get_config_account_authority(config_global_account_info),
+            config_account_authority_account_info.key,
+            LotteryError::InvalidConfigAuthority.into()
+        )?;
+
+        if new_authority_account_info.key == &get_config_account_authority(config_global_account_info) {
+            return Err(
+                LotteryError::InvalidNewConfigAccountAuthority.into()
+            );
+        };
+
+        // update the lottery account
+        sol_memcpy(
+            config_global_account_info
+                .data
+                .try_borrow_mut()
+                .unwrap()
+                .get_mut(10..42)
                 .unwrap(),
             &new_authority_account_info.key.to_bytes(),
             std::mem::size_of::<Pubkey>()
@@ -2096,6 +4170,11 @@ impl Processor {
             LotteryError::InvalidUsdcMintAccount.into()
         )?;
 
+        check_token_program_account_of_mint(
+            standard_token_program_account_info,
+            usdc_mint_account_info
+        )?;
+
         let current_time = (Clock::get()?).unix_timestamp;
 
         for (index, lottery_account_info) in lotteries_accounts_infos.iter().enumerate() {
@@ -2126,9 +4205,10 @@ impl Processor {
 
             // validate associated usdc token account
             check_accounts_key_to_be_identical(
-                &get_associated_token_address(
+                &get_associated_token_address_with_program_id(
                     lottery_account_info.key,
-                    usdc_mint_account_info.key
+                    usdc_mint_account_info.key,
+                    usdc_mint_account_info.owner
                 ),
                 lottery_associated_usdc_token_account_info.key,
                 LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
@@ -2138,9 +4218,7 @@ impl Processor {
                 lottery_account.protocol_fee
             ).ok_or::<ProgramError>(LotteryError::Overflow.into())?;
 
-            let MintAccount { decimals, .. } = MintAccount::unpack(
-                &usdc_mint_account_info.data.try_borrow().unwrap()
-            )?;
+            let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
 
             invoke_signed(
                 &transfer_spl_checked(
@@ -2558,382 +4636,4653 @@ impl Processor {
         Ok(())
     }
 
-    pub fn process(
+    pub fn process_change_vrf_oracle_authority(
         program_id: &Pubkey,
-        accounts_info: &[AccountInfo],
-        instruction_data: &[u8]
+        accounts_info: &[AccountInfo]
     ) -> ProgramResult {
-        let ix = Instructions::unpack(instruction_data)?;
+        let accounts_info = &mut accounts_info.iter();
 
-        use Instructions::*;
-        match ix {
-            CreateAndInitializeProgramConfigAccount {
-                authority,
-                lottery_creation_fee,
-                lottery_tickets_fee,
-                maximum_number_of_winners,
-                pyth_price_receiver_programid,
-                usdc_mint_account,
-                maximum_time_of_price_feed_age,
-                minimum_tickets_to_be_sold_in_lottery,
-                pyth_price_feed_accounts,
-                maximum_time_for_lottery_account,
-                treasury,
-                pyth_price_feed_ids,
-                max_lottery_description_bytes
-            } => {
-                sol_log("Instruction: CreateAndInitializeProgramConfigAccount");
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let config_account_authority_account_info = next_account_info(accounts_info)?;
+        let new_vrf_oracle_authority_account_info = next_account_info(accounts_info)?;
 
-                check_accounts_amount(accounts_info.len(), 4)?;
+        check_account_is_signer(config_account_authority_account_info)?;
 
-                Self::process_create_and_initialize_program_config_account(
-                    accounts_info,
-                    program_id,
-                    authority,
-                    lottery_creation_fee,
-                    lottery_tickets_fee,
-                    maximum_number_of_winners,
-                    pyth_price_receiver_programid,
-                    usdc_mint_account,
-                    maximum_time_of_price_feed_age,
-                    minimum_tickets_to_be_sold_in_lottery,
-                    pyth_price_feed_accounts,
-                    maximum_time_for_lottery_account,
-                    treasury,
-                    pyth_price_feed_ids,
-                    max_lottery_description_bytes
-                )
-            },
-            CreateAndInitializeLotteryAccount {
-                fund_amount,
-                winners_count,
-                starting_time,
-                ending_time,
-                minimum_tickets_amount_required_to_be_sold,
-                ticket_price,
-                maximum_number_of_tickets_per_user,
-                lottery_description
-            } => {
-                sol_log("Instruction: CreateAndInitializeLotteryAccount");
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
 
-                check_accounts_amount(accounts_info.len(), 13)?;
+        // validate authority account
+        check_accounts_key_to_be_identical(
+            &get_config_account_authority(config_global_account_info),
+            config_account_authority_account_info.key,
+            LotteryError::InvalidConfigAuthority.into()
+        )?;
 
-                Self::process_create_and_initialize_lottery_account(
-                    program_id,
-                    accounts_info,
-                    fund_amount,
-                    winners_count,
-                    starting_time,
-                    ending_time,
-                    minimum_tickets_amount_required_to_be_sold,
-                    ticket_price,
-                    maximum_number_of_tickets_per_user,
-                    lottery_description
-                )
-            },
-            CreateAndInitializeUserAccount => {
-                sol_log("Instruction: CreateAndInitializeUserAccount");
+        let mut config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
 
-                check_accounts_amount(accounts_info.len(), 6)?;
+        config_account.vrf_oracle_authority = *new_vrf_oracle_authority_account_info.key;
 
-                Self::process_create_and_initialize_user_account(
-                    program_id,
-                    accounts_info
-                )
-            },
-            BuyTicket {
-                tickets_amount,
-                expected_token_price_per_ticket
-            } => {
-                sol_log("Instruction: BuyTicket");
+        config_account.serialize(
+            &mut &mut config_global_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
 
-                check_accounts_amount(accounts_info.len(), 10)?;
+        sol_log("Config account updated.");
 
-                Self::process_buy_ticket(
-                    program_id,
-                    accounts_info,
-                    tickets_amount,
-                    expected_token_price_per_ticket
-                )
-            },
-            ChangeLotteryTicketPrice { new_ticket_price } => {
-                sol_log("Instruction: ChangeLotteryTicketPrice");
+        Ok(())
+    }
 
-                check_accounts_amount(accounts_info.len(), 3)?;
+    pub fn process_change_lottery_cancellation_penalty(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        new_penalty: u64
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
 
-                Self::process_change_lottery_ticket_price(
-                    accounts_info,
-                    program_id,
-                    new_ticket_price
-                )
-            },
-            EndLotteryAndPickWinners => {
-                sol_log("Instruction: EndLotteryAndPickWinners");
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let config_account_authority_account_info = next_account_info(accounts_info)?;
 
-                check_accounts_amount(accounts_info.len(), 6)?;
+        check_account_is_signer(config_account_authority_account_info)?;
 
-                Self::process_end_lottery_and_pick_winners(
-                    program_id,
-                    accounts_info
-                )
-            },
-            WithdrawSucceedLottery => {
-                sol_log("Instruction: WithdrawSucceedLottery");
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
 
-                check_accounts_amount(accounts_info.len(), 7)?;
+        // validate authority account
+        check_accounts_key_to_be_identical(
+            &get_config_account_authority(config_global_account_info),
+            config_account_authority_account_info.key,
+            LotteryError::InvalidConfigAuthority.into()
+        )?;
 
-                Self::process_withdraw_succeed_lottery(
-                    program_id,
-                    accounts_info
-                )
-            },
-            WithdrawLotterysWinners => {
-                sol_log("Instruction: WithdrawLotterysWinners");
+        let mut config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
 
-                check_accounts_amount(accounts_info.len(), 8)?;
+        config_account.lottery_cancellation_penalty = new_penalty;
 
-                Self::process_withdraw_lottery_winners(
-                    program_id,
-                    accounts_info
-                )
-            },
-            WithdrawAndCloseSucceedUser => {
-                sol_log("Instruction: WithdrawAndCloseSucceedUser");
+        config_account.serialize(
+            &mut &mut config_global_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
 
-                check_accounts_amount(accounts_info.len(), 6)?;
+        sol_log("Config account updated.");
 
-                Self::process_withdraw_and_close_succeed_user(
-                    program_id, 
-                    accounts_info
-                )
-            },
-            WithdrawFailedLottery {} => {
-                sol_log("Instruction: WithdrawFailedLottery");
+        Ok(())
+    }
 
-                check_accounts_amount(accounts_info.len(), 11)?;
+    pub fn process_request_randomness(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
 
-                Self::process_withdraw_failed_lottery(
-                    program_id,
-                    accounts_info
-                )
-            },
-            WithdrawAndCloseFailedUser {} => {
-                sol_log("Instruction: WithdrawAndCloseFailedUser");
+        let randomness_request_account_info = next_account_info(accounts_info)?;
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let funding_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
 
-                check_accounts_amount(accounts_info.len(), 10)?;
+        check_system_program_id(system_program_account_info.key)?;
 
-                Self::process_withdraw_and_close_failed_user(
-                    program_id,
-                    accounts_info
-                )
-            },
-            CloseLotteryAccountAndUsdcTokenAccount => {
-                sol_log("Instruction: CloseLotteryAccountAndUsdcTokenAccount");
+        check_account_is_signer(funding_account_info)?;
 
-                check_accounts_amount(accounts_info.len(), 8)?;
+        check_account_is_raw(randomness_request_account_info)?;
 
-                Self::process_close_lottery_account_and_usdc_token_account(
-                    program_id,
-                    accounts_info
-                )
-            },
-            ChangeFeeOfLotteryCreation { new_fee } => {
-                sol_log("Instruction: ChangeFeeOfLotteryCreation");
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
 
-                Self::process_change_fee_of_lottery_creation(
-                    program_id,
-                    accounts_info,
-                    new_fee
-                )
-            },
-            ChangeConfigAccountAuthority => {
-                sol_log("Instruction: ChangeConfigAccountAuthority");
+        // check is_pause flag
+        config_account.check_is_pause()?;
 
-                check_accounts_amount(accounts_info.len(), 3)?;
+        // validate lottery account
+        Lottery::validate_lottery_account(
+            lottery_account_info,
+            program_id
+        )?;
 
-                Self::process_change_config_account_authority(
-                    program_id,
-                    accounts_info
-                )
-            },
-            ChangeFeeOfTickets { new_fee } => {
-                sol_log("Instruction: ChangeFeeOfTickets");
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+        lottery_account.check_randomness_source(RandomnessSource::VrfOracle)?;
 
-                Self::process_change_fee_of_tickets(
-                    program_id,
-                    accounts_info,
-                    new_fee
-                )
-            },
-            ClaimProtocolFees { n } => {
-                sol_log("Instruction: ClaimProtocolFees");
+        // the request seed is taken once the ticket ledger is final, the oracle can't know it while tickets are sold
+        let clock = Clock::get()?;
+        if lottery_account.get_lottery_state(clock.unix_timestamp) != LotteryState::Successful {
+            return Err(
+                LotteryError::LotteryWasNotSuccessfull.into()
+            );
+        };
 
-                check_accounts_amount(accounts_info.len(), 5 + (n as usize * 2))?;
-                
-                Self::process_claim_protocol_fees(
-                    program_id,
-                    accounts_info,
-                    n
-                )
-            },
-            ChangeMaximumNumberOfWinners { new_max } => {
-                sol_log("Instruction: ChangeMaximumNumberOfWinners");
+        // creator's secret is part of the request seed
+        lottery_account.check_secret_is_revealed(clock.unix_timestamp)?;
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+        if config_account.vrf_oracle_authority == Pubkey::default() {
+            return Err(
+                LotteryError::InvalidOracleAuthority.into()
+            );
+        };
 
-                Self::process_change_maximum_number_of_winners(
-                    program_id,
-                    accounts_info,
-                    new_max
-                )
-            },
-            ChangeMaximumAgeOfPriceFeed { new_max } => {
-                sol_log("Instriction: ChangeMaximumAgeOfPriceFeed");
+        let (
+            randomness_request_pda_addr,
+            randomness_request_pda_canonical_bump
+        ) = Pubkey::try_find_program_address(
+            &[
+                RANDOMNESS_REQUEST_ACCOUNT_SEED.as_bytes(),
+                lottery_account_info.key.to_bytes().as_slice()
+            ],
+            program_id
+        ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?;
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+        check_accounts_key_to_be_identical(
+            &randomness_request_pda_addr,
+            randomness_request_account_info.key,
+            ProgramError::InvalidSeeds
+        )?;
 
-                Self::process_change_maximum_age_of_price_feed(
-                    program_id,
-                    accounts_info,
-                    new_max
-                )
-            },
-            ChangePauseState { pause } => {
-                sol_log("Instruction: ChangePauseState");
+        // create the randomness-request account
+        let seeds: &[&[u8]] = &[
+            RANDOMNESS_REQUEST_ACCOUNT_SEED.as_bytes(),
+            &lottery_account_info.key.to_bytes(),
+            &[ randomness_request_pda_canonical_bump ]
+        ];
+        create_pda_account(
+            randomness_request_account_info,
+            funding_account_info,
+            RandomnessRequest::LEN,
+            program_id,
+            seeds
+        )?;
+        sol_log("Randomness-request account created.");
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+        let request_seed = lottery_account.get_request_seed(lottery_account_info)?;
 
-                Self::process_change_pause_state(
-                    program_id,
-                    accounts_info,
-                    pause
-                )
-            },
-            ChangeTreasury => {
-                sol_log("Instruction: ChangeTreasury");
+        let randomness_request = RandomnessRequest::new(
+            randomness_request_pda_canonical_bump,
+            *lottery_account_info.key,
+            config_account.vrf_oracle_authority,
+            clock.unix_timestamp,
+            request_seed
+        );
 
-                check_accounts_amount(accounts_info.len(), 3)?;
+        randomness_request.serialize(
+            &mut &mut randomness_request_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+        sol_log("Randomness requested.");
 
-                Self::process_change_treasury_account(
-                    program_id,
-                    accounts_info
-                )
-            },
-            ChangeProtocolMintAccount { new_mint_account } => {
-                sol_log("Instruction: ChangeMintAccount");
+        Ok(())
+    }
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+    pub fn process_fulfil_randomness(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        proof: [u8; VRF_PROOF_LENGTH]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
 
-                Self::process_change_protocol_mint_account(
-                    accounts_info,
-                    program_id,
+        let randomness_request_account_info = next_account_info(accounts_info)?;
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        // validate randomness-request account
+        RandomnessRequest::validate_randomness_request_account(
+            randomness_request_account_info,
+            program_id
+        )?;
+
+        let mut randomness_request = RandomnessRequest::deserialize(
+            &mut &randomness_request_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(
+            lottery_account_info,
+            program_id
+        )?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &randomness_request.lottery,
+            lottery_account_info.key,
+            LotteryError::InvalidRandomnessRequestAccount.into()
+        )?;
+
+        // unfulfilled lotteries are failed after the deadline, the tickets are refunded
+        let current_time = (Clock::get()?).unix_timestamp;
+        if lottery_account.is_randomness_fulfilment_timed_out(current_time) == true {
+            return Err(
+                LotteryError::RandomnessFulfilmentTimedOut.into()
+            );
+        };
+
+        if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
+            return Err(
+                LotteryError::LotteryWasNotSuccessfull.into()
+            );
+        };
+
+        // creator's secret must be revealed before the oracle's randomness
+        lottery_account.check_secret_is_revealed(current_time)?;
+
+        // the proof must be the oracle's vrf proof over the request's message
+        randomness_request.fulfil(
+            randomness_request_account_info.key,
+            &proof,
+            current_time
+        )?;
+
+        randomness_request.serialize(
+            &mut &mut randomness_request_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        lottery_account.is_randomness_fulfilled = true;
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        sol_log("Randomness fulfilled.");
+
+        Ok(())
+    }
+
+    pub fn process_add_prize_mint(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        prize_amount: u64
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let lottery_account_authority_account_info = next_account_info(accounts_info)?;
+        let funding_account_info = next_account_info(accounts_info)?;
+        let prize_mint_account_info = next_account_info(accounts_info)?;
+        let lottery_associated_prize_token_account_info = next_account_info(accounts_info)?;
+        let funding_prize_token_account_info = next_account_info(accounts_info)?;
+        let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let associated_token_program_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        // We don't need this check BUT to be developer-friendly we performed this check.
+        if associated_token_program_account_info.key != &ASSOCIATED_TOKEN_PROGRAM_ID {
+            return Err(
+                ProgramError::IncorrectProgramId
+            );
+        };
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(lottery_account_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // check is_pause flag
+        config_account.check_is_pause()?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(
+            lottery_account_info,
+            program_id
+        )?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &lottery_account.authority,
+            lottery_account_authority_account_info.key,
+            LotteryError::InvalidLotteryAccountAuthority.into()
+        )?;
+
+        // prize mints can only be added before "starting_time" (no tickets in the ledger yet)
+        let current_time = (Clock::get()?).unix_timestamp;
+        if lottery_account.is_not_started(current_time) == false {
+            return Err(
+                LotteryError::InvalidLotteryState.into()
+            );
+        };
+
+        if prize_amount == 0 {
+            return Err(
+                LotteryError::InvalidFundAmount.into()
+            );
+        };
+
+        lottery_account.validate_prize_mint(
+            prize_mint_account_info.key,
+            &config_account.usdc_mint_account
+        )?;
+
+        check_token_program_account_of_mint(
+            standard_token_program_account_info,
+            prize_mint_account_info
+        )?;
+
+        check_mint_extensions(prize_mint_account_info)?;
+
+        //  token-2022 transfer fees -> the lottery records the prize amount it actually receives
+        let received_prize_amount = prize_amount
+            .checked_sub(get_transfer_fee(prize_mint_account_info, prize_amount)?)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        // handle lottery's prize ata
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                prize_mint_account_info.key,
+                prize_mint_account_info.owner
+            ),
+            lottery_associated_prize_token_account_info.key,
+            LotteryError::InvalidLotteryPrizeAssociatedTokenAccount.into()
+        )?;
+
+        invoke(
+            &create_associated_token_account_idempotent(
+                funding_account_info.key,
+                lottery_account_info.key,
+                prize_mint_account_info.key,
+                standard_token_program_account_info.key
+            ),
+            &[
+                funding_account_info.clone(),
+                lottery_associated_prize_token_account_info.clone(),
+                lottery_account_info.clone(),
+                prize_mint_account_info.clone(),
+                system_program_account_info.clone(),
+                standard_token_program_account_info.clone()
+            ]
+        )?;
+        sol_log("Lottery's prize token account activated.");
+
+        let MintAccount { decimals, .. } = unpack_mint_account(prize_mint_account_info)?;
+
+        invoke(
+            &transfer_spl_checked(
+                standard_token_program_account_info.key,
+                funding_prize_token_account_info.key,
+                prize_mint_account_info.key,
+                lottery_associated_prize_token_account_info.key,
+                funding_account_info.key,
+                &[],
+                prize_amount,
+                decimals
+            )?,
+            &[
+                funding_prize_token_account_info.clone(),
+                prize_mint_account_info.clone(),
+                lottery_associated_prize_token_account_info.clone(),
+                funding_account_info.clone()
+            ]
+        )?;
+        sol_log("Prize tokens transfered.");
+
+        // the lottery account grows by a prize mint, the winners space & the (empty) ticket ledger move with "initial_bytes"
+        let rent_sysvar_account = Rent::get()?;
+        let rent_exempt = (
+            rent_sysvar_account.lamports_per_byte_year
+                .checked_mul(PRIZE_MINT_INFO_LENGTH as u64)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+        ).checked_mul(rent_sysvar_account.exemption_threshold as u64).ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        invoke(
+            &transfer_lamports(
+                funding_account_info.key,
+                lottery_account_info.key,
+                rent_exempt
+            ),
+            &[
+                funding_account_info.clone(),
+                lottery_account_info.clone()
+            ]
+        )?;
+        sol_log("Rent-exempt lamports transfered to the lottery account.");
+
+        let lottery_new_data_size = lottery_account_info
+            .data_len()
+            .checked_add(PRIZE_MINT_INFO_LENGTH)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        lottery_account_info
+            .realloc(lottery_new_data_size, false)
+            .map_err::<ProgramError, _>(|_| LotteryError::ReallocationFailed.into())?;
+
+        lottery_account.initial_bytes = lottery_account.initial_bytes
+            .checked_add(PRIZE_MINT_INFO_LENGTH as u64)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        lottery_account.prize_mints.push(
+            (
+                *prize_mint_account_info.key,
+                received_prize_amount
+            )
+        );
+        lottery_account.prize_token_accounts_amount = lottery_account.prize_token_accounts_amount
+            .checked_add(1)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        solana_program::msg!(
+            "Prize mint added => {} - Prize-Amount => {}",
+            prize_mint_account_info.key,
+            spl_token::amount_to_ui_amount(received_prize_amount, decimals)
+        );
+
+        Ok(())
+    }
+
+    pub fn process_create_lottery_series(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        jackpot_share: BasisPoints,
+        house_slots: u32,
+        series_description: String
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_series_account_info = next_account_info(accounts_info)?;
+        let lottery_series_authority_account_info = next_account_info(accounts_info)?;
+        let funding_account_info = next_account_info(accounts_info)?;
+        let usdc_mint_account_info = next_account_info(accounts_info)?;
+        let series_jackpot_vault_account_info = next_account_info(accounts_info)?;
+        let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let associated_token_program_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        // We don't need this check BUT to be developer-friendly we performed this check.
+        if associated_token_program_account_info.key != &ASSOCIATED_TOKEN_PROGRAM_ID {
+            return Err(
+                ProgramError::IncorrectProgramId
+            );
+        };
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(lottery_series_authority_account_info)?;
+
+        check_account_is_raw(lottery_series_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // check is_pause flag
+        config_account.check_is_pause()?;
+
+        // check series' description-length
+        if series_description.len() > config_account.max_lottery_description_bytes as usize {
+            return Err(
+                LotteryError::MaxLotteryDescriptionBytesExceeded.into()
+            );
+        };
+
+        LotterySeries::validate_jackpot_share(jackpot_share)?;
+
+        let series_literal_seed = get_lottery_literal_seed(&series_description);
+        let (
+            lottery_series_pda_addr,
+            lottery_series_pda_canonical_bump
+        ) = Pubkey::try_find_program_address(
+            &[
+                LOTTERY_SERIES_ACCOUNT_SEED.as_bytes(),
+                lottery_series_authority_account_info.key.to_bytes().as_slice(),
+                series_literal_seed.as_slice()
+            ],
+            program_id
+        ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?;
+
+        check_accounts_key_to_be_identical(
+            &lottery_series_pda_addr,
+            lottery_series_account_info.key,
+            ProgramError::InvalidSeeds
+        )?;
+
+        // create the lottery-series account
+        let seeds: &[&[u8]] = &[
+            LOTTERY_SERIES_ACCOUNT_SEED.as_bytes(),
+            &lottery_series_authority_account_info.key.to_bytes(),
+            &series_literal_seed,
+            &[ lottery_series_pda_canonical_bump ]
+        ];
+        create_pda_account(
+            lottery_series_account_info,
+            funding_account_info,
+            LotterySeries::LEN,
+            program_id,
+            seeds
+        )?;
+        sol_log("Lottery-series account created.");
+
+        let lottery_series_account = LotterySeries::new(
+            lottery_series_pda_canonical_bump,
+            *lottery_series_authority_account_info.key,
+            series_literal_seed,
+            jackpot_share,
+            house_slots,
+            (Clock::get()?).unix_timestamp
+        );
+
+        lottery_series_account.serialize(
+            &mut &mut lottery_series_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+        sol_log("Lottery-series account initialized.");
+
+        // handle series' jackpot vault (usdc ata)
+        check_accounts_key_to_be_identical(
+            usdc_mint_account_info.key,
+            &config_account.usdc_mint_account,
+            LotteryError::InvalidUsdcMintAccount.into()
+        )?;
+
+        check_token_program_account_of_mint(
+            standard_token_program_account_info,
+            usdc_mint_account_info
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_series_account_info.key,
+                usdc_mint_account_info.key,
+                usdc_mint_account_info.owner
+            ),
+            series_jackpot_vault_account_info.key,
+            LotteryError::InvalidSeriesJackpotVaultAccount.into()
+        )?;
+
+        invoke(
+            &create_associated_token_account_idempotent(
+                funding_account_info.key,
+                lottery_series_account_info.key,
+                usdc_mint_account_info.key,
+                standard_token_program_account_info.key
+            ),
+            &[
+                funding_account_info.clone(),
+                series_jackpot_vault_account_info.clone(),
+                lottery_series_account_info.clone(),
+                usdc_mint_account_info.clone(),
+                system_program_account_info.clone(),
+                standard_token_program_account_info.clone()
+            ]
+        )?;
+        sol_log("Series' jackpot vault activated.");
+
+        Ok(())
+    }
+
+    pub fn process_join_lottery_series(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let lottery_account_authority_account_info = next_account_info(accounts_info)?;
+        let funding_account_info = next_account_info(accounts_info)?;
+        let lottery_series_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(lottery_account_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(
+            lottery_account_info,
+            program_id
+        )?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &lottery_account.authority,
+            lottery_account_authority_account_info.key,
+            LotteryError::InvalidLotteryAccountAuthority.into()
+        )?;
+
+        // validate lottery-series account, rounds are added by the series' authority
+        LotterySeries::validate_lottery_series_account(
+            lottery_series_account_info,
+            program_id
+        )?;
+
+        let mut lottery_series_account = LotterySeries::deserialize(
+            &mut &lottery_series_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &lottery_series_account.authority,
+            lottery_account_authority_account_info.key,
+            LotteryError::InvalidLotteryAccountAuthority.into()
+        )?;
+
+        // a lottery joins a series before "starting_time" (no tickets in the ledger yet)
+        let current_time = (Clock::get()?).unix_timestamp;
+        if lottery_account.is_not_started(current_time) == false {
+            return Err(
+                LotteryError::InvalidLotteryState.into()
+            );
+        };
+
+        if lottery_account.series_jackpot.is_some() == true {
+            return Err(
+                LotteryError::LotterySeriesAlreadyJoined.into()
+            );
+        };
+
+        // rounds follow each other like in "StartNextRound" -> the previous round has ended (or ends before the lottery starts)
+        let remaining_accounts_info = accounts_info.as_slice();
+        if remaining_accounts_info.len() != if lottery_series_account.last_round.is_some() { 1 } else { 0 } {
+            return Err(
+                LotteryError::InvalidPreviousRoundAccount.into()
+            );
+        };
+
+        if let Some(last_round) = lottery_series_account.last_round {
+            let previous_round_account_info = &remaining_accounts_info[0];
+
+            check_accounts_key_to_be_identical(
+                &last_round,
+                previous_round_account_info.key,
+                LotteryError::InvalidPreviousRoundAccount.into()
+            )?;
+
+            // a closed round (its account is gone) has already ended
+            if previous_round_account_info.owner == program_id {
+                Lottery::validate_lottery_account(
+                    previous_round_account_info,
+                    program_id
+                )?;
+
+                let previous_round_account = Lottery::deserialize(
+                    &mut &previous_round_account_info.data.try_borrow().unwrap()[..]
+                )?;
+
+                if
+                    previous_round_account.is_ended(current_time) == false &&
+                    previous_round_account.ending_time > lottery_account.starting_time
+                {
+                    return Err(
+                        LotteryError::PreviousRoundNotEnded.into()
+                    );
+                };
+            };
+        };
+
+        // the lottery account grows by the series jackpot, the winners space & the (empty) ticket ledger move with "initial_bytes"
+        let rent_sysvar_account = Rent::get()?;
+        let rent_exempt = (
+            rent_sysvar_account.lamports_per_byte_year
+                .checked_mul(SERIES_JACKPOT_LENGTH as u64)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+        ).checked_mul(rent_sysvar_account.exemption_threshold as u64).ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        invoke(
+            &transfer_lamports(
+                funding_account_info.key,
+                lottery_account_info.key,
+                rent_exempt
+            ),
+            &[
+                funding_account_info.clone(),
+                lottery_account_info.clone()
+            ]
+        )?;
+        sol_log("Rent-exempt lamports transfered to the lottery account.");
+
+        let lottery_new_data_size = lottery_account_info
+            .data_len()
+            .checked_add(SERIES_JACKPOT_LENGTH)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        lottery_account_info
+            .realloc(lottery_new_data_size, false)
+            .map_err::<ProgramError, _>(|_| LotteryError::ReallocationFailed.into())?;
+
+        lottery_account.initial_bytes = lottery_account.initial_bytes
+            .checked_add(SERIES_JACKPOT_LENGTH as u64)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        lottery_account.series_jackpot = Some(
+            SeriesJackpot {
+                series: *lottery_series_account_info.key,
+                jackpot_share: lottery_series_account.jackpot_share,
+                house_slots: lottery_series_account.house_slots,
+                ..SeriesJackpot::default()
+            }
+        );
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        lottery_series_account.rounds_total_amount = lottery_series_account.rounds_total_amount
+            .checked_add(1)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        lottery_series_account.last_round = Some(*lottery_account_info.key);
+
+        lottery_series_account.serialize(
+            &mut &mut lottery_series_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        solana_program::msg!(
+            "Lottery joined the series => {} - Round => {}",
+            lottery_series_account_info.key,
+            lottery_series_account.rounds_total_amount
+        );
+
+        Ok(())
+    }
+
+    pub fn process_withdraw_series_jackpot(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let user_account_info = next_account_info(accounts_info)?;
+        let user_account_authority_account_info = next_account_info(accounts_info)?;
+        let lottery_series_account_info = next_account_info(accounts_info)?;
+        let series_jackpot_vault_account_info = next_account_info(accounts_info)?;
+        let fund_receiver_usdc_token_account_info = next_account_info(accounts_info)?;
+        let usdc_mint_account_info = next_account_info(accounts_info)?;
+        let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(user_account_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        // validate usdc mint account
+        compare_usdc_mint_account_with_config_global_account_info(
+            config_global_account_info,
+            usdc_mint_account_info.key
+        )?;
+
+        check_token_program_account_of_mint(
+            standard_token_program_account_info,
+            usdc_mint_account_info
+        )?;
+
+        let current_time = (Clock::get()?).unix_timestamp;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // validate user account
+        User::validate_user_account(
+            user_account_info,
+            program_id,
+            lottery_account_info.key,
+            user_account_authority_account_info.key
+        )?;
+
+        // validate lottery state
+        if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
+            return Err(
+                LotteryError::LotteryWasNotSuccessfull.into()
+            );
+        };
+        if lottery_account.is_ended_successfuly == false {
+            return Err(
+                LotteryError::WinnersNotSelected.into()
+            );
+        };
+
+        // validate lottery-series account & its jackpot vault
+        LotterySeries::validate_lottery_series_account(
+            lottery_series_account_info,
+            program_id
+        )?;
+
+        // the round's jackpot is fixed by "EndLotteryAndPickWinners", see "Lottery::reserve_series_jackpot_amount"
+        let SeriesJackpot { series, amount: jackpot_amount, .. } = lottery_account.series_jackpot.ok_or::<ProgramError>(
+            LotteryError::InvalidLotteryType.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &series,
+            lottery_series_account_info.key,
+            LotteryError::InvalidLotterySeriesAccount.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_series_account_info.key,
+                usdc_mint_account_info.key,
+                usdc_mint_account_info.owner
+            ),
+            series_jackpot_vault_account_info.key,
+            LotteryError::InvalidSeriesJackpotVaultAccount.into()
+        )?;
+
+        let mut lottery_series_account = LotterySeries::deserialize(
+            &mut &lottery_series_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // validate user_account as winner, each rank pays its share of the round's jackpot
+        let winning_ranks = lottery_account.claim_series_jackpot_ranks(user_account_info.key)?;
+
+        let mut usdc_to_transfer = 0u64;
+        for rank in winning_ranks.iter().copied() {
+            usdc_to_transfer = usdc_to_transfer
+                .checked_add(lottery_account.get_prize_share(jackpot_amount, rank)?)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        };
+
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+        invoke_signed(
+            &transfer_spl_checked(
+                standard_token_program_account_info.key,
+                series_jackpot_vault_account_info.key,
+                usdc_mint_account_info.key,
+                fund_receiver_usdc_token_account_info.key,
+                lottery_series_account_info.key,
+                &[],
+                usdc_to_transfer,
+                decimals
+            )?,
+            &[
+                series_jackpot_vault_account_info.clone(),
+                usdc_mint_account_info.clone(),
+                fund_receiver_usdc_token_account_info.clone(),
+                lottery_series_account_info.clone()
+            ],
+            &[
+                &[
+                    LOTTERY_SERIES_ACCOUNT_SEED.as_bytes(),
+                    &lottery_series_account.authority.to_bytes(),
+                    lottery_series_account.series_literal_seed.as_slice(),
+                    &[ lottery_series_account.canonical_bump ]
+                ]
+            ]
+        )?;
+        sol_log("Congratulation! series jackpot withdrawed successfully.");
+
+        lottery_series_account.reserved_jackpot_amount = lottery_series_account.reserved_jackpot_amount
+            .checked_sub(usdc_to_transfer)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        lottery_series_account.serialize(
+            &mut &mut lottery_series_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        Ok(())
+    }
+
+    pub fn process_set_lottery_series_round_template(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        round_template: RoundTemplate
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_series_account_info = next_account_info(accounts_info)?;
+        let lottery_series_authority_account_info = next_account_info(accounts_info)?;
+        let funding_account_info = next_account_info(accounts_info)?;
+        let prize_mint_account_info = next_account_info(accounts_info)?;
+        let series_prize_vault_pda_account_info = next_account_info(accounts_info)?;
+        let series_prize_vault_account_info = next_account_info(accounts_info)?;
+        let prize_token_program_account_info = next_account_info(accounts_info)?;
+        let associated_token_program_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        // We don't need this check BUT to be developer-friendly we performed this check.
+        if associated_token_program_account_info.key != &ASSOCIATED_TOKEN_PROGRAM_ID {
+            return Err(
+                ProgramError::IncorrectProgramId
+            );
+        };
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(lottery_series_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // check is_pause flag
+        config_account.check_is_pause()?;
+
+        // validate lottery-series account
+        LotterySeries::validate_lottery_series_account(
+            lottery_series_account_info,
+            program_id
+        )?;
+
+        let mut lottery_series_account = LotterySeries::deserialize(
+            &mut &lottery_series_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &lottery_series_account.authority,
+            lottery_series_authority_account_info.key,
+            LotteryError::InvalidLotteryAccountAuthority.into()
+        )?;
+
+        round_template.validate(&config_account)?;
+
+        // the prize vault holds the tokens of the first prize mint, later templates keep it
+        if let Some(RoundTemplate { prize_mint, .. }) = lottery_series_account.round_template {
+            if prize_mint != round_template.prize_mint {
+                return Err(
+                    LotteryError::SeriesPrizeMintLocked.into()
+                );
+            };
+        };
+
+        check_accounts_key_to_be_identical(
+            prize_mint_account_info.key,
+            &round_template.prize_mint,
+            LotteryError::InvalidPrizeMintAccount.into()
+        )?;
+
+        check_token_program_account_of_mint(
+            prize_token_program_account_info,
+            prize_mint_account_info
+        )?;
+
+        check_mint_extensions(prize_mint_account_info)?;
+
+        // handle series' prize vault (series-prize-vault pda's associated prize token account)
+        let (
+            series_prize_vault_pda_addr,
+            series_prize_vault_pda_canonical_bump
+        ) = get_series_prize_vault_account_pda(
+            lottery_series_account_info.key,
+            program_id
+        ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?;
+
+        check_accounts_key_to_be_identical(
+            &series_prize_vault_pda_addr,
+            series_prize_vault_pda_account_info.key,
+            LotteryError::InvalidSeriesPrizeVaultAccount.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                series_prize_vault_pda_account_info.key,
+                prize_mint_account_info.key,
+                prize_token_program_account_info.key
+            ),
+            series_prize_vault_account_info.key,
+            LotteryError::InvalidSeriesPrizeVaultAccount.into()
+        )?;
+
+        invoke(
+            &create_associated_token_account_idempotent(
+                funding_account_info.key,
+                series_prize_vault_pda_account_info.key,
+                prize_mint_account_info.key,
+                prize_token_program_account_info.key
+            ),
+            &[
+                funding_account_info.clone(),
+                series_prize_vault_account_info.clone(),
+                series_prize_vault_pda_account_info.clone(),
+                prize_mint_account_info.clone(),
+                system_program_account_info.clone(),
+                prize_token_program_account_info.clone()
+            ]
+        )?;
+        sol_log("Series' prize vault activated.");
+
+        lottery_series_account.round_template = Some(round_template);
+        lottery_series_account.prize_vault_canonical_bump = series_prize_vault_pda_canonical_bump;
+
+        lottery_series_account.serialize(
+            &mut &mut lottery_series_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+        sol_log("Series' round template updated.");
+
+        Ok(())
+    }
+
+    pub fn process_start_next_round(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_series_account_info = next_account_info(accounts_info)?;
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let usdc_mint_account_info = next_account_info(accounts_info)?;
+        let lottery_associated_usdc_token_account_info = next_account_info(accounts_info)?;
+        let series_usdc_vault_account_info = next_account_info(accounts_info)?;
+        let prize_mint_account_info = next_account_info(accounts_info)?;
+        let lottery_associated_prize_token_account_info = next_account_info(accounts_info)?;
+        let series_prize_vault_pda_account_info = next_account_info(accounts_info)?;
+        let series_prize_vault_account_info = next_account_info(accounts_info)?;
+        let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let token_2022_program_account_info = next_account_info(accounts_info)?;
+        let associated_token_program_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        // We don't need this check BUT to be developer-friendly we performed this check.
+        if associated_token_program_account_info.key != &ASSOCIATED_TOKEN_PROGRAM_ID {
+            return Err(
+                ProgramError::IncorrectProgramId
+            );
+        };
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_raw(lottery_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // check is_pause flag
+        config_account.check_is_pause()?;
+
+        // validate lottery-series account & its template
+        LotterySeries::validate_lottery_series_account(
+            lottery_series_account_info,
+            program_id
+        )?;
+
+        let mut lottery_series_account = LotterySeries::deserialize(
+            &mut &lottery_series_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        if lottery_series_account.round_template.is_none() == true {
+            return Err(
+                LotteryError::RoundTemplateNotFound.into()
+            );
+        };
+        let round_template = lottery_series_account.round_template.unwrap();
+
+        round_template.validate(&config_account)?;
+
+        let current_time = (Clock::get()?).unix_timestamp;
+
+        // round N+1 starts once round N has ended -> (previous round's lottery account) after the series' first round
+        let remaining_accounts_info = accounts_info.as_slice();
+        if remaining_accounts_info.len() != if lottery_series_account.last_round.is_some() { 1 } else { 0 } {
+            return Err(
+                LotteryError::InvalidPreviousRoundAccount.into()
+            );
+        };
+
+        if let Some(last_round) = lottery_series_account.last_round {
+            let previous_round_account_info = &remaining_accounts_info[0];
+
+            check_accounts_key_to_be_identical(
+                &last_round,
+                previous_round_account_info.key,
+                LotteryError::InvalidPreviousRoundAccount.into()
+            )?;
+
+            // a closed round (its account is gone) has already ended
+            if previous_round_account_info.owner == program_id {
+                Lottery::validate_lottery_account(
+                    previous_round_account_info,
+                    program_id
+                )?;
+
+                let previous_round_account = Lottery::deserialize(
+                    &mut &previous_round_account_info.data.try_borrow().unwrap()[..]
+                )?;
+
+                if previous_round_account.is_ended(current_time) == false {
+                    return Err(
+                        LotteryError::PreviousRoundNotEnded.into()
+                    );
+                };
+            };
+        };
+
+        // round's lottery account -> lottery account of the series' authority, its literal seed comes from the series & the round number
+        let round_number = lottery_series_account.rounds_total_amount
+            .checked_add(1)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        let lottery_description = LotterySeries::get_round_description(
+            lottery_series_account_info.key,
+            round_number
+        );
+
+        let (
+            lottery_pda_addr,
+            lottery_pda_canonical_bump
+        ) = Pubkey::try_find_program_address(
+            &[
+                LOTTERY_ACCOUNT_SEED.as_bytes(),
+                lottery_series_account.authority.to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            program_id
+        ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?;
+
+        check_accounts_key_to_be_identical(
+            &lottery_pda_addr,
+            lottery_account_info.key,
+            ProgramError::InvalidSeeds
+        )?;
+
+        // handle token programs & mint extensions
+        check_accounts_key_to_be_identical(
+            usdc_mint_account_info.key,
+            &config_account.usdc_mint_account,
+            LotteryError::InvalidUsdcMintAccount.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            prize_mint_account_info.key,
+            &round_template.prize_mint,
+            LotteryError::InvalidPrizeMintAccount.into()
+        )?;
+
+        let usdc_token_program_account_info = get_token_program_account_info_of_mint(
+            usdc_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+        let prize_token_program_account_info = get_token_program_account_info_of_mint(
+            prize_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+
+        check_mint_extensions(usdc_mint_account_info)?;
+        check_mint_extensions(prize_mint_account_info)?;
+
+        // validate series' prize vault
+        check_accounts_key_to_be_identical(
+            &Pubkey::create_program_address(
+                &[
+                    SERIES_PRIZE_VAULT_ACCOUNT_SEED.as_bytes(),
+                    lottery_series_account_info.key.to_bytes().as_slice(),
+                    &[ lottery_series_account.prize_vault_canonical_bump ]
+                ],
+                program_id
+            )?,
+            series_prize_vault_pda_account_info.key,
+            LotteryError::InvalidSeriesPrizeVaultAccount.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                series_prize_vault_pda_account_info.key,
+                prize_mint_account_info.key,
+                prize_token_program_account_info.key
+            ),
+            series_prize_vault_account_info.key,
+            LotteryError::InvalidSeriesPrizeVaultAccount.into()
+        )?;
+
+        // validate series' usdc vault, it pays the lottery-creation-fee
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                series_prize_vault_pda_account_info.key,
+                usdc_mint_account_info.key,
+                usdc_token_program_account_info.key
+            ),
+            series_usdc_vault_account_info.key,
+            LotteryError::InvalidSeriesUsdcVaultAccount.into()
+        )?;
+
+        // the series-prize-vault pda pays the rents (pre-funded by the series' authority, which gets them back when the round is closed)
+        let series_prize_vault_signer_seeds: &[&[u8]] = &[
+            SERIES_PRIZE_VAULT_ACCOUNT_SEED.as_bytes(),
+            &lottery_series_account_info.key.to_bytes(),
+            &[ lottery_series_account.prize_vault_canonical_bump ]
+        ];
+
+        //  token-2022 transfer fees -> the round records the amounts it actually receives
+        let received_lottery_creation_fee = config_account.lottery_creation_fee
+            .checked_sub(get_transfer_fee(usdc_mint_account_info, config_account.lottery_creation_fee)?)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        let received_fund_amount = round_template.fund_amount
+            .checked_sub(get_transfer_fee(prize_mint_account_info, round_template.fund_amount)?)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        if received_fund_amount % (round_template.winners_count as u64) != 0 {
+            return Err(
+                LotteryError::WinnersAndFundAmountMismatch.into()
+            );
+        };
+
+        let (
+            _,
+            prize_vault_pda_canonical_bump
+        ) = get_prize_vault_account_pda(
+            lottery_account_info.key,
+            program_id
+        ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?;
+
+        let ending_time = current_time
+            .checked_add(round_template.round_duration as Time)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        let mut lottery_account = Lottery::new(
+            lottery_pda_canonical_bump,
+            received_fund_amount,
+            received_lottery_creation_fee,
+            round_template.winners_count,
+            current_time,
+            ending_time,
+            current_time,
+            round_template.minimum_tickets_amount_required_to_be_sold,
+            round_template.ticket_price,
+            *prize_mint_account_info.key,
+            lottery_series_account.authority,
+            None,
+            None,
+            RandomnessSource::PythPriceFeeds,
+            None,
+            None,
+            0,
+            prize_vault_pda_canonical_bump,
+            None,
+            None,
+            None,
+            lottery_description.clone()
+        );
+
+        // the round joins the series at creation
+        lottery_account.series_jackpot = Some(
+            SeriesJackpot {
+                series: *lottery_series_account_info.key,
+                jackpot_share: lottery_series_account.jackpot_share,
+                house_slots: lottery_series_account.house_slots,
+                ..SeriesJackpot::default()
+            }
+        );
+
+        let data_size = lottery_account
+            .try_to_vec()
+            .unwrap()
+            .len();
+
+        let space_needed_per_winner = size_of::<Pubkey>() + size_of::<Rank>() + size_of::<bool>();
+        let total_space_needed_for_winners = round_template.winners_count as usize * space_needed_per_winner;
+
+        let total_data_size = data_size + total_space_needed_for_winners;
+
+        let lottery_rent_exempt = Rent::get()?.minimum_balance(total_data_size);
+        if lottery_account_info.lamports() < lottery_rent_exempt {
+            invoke_signed(
+                &transfer_lamports(
+                    series_prize_vault_pda_account_info.key,
+                    lottery_account_info.key,
+                    lottery_rent_exempt
+                        .checked_sub(lottery_account_info.lamports())
+                        .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+                ),
+                &[
+                    series_prize_vault_pda_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ series_prize_vault_signer_seeds ]
+            )?;
+        };
+
+        // create the round's lottery-account
+        let seeds: &[&[u8]] = &[
+            LOTTERY_ACCOUNT_SEED.as_bytes(),
+            &lottery_series_account.authority.to_bytes(),
+            &get_lottery_literal_seed(&lottery_description),
+            &[ lottery_pda_canonical_bump ]
+        ];
+        create_pda_account(
+            lottery_account_info,
+            series_prize_vault_pda_account_info,
+            total_data_size,
+            program_id,
+            seeds
+        )?;
+        sol_log("Lottery account created.");
+
+        lottery_account.initial_bytes = total_data_size
+            .try_into()
+            .unwrap();
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+        sol_log("Lottery account initialized.");
+
+        // handle usdc ata, the series' usdc vault pays the lottery-creation-fee
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                usdc_mint_account_info.key,
+                usdc_token_program_account_info.key
+            ),
+            lottery_associated_usdc_token_account_info.key,
+            LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
+        )?;
+
+        invoke_signed(
+            &create_associated_token_account_idempotent(
+                series_prize_vault_pda_account_info.key,
+                lottery_account_info.key,
+                usdc_mint_account_info.key,
+                usdc_token_program_account_info.key
+            ),
+            &[
+                series_prize_vault_pda_account_info.clone(),
+                lottery_associated_usdc_token_account_info.clone(),
+                lottery_account_info.clone(),
+                usdc_mint_account_info.clone(),
+                system_program_account_info.clone(),
+                usdc_token_program_account_info.clone()
+            ],
+            &[ series_prize_vault_signer_seeds ]
+        )?;
+        sol_log("Lottery's usdc token account activated.");
+
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+        invoke_signed(
+            &transfer_spl_checked(
+                usdc_token_program_account_info.key,
+                series_usdc_vault_account_info.key,
+                usdc_mint_account_info.key,
+                lottery_associated_usdc_token_account_info.key,
+                series_prize_vault_pda_account_info.key,
+                &[],
+                config_account.lottery_creation_fee,
+                decimals
+            )?,
+            &[
+                series_usdc_vault_account_info.clone(),
+                usdc_mint_account_info.clone(),
+                lottery_associated_usdc_token_account_info.clone(),
+                series_prize_vault_pda_account_info.clone()
+            ],
+            &[ series_prize_vault_signer_seeds ]
+        )?;
+        sol_log("Lottery's creation-fee transfered.");
+
+        // handle prize ata, the prize is pulled from the series' prize vault
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                prize_mint_account_info.key,
+                prize_token_program_account_info.key
+            ),
+            lottery_associated_prize_token_account_info.key,
+            LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount.into()
+        )?;
+
+        invoke_signed(
+            &create_associated_token_account_idempotent(
+                series_prize_vault_pda_account_info.key,
+                lottery_account_info.key,
+                prize_mint_account_info.key,
+                prize_token_program_account_info.key
+            ),
+            &[
+                series_prize_vault_pda_account_info.clone(),
+                lottery_associated_prize_token_account_info.clone(),
+                lottery_account_info.clone(),
+                prize_mint_account_info.clone(),
+                system_program_account_info.clone(),
+                prize_token_program_account_info.clone()
+            ],
+            &[ series_prize_vault_signer_seeds ]
+        )?;
+        sol_log("Lottery's arbitrary token account activated.");
+
+        let MintAccount { decimals, .. } = unpack_mint_account(prize_mint_account_info)?;
+
+        invoke_signed(
+            &transfer_spl_checked(
+                prize_token_program_account_info.key,
+                series_prize_vault_account_info.key,
+                prize_mint_account_info.key,
+                lottery_associated_prize_token_account_info.key,
+                series_prize_vault_pda_account_info.key,
+                &[],
+                round_template.fund_amount,
+                decimals
+            )?,
+            &[
+                series_prize_vault_account_info.clone(),
+                prize_mint_account_info.clone(),
+                lottery_associated_prize_token_account_info.clone(),
+                series_prize_vault_pda_account_info.clone()
+            ],
+            &[ series_prize_vault_signer_seeds ]
+        )?;
+        sol_log("Funds transfered from the series' prize vault.");
+
+        lottery_series_account.rounds_total_amount = round_number;
+        lottery_series_account.last_round = Some(*lottery_account_info.key);
+
+        lottery_series_account.serialize(
+            &mut &mut lottery_series_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        solana_program::msg!(
+            "Round started => {} - Series => {} - Round => {}",
+            lottery_account_info.key,
+            lottery_series_account_info.key,
+            round_number
+        );
+
+        Ok(())
+    }
+
+    pub fn process_settle_failed_lottery(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // check is_pause flag
+        config_account.check_is_pause()?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(
+            lottery_account_info,
+            program_id
+        )?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        let current_time = (Clock::get()?).unix_timestamp;
+        if lottery_account.get_lottery_state(current_time) != LotteryState::Failed {
+            return Err(
+                LotteryError::InvalidLotteryState.into()
+            );
+        };
+
+        if lottery_account.settlement_bounty.is_none() == true {
+            return Err(
+                LotteryError::SettlementBountyNotFound.into()
+            );
+        };
+
+        if lottery_account.is_settlement_bounty_paid == true {
+            return Err(
+                LotteryError::SettlementBountyAlreadyPaid.into()
+            );
+        };
+
+        pay_settlement_bounty(
+            &mut lottery_account,
+            lottery_account_info,
+            &config_account.usdc_mint_account,
+            accounts_info.as_slice()
+        )?;
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+        sol_log("Failed lottery settled.");
+
+        Ok(())
+    }
+
+    pub fn process_distribute_prizes(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let funding_account_info = next_account_info(accounts_info)?;
+        let lottery_associated_arbitrary_token_account_info = next_account_info(accounts_info)?;
+        let arbitrary_mint_account_info = next_account_info(accounts_info)?;
+        let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let token_2022_program_account_info = next_account_info(accounts_info)?;
+        let associated_token_program_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        // We don't need this check BUT to be developer-friendly we performed this check.
+        if associated_token_program_account_info.key != &ASSOCIATED_TOKEN_PROGRAM_ID {
+            return Err(
+                ProgramError::IncorrectProgramId
+            );
+        };
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(funding_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        let current_time = (Clock::get()?).unix_timestamp;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // validate lottery state
+        if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
+            return Err(
+                LotteryError::LotteryWasNotSuccessfull.into()
+            );
+        };
+        if lottery_account.is_ended_successfuly == false {
+            return Err(
+                LotteryError::WinnersNotSelected.into()
+            );
+        };
+
+        // prizes besides the fund (prize mints, nft prizes, sol prize, pool) are only paid by "WithdrawLotterysWinners"
+        if
+            lottery_account.prize_mints.is_empty() == false ||
+            lottery_account.nft_prizes.as_ref().map_or(false, |nft_prizes| nft_prizes.is_empty() == false) == true ||
+            lottery_account.sol_prize_amount > 0 ||
+            lottery_account.pool_creator_share.is_some() == true
+        {
+            return Err(
+                LotteryError::PrizesNotDistributable.into()
+            );
+        };
+
+        // validate lottery arbitrary mint account
+        check_accounts_key_to_be_identical(
+            arbitrary_mint_account_info.key,
+            &lottery_account.arbitrary_mint_account_address,
+            LotteryError::InvalidArbitraryMintAccount.into()
+        )?;
+
+        // validate lottery associated arbitrary token account
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                arbitrary_mint_account_info.key,
+                arbitrary_mint_account_info.owner
+            ),
+            lottery_associated_arbitrary_token_account_info.key,
+            LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount.into()
+        )?;
+
+        let MintAccount { decimals, .. } = unpack_mint_account(arbitrary_mint_account_info)?;
+
+        let arbitrary_token_program_account_info = get_token_program_account_info_of_mint(
+            arbitrary_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+
+        // winners -> (user pda account, user's authority account, authority's associated arbitrary token account) per winner
+        let winners_accounts_info = accounts_info.as_slice();
+        if winners_accounts_info.len() % 3 != 0 {
+            return Err(
+                LotteryError::InvalidAmountOfWinnersAccounts.into()
+            );
+        };
+
+        for winner_accounts_info in winners_accounts_info.chunks(3) {
+            let user_account_info = &winner_accounts_info[0];
+            let user_account_authority_account_info = &winner_accounts_info[1];
+            let fund_receiver_arbitrary_token_account_info = &winner_accounts_info[2];
+
+            // validate user account
+            User::validate_user_account(
+                user_account_info,
+                program_id,
+                lottery_account_info.key,
+                user_account_authority_account_info.key
+            )?;
+
+            if lottery_account.winners.iter().any(|(winner, _, _)| winner == user_account_info.key) == false {
+                return Err(
+                    LotteryError::WinnerNotFound.into()
+                );
+            };
+
+            // the winner may have withdrawn already, the keeper's batch goes on
+            if lottery_account.winners.iter().any(|(winner, _, is_withdrawed)| winner == user_account_info.key && *is_withdrawed == false) == false {
+                solana_program::msg!("Prize of {} already withdrawed.", user_account_info.key);
+                continue;
+            };
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    user_account_authority_account_info.key,
+                    arbitrary_mint_account_info.key,
+                    arbitrary_token_program_account_info.key
+                ),
+                fund_receiver_arbitrary_token_account_info.key,
+                LotteryError::InvalidWinnerAssociatedTokenAccount.into()
+            )?;
+
+            invoke(
+                &create_associated_token_account_idempotent(
+                    funding_account_info.key,
+                    user_account_authority_account_info.key,
+                    arbitrary_mint_account_info.key,
+                    arbitrary_token_program_account_info.key
+                ),
+                &[
+                    funding_account_info.clone(),
+                    fund_receiver_arbitrary_token_account_info.clone(),
+                    user_account_authority_account_info.clone(),
+                    arbitrary_mint_account_info.clone(),
+                    system_program_account_info.clone(),
+                    arbitrary_token_program_account_info.clone()
+                ]
+            )?;
+
+            // each unclaimed rank pays its own prize, ranks are marked as withdrawn
+            let winning_ranks = lottery_account.get_winner_info(user_account_info.key)?;
+            let mut tokens_amount_to_transfer = 0u64;
+            for rank in winning_ranks.iter().copied() {
+                tokens_amount_to_transfer = tokens_amount_to_transfer
+                    .checked_add(lottery_account.get_prize_amount(rank)?)
+                    .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            };
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    arbitrary_token_program_account_info.key,
+                    lottery_associated_arbitrary_token_account_info.key,
+                    arbitrary_mint_account_info.key,
+                    fund_receiver_arbitrary_token_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    tokens_amount_to_transfer,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_arbitrary_token_account_info.clone(),
+                    arbitrary_mint_account_info.clone(),
+                    fund_receiver_arbitrary_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[
+                    &[
+                        LOTTERY_ACCOUNT_SEED.as_bytes(),
+                        &lottery_account.authority.to_bytes(),
+                        get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                        &[ lottery_account.canonical_bump ]
+                    ]
+                ]
+            )?;
+            solana_program::msg!("Prize of {} distributed.", user_account_info.key);
+        };
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        Ok(())
+    }
+
+    pub fn process_change_unclaimed_prizes_policy(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        prize_claim_window: u32,
+        unclaimed_prizes_receiver: UnclaimedPrizesReceiver
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let config_account_authority_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(config_account_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // validate authority account
+        check_accounts_key_to_be_identical(
+            &get_config_account_authority(config_global_account_info),
+            config_account_authority_account_info.key,
+            LotteryError::InvalidConfigAuthority.into()
+        )?;
+
+        let mut config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        config_account.prize_claim_window = prize_claim_window;
+        config_account.unclaimed_prizes_receiver = unclaimed_prizes_receiver;
+
+        config_account.serialize(
+            &mut &mut config_global_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        sol_log("Config account updated.");
+
+        Ok(())
+    }
+
+    pub fn process_reclaim_unclaimed_prizes(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let reclaimer_account_info = next_account_info(accounts_info)?;
+        let fund_receiver_rent_exempt_account_info = next_account_info(accounts_info)?;
+        let lottery_associated_arbitrary_token_account_info = next_account_info(accounts_info)?;
+        let fund_receiver_arbitrary_token_account_info = next_account_info(accounts_info)?;
+        let arbitrary_mint_account_info = next_account_info(accounts_info)?;
+        let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let token_2022_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let treasury_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(reclaimer_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // check is_pause flag
+        config_account.check_is_pause()?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // validate lottery state
+        let current_time = (Clock::get()?).unix_timestamp;
+        if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
+            return Err(
+                LotteryError::LotteryWasNotSuccessfull.into()
+            );
+        };
+        if lottery_account.is_ended_successfuly == false {
+            return Err(
+                LotteryError::WinnersNotSelected.into()
+            );
+        };
+
+        if lottery_account.is_unclaimed_prizes_reclaimed == true {
+            return Err(
+                LotteryError::UnclaimedPrizesAlreadyReclaimed.into()
+            );
+        };
+
+        lottery_account.check_claim_window(
+            config_account.prize_claim_window,
+            current_time
+        )?;
+
+        // validate reclaimer -> lottery's authority or treasury's authority, per config policy
+        check_accounts_key_to_be_identical(
+            treasury_account_info.key,
+            &config_account.treasury,
+            LotteryError::InvalidTreasuryAccount.into()
+        )?;
+
+        let allowed_reclaimer = match config_account.unclaimed_prizes_receiver {
+            UnclaimedPrizesReceiver::Creator => lottery_account.authority,
+            UnclaimedPrizesReceiver::Treasury => {
+                let TokenAccount { owner: treasury_authority, .. } = unpack_token_account(treasury_account_info)?;
+
+                treasury_authority
+            }
+        };
+
+        check_accounts_key_to_be_identical(
+            reclaimer_account_info.key,
+            &allowed_reclaimer,
+            LotteryError::InvalidUnclaimedPrizesReclaimer.into()
+        )?;
+
+        // validate lottery arbitrary mint account
+        check_accounts_key_to_be_identical(
+            arbitrary_mint_account_info.key,
+            &lottery_account.arbitrary_mint_account_address,
+            LotteryError::InvalidArbitraryMintAccount.into()
+        )?;
+
+        // validate lottery associated arbitrary token account
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                arbitrary_mint_account_info.key,
+                arbitrary_mint_account_info.owner
+            ),
+            lottery_associated_arbitrary_token_account_info.key,
+            LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount.into()
+        )?;
+
+        let unclaimed_ranks = lottery_account.take_unclaimed_ranks();
+        lottery_account.is_unclaimed_prizes_reclaimed = true;
+        lottery_account.is_arbitrary_token_account_closed = true;
+
+        let lottery_literal_seed = get_lottery_literal_seed(&lottery_account.lottery_description);
+        let lottery_account_signer_seeds: &[&[u8]] = &[
+            LOTTERY_ACCOUNT_SEED.as_bytes(),
+            &lottery_account.authority.to_bytes(),
+            lottery_literal_seed.as_slice(),
+            &[ lottery_account.canonical_bump ]
+        ];
+
+        let arbitrary_token_program_account_info = get_token_program_account_info_of_mint(
+            arbitrary_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+
+        // the fund -> the whole balance (rounding dust included), then the arbitrary ata is closed
+        let TokenAccount { amount: arbitrary_token_balance, .. } = unpack_token_account(lottery_associated_arbitrary_token_account_info)?;
+
+        if arbitrary_token_balance > 0 {
+            let MintAccount { decimals, .. } = unpack_mint_account(arbitrary_mint_account_info)?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    arbitrary_token_program_account_info.key,
+                    lottery_associated_arbitrary_token_account_info.key,
+                    arbitrary_mint_account_info.key,
+                    fund_receiver_arbitrary_token_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    arbitrary_token_balance,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_arbitrary_token_account_info.clone(),
+                    arbitrary_mint_account_info.clone(),
+                    fund_receiver_arbitrary_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ lottery_account_signer_seeds ]
+            )?;
+            sol_log("Unclaimed funds reclaimed.");
+        };
+
+        invoke_signed(
+            &close_token_account(
+                arbitrary_token_program_account_info.key,
+                lottery_associated_arbitrary_token_account_info.key,
+                fund_receiver_rent_exempt_account_info.key,
+                lottery_account_info.key,
+                &[]
+            )?,
+            &[
+                lottery_associated_arbitrary_token_account_info.clone(),
+                fund_receiver_rent_exempt_account_info.clone(),
+                lottery_account_info.clone()
+            ],
+            &[ lottery_account_signer_seeds ]
+        )?;
+        sol_log("Arbitrary token account closed & rent exempt lamports refunded.");
+
+        // the prize token accounts are emptied & closed, their rent-exempt lamports go to the fund-receiver
+        let mut closed_prize_token_accounts_amount = 0u16;
+
+        // prize mints -> (prize mint account, lottery's associated prize token account, fund-receiver prize token account) per prize mint
+        let remaining_accounts_info = accounts_info.as_slice();
+        if remaining_accounts_info.len() < lottery_account.prize_mints.len() * 3 {
+            return Err(
+                LotteryError::InvalidAmountOfPrizeMintAccounts.into()
+            );
+        };
+        let (
+            prize_mints_accounts_info,
+            nft_prizes_accounts_info
+        ) = remaining_accounts_info.split_at(lottery_account.prize_mints.len() * 3);
+
+        for (
+            (prize_mint, _),
+            prize_mint_accounts_info
+        ) in lottery_account.prize_mints.iter().zip(prize_mints_accounts_info.chunks(3)) {
+            let prize_mint_account_info = &prize_mint_accounts_info[0];
+            let lottery_associated_prize_token_account_info = &prize_mint_accounts_info[1];
+            let fund_receiver_prize_token_account_info = &prize_mint_accounts_info[2];
+
+            check_accounts_key_to_be_identical(
+                prize_mint_account_info.key,
+                prize_mint,
+                LotteryError::InvalidPrizeMintAccount.into()
+            )?;
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_account_info.key,
+                    prize_mint_account_info.key,
+                    prize_mint_account_info.owner
+                ),
+                lottery_associated_prize_token_account_info.key,
+                LotteryError::InvalidLotteryPrizeAssociatedTokenAccount.into()
+            )?;
+
+            // already closed by the last winner's withdrawal
+            if lottery_associated_prize_token_account_info.data_is_empty() == true {
+                continue;
+            };
+
+            let prize_token_program_account_info = get_token_program_account_info_of_mint(
+                prize_mint_account_info,
+                standard_token_program_account_info,
+                token_2022_program_account_info
+            )?;
+
+            let TokenAccount { amount: prize_token_balance, .. } = unpack_token_account(lottery_associated_prize_token_account_info)?;
+            if prize_token_balance > 0 {
+                let MintAccount { decimals, .. } = unpack_mint_account(prize_mint_account_info)?;
+
+                invoke_signed(
+                    &transfer_spl_checked(
+                        prize_token_program_account_info.key,
+                        lottery_associated_prize_token_account_info.key,
+                        prize_mint_account_info.key,
+                        fund_receiver_prize_token_account_info.key,
+                        lottery_account_info.key,
+                        &[],
+                        prize_token_balance,
+                        decimals
+                    )?,
+                    &[
+                        lottery_associated_prize_token_account_info.clone(),
+                        prize_mint_account_info.clone(),
+                        fund_receiver_prize_token_account_info.clone(),
+                        lottery_account_info.clone()
+                    ],
+                    &[ lottery_account_signer_seeds ]
+                )?;
+            };
+
+            invoke_signed(
+                &close_token_account(
+                    prize_token_program_account_info.key,
+                    lottery_associated_prize_token_account_info.key,
+                    fund_receiver_rent_exempt_account_info.key,
+                    lottery_account_info.key,
+                    &[]
+                )?,
+                &[
+                    lottery_associated_prize_token_account_info.clone(),
+                    fund_receiver_rent_exempt_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ lottery_account_signer_seeds ]
+            )?;
+
+            closed_prize_token_accounts_amount += 1;
+        };
+        if lottery_account.prize_mints.is_empty() == false {
+            sol_log("Unclaimed prize mints reclaimed.");
+        };
+
+        // nft prizes -> (nft prize mint account, lottery's associated nft token account, fund-receiver nft token account) per unclaimed rank
+        let nft_prizes = lottery_account.nft_prizes.clone().unwrap_or_default();
+        let nft_prizes_accounts_amount = if nft_prizes.is_empty() == true { 0 } else { unclaimed_ranks.len() * 3 };
+        if nft_prizes_accounts_info.len() < nft_prizes_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfNftPrizeAccounts.into()
+            );
+        };
+        let (
+            nft_prizes_accounts_info,
+            prize_vault_accounts_info
+        ) = nft_prizes_accounts_info.split_at(nft_prizes_accounts_amount);
+
+        for (
+            rank,
+            nft_prize_accounts_info
+        ) in unclaimed_ranks.iter().copied().zip(nft_prizes_accounts_info.chunks(3)) {
+            let nft_prize_mint_account_info = &nft_prize_accounts_info[0];
+            let lottery_associated_nft_token_account_info = &nft_prize_accounts_info[1];
+            let fund_receiver_nft_token_account_info = &nft_prize_accounts_info[2];
+
+            check_accounts_key_to_be_identical(
+                nft_prize_mint_account_info.key,
+                nft_prizes
+                    .get(rank as usize)
+                    .ok_or::<ProgramError>(LotteryError::WinnerNotFound.into())?,
+                LotteryError::InvalidNftPrizeMintAccount.into()
+            )?;
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_account_info.key,
+                    nft_prize_mint_account_info.key,
+                    nft_prize_mint_account_info.owner
+                ),
+                lottery_associated_nft_token_account_info.key,
+                LotteryError::InvalidLotteryPrizeAssociatedTokenAccount.into()
+            )?;
+
+            let nft_token_program_account_info = get_token_program_account_info_of_mint(
+                nft_prize_mint_account_info,
+                standard_token_program_account_info,
+                token_2022_program_account_info
+            )?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    nft_token_program_account_info.key,
+                    lottery_associated_nft_token_account_info.key,
+                    nft_prize_mint_account_info.key,
+                    fund_receiver_nft_token_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    1,
+                    0
+                )?,
+                &[
+                    lottery_associated_nft_token_account_info.clone(),
+                    nft_prize_mint_account_info.clone(),
+                    fund_receiver_nft_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ lottery_account_signer_seeds ]
+            )?;
+
+            invoke_signed(
+                &close_token_account(
+                    nft_token_program_account_info.key,
+                    lottery_associated_nft_token_account_info.key,
+                    fund_receiver_rent_exempt_account_info.key,
+                    lottery_account_info.key,
+                    &[]
+                )?,
+                &[
+                    lottery_associated_nft_token_account_info.clone(),
+                    fund_receiver_rent_exempt_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ lottery_account_signer_seeds ]
+            )?;
+
+            closed_prize_token_accounts_amount += 1;
+        };
+        if nft_prizes_accounts_amount > 0 {
+            sol_log("Unclaimed nft prizes reclaimed.");
+        };
+
+        if closed_prize_token_accounts_amount > 0 {
+            lottery_account.prize_token_accounts_amount = lottery_account.prize_token_accounts_amount
+                .checked_sub(closed_prize_token_accounts_amount)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            sol_log("Prize token accounts closed & rent exempt lamports refunded.");
+        };
+
+        // sol prize -> (prize-vault pda, fund-receiver lamports account), the unclaimed ranks' shares
+        let prize_vault_accounts_amount = if lottery_account.sol_prize_amount > 0 { 2 } else { 0 };
+        if prize_vault_accounts_info.len() < prize_vault_accounts_amount {
+            return Err(
+                LotteryError::InvalidAmountOfPrizeVaultAccounts.into()
+            );
+        };
+        let (
+            prize_vault_accounts_info,
+            pool_accounts_info
+        ) = prize_vault_accounts_info.split_at(prize_vault_accounts_amount);
+
+        if lottery_account.sol_prize_amount > 0 {
+            let prize_vault_account_info = &prize_vault_accounts_info[0];
+            let fund_receiver_sol_prize_account_info = &prize_vault_accounts_info[1];
+
+            lottery_account.validate_prize_vault_account(
+                prize_vault_account_info,
+                lottery_account_info.key,
+                program_id
+            )?;
+
+            let mut lamports_to_transfer = 0u64;
+            for rank in unclaimed_ranks.iter().copied() {
+                lamports_to_transfer = lamports_to_transfer
+                    .checked_add(lottery_account.get_prize_share(lottery_account.sol_prize_amount, rank)?)
+                    .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            };
+
+            let prize_vault_old_balance = prize_vault_account_info.lamports();
+            let fund_receiver_sol_prize_account_old_balance = fund_receiver_sol_prize_account_info.lamports();
+
+            **prize_vault_account_info.try_borrow_mut_lamports()? = prize_vault_old_balance
+                .checked_sub(lamports_to_transfer)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            **fund_receiver_sol_prize_account_info.try_borrow_mut_lamports()? = fund_receiver_sol_prize_account_old_balance
+                .checked_add(lamports_to_transfer)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            sol_log("Unclaimed sol prize reclaimed.");
+        };
+
+        // pool -> (usdc mint account, lottery's associated usdc token account, fund-receiver usdc token account), the unclaimed ranks' shares
+        if pool_accounts_info.len() != if lottery_account.pool_creator_share.is_some() { 3 } else { 0 } {
+            return Err(
+                LotteryError::InvalidAmountOfPoolAccounts.into()
+            );
+        };
+
+        if lottery_account.pool_creator_share.is_some() == true {
+            let usdc_mint_account_info = &pool_accounts_info[0];
+            let lottery_associated_usdc_token_account_info = &pool_accounts_info[1];
+            let fund_receiver_usdc_token_account_info = &pool_accounts_info[2];
+
+            // validate usdc mint account
+            compare_usdc_mint_account_with_config_global_account_info(
+                config_global_account_info,
+                usdc_mint_account_info.key
+            )?;
+
+            // validate lottery associated usdc token account
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_account_info.key,
+                    usdc_mint_account_info.key,
+                    usdc_mint_account_info.owner
+                ),
+                lottery_associated_usdc_token_account_info.key,
+                LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
+            )?;
+
+            let TokenAccount { amount, .. } = unpack_token_account(lottery_associated_usdc_token_account_info)?;
+
+            let pool_amount = lottery_account.get_pool_amount(amount)?;
+            let pool_prize_amount = lottery_account.get_pool_prize_amount(pool_amount)?;
+
+            let mut usdc_to_transfer = 0u64;
+            for rank in unclaimed_ranks.iter().copied() {
+                usdc_to_transfer = usdc_to_transfer
+                    .checked_add(lottery_account.get_prize_share(pool_prize_amount, rank)?)
+                    .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            };
+
+            let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+            let usdc_token_program_account_info = get_token_program_account_info_of_mint(
+                usdc_mint_account_info,
+                standard_token_program_account_info,
+                token_2022_program_account_info
+            )?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    usdc_token_program_account_info.key,
+                    lottery_associated_usdc_token_account_info.key,
+                    usdc_mint_account_info.key,
+                    fund_receiver_usdc_token_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    usdc_to_transfer,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_usdc_token_account_info.clone(),
+                    usdc_mint_account_info.clone(),
+                    fund_receiver_usdc_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ lottery_account_signer_seeds ]
+            )?;
+            sol_log("Unclaimed pool prize reclaimed.");
+        };
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        Ok(())
+    }
+
+    pub fn process_close_lottery_arbitrary_token_account(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let lottery_authority_account_info = next_account_info(accounts_info)?;
+        let lottery_associated_arbitrary_token_account_info = next_account_info(accounts_info)?;
+        let fund_receiver_arbitrary_token_account_info = next_account_info(accounts_info)?;
+        let fund_receiver_rent_exempt_account_info = next_account_info(accounts_info)?;
+        let arbitrary_mint_account_info = next_account_info(accounts_info)?;
+        let standard_token_program_account_info = next_account_info(accounts_info)?;
+        let token_2022_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(lottery_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // validate lottery authority
+        check_accounts_key_to_be_identical(
+            &lottery_account.authority,
+            lottery_authority_account_info.key,
+            LotteryError::InvalidLotteryAccountAuthority.into()
+        )?;
+
+        // validate lottery state -> every winner withdrawn (a failed lottery's token account is closed by "WithdrawFailedLottery")
+        let current_time = (Clock::get()?).unix_timestamp;
+        let is_arbitrary_token_account_released =
+            lottery_account.get_lottery_state(current_time) == LotteryState::Successful &&
+            lottery_account.is_ended_successfuly == true &&
+            lottery_account.winners.iter().all(|(_, _, is_withdrawed)| *is_withdrawed == true) == true;
+
+        if is_arbitrary_token_account_released == false {
+            return Err(
+                LotteryError::ArbitraryTokenAccountStillInUse.into()
+            );
+        };
+
+        // validate lottery arbitrary mint account
+        check_accounts_key_to_be_identical(
+            arbitrary_mint_account_info.key,
+            &lottery_account.arbitrary_mint_account_address,
+            LotteryError::InvalidArbitraryMintAccount.into()
+        )?;
+
+        // validate lottery associated arbitrary token account
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                arbitrary_mint_account_info.key,
+                arbitrary_mint_account_info.owner
+            ),
+            lottery_associated_arbitrary_token_account_info.key,
+            LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount.into()
+        )?;
+
+        let arbitrary_token_program_account_info = get_token_program_account_info_of_mint(
+            arbitrary_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+
+        let lottery_literal_seed = get_lottery_literal_seed(&lottery_account.lottery_description);
+        let lottery_account_signer_seeds: &[&[u8]] = &[
+            LOTTERY_ACCOUNT_SEED.as_bytes(),
+            &lottery_authority_account_info.key.to_bytes(),
+            lottery_literal_seed.as_slice(),
+            &[ lottery_account.canonical_bump ]
+        ];
+
+        // sweep the rounding dust of the prize split ("fund_amount / winners_count")
+        let TokenAccount { amount: arbitrary_token_balance, .. } = unpack_token_account(lottery_associated_arbitrary_token_account_info)?;
+
+        if arbitrary_token_balance > 0 {
+            let MintAccount { decimals, .. } = unpack_mint_account(arbitrary_mint_account_info)?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    arbitrary_token_program_account_info.key,
+                    lottery_associated_arbitrary_token_account_info.key,
+                    arbitrary_mint_account_info.key,
+                    fund_receiver_arbitrary_token_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    arbitrary_token_balance,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_arbitrary_token_account_info.clone(),
+                    arbitrary_mint_account_info.clone(),
+                    fund_receiver_arbitrary_token_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ lottery_account_signer_seeds ]
+            )?;
+            sol_log("Arbitrary tokens dust transfered.");
+        };
+
+        invoke_signed(
+            &close_token_account(
+                arbitrary_token_program_account_info.key,
+                lottery_associated_arbitrary_token_account_info.key,
+                fund_receiver_rent_exempt_account_info.key,
+                lottery_account_info.key,
+                &[]
+            )?,
+            &[
+                lottery_associated_arbitrary_token_account_info.clone(),
+                fund_receiver_rent_exempt_account_info.clone(),
+                lottery_account_info.clone()
+            ],
+            &[ lottery_account_signer_seeds ]
+        )?;
+        sol_log("Arbitrary token account closed & rent exempt lamports refunded.");
+
+        lottery_account.is_arbitrary_token_account_closed = true;
+
+        // prize mints -> (prize mint account, lottery's associated prize token account, fund-receiver prize token account) per prize mint
+        // the rounding dust left by the winners is swept & the token accounts not closed by the last winner's withdrawal are closed
+        let prize_mints_accounts_info = accounts_info.as_slice();
+        if prize_mints_accounts_info.len() != lottery_account.prize_mints.len() * 3 {
+            return Err(
+                LotteryError::InvalidAmountOfPrizeMintAccounts.into()
+            );
+        };
+
+        let mut closed_prize_token_accounts_amount = 0u16;
+        for (
+            (prize_mint, _),
+            prize_mint_accounts_info
+        ) in lottery_account.prize_mints.iter().zip(prize_mints_accounts_info.chunks(3)) {
+            let prize_mint_account_info = &prize_mint_accounts_info[0];
+            let lottery_associated_prize_token_account_info = &prize_mint_accounts_info[1];
+            let fund_receiver_prize_token_account_info = &prize_mint_accounts_info[2];
+
+            check_accounts_key_to_be_identical(
+                prize_mint_account_info.key,
+                prize_mint,
+                LotteryError::InvalidPrizeMintAccount.into()
+            )?;
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_account_info.key,
+                    prize_mint_account_info.key,
+                    prize_mint_account_info.owner
+                ),
+                lottery_associated_prize_token_account_info.key,
+                LotteryError::InvalidLotteryPrizeAssociatedTokenAccount.into()
+            )?;
+
+            if lottery_associated_prize_token_account_info.data_is_empty() == true {
+                continue;
+            };
+
+            let prize_token_program_account_info = get_token_program_account_info_of_mint(
+                prize_mint_account_info,
+                standard_token_program_account_info,
+                token_2022_program_account_info
+            )?;
+
+            let TokenAccount { amount: prize_token_balance, .. } = unpack_token_account(lottery_associated_prize_token_account_info)?;
+            if prize_token_balance > 0 {
+                let MintAccount { decimals, .. } = unpack_mint_account(prize_mint_account_info)?;
+
+                invoke_signed(
+                    &transfer_spl_checked(
+                        prize_token_program_account_info.key,
+                        lottery_associated_prize_token_account_info.key,
+                        prize_mint_account_info.key,
+                        fund_receiver_prize_token_account_info.key,
+                        lottery_account_info.key,
+                        &[],
+                        prize_token_balance,
+                        decimals
+                    )?,
+                    &[
+                        lottery_associated_prize_token_account_info.clone(),
+                        prize_mint_account_info.clone(),
+                        fund_receiver_prize_token_account_info.clone(),
+                        lottery_account_info.clone()
+                    ],
+                    &[ lottery_account_signer_seeds ]
+                )?;
+            };
+
+            invoke_signed(
+                &close_token_account(
+                    prize_token_program_account_info.key,
+                    lottery_associated_prize_token_account_info.key,
+                    fund_receiver_rent_exempt_account_info.key,
+                    lottery_account_info.key,
+                    &[]
+                )?,
+                &[
+                    lottery_associated_prize_token_account_info.clone(),
+                    fund_receiver_rent_exempt_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[ lottery_account_signer_seeds ]
+            )?;
+
+            closed_prize_token_accounts_amount += 1;
+        };
+
+        if closed_prize_token_accounts_amount > 0 {
+            lottery_account.prize_token_accounts_amount = lottery_account.prize_token_accounts_amount
+                .checked_sub(closed_prize_token_accounts_amount)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            sol_log("Prize token accounts closed & rent exempt lamports refunded.");
+        };
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        Ok(())
+    }
+
+    pub fn process_buy_ticket_for(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        tickets_amount: u32,
+        expected_token_price_per_ticket: u64
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let user_account_info = next_account_info(accounts_info)?;
+        let beneficiary_account_info = next_account_info(accounts_info)?;
+        let sponsor_account_info = next_account_info(accounts_info)?;
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let lottery_usdc_associated_token_account_info = next_account_info(accounts_info)?;
+        let sponsor_usdc_token_account_info = next_account_info(accounts_info)?;
+        let usdc_mint_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+        let standard_token_program_account_info = next_account_info(accounts_info)?;
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(sponsor_account_info)?;
+
+        Lottery::check_max_tickets_per_instruction(tickets_amount)?;
+
+        if tickets_amount == 0 {
+            return Err(
+                LotteryError::InvalidTicketAmount.into()
+            );
+        };
+
+        let current_time = (Clock::get()?).unix_timestamp;
+
+        // handle config account
+        Config::validate_config_account(config_global_account_info, program_id)?;
+
+        let config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // check is_pause flag
+        config_account.check_is_pause()?;
+
+        check_accounts_key_to_be_identical(
+            usdc_mint_account_info.key,
+            &config_account.usdc_mint_account,
+            LotteryError::InvalidUsdcMintAccount.into()
+        )?;
+
+        check_token_program_account_of_mint(
+            standard_token_program_account_info,
+            usdc_mint_account_info
+        )?;
+
+        // handle lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // protecting the sponsor against front-running
+        if expected_token_price_per_ticket != lottery_account.ticket_price {
+            return Err(
+                LotteryError::ExpectedTicketPriceViolated.into()
+            );
+        };
+
+        if lottery_account.is_started_and_not_ended(current_time) == false {
+            return Err(
+                LotteryError::InvalidLotteryState.into()
+            );
+        };
+
+        // ticket tokens of tokenized lotteries are only minted by "BuyTicket"
+        if lottery_account.is_tickets_tokenized == true {
+            return Err(
+                LotteryError::InvalidLotteryType.into()
+            );
+        };
+
+        if *beneficiary_account_info.key == lottery_account.authority {
+            return Err(
+                LotteryError::InvalidUser.into()
+            );
+        };
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                usdc_mint_account_info.key,
+                standard_token_program_account_info.key
+            ),
+            lottery_usdc_associated_token_account_info.key,
+            LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
+        )?;
+
+        // handle beneficiary's user account, created by the sponsor if missing
+        if user_account_info.owner != program_id {
+            let (
+                user_account_pda_addr,
+                user_account_pda_canonical_bump
+            ) = Pubkey::try_find_program_address(
+                &[
+                    USER_ACCOUNT_SEED.as_bytes(),
+                    beneficiary_account_info.key.to_bytes().as_slice(),
+                    lottery_account_info.key.to_bytes().as_slice()
+                ],
+                program_id
+            ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?;
+
+            check_accounts_key_to_be_identical(
+                &user_account_pda_addr,
+                user_account_info.key,
+                ProgramError::InvalidSeeds
+            )?;
+
+            create_pda_account(
+                user_account_info,
+                sponsor_account_info,
+                User::LEN,
+                program_id,
+                &[
+                    USER_ACCOUNT_SEED.as_bytes(),
+                    &beneficiary_account_info.key.to_bytes(),
+                    &lottery_account_info.key.to_bytes(),
+                    &[ user_account_pda_canonical_bump ]
+                ]
+            )?;
+            sol_log("User account created.");
+
+            let mut user_account = User::deserialize(
+                &mut &user_account_info.data.try_borrow().unwrap()[..]
+            )?;
+
+            user_account.discriminator = User::get_discriminator();
+            user_account.canonical_bump = user_account_pda_canonical_bump;
+            user_account.lottery = *lottery_account_info.key;
+            user_account.authority = *beneficiary_account_info.key;
+            user_account.created_at = current_time;
+
+            user_account.serialize(
+                &mut &mut user_account_info.data.try_borrow_mut().unwrap()[..]
+            )?;
+            sol_log("User account initialized.");
+        };
+
+        // validate user account pda
+        User::validate_user_account(
+            user_account_info,
+            program_id,
+            lottery_account_info.key,
+            beneficiary_account_info.key
+        )?;
+
+        let mut user_account = User::deserialize(
+            &mut &user_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        // validate user's holding tickets amount
+        user_account.validate_user_holding_tickets_amount(
+            &lottery_account.maximum_number_of_tickets_per_user,
+            tickets_amount
+        )?;
+
+        // validate lottery's total tickets amount
+        lottery_account.validate_total_tickets_amount(tickets_amount)?;
+
+        // refunds of a failed lottery go back to the sponsor
+        user_account.set_sponsor(sponsor_account_info.key)?;
+
+        //  handle transfering fee & updating lottery accounts
+        let total_tickets_price = calculate_fee_and_update_lottery_account(
+            &config_account,
+            &lottery_account,
+            lottery_account_info,
+            tickets_amount
+        )?;
+
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+        //  token-2022 transfer fees are paid by the sponsor, the lottery receives the full tickets price
+        let total_tickets_price_including_transfer_fee = get_amount_including_transfer_fee(
+            usdc_mint_account_info,
+            total_tickets_price
+        )?;
+
+        invoke(
+            &transfer_spl_checked(
+                standard_token_program_account_info.key,
+                sponsor_usdc_token_account_info.key,
+                usdc_mint_account_info.key,
+                lottery_usdc_associated_token_account_info.key,
+                sponsor_account_info.key,
+                &[],
+                total_tickets_price_including_transfer_fee,
+                decimals
+            )?,
+            &[
+                sponsor_usdc_token_account_info.clone(),
+                usdc_mint_account_info.clone(),
+                lottery_usdc_associated_token_account_info.clone(),
+                sponsor_account_info.clone()
+            ]
+        )?;
+        sol_log("Tickets total price in USDC transfered to the lottery.");
+
+        // a single ticket-range is added per purchase, whatever the tickets amount is
+        let rent_sysvar_account = Rent::get()?;
+        let space_needed = TICKET_RANGE_LENGTH;
+        let rent_exempt = (
+            rent_sysvar_account.lamports_per_byte_year
+                .checked_mul(space_needed as u64)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+        ).checked_mul(rent_sysvar_account.exemption_threshold as u64).ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        // update the user account, the sponsored part of the tickets value is recorded apart
+        user_account.total_tickets_acquired = user_account.total_tickets_acquired
+            .checked_add(tickets_amount)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_tickets_value = user_account.total_tickets_value
+            .checked_add(total_tickets_price)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_sponsored_tickets_value = user_account.total_sponsored_tickets_value
+            .checked_add(total_tickets_price)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_ticket_ranges_acquired = user_account.total_ticket_ranges_acquired
+            .checked_add(1)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_rent_exempt_paied = user_account.total_rent_exempt_paied
+            .checked_add(rent_exempt)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        user_account.serialize(
+            &mut &mut user_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        invoke(
+            &transfer_lamports(
+                sponsor_account_info.key,
+                lottery_account_info.key,
+                rent_exempt
+            ),
+            &[
+                sponsor_account_info.clone(),
+                lottery_account_info.clone()
+            ]
+        )?;
+        sol_log("Rent-exempt lamports transfered to the lottery account.");
+
+        let lottery_old_data_size = lottery_account_info.data_len();
+        let lottery_new_data_size = lottery_old_data_size.checked_add(space_needed).unwrap();
+
+        lottery_account_info
+            .realloc(lottery_new_data_size, false)
+            .map_err::<ProgramError, _>(|_| LotteryError::ReallocationFailed.into())?;
+
+        // add tickets to the lottery account
+        Lottery::add_ticket(
+            lottery_account_info,
+            lottery_account.initial_bytes,
+            lottery_account.tickets_total_amount,
+            lottery_account.ticket_ranges_total_amount,
+            tickets_amount,
+            *user_account_info.key
+        );
+
+        solana_program::msg!(
+            "Total-Tikcets => {} - Total-Tokens-Transfered => {} USDC - Beneficiary => {}",
+            tickets_amount,
+            spl_token::amount_to_ui_amount(total_tickets_price, decimals),
+            beneficiary_account_info.key
+        );
+
+        Ok(())
+    }
+
+    pub fn process_transfer_tickets(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        tickets_amount: u32
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let sender_user_account_info = next_account_info(accounts_info)?;
+        let sender_user_account_authority_account_info = next_account_info(accounts_info)?;
+        let receiver_user_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(sender_user_account_authority_account_info)?;
+
+        if tickets_amount == 0 {
+            return Err(
+                LotteryError::InvalidTicketAmount.into()
+            );
+        };
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        let current_time = (Clock::get()?).unix_timestamp;
+
+        // the ticket ledger is fixed once the lottery ends (see "get_request_seed")
+        if lottery_account.is_started_and_not_ended(current_time) == false {
+            return Err(
+                LotteryError::InvalidLotteryState.into()
+            );
+        };
+
+        // validate user accounts
+        User::validate_user_account(
+            sender_user_account_info,
+            program_id,
+            lottery_account_info.key,
+            sender_user_account_authority_account_info.key
+        )?;
+
+        let mut sender_user_account = User::deserialize(
+            &mut &sender_user_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        let mut receiver_user_account = User::deserialize(
+            &mut &receiver_user_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        User::validate_user_account(
+            receiver_user_account_info,
+            program_id,
+            lottery_account_info.key,
+            &receiver_user_account.authority
+        )?;
+
+        if sender_user_account_info.key == receiver_user_account_info.key {
+            return Err(
+                LotteryError::InvalidTicketsReceiver.into()
+            );
+        };
+
+        if receiver_user_account.authority == lottery_account.authority {
+            return Err(
+                LotteryError::InvalidUser.into()
+            );
+        };
+
+        if tickets_amount > sender_user_account.total_tickets_acquired {
+            return Err(
+                LotteryError::InsufficientTicketsToTransfer.into()
+            );
+        };
+
+        // validate receiver's holding tickets amount
+        receiver_user_account.validate_user_holding_tickets_amount(
+            &lottery_account.maximum_number_of_tickets_per_user,
+            tickets_amount
+        )?;
+
+        // room for a split ticket-range, released if no ticket-range is split
+        let lottery_old_data_size = lottery_account_info.data_len();
+        lottery_account_info
+            .realloc(lottery_old_data_size.checked_add(TICKET_RANGE_LENGTH).unwrap(), false)
+            .map_err::<ProgramError, _>(|_| LotteryError::ReallocationFailed.into())?;
+
+        let (
+            reassigned_ticket_ranges,
+            is_ticket_range_split
+        ) = Lottery::transfer_tickets(
+            lottery_account_info,
+            lottery_account.initial_bytes,
+            lottery_account.ticket_ranges_total_amount,
+            sender_user_account_info.key,
+            receiver_user_account_info.key,
+            tickets_amount
+        )?;
+
+        sender_user_account.transfer_tickets_to(
+            &mut receiver_user_account,
+            tickets_amount,
+            reassigned_ticket_ranges
+        )?;
+
+        if is_ticket_range_split == true {
+            // rent_exempt of the new ticket-range is paid by the sender, refunded to the receiver with its tickets
+            let rent_sysvar_account = Rent::get()?;
+            let rent_exempt = (
+                rent_sysvar_account.lamports_per_byte_year
+                    .checked_mul(TICKET_RANGE_LENGTH as u64)
+                    .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+            ).checked_mul(rent_sysvar_account.exemption_threshold as u64).ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            invoke(
+                &transfer_lamports(
+                    sender_user_account_authority_account_info.key,
+                    lottery_account_info.key,
+                    rent_exempt
+                ),
+                &[
+                    sender_user_account_authority_account_info.clone(),
+                    lottery_account_info.clone()
+                ]
+            )?;
+            sol_log("Rent-exempt lamports of the split ticket-range transfered to the lottery account.");
+
+            receiver_user_account.total_ticket_ranges_acquired = receiver_user_account.total_ticket_ranges_acquired
+                .checked_add(1)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            receiver_user_account.total_rent_exempt_paied = receiver_user_account.total_rent_exempt_paied
+                .checked_add(rent_exempt)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        } else {
+            lottery_account_info
+                .realloc(lottery_old_data_size, false)
+                .map_err::<ProgramError, _>(|_| LotteryError::ReallocationFailed.into())?;
+        };
+
+        sender_user_account.serialize(
+            &mut &mut sender_user_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        receiver_user_account.serialize(
+            &mut &mut receiver_user_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        solana_program::msg!(
+            "Total-Tickets-Transfered => {} - Receiver => {}",
+            tickets_amount,
+            receiver_user_account_info.key
+        );
+
+        Ok(())
+    }
+
+    pub fn process_enable_ticket_tokens(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let lottery_account_authority_account_info = next_account_info(accounts_info)?;
+        let ticket_mint_account_info = next_account_info(accounts_info)?;
+        let ticket_token_program_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+        let config_global_account_info = next_account_info(accounts_info)?;
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(lottery_account_authority_account_info)?;
+
+        check_accounts_key_to_be_identical(
+            ticket_token_program_account_info.key,
+            &TOKEN_STANDARD_PROGRAM_ID,
+            LotteryError::InvalidTokenProgramAccount.into()
+        )?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &lottery_account.authority,
+            lottery_account_authority_account_info.key,
+            LotteryError::InvalidLotteryAccountAuthority.into()
+        )?;
+
+        let current_time = (Clock::get()?).unix_timestamp;
+
+        // every ticket of a tokenized lottery is sold as ticket tokens
+        if lottery_account.is_not_started(current_time) == false {
+            return Err(
+                LotteryError::InvalidLotteryState.into()
+            );
+        };
+
+        if lottery_account.is_tickets_tokenized == true {
+            return Err(
+                LotteryError::TicketTokensAlreadyEnabled.into()
+            );
+        };
+
+        // create the ticket mint -> (ticket-mint pda), its own mint authority, no decimals
+        let (
+            ticket_mint_pda_addr,
+            ticket_mint_pda_canonical_bump
+        ) = get_ticket_mint_account_pda(
+            lottery_account_info.key,
+            program_id
+        ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?;
+
+        check_accounts_key_to_be_identical(
+            &ticket_mint_pda_addr,
+            ticket_mint_account_info.key,
+            LotteryError::InvalidTicketMintAccount.into()
+        )?;
+
+        create_pda_account(
+            ticket_mint_account_info,
+            lottery_account_authority_account_info,
+            MintAccount::LEN,
+            ticket_token_program_account_info.key,
+            &[
+                TICKET_MINT_ACCOUNT_SEED.as_bytes(),
+                &lottery_account_info.key.to_bytes(),
+                &[ ticket_mint_pda_canonical_bump ]
+            ]
+        )?;
+
+        invoke(
+            &initialize_mint2(
+                ticket_token_program_account_info.key,
+                ticket_mint_account_info.key,
+                ticket_mint_account_info.key,
+                None,
+                0
+            )?,
+            &[ ticket_mint_account_info.clone() ]
+        )?;
+        sol_log("Ticket mint account created.");
+
+        lottery_account.is_tickets_tokenized = true;
+        lottery_account.ticket_mint_canonical_bump = ticket_mint_pda_canonical_bump;
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        Ok(())
+    }
+
+    pub fn process_redeem_ticket_tokens(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        tickets_amount: u32
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let user_account_info = next_account_info(accounts_info)?;
+        let user_account_authority_account_info = next_account_info(accounts_info)?;
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let ticket_mint_account_info = next_account_info(accounts_info)?;
+        let user_ticket_token_account_info = next_account_info(accounts_info)?;
+        let ticket_token_program_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        check_account_is_signer(user_account_authority_account_info)?;
+
+        check_accounts_key_to_be_identical(
+            ticket_token_program_account_info.key,
+            &TOKEN_STANDARD_PROGRAM_ID,
+            LotteryError::InvalidTokenProgramAccount.into()
+        )?;
+
+        Lottery::check_max_tickets_per_instruction(tickets_amount)?;
+
+        if tickets_amount == 0 {
+            return Err(
+                LotteryError::InvalidTicketAmount.into()
+            );
+        };
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        let current_time = (Clock::get()?).unix_timestamp;
+
+        // snapshot of the holdings -> tokens not redeemed before the end don't take part in the draw
+        if lottery_account.is_started_and_not_ended(current_time) == false {
+            return Err(
+                LotteryError::InvalidLotteryState.into()
+            );
+        };
+
+        check_accounts_key_to_be_identical(
+            &get_ticket_mint_account_pda(
+                lottery_account_info.key,
+                program_id
+            ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?.0,
+            ticket_mint_account_info.key,
+            LotteryError::InvalidTicketMintAccount.into()
+        )?;
+
+        // validate user account
+        User::validate_user_account(
+            user_account_info,
+            program_id,
+            lottery_account_info.key,
+            user_account_authority_account_info.key
+        )?;
+
+        let mut user_account = User::deserialize(
+            &mut &user_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        if user_account.authority == lottery_account.authority {
+            return Err(
+                LotteryError::InvalidUser.into()
+            );
+        };
+
+        // validate user's holding tickets amount
+        user_account.validate_user_holding_tickets_amount(
+            &lottery_account.maximum_number_of_tickets_per_user,
+            tickets_amount
+        )?;
+
+        let tickets_value = lottery_account.take_ticket_tokens(tickets_amount)?;
+
+        invoke(
+            &burn_checked(
+                ticket_token_program_account_info.key,
+                user_ticket_token_account_info.key,
+                ticket_mint_account_info.key,
+                user_account_authority_account_info.key,
+                &[],
+                tickets_amount as u64,
+                0
+            )?,
+            &[
+                user_ticket_token_account_info.clone(),
+                ticket_mint_account_info.clone(),
+                user_account_authority_account_info.clone()
+            ]
+        )?;
+        sol_log("Ticket tokens burned.");
+
+        // a single ticket-range is added per redemption, whatever the tickets amount is
+        let rent_sysvar_account = Rent::get()?;
+        let space_needed = TICKET_RANGE_LENGTH;
+        let rent_exempt = (
+            rent_sysvar_account.lamports_per_byte_year
+                .checked_mul(space_needed as u64)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+        ).checked_mul(rent_sysvar_account.exemption_threshold as u64).ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        invoke(
+            &transfer_lamports(
+                user_account_authority_account_info.key,
+                lottery_account_info.key,
+                rent_exempt
+            ),
+            &[
+                user_account_authority_account_info.clone(),
+                lottery_account_info.clone()
+            ]
+        )?;
+        sol_log("Rent-exempt lamports transfered to the lottery account.");
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        // update the user account, the redeemed tickets are refunded at their value if the lottery fails
+        user_account.total_tickets_acquired = user_account.total_tickets_acquired
+            .checked_add(tickets_amount)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_tickets_value = user_account.total_tickets_value
+            .checked_add(tickets_value)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_ticket_ranges_acquired = user_account.total_ticket_ranges_acquired
+            .checked_add(1)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        user_account.total_rent_exempt_paied = user_account.total_rent_exempt_paied
+            .checked_add(rent_exempt)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        user_account.serialize(
+            &mut &mut user_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        let lottery_old_data_size = lottery_account_info.data_len();
+        let lottery_new_data_size = lottery_old_data_size.checked_add(space_needed).unwrap();
+
+        lottery_account_info
+            .realloc(lottery_new_data_size, false)
+            .map_err::<ProgramError, _>(|_| LotteryError::ReallocationFailed.into())?;
+
+        // add tickets to the lottery account
+        Lottery::add_ticket(
+            lottery_account_info,
+            lottery_account.initial_bytes,
+            lottery_account.tickets_total_amount,
+            lottery_account.ticket_ranges_total_amount,
+            tickets_amount,
+            *user_account_info.key
+        );
+
+        solana_program::msg!(
+            "Total-Redeemed-Tickets => {}",
+            tickets_amount
+        );
+
+        Ok(())
+    }
+
+    pub fn process_refund_ticket_tokens(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let lottery_account_info = next_account_info(accounts_info)?;
+        let holder_account_info = next_account_info(accounts_info)?;
+        let ticket_mint_account_info = next_account_info(accounts_info)?;
+        let holder_ticket_token_account_info = next_account_info(accounts_info)?;
+        let ticket_token_program_account_info = next_account_info(accounts_info)?;
+        let usdc_mint_account_info = next_account_info(accounts_info)?;
+        let lottery_associated_usdc_token_account_info = next_account_info(accounts_info)?;
+        let fund_receiver_usdc_token_account_info = next_account_info(accounts_info)?;
+        let usdc_token_program_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(holder_account_info)?;
+
+        check_accounts_key_to_be_identical(
+            ticket_token_program_account_info.key,
+            &TOKEN_STANDARD_PROGRAM_ID,
+            LotteryError::InvalidTokenProgramAccount.into()
+        )?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // check is_pause flag
+        Config::check_is_pause_raw(config_global_account_info)?;
+
+        // validate usdc mint account
+        compare_usdc_mint_account_with_config_global_account_info(
+            config_global_account_info,
+            usdc_mint_account_info.key
+        )?;
+
+        check_token_program_account_of_mint(
+            usdc_token_program_account_info,
+            usdc_mint_account_info
+        )?;
+
+        // validate lottery account
+        Lottery::validate_lottery_account(lottery_account_info, program_id)?;
+
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        let current_time = (Clock::get()?).unix_timestamp;
+        if lottery_account.get_lottery_state(current_time) != LotteryState::Failed {
+            return Err(
+                LotteryError::InvalidLotteryState.into()
+            );
+        };
+
+        check_accounts_key_to_be_identical(
+            &get_ticket_mint_account_pda(
+                lottery_account_info.key,
+                program_id
+            ).ok_or::<ProgramError>(LotteryError::FailedToFindProgramAddress.into())?.0,
+            ticket_mint_account_info.key,
+            LotteryError::InvalidTicketMintAccount.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                usdc_mint_account_info.key,
+                usdc_mint_account_info.owner
+            ),
+            lottery_associated_usdc_token_account_info.key,
+            LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
+        )?;
+
+        let TokenAccount { owner, mint, amount, .. } = unpack_token_account(holder_ticket_token_account_info)?;
+        if owner != *holder_account_info.key || mint != *ticket_mint_account_info.key {
+            return Err(
+                LotteryError::InvalidTicketTokenAccount.into()
+            );
+        };
+
+        // every ticket token of the holder is refunded at its value
+        let tickets_value = lottery_account.take_ticket_tokens(
+            u32::try_from(amount).map_err::<ProgramError, _>(|_| LotteryError::Overflow.into())?
+        )?;
+
+        invoke(
+            &burn_checked(
+                ticket_token_program_account_info.key,
+                holder_ticket_token_account_info.key,
+                ticket_mint_account_info.key,
+                holder_account_info.key,
+                &[],
+                amount,
+                0
+            )?,
+            &[
+                holder_ticket_token_account_info.clone(),
+                ticket_mint_account_info.clone(),
+                holder_account_info.clone()
+            ]
+        )?;
+        sol_log("Ticket tokens burned.");
+
+        let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+        let lottery_literal_seed = get_lottery_literal_seed(&lottery_account.lottery_description);
+
+        invoke_signed(
+            &transfer_spl_checked(
+                usdc_token_program_account_info.key,
+                lottery_associated_usdc_token_account_info.key,
+                usdc_mint_account_info.key,
+                fund_receiver_usdc_token_account_info.key,
+                lottery_account_info.key,
+                &[],
+                tickets_value,
+                decimals
+            )?,
+            &[
+                lottery_associated_usdc_token_account_info.clone(),
+                usdc_mint_account_info.clone(),
+                fund_receiver_usdc_token_account_info.clone(),
+                lottery_account_info.clone()
+            ],
+            &[
+                &[
+                    LOTTERY_ACCOUNT_SEED.as_bytes(),
+                    &lottery_account.authority.to_bytes(),
+                    lottery_literal_seed.as_slice(),
+                    &[ lottery_account.canonical_bump ]
+                ]
+            ]
+        )?;
+        sol_log("Ticket tokens refunded.");
+
+        lottery_account.serialize(
+            &mut &mut lottery_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        solana_program::msg!(
+            "Total-Refunded-Ticket-Tokens => {}",
+            amount
+        );
+
+        Ok(())
+    }
+
+    pub fn process_change_sol_treasury(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let config_account_authority_account_info = next_account_info(accounts_info)?;
+        let new_sol_treasury_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(config_account_authority_account_info)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // validate authority account
+        check_accounts_key_to_be_identical(
+            &get_config_account_authority(config_global_account_info),
+            config_account_authority_account_info.key,
+            LotteryError::InvalidConfigAuthority.into()
+        )?;
+
+        let mut config_account = Config::deserialize(
+            &mut &config_global_account_info.data.try_borrow().unwrap()[..]
+        )?;
+
+        config_account.sol_treasury = *new_sol_treasury_account_info.key;
+
+        config_account.serialize(
+            &mut &mut config_global_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        sol_log("Config account updated.");
+
+        Ok(())
+    }
+
+    pub fn process_migrate_config_account(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo]
+    ) -> ProgramResult {
+        let accounts_info = &mut accounts_info.iter();
+
+        let config_global_account_info = next_account_info(accounts_info)?;
+        let config_account_authority_account_info = next_account_info(accounts_info)?;
+        let funding_account_info = next_account_info(accounts_info)?;
+        let system_program_account_info = next_account_info(accounts_info)?;
+
+        check_account_is_signer(config_account_authority_account_info)?;
+        check_account_is_signer(funding_account_info)?;
+
+        check_system_program_id(system_program_account_info.key)?;
+
+        // validate config account
+        Config::validate_config_account(
+            config_global_account_info,
+            program_id
+        )?;
+
+        // validate authority account
+        check_accounts_key_to_be_identical(
+            &get_config_account_authority(config_global_account_info),
+            config_account_authority_account_info.key,
+            LotteryError::InvalidConfigAuthority.into()
+        )?;
+
+        // the config fields are only appended -> the fields missing from an old layout are read as zeros
+        let mut config_account = {
+            let config_account_data = config_global_account_info
+                .data
+                .try_borrow()
+                .unwrap();
+
+            Config::deserialize(
+                &mut [ &config_account_data[..], &[0u8; Config::LEN] ].concat().as_slice()
+            )?
+        };
+
+        let config_old_data_size = config_global_account_info.data_len();
+        let config_new_data_size = config_account
+            .try_to_vec()
+            .unwrap()
+            .len();
+
+        if config_old_data_size >= config_new_data_size {
+            return Err(
+                LotteryError::ConfigAccountAlreadyMigrated.into()
+            );
+        };
+
+        // the appended fields -> their defaults
+        if config_account.sol_treasury == Pubkey::default() {
+            config_account.sol_treasury = config_account.authority;
+        };
+
+        let rent_exempt = Rent::get()?.minimum_balance(config_new_data_size);
+        let config_account_balance = config_global_account_info.lamports();
+        if config_account_balance < rent_exempt {
+            invoke(
+                &transfer_lamports(
+                    funding_account_info.key,
+                    config_global_account_info.key,
+                    rent_exempt
+                        .checked_sub(config_account_balance)
+                        .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+                ),
+                &[
+                    funding_account_info.clone(),
+                    config_global_account_info.clone(),
+                    system_program_account_info.clone()
+                ]
+            )?;
+            sol_log("Rent-exempt lamports transfered to the config account.");
+        };
+
+        config_global_account_info
+            .realloc(config_new_data_size, true)
+            .map_err::<ProgramError, _>(|_| LotteryError::ReallocationFailed.into())?;
+
+        config_account.serialize(
+            &mut &mut config_global_account_info.data.try_borrow_mut().unwrap()[..]
+        )?;
+
+        sol_log("Config account migrated.");
+
+        Ok(())
+    }
+
+    pub fn process(
+        program_id: &Pubkey,
+        accounts_info: &[AccountInfo],
+        instruction_data: &[u8]
+    ) -> ProgramResult {
+        let ix = Instructions::unpack(instruction_data)?;
+
+        use Instructions::*;
+        match ix {
+            CreateAndInitializeProgramConfigAccount {
+                authority,
+                lottery_creation_fee,
+                lottery_tickets_fee,
+                maximum_number_of_winners,
+                pyth_price_receiver_programid,
+                usdc_mint_account,
+                maximum_time_of_price_feed_age,
+                minimum_tickets_to_be_sold_in_lottery,
+                pyth_price_feed_accounts,
+                maximum_time_for_lottery_account,
+                treasury,
+                pyth_price_feed_ids,
+                max_lottery_description_bytes
+            } => {
+                sol_log("Instruction: CreateAndInitializeProgramConfigAccount");
+
+                check_accounts_amount(accounts_info.len(), 4)?;
+
+                Self::process_create_and_initialize_program_config_account(
+                    accounts_info,
+                    program_id,
+                    authority,
+                    lottery_creation_fee,
+                    lottery_tickets_fee,
+                    maximum_number_of_winners,
+                    pyth_price_receiver_programid,
+                    usdc_mint_account,
+                    maximum_time_of_price_feed_age,
+                    minimum_tickets_to_be_sold_in_lottery,
+                    pyth_price_feed_accounts,
+                    maximum_time_for_lottery_account,
+                    treasury,
+                    pyth_price_feed_ids,
+                    max_lottery_description_bytes
+                )
+            },
+            CreateAndInitializeLotteryAccount {
+                fund_amount,
+                winners_count,
+                starting_time,
+                ending_time,
+                minimum_tickets_amount_required_to_be_sold,
+                ticket_price,
+                maximum_number_of_tickets_per_user,
+                randomness_commitment,
+                randomness_source,
+                prize_table,
+                nft_prizes,
+                sol_prize_amount,
+                pool_creator_share,
+                maximum_total_tickets,
+                settlement_bounty,
+                lottery_description
+            } => {
+                sol_log("Instruction: CreateAndInitializeLotteryAccount");
+
+                check_min_accounts_amount(accounts_info.len(), 14)?;
+
+                Self::process_create_and_initialize_lottery_account(
+                    program_id,
+                    accounts_info,
+                    fund_amount,
+                    winners_count,
+                    starting_time,
+                    ending_time,
+                    minimum_tickets_amount_required_to_be_sold,
+                    ticket_price,
+                    maximum_number_of_tickets_per_user,
+                    randomness_commitment,
+                    randomness_source,
+                    prize_table,
+                    nft_prizes,
+                    sol_prize_amount,
+                    pool_creator_share,
+                    maximum_total_tickets,
+                    settlement_bounty,
+                    lottery_description
+                )
+            },
+            CreateAndInitializeUserAccount => {
+                sol_log("Instruction: CreateAndInitializeUserAccount");
+
+                check_accounts_amount(accounts_info.len(), 6)?;
+
+                Self::process_create_and_initialize_user_account(
+                    program_id,
+                    accounts_info
+                )
+            },
+            BuyTicket {
+                tickets_amount,
+                expected_token_price_per_ticket
+            } => {
+                sol_log("Instruction: BuyTicket");
+
+                check_min_accounts_amount(accounts_info.len(), 10)?;
+
+                Self::process_buy_ticket(
+                    program_id,
+                    accounts_info,
+                    tickets_amount,
+                    expected_token_price_per_ticket
+                )
+            },
+            ChangeLotteryTicketPrice { new_ticket_price } => {
+                sol_log("Instruction: ChangeLotteryTicketPrice");
+
+                check_accounts_amount(accounts_info.len(), 3)?;
+
+                Self::process_change_lottery_ticket_price(
+                    accounts_info,
+                    program_id,
+                    new_ticket_price
+                )
+            },
+            EndLotteryAndPickWinners => {
+                sol_log("Instruction: EndLotteryAndPickWinners");
+
+                check_min_accounts_amount(accounts_info.len(), 7)?;
+
+                Self::process_end_lottery_and_pick_winners(
+                    program_id,
+                    accounts_info
+                )
+            },
+            WithdrawSucceedLottery => {
+                sol_log("Instruction: WithdrawSucceedLottery");
+
+                check_min_accounts_amount(accounts_info.len(), 7)?;
+
+                Self::process_withdraw_succeed_lottery(
+                    program_id,
+                    accounts_info
+                )
+            },
+            WithdrawLotterysWinners => {
+                sol_log("Instruction: WithdrawLotterysWinners");
+
+                check_min_accounts_amount(accounts_info.len(), 9)?;
+
+                Self::process_withdraw_lottery_winners(
+                    program_id,
+                    accounts_info
+                )
+            },
+            WithdrawAndCloseSucceedUser => {
+                sol_log("Instruction: WithdrawAndCloseSucceedUser");
+
+                check_accounts_amount(accounts_info.len(), 6)?;
+
+                Self::process_withdraw_and_close_succeed_user(
+                    program_id, 
+                    accounts_info
+                )
+            },
+            WithdrawFailedLottery {} => {
+                sol_log("Instruction: WithdrawFailedLottery");
+
+                check_min_accounts_amount(accounts_info.len(), 12)?;
+
+                Self::process_withdraw_failed_lottery(
+                    program_id,
+                    accounts_info
+                )
+            },
+            WithdrawAndCloseFailedUser {} => {
+                sol_log("Instruction: WithdrawAndCloseFailedUser");
+
+                check_min_accounts_amount(accounts_info.len(), 10)?;
+
+                Self::process_withdraw_and_close_failed_user(
+                    program_id,
+                    accounts_info
+                )
+            },
+            CloseLotteryAccountAndUsdcTokenAccount => {
+                sol_log("Instruction: CloseLotteryAccountAndUsdcTokenAccount");
+
+                check_min_accounts_amount(accounts_info.len(), 9)?;
+
+                Self::process_close_lottery_account_and_usdc_token_account(
+                    program_id,
+                    accounts_info
+                )
+            },
+            ChangeFeeOfLotteryCreation { new_fee } => {
+                sol_log("Instruction: ChangeFeeOfLotteryCreation");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_fee_of_lottery_creation(
+                    program_id,
+                    accounts_info,
+                    new_fee
+                )
+            },
+            ChangeConfigAccountAuthority => {
+                sol_log("Instruction: ChangeConfigAccountAuthority");
+
+                check_accounts_amount(accounts_info.len(), 3)?;
+
+                Self::process_change_config_account_authority(
+                    program_id,
+                    accounts_info
+                )
+            },
+            ChangeFeeOfTickets { new_fee } => {
+                sol_log("Instruction: ChangeFeeOfTickets");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_fee_of_tickets(
+                    program_id,
+                    accounts_info,
+                    new_fee
+                )
+            },
+            ClaimProtocolFees { n } => {
+                sol_log("Instruction: ClaimProtocolFees");
+
+                check_accounts_amount(accounts_info.len(), 5 + (n as usize * 2))?;
+                
+                Self::process_claim_protocol_fees(
+                    program_id,
+                    accounts_info,
+                    n
+                )
+            },
+            ChangeMaximumNumberOfWinners { new_max } => {
+                sol_log("Instruction: ChangeMaximumNumberOfWinners");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_maximum_number_of_winners(
+                    program_id,
+                    accounts_info,
+                    new_max
+                )
+            },
+            ChangeMaximumAgeOfPriceFeed { new_max } => {
+                sol_log("Instriction: ChangeMaximumAgeOfPriceFeed");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_maximum_age_of_price_feed(
+                    program_id,
+                    accounts_info,
+                    new_max
+                )
+            },
+            ChangePauseState { pause } => {
+                sol_log("Instruction: ChangePauseState");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_pause_state(
+                    program_id,
+                    accounts_info,
+                    pause
+                )
+            },
+            ChangeTreasury => {
+                sol_log("Instruction: ChangeTreasury");
+
+                check_accounts_amount(accounts_info.len(), 3)?;
+
+                Self::process_change_treasury_account(
+                    program_id,
+                    accounts_info
+                )
+            },
+            ChangeProtocolMintAccount { new_mint_account } => {
+                sol_log("Instruction: ChangeMintAccount");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_protocol_mint_account(
+                    accounts_info,
+                    program_id,
                     new_mint_account
                 )
             },
             ChangePythPriceReceiverProgramAccount { new_pyth_price_receiver_programid } => {
                 sol_log("Instruction: ChangePythPriceReceiverProgramAccount");
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_pyth_price_receiver_program_account(
+                    accounts_info,
+                    program_id,
+                    new_pyth_price_receiver_programid
+                )
+            },
+            ChangePriceFeedId {
+                index,
+                price_feed_id
+            } => {
+                sol_log("Instruction: ChangePriceFeedId");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_price_feed_id(
+                    program_id, 
+                    accounts_info, 
+                    index, 
+                    price_feed_id
+                )
+            },
+            ChangePriceFeedAccount { 
+                index, 
+                price_feed_account
+            } => {
+                sol_log("Instruction: ChangePriceFeedAccount");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_price_feed_account(
+                    program_id, 
+                    accounts_info, 
+                    index, 
+                    price_feed_account
+                )
+            },
+            ChangeMaxLotteryDescriptionLength { new_length } => {
+                sol_log("Instruction: ChangeMaxLotteryDescriptionLength");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_max_lottery_description_length(
+                    program_id, 
+                    accounts_info, 
+                    new_length
+                )
+            },
+            RevealLotterySecret { secret } => {
+                sol_log("Instruction: RevealLotterySecret");
+
+                check_min_accounts_amount(accounts_info.len(), 3)?;
+
+                Self::process_reveal_lottery_secret(
+                    program_id,
+                    accounts_info,
+                    secret
+                )
+            },
+            ChangeVrfOracleAuthority => {
+                sol_log("Instruction: ChangeVrfOracleAuthority");
+
+                check_accounts_amount(accounts_info.len(), 3)?;
+
+                Self::process_change_vrf_oracle_authority(
+                    program_id,
+                    accounts_info
+                )
+            },
+            RequestRandomness => {
+                sol_log("Instruction: RequestRandomness");
+
+                check_accounts_amount(accounts_info.len(), 5)?;
+
+                Self::process_request_randomness(
+                    program_id,
+                    accounts_info
+                )
+            },
+            FulfilRandomness { proof } => {
+                sol_log("Instruction: FulfilRandomness");
+
+                check_accounts_amount(accounts_info.len(), 3)?;
+
+                Self::process_fulfil_randomness(
+                    program_id,
+                    accounts_info,
+                    proof
+                )
+            },
+            AddPrizeMint { prize_amount } => {
+                sol_log("Instruction: AddPrizeMint");
+
+                check_accounts_amount(accounts_info.len(), 10)?;
+
+                Self::process_add_prize_mint(
+                    program_id,
+                    accounts_info,
+                    prize_amount
+                )
+            },
+            CreateLotterySeries {
+                jackpot_share,
+                house_slots,
+                series_description
+            } => {
+                sol_log("Instruction: CreateLotterySeries");
+
+                check_accounts_amount(accounts_info.len(), 9)?;
+
+                Self::process_create_lottery_series(
+                    program_id,
+                    accounts_info,
+                    jackpot_share,
+                    house_slots,
+                    series_description
+                )
+            },
+            JoinLotterySeries => {
+                sol_log("Instruction: JoinLotterySeries");
+
+                check_min_accounts_amount(accounts_info.len(), 6)?;
+
+                Self::process_join_lottery_series(
+                    program_id,
+                    accounts_info
+                )
+            },
+            WithdrawSeriesJackpot => {
+                sol_log("Instruction: WithdrawSeriesJackpot");
+
+                check_accounts_amount(accounts_info.len(), 9)?;
+
+                Self::process_withdraw_series_jackpot(
+                    program_id,
+                    accounts_info
+                )
+            },
+            BuyTicketWithOracleConversion {
+                tickets_amount,
+                expected_token_price_per_ticket,
+                maximum_lamports_to_spend
+            } => {
+                sol_log("Instruction: BuyTicketWithOracleConversion");
+
+                check_accounts_amount(accounts_info.len(), 9)?;
+
+                Self::process_buy_ticket_with_oracle_conversion(
+                    program_id,
+                    accounts_info,
+                    tickets_amount,
+                    expected_token_price_per_ticket,
+                    maximum_lamports_to_spend
+                )
+            },
+            CancelLottery => {
+                sol_log("Instruction: CancelLottery");
+
+                check_min_accounts_amount(accounts_info.len(), 13)?;
+
+                Self::process_cancel_lottery(
+                    program_id,
+                    accounts_info
+                )
+            },
+            ChangeLotteryCancellationPenalty { new_penalty } => {
+                sol_log("Instruction: ChangeLotteryCancellationPenalty");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_lottery_cancellation_penalty(
+                    program_id,
+                    accounts_info,
+                    new_penalty
+                )
+            },
+            UpdateLotterySchedule {
+                new_starting_time,
+                new_ending_time
+            } => {
+                sol_log("Instruction: UpdateLotterySchedule");
+
+                check_accounts_amount(accounts_info.len(), 3)?;
+
+                Self::process_update_lottery_schedule(
+                    program_id,
+                    accounts_info,
+                    new_starting_time,
+                    new_ending_time
+                )
+            },
+            SetLotterySeriesRoundTemplate { round_template } => {
+                sol_log("Instruction: SetLotterySeriesRoundTemplate");
+
+                check_accounts_amount(accounts_info.len(), 10)?;
+
+                Self::process_set_lottery_series_round_template(
+                    program_id,
+                    accounts_info,
+                    round_template
+                )
+            },
+            StartNextRound => {
+                sol_log("Instruction: StartNextRound");
+
+                check_min_accounts_amount(accounts_info.len(), 14)?;
+
+                Self::process_start_next_round(
+                    program_id,
+                    accounts_info
+                )
+            },
+            SettleFailedLottery => {
+                sol_log("Instruction: SettleFailedLottery");
+
+                check_min_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_settle_failed_lottery(
+                    program_id,
+                    accounts_info
+                )
+            },
+            DistributePrizes => {
+                sol_log("Instruction: DistributePrizes");
+
+                check_min_accounts_amount(accounts_info.len(), 12)?;
+
+                Self::process_distribute_prizes(
+                    program_id,
+                    accounts_info
+                )
+            },
+            ChangeUnclaimedPrizesPolicy {
+                prize_claim_window,
+                unclaimed_prizes_receiver
+            } => {
+                sol_log("Instruction: ChangeUnclaimedPrizesPolicy");
+
+                check_accounts_amount(accounts_info.len(), 2)?;
+
+                Self::process_change_unclaimed_prizes_policy(
+                    program_id,
+                    accounts_info,
+                    prize_claim_window,
+                    unclaimed_prizes_receiver
+                )
+            },
+            ReclaimUnclaimedPrizes => {
+                sol_log("Instruction: ReclaimUnclaimedPrizes");
+
+                check_min_accounts_amount(accounts_info.len(), 10)?;
+
+                Self::process_reclaim_unclaimed_prizes(
+                    program_id,
+                    accounts_info
+                )
+            },
+            CloseLotteryArbitraryTokenAccount => {
+                sol_log("Instruction: CloseLotteryArbitraryTokenAccount");
+
+                check_min_accounts_amount(accounts_info.len(), 9)?;
+
+                Self::process_close_lottery_arbitrary_token_account(
+                    program_id,
+                    accounts_info
+                )
+            },
+            BuyTicketFor {
+                tickets_amount,
+                expected_token_price_per_ticket
+            } => {
+                sol_log("Instruction: BuyTicketFor");
+
+                check_accounts_amount(accounts_info.len(), 10)?;
+
+                Self::process_buy_ticket_for(
+                    program_id,
+                    accounts_info,
+                    tickets_amount,
+                    expected_token_price_per_ticket
+                )
+            },
+            TransferTickets { tickets_amount } => {
+                sol_log("Instruction: TransferTickets");
+
+                check_accounts_amount(accounts_info.len(), 6)?;
+
+                Self::process_transfer_tickets(
+                    program_id,
+                    accounts_info,
+                    tickets_amount
+                )
+            },
+            EnableTicketTokens => {
+                sol_log("Instruction: EnableTicketTokens");
+
+                check_accounts_amount(accounts_info.len(), 6)?;
+
+                Self::process_enable_ticket_tokens(
+                    program_id,
+                    accounts_info
+                )
+            },
+            RedeemTicketTokens { tickets_amount } => {
+                sol_log("Instruction: RedeemTicketTokens");
+
+                check_accounts_amount(accounts_info.len(), 8)?;
 
-                Self::process_change_pyth_price_receiver_program_account(
-                    accounts_info,
+                Self::process_redeem_ticket_tokens(
                     program_id,
-                    new_pyth_price_receiver_programid
+                    accounts_info,
+                    tickets_amount
                 )
             },
-            ChangePriceFeedId {
-                index,
-                price_feed_id
-            } => {
-                sol_log("Instruction: ChangePriceFeedId");
+            RefundTicketTokens => {
+                sol_log("Instruction: RefundTicketTokens");
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+                check_accounts_amount(accounts_info.len(), 10)?;
 
-                Self::process_change_price_feed_id(
-                    program_id, 
-                    accounts_info, 
-                    index, 
-                    price_feed_id
+                Self::process_refund_ticket_tokens(
+                    program_id,
+                    accounts_info
                 )
             },
-            ChangePriceFeedAccount { 
-                index, 
-                price_feed_account
-            } => {
-                sol_log("Instruction: ChangePriceFeedAccount");
+            ChangeSolTreasury => {
+                sol_log("Instruction: ChangeSolTreasury");
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+                check_accounts_amount(accounts_info.len(), 3)?;
 
-                Self::process_change_price_feed_account(
-                    program_id, 
-                    accounts_info, 
-                    index, 
-                    price_feed_account
+                Self::process_change_sol_treasury(
+                    program_id,
+                    accounts_info
                 )
             },
-            ChangeMaxLotteryDescriptionLength { new_length } => {
-                sol_log("Instruction: ChangeMaxLotteryDescriptionLength");
+            MigrateConfigAccount => {
+                sol_log("Instruction: MigrateConfigAccount");
 
-                check_accounts_amount(accounts_info.len(), 2)?;
+                check_accounts_amount(accounts_info.len(), 4)?;
 
-                Self::process_change_max_lottery_description_length(
-                    program_id, 
-                    accounts_info, 
-                    new_length
+                Self::process_migrate_config_account(
+                    program_id,
+                    accounts_info
                 )
             }
         }
-    }
-}
-
-fn create_pda_account<'a, 'info>(
-    new_pda_account_info: &'a AccountInfo<'info>,
-    fee_payer_account_info: &'a AccountInfo<'info>,
-    space: usize,
-    program_id: &Pubkey,
-    seeds: &[&[u8]]
-) -> ProgramResult {
-    let rent = Rent::get()?.minimum_balance(space);
-    let new_pda_account_balance = new_pda_account_info.lamports();
-    if new_pda_account_balance < rent {
-        let lamports_needed = rent
-            .checked_sub(new_pda_account_balance)
-            .unwrap();
-        
-        invoke(
-            &transfer_lamports(
-                fee_payer_account_info.key,
-                new_pda_account_info.key,
-                lamports_needed
-            ),
-            &[
-                fee_payer_account_info.clone(),
-                new_pda_account_info.clone()
-            ]
-        )?;
+    }
+}
+
+fn create_pda_account<'a, 'info>(
+    new_pda_account_info: &'a AccountInfo<'info>,
+    fee_payer_account_info: &'a AccountInfo<'info>,
+    space: usize,
+    program_id: &Pubkey,
+    seeds: &[&[u8]]
+) -> ProgramResult {
+    let rent = Rent::get()?.minimum_balance(space);
+    let new_pda_account_balance = new_pda_account_info.lamports();
+    if new_pda_account_balance < rent {
+        let lamports_needed = rent
+            .checked_sub(new_pda_account_balance)
+            .unwrap();
+        
+        invoke(
+            &transfer_lamports(
+                fee_payer_account_info.key,
+                new_pda_account_info.key,
+                lamports_needed
+            ),
+            &[
+                fee_payer_account_info.clone(),
+                new_pda_account_info.clone()
+            ]
+        )?;
+    };
+
+    invoke_signed(
+        &allocate_memory(
+            new_pda_account_info.key,
+            space as u64
+        ),
+        &[ new_pda_account_info.clone() ],
+        &[ seeds ]
+    )?;
+
+    invoke_signed(
+        &assign_new_owner(
+            new_pda_account_info.key,
+            program_id
+        ),
+        &[ new_pda_account_info.clone() ],
+        &[ seeds ]
+    )?;
+
+    Ok(())
+}
+
+// Returns the arbitrary tokens, every prize mint, every nft prize & the sol prize of the lottery to its creator,
+// their token accounts & the prize-vault are closed (failed & cancelled lotteries)
+//  remaining accounts -> prize mints, nft prizes & the prize-vault (see "WithdrawFailedLottery")
+fn refund_lottery_prizes<'a>(
+    program_id: &Pubkey,
+    lottery_account: &Lottery,
+    lottery_account_info: &AccountInfo<'a>,
+    arbitrary_mint_account_info: &AccountInfo<'a>,
+    lottery_associated_arbitrary_token_account_info: &AccountInfo<'a>,
+    fund_receiver_arbitrary_token_account_info: &AccountInfo<'a>,
+    fund_receiver_refunded_rent_exempt: &AccountInfo<'a>,
+    standard_token_program_account_info: &AccountInfo<'a>,
+    token_2022_program_account_info: &AccountInfo<'a>,
+    remaining_accounts_info: &[AccountInfo<'a>]
+) -> ProgramResult {
+    // transfer all tokens in associated_arbitrary_token_account to the fund_receiver
+    let TokenAccount { amount: arbitrary_token_amount, .. } = unpack_token_account(lottery_associated_arbitrary_token_account_info)?;
+    let MintAccount { decimals: arbitrary_token_decimals, .. } = unpack_mint_account(arbitrary_mint_account_info)?;
+
+    let arbitrary_token_program_account_info = get_token_program_account_info_of_mint(
+        arbitrary_mint_account_info,
+        standard_token_program_account_info,
+        token_2022_program_account_info
+    )?;
+
+    invoke_signed(
+        &transfer_spl_checked(
+            arbitrary_token_program_account_info.key,
+            lottery_associated_arbitrary_token_account_info.key,
+            arbitrary_mint_account_info.key,
+            fund_receiver_arbitrary_token_account_info.key,
+            lottery_account_info.key,
+            &[],
+            arbitrary_token_amount,
+            arbitrary_token_decimals
+        )?,
+        &[
+            lottery_associated_arbitrary_token_account_info.clone(),
+            arbitrary_mint_account_info.clone(),
+            fund_receiver_arbitrary_token_account_info.clone(),
+            lottery_account_info.clone()
+        ],
+        &[
+            &[
+                LOTTERY_ACCOUNT_SEED.as_bytes(),
+                &lottery_account.authority.to_bytes(),
+                get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                &[ lottery_account.canonical_bump ]
+            ]
+        ]
+    )?;
+    sol_log("arbitrary_tokens refunded.");
+
+    // close associated_arbitrary_token_account and send refund_lamports to the 
+    invoke_signed(
+        &close_token_account(
+            arbitrary_token_program_account_info.key,
+            lottery_associated_arbitrary_token_account_info.key,
+            fund_receiver_refunded_rent_exempt.key,
+            lottery_account_info.key,
+            &[]
+        )?,
+        &[
+            lottery_associated_arbitrary_token_account_info.clone(),
+            fund_receiver_refunded_rent_exempt.clone(),
+            lottery_account_info.clone()
+        ],
+        &[
+            &[
+                LOTTERY_ACCOUNT_SEED.as_bytes(),
+                &lottery_account.authority.to_bytes(),
+                get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                &[ lottery_account.canonical_bump ]
+            ]
+        ]
+    )?;
+    sol_log("Arbitrary_token_account closed & rent_exempt_lamports refunded.");
+
+    // return every prize mint & close their associated token accounts
+    //  (prize mint account, lottery's associated prize token account, fund-receiver prize token account) per prize mint
+    if remaining_accounts_info.len() < lottery_account.prize_mints.len() * 3 {
+        return Err(
+            LotteryError::InvalidAmountOfPrizeMintAccounts.into()
+        );
+    };
+    let (
+        prize_mints_accounts_info,
+        nft_prizes_accounts_info
+    ) = remaining_accounts_info.split_at(lottery_account.prize_mints.len() * 3);
+
+    for (
+        (prize_mint, _),
+        prize_mint_accounts_info
+    ) in lottery_account.prize_mints.iter().zip(prize_mints_accounts_info.chunks(3)) {
+        let prize_mint_account_info = &prize_mint_accounts_info[0];
+        let lottery_associated_prize_token_account_info = &prize_mint_accounts_info[1];
+        let fund_receiver_prize_token_account_info = &prize_mint_accounts_info[2];
+
+        check_accounts_key_to_be_identical(
+            prize_mint_account_info.key,
+            prize_mint,
+            LotteryError::InvalidPrizeMintAccount.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                prize_mint_account_info.key,
+                prize_mint_account_info.owner
+            ),
+            lottery_associated_prize_token_account_info.key,
+            LotteryError::InvalidLotteryPrizeAssociatedTokenAccount.into()
+        )?;
+
+        let TokenAccount { amount: prize_token_amount, .. } = unpack_token_account(lottery_associated_prize_token_account_info)?;
+        let MintAccount { decimals: prize_token_decimals, .. } = unpack_mint_account(prize_mint_account_info)?;
+
+        let lottery_literal_seed = get_lottery_literal_seed(&lottery_account.lottery_description);
+        let lottery_account_signer_seeds: &[&[u8]] = &[
+            LOTTERY_ACCOUNT_SEED.as_bytes(),
+            &lottery_account.authority.to_bytes(),
+            lottery_literal_seed.as_slice(),
+            &[ lottery_account.canonical_bump ]
+        ];
+
+        let prize_token_program_account_info = get_token_program_account_info_of_mint(
+            prize_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+
+        invoke_signed(
+            &transfer_spl_checked(
+                prize_token_program_account_info.key,
+                lottery_associated_prize_token_account_info.key,
+                prize_mint_account_info.key,
+                fund_receiver_prize_token_account_info.key,
+                lottery_account_info.key,
+                &[],
+                prize_token_amount,
+                prize_token_decimals
+            )?,
+            &[
+                lottery_associated_prize_token_account_info.clone(),
+                prize_mint_account_info.clone(),
+                fund_receiver_prize_token_account_info.clone(),
+                lottery_account_info.clone()
+            ],
+            &[ lottery_account_signer_seeds ]
+        )?;
+
+        invoke_signed(
+            &close_token_account(
+                prize_token_program_account_info.key,
+                lottery_associated_prize_token_account_info.key,
+                fund_receiver_refunded_rent_exempt.key,
+                lottery_account_info.key,
+                &[]
+            )?,
+            &[
+                lottery_associated_prize_token_account_info.clone(),
+                fund_receiver_refunded_rent_exempt.clone(),
+                lottery_account_info.clone()
+            ],
+            &[ lottery_account_signer_seeds ]
+        )?;
+    };
+    if lottery_account.prize_mints.is_empty() == false {
+        sol_log("Prize tokens refunded & prize_token_accounts closed.");
+    };
+
+    // return every nft prize & close their associated token accounts
+    //  (nft prize mint account, lottery's associated nft token account, fund-receiver nft token account) per rank
+    let nft_prizes = lottery_account.nft_prizes.clone().unwrap_or_default();
+    if nft_prizes_accounts_info.len() < nft_prizes.len() * 3 {
+        return Err(
+            LotteryError::InvalidAmountOfNftPrizeAccounts.into()
+        );
+    };
+    let (
+        nft_prizes_accounts_info,
+        prize_vault_accounts_info
+    ) = nft_prizes_accounts_info.split_at(nft_prizes.len() * 3);
+
+    for (
+        nft_prize_mint,
+        nft_prize_accounts_info
+    ) in nft_prizes.iter().zip(nft_prizes_accounts_info.chunks(3)) {
+        let nft_prize_mint_account_info = &nft_prize_accounts_info[0];
+        let lottery_associated_nft_token_account_info = &nft_prize_accounts_info[1];
+        let fund_receiver_nft_token_account_info = &nft_prize_accounts_info[2];
+
+        check_accounts_key_to_be_identical(
+            nft_prize_mint_account_info.key,
+            nft_prize_mint,
+            LotteryError::InvalidNftPrizeMintAccount.into()
+        )?;
+
+        check_accounts_key_to_be_identical(
+            &get_associated_token_address_with_program_id(
+                lottery_account_info.key,
+                nft_prize_mint_account_info.key,
+                nft_prize_mint_account_info.owner
+            ),
+            lottery_associated_nft_token_account_info.key,
+            LotteryError::InvalidLotteryPrizeAssociatedTokenAccount.into()
+        )?;
+
+        let lottery_literal_seed = get_lottery_literal_seed(&lottery_account.lottery_description);
+        let lottery_account_signer_seeds: &[&[u8]] = &[
+            LOTTERY_ACCOUNT_SEED.as_bytes(),
+            &lottery_account.authority.to_bytes(),
+            lottery_literal_seed.as_slice(),
+            &[ lottery_account.canonical_bump ]
+        ];
+
+        let nft_token_program_account_info = get_token_program_account_info_of_mint(
+            nft_prize_mint_account_info,
+            standard_token_program_account_info,
+            token_2022_program_account_info
+        )?;
+
+        invoke_signed(
+            &transfer_spl_checked(
+                nft_token_program_account_info.key,
+                lottery_associated_nft_token_account_info.key,
+                nft_prize_mint_account_info.key,
+                fund_receiver_nft_token_account_info.key,
+                lottery_account_info.key,
+                &[],
+                1,
+                0
+            )?,
+            &[
+                lottery_associated_nft_token_account_info.clone(),
+                nft_prize_mint_account_info.clone(),
+                fund_receiver_nft_token_account_info.clone(),
+                lottery_account_info.clone()
+            ],
+            &[ lottery_account_signer_seeds ]
+        )?;
+
+        invoke_signed(
+            &close_token_account(
+                nft_token_program_account_info.key,
+                lottery_associated_nft_token_account_info.key,
+                fund_receiver_refunded_rent_exempt.key,
+                lottery_account_info.key,
+                &[]
+            )?,
+            &[
+                lottery_associated_nft_token_account_info.clone(),
+                fund_receiver_refunded_rent_exempt.clone(),
+                lottery_account_info.clone()
+            ],
+            &[ lottery_account_signer_seeds ]
+        )?;
+    };
+    if nft_prizes.is_empty() == false {
+        sol_log("Nft prizes refunded & nft_token_accounts closed.");
+    };
+
+    // return the sol prize & close the prize-vault (its rent-exempt lamports go to the rent_exempt receiver)
+    //  (prize-vault pda, fund-receiver lamports account)
+    if prize_vault_accounts_info.len() != if lottery_account.sol_prize_amount > 0 { 2 } else { 0 } {
+        return Err(
+            LotteryError::InvalidAmountOfPrizeVaultAccounts.into()
+        );
+    };
+
+    if lottery_account.sol_prize_amount > 0 {
+        let prize_vault_account_info = &prize_vault_accounts_info[0];
+        let fund_receiver_sol_prize_account_info = &prize_vault_accounts_info[1];
+
+        lottery_account.validate_prize_vault_account(
+            prize_vault_account_info,
+            lottery_account_info.key,
+            program_id
+        )?;
+
+        let prize_vault_rent_exempt = prize_vault_account_info
+            .lamports()
+            .checked_sub(lottery_account.sol_prize_amount)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        let fund_receiver_sol_prize_account_old_balance = fund_receiver_sol_prize_account_info.lamports();
+        **fund_receiver_sol_prize_account_info.try_borrow_mut_lamports()? = fund_receiver_sol_prize_account_old_balance
+            .checked_add(lottery_account.sol_prize_amount)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        let fund_receiver_refunded_rent_exempt_old_balance = fund_receiver_refunded_rent_exempt.lamports();
+        **fund_receiver_refunded_rent_exempt.try_borrow_mut_lamports()? = fund_receiver_refunded_rent_exempt_old_balance
+            .checked_add(prize_vault_rent_exempt)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        **prize_vault_account_info.try_borrow_mut_lamports()? = 0;
+
+        sol_log("Sol prize refunded & prize-vault closed.");
+    };
+
+    Ok(())
+}
+
+// Pays an unpaid settlement bounty to the caller, see "Lottery::get_settlement_bounty_accounts_amount" for the accounts
+fn pay_settlement_bounty<'a>(
+    lottery_account: &mut Lottery,
+    lottery_account_info: &AccountInfo<'a>,
+    usdc_mint_account: &Pubkey,
+    settlement_bounty_accounts_info: &[AccountInfo<'a>]
+) -> ProgramResult {
+    if settlement_bounty_accounts_info.len() != lottery_account.get_settlement_bounty_accounts_amount() {
+        return Err(
+            LotteryError::InvalidAmountOfSettlementBountyAccounts.into()
+        );
+    };
+
+    if settlement_bounty_accounts_info.is_empty() == true {
+        return Ok(());
+    };
+
+    let bounty_receiver_account_info = &settlement_bounty_accounts_info[0];
+
+    match lottery_account.settlement_bounty.unwrap() {
+        SettlementBounty::Lamports(lamports_bounty) => {
+            let lottery_account_balance = lottery_account_info.lamports();
+            let bounty_receiver_account_balance = bounty_receiver_account_info.lamports();
+
+            **lottery_account_info.try_borrow_mut_lamports()? = lottery_account_balance
+                .checked_sub(lamports_bounty)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            **bounty_receiver_account_info.try_borrow_mut_lamports()? = bounty_receiver_account_balance
+                .checked_add(lamports_bounty)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        },
+        SettlementBounty::Usdc(usdc_bounty) => {
+            let usdc_mint_account_info = &settlement_bounty_accounts_info[1];
+            let lottery_associated_usdc_token_account_info = &settlement_bounty_accounts_info[2];
+            let usdc_token_program_account_info = &settlement_bounty_accounts_info[3];
+
+            check_accounts_key_to_be_identical(
+                usdc_mint_account_info.key,
+                usdc_mint_account,
+                LotteryError::InvalidUsdcMintAccount.into()
+            )?;
+
+            check_token_program_account_of_mint(
+                usdc_token_program_account_info,
+                usdc_mint_account_info
+            )?;
+
+            check_accounts_key_to_be_identical(
+                &get_associated_token_address_with_program_id(
+                    lottery_account_info.key,
+                    usdc_mint_account_info.key,
+                    usdc_token_program_account_info.key
+                ),
+                lottery_associated_usdc_token_account_info.key,
+                LotteryError::InvalidLotteryAssociatedUsdcTokenAccount.into()
+            )?;
+
+            let MintAccount { decimals, .. } = unpack_mint_account(usdc_mint_account_info)?;
+
+            invoke_signed(
+                &transfer_spl_checked(
+                    usdc_token_program_account_info.key,
+                    lottery_associated_usdc_token_account_info.key,
+                    usdc_mint_account_info.key,
+                    bounty_receiver_account_info.key,
+                    lottery_account_info.key,
+                    &[],
+                    usdc_bounty,
+                    decimals
+                )?,
+                &[
+                    lottery_associated_usdc_token_account_info.clone(),
+                    usdc_mint_account_info.clone(),
+                    bounty_receiver_account_info.clone(),
+                    lottery_account_info.clone()
+                ],
+                &[
+                    &[
+                        LOTTERY_ACCOUNT_SEED.as_bytes(),
+                        &lottery_account.authority.to_bytes(),
+                        get_lottery_literal_seed(&lottery_account.lottery_description).as_slice(),
+                        &[ lottery_account.canonical_bump ]
+                    ]
+                ]
+            )?;
+        }
     };
 
-    invoke_signed(
-        &allocate_memory(
-            new_pda_account_info.key,
-            space as u64
-        ),
-        &[ new_pda_account_info.clone() ],
-        &[ seeds ]
-    )?;
+    lottery_account.is_settlement_bounty_paid = true;
 
-    invoke_signed(
-        &assign_new_owner(
-            new_pda_account_info.key,
-            program_id
-        ),
-        &[ new_pda_account_info.clone() ],
-        &[ seeds ]
-    )?;
+    solana_program::msg!(
+        "Settlement bounty paid => {}",
+        bounty_receiver_account_info.key
+    );
 
     Ok(())
 }
@@ -2962,6 +9311,20 @@ pub fn check_accounts_amount(
     Ok(())
 }
 
+// Instructions which accept remaining accounts
+pub fn check_min_accounts_amount(
+    accounts_len: usize,
+    expected_min_len: usize
+) -> ProgramResult {
+    if accounts_len < expected_min_len {
+        return Err(
+            ProgramError::NotEnoughAccountKeys
+        );
+    };
+
+    Ok(())
+}
+
 // We don't need this checker function BUT to be developer-friendly we used it.
 pub fn check_system_program_id(program_id: &Pubkey) -> ProgramResult {
     if check_id(program_id) == false {
@@ -3006,6 +9369,51 @@ pub fn get_price(
     Ok(price)
 }
 
+// Most recent entry of the "SlotHashes" sysvar -> data: len (u64) || [ slot (u64) || hash ], newest first
+pub fn get_most_recent_slot_hash(
+    sysvar_slot_hashes_account_info: &AccountInfo
+) -> Result<SlotHashInfo, ProgramError> {
+    if sysvar_slot_hashes_account_info.key != &solana_program::sysvar::slot_hashes::ID {
+        return Err(
+            LotteryError::InvalidSysvarSlotHashesAccount.into()
+        );
+    };
+
+    let sysvar_slot_hashes_data = sysvar_slot_hashes_account_info
+        .data
+        .try_borrow()
+        .unwrap();
+
+    let entries_amount = u64::from_le_bytes(
+        sysvar_slot_hashes_data
+            .get(..8)
+            .ok_or::<ProgramError>(LotteryError::InvalidSysvarSlotHashesAccount.into())?
+            .try_into()
+            .unwrap()
+    );
+    if entries_amount == 0 {
+        return Err(
+            LotteryError::InvalidSysvarSlotHashesAccount.into()
+        );
+    };
+
+    let slot = u64::from_le_bytes(
+        sysvar_slot_hashes_data
+            .get(8..16)
+            .ok_or::<ProgramError>(LotteryError::InvalidSysvarSlotHashesAccount.into())?
+            .try_into()
+            .unwrap()
+    );
+
+    let slot_hash: SlotHash = sysvar_slot_hashes_data
+        .get(16..16 + HASH_BYTES)
+        .ok_or::<ProgramError>(LotteryError::InvalidSysvarSlotHashesAccount.into())?
+        .try_into()
+        .unwrap();
+
+    Ok((slot, slot_hash))
+}
+
 pub fn calculate_fee_and_update_lottery_account(
     config_account: &Config,
     lottery_account: &Lottery,
@@ -3017,23 +9425,14 @@ pub fn calculate_fee_and_update_lottery_account(
         .checked_mul(*usdc_per_ticket)
         .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
 
-    let protocol_fee_per_tickets = config_account.lottery_tickets_fee;
-    let protocol_fee = ((total_tickets_price as f64) * protocol_fee_per_tickets) / ((10u64.pow(2)) as f64);
-
-    // check for overflow, underflow & NAN 
-    if 
-        protocol_fee == f64::INFINITY || 
-        protocol_fee == f64::NEG_INFINITY || 
-        protocol_fee.is_nan() == true 
-    {
-        return Err(
-            LotteryError::Overflow.into()
-        );
-    };
+    let protocol_fee = calculate_protocol_fee(
+        config_account,
+        total_tickets_price
+    )?;
 
     let old_protocol_fee = lottery_account.protocol_fee;
     let new_protocol_fee = old_protocol_fee
-        .checked_add(protocol_fee as u64)
+        .checked_add(protocol_fee)
         .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
 
     // update lottery's protocol_fee
@@ -3051,6 +9450,74 @@ pub fn calculate_fee_and_update_lottery_account(
     Ok(total_tickets_price)
 }
 
+// "lottery_tickets_fee" % of the tickets price, in the asset the tickets are paid with
+pub fn calculate_protocol_fee(
+    config_account: &Config,
+    total_tickets_price: u64
+) -> Result<u64, ProgramError> {
+    let protocol_fee_per_tickets = config_account.lottery_tickets_fee;
+    let protocol_fee = ((total_tickets_price as f64) * protocol_fee_per_tickets) / ((10u64.pow(2)) as f64);
+
+    // check for overflow, underflow & NAN 
+    if 
+        protocol_fee == f64::INFINITY || 
+        protocol_fee == f64::NEG_INFINITY || 
+        protocol_fee.is_nan() == true 
+    {
+        return Err(
+            LotteryError::Overflow.into()
+        );
+    };
+
+    Ok(protocol_fee as u64)
+}
+
+// Lamports worth "usdc_amount" at the SOL/USD price, rounded up so the lottery never receives less than the tickets' value
+pub fn convert_usdc_to_lamports(
+    usdc_amount: u64,
+    usdc_decimals: u8,
+    sol_price: &Price
+) -> Result<u64, ProgramError> {
+    if sol_price.price <= 0 {
+        return Err(
+            LotteryError::InvalidOraclePrice.into()
+        );
+    };
+
+    // lamports = usdc_amount * 10^(9 - usdc_decimals - exponent) / price
+    let decimals_difference = (LAMPORTS_DECIMALS as i32)
+        .checked_sub(usdc_decimals as i32)
+        .and_then(|difference| difference.checked_sub(sol_price.exponent))
+        .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+    let scale = 10u128
+        .checked_pow(decimals_difference.unsigned_abs())
+        .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+    let (numerator, denominator) = if decimals_difference >= 0 {
+        (
+            (usdc_amount as u128)
+                .checked_mul(scale)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?,
+            sol_price.price as u128
+        )
+    } else {
+        (
+            usdc_amount as u128,
+            (sol_price.price as u128)
+                .checked_mul(scale)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+        )
+    };
+
+    numerator
+        .checked_add(denominator - 1)
+        .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+        .checked_div(denominator)
+        .and_then(|lamports| u64::try_from(lamports).ok())
+        .ok_or::<ProgramError>(LotteryError::Overflow.into())
+}
+
 pub fn compare_usdc_mint_account_with_config_global_account_info(
     config_global_account_info: &AccountInfo,
     expected_usdc_mint_account: &Pubkey
@@ -3131,15 +9598,159 @@ pub fn check_account_is_raw(account_info: &AccountInfo) -> ProgramResult {
     Ok(())
 }
 
+// Both token programs are supported, the owner of each mint decides which one its cpis are sent to.
+pub fn check_token_program_id(program_id: &Pubkey) -> ProgramResult {
+    if program_id != &TOKEN_STANDARD_PROGRAM_ID && program_id != &TOKEN_2022_PROGRAM_ID {
+        return Err(
+            LotteryError::OnlyTokenStandardProgram.into()
+        );
+    };
+
+    Ok(())
+}
+
+pub fn check_token_program_account_of_mint(
+    token_program_account_info: &AccountInfo,
+    mint_account_info: &AccountInfo
+) -> ProgramResult {
+    check_token_program_id(mint_account_info.owner)?;
+
+    check_accounts_key_to_be_identical(
+        token_program_account_info.key,
+        mint_account_info.owner,
+        LotteryError::InvalidTokenProgramAccount.into()
+    )
+}
+
+// Instructions which move tokens of several mints receive both token programs.
+pub fn get_token_program_account_info_of_mint<'a, 'info>(
+    mint_account_info: &AccountInfo<'info>,
+    standard_token_program_account_info: &'a AccountInfo<'info>,
+    token_2022_program_account_info: &'a AccountInfo<'info>
+) -> Result<&'a AccountInfo<'info>, ProgramError> {
+    check_token_program_id(mint_account_info.owner)?;
+
+    [
+        standard_token_program_account_info,
+        token_2022_program_account_info
+    ]
+        .into_iter()
+        .find(|token_program_account_info| token_program_account_info.key == mint_account_info.owner)
+        .ok_or(LotteryError::InvalidTokenProgramAccount.into())
+}
+
+pub fn unpack_mint_account(mint_account_info: &AccountInfo) -> Result<MintAccount, ProgramError> {
+    check_token_program_id(mint_account_info.owner)?;
+
+    let mint_account_data = mint_account_info
+        .data
+        .try_borrow()
+        .unwrap();
+
+    Ok(
+        StateWithExtensions::<MintAccount>::unpack(&mint_account_data)?.base
+    )
+}
+
+pub fn unpack_token_account(token_account_info: &AccountInfo) -> Result<TokenAccount, ProgramError> {
+    check_token_program_id(token_account_info.owner)?;
+
+    let token_account_data = token_account_info
+        .data
+        .try_borrow()
+        .unwrap();
+
+    Ok(
+        StateWithExtensions::<TokenAccount>::unpack(&token_account_data)?.base
+    )
+}
+
+// Transfer-hooks need accounts we can't know about & non-transferable tokens can't be escrowed, both are rejected.
+pub fn check_mint_extensions(mint_account_info: &AccountInfo) -> ProgramResult {
+    if mint_account_info.owner != &TOKEN_2022_PROGRAM_ID {
+        return Ok(());
+    };
+
+    let mint_account_data = mint_account_info
+        .data
+        .try_borrow()
+        .unwrap();
+    let mint_account = StateWithExtensions::<MintAccount>::unpack(&mint_account_data)?;
+
+    if 
+        mint_account.get_extension::<TransferHook>().is_ok() == true ||
+        mint_account.get_extension::<NonTransferable>().is_ok() == true
+    {
+        return Err(
+            LotteryError::UnsupportedMintExtension.into()
+        );
+    };
+
+    Ok(())
+}
+
+// Fee withheld by a token-2022 "TransferFeeConfig" when "amount" is sent, the receiver gets "amount - fee".
+pub fn get_transfer_fee(
+    mint_account_info: &AccountInfo,
+    amount: u64
+) -> Result<u64, ProgramError> {
+    if mint_account_info.owner != &TOKEN_2022_PROGRAM_ID {
+        return Ok(0);
+    };
+
+    let mint_account_data = mint_account_info
+        .data
+        .try_borrow()
+        .unwrap();
+    let mint_account = StateWithExtensions::<MintAccount>::unpack(&mint_account_data)?;
+
+    match mint_account.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_epoch_fee((Clock::get()?).epoch, amount)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into()),
+        Err(_) => Ok(0)
+    }
+}
+
+// Amount to send so that the receiver gets exactly "amount" after the token-2022 transfer fee.
+pub fn get_amount_including_transfer_fee(
+    mint_account_info: &AccountInfo,
+    amount: u64
+) -> Result<u64, ProgramError> {
+    if mint_account_info.owner != &TOKEN_2022_PROGRAM_ID {
+        return Ok(amount);
+    };
+
+    let mint_account_data = mint_account_info
+        .data
+        .try_borrow()
+        .unwrap();
+    let mint_account = StateWithExtensions::<MintAccount>::unpack(&mint_account_data)?;
+
+    let transfer_fee = match mint_account.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_inverse_epoch_fee((Clock::get()?).epoch, amount)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?,
+        Err(_) => 0
+    };
+
+    amount
+        .checked_add(transfer_fee)
+        .ok_or::<ProgramError>(LotteryError::Overflow.into())
+}
+
 #[cfg(test)]
 mod test_processor {
     use {
         super::{
             get_price,
+            get_most_recent_slot_hash,
             BorshSerialize,
             VerificationLevel,
             Pubkey,
             calculate_fee_and_update_lottery_account,
+            convert_usdc_to_lamports,
+            Price,
             compare_usdc_mint_account_with_config_global_account_info,
             check_max_numbers_of_winner,
             check_max_price_feed_age,
@@ -3148,7 +9759,8 @@ mod test_processor {
             check_account_is_raw,
             AccountInfo,
             Config,
-            Lottery
+            Lottery,
+            LotteryError
         },
         std::{
             rc::Rc,
@@ -3206,6 +9818,71 @@ mod test_processor {
         );
     }
 
+    #[test]
+    fn test_get_most_recent_slot_hash() {
+        let mut slot_hashes_data: Vec<u8> = vec![
+            2u64.to_le_bytes().to_vec(),
+            11u64.to_le_bytes().to_vec(), vec![ 7u8; 32 ],
+            10u64.to_le_bytes().to_vec(), vec![ 6u8; 32 ]
+        ].concat();
+
+        let slot_hash = get_most_recent_slot_hash(
+            &AccountInfo {
+                lamports: Rc::new(RefCell::new(&mut u64::default())),
+                owner: &solana_program::sysvar::ID,
+                key: &solana_program::sysvar::slot_hashes::ID,
+                data: Rc::new(RefCell::new(&mut slot_hashes_data)),
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            }
+        ).unwrap();
+
+        assert_eq!(
+            slot_hash,
+            (11, [7u8; 32])
+        );
+
+        // invalid sysvar account
+        let error = get_most_recent_slot_hash(
+            &AccountInfo {
+                lamports: Rc::new(RefCell::new(&mut u64::default())),
+                owner: &solana_program::sysvar::ID,
+                key: &Pubkey::new_unique(),
+                data: Rc::new(RefCell::new(&mut slot_hashes_data)),
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            }
+        ).unwrap_err();
+
+        assert_eq!(
+            error,
+            LotteryError::InvalidSysvarSlotHashesAccount.into()
+        );
+
+        // no slot-hash entry
+        let error = get_most_recent_slot_hash(
+            &AccountInfo {
+                lamports: Rc::new(RefCell::new(&mut u64::default())),
+                owner: &solana_program::sysvar::ID,
+                key: &solana_program::sysvar::slot_hashes::ID,
+                data: Rc::new(RefCell::new(&mut 0u64.to_le_bytes().to_vec())),
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            }
+        ).unwrap_err();
+
+        assert_eq!(
+            error,
+            LotteryError::InvalidSysvarSlotHashesAccount.into()
+        );
+    }
+
     #[test]
     fn test_calculate_fee_and_update_lottery_account() {
         let mut config_account = Config::default();
@@ -3253,6 +9930,35 @@ mod test_processor {
         );
     }
 
+    #[test]
+    fn test_convert_usdc_to_lamports() {
+        let mut sol_price = Price {
+            price: 250_00000000, // 250 USD
+            conf: 0,
+            exponent: -8,
+            publish_time: 0
+        };
+
+        // 10 USDC -> 0.04 SOL
+        assert_eq!(
+            convert_usdc_to_lamports(10_000000, 6, &sol_price).unwrap(),
+            40_000000
+        );
+
+        // rounded up in favor of the lottery
+        sol_price.price = 300_00000000;
+        assert_eq!(
+            convert_usdc_to_lamports(1_000000, 6, &sol_price).unwrap(),
+            3_333334
+        );
+
+        sol_price.price = 0;
+        assert_eq!(
+            convert_usdc_to_lamports(1_000000, 6, &sol_price),
+            Err(LotteryError::InvalidOraclePrice.into())
+        );
+    }
+
     #[test]
     fn test_compare_usdc_mint_account_with_config_global_account_info() {
         let mut config_account = Config::default();
@@ -1,7 +1,11 @@
 use {
     crate::{
         error::LotteryError,
-        types::*
+        types::*,
+        vrf::{
+            verify_vrf_proof,
+            VRF_PROOF_LENGTH
+        }
     },
 
     borsh::{
@@ -16,6 +20,7 @@ use {
         entrypoint::ProgramResult,
         hash::{
             hash,
+            hashv,
             HASH_BYTES
         },
         log::sol_log,
@@ -38,6 +43,8 @@ pub(crate) const LOTTERY_ACCOUNT_DISCRIMINATOR: &str = "account:Lottery";
 pub(crate) const USER_ACCOUNT_DISCRIMINATOR: &str = "account:User";
 pub(crate) const CLOSED_USER_ACCOUNT_DISCRIMINATOR: &str = "CLOSED_USER_ACCOUNT";
 pub(crate) const CLOSED_LOTTERY_ACCOUNT_DISCRIMINATOR: &str = "CLOSED_LOTTERY_ACCOUNT";
+pub(crate) const RANDOMNESS_REQUEST_ACCOUNT_DISCRIMINATOR: &str = "account:RandomnessRequest";
+pub(crate) const LOTTERY_SERIES_ACCOUNT_DISCRIMINATOR: &str = "account:LotterySeries";
 
 // Discriminator Length
 pub(crate) const DISCRIMINATOR_LENTGH: usize = 8;
@@ -48,6 +55,12 @@ pub(crate) const CANONICAL_BUMP_LENGTH: usize = 1;
 pub(crate) const CONFIG_ACCOUNT_SEED: &str = "solottery_program_config_account";
 pub(crate) const LOTTERY_ACCOUNT_SEED: &str = "lottery_account";
 pub(crate) const USER_ACCOUNT_SEED: &str = "user_account";
+pub(crate) const RANDOMNESS_REQUEST_ACCOUNT_SEED: &str = "randomness_request_account";
+pub(crate) const PRIZE_VAULT_ACCOUNT_SEED: &str = "prize_vault_account";
+pub(crate) const LOTTERY_SERIES_ACCOUNT_SEED: &str = "lottery_series_account";
+pub(crate) const SOL_PAYMENTS_VAULT_ACCOUNT_SEED: &str = "sol_payments_vault_account";
+pub(crate) const SERIES_PRIZE_VAULT_ACCOUNT_SEED: &str = "series_prize_vault_account";
+pub(crate) const TICKET_MINT_ACCOUNT_SEED: &str = "ticket_mint_account";
 
 #[derive(Debug, BorshDeserialize, BorshSerialize, Clone, PartialEq, Default)]
 pub struct Config {
@@ -69,7 +82,12 @@ pub struct Config {
     pub maximum_time_for_lottery_account: u32, // in seconds
     pub treasury: Pubkey, // USDC token account
     pub max_lottery_description_bytes: u64,
-    pub pyth_price_feed_ids: [String; 3] // SOL, BTC, ETH
+    pub pyth_price_feed_ids: [String; 3], // SOL, BTC, ETH
+    pub vrf_oracle_authority: Pubkey, // signs the randomness requests of "VrfOracle" lotteries
+    pub lottery_cancellation_penalty: u64, // USDC, kept by the treasury from the creation fee of a cancelled lottery
+    pub prize_claim_window: u32, // in seconds after "ending_time", then "ReclaimUnclaimedPrizes" sweeps the unclaimed prizes ("0" -> prizes never expire)
+    pub unclaimed_prizes_receiver: UnclaimedPrizesReceiver,
+    pub sol_treasury: Pubkey // receives the protocol fee of the tickets paid in SOL ("BuyTicketWithOracleConversion")
 }
 impl Config {
     pub const LEN: usize =
@@ -89,7 +107,12 @@ impl Config {
         size_of::<u32>() +
         size_of::<Pubkey>() +
         size_of::<u64>() +
-        (3 * 70);
+        (3 * 70) +
+        size_of::<Pubkey>() +
+        size_of::<u64>() +
+        size_of::<u32>() +
+        size_of::<u8>() +
+        size_of::<Pubkey>();
 
     pub fn new(
         canonical_bump: u8,
@@ -124,7 +147,12 @@ impl Config {
             maximum_time_for_lottery_account,
             treasury,
             pyth_price_feed_ids,
-            max_lottery_description_bytes
+            max_lottery_description_bytes,
+            vrf_oracle_authority: Pubkey::default(),
+            lottery_cancellation_penalty: u64::default(),
+            prize_claim_window: u32::default(),
+            unclaimed_prizes_receiver: UnclaimedPrizesReceiver::default(),
+            sol_treasury: authority
         })
     }
 
@@ -236,6 +264,26 @@ impl Config {
         Ok(())
     }
 
+    // Tickets paid in SOL are priced by the SOL feed only
+    pub fn validate_sol_price_feed_account(
+        &self,
+        sol_price_feed_account_info: &AccountInfo
+    ) -> ProgramResult {
+        if sol_price_feed_account_info.owner != &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID {
+            return Err(
+                LotteryError::InvalidPriceFeedAccountsOwner.into()
+            );
+        };
+
+        if sol_price_feed_account_info.key != self.pyth_price_feed_accounts.get(0).unwrap() {
+            return Err(
+                LotteryError::InvalidSolPriceFeedAccount.into()
+            );
+        };
+
+        Ok(())
+    }
+
     pub fn validate_fee_per_ticket(fee: &f64) -> ProgramResult {
         if fee >= &100_f64 {
             return Err(
@@ -268,8 +316,48 @@ impl Config {
     }
 }
 
+// Who sweeps the prizes left unclaimed after the claim window ("ReclaimUnclaimedPrizes")
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Clone, Copy, Default)]
+pub enum UnclaimedPrizesReceiver {
+    #[default]
+    Creator, // lottery's authority
+    Treasury // authority (owner) of the treasury token account
+}
+
 // Maximum number of tickets that can be added to a lottery in a single instruction
 const MAX_TICKETS_PER_INSTRUCTION: usize = 300;
+// Maximum number of prize mints that can be added to a lottery (besides its arbitrary mint)
+pub(crate) const MAX_PRIZE_MINTS_PER_LOTTERY: usize = 4;
+// Length of a prize mint in the lottery account -> (prize mint, prize amount)
+pub(crate) const PRIZE_MINT_INFO_LENGTH: usize = size_of::<PrizeMint>() + size_of::<PrizeAmount>();
+// Sum of the prize table's shares
+pub(crate) const MAX_BASIS_POINTS: BasisPoints = 10_000;
+// Length of a ticket-range in the lottery's ticket ledger -> (user's pda-account pubkey, cumulative end index)
+pub(crate) const TICKET_RANGE_LENGTH: usize = size_of::<UserAccount>() + size_of::<TicketEndIndex>();
+// Length of a round's series jackpot in the lottery account, see "SeriesJackpot"
+pub(crate) const SERIES_JACKPOT_LENGTH: usize =
+    size_of::<Pubkey>() +
+    size_of::<BasisPoints>() +
+    size_of::<u32>() +
+    size_of::<bool>() +
+    size_of::<bool>() +
+    size_of::<bool>() +
+    size_of::<u64>() +
+    size_of::<[u8; 32]>();
+// Length of a series' round template, see "RoundTemplate"
+pub(crate) const ROUND_TEMPLATE_LENGTH: usize =
+    size_of::<Pubkey>() +
+    size_of::<u64>() +
+    size_of::<u64>() +
+    size_of::<u8>() +
+    size_of::<u32>() +
+    size_of::<u32>();
+// After "ending_time + MAX_TIME_TO_REVEAL_SECRET" winners can be picked without the creator's secret (oracle-only randomness)
+pub(crate) const MAX_TIME_TO_REVEAL_SECRET: Time = 86_400; // in seconds
+// After "ending_time + MAX_TIME_TO_REVEAL_SECRET + MAX_TIME_TO_FULFIL_RANDOMNESS" unfulfilled "VrfOracle" lotteries are failed (tickets refunded)
+pub(crate) const MAX_TIME_TO_FULFIL_RANDOMNESS: Time = 86_400; // in seconds
+// After "ending_time + MAX_TIME_TO_REFUND_TICKET_TOKENS" the ticket tokens of a failed lottery not refunded yet are swept to the treasury on its close
+pub(crate) const MAX_TIME_TO_REFUND_TICKET_TOKENS: Time = 2_592_000; // in seconds (30 days)
 
 #[derive(Debug, BorshDeserialize, BorshSerialize, Clone, PartialEq, Eq, Default)]
 pub struct Lottery {
@@ -281,7 +369,7 @@ pub struct Lottery {
     pub arbitrary_mint_account_address: Pubkey,
     pub ticket_price: u64, // USDC
     pub lottery_creation_fee: u64, // USDC
-    pub winners_count: u8, // Note: fund_amount % winners_count == 0
+    pub winners_count: u8, // Note: fund_amount % winners_count == 0 (without a prize table)
     pub minimum_tickets_amount_required_to_be_sold: u32,
     pub created_at: Time,
     pub starting_time: Time,
@@ -291,12 +379,111 @@ pub struct Lottery {
     pub is_creator_withdrawed_when_lottery_was_failed: bool,
     pub is_ended_successfuly: bool,
     pub is_protocol_fee_claimed: bool,
-    pub random_numbers_info: RandomNumberInfo,
+    pub random_numbers_info: RandomNumbersInfo,
     pub tickets_total_amount: u32,
+    pub ticket_ranges_total_amount: u32,
     pub maximum_number_of_tickets_per_user: Option<u32>,
+    pub randomness_commitment: Option<[u8; HASH_BYTES]>, // sha256(lottery_account || secret)
+    pub revealed_secret: [u8; HASH_BYTES],
+    pub is_secret_revealed: bool,
+    pub randomness_source: RandomnessSource,
+    pub is_randomness_fulfilled: bool, // set by "FulfilRandomness", unfulfilled "VrfOracle" lotteries are failed after the fulfilment deadline
+    pub prize_table: Option<Vec<BasisPoints>>, // share of the fund per rank (1st, 2nd, ...), "None" -> equal split
+    pub prize_mints: Vec<PrizeMintInfo>, // added by "AddPrizeMint", split between the winners like the fund
+    pub nft_prizes: Option<Vec<NftPrizeMint>>, // nft mint per rank (1st, 2nd, ...), escrowed at creation
+    pub sol_prize_amount: u64, // lamports escrowed in the prize-vault pda ("0" -> no sol prize), the vault's own rent-exempt isn't part of it
+    pub prize_vault_canonical_bump: u8,
+    pub pool_creator_share: Option<BasisPoints>, // pool lotteries -> creator's share of the ticket sales, the rest is the winners' prize
+    pub pool_amount: u64, // ticket sales minus the protocol fees, taken at the first withdrawal of a pool lottery (fixed size, the ledger follows)
+    pub series_jackpot: Option<SeriesJackpot>, // set by "JoinLotterySeries", the round's share of the series' rolling jackpot
+    pub sol_payments_amount: u64, // lamports paid by "BuyTicketWithOracleConversion" (protocol fee included), escrowed in the sol-payments-vault pda
+    pub sol_protocol_fee: u64, // lamports, protocol fee of the tickets paid in SOL
+    pub maximum_total_tickets: Option<u32>, // sold out -> the lottery ends before "ending_time"
+    pub settlement_bounty: Option<SettlementBounty>, // paid to whoever settles the lottery ("EndLotteryAndPickWinners" or "SettleFailedLottery")
+    pub is_settlement_bounty_paid: bool,
+    pub is_unclaimed_prizes_reclaimed: bool, // set by "ReclaimUnclaimedPrizes", winners can't withdraw anymore
+    pub is_arbitrary_token_account_closed: bool, // succeeded lotteries -> set by "CloseLotteryArbitraryTokenAccount" or "ReclaimUnclaimedPrizes"
+    pub prize_token_accounts_amount: u16, // prize-mint & nft-prize token accounts of the lottery not closed yet, a succeeded lottery is closed once they all are
+    pub is_tickets_tokenized: bool, // set by "EnableTicketTokens", "BuyTicket" mints ticket tokens, redeemed into the ticket ledger by "RedeemTicketTokens"
+    pub ticket_mint_canonical_bump: u8, // ticket-mint pda, its own mint authority
+    pub ticket_tokens_outstanding: u32, // minted & not redeemed (or refunded) yet, counted by "maximum_total_tickets"
+    pub ticket_tokens_value: u64, // USDC paid for the outstanding ticket tokens
     pub lottery_description: String,
     pub winners: Vec<WinnerStatus>
-    // tickets (user's pda-account pubkey) - zero_copy
+    // ticket ranges (user's pda-account pubkey, cumulative end index), one per purchase - zero_copy
+}
+
+// Where the entropy of the winners-seed comes from, chosen by the lottery's creator
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Clone, Copy, Default)]
+pub enum RandomnessSource {
+    #[default]
+    PythPriceFeeds,
+    VrfOracle // "RequestRandomness" + "FulfilRandomness"
+}
+
+// Every input of the winners-seed, recorded on the lottery so anyone can recompute the draw off-chain
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Clone, Copy, Default)]
+pub struct RandomNumbersInfo {
+    pub price_feeds: [RandomNumberInfo; 3], // SOL, BTC, ETH ("VrfOracle" -> randomness-request account & its fulfilment time)
+    pub slot_hash: SlotHashInfo // most recent "SlotHashes" entry
+}
+
+impl RandomNumbersInfo {
+    // Entropy of "PythPriceFeeds" lotteries -> (price || confidence || publish_time) of each feed || slot || slot_hash || lottery_account
+    pub fn get_price_feeds_randomness(
+        &self,
+        lottery_account: &Pubkey
+    ) -> Vec<u8> {
+        let mut randomness: Vec<u8> = Vec::with_capacity(
+            (3 * (size_of::<Price>() + size_of::<PriceConfidence>() + size_of::<PricePublishTime>())) +
+            size_of::<Slot>() +
+            size_of::<SlotHash>() +
+            size_of::<Pubkey>()
+        );
+
+        for (_, publish_time, price, confidence) in self.price_feeds.iter() {
+            randomness.extend_from_slice(price.to_le_bytes().as_slice());
+            randomness.extend_from_slice(confidence.to_le_bytes().as_slice());
+            randomness.extend_from_slice(publish_time.to_le_bytes().as_slice());
+        };
+
+        let (slot, slot_hash) = self.slot_hash;
+        randomness.extend_from_slice(slot.to_le_bytes().as_slice());
+        randomness.extend_from_slice(slot_hash.as_slice());
+        randomness.extend_from_slice(lottery_account.as_ref());
+
+        randomness
+    }
+}
+
+// Deposited by the creator at creation, rewards the keeper that settles the lottery after its end
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Clone, Copy)]
+pub enum SettlementBounty {
+    Lamports(u64), // held by the lottery account on top of its rent-exempt lamports
+    Usdc(u64) // held by the lottery's associated usdc token account (amount received after the transfer fee)
+}
+
+// Jackpot of a series round, copied from the series by "JoinLotterySeries" & drawn with the winners
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Clone, Copy, Default)]
+pub struct SeriesJackpot {
+    pub series: Pubkey,
+    pub jackpot_share: BasisPoints, // share of the creator's ticket revenue sent to the series' jackpot vault
+    pub house_slots: u32, // jackpot draw -> one of "tickets_total_amount + house_slots" slots, a house slot rolls the jackpot over
+    pub is_drawn: bool,
+    pub is_won: bool,
+    pub is_amount_reserved: bool,
+    pub amount: u64, // unreserved jackpot of the series vault, fixed & reserved in the series when the round's jackpot is won
+    pub claimed_ranks: [u8; 32] // one bit per rank (every "Rank" fits)
+}
+
+impl SeriesJackpot {
+    pub fn is_rank_claimed(&self, rank: Rank) -> bool {
+        self.claimed_ranks[(rank / 8) as usize] & (1 << (rank % 8)) != 0
+    }
+
+    pub fn set_rank_claimed(&mut self, rank: Rank) {
+        self.claimed_ranks[(rank / 8) as usize] |= 1 << (rank % 8);
+    }
 }
 
 #[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Clone, Copy)]
@@ -321,8 +508,20 @@ impl Lottery {
         arbitrary_mint_account_address: Pubkey,
         authority: Pubkey,
         maximum_number_of_tickets_per_user: Option<u32>,
+        randomness_commitment: Option<[u8; HASH_BYTES]>,
+        randomness_source: RandomnessSource,
+        prize_table: Option<Vec<BasisPoints>>,
+        nft_prizes: Option<Vec<NftPrizeMint>>,
+        sol_prize_amount: u64,
+        prize_vault_canonical_bump: u8,
+        pool_creator_share: Option<BasisPoints>,
+        maximum_total_tickets: Option<u32>,
+        settlement_bounty: Option<SettlementBounty>,
         lottery_description: String
     ) -> Self {
+        // the nft prizes are escrowed in a token account each, at creation
+        let prize_token_accounts_amount = nft_prizes.as_ref().map_or(0, |nft_prizes| nft_prizes.len() as u16);
+
         Self {
             discriminator: Self::get_discriminator(),
             canonical_bump,
@@ -342,10 +541,36 @@ impl Lottery {
             is_creator_withdrawed_when_lottery_was_successful: bool::default(),
             is_creator_withdrawed_when_lottery_was_failed: bool::default(),
             maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            revealed_secret: [0; HASH_BYTES],
+            is_secret_revealed: bool::default(),
+            randomness_source,
+            is_randomness_fulfilled: bool::default(),
+            prize_table,
+            prize_mints: Vec::default(),
+            nft_prizes,
+            sol_prize_amount,
+            prize_vault_canonical_bump,
+            pool_creator_share,
+            pool_amount: 0,
+            series_jackpot: None,
+            sol_payments_amount: 0,
+            sol_protocol_fee: 0,
+            maximum_total_tickets,
+            settlement_bounty,
+            is_settlement_bounty_paid: bool::default(),
+            is_unclaimed_prizes_reclaimed: bool::default(),
+            is_arbitrary_token_account_closed: bool::default(),
+            prize_token_accounts_amount,
+            is_tickets_tokenized: bool::default(),
+            ticket_mint_canonical_bump: u8::default(),
+            ticket_tokens_outstanding: u32::default(),
+            ticket_tokens_value: u64::default(),
             lottery_description,
-            random_numbers_info: (Pubkey::default(), i64::default(), i64::default()),
+            random_numbers_info: RandomNumbersInfo::default(),
             is_protocol_fee_claimed: bool::default(),
             tickets_total_amount: u32::default(),
+            ticket_ranges_total_amount: u32::default(),
             winners: Vec::default()
         }
     }
@@ -387,1498 +612,3916 @@ impl Lottery {
         &self,
         current_time: Time
     ) -> bool {
-        current_time >= self.starting_time && current_time < self.ending_time
+        current_time >= self.starting_time && current_time < self.ending_time && self.is_sold_out() == false
     }
 
+    // A sold out lottery is ended, its winners can be picked before "ending_time"
     pub fn is_ended(
         &self,
         current_time: Time
     ) -> bool {
-        current_time >= self.ending_time
+        current_time >= self.ending_time || (current_time >= self.starting_time && self.is_sold_out() == true)
     }
 
-    pub fn add_ticket(
-        lottery_account_info: &AccountInfo,
-        initial_bytes: u64,
-        tickets_total_amount: u32,
-        tickets_amount: u32,
-        user_account_pda: Pubkey
-    ) {
-        let mut lottery_account_data = lottery_account_info
-            .data
-            .try_borrow_mut()
-            .unwrap();
-
-        let mut current_offset = initial_bytes.checked_add(
-            (tickets_total_amount as usize).checked_mul(
-                size_of::<Pubkey>()
-            ).unwrap() as u64
-        ).unwrap() as usize;
+    pub fn is_sold_out(&self) -> bool {
+        self.maximum_total_tickets.map_or(false, |maximum_total_tickets| self.tickets_total_amount >= maximum_total_tickets)
+    }
 
-        let ticket_pubkey = &user_account_pda.to_bytes();
+    pub fn validate_maximum_total_tickets(
+        maximum_total_tickets: &Option<u32>,
+        minimum_tickets_amount_required_to_be_sold: u32
+    ) -> ProgramResult {
+        if maximum_total_tickets.map_or(false, |maximum| maximum < minimum_tickets_amount_required_to_be_sold) == true {
+            return Err(
+                LotteryError::InvalidMaximumTotalTickets.into()
+            );
+        };
 
-        for _ in 1..=tickets_amount {
-            let from_offset = current_offset;
-            let to_offset = current_offset.checked_add(
-                size_of::<Pubkey>()
-            ).unwrap();
+        Ok(())
+    }
 
-            // add the ticket to the lottery account
-            sol_memcpy(
-                lottery_account_data
-                    .get_mut(from_offset..to_offset)
-                    .unwrap(),
-                ticket_pubkey,
-                size_of::<Pubkey>()
+    // USDC value of "tickets_amount" outstanding ticket tokens (prorated, the ticket price can change during the sales),
+    // they are removed from the outstanding ones (redeemed or refunded)
+    pub fn take_ticket_tokens(
+        &mut self,
+        tickets_amount: u32
+    ) -> Result<u64, ProgramError> {
+        if self.is_tickets_tokenized == false {
+            return Err(
+                LotteryError::TicketTokensNotEnabled.into()
             );
+        };
 
-            current_offset = current_offset.checked_add(
-                size_of::<Pubkey>()
-            ).unwrap();
+        if tickets_amount == 0 || tickets_amount > self.ticket_tokens_outstanding {
+            return Err(
+                LotteryError::InvalidTicketAmount.into()
+            );
         };
 
-        let new_total_tickets_amount = tickets_total_amount.checked_add(
-            tickets_amount
-        ).unwrap();
+        let tickets_value = ((self.ticket_tokens_value as u128) * (tickets_amount as u128) / (self.ticket_tokens_outstanding as u128)) as u64;
 
-        // update tickets_total_amount field
-        sol_memcpy(
-            lottery_account_data
-                .get_mut(194..198)
-                .unwrap(),
-            new_total_tickets_amount.to_le_bytes().as_slice(),
-            size_of::<u32>()
-        );
+        self.ticket_tokens_outstanding -= tickets_amount;
+        self.ticket_tokens_value -= tickets_value;
+
+        Ok(tickets_value)
     }
 
-    pub fn get_lottery_state(
+    pub fn validate_total_tickets_amount(
         &self,
-        current_time: Time
-    ) -> LotteryState {
-        if Self::is_not_started(&self, current_time) == true {
-            return LotteryState::Unknown;
+        tickets_amount_to_buy_now: u32
+    ) -> ProgramResult {
+        let Some(maximum_total_tickets) = self.maximum_total_tickets else {
+            return Ok(());
         };
 
-        if Self::is_started_and_not_ended(&self, current_time) == true {
-            return LotteryState::Unknown;
+        // ticket tokens not redeemed yet are sold tickets
+        if self.tickets_total_amount
+            .checked_add(self.ticket_tokens_outstanding)
+            .and_then(|sold_tickets| sold_tickets.checked_add(tickets_amount_to_buy_now))
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())? > maximum_total_tickets
+        {
+            return Err(
+                LotteryError::MaximumTotalTicketsExceeded.into()
+            );
         };
 
-        if Self::is_ended(&self, current_time) == false {
-            return LotteryState::Invalid;
-        };
+        Ok(())
+    }
 
-        if self.tickets_total_amount as usize >= (self.minimum_tickets_amount_required_to_be_sold as usize) {
-            return LotteryState::Successful;
-        } else {
-            return LotteryState::Failed;
+    pub fn validate_settlement_bounty(settlement_bounty: &Option<SettlementBounty>) -> ProgramResult {
+        if let Some(SettlementBounty::Lamports(0) | SettlementBounty::Usdc(0)) = settlement_bounty {
+            return Err(
+                LotteryError::InvalidSettlementBounty.into()
+            );
         };
+
+        Ok(())
     }
 
-    pub fn pick_winners(
-        &mut self,
-        sha256_hash: &[u8; HASH_BYTES],
-        lottery_account_info: &AccountInfo
+    // Unpaid bounty -> (bounty receiver) for lamports, (bounty receiver usdc token account, usdc mint, lottery's usdc ata, token program) for usdc
+    pub fn get_settlement_bounty_accounts_amount(&self) -> usize {
+        match self.settlement_bounty {
+            Some(SettlementBounty::Lamports(_)) if self.is_settlement_bounty_paid == false => 1,
+            Some(SettlementBounty::Usdc(_)) if self.is_settlement_bounty_paid == false => 4,
+            _ => 0
+        }
+    }
+
+    // Unclaimed prizes can be reclaimed once "ending_time + prize_claim_window" has passed
+    pub fn check_claim_window(
+        &self,
+        prize_claim_window: u32,
+        current_time: Time
     ) -> ProgramResult {
-        let total_tickets = self.tickets_total_amount as usize;
-        let mut random_numbers: Vec<u32> = Vec::with_capacity(HASH_BYTES);
-        for random_number in sha256_hash.iter() {
-            random_numbers.push(
-                ((*random_number as usize) % total_tickets)
-                    .try_into()
-                    .unwrap()
+        if prize_claim_window == 0 {
+            return Err(
+                LotteryError::PrizesNeverExpire.into()
             );
         };
 
-        let mut random_numbers_without_duplication: Vec<u32> = Vec::with_capacity(HASH_BYTES);
-        for rand_num in random_numbers.iter() {
-            if random_numbers_without_duplication.len() == 0 {
-                random_numbers_without_duplication.push(*rand_num);
-            };
+        let claim_window_ending_time = self.ending_time
+            .checked_add(prize_claim_window as Time)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
 
-            let mut is_exist = false;
-            for n in random_numbers_without_duplication.iter() {
-                if n == rand_num {
-                    is_exist = true;
-                    break;
-                };
-            };
+        if current_time < claim_window_ending_time {
+            return Err(
+                LotteryError::ClaimWindowNotClosed.into()
+            );
+        };
 
-            if is_exist == false {
-                random_numbers_without_duplication.push(*rand_num);
+        Ok(())
+    }
+
+    // Ranks no winner has withdrawn yet, they are marked as withdrawn
+    pub fn take_unclaimed_ranks(&mut self) -> Vec<Rank> {
+        let mut unclaimed_ranks: Vec<Rank> = Vec::new();
+        for winner in self.winners.iter_mut() {
+            if winner.2 == false {
+                unclaimed_ranks.push(winner.1);
+                winner.2 = true;
             };
         };
 
-        let winners_count: u8 = self.winners_count;
-        if winners_count as usize > random_numbers_without_duplication.len() {
+        unclaimed_ranks
+    }
+
+    // The draw of a "VrfOracle" lottery mixes the creator's secret, the oracle alone can't know the winners
+    pub fn validate_randomness_commitment(
+        randomness_source: RandomnessSource,
+        randomness_commitment: &Option<[u8; HASH_BYTES]>
+    ) -> ProgramResult {
+        if
+            randomness_source == RandomnessSource::VrfOracle &&
+            randomness_commitment.is_none()
+        {
             return Err(
-                LotteryError::InsufficientRandomNumbers.into()
+                LotteryError::RandomnessCommitmentNotFound.into()
             );
         };
 
-        let mut counter = 0u8;
-        let mut winners_index: Vec<u32> = Vec::with_capacity(winners_count as usize);
-        for w_i in random_numbers_without_duplication.iter() {
-            counter += 1;
+        Ok(())
+    }
 
-            if counter > winners_count {
-                break;
-            };
+    // One share per winner, the shares must add up to the whole fund
+    pub fn validate_prize_table(
+        prize_table: &Option<Vec<BasisPoints>>,
+        winners_count: u8
+    ) -> ProgramResult {
+        let prize_table = match prize_table {
+            Some(prize_table) => prize_table,
+            None => return Ok(())
+        };
 
-            winners_index.push(*w_i);
+        if prize_table.len() != winners_count as usize {
+            return Err(
+                LotteryError::InvalidPrizeTable.into()
+            );
         };
-        sol_log("Winners_Index :");
-        msg!("{:?}", winners_index);
 
-        sol_log("Winners :");
-        for w in winners_index {
-            // read winner pubkey
-            let winner_pubkey = match Self::get_ticket(lottery_account_info, w as usize) {
-                Ok(pubkey) => pubkey,
-                Err(_) => return Err(
-                    LotteryError::FailedToGetTicket.into()
-                )
+        let mut total_basis_points = 0u32;
+        for share in prize_table.iter() {
+            if *share == 0 {
+                return Err(
+                    LotteryError::InvalidPrizeTable.into()
+                );
             };
 
-            self.winners.push(
-                (
-                    winner_pubkey,
-                    bool::default()
-                )
-            );
+            total_basis_points += *share as u32;
+        };
 
-            winner_pubkey.log();
+        if total_basis_points != MAX_BASIS_POINTS as u32 {
+            return Err(
+                LotteryError::InvalidPrizeTable.into()
+            );
         };
 
         Ok(())
     }
 
-    pub fn get_winner_info(
-        &mut self,
-        winner_account: &Pubkey
-    ) -> Result<u8, ProgramError> {
-        let mut winning_count = 0u8;
-        for winner in self.winners.iter_mut() {
-            if &winner.0 == winner_account && winner.1 == false {
-                winning_count += 1;
-                winner.1 = true;
+    // One nft per winner, each nft mint gets its own associated token account (no usdc, arbitrary or repeated mint)
+    pub fn validate_nft_prizes(
+        nft_prizes: &Option<Vec<NftPrizeMint>>,
+        winners_count: u8,
+        usdc_mint: &Pubkey,
+        arbitrary_mint: &Pubkey
+    ) -> ProgramResult {
+        let nft_prizes = match nft_prizes {
+            Some(nft_prizes) => nft_prizes,
+            None => return Ok(())
+        };
+
+        if nft_prizes.len() != winners_count as usize {
+            return Err(
+                LotteryError::InvalidNftPrizes.into()
+            );
+        };
+
+        for (index, nft_prize_mint) in nft_prizes.iter().enumerate() {
+            if
+                nft_prize_mint == usdc_mint ||
+                nft_prize_mint == arbitrary_mint ||
+                nft_prizes[..index].contains(nft_prize_mint) == true
+            {
+                return Err(
+                    LotteryError::InvalidNftPrizes.into()
+                );
             };
         };
 
-        if winning_count == 0 {
-            Err(
-                LotteryError::WinnerNotFound.into()
-            )
-        } else {
-            Ok(winning_count)
-        }
+        Ok(())
     }
 
-    pub fn get_ticket(
-        lottery_account_info: &AccountInfo,
-        ticket_index: usize
-    ) -> Result<Pubkey, u8> {
-        let lottery_account_data = lottery_account_info
-            .data
-            .try_borrow()
-            .map_err(|_| 0u8)?;
+    // An nft -> no decimals & a supply of one token
+    pub fn validate_nft_prize_mint(
+        decimals: u8,
+        supply: u64
+    ) -> ProgramResult {
+        if decimals != 0 || supply != 1 {
+            return Err(
+                LotteryError::InvalidNftPrizeMintAccount.into()
+            );
+        };
 
-        let initial_bytes = u64::from_le_bytes(
-            lottery_account_data
-                .get(9..17)
-                .ok_or(1u8)?
-                .try_into()
-                .map_err(|_| 2u8)?
-        );
+        Ok(())
+    }
 
-        let ticket_offset = initial_bytes.checked_add(
-            (ticket_index as usize).checked_mul(
-                size_of::<Pubkey>()
-            ).ok_or(3)? as u64
-        ).ok_or(4)? as usize;
+    // The prize-vault pda is owned by the program, so the sol prize is paid by lamport transfers
+    pub fn validate_prize_vault_account(
+        &self,
+        prize_vault_account_info: &AccountInfo,
+        lottery_account: &Pubkey,
+        program_id: &Pubkey
+    ) -> ProgramResult {
+        if prize_vault_account_info.owner != program_id {
+            return Err(
+                ProgramError::IncorrectProgramId
+            );
+        };
 
-        let ticket = Pubkey::try_from_slice(
-            lottery_account_info
-                .data
-                .try_borrow()
-                .map_err(|_| 5u8)?
-                .get(
-                    ticket_offset..ticket_offset.checked_add(
-                        size_of::<Pubkey>()
-                    ).ok_or(6)?
-                )
-                .ok_or(7u8)?
-        ).map_err(|_| 8u8)?;
+        let prize_vault_pda_addr = Pubkey::create_program_address(
+            &[
+                PRIZE_VAULT_ACCOUNT_SEED.as_bytes(),
+                lottery_account.to_bytes().as_slice(),
+                &[ self.prize_vault_canonical_bump ]
+            ],
+            program_id
+        )?;
+
+        if &prize_vault_pda_addr != prize_vault_account_info.key {
+            return Err(
+                LotteryError::InvalidPrizeVaultAccount.into()
+            );
+        };
 
-        Ok(ticket)
+        Ok(())
     }
 
-    pub fn check_max_tickets_per_instruction(tickets_amount: u32) -> ProgramResult {
-        if tickets_amount as usize > MAX_TICKETS_PER_INSTRUCTION {
+    // Prize of the rank in the lottery's arbitrary mint
+    pub fn get_prize_amount(
+        &self,
+        rank: Rank
+    ) -> Result<u64, ProgramError> {
+        self.get_prize_share(self.fund_amount, rank)
+    }
+
+    pub fn validate_pool_creator_share(pool_creator_share: &Option<BasisPoints>) -> ProgramResult {
+        if pool_creator_share.map_or(false, |share| share > MAX_BASIS_POINTS) == true {
             return Err(
-                LotteryError::MaxTicketsPerInstructionExceeded.into()
-            )
+                LotteryError::InvalidPoolCreatorShare.into()
+            );
         };
 
         Ok(())
     }
 
-    pub fn close_lottery_account(
-        lottery_account_info: &AccountInfo,
-        rent_exempt_recepient_account_info: &AccountInfo
-    ) -> ProgramResult {
-        // send all lamports to the recepient
-        let lottery_account_balance = lottery_account_info.lamports();
-        let rent_exempt_recepient_account_balance = rent_exempt_recepient_account_info.lamports();
-        
-        **lottery_account_info.try_borrow_mut_lamports()? = 0;
-        **rent_exempt_recepient_account_info.try_borrow_mut_lamports()? = (rent_exempt_recepient_account_balance)
-            .checked_add(lottery_account_balance)
+    // Pool of a pool lottery, the usdc balance minus the unclaimed protocol fees, taken before anything else leaves the usdc account
+    pub fn get_pool_amount(
+        &mut self,
+        lottery_usdc_balance: u64
+    ) -> Result<u64, ProgramError> {
+        if self.pool_amount > 0 {
+            return Ok(self.pool_amount);
+        };
+
+        let unclaimed_protocol_fee = if self.is_protocol_fee_claimed == true {
+            0
+        } else {
+            self.lottery_creation_fee
+                .checked_add(self.protocol_fee)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?
+        };
+
+        let pool_amount = lottery_usdc_balance
+            .checked_sub(unclaimed_protocol_fee)
             .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
 
-        // clear data field and write "CLOSED_LOTTERY_ACCOUNT" discriminator
-        //  clear data
-        let initial_bytes = u64::from_le_bytes(
-            lottery_account_info
-                .try_borrow_data()
-                .unwrap()
-                .get(9..17)
-                .unwrap()
-                .try_into()
-                .unwrap()
-        );
-        
-        sol_memset(
-            &mut lottery_account_info
-                .data
-                .try_borrow_mut()
-                .unwrap(),
-            0,
-            initial_bytes
-                .try_into()
-                .unwrap()
-        );
+        self.pool_amount = pool_amount;
 
-        //  write new discriminator
-        sol_memcpy(
-            &mut lottery_account_info
-                .data
-                .try_borrow_mut()
-                .unwrap(),
-            &Self::get_closed_account_discriminator(),
-            DISCRIMINATOR_LENTGH
-        );
+        Ok(pool_amount)
+    }
 
-        Ok(())
+    // Creator's cut of the pool -> "pool_amount * pool_creator_share / MAX_BASIS_POINTS", the rest is split between the winners
+    pub fn get_pool_creator_amount(
+        &self,
+        pool_amount: u64
+    ) -> Result<u64, ProgramError> {
+        let pool_creator_share = self.pool_creator_share.ok_or::<ProgramError>(
+            LotteryError::InvalidLotteryType.into()
+        )?;
+
+        Ok(
+            ((pool_amount as u128) * (pool_creator_share as u128) / (MAX_BASIS_POINTS as u128)) as u64
+        )
     }
 
-    pub fn get_closed_account_discriminator() -> [u8; DISCRIMINATOR_LENTGH] {
-        hash(CLOSED_LOTTERY_ACCOUNT_DISCRIMINATOR.as_bytes())
-            .to_bytes()
-            .get(..8)
-            .and_then(|slice| slice.try_into().ok())
-            .map(|dis: [u8; 8]| dis)
-            .unwrap()
+    pub fn get_pool_prize_amount(
+        &self,
+        pool_amount: u64
+    ) -> Result<u64, ProgramError> {
+        pool_amount
+            .checked_sub(self.get_pool_creator_amount(pool_amount)?)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())
     }
 
-    pub fn get_discriminator() -> [u8; 8] {
-        hash(LOTTERY_ACCOUNT_DISCRIMINATOR.as_bytes())
-            .to_bytes()
-            .get(..8)
-            .and_then(|slice| slice.try_into().ok())
-            .map(|dis: [u8; 8]| dis)
-            .unwrap()
+    // Jackpot draw of a series round, seeded by the winners-seed -> a ticket slot wins the jackpot for the round's winners
+    pub fn draw_series_jackpot(
+        &mut self,
+        sha256_hash: &[u8; HASH_BYTES]
+    ) -> ProgramResult {
+        let tickets_total_amount = self.tickets_total_amount;
+        let series_jackpot = match self.series_jackpot.as_mut() {
+            Some(series_jackpot) => series_jackpot,
+            None => return Ok(())
+        };
+
+        let slots_total_amount = tickets_total_amount
+            .checked_add(series_jackpot.house_slots)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        let mut random_number_generator = RandomNumberGenerator::new(
+            &hashv(
+                &[
+                    sha256_hash.as_slice(),
+                    LOTTERY_SERIES_ACCOUNT_SEED.as_bytes()
+                ]
+            ).to_bytes()
+        );
+        let jackpot_slot = random_number_generator.next_index(slots_total_amount);
+
+        series_jackpot.is_drawn = true;
+        series_jackpot.is_won = jackpot_slot < tickets_total_amount;
+
+        msg!(
+            "Series jackpot slot => {} - Won => {}",
+            jackpot_slot,
+            series_jackpot.is_won
+        );
+
+        Ok(())
     }
-}
 
-#[derive(Debug, Default, BorshDeserialize, BorshSerialize, Clone, Copy, PartialEq, Eq)]
-pub struct User {
-    pub discriminator: [u8; DISCRIMINATOR_LENTGH],
-    pub canonical_bump: u8,
-    pub lottery: Pubkey,
-    pub authority: Pubkey,
-    pub total_tickets_value: u64, // USDC
-    pub total_rent_exempt_paied: u64, // Lamports
-    pub total_tickets_acquired: u32,
-    pub created_at: Time
-}
-impl User {
-    pub const LEN: usize =
-        DISCRIMINATOR_LENTGH +
-        CANONICAL_BUMP_LENGTH +
-        size_of::<Pubkey>() +
-        size_of::<Pubkey>() +
-        size_of::<u64>() +
-        size_of::<u64>() +
-        size_of::<u32>() +
-        size_of::<Time>();
+    // Unclaimed jackpot ranks of the winner, marked as claimed
+    pub fn claim_series_jackpot_ranks(
+        &mut self,
+        user_account: &Pubkey
+    ) -> Result<Vec<Rank>, ProgramError> {
+        let series_jackpot = self.series_jackpot.as_mut().ok_or::<ProgramError>(
+            LotteryError::InvalidLotteryType.into()
+        )?;
 
-    pub fn validate_user_account(
-        user_account_info: &AccountInfo,
-        program_id: &Pubkey,
-        lottery_account: &Pubkey,
-        user_account_authority: &Pubkey
-    ) -> ProgramResult {
-        if user_account_info.owner != program_id {
+        if series_jackpot.is_won == false {
             return Err(
-                ProgramError::IncorrectProgramId
+                LotteryError::JackpotNotWon.into()
             );
         };
 
-        if sol_memcmp(
-            &user_account_info
-                .data
-                .try_borrow()
-                .unwrap(),
-            &Self::get_discriminator(),
-            DISCRIMINATOR_LENTGH
-        ) != 0 {
+        let winner_ranks = self.winners
+            .iter()
+            .filter(|(winner, _, _)| winner == user_account)
+            .map(|(_, rank, _)| *rank)
+            .collect::<Vec<Rank>>();
+
+        if winner_ranks.is_empty() == true {
             return Err(
-                LotteryError::InvalidDiscriminator.into()
+                LotteryError::WinnerNotFound.into()
             );
         };
 
-        // Both user_account_authority & lottery_account are validated here
-        let user_pda_addr = Pubkey::create_program_address(
-            &[
-                USER_ACCOUNT_SEED.as_bytes(),
-                user_account_authority.to_bytes().as_slice(),
-                lottery_account.to_bytes().as_slice(),
-                &[
-                    *user_account_info
-                        .data
-                        .try_borrow()
-                        .unwrap()
-                        .get(8)
-                        .unwrap()
-                ]
-            ],
-            program_id
-        ).map_err::<ProgramError, _>(|_| LotteryError::FailedToFindProgramAddress.into())?;
+        let unclaimed_ranks = winner_ranks
+            .into_iter()
+            .filter(|rank| series_jackpot.is_rank_claimed(*rank) == false)
+            .collect::<Vec<Rank>>();
 
-        if user_pda_addr != *user_account_info.key {
+        if unclaimed_ranks.is_empty() == true {
             return Err(
-                ProgramError::InvalidSeeds
+                LotteryError::JackpotAlreadyClaimed.into()
             );
         };
 
-        Ok(())
+        for rank in unclaimed_ranks.iter() {
+            series_jackpot.set_rank_claimed(*rank);
+        };
+
+        Ok(unclaimed_ranks)
     }
 
-    pub fn validate_user_holding_tickets_amount(
-        &self,
-        maximum_amount: &Option<u32>,
-        tickets_amount_to_buy_now: u32
+    // Round's jackpot -> whatever the series vault holds besides the jackpots reserved by other won rounds,
+    // reserved once at the draw so neither a later round nor later contributions change it
+    pub fn reserve_series_jackpot_amount(
+        &mut self,
+        lottery_series_account: &mut LotterySeries,
+        jackpot_vault_balance: u64
     ) -> ProgramResult {
-        if maximum_amount.is_none() {
+        let series_jackpot = self.series_jackpot.as_mut().ok_or::<ProgramError>(
+            LotteryError::InvalidLotteryType.into()
+        )?;
+
+        if series_jackpot.is_won == false || series_jackpot.is_amount_reserved == true {
             return Ok(());
         };
 
-        let max_permitted_tickets_to_hold = maximum_amount.unwrap();
-        if 
-            &self.total_tickets_acquired == &max_permitted_tickets_to_hold ||
-            &self.total_tickets_acquired.checked_add(tickets_amount_to_buy_now).unwrap() > &max_permitted_tickets_to_hold
-        {
-            Err(
-                LotteryError::MaxTicketsAmountViolated.into()
-            )
-        } else {
-            Ok(())
-        }
-    }
-
-    pub fn close_user_account(
-        user_account_info: &AccountInfo,
-        rent_exempt_recepient_account_info: &AccountInfo
-    ) -> ProgramResult {
-        // send all lamports to the recepient
-        let user_account_balance = user_account_info.lamports();
-        let rent_exempt_recepient_account_balance = rent_exempt_recepient_account_info.lamports();
-        
-        **user_account_info.try_borrow_mut_lamports()? = 0;
-        **rent_exempt_recepient_account_info.try_borrow_mut_lamports()? = (rent_exempt_recepient_account_balance)
-            .checked_add(user_account_balance)
+        series_jackpot.amount = jackpot_vault_balance
+            .checked_sub(lottery_series_account.reserved_jackpot_amount)
             .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        series_jackpot.is_amount_reserved = true;
 
-        // clear data field and write "CLOSED_USER_ACCOUNT" discriminator
-        //  clear data
-        sol_memset(
-            &mut user_account_info
-                .data
-                .try_borrow_mut()
-                .unwrap(),
-            0,
-            Self::LEN
-        );
-
-        //  write new discriminator
-        sol_memcpy(
-            &mut user_account_info
-                .data
-                .try_borrow_mut()
-                .unwrap(),
-            &Self::get_closed_account_discriminator(),
-            DISCRIMINATOR_LENTGH
-        );
+        lottery_series_account.reserved_jackpot_amount = jackpot_vault_balance;
 
         Ok(())
     }
 
-    pub fn get_discriminator() -> [u8; DISCRIMINATOR_LENTGH] {
-        hash(USER_ACCOUNT_DISCRIMINATOR.as_bytes())
-            .to_bytes()
-            .get(..8)
-            .and_then(|slice| slice.try_into().ok())
-            .map(|dis: [u8; 8]| dis)
-            .unwrap()
-    }
+    // Share of the rank in "total_amount" (the fund or a prize mint's amount) -> "total_amount * share / MAX_BASIS_POINTS"
+    // (or an equal split without a prize table), the rounding dust goes to the 1st rank so the whole amount is paid
+    pub fn get_prize_share(
+        &self,
+        total_amount: u64,
+        rank: Rank
+    ) -> Result<u64, ProgramError> {
+        if rank >= self.winners_count {
+            return Err(
+                LotteryError::WinnerNotFound.into()
+            );
+        };
 
-    pub fn get_closed_account_discriminator() -> [u8; DISCRIMINATOR_LENTGH] {
-        hash(CLOSED_USER_ACCOUNT_DISCRIMINATOR.as_bytes())
-            .to_bytes()
-            .get(..8)
-            .and_then(|slice| slice.try_into().ok())
-            .map(|dis: [u8; 8]| dis)
-            .unwrap()
-    }
-}
+        let get_share_amount = |share: u128, total_shares: u128| -> u64 {
+            ((total_amount as u128) * share / total_shares) as u64
+        };
 
-#[cfg(test)]
-mod test_config {
-    use {
-        borsh::BorshSerialize,
-        solana_program::clock::Epoch,
-        std::{
-            rc::Rc,
-            cell::RefCell,
-            str::FromStr
-        },
-        crate::error::LotteryError
-    };
-    use super::{
-        Config,
-        AccountInfo,
-        CONFIG_ACCOUNT_SEED,
-        Pubkey,
-        ProgramError,
-        PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID
-    };
+        let (
+            share_amount,
+            total_shares_amount
+        ) = match &self.prize_table {
+            Some(prize_table) => (
+                get_share_amount(
+                    *prize_table
+                        .get(rank as usize)
+                        .ok_or::<ProgramError>(LotteryError::InvalidPrizeTable.into())? as u128,
+                    MAX_BASIS_POINTS as u128
+                ),
+                prize_table
+                    .iter()
+                    .map(|share| get_share_amount(*share as u128, MAX_BASIS_POINTS as u128))
+                    .sum::<u64>()
+            ),
+            None => {
+                let share_amount = get_share_amount(1, self.winners_count as u128);
 
-    #[test]
-    fn test_succeed_check_is_pause() {
-        let config_account = Config::default();
-        config_account.check_is_pause().unwrap();
-    }
+                (
+                    share_amount,
+                    share_amount * (self.winners_count as u64)
+                )
+            }
+        };
 
-    #[test]
-    #[should_panic]
-    fn test_fail_check_is_pause() {
-        let mut config_account = Config::default();
-        config_account.is_pause = true;
+        if rank == 0 {
+            let dust = total_amount
+                .checked_sub(total_shares_amount)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
 
-        config_account.check_is_pause().unwrap();
+            return share_amount
+                .checked_add(dust)
+                .ok_or(LotteryError::Overflow.into());
+        };
+
+        Ok(share_amount)
     }
 
-    #[test]
-    fn test_succeed_check_is_pause_raw() {
-        let config_account = Config::default();
+    // Each prize mint gets its own associated token account, so the usdc mint (ticket sales) & the arbitrary mint are rejected
+    pub fn validate_prize_mint(
+        &self,
+        prize_mint: &Pubkey,
+        usdc_mint: &Pubkey
+    ) -> ProgramResult {
+        if
+            prize_mint == usdc_mint ||
+            prize_mint == &self.arbitrary_mint_account_address ||
+            self.prize_mints.iter().any(|(mint, _)| mint == prize_mint) == true ||
+            self.nft_prizes.as_ref().map_or(false, |nft_prizes| nft_prizes.contains(prize_mint)) == true
+        {
+            return Err(
+                LotteryError::InvalidPrizeMintAccount.into()
+            );
+        };
 
-        let mut data: [u8; Config::LEN] = [0; Config::LEN];
-        config_account.serialize(
-            &mut &mut data.as_mut_slice()
-        ).unwrap();
+        if self.prize_mints.len() >= MAX_PRIZE_MINTS_PER_LOTTERY {
+            return Err(
+                LotteryError::MaxPrizeMintsExceeded.into()
+            );
+        };
 
-        Config::check_is_pause_raw(
-            &AccountInfo {
-                key: &Pubkey::new_unique(),
-                lamports: Rc::new(RefCell::new(&mut u64::default())),
-                data: Rc::new(RefCell::new(&mut data)),
-                owner: &Pubkey::new_unique(),
-                rent_epoch: Epoch::default(),
-                is_signer: false,
-                is_writable: false,
-                executable: false
-            }
-        ).unwrap();
+        Ok(())
     }
 
-    #[test]
-    #[should_panic]
-    fn test_fail_check_is_pause_raw() {
-        let mut config_account = Config::default();
-        config_account.is_pause = true;
+    // The commitment is bound to the lottery account, so a revealed secret can't be replayed by another lottery
+    pub fn validate_secret(
+        &self,
+        lottery_account: &Pubkey,
+        secret: &[u8; HASH_BYTES]
+    ) -> ProgramResult {
+        let randomness_commitment = self.randomness_commitment.ok_or::<ProgramError>(
+            LotteryError::RandomnessCommitmentNotFound.into()
+        )?;
 
-        let mut data: [u8; Config::LEN] = [0; Config::LEN];
-        config_account.serialize(
-            &mut &mut data.as_mut_slice()
-        ).unwrap();
+        if self.is_secret_revealed == true {
+            return Err(
+                LotteryError::SecretAlreadyRevealed.into()
+            );
+        };
 
-        Config::check_is_pause_raw(
-            &AccountInfo {
-                key: &Pubkey::new_unique(),
-                lamports: Rc::new(RefCell::new(&mut u64::default())),
-                data: Rc::new(RefCell::new(&mut data)),
-                owner: &Pubkey::new_unique(),
-                rent_epoch: Epoch::default(),
-                is_signer: false,
-                is_writable: false,
-                executable: false
-            }
-        ).unwrap();
-    }
+        if sol_memcmp(
+            &hashv(
+                &[
+                    lottery_account.as_ref(),
+                    secret.as_slice()
+                ]
+            ).to_bytes(),
+            &randomness_commitment,
+            HASH_BYTES
+        ) != 0 {
+            return Err(
+                LotteryError::InvalidSecret.into()
+            );
+        };
 
-    #[test]
-    fn test_succeed_validate_config_account() {
-        let program_id = Pubkey::from_str("EGxRBwjoC99LtLznAyLFcSxaiCrzPiXW3gHmemq4pump").unwrap();
-        let config_account_addr: Pubkey;
+        Ok(())
+    }
 
-        let mut config_account = Config::default();
-        
-        let (
-            pda_addr,
-            pda_bump
-        ) = Pubkey::try_find_program_address(
-            &[
-                CONFIG_ACCOUNT_SEED.as_bytes()
-            ],
-            &program_id
-        ).unwrap();
-        
-        config_account.canonical_bump = pda_bump;
-        config_account.discriminator = Config::get_discriminator();
-        config_account_addr = pda_addr;
+    pub fn is_secret_reveal_timed_out(
+        &self,
+        current_time: Time
+    ) -> bool {
+        current_time >= self.ending_time.saturating_add(MAX_TIME_TO_REVEAL_SECRET)
+    }
 
-        let mut data: [u8; Config::LEN] = [0; Config::LEN];
-        config_account.serialize(
-            &mut &mut data.as_mut_slice()
-        ).unwrap();
+    pub fn is_randomness_fulfilment_timed_out(
+        &self,
+        current_time: Time
+    ) -> bool {
+        current_time >= self.ending_time
+            .saturating_add(MAX_TIME_TO_REVEAL_SECRET)
+            .saturating_add(MAX_TIME_TO_FULFIL_RANDOMNESS)
+    }
 
-        Config::validate_config_account(
-            &AccountInfo {
-                key: &config_account_addr,
-                lamports: Rc::new(RefCell::new(&mut u64::default())),
-                data: Rc::new(RefCell::new(&mut data)),
-                owner: &program_id,
-                rent_epoch: Epoch::default(),
-                is_signer: false,
-                is_writable: false,
-                executable: false
-            },
-            &program_id
-        ).unwrap();
+    pub fn is_ticket_tokens_refund_timed_out(
+        &self,
+        current_time: Time
+    ) -> bool {
+        current_time >= self.ending_time.saturating_add(MAX_TIME_TO_REFUND_TICKET_TOKENS)
     }
 
-    #[test]
-    fn test_validate_config_account_error_invalid_programid() {
-        let program_id = Pubkey::from_str("EGxRBwjoC99LtLznAyLFcSxaiCrzPiXW3gHmemq4pump").unwrap();
-        let config_account_addr: Pubkey;
+    // The oracle withheld the randomness of a "VrfOracle" lottery until the deadline
+    pub fn is_randomness_fulfilment_missed(
+        &self,
+        current_time: Time
+    ) -> bool {
+        self.randomness_source == RandomnessSource::VrfOracle &&
+        self.is_randomness_fulfilled == false &&
+        self.is_randomness_fulfilment_timed_out(current_time)
+    }
 
-        let mut config_account = Config::default();
-        
-        let (
-            pda_addr,
-            pda_bump
-        ) = Pubkey::try_find_program_address(
-            &[
-                CONFIG_ACCOUNT_SEED.as_bytes()
-            ],
-            &program_id
-        ).unwrap();
-        
-        config_account.canonical_bump = pda_bump;
-        config_account.discriminator = Config::get_discriminator();
-        config_account_addr = pda_addr;
+    // Creator's secret must be revealed before using the randomness source, unless the reveal is timed out
+    pub fn check_secret_is_revealed(
+        &self,
+        current_time: Time
+    ) -> ProgramResult {
+        if
+            self.randomness_commitment.is_some() &&
+            self.is_secret_revealed == false
+        {
+            if self.is_secret_reveal_timed_out(current_time) == false {
+                return Err(
+                    LotteryError::SecretNotRevealed.into()
+                );
+            };
 
-        let mut data: [u8; Config::LEN] = [0; Config::LEN];
-        config_account.serialize(
-            &mut &mut data.as_mut_slice()
-        ).unwrap();
+            sol_log("Secret is not revealed in time, only oracle's randomness is used.");
+        };
 
-        let result = Config::validate_config_account(
-            &AccountInfo {
-                key: &config_account_addr,
-                lamports: Rc::new(RefCell::new(&mut u64::default())),
-                data: Rc::new(RefCell::new(&mut data)),
-                owner: &Pubkey::default(),
-                rent_epoch: Epoch::default(),
-                is_signer: false,
-                is_writable: false,
-                executable: false
-            },
-            &program_id
-        );
+        Ok(())
+    }
 
-        if let Err(error) = result {
-            assert_eq!(
-                error,
-                ProgramError::IncorrectProgramId
+    pub fn check_randomness_source(
+        &self,
+        expected_randomness_source: RandomnessSource
+    ) -> ProgramResult {
+        if self.randomness_source != expected_randomness_source {
+            return Err(
+                LotteryError::InvalidRandomnessSource.into()
             );
-        } else {
-            panic!("Test must gives us an erorr!");
         };
+
+        Ok(())
     }
 
-    #[test]
-    fn test_validate_config_account_error_invalid_discriminator() {
-        let program_id = Pubkey::from_str("EGxRBwjoC99LtLznAyLFcSxaiCrzPiXW3gHmemq4pump").unwrap();
-        let config_account_addr: Pubkey;
+    // Vrf request seed, only made of data fixed at "ending_time" (the lottery account, its ticket ledger & the creator's secret),
+    // requesting it later gives the same seed so nobody can pick the oracle's input
+    pub fn get_request_seed(
+        &self,
+        lottery_account_info: &AccountInfo
+    ) -> Result<[u8; HASH_BYTES], ProgramError> {
+        let ticket_ledger_end = (self.ticket_ranges_total_amount as u64)
+            .checked_mul(TICKET_RANGE_LENGTH as u64)
+            .and_then(|tickets_bytes| tickets_bytes.checked_add(self.initial_bytes))
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())? as usize;
 
-        let mut config_account = Config::default();
-        
-        let (
-            pda_addr,
-            pda_bump
-        ) = Pubkey::try_find_program_address(
-            &[
-                CONFIG_ACCOUNT_SEED.as_bytes()
-            ],
-            &program_id
-        ).unwrap();
-        
-        config_account.canonical_bump = pda_bump;
-        config_account.discriminator = [0; 8];
-        config_account_addr = pda_addr;
+        let lottery_account_data = lottery_account_info
+            .data
+            .try_borrow()
+            .unwrap();
 
-        let mut data: [u8; Config::LEN] = [0; Config::LEN];
-        config_account.serialize(
-            &mut &mut data.as_mut_slice()
-        ).unwrap();
+        let ticket_ledger = lottery_account_data
+            .get(self.initial_bytes as usize..ticket_ledger_end)
+            .ok_or::<ProgramError>(LotteryError::FailedToGetTicket.into())?;
 
-        let result = Config::validate_config_account(
-            &AccountInfo {
-                key: &config_account_addr,
-                lamports: Rc::new(RefCell::new(&mut u64::default())),
-                data: Rc::new(RefCell::new(&mut data)),
-                owner: &program_id,
-                rent_epoch: Epoch::default(),
-                is_signer: false,
-                is_writable: false,
-                executable: false
-            },
-            &program_id
-        );
+        Ok(
+            hashv(
+                &[
+                    lottery_account_info.key.to_bytes().as_slice(),
+                    ticket_ledger,
+                    self.revealed_secret.as_slice()
+                ]
+            ).to_bytes()
+        )
+    }
 
-        if let Err(error) = result {
-            assert_eq!(
-                error,
-                ProgramError::Custom(
-                    LotteryError::InvalidDiscriminator as u32
-                )
-            );
+    // Creator's secret (if revealed) is mixed with the randomness source's entropy
+    pub fn get_winners_seed(
+        &self,
+        randomness: &[u8]
+    ) -> [u8; HASH_BYTES] {
+        if self.is_secret_revealed == true {
+            hashv(
+                &[
+                    randomness,
+                    self.revealed_secret.as_slice()
+                ]
+            ).to_bytes()
         } else {
-            panic!("Test must gives us an erorr!");
-        };
+            hash(randomness).to_bytes()
+        }
     }
 
-    #[test]
-    fn test_validate_config_account_error_invalid_account_address_invalid_seeds() {
-        let program_id = Pubkey::from_str("EGxRBwjoC99LtLznAyLFcSxaiCrzPiXW3gHmemq4pump").unwrap();
-        let config_account_addr: Pubkey;
+    // Appends a single ticket-range for the purchase, its end index is the new "tickets_total_amount" (exclusive)
+    pub fn add_ticket(
+        lottery_account_info: &AccountInfo,
+        initial_bytes: u64,
+        tickets_total_amount: u32,
+        ticket_ranges_total_amount: u32,
+        tickets_amount: u32,
+        user_account_pda: Pubkey
+    ) {
+        let mut lottery_account_data = lottery_account_info
+            .data
+            .try_borrow_mut()
+            .unwrap();
 
-        let mut config_account = Config::default();
-        
-        let (
-            pda_addr,
-            pda_bump
-        ) = Pubkey::try_find_program_address(
-            &[
-                CONFIG_ACCOUNT_SEED.as_bytes(),
-                b"invalid_seed"
-            ],
-            &program_id
+        let from_offset = initial_bytes.checked_add(
+            (ticket_ranges_total_amount as usize).checked_mul(
+                TICKET_RANGE_LENGTH
+            ).unwrap() as u64
+        ).unwrap() as usize;
+        let to_offset = from_offset.checked_add(
+            TICKET_RANGE_LENGTH
         ).unwrap();
-        
-        config_account.canonical_bump = pda_bump;
-        config_account.discriminator = Config::get_discriminator();
-        config_account_addr = pda_addr;
 
-        let mut data: [u8; Config::LEN] = [0; Config::LEN];
-        config_account.serialize(
-            &mut &mut data.as_mut_slice()
+        let new_total_tickets_amount = tickets_total_amount.checked_add(
+            tickets_amount
         ).unwrap();
 
-        let result = Config::validate_config_account(
-            &AccountInfo {
-                key: &config_account_addr,
-                lamports: Rc::new(RefCell::new(&mut u64::default())),
-                data: Rc::new(RefCell::new(&mut data)),
-                owner: &program_id,
-                rent_epoch: Epoch::default(),
-                is_signer: false,
-                is_writable: false,
-                executable: false
-            },
-            &program_id
+        // add the ticket-range to the lottery account
+        sol_memcpy(
+            lottery_account_data
+                .get_mut(from_offset..to_offset)
+                .unwrap(),
+            [
+                user_account_pda.to_bytes().as_slice(),
+                new_total_tickets_amount.to_le_bytes().as_slice()
+            ].concat().as_slice(),
+            TICKET_RANGE_LENGTH
         );
 
-        if let Err(error) = result {
-            assert_eq!(
-                error,
-                ProgramError::Custom(
-                    LotteryError::InvalidConfigAccount as u32
+        let new_ticket_ranges_total_amount = ticket_ranges_total_amount.checked_add(
+            1
+        ).unwrap();
+
+        // update tickets_total_amount & ticket_ranges_total_amount fields
+        sol_memcpy(
+            lottery_account_data
+                .get_mut(354..358)
+                .unwrap(),
+            new_total_tickets_amount.to_le_bytes().as_slice(),
+            size_of::<u32>()
+        );
+        sol_memcpy(
+            lottery_account_data
+                .get_mut(358..362)
+                .unwrap(),
+            new_ticket_ranges_total_amount.to_le_bytes().as_slice(),
+            size_of::<u32>()
+        );
+    }
+
+    // Moves the sender's last "tickets_amount" tickets to the receiver, walking the ticket ledger backwards.
+    // Whole ticket-ranges are re-assigned, a partially moved one is split (its last tickets become a new ticket-range
+    // right after it, the account must have room for it). Returns the re-assigned ticket-ranges amount & whether one was split
+    pub fn transfer_tickets(
+        lottery_account_info: &AccountInfo,
+        initial_bytes: u64,
+        ticket_ranges_total_amount: u32,
+        sender_user_account_pda: &Pubkey,
+        receiver_user_account_pda: &Pubkey,
+        tickets_amount: u32
+    ) -> Result<(u32, bool), ProgramError> {
+        let mut lottery_account_data = lottery_account_info
+            .data
+            .try_borrow_mut()
+            .unwrap();
+
+        let get_range_offset = |range_index: usize| -> Result<usize, ProgramError> {
+            range_index
+                .checked_mul(TICKET_RANGE_LENGTH)
+                .and_then(|range_bytes| range_bytes.checked_add(initial_bytes as usize))
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())
+        };
+
+        let mut remaining_tickets = tickets_amount;
+        let mut reassigned_ticket_ranges = 0u32;
+        let mut range_index = ticket_ranges_total_amount as usize;
+        while remaining_tickets > 0 && range_index > 0 {
+            range_index -= 1;
+
+            let range_offset = get_range_offset(range_index)?;
+            let (owner, end_index) = TicketRange::try_from_slice(
+                lottery_account_data
+                    .get(range_offset..range_offset + TICKET_RANGE_LENGTH)
+                    .ok_or::<ProgramError>(LotteryError::FailedToGetTicket.into())?
+            )?;
+
+            if owner != *sender_user_account_pda {
+                continue;
+            };
+
+            let start_index = if range_index == 0 {
+                0
+            } else {
+                TicketRange::try_from_slice(
+                    lottery_account_data
+                        .get(range_offset - TICKET_RANGE_LENGTH..range_offset)
+                        .ok_or::<ProgramError>(LotteryError::FailedToGetTicket.into())?
+                )?.1
+            };
+            let range_tickets = end_index
+                .checked_sub(start_index)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+            if range_tickets <= remaining_tickets {
+                // re-assign the whole ticket-range
+                sol_memcpy(
+                    lottery_account_data
+                        .get_mut(range_offset..range_offset + size_of::<UserAccount>())
+                        .unwrap(),
+                    receiver_user_account_pda.to_bytes().as_slice(),
+                    size_of::<UserAccount>()
+                );
+
+                remaining_tickets -= range_tickets;
+                reassigned_ticket_ranges += 1;
+                continue;
+            };
+
+            // split the ticket-range, the later ticket-ranges are shifted by one
+            let ledger_end = get_range_offset(ticket_ranges_total_amount as usize)?;
+            if lottery_account_data.len() < ledger_end + TICKET_RANGE_LENGTH {
+                return Err(
+                    LotteryError::ReallocationFailed.into()
+                );
+            };
+
+            lottery_account_data.copy_within(
+                range_offset + TICKET_RANGE_LENGTH..ledger_end,
+                range_offset + TICKET_RANGE_LENGTH * 2
+            );
+
+            sol_memcpy(
+                lottery_account_data
+                    .get_mut(range_offset + size_of::<UserAccount>()..range_offset + TICKET_RANGE_LENGTH)
+                    .unwrap(),
+                (end_index - remaining_tickets).to_le_bytes().as_slice(),
+                size_of::<TicketEndIndex>()
+            );
+            sol_memcpy(
+                lottery_account_data
+                    .get_mut(range_offset + TICKET_RANGE_LENGTH..range_offset + TICKET_RANGE_LENGTH * 2)
+                    .unwrap(),
+                [
+                    receiver_user_account_pda.to_bytes().as_slice(),
+                    end_index.to_le_bytes().as_slice()
+                ].concat().as_slice(),
+                TICKET_RANGE_LENGTH
+            );
+
+            // update ticket_ranges_total_amount field
+            let new_ticket_ranges_total_amount = ticket_ranges_total_amount
+                .checked_add(1)
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+            sol_memcpy(
+                lottery_account_data
+                    .get_mut(358..362)
+                    .unwrap(),
+                new_ticket_ranges_total_amount.to_le_bytes().as_slice(),
+                size_of::<u32>()
+            );
+
+            return Ok((reassigned_ticket_ranges, true));
+        };
+
+        if remaining_tickets > 0 {
+            return Err(
+                LotteryError::InsufficientTicketsToTransfer.into()
+            );
+        };
+
+        Ok((reassigned_ticket_ranges, false))
+    }
+
+    pub fn get_lottery_state(
+        &self,
+        current_time: Time
+    ) -> LotteryState {
+        if Self::is_not_started(&self, current_time) == true {
+            return LotteryState::Unknown;
+        };
+
+        if Self::is_started_and_not_ended(&self, current_time) == true {
+            return LotteryState::Unknown;
+        };
+
+        if Self::is_ended(&self, current_time) == false {
+            return LotteryState::Invalid;
+        };
+
+        if self.tickets_total_amount as usize >= (self.minimum_tickets_amount_required_to_be_sold as usize) {
+            // the winners can't be picked without the oracle's randomness -> the tickets are refunded
+            if self.is_randomness_fulfilment_missed(current_time) == true {
+                return LotteryState::Failed;
+            };
+
+            return LotteryState::Successful;
+        } else {
+            return LotteryState::Failed;
+        };
+    }
+
+    pub fn pick_winners(
+        &mut self,
+        sha256_hash: &[u8; HASH_BYTES],
+        lottery_account_info: &AccountInfo
+    ) -> ProgramResult {
+        let (
+            winners_index,
+            winners_pubkey
+        ) = self.get_winners(sha256_hash, lottery_account_info)?;
+        sol_log("Winners_Index :");
+        msg!("{:?}", winners_index);
+
+        sol_log("Winners :");
+        for (rank, winner_pubkey) in winners_pubkey.into_iter().enumerate() {
+            self.winners.push(
+                (
+                    winner_pubkey,
+                    rank as Rank,
+                    bool::default()
+                )
+            );
+
+            winner_pubkey.log();
+        };
+
+        Ok(())
+    }
+
+    // Winners (ticket indexes & their owners) of the seed, shared by "pick_winners" and the off-chain verifier
+    pub fn get_winners(
+        &self,
+        sha256_hash: &[u8; HASH_BYTES],
+        lottery_account_info: &AccountInfo
+    ) -> Result<(Vec<u32>, Vec<Pubkey>), ProgramError> {
+        let winners_index = Self::get_winners_index(
+            sha256_hash,
+            self.tickets_total_amount,
+            self.winners_count
+        )?;
+
+        let mut winners_pubkey: Vec<Pubkey> = Vec::with_capacity(winners_index.len());
+        for w in winners_index.iter() {
+            // read winner pubkey
+            let winner_pubkey = match Self::get_ticket(lottery_account_info, *w as usize) {
+                Ok(pubkey) => pubkey,
+                Err(_) => return Err(
+                    LotteryError::FailedToGetTicket.into()
                 )
+            };
+
+            winners_pubkey.push(winner_pubkey);
+        };
+
+        Ok((winners_index, winners_pubkey))
+    }
+
+    // Draws "winners_count" distinct ticket indexes in 0..tickets_total_amount, every ticket has the same chance to win.
+    pub fn get_winners_index(
+        seed: &[u8; HASH_BYTES],
+        tickets_total_amount: u32,
+        winners_count: u8
+    ) -> Result<Vec<u32>, ProgramError> {
+        if winners_count as u32 > tickets_total_amount {
+            return Err(
+                LotteryError::InsufficientRandomNumbers.into()
             );
+        };
+
+        let mut random_number_generator = RandomNumberGenerator::new(seed);
+        let mut winners_index: Vec<u32> = Vec::with_capacity(winners_count as usize);
+        while winners_index.len() < winners_count as usize {
+            let random_index = random_number_generator.next_index(tickets_total_amount);
+
+            if winners_index.contains(&random_index) == false {
+                winners_index.push(random_index);
+            };
+        };
+
+        Ok(winners_index)
+    }
+
+    // Ranks the winner hasn't withdrawn yet, they are marked as withdrawn
+    pub fn get_winner_info(
+        &mut self,
+        winner_account: &Pubkey
+    ) -> Result<Vec<Rank>, ProgramError> {
+        let mut winning_ranks: Vec<Rank> = Vec::new();
+        for winner in self.winners.iter_mut() {
+            if &winner.0 == winner_account && winner.2 == false {
+                winning_ranks.push(winner.1);
+                winner.2 = true;
+            };
+        };
+
+        if winning_ranks.is_empty() == true {
+            Err(
+                LotteryError::WinnerNotFound.into()
+            )
         } else {
-            panic!("Test must gives us an erorr!");
+            Ok(winning_ranks)
+        }
+    }
+
+    // Owner of the ticket -> the first ticket-range whose end index is greater than "ticket_index" (binary search)
+    pub fn get_ticket(
+        lottery_account_info: &AccountInfo,
+        ticket_index: usize
+    ) -> Result<Pubkey, u8> {
+        let lottery_account_data = lottery_account_info
+            .data
+            .try_borrow()
+            .map_err(|_| 0u8)?;
+
+        let initial_bytes = u64::from_le_bytes(
+            lottery_account_data
+                .get(9..17)
+                .ok_or(1u8)?
+                .try_into()
+                .map_err(|_| 2u8)?
+        ) as usize;
+
+        let ticket_ranges_total_amount = u32::from_le_bytes(
+            lottery_account_data
+                .get(358..362)
+                .ok_or(3u8)?
+                .try_into()
+                .map_err(|_| 4u8)?
+        ) as usize;
+
+        let get_ticket_range = |range_index: usize| -> Result<TicketRange, u8> {
+            let range_offset = initial_bytes.checked_add(
+                range_index.checked_mul(
+                    TICKET_RANGE_LENGTH
+                ).ok_or(5u8)?
+            ).ok_or(6u8)?;
+
+            TicketRange::try_from_slice(
+                lottery_account_data
+                    .get(
+                        range_offset..range_offset.checked_add(
+                            TICKET_RANGE_LENGTH
+                        ).ok_or(7u8)?
+                    )
+                    .ok_or(8u8)?
+            ).map_err(|_| 9u8)
+        };
+
+        let mut low = 0usize;
+        let mut high = ticket_ranges_total_amount;
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            if (get_ticket_range(middle)?.1 as usize) > ticket_index {
+                high = middle;
+            } else {
+                low = middle + 1;
+            };
+        };
+
+        if low == ticket_ranges_total_amount {
+            return Err(10u8);
         };
+
+        Ok(get_ticket_range(low)?.0)
     }
 
-    #[test]
-    fn test_validate_price_feed_accounts() {
-        let mut config_account = Config::default();
-        config_account.pyth_price_feed_accounts = [
-            Pubkey::new_from_array([1; 32]), // Sol
-            Pubkey::new_from_array([2; 32]), // Btc
-            Pubkey::new_from_array([3; 32]) // Eth
-        ];
+    pub fn check_max_tickets_per_instruction(tickets_amount: u32) -> ProgramResult {
+        if tickets_amount as usize > MAX_TICKETS_PER_INSTRUCTION {
+            return Err(
+                LotteryError::MaxTicketsPerInstructionExceeded.into()
+            )
+        };
 
-        let mut data: [u8; Config::LEN] = [0; Config::LEN];
-        config_account.serialize(
-            &mut data.as_mut_slice()
-        ).unwrap();
+        Ok(())
+    }
+
+    pub fn close_lottery_account(
+        lottery_account_info: &AccountInfo,
+        rent_exempt_recepient_account_info: &AccountInfo
+    ) -> ProgramResult {
+        // send all lamports to the recepient
+        let lottery_account_balance = lottery_account_info.lamports();
+        let rent_exempt_recepient_account_balance = rent_exempt_recepient_account_info.lamports();
+        
+        **lottery_account_info.try_borrow_mut_lamports()? = 0;
+        **rent_exempt_recepient_account_info.try_borrow_mut_lamports()? = (rent_exempt_recepient_account_balance)
+            .checked_add(lottery_account_balance)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        // clear data field and write "CLOSED_LOTTERY_ACCOUNT" discriminator
+        //  clear data
+        let initial_bytes = u64::from_le_bytes(
+            lottery_account_info
+                .try_borrow_data()
+                .unwrap()
+                .get(9..17)
+                .unwrap()
+                .try_into()
+                .unwrap()
+        );
+        
+        sol_memset(
+            &mut lottery_account_info
+                .data
+                .try_borrow_mut()
+                .unwrap(),
+            0,
+            initial_bytes
+                .try_into()
+                .unwrap()
+        );
+
+        //  write new discriminator
+        sol_memcpy(
+            &mut lottery_account_info
+                .data
+                .try_borrow_mut()
+                .unwrap(),
+            &Self::get_closed_account_discriminator(),
+            DISCRIMINATOR_LENTGH
+        );
+
+        Ok(())
+    }
+
+    pub fn get_closed_account_discriminator() -> [u8; DISCRIMINATOR_LENTGH] {
+        hash(CLOSED_LOTTERY_ACCOUNT_DISCRIMINATOR.as_bytes())
+            .to_bytes()
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(|dis: [u8; 8]| dis)
+            .unwrap()
+    }
+
+    pub fn get_discriminator() -> [u8; 8] {
+        hash(LOTTERY_ACCOUNT_DISCRIMINATOR.as_bytes())
+            .to_bytes()
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(|dis: [u8; 8]| dis)
+            .unwrap()
+    }
+}
+
+// Expands a 32-bytes seed into as many u64 random numbers as needed -> sha256(seed || counter)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RandomNumberGenerator {
+    pub seed: [u8; HASH_BYTES],
+    pub counter: u64,
+    pub block: [u8; HASH_BYTES],
+    pub block_offset: usize
+}
+
+impl RandomNumberGenerator {
+    pub fn new(seed: &[u8; HASH_BYTES]) -> Self {
+        Self {
+            seed: *seed,
+            counter: u64::default(),
+            block: [0; HASH_BYTES],
+            // forces a new block on the first draw
+            block_offset: HASH_BYTES
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        if self.block_offset == HASH_BYTES {
+            self.block = hashv(
+                &[
+                    self.seed.as_slice(),
+                    self.counter.to_le_bytes().as_slice()
+                ]
+            ).to_bytes();
+
+            self.counter = self.counter.checked_add(1).unwrap();
+            self.block_offset = 0;
+        };
+
+        let random_number = u64::from_le_bytes(
+            self.block
+                .get(self.block_offset..self.block_offset + size_of::<u64>())
+                .unwrap()
+                .try_into()
+                .unwrap()
+        );
+        self.block_offset += size_of::<u64>();
+
+        random_number
+    }
+
+    // Uniform index in 0..upper_bound, draws above the largest multiple of "upper_bound" are rejected to avoid modulo bias.
+    pub fn next_index(&mut self, upper_bound: u32) -> u32 {
+        let upper_bound = upper_bound as u64;
+        let zone = u64::MAX - (u64::MAX % upper_bound);
+
+        loop {
+            let random_number = self.next_u64();
+
+            if random_number < zone {
+                return (random_number % upper_bound) as u32;
+            };
+        };
+    }
+}
+
+#[derive(Debug, Default, BorshDeserialize, BorshSerialize, Clone, Copy, PartialEq, Eq)]
+pub struct User {
+    pub discriminator: [u8; DISCRIMINATOR_LENTGH],
+    pub canonical_bump: u8,
+    pub lottery: Pubkey,
+    pub authority: Pubkey,
+    pub total_tickets_value: u64, // USDC
+    pub total_rent_exempt_paied: u64, // Lamports
+    pub total_tickets_acquired: u32,
+    pub total_ticket_ranges_acquired: u32, // one per purchase, see "Lottery::add_ticket"
+    pub created_at: Time,
+    pub total_converted_tickets_value: u64, // USDC, part of "total_tickets_value" paid in SOL (refunded in SOL)
+    pub total_tickets_lamports: u64, // Lamports paid by "BuyTicketWithOracleConversion"
+    pub sponsor: Option<Pubkey>, // set by "BuyTicketFor", a user account has a single sponsor
+    pub total_sponsored_tickets_value: u64 // USDC, part of "total_tickets_value" paid by the sponsor (refunded to the sponsor)
+}
+impl User {
+    pub const LEN: usize =
+        DISCRIMINATOR_LENTGH +
+        CANONICAL_BUMP_LENGTH +
+        size_of::<Pubkey>() +
+        size_of::<Pubkey>() +
+        size_of::<u64>() +
+        size_of::<u64>() +
+        size_of::<u32>() +
+        size_of::<u32>() +
+        size_of::<Time>() +
+        size_of::<u64>() +
+        size_of::<u64>() +
+        size_of::<bool>() + size_of::<Pubkey>() +
+        size_of::<u64>();
+
+    pub fn validate_user_account(
+        user_account_info: &AccountInfo,
+        program_id: &Pubkey,
+        lottery_account: &Pubkey,
+        user_account_authority: &Pubkey
+    ) -> ProgramResult {
+        if user_account_info.owner != program_id {
+            return Err(
+                ProgramError::IncorrectProgramId
+            );
+        };
+
+        if sol_memcmp(
+            &user_account_info
+                .data
+                .try_borrow()
+                .unwrap(),
+            &Self::get_discriminator(),
+            DISCRIMINATOR_LENTGH
+        ) != 0 {
+            return Err(
+                LotteryError::InvalidDiscriminator.into()
+            );
+        };
+
+        // Both user_account_authority & lottery_account are validated here
+        let user_pda_addr = Pubkey::create_program_address(
+            &[
+                USER_ACCOUNT_SEED.as_bytes(),
+                user_account_authority.to_bytes().as_slice(),
+                lottery_account.to_bytes().as_slice(),
+                &[
+                    *user_account_info
+                        .data
+                        .try_borrow()
+                        .unwrap()
+                        .get(8)
+                        .unwrap()
+                ]
+            ],
+            program_id
+        ).map_err::<ProgramError, _>(|_| LotteryError::FailedToFindProgramAddress.into())?;
+
+        if user_pda_addr != *user_account_info.key {
+            return Err(
+                ProgramError::InvalidSeeds
+            );
+        };
+
+        Ok(())
+    }
+
+    pub fn validate_user_holding_tickets_amount(
+        &self,
+        maximum_amount: &Option<u32>,
+        tickets_amount_to_buy_now: u32
+    ) -> ProgramResult {
+        if maximum_amount.is_none() {
+            return Ok(());
+        };
+
+        let max_permitted_tickets_to_hold = maximum_amount.unwrap();
+        if 
+            &self.total_tickets_acquired == &max_permitted_tickets_to_hold ||
+            &self.total_tickets_acquired.checked_add(tickets_amount_to_buy_now).unwrap() > &max_permitted_tickets_to_hold
+        {
+            Err(
+                LotteryError::MaxTicketsAmountViolated.into()
+            )
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn set_sponsor(
+        &mut self,
+        sponsor: &Pubkey
+    ) -> ProgramResult {
+        match self.sponsor {
+            Some(current_sponsor) if current_sponsor != *sponsor => Err(
+                LotteryError::SponsorMismatch.into()
+            ),
+            _ => {
+                self.sponsor = Some(*sponsor);
+
+                Ok(())
+            }
+        }
+    }
+
+    // Moves the prorated share of "tickets_amount" tickets (values, SOL payments & sponsored part) to the receiver,
+    // the rent_exempt of the re-assigned ticket-ranges moves with them
+    pub fn transfer_tickets_to(
+        &mut self,
+        receiver: &mut User,
+        tickets_amount: u32,
+        reassigned_ticket_ranges: u32
+    ) -> ProgramResult {
+        if tickets_amount == 0 || tickets_amount > self.total_tickets_acquired {
+            return Err(
+                LotteryError::InsufficientTicketsToTransfer.into()
+            );
+        };
+
+        let get_prorated_amount = |amount: u64, part: u32, total: u32| -> u64 {
+            ((amount as u128) * (part as u128) / (total as u128)) as u64
+        };
+
+        // every part of the tickets value is prorated on its own & the moved value is their sum,
+        // so the converted & sponsored parts never exceed the tickets value left to the sender
+        let converted_tickets_value = get_prorated_amount(self.total_converted_tickets_value, tickets_amount, self.total_tickets_acquired);
+        let sponsored_tickets_value = get_prorated_amount(self.total_sponsored_tickets_value, tickets_amount, self.total_tickets_acquired);
+        let self_paid_tickets_value = get_prorated_amount(
+            self.total_tickets_value
+                .checked_sub(self.total_converted_tickets_value)
+                .and_then(|value| value.checked_sub(self.total_sponsored_tickets_value))
+                .ok_or::<ProgramError>(LotteryError::Overflow.into())?,
+            tickets_amount,
+            self.total_tickets_acquired
+        );
+        let tickets_value = converted_tickets_value
+            .checked_add(sponsored_tickets_value)
+            .and_then(|value| value.checked_add(self_paid_tickets_value))
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        let tickets_lamports = get_prorated_amount(self.total_tickets_lamports, tickets_amount, self.total_tickets_acquired);
+        let rent_exempt = if self.total_ticket_ranges_acquired == 0 {
+            0
+        } else {
+            get_prorated_amount(self.total_rent_exempt_paied, reassigned_ticket_ranges, self.total_ticket_ranges_acquired)
+        };
+
+        // the sponsored part is refunded to the sponsor, the receiver must have the same one (or none)
+        if sponsored_tickets_value > 0 {
+            if let Some(sponsor) = self.sponsor {
+                receiver.set_sponsor(&sponsor)?;
+            };
+        };
+
+        self.total_tickets_acquired -= tickets_amount;
+        self.total_ticket_ranges_acquired = self.total_ticket_ranges_acquired
+            .checked_sub(reassigned_ticket_ranges)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        self.total_tickets_value -= tickets_value;
+        self.total_converted_tickets_value -= converted_tickets_value;
+        self.total_sponsored_tickets_value -= sponsored_tickets_value;
+        self.total_tickets_lamports -= tickets_lamports;
+        self.total_rent_exempt_paied -= rent_exempt;
+
+        receiver.total_tickets_acquired = receiver.total_tickets_acquired
+            .checked_add(tickets_amount)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        receiver.total_ticket_ranges_acquired = receiver.total_ticket_ranges_acquired
+            .checked_add(reassigned_ticket_ranges)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        receiver.total_tickets_value = receiver.total_tickets_value
+            .checked_add(tickets_value)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        receiver.total_converted_tickets_value = receiver.total_converted_tickets_value
+            .checked_add(converted_tickets_value)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        receiver.total_sponsored_tickets_value = receiver.total_sponsored_tickets_value
+            .checked_add(sponsored_tickets_value)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        receiver.total_tickets_lamports = receiver.total_tickets_lamports
+            .checked_add(tickets_lamports)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+        receiver.total_rent_exempt_paied = receiver.total_rent_exempt_paied
+            .checked_add(rent_exempt)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        Ok(())
+    }
+
+    pub fn close_user_account(
+        user_account_info: &AccountInfo,
+        rent_exempt_recepient_account_info: &AccountInfo
+    ) -> ProgramResult {
+        // send all lamports to the recepient
+        let user_account_balance = user_account_info.lamports();
+        let rent_exempt_recepient_account_balance = rent_exempt_recepient_account_info.lamports();
+        
+        **user_account_info.try_borrow_mut_lamports()? = 0;
+        **rent_exempt_recepient_account_info.try_borrow_mut_lamports()? = (rent_exempt_recepient_account_balance)
+            .checked_add(user_account_balance)
+            .ok_or::<ProgramError>(LotteryError::Overflow.into())?;
+
+        // clear data field and write "CLOSED_USER_ACCOUNT" discriminator
+        //  clear data
+        sol_memset(
+            &mut user_account_info
+                .data
+                .try_borrow_mut()
+                .unwrap(),
+            0,
+            Self::LEN
+        );
+
+        //  write new discriminator
+        sol_memcpy(
+            &mut user_account_info
+                .data
+                .try_borrow_mut()
+                .unwrap(),
+            &Self::get_closed_account_discriminator(),
+            DISCRIMINATOR_LENTGH
+        );
+
+        Ok(())
+    }
+
+    pub fn get_discriminator() -> [u8; DISCRIMINATOR_LENTGH] {
+        hash(USER_ACCOUNT_DISCRIMINATOR.as_bytes())
+            .to_bytes()
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(|dis: [u8; 8]| dis)
+            .unwrap()
+    }
+
+    pub fn get_closed_account_discriminator() -> [u8; DISCRIMINATOR_LENTGH] {
+        hash(CLOSED_USER_ACCOUNT_DISCRIMINATOR.as_bytes())
+            .to_bytes()
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(|dis: [u8; 8]| dis)
+            .unwrap()
+    }
+}
+
+#[derive(Debug, Default, BorshDeserialize, BorshSerialize, Clone, Copy, PartialEq, Eq)]
+pub struct RandomnessRequest {
+    pub discriminator: [u8; DISCRIMINATOR_LENTGH],
+    pub canonical_bump: u8,
+    pub lottery: Pubkey,
+    pub oracle_authority: Pubkey,
+    pub requested_at: Time,
+    pub request_seed: [u8; HASH_BYTES], // sha256(lottery_account || ticket ledger || revealed secret), see "Lottery::get_request_seed"
+    pub is_fulfilled: bool,
+    pub fulfilled_at: Time,
+    pub randomness: [u8; HASH_BYTES] // first bytes of the vrf output (beta) of the oracle's proof
+}
+impl RandomnessRequest {
+    pub const LEN: usize =
+        DISCRIMINATOR_LENTGH +
+        CANONICAL_BUMP_LENGTH +
+        size_of::<Pubkey>() +
+        size_of::<Pubkey>() +
+        size_of::<Time>() +
+        HASH_BYTES +
+        size_of::<bool>() +
+        size_of::<Time>() +
+        HASH_BYTES;
+
+    pub fn new(
+        canonical_bump: u8,
+        lottery: Pubkey,
+        oracle_authority: Pubkey,
+        requested_at: Time,
+        request_seed: [u8; HASH_BYTES]
+    ) -> Self {
+        Self {
+            discriminator: Self::get_discriminator(),
+            canonical_bump,
+            lottery,
+            oracle_authority,
+            requested_at,
+            request_seed,
+            is_fulfilled: bool::default(),
+            fulfilled_at: Time::default(),
+            randomness: [0; HASH_BYTES]
+        }
+    }
+
+    pub fn validate_randomness_request_account(
+        randomness_request_account_info: &AccountInfo,
+        program_id: &Pubkey
+    ) -> ProgramResult {
+        if randomness_request_account_info.owner != program_id {
+            return Err(
+                ProgramError::IncorrectProgramId
+            );
+        };
+
+        if sol_memcmp(
+            &randomness_request_account_info
+                .data
+                .try_borrow()
+                .unwrap(),
+            &Self::get_discriminator(),
+            DISCRIMINATOR_LENTGH
+        ) != 0 {
+            return Err(
+                LotteryError::InvalidDiscriminator.into()
+            );
+        };
+
+        Ok(())
+    }
+
+    // The vrf input -> randomness_request_account || request_seed
+    pub fn get_message(
+        &self,
+        randomness_request_account: &Pubkey
+    ) -> Vec<u8> {
+        [
+            randomness_request_account.to_bytes().as_slice(),
+            self.request_seed.as_slice()
+        ].concat()
+    }
+
+    // The oracle's vrf proof over the message, a single proof is valid so the oracle can't pick the randomness
+    pub fn fulfil(
+        &mut self,
+        randomness_request_account: &Pubkey,
+        proof: &[u8; VRF_PROOF_LENGTH],
+        current_time: Time
+    ) -> ProgramResult {
+        if self.is_fulfilled == true {
+            return Err(
+                LotteryError::RandomnessAlreadyFulfilled.into()
+            );
+        };
+
+        // the first bytes of the vrf output (beta) are as uniform as the whole output
+        let vrf_output = verify_vrf_proof(
+            &self.oracle_authority,
+            &self.get_message(randomness_request_account),
+            proof
+        )?;
+        self.randomness = vrf_output[..HASH_BYTES].try_into().unwrap();
+        self.is_fulfilled = true;
+        self.fulfilled_at = current_time;
+
+        Ok(())
+    }
+
+    pub fn get_randomness(&self) -> Result<[u8; HASH_BYTES], ProgramError> {
+        if self.is_fulfilled == false {
+            return Err(
+                LotteryError::RandomnessNotFulfilled.into()
+            );
+        };
+
+        Ok(self.randomness)
+    }
+
+    pub fn get_discriminator() -> [u8; DISCRIMINATOR_LENTGH] {
+        hash(RANDOMNESS_REQUEST_ACCOUNT_DISCRIMINATOR.as_bytes())
+            .to_bytes()
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(|dis: [u8; 8]| dis)
+            .unwrap()
+    }
+}
+
+#[derive(Debug, Default, BorshDeserialize, BorshSerialize, Clone, Copy, PartialEq, Eq)]
+pub struct LotterySeries {
+    pub discriminator: [u8; DISCRIMINATOR_LENTGH],
+    pub canonical_bump: u8,
+    pub authority: Pubkey,
+    pub series_literal_seed: [u8; HASH_BYTES], // sha256(series_description), see "get_lottery_literal_seed"
+    pub jackpot_share: BasisPoints, // share of each round's ticket revenue (creator's side) sent to the jackpot vault
+    pub house_slots: u32,
+    pub reserved_jackpot_amount: u64, // USDC won by rounds & not claimed yet
+    pub rounds_total_amount: u32,
+    pub created_at: Time,
+    pub round_template: Option<RoundTemplate>, // set by "SetLotterySeriesRoundTemplate", its rounds are started by "StartNextRound"
+    pub prize_vault_canonical_bump: u8, // series-prize-vault pda, owner of the series' prize vault
+    pub last_round: Option<Pubkey> // latest round ("StartNextRound" or "JoinLotterySeries"), the next one waits for its end
+    // jackpot vault -> series account's associated usdc token account
+    // prize vault -> series-prize-vault pda's associated token account of the template's prize mint
+}
+
+// Template of the rounds started by "StartNextRound", each round's prize is pulled from the series' prize vault
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Clone, Copy, Default)]
+pub struct RoundTemplate {
+    pub prize_mint: Pubkey, // can't be changed once set (the prize vault holds its tokens)
+    pub fund_amount: u64, // prize of each round, split equally between the winners
+    pub ticket_price: u64, // USDC
+    pub winners_count: u8,
+    pub round_duration: u32, // in seconds
+    pub minimum_tickets_amount_required_to_be_sold: u32
+}
+impl LotterySeries {
+    pub const LEN: usize =
+        DISCRIMINATOR_LENTGH +
+        CANONICAL_BUMP_LENGTH +
+        size_of::<Pubkey>() +
+        HASH_BYTES +
+        size_of::<BasisPoints>() +
+        size_of::<u32>() +
+        size_of::<u64>() +
+        size_of::<u32>() +
+        size_of::<Time>() +
+        size_of::<bool>() + ROUND_TEMPLATE_LENGTH +
+        CANONICAL_BUMP_LENGTH +
+        size_of::<bool>() + size_of::<Pubkey>();
+
+    pub fn new(
+        canonical_bump: u8,
+        authority: Pubkey,
+        series_literal_seed: [u8; HASH_BYTES],
+        jackpot_share: BasisPoints,
+        house_slots: u32,
+        created_at: Time
+    ) -> Self {
+        Self {
+            discriminator: Self::get_discriminator(),
+            canonical_bump,
+            authority,
+            series_literal_seed,
+            jackpot_share,
+            house_slots,
+            reserved_jackpot_amount: u64::default(),
+            rounds_total_amount: u32::default(),
+            created_at,
+            round_template: None,
+            prize_vault_canonical_bump: u8::default(),
+            last_round: None
+        }
+    }
+
+    pub fn validate_lottery_series_account(
+        lottery_series_account_info: &AccountInfo,
+        program_id: &Pubkey
+    ) -> ProgramResult {
+        if lottery_series_account_info.owner != program_id {
+            return Err(
+                ProgramError::IncorrectProgramId
+            );
+        };
+
+        if sol_memcmp(
+            &lottery_series_account_info
+                .data
+                .try_borrow()
+                .unwrap(),
+            &Self::get_discriminator(),
+            DISCRIMINATOR_LENTGH
+        ) != 0 {
+            return Err(
+                LotteryError::InvalidDiscriminator.into()
+            );
+        };
+
+        Ok(())
+    }
+
+    pub fn validate_jackpot_share(jackpot_share: BasisPoints) -> ProgramResult {
+        if jackpot_share > MAX_BASIS_POINTS {
+            return Err(
+                LotteryError::InvalidJackpotShare.into()
+            );
+        };
+
+        Ok(())
+    }
+
+    // Round's contribution to the jackpot vault -> "revenue * jackpot_share / MAX_BASIS_POINTS"
+    pub fn get_jackpot_contribution(
+        jackpot_share: BasisPoints,
+        revenue: u64
+    ) -> u64 {
+        ((revenue as u128) * (jackpot_share as u128) / (MAX_BASIS_POINTS as u128)) as u64
+    }
+
+    // Rounds started by "StartNextRound" are lottery accounts of the series' authority,
+    // their description (literal seed) is derived from the series & the round number
+    pub fn get_round_description(
+        lottery_series_account: &Pubkey,
+        round_number: u32
+    ) -> String {
+        format!("{}#{}", lottery_series_account, round_number)
+    }
+
+    pub fn get_discriminator() -> [u8; DISCRIMINATOR_LENTGH] {
+        hash(LOTTERY_SERIES_ACCOUNT_DISCRIMINATOR.as_bytes())
+            .to_bytes()
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(|dis: [u8; 8]| dis)
+            .unwrap()
+    }
+}
+
+impl RoundTemplate {
+    // Checked when the template is set & again by each round, the config can change in between
+    pub fn validate(
+        &self,
+        config_account: &Config
+    ) -> ProgramResult {
+        if !(
+            self.winners_count > 0 &&
+            self.winners_count <= config_account.maximum_number_of_winners
+        ) {
+            return Err(
+                LotteryError::InvalidWinnersAmount.into()
+            );
+        };
+
+        if self.minimum_tickets_amount_required_to_be_sold < (config_account.minimum_tickets_to_be_sold_in_lottery as u32) {
+            return Err(
+                LotteryError::InvalidMinimumAmountTickets.into()
+            );
+        };
+
+        if (self.winners_count as u32) > self.minimum_tickets_amount_required_to_be_sold {
+            return Err(
+                LotteryError::InvalidMinTicketsReqAndWinnersAmount.into()
+            );
+        };
+
+        if self.round_duration == 0 {
+            return Err(
+                LotteryError::InvalidTime.into()
+            );
+        };
+
+        if self.round_duration > config_account.maximum_time_for_lottery_account {
+            return Err(
+                LotteryError::MaximumTimeExceed.into()
+            );
+        };
+
+        if self.fund_amount == 0 {
+            return Err(
+                LotteryError::InvalidFundAmount.into()
+            );
+        };
+
+        if self.fund_amount % (self.winners_count as u64) != 0 {
+            return Err(
+                LotteryError::WinnersAndFundAmountMismatch.into()
+            );
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_config {
+    use {
+        borsh::BorshSerialize,
+        solana_program::clock::Epoch,
+        std::{
+            rc::Rc,
+            cell::RefCell,
+            str::FromStr
+        },
+        crate::error::LotteryError
+    };
+    use super::{
+        Config,
+        AccountInfo,
+        CONFIG_ACCOUNT_SEED,
+        Pubkey,
+        ProgramError,
+        PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID
+    };
+
+    #[test]
+    fn test_succeed_check_is_pause() {
+        let config_account = Config::default();
+        config_account.check_is_pause().unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fail_check_is_pause() {
+        let mut config_account = Config::default();
+        config_account.is_pause = true;
+
+        config_account.check_is_pause().unwrap();
+    }
+
+    #[test]
+    fn test_succeed_check_is_pause_raw() {
+        let config_account = Config::default();
+
+        let mut data: [u8; Config::LEN] = [0; Config::LEN];
+        config_account.serialize(
+            &mut &mut data.as_mut_slice()
+        ).unwrap();
+
+        Config::check_is_pause_raw(
+            &AccountInfo {
+                key: &Pubkey::new_unique(),
+                lamports: Rc::new(RefCell::new(&mut u64::default())),
+                data: Rc::new(RefCell::new(&mut data)),
+                owner: &Pubkey::new_unique(),
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            }
+        ).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fail_check_is_pause_raw() {
+        let mut config_account = Config::default();
+        config_account.is_pause = true;
+
+        let mut data: [u8; Config::LEN] = [0; Config::LEN];
+        config_account.serialize(
+            &mut &mut data.as_mut_slice()
+        ).unwrap();
+
+        Config::check_is_pause_raw(
+            &AccountInfo {
+                key: &Pubkey::new_unique(),
+                lamports: Rc::new(RefCell::new(&mut u64::default())),
+                data: Rc::new(RefCell::new(&mut data)),
+                owner: &Pubkey::new_unique(),
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            }
+        ).unwrap();
+    }
+
+    #[test]
+    fn test_succeed_validate_config_account() {
+        let program_id = Pubkey::from_str("EGxRBwjoC99LtLznAyLFcSxaiCrzPiXW3gHmemq4pump").unwrap();
+        let config_account_addr: Pubkey;
+
+        let mut config_account = Config::default();
+        
+        let (
+            pda_addr,
+            pda_bump
+        ) = Pubkey::try_find_program_address(
+            &[
+                CONFIG_ACCOUNT_SEED.as_bytes()
+            ],
+            &program_id
+        ).unwrap();
+        
+        config_account.canonical_bump = pda_bump;
+        config_account.discriminator = Config::get_discriminator();
+        config_account_addr = pda_addr;
+
+        let mut data: [u8; Config::LEN] = [0; Config::LEN];
+        config_account.serialize(
+            &mut &mut data.as_mut_slice()
+        ).unwrap();
+
+        Config::validate_config_account(
+            &AccountInfo {
+                key: &config_account_addr,
+                lamports: Rc::new(RefCell::new(&mut u64::default())),
+                data: Rc::new(RefCell::new(&mut data)),
+                owner: &program_id,
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            },
+            &program_id
+        ).unwrap();
+    }
+
+    #[test]
+    fn test_validate_config_account_error_invalid_programid() {
+        let program_id = Pubkey::from_str("EGxRBwjoC99LtLznAyLFcSxaiCrzPiXW3gHmemq4pump").unwrap();
+        let config_account_addr: Pubkey;
+
+        let mut config_account = Config::default();
+        
+        let (
+            pda_addr,
+            pda_bump
+        ) = Pubkey::try_find_program_address(
+            &[
+                CONFIG_ACCOUNT_SEED.as_bytes()
+            ],
+            &program_id
+        ).unwrap();
+        
+        config_account.canonical_bump = pda_bump;
+        config_account.discriminator = Config::get_discriminator();
+        config_account_addr = pda_addr;
+
+        let mut data: [u8; Config::LEN] = [0; Config::LEN];
+        config_account.serialize(
+            &mut &mut data.as_mut_slice()
+        ).unwrap();
+
+        let result = Config::validate_config_account(
+            &AccountInfo {
+                key: &config_account_addr,
+                lamports: Rc::new(RefCell::new(&mut u64::default())),
+                data: Rc::new(RefCell::new(&mut data)),
+                owner: &Pubkey::default(),
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            },
+            &program_id
+        );
+
+        if let Err(error) = result {
+            assert_eq!(
+                error,
+                ProgramError::IncorrectProgramId
+            );
+        } else {
+            panic!("Test must gives us an erorr!");
+        };
+    }
+
+    #[test]
+    fn test_validate_config_account_error_invalid_discriminator() {
+        let program_id = Pubkey::from_str("EGxRBwjoC99LtLznAyLFcSxaiCrzPiXW3gHmemq4pump").unwrap();
+        let config_account_addr: Pubkey;
+
+        let mut config_account = Config::default();
+        
+        let (
+            pda_addr,
+            pda_bump
+        ) = Pubkey::try_find_program_address(
+            &[
+                CONFIG_ACCOUNT_SEED.as_bytes()
+            ],
+            &program_id
+        ).unwrap();
+        
+        config_account.canonical_bump = pda_bump;
+        config_account.discriminator = [0; 8];
+        config_account_addr = pda_addr;
+
+        let mut data: [u8; Config::LEN] = [0; Config::LEN];
+        config_account.serialize(
+            &mut &mut data.as_mut_slice()
+        ).unwrap();
+
+        let result = Config::validate_config_account(
+            &AccountInfo {
+                key: &config_account_addr,
+                lamports: Rc::new(RefCell::new(&mut u64::default())),
+                data: Rc::new(RefCell::new(&mut data)),
+                owner: &program_id,
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            },
+            &program_id
+        );
+
+        if let Err(error) = result {
+            assert_eq!(
+                error,
+                ProgramError::Custom(
+                    LotteryError::InvalidDiscriminator as u32
+                )
+            );
+        } else {
+            panic!("Test must gives us an erorr!");
+        };
+    }
+
+    #[test]
+    fn test_validate_config_account_error_invalid_account_address_invalid_seeds() {
+        let program_id = Pubkey::from_str("EGxRBwjoC99LtLznAyLFcSxaiCrzPiXW3gHmemq4pump").unwrap();
+        let config_account_addr: Pubkey;
+
+        let mut config_account = Config::default();
+        
+        let (
+            pda_addr,
+            pda_bump
+        ) = Pubkey::try_find_program_address(
+            &[
+                CONFIG_ACCOUNT_SEED.as_bytes(),
+                b"invalid_seed"
+            ],
+            &program_id
+        ).unwrap();
+        
+        config_account.canonical_bump = pda_bump;
+        config_account.discriminator = Config::get_discriminator();
+        config_account_addr = pda_addr;
+
+        let mut data: [u8; Config::LEN] = [0; Config::LEN];
+        config_account.serialize(
+            &mut &mut data.as_mut_slice()
+        ).unwrap();
+
+        let result = Config::validate_config_account(
+            &AccountInfo {
+                key: &config_account_addr,
+                lamports: Rc::new(RefCell::new(&mut u64::default())),
+                data: Rc::new(RefCell::new(&mut data)),
+                owner: &program_id,
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            },
+            &program_id
+        );
+
+        if let Err(error) = result {
+            assert_eq!(
+                error,
+                ProgramError::Custom(
+                    LotteryError::InvalidConfigAccount as u32
+                )
+            );
+        } else {
+            panic!("Test must gives us an erorr!");
+        };
+    }
+
+    #[test]
+    fn test_validate_price_feed_accounts() {
+        let mut config_account = Config::default();
+        config_account.pyth_price_feed_accounts = [
+            Pubkey::new_from_array([1; 32]), // Sol
+            Pubkey::new_from_array([2; 32]), // Btc
+            Pubkey::new_from_array([3; 32]) // Eth
+        ];
+
+        let mut data: [u8; Config::LEN] = [0; Config::LEN];
+        config_account.serialize(
+            &mut data.as_mut_slice()
+        ).unwrap();
+
+        // success
+        {
+            config_account.validate_price_feed_accounts(
+                // Sol price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[0],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Btc price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[1],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Eth price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[2],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                }
+            ).unwrap();
+        }
+
+        // fail(all) - invalid_price_feed_accounts_owner
+        {
+            let result = config_account.validate_price_feed_accounts(
+                // Sol price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[0],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &Pubkey::new_from_array([5; 32]),
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Btc price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[1],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &Pubkey::new_from_array([5; 32]),
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Eth price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[2],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &Pubkey::new_from_array([5; 32]),
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                }
+            );
+            
+            if let Err(error) = result {
+                assert_eq!(
+                    error,
+                    ProgramError::Custom(
+                        LotteryError::InvalidPriceFeedAccountsOwner as u32
+                    )
+                );
+            } else {
+                panic!("We must have an invalid_owner error!");
+            };
+        }
+
+        // fail(any) - invalid_price_feed_accounts_owner
+        {
+            let result = config_account.validate_price_feed_accounts(
+                // Sol price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[0],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Btc price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[1],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &Pubkey::new_from_array([5; 32]),
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Eth price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[2],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                }
+            );
+            
+            if let Err(error) = result {
+                assert_eq!(
+                    error,
+                    ProgramError::Custom(
+                        LotteryError::InvalidPriceFeedAccountsOwner as u32
+                    )
+                );
+            } else {
+                panic!("We must have an invalid_owner error!");
+            };
+        }
+
+        // fail - invalid_sol_price_feed_account
+        {
+            let result = config_account.validate_price_feed_accounts(
+                // Sol price feed account
+                &AccountInfo {
+                    key: &Pubkey::new_from_array([5; 32]),
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Btc price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[1],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Eth price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[2],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                }
+            );
+
+            if let Err(error) = result {
+                assert_eq!(
+                    error,
+                    ProgramError::Custom(
+                        LotteryError::InvalidSolPriceFeedAccount as u32
+                    )
+                );
+            } else {
+                panic!("We must have an invalid_sol_price_feed_account error!");
+            };
+        }
+
+        // fail - invalid_btc_price_feed_account
+        {
+            let result = config_account.validate_price_feed_accounts(
+                // Sol price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[0],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Btc price feed account
+                &AccountInfo {
+                    key: &Pubkey::new_from_array([6; 32]),
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Eth price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[2],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                }
+            );
+
+            if let Err(error) = result {
+                assert_eq!(
+                    error,
+                    ProgramError::Custom(
+                        LotteryError::InvalidBtcPriceFeedAccount as u32
+                    )
+                );
+            } else {
+                panic!("We must have an invalid_btc_price_feed_account error!");
+            };
+        }
+
+        // fail - invalid_eth_price_feed_account
+        {
+            let result = config_account.validate_price_feed_accounts(
+                // Sol price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[0],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Btc price feed account
+                &AccountInfo {
+                    key: &config_account.pyth_price_feed_accounts[1],
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                // Eth price feed account
+                &AccountInfo {
+                    key: &Pubkey::new_from_array([6; 32]),
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut [])),
+                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                }
+            );
+
+            if let Err(error) = result {
+                assert_eq!(
+                    error,
+                    ProgramError::Custom(
+                        LotteryError::InvalidEthPriceFeedAccount as u32
+                    )
+                );
+            } else {
+                panic!("We must have an invalid_eth_price_feed_account error!");
+            };
+        }
+    }
+
+    #[test]
+    fn test_validate_fee_per_ticket() {
+        // success
+        Config::validate_fee_per_ticket(&45.6).unwrap();
+
+        // fail
+        if let Ok(_) = Config::validate_fee_per_ticket(&101.0) {
+            panic!("This must fail!");
+        };
+    } 
+}
+
+#[cfg(test)]
+mod test_lottery {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use solana_program::{
+        pubkey::Pubkey,
+        account_info::AccountInfo,
+        clock::Epoch,
+        program_error::ProgramError,
+        hash::{
+            hash,
+            hashv
+        }
+    };
+    use super::{
+        LotteryError,
+        Config,
+        Lottery,
+        LotterySeries,
+        LotteryState,
+        RandomNumberGenerator,
+        RandomNumbersInfo,
+        RandomnessSource,
+        RoundTemplate,
+        SeriesJackpot,
+        MAX_TIME_TO_FULFIL_RANDOMNESS,
+        MAX_PRIZE_MINTS_PER_LOTTERY,
+        MAX_BASIS_POINTS,
+        MAX_TIME_TO_REVEAL_SECRET,
+        MAX_TIME_TO_REFUND_TICKET_TOKENS,
+        TICKET_RANGE_LENGTH
+    };
+    use std::{
+        rc::Rc,
+        cell::RefCell
+    };
+
+    #[test]
+    fn test_validate_lottery_account() {
+        let program_id = Pubkey::new_from_array([1; 32]);
+        let mut lottery_account = Lottery::default();
+        lottery_account.discriminator = Lottery::get_discriminator();
+
+        let mut data: [u8; 500] = [0; 500];
+        lottery_account.serialize(
+            &mut data.as_mut_slice()
+        ).unwrap();
+
+        // success
+        {
+            Lottery::validate_lottery_account(
+                &AccountInfo {
+                    key: &Pubkey::new_unique(),
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut data)),
+                    owner: &program_id,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                &program_id
+            ).unwrap();
+        }
+
+        // fail - invalid program id
+        {
+            let result = Lottery::validate_lottery_account(
+                &AccountInfo {
+                    key: &Pubkey::new_unique(),
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut data)),
+                    owner: &Pubkey::new_unique(),
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                &program_id
+            );
+
+            if let Err(error) = result {
+                assert_eq!(
+                    error,
+                    ProgramError::IncorrectProgramId
+                );
+            } else {
+                panic!("It must panic.");
+            };
+        }
+
+        // fail - invalid discriminator
+        {
+            let mut new_data = data.get_mut(8..).unwrap();
+
+            let result = Lottery::validate_lottery_account(
+                &AccountInfo {
+                    key: &Pubkey::new_unique(),
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut new_data)),
+                    owner: &program_id,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                &program_id
+            );
+
+            if let Err(error) = result {
+                assert_eq!(
+                    error,
+                    ProgramError::Custom(
+                        LotteryError::InvalidDiscriminator as u32
+                    )
+                );
+            } else {
+                panic!("It must panic.");
+            };
+        }
+    }
+
+    #[test]
+    fn test_lottery_timestamps() {
+        let mut lottery_account = Lottery::default();
+        let mut current_time: i64;
+
+        // not_started state
+        {
+            // true
+            {
+                lottery_account.starting_time = 1000;
+                current_time = 100;
+
+                assert_eq!(
+                    lottery_account.is_not_started(current_time),
+                    true
+                );
+            }
+
+            // false 
+            {
+                lottery_account.starting_time = 1000;
+                current_time = 1000;
+
+                assert_eq!(
+                    lottery_account.is_not_started(current_time),
+                    false
+                );
+            }
+        }
+
+        // started_and_not_ended state
+        {
+            // true
+            {
+                lottery_account.starting_time = 1000;
+                lottery_account.ending_time = 2000;
+                current_time = 1200;
+
+                assert_eq!(
+                    lottery_account.is_started_and_not_ended(current_time),
+                    true
+                );
+            }
+
+            // false
+            {
+                lottery_account.starting_time = 1000;
+                lottery_account.ending_time = 2000;
+                current_time = 2200;
+
+                assert_eq!(
+                    lottery_account.is_started_and_not_ended(current_time),
+                    false
+                );
+            }
+        }
+
+        // ended state 
+        {
+            // true
+            {
+                lottery_account.ending_time = 2000;
+                current_time = 2000;
+
+                assert_eq!(
+                    lottery_account.is_ended(current_time),
+                    true
+                );
+            }
+
+            // false
+            {
+                lottery_account.ending_time = 2000;
+                current_time = 1800;
+
+                assert_eq!(
+                    lottery_account.is_ended(current_time),
+                    false
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_tickets_and_get_ticket() {
+        let mut lottery_account = Lottery::default();
+        lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+
+        let mut lottery_account_data: Vec<u8> = vec![
+            lottery_account.try_to_vec().unwrap(), vec![0; TICKET_RANGE_LENGTH * 4]
+        ].concat();
+        let mut lottery_balance = solana_program::native_token::sol_to_lamports(1.0);
+        let lottery_account_info = &AccountInfo {
+            key: &Pubkey::new_unique(),
+            lamports: Rc::new(RefCell::new(&mut lottery_balance)),
+            data: Rc::new(RefCell::new(&mut lottery_account_data)),
+            owner: &Pubkey::new_unique(),
+            rent_epoch: Epoch::default(),
+            is_signer: false,
+            is_writable: false,
+            executable: false
+        };
+
+        assert_eq!(
+            lottery_account.tickets_total_amount,
+            0
+        );
+
+        assert_eq!(
+            lottery_account_info.data_len(),
+            lottery_account.initial_bytes as usize + TICKET_RANGE_LENGTH * 4
+        );
+
+        Lottery::add_ticket(
+            lottery_account_info,
+            lottery_account.initial_bytes,
+            0,
+            0,
+            1,
+            Pubkey::new_from_array([5; 32])
+        );
+
+        Lottery::add_ticket(
+            lottery_account_info,
+            lottery_account.initial_bytes,
+            1,
+            1,
+            1,
+            Pubkey::new_from_array([23; 32])
+        );
+
+        Lottery::add_ticket(
+            lottery_account_info,
+            lottery_account.initial_bytes,
+            2,
+            2,
+            2,
+            Pubkey::new_from_array([55; 32])
+        );
+
+        Lottery::add_ticket(
+            lottery_account_info,
+            lottery_account.initial_bytes,
+            4,
+            3,
+            1,
+            Pubkey::new_from_array([9; 32])
+        );
+
+        let updated_lottery_account = Lottery::deserialize(
+            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+        ).unwrap();
+
+        assert_eq!(
+            updated_lottery_account.tickets_total_amount,
+            5
+        );
+
+        assert_eq!(
+            updated_lottery_account.ticket_ranges_total_amount,
+            4
+        );
+
+
+        assert_eq!(
+            Lottery::get_ticket(lottery_account_info, 0).unwrap(),
+            Pubkey::new_from_array([5; 32])
+        );
+
+        assert_eq!(
+            Lottery::get_ticket(lottery_account_info, 1).unwrap(),
+            Pubkey::new_from_array([23; 32])
+        );
+
+        assert_eq!(
+            Lottery::get_ticket(lottery_account_info, 2).unwrap(),
+            Pubkey::new_from_array([55; 32])
+        );
+
+        assert_eq!(
+            Lottery::get_ticket(lottery_account_info, 3).unwrap(),
+            Pubkey::new_from_array([55; 32])
+        );
+
+        assert_eq!(
+            Lottery::get_ticket(lottery_account_info, 4).unwrap(),
+            Pubkey::new_from_array([9; 32])
+        );
+
+        // out of range
+        assert_eq!(
+            Lottery::get_ticket(lottery_account_info, 5).is_err(),
+            true
+        );
+    }
+
+    #[test]
+    fn test_transfer_tickets() {
+        let mut lottery_account = Lottery::default();
+        lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+
+        // room for a split ticket-range
+        let mut lottery_account_data: Vec<u8> = vec![
+            lottery_account.try_to_vec().unwrap(), vec![0; TICKET_RANGE_LENGTH * 5]
+        ].concat();
+        let mut lottery_balance = solana_program::native_token::sol_to_lamports(1.0);
+        let lottery_account_info = &AccountInfo {
+            key: &Pubkey::new_unique(),
+            lamports: Rc::new(RefCell::new(&mut lottery_balance)),
+            data: Rc::new(RefCell::new(&mut lottery_account_data)),
+            owner: &Pubkey::new_unique(),
+            rent_epoch: Epoch::default(),
+            is_signer: false,
+            is_writable: false,
+            executable: false
+        };
+
+        let sender = Pubkey::new_from_array([5; 32]);
+        let other_user = Pubkey::new_from_array([23; 32]);
+        let receiver = Pubkey::new_from_array([55; 32]);
+
+        // ticket ledger -> sender [0, 2), other user [2, 3), sender [3, 6), sender [6, 7)
+        for (tickets_total_amount, ticket_ranges_total_amount, tickets_amount, user) in [
+            (0, 0, 2, sender),
+            (2, 1, 1, other_user),
+            (3, 2, 3, sender),
+            (6, 3, 1, sender)
+        ] {
+            Lottery::add_ticket(
+                lottery_account_info,
+                lottery_account.initial_bytes,
+                tickets_total_amount,
+                ticket_ranges_total_amount,
+                tickets_amount,
+                user
+            );
+        };
+
+        // success - the last ticket-range is re-assigned, the previous one is split
+        {
+            let result = Lottery::transfer_tickets(
+                lottery_account_info,
+                lottery_account.initial_bytes,
+                4,
+                &sender,
+                &receiver,
+                3
+            ).unwrap();
+
+            assert_eq!(result, (1, true));
+
+            let updated_lottery_account = Lottery::deserialize(
+                &mut &lottery_account_info.data.try_borrow().unwrap()[..]
+            ).unwrap();
+
+            assert_eq!(updated_lottery_account.tickets_total_amount, 7);
+            assert_eq!(updated_lottery_account.ticket_ranges_total_amount, 5);
 
-        // success
+            let owners: Vec<Pubkey> = (0..7)
+                .map(|ticket_index| Lottery::get_ticket(lottery_account_info, ticket_index).unwrap())
+                .collect();
+            assert_eq!(
+                owners,
+                vec![sender, sender, other_user, sender, receiver, receiver, receiver]
+            );
+        }
+
+        // success - whole ticket-ranges only
         {
-            config_account.validate_price_feed_accounts(
-                // Sol price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[0],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Btc price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[1],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Eth price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[2],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                }
+            let result = Lottery::transfer_tickets(
+                lottery_account_info,
+                lottery_account.initial_bytes,
+                5,
+                &sender,
+                &receiver,
+                1
             ).unwrap();
+
+            assert_eq!(result, (1, false));
+            assert_eq!(Lottery::get_ticket(lottery_account_info, 3).unwrap(), receiver);
         }
 
-        // fail(all) - invalid_price_feed_accounts_owner
+        // fail - sender doesn't hold enough tickets
         {
-            let result = config_account.validate_price_feed_accounts(
-                // Sol price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[0],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &Pubkey::new_from_array([5; 32]),
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Btc price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[1],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &Pubkey::new_from_array([5; 32]),
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Eth price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[2],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &Pubkey::new_from_array([5; 32]),
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                }
+            let result = Lottery::transfer_tickets(
+                lottery_account_info,
+                lottery_account.initial_bytes,
+                5,
+                &sender,
+                &receiver,
+                3
             );
-            
-            if let Err(error) = result {
-                assert_eq!(
-                    error,
+
+            assert_eq!(
+                result,
+                Err(
                     ProgramError::Custom(
-                        LotteryError::InvalidPriceFeedAccountsOwner as u32
+                        LotteryError::InsufficientTicketsToTransfer as u32
                     )
-                );
-            } else {
-                panic!("We must have an invalid_owner error!");
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_lottery_state() {
+        // LotteryState::Unknown
+        {
+            let mut lottery_account = Lottery::default();
+            lottery_account.ending_time = 200;
+            lottery_account.starting_time = 100;
+            
+            let current_time = 50;
+            
+            if lottery_account.get_lottery_state(current_time) != LotteryState::Unknown {
+                panic!("Invalid lottery state (1)");
             };
         }
 
-        // fail(any) - invalid_price_feed_accounts_owner
+        // LotteryState::Unknown
         {
-            let result = config_account.validate_price_feed_accounts(
-                // Sol price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[0],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Btc price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[1],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &Pubkey::new_from_array([5; 32]),
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Eth price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[2],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                }
-            );
+            let mut lottery_account = Lottery::default();
+            lottery_account.ending_time = 200;
+            lottery_account.starting_time = 100;
             
-            if let Err(error) = result {
-                assert_eq!(
-                    error,
-                    ProgramError::Custom(
-                        LotteryError::InvalidPriceFeedAccountsOwner as u32
-                    )
-                );
-            } else {
-                panic!("We must have an invalid_owner error!");
+            let current_time = 150;
+            
+            if lottery_account.get_lottery_state(current_time) != LotteryState::Unknown {
+                panic!("Invalid lottery state (2)");
             };
         }
 
-        // fail - invalid_sol_price_feed_account
+        // LotteryState::Successful
         {
-            let result = config_account.validate_price_feed_accounts(
-                // Sol price feed account
-                &AccountInfo {
-                    key: &Pubkey::new_from_array([5; 32]),
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Btc price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[1],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Eth price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[2],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                }
-            );
+            let mut lottery_account = Lottery::default();
+            lottery_account.ending_time = 200;
+            lottery_account.starting_time = 100;
+            lottery_account.minimum_tickets_amount_required_to_be_sold = 100;
+            lottery_account.tickets_total_amount = 150;
 
-            if let Err(error) = result {
-                assert_eq!(
-                    error,
-                    ProgramError::Custom(
-                        LotteryError::InvalidSolPriceFeedAccount as u32
-                    )
-                );
-            } else {
-                panic!("We must have an invalid_sol_price_feed_account error!");
+            let current_time = 250;
+
+            if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
+                panic!("Invalid lottery state (3)");
             };
         }
 
-        // fail - invalid_btc_price_feed_account
+        // LotteryState::Failed
         {
-            let result = config_account.validate_price_feed_accounts(
-                // Sol price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[0],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Btc price feed account
-                &AccountInfo {
-                    key: &Pubkey::new_from_array([6; 32]),
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Eth price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[2],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                }
-            );
+            let mut lottery_account = Lottery::default();
+            lottery_account.ending_time = 200;
+            lottery_account.starting_time = 100;
+            lottery_account.minimum_tickets_amount_required_to_be_sold = 100;
+            lottery_account.tickets_total_amount = 90;
 
-            if let Err(error) = result {
-                assert_eq!(
-                    error,
-                    ProgramError::Custom(
-                        LotteryError::InvalidBtcPriceFeedAccount as u32
-                    )
-                );
-            } else {
-                panic!("We must have an invalid_btc_price_feed_account error!");
+            let current_time = 250;
+
+            if lottery_account.get_lottery_state(current_time) != LotteryState::Failed {
+                panic!("Invalid lottery state (4)");
             };
         }
 
-        // fail - invalid_eth_price_feed_account
+        // LotteryState::Successful (sold out before the ending time)
         {
-            let result = config_account.validate_price_feed_accounts(
-                // Sol price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[0],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Btc price feed account
-                &AccountInfo {
-                    key: &config_account.pyth_price_feed_accounts[1],
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                // Eth price feed account
-                &AccountInfo {
-                    key: &Pubkey::new_from_array([6; 32]),
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut [])),
-                    owner: &PYTH_PULL_ORACLE_RECEIVER_PROGRAM_ID,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                }
-            );
+            let mut lottery_account = Lottery::default();
+            lottery_account.ending_time = 200;
+            lottery_account.starting_time = 100;
+            lottery_account.minimum_tickets_amount_required_to_be_sold = 100;
+            lottery_account.maximum_total_tickets = Some(120);
+            lottery_account.tickets_total_amount = 120;
 
-            if let Err(error) = result {
-                assert_eq!(
-                    error,
-                    ProgramError::Custom(
-                        LotteryError::InvalidEthPriceFeedAccount as u32
-                    )
-                );
-            } else {
-                panic!("We must have an invalid_eth_price_feed_account error!");
+            let current_time = 150;
+
+            if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
+                panic!("Invalid lottery state (5)");
             };
         }
     }
 
     #[test]
-    fn test_validate_fee_per_ticket() {
-        // success
-        Config::validate_fee_per_ticket(&45.6).unwrap();
+    fn test_maximum_total_tickets() {
+        let mut lottery_account = Lottery::default();
+        lottery_account.starting_time = 100;
+        lottery_account.ending_time = 200;
+        lottery_account.tickets_total_amount = 90;
 
-        // fail
-        if let Ok(_) = Config::validate_fee_per_ticket(&101.0) {
-            panic!("This must fail!");
-        };
-    } 
-}
+        assert_eq!(
+            Lottery::validate_maximum_total_tickets(&Some(80), 100),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidMaximumTotalTickets as u32
+                )
+            )
+        );
+        assert_eq!(
+            Lottery::validate_maximum_total_tickets(&Some(100), 100),
+            Ok(())
+        );
 
-#[cfg(test)]
-mod test_lottery {
-    use borsh::{BorshDeserialize, BorshSerialize};
-    use solana_program::{
-        pubkey::Pubkey,
-        account_info::AccountInfo,
-        clock::Epoch,
-        program_error::ProgramError,
-        hash::hash
-    };
-    use super::{
-        LotteryError,
-        Lottery,
-        LotteryState
-    };
-    use std::{
-        rc::Rc,
-        cell::RefCell
-    };
+        // no cap
+        assert_eq!(
+            lottery_account.validate_total_tickets_amount(1_000),
+            Ok(())
+        );
+        assert_eq!(
+            lottery_account.is_sold_out(),
+            false
+        );
+
+        lottery_account.maximum_total_tickets = Some(100);
+        assert_eq!(
+            lottery_account.validate_total_tickets_amount(10),
+            Ok(())
+        );
+        assert_eq!(
+            lottery_account.validate_total_tickets_amount(11),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::MaximumTotalTicketsExceeded as u32
+                )
+            )
+        );
+
+        // outstanding ticket tokens are sold tickets
+        lottery_account.ticket_tokens_outstanding = 5;
+        assert_eq!(
+            lottery_account.validate_total_tickets_amount(6),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::MaximumTotalTicketsExceeded as u32
+                )
+            )
+        );
+        assert_eq!(
+            lottery_account.is_sold_out(),
+            false
+        );
+        lottery_account.ticket_tokens_outstanding = 0;
+        assert_eq!(
+            lottery_account.is_started_and_not_ended(150),
+            true
+        );
+
+        // sold out -> ended before the ending time
+        lottery_account.tickets_total_amount = 100;
+        assert_eq!(
+            lottery_account.is_started_and_not_ended(150),
+            false
+        );
+        assert_eq!(
+            lottery_account.is_ended(150),
+            true
+        );
+    }
 
     #[test]
-    fn test_validate_lottery_account() {
-        let program_id = Pubkey::new_from_array([1; 32]);
+    fn test_take_ticket_tokens() {
         let mut lottery_account = Lottery::default();
-        lottery_account.discriminator = Lottery::get_discriminator();
 
-        let mut data: [u8; 500] = [0; 500];
-        lottery_account.serialize(
-            &mut data.as_mut_slice()
-        ).unwrap();
+        // fail - ticket tokens not enabled
+        assert_eq!(
+            lottery_account.take_ticket_tokens(1),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::TicketTokensNotEnabled as u32
+                )
+            )
+        );
+
+        // 3 tokens bought at 10 USDC, then 2 tokens at 11 USDC
+        lottery_account.is_tickets_tokenized = true;
+        lottery_account.ticket_tokens_outstanding = 5;
+        lottery_account.ticket_tokens_value = 52_000000;
+
+        // fail - more than the outstanding ticket tokens
+        assert_eq!(
+            lottery_account.take_ticket_tokens(6),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidTicketAmount as u32
+                )
+            )
+        );
+
+        // success - prorated value, the last tokens take the remaining value
+        assert_eq!(lottery_account.take_ticket_tokens(2), Ok(20_800000));
+        assert_eq!(lottery_account.take_ticket_tokens(3), Ok(31_200000));
+        assert_eq!(lottery_account.ticket_tokens_outstanding, 0);
+        assert_eq!(lottery_account.ticket_tokens_value, 0);
+    }
+
+    #[test]
+    fn test_pick_winners() {
+        let hashes: [[u8; 32]; 10] = [
+            self::get_sha256_hash(110),
+            self::get_sha256_hash(111),
+            self::get_sha256_hash(112),
+            self::get_sha256_hash(113),
+            self::get_sha256_hash(114),
+            self::get_sha256_hash(115),
+            self::get_sha256_hash(116),
+            self::get_sha256_hash(117),
+            self::get_sha256_hash(118),
+            self::get_sha256_hash(119)
+        ];
+
+        for index in 0..10 {
+            let mut lottery_account = Lottery::default();
+            lottery_account.winners_count = 10;
+            lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+
+            let mut lottery_account_data: Vec<u8> = vec![
+                lottery_account.try_to_vec().unwrap(), vec![0; TICKET_RANGE_LENGTH * 3]
+            ].concat();
+            let mut lottery_balance = solana_program::native_token::sol_to_lamports(1.0);
+            let lottery_account_info = &AccountInfo {
+                key: &Pubkey::new_unique(),
+                lamports: Rc::new(RefCell::new(&mut lottery_balance)),
+                data: Rc::new(RefCell::new(&mut lottery_account_data)),
+                owner: &Pubkey::new_unique(),
+                rent_epoch: Epoch::default(),
+                is_signer: false,
+                is_writable: false,
+                executable: false
+            };
+
+            Lottery::add_ticket(
+                lottery_account_info,
+                lottery_account.initial_bytes, 
+                0, 
+                0, 
+                30, 
+                Pubkey::new_from_array([99; 32])
+            );
+            Lottery::add_ticket(
+                lottery_account_info,
+                lottery_account.initial_bytes, 
+                30, 
+                1, 
+                30, 
+                Pubkey::new_from_array([88; 32])
+            );
+            Lottery::add_ticket(
+                lottery_account_info,
+                lottery_account.initial_bytes, 
+                60, 
+                2, 
+                40, 
+                Pubkey::new_from_array([77; 32])
+            );
+
+            let mut updated_lottery_account = Lottery::deserialize(
+                &mut &lottery_account_info.data.try_borrow_mut().unwrap()[..]
+            ).unwrap();
+
+            updated_lottery_account.pick_winners(
+                &hashes[index],
+                lottery_account_info
+            ).unwrap();
+        };
+    }
+
+    fn get_sha256_hash(price: i64) -> [u8; 32] {
+        hash(price.to_le_bytes().as_slice()).to_bytes()
+    }
+
+    #[test]
+    fn test_random_number_generator() {
+        let seed = self::get_sha256_hash(110);
+
+        let mut generator_a = RandomNumberGenerator::new(&seed);
+        let mut generator_b = RandomNumberGenerator::new(&seed);
+        let mut generator_c = RandomNumberGenerator::new(&self::get_sha256_hash(111));
+
+        let draws_a: Vec<u64> = (0..100).map(|_| generator_a.next_u64()).collect();
+        let draws_b: Vec<u64> = (0..100).map(|_| generator_b.next_u64()).collect();
+        let draws_c: Vec<u64> = (0..100).map(|_| generator_c.next_u64()).collect();
+
+        // same seed -> same draws
+        assert_eq!(draws_a, draws_b);
+
+        // different seed -> different draws
+        assert_ne!(draws_a, draws_c);
+
+        // 4 draws per sha256 block
+        assert_eq!(generator_a.counter, 25);
+    }
+
+    #[test]
+    fn test_get_winners_index_reaches_every_ticket() {
+        let tickets_total_amount = 5_000u32;
+        let mut hits = vec![0u32; tickets_total_amount as usize];
+
+        let mut generator = RandomNumberGenerator::new(&self::get_sha256_hash(250));
+        for _ in 0..(tickets_total_amount * 40) {
+            let index = generator.next_index(tickets_total_amount);
+            assert!(index < tickets_total_amount);
+
+            hits[index as usize] += 1;
+        };
+
+        // every ticket (not only the first 256 ones) can win
+        for (index, hit) in hits.iter().enumerate() {
+            assert!(*hit > 0, "ticket {} is never drawn", index);
+        };
+    }
+
+    #[test]
+    fn test_get_winners_index_distribution() {
+        // chi-squared goodness-of-fit test against the uniform distribution
+        //  (tickets_total_amount, draws, critical value for p = 0.001)
+        let cases: [(u32, u32, f64); 3] = [
+            (7, 70_000, 22.458), // small, non power of two
+            (300, 150_000, 380.0),
+            (1_000, 200_000, 1_142.0)
+        ];
+
+        for (case_index, (tickets_total_amount, draws, critical_value)) in cases.iter().enumerate() {
+            let mut generator = RandomNumberGenerator::new(&self::get_sha256_hash(case_index as i64));
+            let mut hits = vec![0u32; *tickets_total_amount as usize];
+
+            for _ in 0..*draws {
+                hits[generator.next_index(*tickets_total_amount) as usize] += 1;
+            };
+
+            let expected = *draws as f64 / *tickets_total_amount as f64;
+            let chi_squared: f64 = hits
+                .iter()
+                .map(|hit| (*hit as f64 - expected).powi(2) / expected)
+                .sum();
+
+            assert!(
+                chi_squared < *critical_value,
+                "biased distribution for {} tickets -> chi_squared = {}", tickets_total_amount, chi_squared
+            );
+
+            // the upper half of the range must be as likely as the lower half
+            let lower_half: u32 = hits.get(..(*tickets_total_amount as usize / 2)).unwrap().iter().sum();
+            let ratio = lower_half as f64 / *draws as f64;
+            let expected_ratio = (*tickets_total_amount / 2) as f64 / *tickets_total_amount as f64;
+            assert!((ratio - expected_ratio).abs() < 0.01, "lower_half ratio = {}", ratio);
+        };
+    }
 
-        // success
+    #[test]
+    fn test_get_winners_index() {
+        // distinct indexes in range
         {
-            Lottery::validate_lottery_account(
-                &AccountInfo {
-                    key: &Pubkey::new_unique(),
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut data)),
-                    owner: &program_id,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                &program_id
-            ).unwrap();
+            for seed in 0..50 {
+                let winners_index = Lottery::get_winners_index(
+                    &self::get_sha256_hash(seed),
+                    3_000,
+                    30
+                ).unwrap();
+
+                assert_eq!(winners_index.len(), 30);
+
+                for (index, winner_index) in winners_index.iter().enumerate() {
+                    assert!(*winner_index < 3_000);
+                    assert_eq!(
+                        winners_index.iter().skip(index + 1).any(|w| w == winner_index),
+                        false
+                    );
+                };
+            };
         }
 
-        // fail - invalid program id
+        // every ticket wins when winners_count == tickets_total_amount
         {
-            let result = Lottery::validate_lottery_account(
-                &AccountInfo {
-                    key: &Pubkey::new_unique(),
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut data)),
-                    owner: &Pubkey::new_unique(),
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                &program_id
-            );
+            let mut winners_index = Lottery::get_winners_index(
+                &self::get_sha256_hash(7),
+                10,
+                10
+            ).unwrap();
+            winners_index.sort();
 
-            if let Err(error) = result {
-                assert_eq!(
-                    error,
-                    ProgramError::IncorrectProgramId
-                );
-            } else {
-                panic!("It must panic.");
-            };
+            assert_eq!(winners_index, (0..10).collect::<Vec<u32>>());
         }
 
-        // fail - invalid discriminator
+        // fail - not enough tickets
         {
-            let mut new_data = data.get_mut(8..).unwrap();
-
-            let result = Lottery::validate_lottery_account(
-                &AccountInfo {
-                    key: &Pubkey::new_unique(),
-                    lamports: Rc::new(RefCell::new(&mut u64::default())),
-                    data: Rc::new(RefCell::new(&mut new_data)),
-                    owner: &program_id,
-                    rent_epoch: Epoch::default(),
-                    is_signer: false,
-                    is_writable: false,
-                    executable: false
-                },
-                &program_id
+            let result = Lottery::get_winners_index(
+                &self::get_sha256_hash(7),
+                10,
+                11
             );
 
-            if let Err(error) = result {
-                assert_eq!(
-                    error,
+            assert_eq!(
+                result,
+                Err(
                     ProgramError::Custom(
-                        LotteryError::InvalidDiscriminator as u32
+                        LotteryError::InsufficientRandomNumbers as u32
                     )
-                );
-            } else {
-                panic!("It must panic.");
-            };
+                )
+            );
         }
     }
 
     #[test]
-    fn test_lottery_timestamps() {
+    fn test_validate_secret() {
+        let secret = [7u8; 32];
+        let lottery_account_key = Pubkey::new_from_array([1; 32]);
+
         let mut lottery_account = Lottery::default();
-        let mut current_time: i64;
 
-        // not_started state
-        {
-            // true
-            {
-                lottery_account.starting_time = 1000;
-                current_time = 100;
+        // fail - no commitment
+        assert_eq!(
+            lottery_account.validate_secret(&lottery_account_key, &secret),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::RandomnessCommitmentNotFound as u32
+                )
+            )
+        );
 
-                assert_eq!(
-                    lottery_account.is_not_started(current_time),
-                    true
-                );
-            }
+        lottery_account.randomness_commitment = Some(
+            hashv(&[ lottery_account_key.as_ref(), secret.as_slice() ]).to_bytes()
+        );
 
-            // false 
-            {
-                lottery_account.starting_time = 1000;
-                current_time = 1000;
+        // success
+        lottery_account.validate_secret(&lottery_account_key, &secret).unwrap();
 
-                assert_eq!(
-                    lottery_account.is_not_started(current_time),
-                    false
-                );
-            }
-        }
+        // fail - invalid secret
+        assert_eq!(
+            lottery_account.validate_secret(&lottery_account_key, &[8u8; 32]),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidSecret as u32
+                )
+            )
+        );
 
-        // started_and_not_ended state
-        {
-            // true
-            {
-                lottery_account.starting_time = 1000;
-                lottery_account.ending_time = 2000;
-                current_time = 1200;
+        // fail - commitment of another lottery
+        assert_eq!(
+            lottery_account.validate_secret(&Pubkey::new_from_array([2; 32]), &secret),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidSecret as u32
+                )
+            )
+        );
 
-                assert_eq!(
-                    lottery_account.is_started_and_not_ended(current_time),
-                    true
-                );
-            }
+        // fail - already revealed
+        lottery_account.is_secret_revealed = true;
+        assert_eq!(
+            lottery_account.validate_secret(&lottery_account_key, &secret),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::SecretAlreadyRevealed as u32
+                )
+            )
+        );
+    }
 
-            // false
-            {
-                lottery_account.starting_time = 1000;
-                lottery_account.ending_time = 2000;
-                current_time = 2200;
+    #[test]
+    fn test_is_secret_reveal_timed_out() {
+        let mut lottery_account = Lottery::default();
+        lottery_account.ending_time = 2000;
 
-                assert_eq!(
-                    lottery_account.is_started_and_not_ended(current_time),
-                    false
-                );
-            }
-        }
+        assert_eq!(
+            lottery_account.is_secret_reveal_timed_out(2000 + MAX_TIME_TO_REVEAL_SECRET - 1),
+            false
+        );
 
-        // ended state 
-        {
-            // true
-            {
-                lottery_account.ending_time = 2000;
-                current_time = 2000;
+        assert_eq!(
+            lottery_account.is_secret_reveal_timed_out(2000 + MAX_TIME_TO_REVEAL_SECRET),
+            true
+        );
+    }
 
-                assert_eq!(
-                    lottery_account.is_ended(current_time),
-                    true
-                );
-            }
+    #[test]
+    fn test_is_randomness_fulfilment_timed_out() {
+        let mut lottery_account = Lottery::default();
+        lottery_account.ending_time = 2000;
 
-            // false
-            {
-                lottery_account.ending_time = 2000;
-                current_time = 1800;
+        assert_eq!(
+            lottery_account.is_randomness_fulfilment_timed_out(2000 + MAX_TIME_TO_REVEAL_SECRET + MAX_TIME_TO_FULFIL_RANDOMNESS - 1),
+            false
+        );
+        assert_eq!(
+            lottery_account.is_randomness_fulfilment_timed_out(2000 + MAX_TIME_TO_REVEAL_SECRET + MAX_TIME_TO_FULFIL_RANDOMNESS),
+            true
+        );
+    }
 
-                assert_eq!(
-                    lottery_account.is_ended(current_time),
-                    false
-                );
-            }
-        }
+    #[test]
+    fn test_is_ticket_tokens_refund_timed_out() {
+        let mut lottery_account = Lottery::default();
+        lottery_account.ending_time = 2000;
+
+        assert_eq!(
+            lottery_account.is_ticket_tokens_refund_timed_out(2000 + MAX_TIME_TO_REFUND_TICKET_TOKENS - 1),
+            false
+        );
+        assert_eq!(
+            lottery_account.is_ticket_tokens_refund_timed_out(2000 + MAX_TIME_TO_REFUND_TICKET_TOKENS),
+            true
+        );
+    }
+
+    #[test]
+    fn test_validate_randomness_commitment() {
+        // success
+        Lottery::validate_randomness_commitment(RandomnessSource::PythPriceFeeds, &None).unwrap();
+        Lottery::validate_randomness_commitment(RandomnessSource::VrfOracle, &Some([1u8; 32])).unwrap();
+
+        // failure - vrf oracle without the creator's commitment
+        assert_eq!(
+            Lottery::validate_randomness_commitment(RandomnessSource::VrfOracle, &None),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::RandomnessCommitmentNotFound as u32
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_validate_prize_table() {
+        // success
+        Lottery::validate_prize_table(&None, 3).unwrap();
+        Lottery::validate_prize_table(&Some(vec![ 5_000, 3_000, 2_000 ]), 3).unwrap();
+
+        // failure - a share per winner
+        assert_eq!(
+            Lottery::validate_prize_table(&Some(vec![ 5_000, 5_000 ]), 3),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidPrizeTable as u32
+                )
+            )
+        );
+
+        // failure - shares must add up to 10_000 bps
+        assert_eq!(
+            Lottery::validate_prize_table(&Some(vec![ 5_000, 3_000, 1_000 ]), 3),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidPrizeTable as u32
+                )
+            )
+        );
+
+        // failure - empty share
+        assert_eq!(
+            Lottery::validate_prize_table(&Some(vec![ 7_000, 3_000, 0 ]), 3),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidPrizeTable as u32
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_get_prize_amount() {
+        let mut lottery_account = Lottery::default();
+        lottery_account.fund_amount = 1000;
+        lottery_account.winners_count = 4;
+
+        // equal split
+        for rank in 0..4 {
+            assert_eq!(lottery_account.get_prize_amount(rank).unwrap(), 250);
+        };
+
+        // equal split of a prize mint, the rounding dust goes to the 1st rank
+        assert_eq!(lottery_account.get_prize_share(1003, 0).unwrap(), 250 + 3);
+        assert_eq!(lottery_account.get_prize_share(1003, 3).unwrap(), 250);
+
+        // 1st 50% - 2nd 30% - 3rd 20%
+        lottery_account.winners_count = 3;
+        lottery_account.prize_table = Some(vec![ 5_000, 3_000, 2_000 ]);
+        assert_eq!(lottery_account.get_prize_amount(0).unwrap(), 500);
+        assert_eq!(lottery_account.get_prize_amount(1).unwrap(), 300);
+        assert_eq!(lottery_account.get_prize_amount(2).unwrap(), 200);
+
+        // the rounding dust goes to the 1st rank
+        lottery_account.fund_amount = 1001;
+        lottery_account.prize_table = Some(vec![ 3_333, 3_333, 3_334 ]);
+        assert_eq!(lottery_account.get_prize_amount(0).unwrap(), 333 + 2);
+        assert_eq!(lottery_account.get_prize_amount(1).unwrap(), 333);
+        assert_eq!(lottery_account.get_prize_amount(2).unwrap(), 333);
+        assert_eq!(
+            (0..3).map(|rank| lottery_account.get_prize_amount(rank).unwrap()).sum::<u64>(),
+            1001
+        );
+
+        // failure - unknown rank
+        assert_eq!(
+            lottery_account.get_prize_amount(3),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::WinnerNotFound as u32
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_validate_prize_mint() {
+        let usdc_mint = Pubkey::new_from_array([1; 32]);
+        let mut lottery_account = Lottery::default();
+        lottery_account.arbitrary_mint_account_address = Pubkey::new_from_array([2; 32]);
+
+        // success
+        lottery_account.validate_prize_mint(&Pubkey::new_from_array([3; 32]), &usdc_mint).unwrap();
+
+        // failure - usdc, arbitrary & already added mints
+        lottery_account.prize_mints.push((Pubkey::new_from_array([3; 32]), 100));
+        for prize_mint in [ [1; 32], [2; 32], [3; 32] ] {
+            assert_eq!(
+                lottery_account.validate_prize_mint(&Pubkey::new_from_array(prize_mint), &usdc_mint),
+                Err(
+                    ProgramError::Custom(
+                        LotteryError::InvalidPrizeMintAccount as u32
+                    )
+                )
+            );
+        };
+
+        // failure - nft prize mint
+        lottery_account.nft_prizes = Some(vec![ Pubkey::new_from_array([4; 32]) ]);
+        assert_eq!(
+            lottery_account.validate_prize_mint(&Pubkey::new_from_array([4; 32]), &usdc_mint),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidPrizeMintAccount as u32
+                )
+            )
+        );
+
+        // failure - max prize mints
+        lottery_account.prize_mints = (0..MAX_PRIZE_MINTS_PER_LOTTERY as u8)
+            .map(|index| (Pubkey::new_from_array([index + 10; 32]), 100))
+            .collect();
+        assert_eq!(
+            lottery_account.validate_prize_mint(&Pubkey::new_from_array([3; 32]), &usdc_mint),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::MaxPrizeMintsExceeded as u32
+                )
+            )
+        );
     }
 
     #[test]
-    fn test_add_tickets_and_get_ticket() {
+    fn test_pool_amounts() {
         let mut lottery_account = Lottery::default();
-        lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+        lottery_account.lottery_creation_fee = 5_000000;
+        lottery_account.protocol_fee = 3_000000;
 
-        let mut lottery_account_data: Vec<u8> = vec![
-            lottery_account.try_to_vec().unwrap(), vec![0; size_of::<Pubkey>() * 5]
-        ].concat();
-        let mut lottery_balance = solana_program::native_token::sol_to_lamports(1.0);
-        let lottery_account_info = &AccountInfo {
-            key: &Pubkey::new_unique(),
-            lamports: Rc::new(RefCell::new(&mut lottery_balance)),
-            data: Rc::new(RefCell::new(&mut lottery_account_data)),
-            owner: &Pubkey::new_unique(),
-            rent_epoch: Epoch::default(),
-            is_signer: false,
-            is_writable: false,
-            executable: false
-        };
+        // not a pool lottery
+        assert_eq!(
+            lottery_account.get_pool_creator_amount(100_000000),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidLotteryType as u32
+                )
+            )
+        );
 
+        // failure - share above 100%
         assert_eq!(
-            lottery_account.tickets_total_amount,
-            0
+            Lottery::validate_pool_creator_share(&Some(MAX_BASIS_POINTS + 1)),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidPoolCreatorShare as u32
+                )
+            )
         );
+        Lottery::validate_pool_creator_share(&Some(MAX_BASIS_POINTS)).unwrap();
+        Lottery::validate_pool_creator_share(&None).unwrap();
+
+        // 10% creator's share, the pool is taken once
+        lottery_account.pool_creator_share = Some(1_000);
+        assert_eq!(lottery_account.get_pool_amount(108_000005).unwrap(), 100_000005);
+        assert_eq!(lottery_account.get_pool_amount(50_000000).unwrap(), 100_000005);
+        assert_eq!(lottery_account.get_pool_creator_amount(100_000005).unwrap(), 10_000000);
+        assert_eq!(lottery_account.get_pool_prize_amount(100_000005).unwrap(), 90_000005);
+
+        // protocol fees already claimed
+        lottery_account.pool_amount = 0;
+        lottery_account.is_protocol_fee_claimed = true;
+        assert_eq!(lottery_account.get_pool_amount(100_000000).unwrap(), 100_000000);
+    }
+
+    #[test]
+    fn test_series_jackpot() {
+        let user_account = Pubkey::new_unique();
+        let mut lottery_account = Lottery::default();
+        lottery_account.winners_count = 3;
+        lottery_account.tickets_total_amount = 10;
+        lottery_account.winners = vec![
+            (user_account, 0, false),
+            (Pubkey::new_unique(), 1, false),
+            (user_account, 2, false)
+        ];
 
+        // not a series round
+        lottery_account.draw_series_jackpot(&[7; 32]).unwrap();
         assert_eq!(
-            lottery_account_info.data_len(),
-            lottery_account.initial_bytes as usize + size_of::<Pubkey>() * 5
+            lottery_account.claim_series_jackpot_ranks(&user_account),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidLotteryType as u32
+                )
+            )
         );
 
-        Lottery::add_ticket(
-            lottery_account_info,
-            lottery_account.initial_bytes,
-            0,
-            1,
-            Pubkey::new_from_array([5; 32])
+        // failure - jackpot is not drawn yet
+        lottery_account.series_jackpot = Some(
+            SeriesJackpot {
+                jackpot_share: 1_000,
+                ..SeriesJackpot::default()
+            }
+        );
+        assert_eq!(
+            lottery_account.claim_series_jackpot_ranks(&user_account),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::JackpotNotWon as u32
+                )
+            )
         );
 
-        Lottery::add_ticket(
-            lottery_account_info,
-            lottery_account.initial_bytes,
-            1,
-            1,
-            Pubkey::new_from_array([23; 32])
+        // without house slots every draw lands on a ticket
+        lottery_account.draw_series_jackpot(&[7; 32]).unwrap();
+        let series_jackpot = lottery_account.series_jackpot.unwrap();
+        assert_eq!(series_jackpot.is_drawn, true);
+        assert_eq!(series_jackpot.is_won, true);
+
+        // failure - not a winner
+        assert_eq!(
+            lottery_account.claim_series_jackpot_ranks(&Pubkey::new_unique()),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::WinnerNotFound as u32
+                )
+            )
         );
 
-        Lottery::add_ticket(
-            lottery_account_info,
-            lottery_account.initial_bytes,
-            2,
-            2,
-            Pubkey::new_from_array([55; 32])
+        assert_eq!(
+            lottery_account.claim_series_jackpot_ranks(&user_account).unwrap(),
+            vec![0, 2]
+        );
+        assert_eq!(lottery_account.series_jackpot.unwrap().claimed_ranks[0], 0b101);
+        assert_eq!(
+            lottery_account.claim_series_jackpot_ranks(&user_account),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::JackpotAlreadyClaimed as u32
+                )
+            )
         );
 
-        Lottery::add_ticket(
-            lottery_account_info,
-            lottery_account.initial_bytes,
-            4,
-            1,
-            Pubkey::new_from_array([9; 32])
+        // ranks above 31 have their own bits
+        let mut many_winners_lottery_account = Lottery::default();
+        let (first_winner, high_rank_winner, last_rank_winner) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        many_winners_lottery_account.winners = vec![
+            (first_winner, 0, false),
+            (high_rank_winner, 32, false),
+            (last_rank_winner, 255, false)
+        ];
+        many_winners_lottery_account.series_jackpot = Some(
+            SeriesJackpot {
+                is_drawn: true,
+                is_won: true,
+                ..SeriesJackpot::default()
+            }
+        );
+        assert_eq!(
+            many_winners_lottery_account.claim_series_jackpot_ranks(&high_rank_winner).unwrap(),
+            vec![32]
+        );
+        assert_eq!(
+            many_winners_lottery_account.claim_series_jackpot_ranks(&last_rank_winner).unwrap(),
+            vec![255]
+        );
+        let series_jackpot = many_winners_lottery_account.series_jackpot.unwrap();
+        assert_eq!(series_jackpot.is_rank_claimed(0), false);
+        assert_eq!(series_jackpot.is_rank_claimed(32), true);
+        assert_eq!(series_jackpot.is_rank_claimed(255), true);
+        assert_eq!(
+            many_winners_lottery_account.claim_series_jackpot_ranks(&first_winner).unwrap(),
+            vec![0]
         );
 
-        let updated_lottery_account = Lottery::deserialize(
-            &mut &lottery_account_info.data.try_borrow().unwrap()[..]
-        ).unwrap();
+        // the jackpot reserved by other rounds isn't part of the round's jackpot, it is reserved once
+        let mut lottery_series_account = LotterySeries::default();
+        lottery_series_account.reserved_jackpot_amount = 100_000000;
+        lottery_account.reserve_series_jackpot_amount(&mut lottery_series_account, 400_000000).unwrap();
+        assert_eq!(lottery_account.series_jackpot.unwrap().amount, 300_000000);
+        assert_eq!(lottery_series_account.reserved_jackpot_amount, 400_000000);
+
+        lottery_account.reserve_series_jackpot_amount(&mut lottery_series_account, 500_000000).unwrap();
+        assert_eq!(lottery_account.series_jackpot.unwrap().amount, 300_000000);
+        assert_eq!(lottery_series_account.reserved_jackpot_amount, 400_000000);
+
+        // a jackpot rolled over (house slot) reserves nothing
+        let mut rolled_over_lottery_account = Lottery::default();
+        rolled_over_lottery_account.series_jackpot = Some(
+            SeriesJackpot {
+                is_drawn: true,
+                ..SeriesJackpot::default()
+            }
+        );
+        rolled_over_lottery_account.reserve_series_jackpot_amount(&mut lottery_series_account, 500_000000).unwrap();
+        assert_eq!(rolled_over_lottery_account.series_jackpot.unwrap().amount, 0);
+        assert_eq!(lottery_series_account.reserved_jackpot_amount, 400_000000);
 
+        // contributions
+        assert_eq!(LotterySeries::get_jackpot_contribution(1_000, 900_000000), 90_000000);
         assert_eq!(
-            updated_lottery_account.tickets_total_amount,
-            5
+            LotterySeries::validate_jackpot_share(MAX_BASIS_POINTS + 1),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidJackpotShare as u32
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_series_jackpot_of_rounds_claimed_in_reverse_order() {
+        let won_series_jackpot = Some(
+            SeriesJackpot {
+                is_drawn: true,
+                is_won: true,
+                ..SeriesJackpot::default()
+            }
         );
+        let mut lottery_series_account = LotterySeries::default();
+        let mut first_round_lottery_account = Lottery::default();
+        first_round_lottery_account.series_jackpot = won_series_jackpot;
+        let mut second_round_lottery_account = Lottery::default();
+        second_round_lottery_account.series_jackpot = won_series_jackpot;
+
+        // 1st round is drawn with 100 USDC in the vault
+        let mut jackpot_vault_balance = 100_000000;
+        first_round_lottery_account.reserve_series_jackpot_amount(&mut lottery_series_account, jackpot_vault_balance).unwrap();
+
+        // 1st round's creator contributes 50 USDC, then the 2nd round is drawn
+        jackpot_vault_balance += 50_000000;
+        second_round_lottery_account.reserve_series_jackpot_amount(&mut lottery_series_account, jackpot_vault_balance).unwrap();
+        assert_eq!(first_round_lottery_account.series_jackpot.unwrap().amount, 100_000000);
+        assert_eq!(second_round_lottery_account.series_jackpot.unwrap().amount, 50_000000);
+        assert_eq!(lottery_series_account.reserved_jackpot_amount, 150_000000);
+
+        // 2nd round claims first, the 1st round's jackpot stays fully covered by the vault
+        let second_round_jackpot_amount = second_round_lottery_account.series_jackpot.unwrap().amount;
+        jackpot_vault_balance -= second_round_jackpot_amount;
+        lottery_series_account.reserved_jackpot_amount -= second_round_jackpot_amount;
+        assert_eq!(first_round_lottery_account.series_jackpot.unwrap().amount, jackpot_vault_balance);
+        assert_eq!(lottery_series_account.reserved_jackpot_amount, jackpot_vault_balance);
+
+        // an already reserved round isn't reserved again
+        first_round_lottery_account.reserve_series_jackpot_amount(&mut lottery_series_account, jackpot_vault_balance).unwrap();
+        assert_eq!(first_round_lottery_account.series_jackpot.unwrap().amount, 100_000000);
+        assert_eq!(lottery_series_account.reserved_jackpot_amount, 100_000000);
+    }
 
-        // println!("\n>>>>> {:?}", lottery_account_info.data.try_borrow().unwrap().get(207..).unwrap().get(..32));
-        // println!(">>>>> {:?}", lottery_account_info.data.try_borrow().unwrap().get(207..).unwrap().get(32..64));
-        // println!(">>>>> {:?}", lottery_account_info.data.try_borrow().unwrap().get(207..).unwrap().get(64..96));
+    #[test]
+    fn test_round_template() {
+        let config_account = Config {
+            maximum_number_of_winners: 5,
+            minimum_tickets_to_be_sold_in_lottery: 10,
+            maximum_time_for_lottery_account: 86_400,
+            ..Config::default()
+        };
+        let round_template = RoundTemplate {
+            prize_mint: Pubkey::new_unique(),
+            fund_amount: 300_000000,
+            ticket_price: 1_000000,
+            winners_count: 3,
+            round_duration: 3_600,
+            minimum_tickets_amount_required_to_be_sold: 10
+        };
+        assert_eq!(round_template.validate(&config_account), Ok(()));
+
+        for (invalid_round_template, error) in [
+            (RoundTemplate { winners_count: 0, ..round_template }, LotteryError::InvalidWinnersAmount),
+            (RoundTemplate { winners_count: 6, ..round_template }, LotteryError::InvalidWinnersAmount),
+            (RoundTemplate { minimum_tickets_amount_required_to_be_sold: 9, ..round_template }, LotteryError::InvalidMinimumAmountTickets),
+            (RoundTemplate { round_duration: 0, ..round_template }, LotteryError::InvalidTime),
+            (RoundTemplate { round_duration: 86_401, ..round_template }, LotteryError::MaximumTimeExceed),
+            (RoundTemplate { fund_amount: 0, ..round_template }, LotteryError::InvalidFundAmount),
+            (RoundTemplate { fund_amount: 100_000001, ..round_template }, LotteryError::WinnersAndFundAmountMismatch)
+        ] {
+            assert_eq!(
+                invalid_round_template.validate(&config_account),
+                Err(
+                    ProgramError::Custom(
+                        error as u32
+                    )
+                )
+            );
+        };
 
-        // println!("\n>>> {:?}", lottery_account_info.data.try_borrow().unwrap());
+        // a series with a template & a last round fills its whole account
+        let lottery_series_account = LotterySeries {
+            round_template: Some(round_template),
+            last_round: Some(Pubkey::new_unique()),
+            ..LotterySeries::default()
+        };
+        assert_eq!(lottery_series_account.try_to_vec().unwrap().len(), LotterySeries::LEN);
 
+        // rounds are seeded by the series & the round number
+        let lottery_series = Pubkey::new_unique();
         assert_eq!(
-            Lottery::get_ticket(lottery_account_info, 0).unwrap(),
-            Pubkey::new_from_array([5; 32])
+            LotterySeries::get_round_description(&lottery_series, 2),
+            format!("{}#2", lottery_series)
         );
+        assert_ne!(
+            LotterySeries::get_round_description(&lottery_series, 2),
+            LotterySeries::get_round_description(&lottery_series, 3)
+        );
+    }
+
+    #[test]
+    fn test_validate_nft_prizes() {
+        let usdc_mint = Pubkey::new_from_array([1; 32]);
+        let arbitrary_mint = Pubkey::new_from_array([2; 32]);
+
+        // success
+        Lottery::validate_nft_prizes(&None, 3, &usdc_mint, &arbitrary_mint).unwrap();
+        Lottery::validate_nft_prizes(
+            &Some(vec![ Pubkey::new_from_array([3; 32]), Pubkey::new_from_array([4; 32]) ]),
+            2,
+            &usdc_mint,
+            &arbitrary_mint
+        ).unwrap();
+
+        // failure - one nft per winner, no usdc, arbitrary or repeated mint
+        for nft_prizes in [
+            vec![ [3; 32] ],
+            vec![ [3; 32], [1; 32] ],
+            vec![ [2; 32], [3; 32] ],
+            vec![ [3; 32], [3; 32] ]
+        ] {
+            assert_eq!(
+                Lottery::validate_nft_prizes(
+                    &Some(nft_prizes.into_iter().map(Pubkey::new_from_array).collect()),
+                    2,
+                    &usdc_mint,
+                    &arbitrary_mint
+                ),
+                Err(
+                    ProgramError::Custom(
+                        LotteryError::InvalidNftPrizes as u32
+                    )
+                )
+            );
+        };
 
+        // nft prize mint -> 0 decimals & supply of 1
+        Lottery::validate_nft_prize_mint(0, 1).unwrap();
+        for (decimals, supply) in [ (6, 1), (0, 2), (0, 0) ] {
+            assert_eq!(
+                Lottery::validate_nft_prize_mint(decimals, supply),
+                Err(
+                    ProgramError::Custom(
+                        LotteryError::InvalidNftPrizeMintAccount as u32
+                    )
+                )
+            );
+        };
+    }
+
+    #[test]
+    fn test_get_lottery_state_when_randomness_fulfilment_is_missed() {
+        let mut lottery_account = Lottery::default();
+        lottery_account.starting_time = 1000;
+        lottery_account.ending_time = 2000;
+        lottery_account.tickets_total_amount = 10;
+        lottery_account.minimum_tickets_amount_required_to_be_sold = 5;
+        lottery_account.randomness_source = RandomnessSource::VrfOracle;
+
+        let deadline = 2000 + MAX_TIME_TO_REVEAL_SECRET + MAX_TIME_TO_FULFIL_RANDOMNESS;
+
+        // success - the oracle can still fulfil the randomness
         assert_eq!(
-            Lottery::get_ticket(lottery_account_info, 1).unwrap(),
-            Pubkey::new_from_array([23; 32])
+            lottery_account.get_lottery_state(deadline - 1),
+            LotteryState::Successful
         );
 
+        // success - the oracle withheld the randomness -> failed
         assert_eq!(
-            Lottery::get_ticket(lottery_account_info, 2).unwrap(),
-            Pubkey::new_from_array([55; 32])
+            lottery_account.get_lottery_state(deadline),
+            LotteryState::Failed
         );
 
+        // success - fulfilled in time
+        lottery_account.is_randomness_fulfilled = true;
         assert_eq!(
-            Lottery::get_ticket(lottery_account_info, 3).unwrap(),
-            Pubkey::new_from_array([55; 32])
+            lottery_account.get_lottery_state(deadline),
+            LotteryState::Successful
         );
 
+        // success - price feeds don't depend on the oracle
+        lottery_account.is_randomness_fulfilled = false;
+        lottery_account.randomness_source = RandomnessSource::PythPriceFeeds;
         assert_eq!(
-            Lottery::get_ticket(lottery_account_info, 4).unwrap(),
-            Pubkey::new_from_array([9; 32])
+            lottery_account.get_lottery_state(deadline),
+            LotteryState::Successful
         );
     }
 
     #[test]
-    fn test_get_lottery_state() {
-        // LotteryState::Unknown
-        {
-            let mut lottery_account = Lottery::default();
-            lottery_account.ending_time = 200;
-            lottery_account.starting_time = 100;
-            
-            let current_time = 50;
-            
-            if lottery_account.get_lottery_state(current_time) != LotteryState::Unknown {
-                panic!("Invalid lottery state (1)");
-            };
-        }
-
-        // LotteryState::Unknown
-        {
-            let mut lottery_account = Lottery::default();
-            lottery_account.ending_time = 200;
-            lottery_account.starting_time = 100;
-            
-            let current_time = 150;
-            
-            if lottery_account.get_lottery_state(current_time) != LotteryState::Unknown {
-                panic!("Invalid lottery state (2)");
-            };
-        }
-
-        // LotteryState::Successful
-        {
-            let mut lottery_account = Lottery::default();
-            lottery_account.ending_time = 200;
-            lottery_account.starting_time = 100;
-            lottery_account.minimum_tickets_amount_required_to_be_sold = 100;
-            lottery_account.tickets_total_amount = 150;
-
-            let current_time = 250;
+    fn test_check_secret_is_revealed() {
+        let mut lottery_account = Lottery::default();
+        lottery_account.ending_time = 2000;
 
-            if lottery_account.get_lottery_state(current_time) != LotteryState::Successful {
-                panic!("Invalid lottery state (3)");
-            };
-        }
+        // success - no commitment
+        lottery_account.check_secret_is_revealed(2000).unwrap();
 
-        // LotteryState::Failed
-        {
-            let mut lottery_account = Lottery::default();
-            lottery_account.ending_time = 200;
-            lottery_account.starting_time = 100;
-            lottery_account.minimum_tickets_amount_required_to_be_sold = 100;
-            lottery_account.tickets_total_amount = 90;
+        // fail - secret is not revealed yet
+        lottery_account.randomness_commitment = Some(hash(&[7u8; 32]).to_bytes());
+        assert_eq!(
+            lottery_account.check_secret_is_revealed(2000),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::SecretNotRevealed as u32
+                )
+            )
+        );
 
-            let current_time = 250;
+        // success - reveal is timed out
+        lottery_account.check_secret_is_revealed(2000 + MAX_TIME_TO_REVEAL_SECRET).unwrap();
 
-            if lottery_account.get_lottery_state(current_time) != LotteryState::Failed {
-                panic!("Invalid lottery state (4)");
-            };
-        }
+        // success - secret is revealed
+        lottery_account.is_secret_revealed = true;
+        lottery_account.check_secret_is_revealed(2000).unwrap();
     }
 
     #[test]
-    fn test_pick_winners() {
-        let hashes: [[u8; 32]; 10] = [
-            self::get_sha256_hash(110),
-            self::get_sha256_hash(111),
-            self::get_sha256_hash(112),
-            self::get_sha256_hash(113),
-            self::get_sha256_hash(114),
-            self::get_sha256_hash(115),
-            self::get_sha256_hash(116),
-            self::get_sha256_hash(117),
-            self::get_sha256_hash(118),
-            self::get_sha256_hash(119)
-        ];
-
-        for index in 0..10 {
-            let mut lottery_account = Lottery::default();
-            lottery_account.winners_count = 10;
-            lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+    fn test_check_randomness_source() {
+        let mut lottery_account = Lottery::default();
+        assert_eq!(lottery_account.randomness_source, RandomnessSource::PythPriceFeeds);
 
-            let mut lottery_account_data: Vec<u8> = vec![
-                lottery_account.try_to_vec().unwrap(), vec![0; size_of::<Pubkey>() * 100]
-            ].concat();
-            let mut lottery_balance = solana_program::native_token::sol_to_lamports(1.0);
-            let lottery_account_info = &AccountInfo {
-                key: &Pubkey::new_unique(),
-                lamports: Rc::new(RefCell::new(&mut lottery_balance)),
-                data: Rc::new(RefCell::new(&mut lottery_account_data)),
-                owner: &Pubkey::new_unique(),
-                rent_epoch: Epoch::default(),
-                is_signer: false,
-                is_writable: false,
-                executable: false
-            };
+        lottery_account.check_randomness_source(RandomnessSource::PythPriceFeeds).unwrap();
 
-            Lottery::add_ticket(
-                lottery_account_info,
-                lottery_account.initial_bytes, 
-                0, 
-                30, 
-                Pubkey::new_from_array([99; 32])
-            );
-            Lottery::add_ticket(
-                lottery_account_info,
-                lottery_account.initial_bytes, 
-                30, 
-                30, 
-                Pubkey::new_from_array([88; 32])
-            );
-            Lottery::add_ticket(
-                lottery_account_info,
-                lottery_account.initial_bytes, 
-                60, 
-                40, 
-                Pubkey::new_from_array([77; 32])
-            );
+        lottery_account.randomness_source = RandomnessSource::VrfOracle;
+        assert_eq!(
+            lottery_account.check_randomness_source(RandomnessSource::PythPriceFeeds),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidRandomnessSource as u32
+                )
+            )
+        );
+    }
 
-            let mut updated_lottery_account = Lottery::deserialize(
-                &mut &lottery_account_info.data.try_borrow_mut().unwrap()[..]
-            ).unwrap();
+    #[test]
+    fn test_get_winners_seed() {
+        let mut lottery_account = Lottery::default();
+        let price = 250i64;
 
-            updated_lottery_account.pick_winners(
-                &hashes[index],
-                lottery_account_info
-            ).unwrap();
-        };
+        // oracle-only randomness
+        assert_eq!(
+            lottery_account.get_winners_seed(price.to_le_bytes().as_slice()),
+            self::get_sha256_hash(price)
+        );
+
+        // oracle's randomness mixed with the creator's secret
+        lottery_account.revealed_secret = [7u8; 32];
+        lottery_account.is_secret_revealed = true;
+
+        let seed = lottery_account.get_winners_seed(price.to_le_bytes().as_slice());
+        assert_ne!(seed, self::get_sha256_hash(price));
+        assert_eq!(
+            seed,
+            hashv(&[ price.to_le_bytes().as_slice(), [7u8; 32].as_slice() ]).to_bytes()
+        );
     }
 
-    fn get_sha256_hash(price: i64) -> [u8; 32] {
-        hash(price.to_le_bytes().as_slice()).to_bytes()
+    #[test]
+    fn test_get_price_feeds_randomness() {
+        let lottery_account = Pubkey::new_from_array([9; 32]);
+        let random_numbers_info = RandomNumbersInfo {
+            price_feeds: [
+                (Pubkey::new_from_array([1; 32]), 300, 250, 5),
+                (Pubkey::new_from_array([2; 32]), 301, 150, 6),
+                (Pubkey::new_from_array([3; 32]), 302, -50, 7)
+            ],
+            slot_hash: (42, [8; 32])
+        };
+
+        let randomness = random_numbers_info.get_price_feeds_randomness(&lottery_account);
+        assert_eq!(
+            randomness,
+            [
+                250i64.to_le_bytes().as_slice(), 5u64.to_le_bytes().as_slice(), 300i64.to_le_bytes().as_slice(),
+                150i64.to_le_bytes().as_slice(), 6u64.to_le_bytes().as_slice(), 301i64.to_le_bytes().as_slice(),
+                (-50i64).to_le_bytes().as_slice(), 7u64.to_le_bytes().as_slice(), 302i64.to_le_bytes().as_slice(),
+                42u64.to_le_bytes().as_slice(),
+                [8; 32].as_slice(),
+                lottery_account.as_ref()
+            ].concat()
+        );
+
+        // every input changes the randomness
+        let mut other_random_numbers_info = random_numbers_info;
+        other_random_numbers_info.price_feeds[2].3 = 8;
+        assert_ne!(other_random_numbers_info.get_price_feeds_randomness(&lottery_account), randomness);
+
+        let mut other_random_numbers_info = random_numbers_info;
+        other_random_numbers_info.slot_hash.1 = [0; 32];
+        assert_ne!(other_random_numbers_info.get_price_feeds_randomness(&lottery_account), randomness);
+
+        assert_ne!(
+            random_numbers_info.get_price_feeds_randomness(&Pubkey::new_from_array([10; 32])),
+            randomness
+        );
     }
 
     #[test]
     fn test_get_winner_info() {
         let mut lottery_account = Lottery::default();
         lottery_account.winners = vec![
-            (Pubkey::new_from_array([1; 32]), false),
-            (Pubkey::new_from_array([1; 32]), false),
-            (Pubkey::new_from_array([1; 32]), false)
+            (Pubkey::new_from_array([1; 32]), 0, false),
+            (Pubkey::new_from_array([2; 32]), 1, false),
+            (Pubkey::new_from_array([1; 32]), 2, false),
+            (Pubkey::new_from_array([1; 32]), 3, false)
         ];
 
         // get winner's info for the first time
         {
-            let winning_count = lottery_account
+            let winning_ranks = lottery_account
                 .get_winner_info(&Pubkey::new_from_array([1; 32]))
                 .unwrap();
 
-            assert_eq!(winning_count, 3);
-            assert_eq!(lottery_account.winners[0].1, true);
-            assert_eq!(lottery_account.winners[1].1, true);
-            assert_eq!(lottery_account.winners[2].1, true);
+            assert_eq!(winning_ranks, vec![ 0, 2, 3 ]);
+            assert_eq!(lottery_account.winners[0].2, true);
+            assert_eq!(lottery_account.winners[1].2, false);
+            assert_eq!(lottery_account.winners[2].2, true);
+            assert_eq!(lottery_account.winners[3].2, true);
         }
 
         // try to get winner's info for the second time
@@ -1913,6 +4556,36 @@ mod test_lottery {
             );
         }
     }
+
+    #[test]
+    fn test_unclaimed_prizes() {
+        let mut lottery_account = Lottery {
+            ending_time: 200,
+            ..Lottery::default()
+        };
+        lottery_account.winners = vec![
+            (Pubkey::new_from_array([1; 32]), 0, true),
+            (Pubkey::new_from_array([2; 32]), 1, false),
+            (Pubkey::new_from_array([1; 32]), 2, false)
+        ];
+
+        // claim window
+        for (prize_claim_window, current_time, expected_result) in [
+            (0, 10_000, Err(ProgramError::Custom(LotteryError::PrizesNeverExpire as u32))),
+            (100, 299, Err(ProgramError::Custom(LotteryError::ClaimWindowNotClosed as u32))),
+            (100, 300, Ok(()))
+        ] {
+            assert_eq!(
+                lottery_account.check_claim_window(prize_claim_window, current_time),
+                expected_result
+            );
+        };
+
+        // unclaimed ranks are taken once
+        assert_eq!(lottery_account.take_unclaimed_ranks(), vec![ 1, 2 ]);
+        assert_eq!(lottery_account.winners.iter().all(|(_, _, is_withdrawed)| *is_withdrawed == true), true);
+        assert_eq!(lottery_account.take_unclaimed_ranks(), Vec::<u8>::new());
+    }
 }
 
 #[cfg(test)]
@@ -2116,4 +4789,331 @@ mod test_user {
             );
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_set_sponsor() {
+        let mut user_account = User::default();
+        let sponsor = Pubkey::new_from_array([9; 32]);
+
+        // success
+        {
+            // 1. first sponsored purchase
+            user_account.set_sponsor(&sponsor).unwrap();
+            assert_eq!(user_account.sponsor, Some(sponsor));
+
+            // 2. same sponsor again
+            user_account.set_sponsor(&sponsor).unwrap();
+            assert_eq!(user_account.sponsor, Some(sponsor));
+        }
+
+        // fail - another sponsor
+        {
+            let result = user_account.set_sponsor(&Pubkey::new_from_array([10; 32]));
+
+            assert_eq!(
+                result,
+                Err(
+                    ProgramError::Custom(
+                        LotteryError::SponsorMismatch as u32
+                    )
+                )
+            );
+            assert_eq!(user_account.sponsor, Some(sponsor));
+        }
+    }
+
+    #[test]
+    fn test_transfer_tickets_to() {
+        let sponsor = Pubkey::new_from_array([9; 32]);
+        let mut sender = User {
+            total_tickets_acquired: 10,
+            total_ticket_ranges_acquired: 4,
+            total_tickets_value: 100_000000,
+            total_converted_tickets_value: 20_000000,
+            total_tickets_lamports: 200_000000,
+            total_sponsored_tickets_value: 30_000000,
+            total_rent_exempt_paied: 4000,
+            sponsor: Some(sponsor),
+            ..User::default()
+        };
+        let mut receiver = User::default();
+
+        // success - prorated share of 4 tickets (1 re-assigned ticket-range)
+        {
+            sender.transfer_tickets_to(&mut receiver, 4, 1).unwrap();
+
+            assert_eq!(receiver.total_tickets_acquired, 4);
+            assert_eq!(receiver.total_ticket_ranges_acquired, 1);
+            assert_eq!(receiver.total_tickets_value, 40_000000);
+            assert_eq!(receiver.total_converted_tickets_value, 8_000000);
+            assert_eq!(receiver.total_tickets_lamports, 80_000000);
+            assert_eq!(receiver.total_sponsored_tickets_value, 12_000000);
+            assert_eq!(receiver.total_rent_exempt_paied, 1000);
+            assert_eq!(receiver.sponsor, Some(sponsor));
+
+            assert_eq!(sender.total_tickets_acquired, 6);
+            assert_eq!(sender.total_ticket_ranges_acquired, 3);
+            assert_eq!(sender.total_tickets_value, 60_000000);
+            assert_eq!(sender.total_rent_exempt_paied, 3000);
+        }
+
+        // fail - sponsored tickets to a user of another sponsor
+        {
+            let mut other_receiver = User {
+                sponsor: Some(Pubkey::new_from_array([10; 32])),
+                ..User::default()
+            };
+
+            let result = sender.transfer_tickets_to(&mut other_receiver, 1, 0);
+
+            assert_eq!(
+                result,
+                Err(
+                    ProgramError::Custom(
+                        LotteryError::SponsorMismatch as u32
+                    )
+                )
+            );
+        }
+
+        // success - every remaining ticket, the exact remaining values move
+        {
+            sender.transfer_tickets_to(&mut receiver, 6, 3).unwrap();
+
+            assert_eq!(sender, User { sponsor: Some(sponsor), ..User::default() });
+            assert_eq!(receiver.total_tickets_value, 100_000000);
+            assert_eq!(receiver.total_rent_exempt_paied, 4000);
+        }
+
+        // fail - not enough tickets
+        {
+            let result = sender.transfer_tickets_to(&mut receiver, 1, 0);
+
+            assert_eq!(
+                result,
+                Err(
+                    ProgramError::Custom(
+                        LotteryError::InsufficientTicketsToTransfer as u32
+                    )
+                )
+            );
+        }
+
+        // success - mixed payments, the converted & sponsored parts stay within the sender's tickets value
+        {
+            let mut sender = User {
+                total_tickets_acquired: 2,
+                total_tickets_value: 2_000002,
+                total_converted_tickets_value: 1_000001,
+                total_sponsored_tickets_value: 1_000001,
+                sponsor: Some(sponsor),
+                ..User::default()
+            };
+            let mut receiver = User::default();
+
+            sender.transfer_tickets_to(&mut receiver, 1, 0).unwrap();
+
+            assert_eq!(receiver.total_converted_tickets_value, 500000);
+            assert_eq!(receiver.total_sponsored_tickets_value, 500000);
+            assert_eq!(receiver.total_tickets_value, 1_000000);
+
+            assert_eq!(sender.total_converted_tickets_value, 500001);
+            assert_eq!(sender.total_sponsored_tickets_value, 500001);
+            assert_eq!(sender.total_tickets_value, 1_000002);
+            assert!(
+                sender.total_converted_tickets_value + sender.total_sponsored_tickets_value <= sender.total_tickets_value
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_randomness_request {
+    use borsh::BorshSerialize;
+    use super::{
+        RandomnessRequest,
+        LotteryError,
+        Pubkey
+    };
+    use crate::vrf::{
+        get_vrf_public_key,
+        prove_vrf,
+        verify_vrf_proof
+    };
+    use solana_program::{
+        account_info::AccountInfo,
+        clock::Epoch,
+        program_error::ProgramError
+    };
+    use std::{
+        rc::Rc,
+        cell::RefCell
+    };
+
+    #[test]
+    fn test_validate_randomness_request_account() {
+        let program_id = Pubkey::new_from_array([5; 32]);
+        let randomness_request_account = Pubkey::new_from_array([6; 32]);
+
+        let randomness_request = RandomnessRequest::new(
+            255,
+            Pubkey::new_from_array([7; 32]),
+            Pubkey::new_from_array([8; 32]),
+            100,
+            [9; 32]
+        );
+
+        let mut data: [u8; RandomnessRequest::LEN] = [0; RandomnessRequest::LEN];
+        randomness_request.serialize(
+            &mut data.as_mut_slice()
+        ).unwrap();
+
+        // success
+        {
+            RandomnessRequest::validate_randomness_request_account(
+                &AccountInfo {
+                    key: &randomness_request_account,
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut data)),
+                    owner: &program_id,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                &program_id
+            ).unwrap();
+        }
+
+        // fail - invalid owner
+        {
+            let result = RandomnessRequest::validate_randomness_request_account(
+                &AccountInfo {
+                    key: &randomness_request_account,
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut data)),
+                    owner: &Pubkey::default(),
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                &program_id
+            );
+
+            assert_eq!(
+                result,
+                Err(
+                    ProgramError::IncorrectProgramId
+                )
+            );
+        }
+
+        // fail - invalid discriminator
+        {
+            let mut data: [u8; RandomnessRequest::LEN] = [0; RandomnessRequest::LEN];
+            let result = RandomnessRequest::validate_randomness_request_account(
+                &AccountInfo {
+                    key: &randomness_request_account,
+                    lamports: Rc::new(RefCell::new(&mut u64::default())),
+                    data: Rc::new(RefCell::new(&mut data)),
+                    owner: &program_id,
+                    rent_epoch: Epoch::default(),
+                    is_signer: false,
+                    is_writable: false,
+                    executable: false
+                },
+                &program_id
+            );
+
+            assert_eq!(
+                result,
+                Err(
+                    ProgramError::Custom(
+                        LotteryError::InvalidDiscriminator as u32
+                    )
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_message() {
+        let randomness_request_account = Pubkey::new_from_array([6; 32]);
+        let mut randomness_request = RandomnessRequest::default();
+        randomness_request.request_seed = [9; 32];
+
+        let message = randomness_request.get_message(&randomness_request_account);
+
+        assert_eq!(message.len(), 64);
+        assert_eq!(&message[..32], &[6; 32]);
+        assert_eq!(&message[32..], &[9; 32]);
+    }
+
+    #[test]
+    fn test_fulfil() {
+        let randomness_request_account = Pubkey::new_from_array([6; 32]);
+        let oracle_secret_key = [5u8; 32];
+        let mut randomness_request = RandomnessRequest {
+            oracle_authority: get_vrf_public_key(&oracle_secret_key),
+            request_seed: [9; 32],
+            ..RandomnessRequest::default()
+        };
+        let message = randomness_request.get_message(&randomness_request_account);
+        let proof = prove_vrf(&oracle_secret_key, &message);
+
+        // fail - not fulfilled yet
+        assert_eq!(
+            randomness_request.get_randomness(),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::RandomnessNotFulfilled as u32
+                )
+            )
+        );
+
+        // fail - proof of another request
+        assert_eq!(
+            randomness_request.fulfil(&Pubkey::new_from_array([7; 32]), &proof, 250),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidRandomnessProof as u32
+                )
+            )
+        );
+
+        // fail - proof of another oracle
+        assert_eq!(
+            randomness_request.fulfil(
+                &randomness_request_account,
+                &prove_vrf(&[8u8; 32], &message),
+                250
+            ),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidRandomnessProof as u32
+                )
+            )
+        );
+
+        // success
+        randomness_request.fulfil(&randomness_request_account, &proof, 250).unwrap();
+        assert_eq!(randomness_request.is_fulfilled, true);
+        assert_eq!(randomness_request.fulfilled_at, 250);
+        assert_eq!(
+            randomness_request.get_randomness().unwrap(),
+            verify_vrf_proof(&randomness_request.oracle_authority, &message, &proof).unwrap()[..32]
+        );
+
+        // fail - already fulfilled
+        assert_eq!(
+            randomness_request.fulfil(&randomness_request_account, &proof, 260),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::RandomnessAlreadyFulfilled as u32
+                )
+            )
+        );
+        assert_eq!(randomness_request.fulfilled_at, 250);
+    }
+}
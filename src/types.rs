@@ -4,7 +4,19 @@ pub type Time = i64;
 pub type PriceFeedAccount = Pubkey;
 pub type PricePublishTime = i64;
 pub type Price = i64;
+pub type PriceConfidence = u64;
+pub type Slot = u64;
+pub type SlotHash = [u8; 32];
 pub type UserAccount = Pubkey;
 pub type IsWithdrawed = bool;
-pub type WinnerStatus = (UserAccount, IsWithdrawed);
-pub type RandomNumberInfo = (PriceFeedAccount, PricePublishTime, Price);
\ No newline at end of file
+pub type Rank = u8;
+pub type WinnerStatus = (UserAccount, Rank, IsWithdrawed);
+pub type BasisPoints = u16;
+pub type PrizeMint = Pubkey;
+pub type PrizeAmount = u64;
+pub type PrizeMintInfo = (PrizeMint, PrizeAmount);
+pub type NftPrizeMint = Pubkey;
+pub type RandomNumberInfo = (PriceFeedAccount, PricePublishTime, Price, PriceConfidence);
+pub type SlotHashInfo = (Slot, SlotHash);
+pub type TicketEndIndex = u32;
+pub type TicketRange = (UserAccount, TicketEndIndex);
@@ -0,0 +1,502 @@
+// Off-chain verification of a lottery's draw (off-chain only).
+// Re-runs the on-chain winners selection against the seed inputs recorded on a dumped lottery account and its ticket ledger,
+// the seed & the winners are computed by the same code as "EndLotteryAndPickWinners" so both paths can't drift apart.
+// A dump is the raw account data or the base64 JSON of `solana account <ADDRESS> --output json` (or of "getAccountInfo").
+use {
+    crate::{
+        state::{
+            Lottery,
+            RandomnessRequest,
+            RandomnessSource,
+            TICKET_RANGE_LENGTH
+        },
+        types::WinnerStatus
+    },
+
+    base64::{
+        engine::general_purpose::STANDARD as BASE64_STANDARD,
+        Engine
+    },
+
+    borsh::BorshDeserialize,
+
+    solana_program::{
+        account_info::AccountInfo,
+        clock::Epoch,
+        hash::HASH_BYTES,
+        program_error::ProgramError,
+        pubkey::Pubkey
+    },
+
+    std::{
+        fmt,
+        str::FromStr
+    },
+
+    thiserror::Error
+};
+
+#[derive(Debug, Error, PartialEq)]
+pub enum VerifyError {
+    #[error("invalid account dump.")]
+    InvalidAccountDump,
+    #[error("lottery account address is not found in the dump, it must be given.")]
+    LotteryAccountNotFound,
+    #[error("invalid lottery account.")]
+    InvalidLotteryAccount,
+    #[error("winners are not selected yet!")]
+    WinnersNotSelected,
+    #[error("randomness-request account is required for \"VrfOracle\" lotteries.")]
+    RandomnessRequestAccountNotFound,
+    #[error("invalid randomness-request account.")]
+    InvalidRandomnessRequestAccount,
+    #[error("ticket ledger is incomplete (users accounts are already closed).")]
+    IncompleteTicketLedger,
+    #[error("program error -> {0}")]
+    Program(ProgramError)
+}
+
+impl From<ProgramError> for VerifyError {
+    fn from(e: ProgramError) -> Self {
+        VerifyError::Program(e)
+    }
+}
+
+// Account data & its address (if the dump has it)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountDump {
+    pub pubkey: Option<Pubkey>,
+    pub data: Vec<u8>
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrawVerification {
+    pub lottery_account: Pubkey,
+    pub randomness_source: RandomnessSource,
+    pub winners_seed: [u8; HASH_BYTES],
+    pub winners_index: Vec<u32>,
+    pub expected_winners: Vec<Pubkey>,
+    pub onchain_winners: Vec<Pubkey>
+}
+
+impl DrawVerification {
+    pub fn is_matched(&self) -> bool {
+        self.expected_winners == self.onchain_winners
+    }
+}
+
+impl fmt::Display for DrawVerification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Lottery : {}", self.lottery_account)?;
+        writeln!(f, "Randomness-Source : {:?}", self.randomness_source)?;
+        writeln!(f, "Winners-Seed : {}", Pubkey::new_from_array(self.winners_seed))?;
+        writeln!(f, "Winners_Index : {:?}", self.winners_index)?;
+
+        writeln!(f, "Winners (expected -> on-chain) :")?;
+        for (index, expected_winner) in self.expected_winners.iter().enumerate() {
+            match self.onchain_winners.get(index) {
+                Some(onchain_winner) => writeln!(
+                    f,
+                    "  {} -> {} {}",
+                    expected_winner,
+                    onchain_winner,
+                    if expected_winner == onchain_winner { "(ok)" } else { "(MISMATCH)" }
+                )?,
+                None => writeln!(f, "  {} -> - (MISMATCH)", expected_winner)?
+            };
+        };
+
+        if self.is_matched() == true {
+            write!(f, "Result : on-chain winners match the draw.")
+        } else {
+            write!(f, "Result : on-chain winners DO NOT match the draw!")
+        }
+    }
+}
+
+// Raw account data, or the base64 JSON of the solana-cli / rpc
+pub fn parse_account_dump(dump: &[u8]) -> Result<AccountDump, VerifyError> {
+    let json = match serde_json::from_slice::<serde_json::Value>(dump) {
+        Ok(json @ serde_json::Value::Object(_)) => json,
+        _ => return Ok(
+            AccountDump {
+                pubkey: None,
+                data: dump.to_vec()
+            }
+        )
+    };
+
+    // "solana account --output json" -> { pubkey, account: { data } }, "getAccountInfo" -> { result: { value: { data } } }
+    let account = [
+        &json["account"],
+        &json["result"]["value"],
+        &json["value"],
+        &json
+    ]
+        .into_iter()
+        .find(|account| account["data"].is_null() == false)
+        .ok_or(VerifyError::InvalidAccountDump)?;
+
+    let encoded_data = match &account["data"] {
+        serde_json::Value::Array(data) if data.get(1).and_then(|encoding| encoding.as_str()) == Some("base64") => data
+            .first()
+            .and_then(|data| data.as_str())
+            .ok_or(VerifyError::InvalidAccountDump)?,
+        _ => return Err(
+            VerifyError::InvalidAccountDump
+        )
+    };
+
+    let data = BASE64_STANDARD
+        .decode(encoded_data)
+        .map_err(|_| VerifyError::InvalidAccountDump)?;
+
+    let pubkey = match json["pubkey"].as_str() {
+        Some(pubkey) => Some(
+            Pubkey::from_str(pubkey).map_err(|_| VerifyError::InvalidAccountDump)?
+        ),
+        None => None
+    };
+
+    Ok(
+        AccountDump {
+            pubkey,
+            data
+        }
+    )
+}
+
+// Recomputes the winners of an ended lottery, the randomness-request account is only needed for "VrfOracle" lotteries.
+// Must be run before the non-winner users close their accounts, closing them shrinks the ticket ledger.
+pub fn verify_draw(
+    lottery_account: &Pubkey,
+    lottery_account_data: &[u8],
+    randomness_request_account_data: Option<&[u8]>
+) -> Result<DrawVerification, VerifyError> {
+    if lottery_account_data.get(..8) != Some(Lottery::get_discriminator().as_slice()) {
+        return Err(
+            VerifyError::InvalidLotteryAccount
+        );
+    };
+
+    let lottery = Lottery::deserialize(
+        &mut &lottery_account_data[..]
+    ).map_err(|_| VerifyError::InvalidLotteryAccount)?;
+
+    if lottery.is_ended_successfuly == false {
+        return Err(
+            VerifyError::WinnersNotSelected
+        );
+    };
+
+    let ticket_ledger_end = (lottery.ticket_ranges_total_amount as u64)
+        .checked_mul(TICKET_RANGE_LENGTH as u64)
+        .and_then(|ticket_ranges_bytes| ticket_ranges_bytes.checked_add(lottery.initial_bytes))
+        .ok_or(VerifyError::InvalidLotteryAccount)?;
+    if (lottery_account_data.len() as u64) < ticket_ledger_end {
+        return Err(
+            VerifyError::IncompleteTicketLedger
+        );
+    };
+
+    // same entropy as "EndLotteryAndPickWinners"
+    let randomness = match lottery.randomness_source {
+        RandomnessSource::PythPriceFeeds => lottery.random_numbers_info.get_price_feeds_randomness(lottery_account),
+        RandomnessSource::VrfOracle => {
+            let randomness_request_account_data = randomness_request_account_data
+                .ok_or(VerifyError::RandomnessRequestAccountNotFound)?;
+
+            if randomness_request_account_data.get(..8) != Some(RandomnessRequest::get_discriminator().as_slice()) {
+                return Err(
+                    VerifyError::InvalidRandomnessRequestAccount
+                );
+            };
+
+            let randomness_request = RandomnessRequest::deserialize(
+                &mut &randomness_request_account_data[..]
+            ).map_err(|_| VerifyError::InvalidRandomnessRequestAccount)?;
+
+            if &randomness_request.lottery != lottery_account {
+                return Err(
+                    VerifyError::InvalidRandomnessRequestAccount
+                );
+            };
+
+            randomness_request.get_randomness()?.to_vec()
+        }
+    };
+
+    let winners_seed = lottery.get_winners_seed(&randomness);
+
+    let mut lamports = u64::default();
+    let mut data = lottery_account_data.to_vec();
+    let owner = crate::program::ID;
+    let lottery_account_info = AccountInfo::new(
+        lottery_account,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        Epoch::default()
+    );
+
+    let (
+        winners_index,
+        expected_winners
+    ) = lottery.get_winners(&winners_seed, &lottery_account_info)?;
+
+    Ok(
+        DrawVerification {
+            lottery_account: *lottery_account,
+            randomness_source: lottery.randomness_source,
+            winners_seed,
+            winners_index,
+            expected_winners,
+            onchain_winners: lottery.winners
+                .iter()
+                .map(|(winner, _, _): &WinnerStatus| *winner)
+                .collect()
+        }
+    )
+}
+
+#[cfg(test)]
+mod test_verify {
+    use {
+        super::{
+            parse_account_dump,
+            verify_draw,
+            AccountDump,
+            VerifyError
+        },
+        crate::state::{
+            Lottery,
+            RandomNumbersInfo,
+            RandomnessRequest,
+            RandomnessSource,
+            TICKET_RANGE_LENGTH
+        },
+        base64::{
+            engine::general_purpose::STANDARD as BASE64_STANDARD,
+            Engine
+        },
+        borsh::{
+            BorshDeserialize,
+            BorshSerialize
+        },
+        solana_program::{
+            hash::hashv,
+            pubkey::Pubkey
+        }
+    };
+
+    // Ended lottery account data with 10 tickets, the winners are picked with the recorded seed inputs
+    fn get_lottery_account_data(
+        lottery_account: &Pubkey,
+        randomness: Option<&[u8]>,
+        randomness_source: RandomnessSource
+    ) -> Vec<u8> {
+        let mut lottery = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            winners_count: 3,
+            tickets_total_amount: 10,
+            ticket_ranges_total_amount: 10,
+            is_ended_successfuly: true,
+            is_secret_revealed: true,
+            revealed_secret: [4; 32],
+            randomness_source,
+            random_numbers_info: RandomNumbersInfo {
+                price_feeds: [
+                    (Pubkey::new_from_array([1; 32]), 300, 250, 7),
+                    (Pubkey::new_from_array([2; 32]), 301, 150, 3),
+                    (Pubkey::new_from_array([3; 32]), 302, 350, 5)
+                ],
+                slot_hash: (42, [8; 32])
+            },
+            ..Lottery::default()
+        };
+        lottery.initial_bytes = lottery.try_to_vec().unwrap().len() as u64 + (lottery.winners_count as u64 * 34);
+
+        let ticket_ranges: Vec<u8> = (0..10u8)
+            .flat_map(|ticket| [ [ticket + 10; 32].as_slice(), (ticket as u32 + 1).to_le_bytes().as_slice() ].concat())
+            .collect();
+
+        let randomness = match randomness {
+            Some(randomness) => randomness.to_vec(),
+            None => lottery.random_numbers_info.get_price_feeds_randomness(lottery_account)
+        };
+        let seed = hashv(&[ randomness.as_slice(), [4; 32].as_slice() ]).to_bytes();
+        let winners_index = Lottery::get_winners_index(&seed, 10, 3).unwrap();
+        lottery.winners = winners_index
+            .iter()
+            .enumerate()
+            .map(|(rank, index)| (Pubkey::new_from_array([*index as u8 + 10; 32]), rank as u8, false))
+            .collect();
+
+        [
+            lottery.try_to_vec().unwrap(),
+            ticket_ranges
+        ].concat()
+    }
+
+    #[test]
+    fn test_parse_account_dump() {
+        let lottery_account = Pubkey::new_from_array([9; 32]);
+        let data = vec![ 1u8, 2, 3, 4, 5 ];
+
+        // raw bytes
+        assert_eq!(
+            parse_account_dump(&data).unwrap(),
+            AccountDump {
+                pubkey: None,
+                data: data.clone()
+            }
+        );
+
+        // solana-cli json
+        let json = format!(
+            r#"{{"pubkey":"{}","account":{{"lamports":1,"data":["{}","base64"],"owner":"{}","executable":false,"rentEpoch":0,"space":5}}}}"#,
+            lottery_account,
+            BASE64_STANDARD.encode(&data),
+            Pubkey::default()
+        );
+        assert_eq!(
+            parse_account_dump(json.as_bytes()).unwrap(),
+            AccountDump {
+                pubkey: Some(lottery_account),
+                data: data.clone()
+            }
+        );
+
+        // rpc json
+        let json = format!(
+            r#"{{"jsonrpc":"2.0","result":{{"context":{{"slot":1}},"value":{{"lamports":1,"data":["{}","base64"]}}}},"id":1}}"#,
+            BASE64_STANDARD.encode(&data)
+        );
+        assert_eq!(
+            parse_account_dump(json.as_bytes()).unwrap(),
+            AccountDump {
+                pubkey: None,
+                data
+            }
+        );
+
+        // unsupported encoding
+        let json = r#"{"account":{"data":["AQIDBAU=","base58"]}}"#;
+        assert_eq!(
+            parse_account_dump(json.as_bytes()).unwrap_err(),
+            VerifyError::InvalidAccountDump
+        );
+    }
+
+    #[test]
+    fn test_verify_draw_with_price_feeds() {
+        let lottery_account = Pubkey::new_from_array([9; 32]);
+        let lottery_account_data = get_lottery_account_data(
+            &lottery_account,
+            None,
+            RandomnessSource::PythPriceFeeds
+        );
+
+        let draw_verification = verify_draw(
+            &lottery_account,
+            &lottery_account_data,
+            None
+        ).unwrap();
+        assert_eq!(draw_verification.is_matched(), true);
+        assert_eq!(draw_verification.expected_winners.len(), 3);
+
+        // the seed is bound to the lottery account
+        let draw_verification = verify_draw(
+            &Pubkey::new_from_array([10; 32]),
+            &lottery_account_data,
+            None
+        ).unwrap();
+        assert_eq!(draw_verification.is_matched(), false);
+
+        // tampered winner
+        let mut lottery = Lottery::deserialize(&mut &lottery_account_data[..]).unwrap();
+        lottery.winners[0].0 = Pubkey::new_from_array([99; 32]);
+        let tampered_lottery_account_data = [
+            lottery.try_to_vec().unwrap(),
+            lottery_account_data[lottery.try_to_vec().unwrap().len()..].to_vec()
+        ].concat();
+
+        let draw_verification = verify_draw(
+            &lottery_account,
+            &tampered_lottery_account_data,
+            None
+        ).unwrap();
+        assert_eq!(draw_verification.is_matched(), false);
+
+        // closed users shrink the ticket ledger
+        assert_eq!(
+            verify_draw(
+                &lottery_account,
+                &lottery_account_data[..lottery_account_data.len() - TICKET_RANGE_LENGTH],
+                None
+            ).unwrap_err(),
+            VerifyError::IncompleteTicketLedger
+        );
+
+        // not a lottery account
+        assert_eq!(
+            verify_draw(
+                &lottery_account,
+                &[0; 64],
+                None
+            ).unwrap_err(),
+            VerifyError::InvalidLotteryAccount
+        );
+    }
+
+    #[test]
+    fn test_verify_draw_with_vrf_oracle() {
+        let lottery_account = Pubkey::new_from_array([9; 32]);
+        let randomness = [6u8; 32];
+        let lottery_account_data = get_lottery_account_data(
+            &lottery_account,
+            Some(randomness.as_slice()),
+            RandomnessSource::VrfOracle
+        );
+
+        let mut randomness_request = RandomnessRequest::new(
+            255,
+            lottery_account,
+            Pubkey::new_unique(),
+            100,
+            [0; 32]
+        );
+        randomness_request.is_fulfilled = true;
+        randomness_request.randomness = randomness;
+
+        let draw_verification = verify_draw(
+            &lottery_account,
+            &lottery_account_data,
+            Some(&randomness_request.try_to_vec().unwrap())
+        ).unwrap();
+        assert_eq!(draw_verification.is_matched(), true);
+
+        // the randomness-request account is required
+        assert_eq!(
+            verify_draw(
+                &lottery_account,
+                &lottery_account_data,
+                None
+            ).unwrap_err(),
+            VerifyError::RandomnessRequestAccountNotFound
+        );
+
+        // request of another lottery
+        randomness_request.lottery = Pubkey::new_unique();
+        assert_eq!(
+            verify_draw(
+                &lottery_account,
+                &lottery_account_data,
+                Some(&randomness_request.try_to_vec().unwrap())
+            ).unwrap_err(),
+            VerifyError::InvalidRandomnessRequestAccount
+        );
+    }}
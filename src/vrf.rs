@@ -0,0 +1,437 @@
+// ECVRF-EDWARDS25519-SHA512-TAI (RFC 9381, suite string 0x03).
+// A public key and an input have a single valid output, the oracle can't pick one of several valid proofs.
+// The curve operations are the curve25519 syscalls on-chain, "prove_vrf" is the oracle's side (off-chain only).
+// The keys are RFC 8032 ed25519 keys, any RFC 9381 prover of this suite produces proofs accepted here.
+use {
+    crate::error::LotteryError,
+
+    sha2::{
+        Digest,
+        Sha512
+    },
+
+    solana_program::{
+        program_error::ProgramError,
+        program_memory::sol_memcmp,
+        pubkey::Pubkey
+    },
+
+    spl_token_2022::solana_zk_token_sdk::curve25519::{
+        edwards::{
+            multiply_edwards,
+            subtract_edwards,
+            validate_edwards,
+            PodEdwardsPoint
+        },
+        scalar::PodScalar
+    }
+};
+
+#[cfg(not(target_os = "solana"))]
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_TABLE,
+    scalar::Scalar
+};
+
+const SUITE_STRING: u8 = 0x03;
+const ENCODE_TO_CURVE_DOMAIN_SEPARATOR_FRONT: u8 = 0x01;
+const CHALLENGE_GENERATION_DOMAIN_SEPARATOR_FRONT: u8 = 0x02;
+const PROOF_TO_HASH_DOMAIN_SEPARATOR_FRONT: u8 = 0x03;
+const DOMAIN_SEPARATOR_BACK: u8 = 0x00;
+
+const COFACTOR: u8 = 8;
+const POINT_LENGTH: usize = 32;
+const CHALLENGE_LENGTH: usize = 16;
+const SCALAR_LENGTH: usize = 32;
+const SHA512_LENGTH: usize = 64;
+
+// gamma || c || s
+pub const VRF_PROOF_LENGTH: usize = POINT_LENGTH + CHALLENGE_LENGTH + SCALAR_LENGTH;
+// beta
+pub const VRF_OUTPUT_LENGTH: usize = SHA512_LENGTH;
+
+// compressed base point of edwards25519 (y = 4/5)
+const BASEPOINT: PodEdwardsPoint = PodEdwardsPoint(
+    [
+        0x58, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+        0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+        0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+        0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66
+    ]
+);
+
+// compressed neutral element (y = 1)
+const IDENTITY: PodEdwardsPoint = PodEdwardsPoint(
+    [
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+    ]
+);
+
+fn sha512(values: &[&[u8]]) -> [u8; SHA512_LENGTH] {
+    let mut hasher = Sha512::new();
+    for value in values {
+        hasher.update(value);
+    };
+
+    hasher.finalize().into()
+}
+
+// Little-endian scalar, zero-padded
+fn get_scalar(bytes: &[u8]) -> PodScalar {
+    let mut scalar = [0u8; SCALAR_LENGTH];
+    scalar[..bytes.len()].copy_from_slice(bytes);
+
+    PodScalar(scalar)
+}
+
+fn invalid_proof() -> ProgramError {
+    LotteryError::InvalidRandomnessProof.into()
+}
+
+fn multiply_by_cofactor(point: &PodEdwardsPoint) -> Result<PodEdwardsPoint, ProgramError> {
+    multiply_edwards(
+        &get_scalar(&[ COFACTOR ]),
+        point
+    ).ok_or_else(invalid_proof)
+}
+
+// ECVRF_encode_to_curve_try_and_increment, the first hash that decodes to a point is multiplied by the cofactor
+fn encode_to_curve(
+    public_key: &PodEdwardsPoint,
+    alpha: &[u8]
+) -> Result<PodEdwardsPoint, ProgramError> {
+    for counter in 0..=u8::MAX {
+        let hash_string = sha512(
+            &[
+                &[ SUITE_STRING, ENCODE_TO_CURVE_DOMAIN_SEPARATOR_FRONT ],
+                public_key.0.as_slice(),
+                alpha,
+                &[ counter, DOMAIN_SEPARATOR_BACK ]
+            ]
+        );
+        let candidate = PodEdwardsPoint(hash_string[..POINT_LENGTH].try_into().unwrap());
+
+        if validate_edwards(&candidate) == true {
+            return multiply_by_cofactor(&candidate);
+        };
+    };
+
+    Err(
+        invalid_proof()
+    )
+}
+
+// ECVRF_challenge_generation(Y, H, Gamma, U, V)
+fn generate_challenge(
+    public_key: &PodEdwardsPoint,
+    h: &PodEdwardsPoint,
+    gamma: &PodEdwardsPoint,
+    u: &PodEdwardsPoint,
+    v: &PodEdwardsPoint
+) -> [u8; CHALLENGE_LENGTH] {
+    let challenge = sha512(
+        &[
+            &[ SUITE_STRING, CHALLENGE_GENERATION_DOMAIN_SEPARATOR_FRONT ],
+            public_key.0.as_slice(),
+            h.0.as_slice(),
+            gamma.0.as_slice(),
+            u.0.as_slice(),
+            v.0.as_slice(),
+            &[ DOMAIN_SEPARATOR_BACK ]
+        ]
+    );
+
+    challenge[..CHALLENGE_LENGTH].try_into().unwrap()
+}
+
+// ECVRF_proof_to_hash, the output of a valid proof
+fn proof_to_hash(gamma: &PodEdwardsPoint) -> Result<[u8; VRF_OUTPUT_LENGTH], ProgramError> {
+    let cofactor_gamma = multiply_by_cofactor(gamma)?;
+
+    Ok(
+        sha512(
+            &[
+                &[ SUITE_STRING, PROOF_TO_HASH_DOMAIN_SEPARATOR_FRONT ],
+                cofactor_gamma.0.as_slice(),
+                &[ DOMAIN_SEPARATOR_BACK ]
+            ]
+        )
+    )
+}
+
+// ECVRF_verify (with ECVRF_validate_key) of the "proof" of "public_key" over "alpha", returns the vrf output (beta)
+pub fn verify_vrf_proof(
+    public_key: &Pubkey,
+    alpha: &[u8],
+    proof: &[u8; VRF_PROOF_LENGTH]
+) -> Result<[u8; VRF_OUTPUT_LENGTH], ProgramError> {
+    let public_key = PodEdwardsPoint(public_key.to_bytes());
+    let gamma = PodEdwardsPoint(proof[..POINT_LENGTH].try_into().unwrap());
+    let challenge = &proof[POINT_LENGTH..POINT_LENGTH + CHALLENGE_LENGTH];
+    let response = get_scalar(&proof[POINT_LENGTH + CHALLENGE_LENGTH..]);
+
+    // the public key must be a point of the curve, not of small order
+    if
+        validate_edwards(&public_key) == false ||
+        multiply_by_cofactor(&public_key)? == IDENTITY
+    {
+        return Err(
+            LotteryError::InvalidOracleAuthority.into()
+        );
+    };
+
+    if validate_edwards(&gamma) == false {
+        return Err(
+            invalid_proof()
+        );
+    };
+
+    let h = encode_to_curve(&public_key, alpha)?;
+    let challenge_scalar = get_scalar(challenge);
+
+    // U = s*B - c*Y, V = s*H - c*Gamma (a non-canonical "s" is rejected by the multiplication)
+    let u = subtract_edwards(
+        &multiply_edwards(&response, &BASEPOINT).ok_or_else(invalid_proof)?,
+        &multiply_edwards(&challenge_scalar, &public_key).ok_or_else(invalid_proof)?
+    ).ok_or_else(invalid_proof)?;
+    let v = subtract_edwards(
+        &multiply_edwards(&response, &h).ok_or_else(invalid_proof)?,
+        &multiply_edwards(&challenge_scalar, &gamma).ok_or_else(invalid_proof)?
+    ).ok_or_else(invalid_proof)?;
+
+    if sol_memcmp(
+        &generate_challenge(&public_key, &h, &gamma, &u, &v),
+        challenge,
+        CHALLENGE_LENGTH
+    ) != 0 {
+        return Err(
+            invalid_proof()
+        );
+    };
+
+    proof_to_hash(&gamma)
+}
+
+// RFC 8032 key expansion -> (secret scalar x, nonce prefix)
+#[cfg(not(target_os = "solana"))]
+fn expand_secret_key(secret_key: &[u8; SCALAR_LENGTH]) -> (Scalar, [u8; SCALAR_LENGTH]) {
+    let hashed_secret_key = sha512(&[ secret_key.as_slice() ]);
+
+    let mut x: [u8; SCALAR_LENGTH] = hashed_secret_key[..SCALAR_LENGTH].try_into().unwrap();
+    x[0] &= 248;
+    x[31] &= 127;
+    x[31] |= 64;
+
+    (
+        Scalar::from_bytes_mod_order(x),
+        hashed_secret_key[SCALAR_LENGTH..].try_into().unwrap()
+    )
+}
+
+// Public key of the oracle's ed25519 secret key (same as its ed25519 public key)
+#[cfg(not(target_os = "solana"))]
+pub fn get_vrf_public_key(secret_key: &[u8; SCALAR_LENGTH]) -> Pubkey {
+    let (x, _) = expand_secret_key(secret_key);
+
+    Pubkey::new_from_array(
+        (&x * &ED25519_BASEPOINT_TABLE).compress().to_bytes()
+    )
+}
+
+// ECVRF_prove, the oracle's proof over "alpha" (RFC 8032 nonce generation)
+#[cfg(not(target_os = "solana"))]
+pub fn prove_vrf(
+    secret_key: &[u8; SCALAR_LENGTH],
+    alpha: &[u8]
+) -> [u8; VRF_PROOF_LENGTH] {
+    let (x, nonce_prefix) = expand_secret_key(secret_key);
+    let public_key = PodEdwardsPoint(get_vrf_public_key(secret_key).to_bytes());
+
+    let h = encode_to_curve(&public_key, alpha).unwrap();
+    let gamma = multiply_edwards(&PodScalar(x.to_bytes()), &h).unwrap();
+
+    let k = Scalar::from_bytes_mod_order_wide(
+        &sha512(&[ nonce_prefix.as_slice(), h.0.as_slice() ])
+    );
+
+    let u = multiply_edwards(&PodScalar(k.to_bytes()), &BASEPOINT).unwrap();
+    let v = multiply_edwards(&PodScalar(k.to_bytes()), &h).unwrap();
+
+    let challenge = generate_challenge(&public_key, &h, &gamma, &u, &v);
+    let s = k + Scalar::from_bytes_mod_order(get_scalar(&challenge).0) * x;
+
+    [
+        gamma.0.as_slice(),
+        challenge.as_slice(),
+        s.as_bytes().as_slice()
+    ].concat().try_into().unwrap()
+}
+
+#[cfg(test)]
+mod test_vrf {
+    use {
+        super::*,
+        solana_program::program_error::ProgramError
+    };
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).unwrap())
+            .collect()
+    }
+
+    // RFC 9381, appendix B.3 (examples 16, 17 & 18) -> (SK, PK, alpha, pi, beta)
+    const RFC_9381_TEST_VECTORS: [(&str, &str, &str, &str, &str); 3] = [
+        (
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "",
+            "8657106690b5526245a92b003bb079ccd1a92130477671f6fc01ad16f26f723f26f8a57ccaed74ee1b190bed1f479d9727d2d0f9b005a6e456a35d4fb0daab1268a1b0db10836d9826a528ca76567805",
+            "90cf1df3b703cce59e2a35b925d411164068269d7b2d29f3301c03dd757876ff66b71dda49d2de59d03450451af026798e8f81cd2e333de5cdf4f3e140fdd8ae"
+        ),
+        (
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "72",
+            "f3141cd382dc42909d19ec5110469e4feae18300e94f304590abdced48aed5933bf0864a62558b3ed7f2fea45c92a465301b3bbf5e3e54ddf2d935be3b67926da3ef39226bbc355bdc9850112c8f4b02",
+            "eb4440665d3891d668e7e0fcaf587f1b4bd7fbfe99d0eb2211ccec90496310eb5e33821bc613efb94db5e5b54c70a848a0bef4553a41befc57663b56373a5031"
+        ),
+        (
+            "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "af82",
+            "9bc0f79119cc5604bf02d23b4caede71393cedfbb191434dd016d30177ccbf8096bb474e53895c362d8628ee9f9ea3c0e52c7a5c691b6c18c9979866568add7a2d41b00b05081ed0f58ee5e31b3a970e",
+            "645427e5d00c62a23fb703732fa5d892940935942101e456ecca7bb217c61c452118fec1219202a0edcf038bb6373241578be7217ba85a2687f7a0310b2df19f"
+        )
+    ];
+
+    #[test]
+    fn test_rfc_9381_test_vectors() {
+        for (secret_key, public_key, alpha, proof, output) in RFC_9381_TEST_VECTORS {
+            let secret_key: [u8; SCALAR_LENGTH] = decode_hex(secret_key).try_into().unwrap();
+            let public_key = Pubkey::new_from_array(decode_hex(public_key).try_into().unwrap());
+            let alpha = decode_hex(alpha);
+            let proof: [u8; VRF_PROOF_LENGTH] = decode_hex(proof).try_into().unwrap();
+
+            assert_eq!(get_vrf_public_key(&secret_key), public_key, "invalid public key.");
+            assert_eq!(prove_vrf(&secret_key, &alpha), proof, "invalid proof.");
+            assert_eq!(
+                verify_vrf_proof(&public_key, &alpha, &proof).unwrap().to_vec(),
+                decode_hex(output),
+                "invalid output."
+            );
+        };
+    }
+
+    #[test]
+    fn test_verify_vrf_proof() {
+        let secret_key = [7u8; SCALAR_LENGTH];
+        let public_key = get_vrf_public_key(&secret_key);
+        let alpha = [3u8; 64];
+
+        let proof = prove_vrf(&secret_key, &alpha);
+        let output = verify_vrf_proof(&public_key, &alpha, &proof).unwrap();
+
+        // the output only depends on the public key & the input
+        assert_eq!(prove_vrf(&secret_key, &alpha), proof);
+        assert_ne!(
+            verify_vrf_proof(&public_key, &[4u8; 64], &prove_vrf(&secret_key, &[4u8; 64])).unwrap(),
+            output
+        );
+
+        // another input
+        assert_eq!(
+            verify_vrf_proof(&public_key, &[4u8; 64], &proof),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidRandomnessProof as u32
+                )
+            )
+        );
+
+        // another oracle
+        let other_public_key = get_vrf_public_key(&[8u8; SCALAR_LENGTH]);
+        assert_eq!(
+            verify_vrf_proof(&other_public_key, &alpha, &proof),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidRandomnessProof as u32
+                )
+            )
+        );
+
+        // tampered challenge
+        let mut tampered_proof = proof;
+        tampered_proof[POINT_LENGTH] ^= 1;
+        assert_eq!(
+            verify_vrf_proof(&public_key, &alpha, &tampered_proof),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidRandomnessProof as u32
+                )
+            )
+        );
+
+        // non-canonical response (s + l)
+        let mut non_canonical_proof = proof;
+        let s = Scalar::from_bytes_mod_order(proof[POINT_LENGTH + CHALLENGE_LENGTH..].try_into().unwrap());
+        let l_plus_s = get_l_plus(&s);
+        non_canonical_proof[POINT_LENGTH + CHALLENGE_LENGTH..].copy_from_slice(&l_plus_s);
+        assert_eq!(
+            verify_vrf_proof(&public_key, &alpha, &non_canonical_proof),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidRandomnessProof as u32
+                )
+            )
+        );
+
+        // the public key is not a point
+        let invalid_public_key = (0..=u8::MAX)
+            .map(|byte| Pubkey::new_from_array([byte; 32]))
+            .find(|key| validate_edwards(&PodEdwardsPoint(key.to_bytes())) == false)
+            .unwrap();
+        assert_eq!(
+            verify_vrf_proof(&invalid_public_key, &alpha, &proof),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidOracleAuthority as u32
+                )
+            )
+        );
+
+        // the public key is of small order
+        assert_eq!(
+            verify_vrf_proof(&Pubkey::new_from_array(IDENTITY.0), &alpha, &proof),
+            Err(
+                ProgramError::Custom(
+                    LotteryError::InvalidOracleAuthority as u32
+                )
+            )
+        );
+    }
+
+    // s + l, little-endian (l = 2^252 + 27742317777372353535851937790883648493)
+    fn get_l_plus(s: &Scalar) -> [u8; SCALAR_LENGTH] {
+        const L: [u8; SCALAR_LENGTH] = [
+            0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58,
+            0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10
+        ];
+
+        let mut sum = [0u8; SCALAR_LENGTH];
+        let mut carry = 0u16;
+        for (index, byte) in s.as_bytes().iter().enumerate() {
+            let total = *byte as u16 + L[index] as u16 + carry;
+            sum[index] = total as u8;
+            carry = total >> 8;
+        };
+
+        sum
+    }
+}
@@ -19,7 +19,12 @@ use {
         error::LotteryError, 
         instruction::{
             Instructions,
+            instruction_add_prize_mint,
             instruction_buy_ticket, 
+            instruction_buy_ticket_for,
+            instruction_buy_ticket_with_oracle_conversion,
+            instruction_cancel_lottery,
+            instruction_update_lottery_schedule,
             instruction_change_lottery_ticket_price, 
             instruction_create_and_initialize_lottery_account, 
             instruction_create_and_initialize_user_account, 
@@ -28,9 +33,31 @@ use {
             instruction_withdraw_failed_lottery, 
             instruction_withdraw_lottery_winners, 
             instruction_withdraw_succeed_lottery, 
+            instruction_reveal_lottery_secret,
+            instruction_request_randomness,
+            instruction_fulfil_randomness,
             instruction_close_lottery_account_and_usdc_token_account,
-            instruction_withdraw_and_close_succeed_user
+            instruction_withdraw_and_close_succeed_user,
+            instruction_create_lottery_series,
+            instruction_join_lottery_series,
+            instruction_withdraw_series_jackpot,
+            instruction_set_lottery_series_round_template,
+            instruction_start_next_round,
+            instruction_settle_failed_lottery,
+            instruction_distribute_prizes,
+            instruction_reclaim_unclaimed_prizes,
+            instruction_close_lottery_arbitrary_token_account,
+            instruction_transfer_tickets,
+            instruction_enable_ticket_tokens,
+            instruction_redeem_ticket_tokens,
+            instruction_refund_ticket_tokens
         }, 
+        pda::{
+            get_prize_vault_account_pda,
+            get_series_prize_vault_account_pda,
+            get_sol_payments_vault_account_pda,
+            get_ticket_mint_account_pda
+        },
         processor::{
             get_lottery_literal_seed,
             Processor
@@ -39,7 +66,20 @@ use {
         state::{
             Config,
             Lottery,
+            LotterySeries,
+            RandomNumbersInfo,
+            RandomnessRequest,
+            RandomnessSource,
+            RoundTemplate,
+            SeriesJackpot,
+            SettlementBounty,
+            UnclaimedPrizesReceiver,
             User
+        },
+        vrf::{
+            get_vrf_public_key,
+            prove_vrf,
+            verify_vrf_proof
         }
     }, 
 
@@ -79,9 +119,14 @@ use {
         }
     }, 
 
-    spl_associated_token_account::get_associated_token_address, 
+    spl_associated_token_account::{
+        get_associated_token_address,
+        get_associated_token_address_with_program_id,
+        instruction::create_associated_token_account
+    }, 
 
     spl_token::{
+        instruction::transfer_checked as transfer_spl_checked,
         state::{
             Account as TokenAccount,
             AccountState as TokenAccountState,
@@ -89,6 +134,28 @@ use {
         },
         ID as TOKEN_STANDARD_PROGRAM_ID
     }, 
+
+    spl_token_2022::{
+        extension::{
+            non_transferable::NonTransferable,
+            transfer_fee::{
+                TransferFee,
+                TransferFeeAmount,
+                TransferFeeConfig
+            },
+            transfer_hook::TransferHook,
+            BaseStateWithExtensions,
+            ExtensionType,
+            StateWithExtensions,
+            StateWithExtensionsMut
+        },
+        state::{
+            Account as Token2022Account,
+            AccountState as Token2022AccountState,
+            Mint as Token2022MintAccount
+        },
+        ID as TOKEN_2022_PROGRAM_ID
+    },
     
     std::{
         cell::RefCell, 
@@ -117,6 +184,79 @@ fn change_clock_sysvar(
         }
     );
 }
+
+// "TransferFeeConfig" is initialized with "transfer_fee_basis_points" (no maximum fee) if it's one of the "extension_types"
+fn pack_token_2022_mint_account(
+    mint_account: Token2022MintAccount,
+    extension_types: &[ExtensionType],
+    transfer_fee_basis_points: u16
+) -> Vec<u8> {
+    let mut mint_account_data = vec![
+        0u8;
+        ExtensionType::try_calculate_account_len::<Token2022MintAccount>(extension_types).unwrap()
+    ];
+    let mut mint_account_state = StateWithExtensionsMut::<Token2022MintAccount>::unpack_uninitialized(
+        &mut mint_account_data
+    ).unwrap();
+
+    for extension_type in extension_types {
+        match extension_type {
+            ExtensionType::TransferFeeConfig => {
+                let transfer_fee = TransferFee {
+                    epoch: 0.into(),
+                    maximum_fee: u64::MAX.into(),
+                    transfer_fee_basis_points: transfer_fee_basis_points.into()
+                };
+
+                let transfer_fee_config = mint_account_state.init_extension::<TransferFeeConfig>(true).unwrap();
+                transfer_fee_config.older_transfer_fee = transfer_fee;
+                transfer_fee_config.newer_transfer_fee = transfer_fee;
+            },
+            ExtensionType::NonTransferable => {
+                mint_account_state.init_extension::<NonTransferable>(true).unwrap();
+            },
+            ExtensionType::TransferHook => {
+                mint_account_state.init_extension::<TransferHook>(true).unwrap();
+            },
+            _ => panic!("unsupported extension {:?}", extension_type)
+        };
+    };
+
+    mint_account_state.base = mint_account;
+    mint_account_state.pack_base();
+    mint_account_state.init_account_type().unwrap();
+
+    mint_account_data
+}
+
+// Token accounts of a transfer-fee mint need the "TransferFeeAmount" extension
+fn pack_token_2022_token_account(
+    token_account: Token2022Account,
+    extension_types: &[ExtensionType]
+) -> Vec<u8> {
+    let mut token_account_data = vec![
+        0u8;
+        ExtensionType::try_calculate_account_len::<Token2022Account>(extension_types).unwrap()
+    ];
+    let mut token_account_state = StateWithExtensionsMut::<Token2022Account>::unpack_uninitialized(
+        &mut token_account_data
+    ).unwrap();
+
+    for extension_type in extension_types {
+        match extension_type {
+            ExtensionType::TransferFeeAmount => {
+                token_account_state.init_extension::<TransferFeeAmount>(true).unwrap();
+            },
+            _ => panic!("unsupported extension {:?}", extension_type)
+        };
+    };
+
+    token_account_state.base = token_account;
+    token_account_state.pack_base();
+    token_account_state.init_account_type().unwrap();
+
+    token_account_data
+}
 ////////////////////////////////////// Helper-Functions ///////////////////////////////
 
 ////////////////////////////////////// Config Instructions
@@ -3104,11 +3244,9 @@ async fn test_change_max_lottery_description_length() {
     }
     // success
 }
-////////////////////////////////////// Config Instructions
 
-////////////////////////////////////// Lottery Instructions
 #[tokio::test]
-async fn test_create_and_initialize_lottery_account() {
+async fn test_change_vrf_oracle_authority() {
     let lottery_program_id = LOTTERY_PROGRAM_ID;
     let mut pt = setup_program_test(lottery_program_id);
 
@@ -3120,15 +3258,16 @@ async fn test_create_and_initialize_lottery_account() {
         &lottery_program_id
     );
 
+    let config_authority = Keypair::new();
     let config_account = Config {
         discriminator: Config::get_discriminator(),
         canonical_bump: config_account_pda.1,
-        lottery_creation_fee: 5_000000, // 5 USDC
-        maximum_number_of_winners: 10,
-        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
-        maximum_time_for_lottery_account: 1000,
-        minimum_tickets_to_be_sold_in_lottery: 20,
-        max_lottery_description_bytes: 10,
+        authority: config_authority.pubkey(),
+        pyth_price_feed_ids: [
+            "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d".to_string(),
+            "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43".to_string(),
+            "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace".to_string()
+        ],
         ..Config::default()
     };
     let config_account_data = config_account.try_to_vec().unwrap();
@@ -3146,201 +3285,19 @@ async fn test_create_and_initialize_lottery_account() {
     );
     //////////////////////? add config account
 
-    //////////////////////? add USDC and Arbitrary mint accounts
-    // 1. USDC mint account
-    let usdc_mint_account = MintAccount {
-        supply: 1000_000000,
-        decimals: 6,
-        is_initialized: true,
-        ..MintAccount::default()
-    };
-
-    let mut usdc_mint_account_data: [u8; MintAccount::LEN] = [0; MintAccount::LEN];
-    MintAccount::pack(
-        usdc_mint_account,
-        usdc_mint_account_data.as_mut_slice()
-    ).unwrap();
-
-    let uscd_mint_solana_account = SolanaAccount {
-        lamports: sol_to_lamports(0.01),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        data: usdc_mint_account_data.to_vec(),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        config_account.usdc_mint_account,
-        uscd_mint_solana_account
-    );
-
-    // 2. Arbitrary mint account
-    let arbitrary_mint_account_addr = Pubkey::new_from_array([3; 32]);
-    let arbitrary_mint_account = MintAccount {
-        decimals: 3,
-        supply: 100_000,
-        is_initialized: true,
-        ..MintAccount::default()
-    };
-
-    let mut arbitrary_mint_account_data: [u8; MintAccount::LEN] = [0; MintAccount::LEN];
-    MintAccount::pack(
-        arbitrary_mint_account,
-        arbitrary_mint_account_data.as_mut_slice()
-    ).unwrap();
-
-    let arbitrary_mint_solana_account = SolanaAccount {
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(0.01),
-        data: arbitrary_mint_account_data.to_vec(),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        arbitrary_mint_account_addr,
-        arbitrary_mint_solana_account
-    );
-    //////////////////////? add USDC and Arbitrary mint accounts
-
-    //////////////////////? add token accounts
-    // add funding account for rents, usdc_token_account and arbitrary_token_account
-    // 1. add funding account
-    let funding_account = Keypair::new();
-
-    let funding_solana_account = SolanaAccount {
-        owner: SYSTEM_PROGRAM_ID,
-        lamports: sol_to_lamports(10.0),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        funding_account.pubkey(),
-    funding_solana_account
-    );
-    
-    // 2. add funding usdc token account
-    let funding_usdc_token_account_pubkey = Pubkey::new_from_array([4; 32]);
-    let funding_usdc_token_account = TokenAccount {
-        mint: config_account.usdc_mint_account,
-        owner: funding_account.pubkey(),
-        amount: 1000_000000,
-        state: TokenAccountState::Initialized,
-        ..TokenAccount::default()
-    };
-
-    let mut funding_usdc_token_account_data: [u8; TokenAccount::LEN] = [0; TokenAccount::LEN];
-    TokenAccount::pack(
-        funding_usdc_token_account,
-        funding_usdc_token_account_data.as_mut_slice()
-    ).unwrap();
-
-    let funding_usdc_token_solana_account = SolanaAccount {
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        data: funding_usdc_token_account_data.to_vec(),
-        lamports: sol_to_lamports(0.01),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        funding_usdc_token_account_pubkey,
-        funding_usdc_token_solana_account
-    );
-
-    // 3. add funding arbitrary token account
-    let funding_arbitrary_token_account_pubkey = Pubkey::new_from_array([5; 32]);
-    let funding_arbitrary_token_account = TokenAccount {
-        mint: arbitrary_mint_account_addr,
-        owner: funding_account.pubkey(),
-        amount: 100_000,
-        state: TokenAccountState::Initialized,
-        ..TokenAccount::default()
-    };
-
-    let mut funding_arbitrary_token_account_data: [u8; TokenAccount::LEN] = [0; TokenAccount::LEN];
-    TokenAccount::pack(
-        funding_arbitrary_token_account,
-        funding_arbitrary_token_account_data.as_mut_slice()
-    ).unwrap();
-
-    let funding_arbitrary_token_solana_account = SolanaAccount {
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(0.01),
-        data: funding_arbitrary_token_account_data.to_vec(),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        funding_arbitrary_token_account_pubkey,
-        funding_arbitrary_token_solana_account
-    );
-
-    // add funding account for rents, usdc_token_account and arbitrary_token_account
-    //////////////////////? add token accounts
-    
-    //////////////////////? add lottery_account authority
-    let lottery_authority_account = Keypair::new();
-    pt.add_account(
-        lottery_authority_account.pubkey(),
-        SolanaAccount {
-            owner: SYSTEM_PROGRAM_ID,
-            lamports: sol_to_lamports(1.0),
-            ..SolanaAccount::default()
-        }
-    );
-    //////////////////////? add lottery_account authority
-    
-    //? Start program_test_context
     let mut ptc = pt.start_with_context().await;
 
-    // faliure - invalid lottery's description length
+    // failure - invalid config authority
     {
-        let lottery_description = String::from("PooriaGG.Solana");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 550);
-
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 1000_i64;
-        let ending_time = 1350_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+        let fake_config_authority = Keypair::new();
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::ChangeVrfOracleAuthority,
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(fake_config_authority.pubkey(), true),
+                AccountMeta::new_readonly(Pubkey::new_from_array([55; 32]), false)
+            ]
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -3348,14 +3305,13 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &fake_config_authority
             ],
             ptc.last_blockhash
         );
 
-        let error = ptc.
-            banks_client
+        let error = ptc
+            .banks_client
             .process_transaction(tx)
             .await
             .unwrap_err()
@@ -3366,63 +3322,29 @@ async fn test_create_and_initialize_lottery_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::MaxLotteryDescriptionBytesExceeded as u32
+                    LotteryError::InvalidConfigAuthority as u32
                 )
             )
         );
     }
-    // faliure - invalid lottery's description length
+    // failure - invalid config authority
 
-    // faliure - invalid max time for lottery
+    // success
     {
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 550);
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 1000_i64;
-        let ending_time = 2350_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+        let new_vrf_oracle_authority = Pubkey::new_from_array([55; 32]);
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::ChangeVrfOracleAuthority,
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(config_authority.pubkey(), true),
+                AccountMeta::new_readonly(new_vrf_oracle_authority, false)
+            ]
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -3430,81 +3352,90 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &config_authority
             ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(config_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Config { vrf_oracle_authority, .. } = Config::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
         assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::MaximumTimeExceed as u32
-                )
-            )
+            vrf_oracle_authority,
+            new_vrf_oracle_authority,
+            "invalid new vrf oracle authority."
         );
     }
-    // faliure - invalid max time for lottery
+    // success
+}
 
-    // faliure - invalid seeds
-    {
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_accountt",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 550);
+#[tokio::test]
+async fn test_change_sol_treasury() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
 
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 1000_i64;
-        let ending_time = 1350_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_authority = Keypair::new();
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        authority: config_authority.pubkey(),
+        pyth_price_feed_ids: [
+            "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d".to_string(),
+            "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43".to_string(),
+            "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace".to_string()
+        ],
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid config authority
+    {
+        let fake_config_authority = Keypair::new();
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::ChangeSolTreasury,
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(fake_config_authority.pubkey(), true),
+                AccountMeta::new_readonly(Pubkey::new_from_array([55; 32]), false)
+            ]
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -3512,8 +3443,7 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &fake_config_authority
             ],
             ptc.last_blockhash
         );
@@ -3529,62 +3459,30 @@ async fn test_create_and_initialize_lottery_account() {
             error,
             TransactionError::InstructionError(
                 0,
-                InstructionError::InvalidSeeds
+                InstructionError::Custom(
+                    LotteryError::InvalidConfigAuthority as u32
+                )
             )
         );
     }
-    // faliure - invalid seeds
+    // failure - invalid config authority
 
-    // failure - invalid winners count
+    // success
     {
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 550);
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-        let fund_amount = 100_u64;
-        let winners_count = 100_u8;
-        let starting_time = 1000_i64;
-        let ending_time = 1350_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+        let new_sol_treasury = Pubkey::new_from_array([55; 32]);
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::ChangeSolTreasury,
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(config_authority.pubkey(), true),
+                AccountMeta::new_readonly(new_sol_treasury, false)
+            ]
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -3592,81 +3490,97 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &config_authority
             ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(config_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Config { sol_treasury, .. } = Config::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
         assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::InvalidWinnersAmount as u32
-                )
-            )
+            sol_treasury,
+            new_sol_treasury,
+            "invalid new sol treasury."
         );
     }
-    // failure - invalid winners count
+    // success
+}
 
-    // failure - invalid min tickets required to be sold 
-    {
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 550);
+#[tokio::test]
+async fn test_migrate_config_account() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
 
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 1000_i64;
-        let ending_time = 1350_i64;
-        let minimum_tickets_amount_required_to_be_sold = 1u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+    //////////////////////? add legacy config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_authority = Keypair::new();
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        authority: config_authority.pubkey(),
+        lottery_creation_fee: 5_000000,
+        treasury: Pubkey::new_from_array([9; 32]),
+        pyth_price_feed_ids: [
+            "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d".to_string(),
+            "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43".to_string(),
+            "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace".to_string()
+        ],
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    // the layout before "vrf_oracle_authority", "lottery_cancellation_penalty", "prize_claim_window",
+    // "unclaimed_prizes_receiver" & "sol_treasury" -> 32 + 8 + 4 + 1 + 32 bytes shorter
+    let legacy_config_account_data = config_account_data[..config_account_data.len() - 77].to_vec();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: legacy_config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add legacy config account
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid config authority
+    {
+        let fake_config_authority = Keypair::new();
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::MigrateConfigAccount,
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(fake_config_authority.pubkey(), true),
+                AccountMeta::new(ptc.payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false)
+            ]
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -3674,8 +3588,7 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &fake_config_authority
             ],
             ptc.last_blockhash
         );
@@ -3692,64 +3605,95 @@ async fn test_create_and_initialize_lottery_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidMinimumAmountTickets as u32
+                    LotteryError::InvalidConfigAuthority as u32
                 )
             )
         );
     }
-    // failure - invalid min tickets required to be sold 
+    // failure - invalid config authority
 
-    // failure - invalid starting and ending time
+    // success
     {
-        // 1. starting and ending time mismatched
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::MigrateConfigAccount,
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(config_authority.pubkey(), true),
+                AccountMeta::new(ptc.payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false)
+            ]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
+                &ptc.payer,
+                &config_authority
             ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 550);
+            ptc.last_blockhash
+        );
 
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 1500_i64;
-        let ending_time = 1350_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, lamports, .. } = ptc
+            .banks_client
+            .get_account(config_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let rent = ptc.banks_client.get_rent().await.unwrap();
+
+        assert_eq!(
+            data.len(),
+            config_account_data.len(),
+            "invalid config account size."
+        );
+
+        assert_eq!(
+            lamports,
+            rent.minimum_balance(data.len()),
+            "invalid config account balance."
+        );
+
+        assert_eq!(
+            Config::deserialize(&mut &data[..]).unwrap(),
+            Config {
+                sol_treasury: config_authority.pubkey(),
+                ..config_account
+            },
+            "invalid migrated config account."
+        );
+    }
+    // success
+
+    // failure - config account is already migrated
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::MigrateConfigAccount,
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(config_authority.pubkey(), true),
+                AccountMeta::new(ptc.payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false)
+            ]
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -3757,8 +3701,7 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &config_authority
             ],
             ptc.last_blockhash
         );
@@ -3775,60 +3718,63 @@ async fn test_create_and_initialize_lottery_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidTime as u32
+                    LotteryError::ConfigAccountAlreadyMigrated as u32
                 )
             )
         );
+    }
+    // failure - config account is already migrated
+}
 
-        // 2. starting and current_time does not match
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 550);
+#[tokio::test]
+async fn test_change_lottery_cancellation_penalty() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
 
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 450_i64;
-        let ending_time = 800_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_authority = Keypair::new();
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        authority: config_authority.pubkey(),
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid config authority
+    {
+        let fake_config_authority = Keypair::new();
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::ChangeLotteryCancellationPenalty {
+                new_penalty: 3_000000
+            },
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(fake_config_authority.pubkey(), true)
+            ]
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -3836,8 +3782,7 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &fake_config_authority
             ],
             ptc.last_blockhash
         );
@@ -3854,64 +3799,29 @@ async fn test_create_and_initialize_lottery_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidTime as u32
+                    LotteryError::InvalidConfigAuthority as u32
                 )
             )
         );
     }
-    // failure - invalid starting and ending time
+    // failure - invalid config authority
 
-    // failure - invalid fund amount
+    // success
     {
-        // 1. invalid fund_amount
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 150);
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-        let fund_amount = 0_u64;
-        let winners_count = 1_u8;
-        let starting_time = 250_i64;
-        let ending_time = 500_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::ChangeLotteryCancellationPenalty {
+                new_penalty: 3_000000
+            },
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(config_authority.pubkey(), true)
+            ]
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -3919,78 +3829,87 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &config_authority
             ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(config_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Config { lottery_cancellation_penalty, .. } = Config::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
         assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::InvalidFundAmount as u32
-                )
-            )
+            lottery_cancellation_penalty,
+            3_000000,
+            "invalid new lottery cancellation penalty."
         );
+    }
+    // success
+}
 
-        // 2. fund_amount & winners_count mismatched
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 150);
+#[tokio::test]
+async fn test_change_unclaimed_prizes_policy() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
 
-        let fund_amount = 100_u64;
-        let winners_count = 3_u8;
-        let starting_time = 250_i64;
-        let ending_time = 500_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_authority = Keypair::new();
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        authority: config_authority.pubkey(),
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid config authority
+    {
+        let fake_config_authority = Keypair::new();
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::ChangeUnclaimedPrizesPolicy {
+                prize_claim_window: 7_776_000,
+                unclaimed_prizes_receiver: UnclaimedPrizesReceiver::Treasury
+            },
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(fake_config_authority.pubkey(), true)
+            ]
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -3998,8 +3917,7 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &fake_config_authority
             ],
             ptc.last_blockhash
         );
@@ -4016,61 +3934,12826 @@ async fn test_create_and_initialize_lottery_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::WinnersAndFundAmountMismatch as u32
+                    LotteryError::InvalidConfigAuthority as u32
                 )
             )
         );
     }
-    // failure - invalid fund amount
+    // failure - invalid config authority
 
-    // failure - invalid lottery's ata for usdc and arbitrary token accounts
+    // success
     {
-        // 1. invalid usdc ata
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = Instruction::new_with_borsh(
+            lottery_program_id,
+            &Instructions::ChangeUnclaimedPrizesPolicy {
+                prize_claim_window: 7_776_000, // 90 days
+                unclaimed_prizes_receiver: UnclaimedPrizesReceiver::Treasury
+            },
+            vec![
+                AccountMeta::new(config_account_pda.0, false),
+                AccountMeta::new_readonly(config_authority.pubkey(), true)
+            ]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &config_authority
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(config_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Config { prize_claim_window, unclaimed_prizes_receiver, .. } = Config::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(prize_claim_window, 7_776_000, "invalid new prize claim window.");
+        assert_eq!(
+            unclaimed_prizes_receiver,
+            UnclaimedPrizesReceiver::Treasury,
+            "invalid new unclaimed prizes receiver."
+        );
+    }
+    // success
+}
+////////////////////////////////////// Config Instructions
+
+////////////////////////////////////// Lottery Instructions
+#[tokio::test]
+async fn test_create_and_initialize_lottery_account() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        lottery_creation_fee: 5_000000, // 5 USDC
+        maximum_number_of_winners: 10,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        maximum_time_for_lottery_account: 1000,
+        minimum_tickets_to_be_sold_in_lottery: 20,
+        max_lottery_description_bytes: 10,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add USDC and Arbitrary mint accounts
+    // 1. USDC mint account
+    let usdc_mint_account = MintAccount {
+        supply: 1000_000000,
+        decimals: 6,
+        is_initialized: true,
+        ..MintAccount::default()
+    };
+
+    let mut usdc_mint_account_data: [u8; MintAccount::LEN] = [0; MintAccount::LEN];
+    MintAccount::pack(
+        usdc_mint_account,
+        usdc_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    let uscd_mint_solana_account = SolanaAccount {
+        lamports: sol_to_lamports(0.01),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        data: usdc_mint_account_data.to_vec(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account.usdc_mint_account,
+        uscd_mint_solana_account
+    );
+
+    // 2. Arbitrary mint account
+    let arbitrary_mint_account_addr = Pubkey::new_from_array([3; 32]);
+    let arbitrary_mint_account = MintAccount {
+        decimals: 3,
+        supply: 100_000,
+        is_initialized: true,
+        ..MintAccount::default()
+    };
+
+    let mut arbitrary_mint_account_data: [u8; MintAccount::LEN] = [0; MintAccount::LEN];
+    MintAccount::pack(
+        arbitrary_mint_account,
+        arbitrary_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    let arbitrary_mint_solana_account = SolanaAccount {
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(0.01),
+        data: arbitrary_mint_account_data.to_vec(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        arbitrary_mint_account_addr,
+        arbitrary_mint_solana_account
+    );
+    //////////////////////? add USDC and Arbitrary mint accounts
+
+    //////////////////////? add token accounts
+    // add funding account for rents, usdc_token_account and arbitrary_token_account
+    // 1. add funding account
+    let funding_account = Keypair::new();
+
+    let funding_solana_account = SolanaAccount {
+        owner: SYSTEM_PROGRAM_ID,
+        lamports: sol_to_lamports(10.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        funding_account.pubkey(),
+    funding_solana_account
+    );
+    
+    // 2. add funding usdc token account
+    let funding_usdc_token_account_pubkey = Pubkey::new_from_array([4; 32]);
+    let funding_usdc_token_account = TokenAccount {
+        mint: config_account.usdc_mint_account,
+        owner: funding_account.pubkey(),
+        amount: 1000_000000,
+        state: TokenAccountState::Initialized,
+        ..TokenAccount::default()
+    };
+
+    let mut funding_usdc_token_account_data: [u8; TokenAccount::LEN] = [0; TokenAccount::LEN];
+    TokenAccount::pack(
+        funding_usdc_token_account,
+        funding_usdc_token_account_data.as_mut_slice()
+    ).unwrap();
+
+    let funding_usdc_token_solana_account = SolanaAccount {
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        data: funding_usdc_token_account_data.to_vec(),
+        lamports: sol_to_lamports(0.01),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        funding_usdc_token_account_pubkey,
+        funding_usdc_token_solana_account
+    );
+
+    // 3. add funding arbitrary token account
+    let funding_arbitrary_token_account_pubkey = Pubkey::new_from_array([5; 32]);
+    let funding_arbitrary_token_account = TokenAccount {
+        mint: arbitrary_mint_account_addr,
+        owner: funding_account.pubkey(),
+        amount: 100_000,
+        state: TokenAccountState::Initialized,
+        ..TokenAccount::default()
+    };
+
+    let mut funding_arbitrary_token_account_data: [u8; TokenAccount::LEN] = [0; TokenAccount::LEN];
+    TokenAccount::pack(
+        funding_arbitrary_token_account,
+        funding_arbitrary_token_account_data.as_mut_slice()
+    ).unwrap();
+
+    let funding_arbitrary_token_solana_account = SolanaAccount {
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(0.01),
+        data: funding_arbitrary_token_account_data.to_vec(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        funding_arbitrary_token_account_pubkey,
+        funding_arbitrary_token_solana_account
+    );
+
+    // add funding account for rents, usdc_token_account and arbitrary_token_account
+    //////////////////////? add token accounts
+    
+    //////////////////////? add lottery_account authority
+    let lottery_authority_account = Keypair::new();
+    pt.add_account(
+        lottery_authority_account.pubkey(),
+        SolanaAccount {
+            owner: SYSTEM_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery_account authority
+
+    //////////////////////? add token-2022 arbitrary mints (transfer hook & 1% transfer fee) & the funding transfer-fee token account
+    let transfer_hook_mint_account_addr = Pubkey::new_unique();
+    pt.add_account(
+        transfer_hook_mint_account_addr,
+        SolanaAccount {
+            data: pack_token_2022_mint_account(
+                Token2022MintAccount {
+                    decimals: 3,
+                    supply: 100_000,
+                    is_initialized: true,
+                    ..Token2022MintAccount::default()
+                },
+                &[ ExtensionType::TransferHook ],
+                0
+            ),
+            owner: TOKEN_2022_PROGRAM_ID,
+            lamports: sol_to_lamports(0.01),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let transfer_fee_mint_account_addr = Pubkey::new_unique();
+    pt.add_account(
+        transfer_fee_mint_account_addr,
+        SolanaAccount {
+            data: pack_token_2022_mint_account(
+                Token2022MintAccount {
+                    decimals: 3,
+                    supply: 100_000,
+                    is_initialized: true,
+                    ..Token2022MintAccount::default()
+                },
+                &[ ExtensionType::TransferFeeConfig ],
+                100
+            ),
+            owner: TOKEN_2022_PROGRAM_ID,
+            lamports: sol_to_lamports(0.01),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let funding_transfer_fee_token_account_pubkey = Pubkey::new_unique();
+    pt.add_account(
+        funding_transfer_fee_token_account_pubkey,
+        SolanaAccount {
+            data: pack_token_2022_token_account(
+                Token2022Account {
+                    mint: transfer_fee_mint_account_addr,
+                    owner: funding_account.pubkey(),
+                    amount: 100_000,
+                    state: Token2022AccountState::Initialized,
+                    ..Token2022Account::default()
+                },
+                &[ ExtensionType::TransferFeeAmount ]
+            ),
+            owner: TOKEN_2022_PROGRAM_ID,
+            lamports: sol_to_lamports(0.01),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add token-2022 arbitrary mints (transfer hook & 1% transfer fee) & the funding transfer-fee token account
+    
+    //? Start program_test_context
+    let mut ptc = pt.start_with_context().await;
+
+    // faliure - invalid lottery's description length
+    {
+        let lottery_description = String::from("PooriaGG.Solana");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 550);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 1000_i64;
+        let ending_time = 1350_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc.
+            banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::MaxLotteryDescriptionBytesExceeded as u32
+                )
+            )
+        );
+    }
+    // faliure - invalid lottery's description length
+
+    // faliure - invalid max time for lottery
+    {
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 550);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 1000_i64;
+        let ending_time = 2350_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::MaximumTimeExceed as u32
+                )
+            )
+        );
+    }
+    // faliure - invalid max time for lottery
+
+    // faliure - invalid seeds
+    {
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_accountt",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 550);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 1000_i64;
+        let ending_time = 1350_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::InvalidSeeds
+            )
+        );
+    }
+    // faliure - invalid seeds
+
+    // failure - invalid winners count
+    {
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 550);
+
+        let fund_amount = 100_u64;
+        let winners_count = 100_u8;
+        let starting_time = 1000_i64;
+        let ending_time = 1350_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidWinnersAmount as u32
+                )
+            )
+        );
+    }
+    // failure - invalid winners count
+
+    // failure - invalid min tickets required to be sold 
+    {
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 550);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 1000_i64;
+        let ending_time = 1350_i64;
+        let minimum_tickets_amount_required_to_be_sold = 1u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidMinimumAmountTickets as u32
+                )
+            )
+        );
+    }
+    // failure - invalid min tickets required to be sold 
+
+    // failure - invalid starting and ending time
+    {
+        // 1. starting and ending time mismatched
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 550);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 1500_i64;
+        let ending_time = 1350_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidTime as u32
+                )
+            )
+        );
+
+        // 2. starting and current_time does not match
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 550);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 450_i64;
+        let ending_time = 800_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidTime as u32
+                )
+            )
+        );
+    }
+    // failure - invalid starting and ending time
+
+    // failure - invalid fund amount
+    {
+        // 1. invalid fund_amount
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 150);
+
+        let fund_amount = 0_u64;
+        let winners_count = 1_u8;
+        let starting_time = 250_i64;
+        let ending_time = 500_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidFundAmount as u32
+                )
+            )
+        );
+
+        // 2. fund_amount & winners_count mismatched
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 150);
+
+        let fund_amount = 100_u64;
+        let winners_count = 3_u8;
+        let starting_time = 250_i64;
+        let ending_time = 500_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::WinnersAndFundAmountMismatch as u32
+                )
+            )
+        );
+    }
+    // failure - invalid fund amount
+
+    // failure - invalid prize table
+    {
+        // shares don't add up to 10_000 bps
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 150);
+
+        let fund_amount = 100_u64;
+        let winners_count = 3_u8;
+        let starting_time = 250_i64;
+        let ending_time = 500_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = Some(vec![ 5_000, 3_000, 1_000 ]);
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidPrizeTable as u32
+                )
+            )
+        );
+    }
+    // failure - invalid prize table
+
+    // failure - invalid lottery's ata for usdc and arbitrary token accounts
+    {
+        // 1. invalid usdc ata
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 150);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 250_i64;
+        let ending_time = 500_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            Pubkey::new_unique(),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryAssociatedUsdcTokenAccount as u32
+                )
+            )
+        );
+        
+        // 2. invalid arbitrary ata
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 150);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 250_i64;
+        let ending_time = 500_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            Pubkey::new_unique(),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount as u32
+                )
+            )
+        );
+    }
+    // failure - invalid lottery's ata for usdc and arbitrary token accounts
+
+    // faliure - invalid usdc mint account
+    {
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 150);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 250_i64;
+        let ending_time = 500_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::PythPriceFeeds;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            Pubkey::new_unique(),
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidUsdcMintAccount as u32
+                )
+            )
+        );
+    }
+    // faliure - invalid usdc mint account
+    
+    // failure - vrf oracle lottery without the creator's commitment
+    {
+        let lottery_description = String::from("AABBCD");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 550);
+
+        let fund_amount = 100_u64;
+        let winners_count = 1_u8;
+        let starting_time = 1000_i64;
+        let ending_time = 1350_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = None;
+        let randomness_source = RandomnessSource::VrfOracle;
+        let prize_table = None;
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table,
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::RandomnessCommitmentNotFound as u32
+                )
+            )
+        );
+    }
+    // failure - vrf oracle lottery without the creator's commitment
+
+    // success
+    {
+        let lottery_description = String::from("AABBCC");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        
+        change_clock_sysvar(&ptc, 550);
+
+        let fund_amount = 100_u64;
+        let winners_count = 3_u8;
+        let starting_time = 1000_i64;
+        let ending_time = 1350_i64;
+        let minimum_tickets_amount_required_to_be_sold = 25u32;
+        let ticket_price = 1_000000u64;
+        let maximum_number_of_tickets_per_user = None;
+        let randomness_commitment = Some(solana_sdk::hash::hash(&[7; 32]).to_bytes());
+        let randomness_source = RandomnessSource::VrfOracle;
+        let prize_table = Some(vec![ 5_000, 3_000, 2_000 ]); // fund_amount % winners_count != 0 is fine with a prize table
+        let nft_prizes_accounts = None;
+        let sol_prize_amount = 0;
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            fund_amount,
+            winners_count,
+            starting_time,
+            ending_time,
+            minimum_tickets_amount_required_to_be_sold,
+            ticket_price,
+            maximum_number_of_tickets_per_user,
+            randomness_commitment,
+            randomness_source,
+            prize_table.clone(),
+            nft_prizes_accounts,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: lottery_account_data, owner: lottery_account_owner, .. } = ptc
+            .banks_client
+            .get_account(lottery_account)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(
+            lottery_account_owner,
+            lottery_program_id,
+            "Invalid lottery-account's owner"
+        );
+
+        assert_eq!(
+            starting_time,
+            lottery.starting_time,
+            "Invalid starting-time."
+        );
+
+        assert_eq!(
+            ending_time,
+            lottery.ending_time,
+            "Invalid ending-time."
+        );
+
+        assert_eq!(
+            fund_amount,
+            lottery.fund_amount,
+            "Invalid fund-amount."
+        );
+
+        assert_eq!(
+            winners_count,
+            lottery.winners_count,
+            "Invalid winners-count."
+        );
+
+        assert_eq!(
+            maximum_number_of_tickets_per_user,
+            lottery.maximum_number_of_tickets_per_user,
+            "Invalid max number of tickets per user."
+        );
+
+        assert_eq!(
+            randomness_commitment,
+            lottery.randomness_commitment,
+            "Invalid randomness commitment."
+        );
+
+        assert_eq!(
+            lottery.is_secret_revealed,
+            bool::default(),
+            "invalid is_secret_revealed flag."
+        );
+
+        assert_eq!(
+            lottery.randomness_source,
+            randomness_source,
+            "invalid randomness source."
+        );
+
+        assert_eq!(
+            lottery.prize_table,
+            prize_table,
+            "invalid prize table."
+        );
+
+        assert_eq!(
+            ticket_price,
+            lottery.ticket_price,
+            "Invalid ticket price."
+        );
+
+        assert_eq!(
+            minimum_tickets_amount_required_to_be_sold,
+            lottery.minimum_tickets_amount_required_to_be_sold,
+            "Invalid min tickets amount required to be sold."
+        );
+
+        assert_eq!(
+            Lottery::get_discriminator(),
+            lottery.discriminator,
+            "invalid lottery account discriminator."
+        );
+
+        assert_eq!(
+            lottery.is_creator_withdrawed_when_lottery_was_failed,
+            bool::default(),
+            "invalid is_creator_withdrawed_when_lottery_was_failed flag."
+        );
+
+        assert_eq!(
+            lottery.is_creator_withdrawed_when_lottery_was_successful,
+            bool::default(),
+            "invalid is_creator_withdrawed_when_lottery_was_successful."
+        );
+
+        assert_eq!(
+            lottery.is_ended_successfuly,
+            bool::default(),
+            "invalid is_ended_successfuly flag."
+        );
+
+        assert_eq!(
+            lottery.is_protocol_fee_claimed,
+            bool::default(),
+            "invalid is_protocol_fee_claimed flag."
+        );
+
+        assert_eq!(
+            lottery.protocol_fee,
+            u64::default(),
+            "invalid protocol fee in lottery_account."
+        );
+
+        let lottery_usdc_associated_token_account = ptc.banks_client.get_account(
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            )
+        ).await.unwrap().unwrap();
+
+        assert_eq!(
+            lottery_usdc_associated_token_account.owner,
+            TOKEN_STANDARD_PROGRAM_ID,
+            "invalid owner for usdc token account."
+        );
+
+        let usdc_token_account = TokenAccount::unpack(
+            &mut &lottery_usdc_associated_token_account.data
+        ).unwrap();
+
+        assert_eq!(
+            usdc_token_account.state,
+            TokenAccountState::Initialized,
+            "invalid usdc-token-account's state."
+        );
+
+        assert_eq!(
+            usdc_token_account.mint,
+            config_account.usdc_mint_account,
+            "invalid mint account for usdc-token-account."
+        );
+
+        assert_eq!(
+            usdc_token_account.amount,
+            config_account.lottery_creation_fee,
+            "invalid usdc token balance."
+        );
+
+        assert_eq!(
+            usdc_token_account.owner,
+            lottery_account,
+            "invalid owner for usdc token account."
+        );
+
+        let lottery_arbitrary_associated_token_account = ptc.banks_client.get_account(
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            )
+        ).await.unwrap().unwrap();
+
+        assert_eq!(
+            lottery_arbitrary_associated_token_account.owner,
+            TOKEN_STANDARD_PROGRAM_ID,
+            "invalid owner for arbitrary token account."
+        );
+
+        let arbitrary_token_account = TokenAccount::unpack(
+            &mut &lottery_arbitrary_associated_token_account.data
+        ).unwrap();
+
+        assert_eq!(
+            arbitrary_token_account.state,
+            TokenAccountState::Initialized,
+            "invalid arbitrary-token-account's state."
+        );
+
+        assert_eq!(
+            arbitrary_token_account.mint,
+            arbitrary_mint_account_addr,
+            "invalid mint account for arbitrary-token-account."
+        );
+
+        assert_eq!(
+            arbitrary_token_account.amount,
+            fund_amount,
+            "invalid arbitrary token balance."
+        );
+
+        assert_eq!(
+            arbitrary_token_account.owner,
+            lottery_account,
+            "invalid owner for arbitrary token account."
+        );
+    }
+    // success
+
+    // nft prizes
+    {
+        // 1st & 2nd ranks nfts, a 3rd mint with a supply of 2 isn't an nft
+        let nft_prize_mint_accounts = [
+            (Pubkey::new_unique(), 1u64),
+            (Pubkey::new_unique(), 1u64),
+            (Pubkey::new_unique(), 2u64)
+        ];
+        let funding_nft_token_accounts = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique()
+        ];
+
+        for (
+            (nft_prize_mint_account, supply),
+            funding_nft_token_account
+        ) in nft_prize_mint_accounts.iter().zip(funding_nft_token_accounts.iter()) {
+            let mut nft_prize_mint_account_data = [0u8; MintAccount::LEN];
+            MintAccount::pack(
+                MintAccount {
+                    supply: *supply,
+                    decimals: 0,
+                    is_initialized: true,
+                    ..MintAccount::default()
+                },
+                nft_prize_mint_account_data.as_mut_slice()
+            ).unwrap();
+
+            ptc.set_account(
+                nft_prize_mint_account,
+                &SolanaSharedDataAccount::from(
+                    SolanaAccount {
+                        data: nft_prize_mint_account_data.to_vec(),
+                        owner: TOKEN_STANDARD_PROGRAM_ID,
+                        lamports: sol_to_lamports(0.01),
+                        ..SolanaAccount::default()
+                    }
+                )
+            );
+
+            let mut funding_nft_token_account_data = [0u8; TokenAccount::LEN];
+            TokenAccount::pack(
+                TokenAccount {
+                    mint: *nft_prize_mint_account,
+                    owner: funding_account.pubkey(),
+                    amount: *supply,
+                    state: TokenAccountState::Initialized,
+                    ..TokenAccount::default()
+                },
+                funding_nft_token_account_data.as_mut_slice()
+            ).unwrap();
+
+            ptc.set_account(
+                funding_nft_token_account,
+                &SolanaSharedDataAccount::from(
+                    SolanaAccount {
+                        data: funding_nft_token_account_data.to_vec(),
+                        owner: TOKEN_STANDARD_PROGRAM_ID,
+                        lamports: sol_to_lamports(0.01),
+                        ..SolanaAccount::default()
+                    }
+                )
+            );
+        };
+
+        // failure - invalid nft prize mint
+        {
+            let lottery_description = String::from("NFT");
+            let lottery_account = Pubkey::find_program_address(
+                &[
+                    b"lottery_account",
+                    lottery_authority_account.pubkey().to_bytes().as_slice(),
+                    get_lottery_literal_seed(&lottery_description).as_slice()
+                ],
+                &lottery_program_id
+            ).0;
+
+            ptc
+                .get_new_latest_blockhash()
+                .await
+                .unwrap();
+            change_clock_sysvar(&ptc, 550);
+
+            let nft_prizes_accounts = Some(
+                [ 0, 2 ]
+                    .into_iter()
+                    .map(|index| (
+                        nft_prize_mint_accounts[index].0,
+                        get_associated_token_address(
+                            &lottery_account,
+                            &nft_prize_mint_accounts[index].0
+                        ),
+                        funding_nft_token_accounts[index]
+                    ))
+                    .collect::<Vec<(Pubkey, Pubkey, Pubkey)>>()
+            );
+
+            let instruction = instruction_create_and_initialize_lottery_account(
+                lottery_account,
+                lottery_authority_account.pubkey(),
+                funding_account.pubkey(),
+                config_account.usdc_mint_account,
+                get_associated_token_address(
+                    &lottery_account,
+                    &config_account.usdc_mint_account
+                ),
+                funding_usdc_token_account_pubkey,
+                arbitrary_mint_account_addr,
+                get_associated_token_address(
+                    &lottery_account,
+                    &arbitrary_mint_account_addr
+                ),
+                funding_arbitrary_token_account_pubkey,
+                TOKEN_STANDARD_PROGRAM_ID,
+                spl_associated_token_account::ID,
+                SYSTEM_PROGRAM_ID,
+                config_account_pda.0,
+                0,
+                2,
+                1000,
+                1350,
+                25,
+                1_000000,
+                None,
+                None,
+                RandomnessSource::PythPriceFeeds,
+                None,
+                nft_prizes_accounts,
+                0,
+                None,
+                None,
+                None,
+                lottery_description
+            );
+
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ],
+                Some(&ptc.payer.pubkey()),
+                &[
+                    &ptc.payer,
+                    &funding_account,
+                    &lottery_authority_account
+                ],
+                ptc.last_blockhash
+            );
+
+            let error = ptc
+                .banks_client
+                .process_transaction(tx)
+                .await
+                .unwrap_err()
+                .unwrap();
+
+            assert_eq!(
+                error,
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(
+                        LotteryError::InvalidNftPrizeMintAccount as u32
+                    )
+                )
+            );
+        }
+        // failure - invalid nft prize mint
+
+        // success - nft prizes only
+        {
+            let lottery_description = String::from("NFT");
+            let lottery_account = Pubkey::find_program_address(
+                &[
+                    b"lottery_account",
+                    lottery_authority_account.pubkey().to_bytes().as_slice(),
+                    get_lottery_literal_seed(&lottery_description).as_slice()
+                ],
+                &lottery_program_id
+            ).0;
+
+            ptc
+                .get_new_latest_blockhash()
+                .await
+                .unwrap();
+            change_clock_sysvar(&ptc, 550);
+
+            let nft_prizes_accounts = Some(
+                [ 0, 1 ]
+                    .into_iter()
+                    .map(|index| (
+                        nft_prize_mint_accounts[index].0,
+                        get_associated_token_address(
+                            &lottery_account,
+                            &nft_prize_mint_accounts[index].0
+                        ),
+                        funding_nft_token_accounts[index]
+                    ))
+                    .collect::<Vec<(Pubkey, Pubkey, Pubkey)>>()
+            );
+
+            let instruction = instruction_create_and_initialize_lottery_account(
+                lottery_account,
+                lottery_authority_account.pubkey(),
+                funding_account.pubkey(),
+                config_account.usdc_mint_account,
+                get_associated_token_address(
+                    &lottery_account,
+                    &config_account.usdc_mint_account
+                ),
+                funding_usdc_token_account_pubkey,
+                arbitrary_mint_account_addr,
+                get_associated_token_address(
+                    &lottery_account,
+                    &arbitrary_mint_account_addr
+                ),
+                funding_arbitrary_token_account_pubkey,
+                TOKEN_STANDARD_PROGRAM_ID,
+                spl_associated_token_account::ID,
+                SYSTEM_PROGRAM_ID,
+                config_account_pda.0,
+                0,
+                2,
+                1000,
+                1350,
+                25,
+                1_000000,
+                None,
+                None,
+                RandomnessSource::PythPriceFeeds,
+                None,
+                nft_prizes_accounts,
+                0,
+                None,
+                None,
+                None,
+                lottery_description
+            );
+
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ],
+                Some(&ptc.payer.pubkey()),
+                &[
+                    &ptc.payer,
+                    &funding_account,
+                    &lottery_authority_account
+                ],
+                ptc.last_blockhash
+            );
+
+            ptc
+                .banks_client
+                .process_transaction(tx)
+                .await
+                .unwrap();
+
+            let SolanaAccount { data: lottery_account_data, .. } = ptc
+                .banks_client
+                .get_account(lottery_account)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let lottery = Lottery::deserialize(
+                &mut &lottery_account_data[..]
+            ).unwrap();
+
+            assert_eq!(
+                lottery.nft_prizes,
+                Some(vec![ nft_prize_mint_accounts[0].0, nft_prize_mint_accounts[1].0 ]),
+                "invalid nft prizes."
+            );
+
+            for (nft_prize_mint_account, _) in nft_prize_mint_accounts[..2].iter() {
+                let SolanaAccount { data, .. } = ptc
+                    .banks_client
+                    .get_account(
+                        get_associated_token_address(
+                            &lottery_account,
+                            nft_prize_mint_account
+                        )
+                    )
+                    .await
+                    .unwrap()
+                    .unwrap();
+
+                let TokenAccount { amount, owner, .. } = TokenAccount::unpack(
+                    &data
+                ).unwrap();
+
+                assert_eq!(
+                    amount,
+                    1,
+                    "invalid lottery's nft token account balance."
+                );
+                assert_eq!(
+                    owner,
+                    lottery_account,
+                    "invalid lottery's nft token account owner."
+                );
+            };
+        }
+        // success - nft prizes only
+    }
+    // nft prizes
+
+    // success - sol prize only
+    {
+        let lottery_description = String::from("SOL");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        let (prize_vault_account, _) = get_prize_vault_account_pda(
+            &lottery_account,
+            &lottery_program_id
+        ).unwrap();
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 550);
+
+        let sol_prize_amount = sol_to_lamports(1.5);
+        let pool_creator_share = None;
+        let maximum_total_tickets = None;
+        let settlement_bounty = None;
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            0,
+            2,
+            1000,
+            1350,
+            25,
+            1_000000,
+            None,
+            None,
+            RandomnessSource::PythPriceFeeds,
+            None,
+            None,
+            sol_prize_amount,
+            pool_creator_share,
+            maximum_total_tickets,
+            settlement_bounty,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(
+            lottery.sol_prize_amount,
+            sol_prize_amount,
+            "invalid sol prize amount."
+        );
+
+        let SolanaAccount { lamports, owner, .. } = ptc
+            .banks_client
+            .get_account(prize_vault_account)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let prize_vault_rent_exempt = (
+            (ACCOUNT_STORAGE_OVERHEAD * DEFAULT_LAMPORTS_PER_BYTE_YEAR) as f64 * DEFAULT_EXEMPTION_THRESHOLD
+        ) as u64;
+
+        assert_eq!(
+            lamports,
+            sol_prize_amount + prize_vault_rent_exempt,
+            "invalid prize-vault balance."
+        );
+        assert_eq!(
+            owner,
+            lottery_program_id,
+            "invalid prize-vault owner."
+        );
+    }
+    // success - sol prize only
+
+    // failure - invalid settlement bounty
+    {
+        let lottery_description = String::from("BOUNTY");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 550);
+
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            0,
+            2,
+            1000,
+            1350,
+            25,
+            1_000000,
+            None,
+            None,
+            RandomnessSource::PythPriceFeeds,
+            None,
+            None,
+            sol_to_lamports(0.5),
+            None,
+            None,
+            Some(SettlementBounty::Usdc(0)),
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidSettlementBounty as u32
+                )
+            )
+        );
+    }
+    // failure - invalid settlement bounty
+
+    // success - usdc settlement bounty
+    {
+        let lottery_description = String::from("BOUNTY");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
         ).0;
-        
+        let lottery_usdc_ata = get_associated_token_address(
+            &lottery_account,
+            &config_account.usdc_mint_account
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 550);
+
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            lottery_usdc_ata,
+            funding_usdc_token_account_pubkey,
+            arbitrary_mint_account_addr,
+            get_associated_token_address(
+                &lottery_account,
+                &arbitrary_mint_account_addr
+            ),
+            funding_arbitrary_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            0,
+            2,
+            1000,
+            1350,
+            25,
+            1_000000,
+            None,
+            None,
+            RandomnessSource::PythPriceFeeds,
+            None,
+            None,
+            sol_to_lamports(0.5),
+            None,
+            None,
+            Some(SettlementBounty::Usdc(1_000000)),
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(
+            lottery.settlement_bounty,
+            Some(SettlementBounty::Usdc(1_000000)),
+            "invalid settlement bounty."
+        );
+        assert_eq!(
+            lottery.is_settlement_bounty_paid,
+            false,
+            "invalid settlement bounty flag."
+        );
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_usdc_ata)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &data
+        ).unwrap();
+
+        assert_eq!(
+            amount,
+            config_account.lottery_creation_fee + 1_000000,
+            "invalid lottery's usdc balance (creation fee & settlement bounty)."
+        );
+    }
+    // success - usdc settlement bounty
+
+    // failure - token-2022 arbitrary mint with a transfer hook
+    {
+        let lottery_description = String::from("HOOK");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 550);
+
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            transfer_hook_mint_account_addr,
+            get_associated_token_address_with_program_id(
+                &lottery_account,
+                &transfer_hook_mint_account_addr,
+                &TOKEN_2022_PROGRAM_ID
+            ),
+            Pubkey::new_unique(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            100,
+            1,
+            1000,
+            1350,
+            25,
+            1_000000,
+            None,
+            None,
+            RandomnessSource::PythPriceFeeds,
+            None,
+            None,
+            0,
+            None,
+            None,
+            None,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::UnsupportedMintExtension as u32
+                )
+            )
+        );
+    }
+    // failure - token-2022 arbitrary mint with a transfer hook
+
+    // success - token-2022 arbitrary mint with a 1% transfer fee, the lottery records the received fund amount
+    {
+        let lottery_description = String::from("FEE");
+        let lottery_account = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&lottery_description).as_slice()
+            ],
+            &lottery_program_id
+        ).0;
+        let lottery_arbitrary_ata = get_associated_token_address_with_program_id(
+            &lottery_account,
+            &transfer_fee_mint_account_addr,
+            &TOKEN_2022_PROGRAM_ID
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 550);
+
+        let instruction = instruction_create_and_initialize_lottery_account(
+            lottery_account,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account,
+                &config_account.usdc_mint_account
+            ),
+            funding_usdc_token_account_pubkey,
+            transfer_fee_mint_account_addr,
+            lottery_arbitrary_ata,
+            funding_transfer_fee_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            10_000,
+            2,
+            1000,
+            1350,
+            25,
+            1_000000,
+            None,
+            None,
+            RandomnessSource::PythPriceFeeds,
+            None,
+            None,
+            0,
+            None,
+            None,
+            None,
+            lottery_description
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &funding_account,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(
+            lottery.fund_amount,
+            9_900,
+            "invalid fund amount (1% transfer fee withheld)."
+        );
+
+        let SolanaAccount { data, owner, .. } = ptc
+            .banks_client
+            .get_account(lottery_arbitrary_ata)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            owner,
+            TOKEN_2022_PROGRAM_ID,
+            "invalid lottery's arbitrary token account owner."
+        );
+        assert_eq!(
+            StateWithExtensions::<Token2022Account>::unpack(&data).unwrap().base.amount,
+            9_900,
+            "invalid lottery's arbitrary token balance."
+        );
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(funding_transfer_fee_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            StateWithExtensions::<Token2022Account>::unpack(&data).unwrap().base.amount,
+            90_000,
+            "invalid funding arbitrary token balance."
+        );
+    }
+    // success - token-2022 arbitrary mint with a 1% transfer fee, the lottery records the received fund amount
+}
+
+#[tokio::test]
+async fn test_change_lottery_ticket_price() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        lottery_creation_fee: 5_000000, // 5 USDC
+        maximum_number_of_winners: 10,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        maximum_time_for_lottery_account: 1000,
+        minimum_tickets_to_be_sold_in_lottery: 20,
+        max_lottery_description_bytes: 300,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+    
+    //////////////////////? add lottery account
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let lottery_authority_account = Keypair::new();
+    let lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        lottery_creation_fee: 5_000000,
+        protocol_fee: 300_000000,
+        starting_time: 100,
+        ending_time: 200,
+        ticket_price: 5_000000, // 5 USDC
+        authority: lottery_authority_account.pubkey(),
+        lottery_description: String::from("1"),
+        ..Lottery::default()
+    };
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: lottery_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+    
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid lottery state
+    {
+        // lottery is ended
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            300
+        );
+
+        let new_ticket_price = 10_000000u64;
+        let instruction = instruction_change_lottery_ticket_price(
+            lottery_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            config_account_pda.0, 
+            new_ticket_price // USDC
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryState as u32
+                )
+            )
+        );
+    }
+    // failure - invalid lottery state
+
+    // failure - invalid lottery's new ticket-price
+    {
+        // lottery is started
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            120
+        );
+
+        let new_ticket_price = 5_000000u64;
+        let instruction = instruction_change_lottery_ticket_price(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            new_ticket_price
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction
+            ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidTicketPrice as u32
+                )
+            )
+        );
+    }
+    // failure - invalid lottery's new ticket-price
+
+    // success
+    {
+        // lottery is started
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            120
+        );
+
+        let new_ticket_price = 10_000000u64;
+        let instruction = instruction_change_lottery_ticket_price(
+            lottery_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            config_account_pda.0, 
+            new_ticket_price // USDC
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { ticket_price, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            ticket_price,
+            new_ticket_price,
+            "1. invalid new lottery's ticket price."
+        );
+
+        // 2. lottery is not started yet
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            65
+        );
+
+        let new_ticket_price = 15_000000u64;
+        let instruction = instruction_change_lottery_ticket_price(
+            lottery_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            config_account_pda.0, 
+            new_ticket_price // USDC
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { ticket_price, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            ticket_price,
+            new_ticket_price,
+            "2. invalid new lottery's ticket price."
+        );
+    }
+    // success
+}
+
+#[tokio::test]
+async fn test_update_lottery_schedule() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        maximum_time_for_lottery_account: 1000,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add lottery accounts (undersubscribed & subscribed)
+    let lottery_authority_account = Keypair::new();
+    let mut lottery_accounts_pda = vec![];
+    for (lottery_description, tickets_total_amount) in [ ("1", 10u32), ("2", 20) ] {
+        let lottery_account_pda = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&String::from(lottery_description)).as_slice()
+            ],
+            &lottery_program_id
+        );
+
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            tickets_total_amount,
+            minimum_tickets_amount_required_to_be_sold: 20,
+            authority: lottery_authority_account.pubkey(),
+            lottery_description: String::from(lottery_description),
+            ..Lottery::default()
+        };
+
+        pt.add_account(
+            lottery_account_pda.0,
+            SolanaAccount {
+                owner: lottery_program_id,
+                lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+                data: lottery_account.try_to_vec().unwrap(),
+                ..SolanaAccount::default()
+            }
+        );
+
+        lottery_accounts_pda.push(lottery_account_pda.0);
+    };
+    let lottery_account_pda = lottery_accounts_pda[0];
+    let subscribed_lottery_account_pda = lottery_accounts_pda[1];
+    //////////////////////? add lottery accounts (undersubscribed & subscribed)
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid schedule (before the start)
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let mut errors = vec![];
+        for (new_starting_time, new_ending_time) in [ (40, 200), (150, 150), (150, 1200) ] {
+            let instruction = instruction_update_lottery_schedule(
+                lottery_account_pda,
+                lottery_authority_account.pubkey(),
+                config_account_pda.0,
+                new_starting_time,
+                new_ending_time
+            );
+
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ],
+                Some(&ptc.payer.pubkey()),
+                &[
+                    &ptc.payer,
+                    &lottery_authority_account
+                ],
+                ptc.last_blockhash
+            );
+
+            errors.push(
+                ptc
+                    .banks_client
+                    .process_transaction(tx)
+                    .await
+                    .unwrap_err()
+                    .unwrap()
+            );
+        };
+
+        assert_eq!(
+            errors,
+            vec![
+                TransactionError::InstructionError(0, InstructionError::Custom(LotteryError::InvalidTime as u32)),
+                TransactionError::InstructionError(0, InstructionError::Custom(LotteryError::InvalidTime as u32)),
+                TransactionError::InstructionError(0, InstructionError::Custom(LotteryError::MaximumTimeExceed as u32))
+            ]
+        );
+    }
+    // failure - invalid schedule (before the start)
+
+    // success - move the schedule before the start
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_update_lottery_schedule(
+            lottery_account_pda,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            150,
+            400
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { starting_time, ending_time, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            (starting_time, ending_time),
+            (150, 400),
+            "invalid lottery's schedule."
+        );
+    }
+    // success - move the schedule before the start
+
+    // failure - starting time changed or ending time shortened after the start
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 200);
+
+        let mut errors = vec![];
+        for (new_starting_time, new_ending_time) in [ (160, 500), (150, 300) ] {
+            let instruction = instruction_update_lottery_schedule(
+                lottery_account_pda,
+                lottery_authority_account.pubkey(),
+                config_account_pda.0,
+                new_starting_time,
+                new_ending_time
+            );
+
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ],
+                Some(&ptc.payer.pubkey()),
+                &[
+                    &ptc.payer,
+                    &lottery_authority_account
+                ],
+                ptc.last_blockhash
+            );
+
+            errors.push(
+                ptc
+                    .banks_client
+                    .process_transaction(tx)
+                    .await
+                    .unwrap_err()
+                    .unwrap()
+            );
+        };
+
+        assert_eq!(
+            errors,
+            vec![
+                TransactionError::InstructionError(0, InstructionError::Custom(LotteryError::InvalidTime as u32)),
+                TransactionError::InstructionError(0, InstructionError::Custom(LotteryError::InvalidTime as u32))
+            ]
+        );
+    }
+    // failure - starting time changed or ending time shortened after the start
+
+    // failure - enough tickets are sold
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 150);
+
+        let instruction = instruction_update_lottery_schedule(
+            subscribed_lottery_account_pda,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            100,
+            500
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotteryScheduleLocked as u32
+                )
+            )
+        );
+    }
+    // failure - enough tickets are sold
+
+    // success - extend an undersubscribed lottery
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 200);
+
+        let instruction = instruction_update_lottery_schedule(
+            lottery_account_pda,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            150,
+            1150
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { starting_time, ending_time, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            (starting_time, ending_time),
+            (150, 1150),
+            "invalid lottery's schedule."
+        );
+    }
+    // success - extend an undersubscribed lottery
+
+    // failure - lottery is ended
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 1200);
+
+        let instruction = instruction_update_lottery_schedule(
+            lottery_account_pda,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            150,
+            1140
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryState as u32
+                )
+            )
+        );
+    }
+    // failure - lottery is ended
+}
+
+#[tokio::test]
+async fn test_add_prize_mint() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add lottery account
+    let lottery_authority_account = Keypair::new();
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            lottery_authority_account.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let mut lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        winners_count: 2,
+        authority: lottery_authority_account.pubkey(),
+        arbitrary_mint_account_address: Pubkey::new_from_array([1; 32]),
+        lottery_description: String::from("1"),
+        ..Lottery::default()
+    };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64 + 2 * 34;
+
+    let mut lottery_account_data = lottery_account.try_to_vec().unwrap();
+    lottery_account_data.extend_from_slice(&[0u8; 2 * 34]);
+
+    pt.add_account(
+        lottery_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(1.0),
+            data: lottery_account_data.clone(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery account
+
+    //////////////////////? add prize mint account
+    let prize_mint_account_pubkey = Pubkey::new_unique();
+    let mut prize_mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        MintAccount {
+            supply: 10_000,
+            decimals: 2,
+            is_initialized: true,
+            ..MintAccount::default()
+        },
+        prize_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        prize_mint_account_pubkey,
+        SolanaAccount {
+            data: prize_mint_account_data.to_vec(),
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add prize mint account
+
+    //////////////////////? add funding account & its prize token account
+    let funding_account = Keypair::new();
+    pt.add_account(
+        funding_account.pubkey(),
+        SolanaAccount {
+            owner: SYSTEM_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let funding_prize_token_account_pubkey = Pubkey::new_unique();
+    let mut funding_prize_token_account_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            amount: 10_000,
+            owner: funding_account.pubkey(),
+            state: TokenAccountState::Initialized,
+            mint: prize_mint_account_pubkey,
+            ..TokenAccount::default()
+        },
+        funding_prize_token_account_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        funding_prize_token_account_pubkey,
+        SolanaAccount {
+            data: funding_prize_token_account_data.to_vec(),
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add funding account & its prize token account
+
+    let lottery_prize_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &prize_mint_account_pubkey
+    );
+
+    //////////////////////? add token-2022 prize mints (1% transfer fee & non-transferable) & the funding transfer-fee token account
+    let transfer_fee_prize_mint_account_pubkey = Pubkey::new_unique();
+    pt.add_account(
+        transfer_fee_prize_mint_account_pubkey,
+        SolanaAccount {
+            data: pack_token_2022_mint_account(
+                Token2022MintAccount {
+                    supply: 10_000,
+                    decimals: 2,
+                    is_initialized: true,
+                    ..Token2022MintAccount::default()
+                },
+                &[ ExtensionType::TransferFeeConfig ],
+                100
+            ),
+            owner: TOKEN_2022_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let non_transferable_prize_mint_account_pubkey = Pubkey::new_unique();
+    pt.add_account(
+        non_transferable_prize_mint_account_pubkey,
+        SolanaAccount {
+            data: pack_token_2022_mint_account(
+                Token2022MintAccount {
+                    supply: 10_000,
+                    decimals: 2,
+                    is_initialized: true,
+                    ..Token2022MintAccount::default()
+                },
+                &[ ExtensionType::NonTransferable ],
+                0
+            ),
+            owner: TOKEN_2022_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let funding_transfer_fee_prize_token_account_pubkey = Pubkey::new_unique();
+    pt.add_account(
+        funding_transfer_fee_prize_token_account_pubkey,
+        SolanaAccount {
+            data: pack_token_2022_token_account(
+                Token2022Account {
+                    amount: 10_000,
+                    owner: funding_account.pubkey(),
+                    state: Token2022AccountState::Initialized,
+                    mint: transfer_fee_prize_mint_account_pubkey,
+                    ..Token2022Account::default()
+                },
+                &[ ExtensionType::TransferFeeAmount ]
+            ),
+            owner: TOKEN_2022_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let lottery_transfer_fee_prize_ata_pubkey = get_associated_token_address_with_program_id(
+        &lottery_account_pda.0,
+        &transfer_fee_prize_mint_account_pubkey,
+        &TOKEN_2022_PROGRAM_ID
+    );
+    //////////////////////? add token-2022 prize mints (1% transfer fee & non-transferable) & the funding transfer-fee token account
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - usdc mint as prize mint
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_add_prize_mint(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            config_account.usdc_mint_account,
+            get_associated_token_address(
+                &lottery_account_pda.0,
+                &config_account.usdc_mint_account
+            ),
+            funding_prize_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            1_000
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account,
+                &funding_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidPrizeMintAccount as u32
+                )
+            )
+        );
+    }
+    // failure - usdc mint as prize mint
+
+    // failure - lottery is started
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 120);
+
+        let instruction = instruction_add_prize_mint(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            prize_mint_account_pubkey,
+            lottery_prize_ata_pubkey,
+            funding_prize_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            1_000
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account,
+                &funding_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryState as u32
+                )
+            )
+        );
+    }
+    // failure - lottery is started
+
+    // success
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_add_prize_mint(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            prize_mint_account_pubkey,
+            lottery_prize_ata_pubkey,
+            funding_prize_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            4_000
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account,
+                &funding_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            data.len(),
+            lottery_account_data.len() + 40,
+            "invalid lottery account size."
+        );
+
+        let Lottery { prize_mints, initial_bytes, prize_token_accounts_amount, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            prize_mints,
+            vec![ (prize_mint_account_pubkey, 4_000) ],
+            "invalid lottery's prize mints."
+        );
+        assert_eq!(
+            initial_bytes,
+            lottery_account.initial_bytes + 40,
+            "invalid lottery's initial bytes."
+        );
+        assert_eq!(
+            prize_token_accounts_amount,
+            lottery_account.prize_token_accounts_amount + 1,
+            "invalid lottery's prize token accounts amount."
+        );
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_prize_ata_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, owner, .. } = TokenAccount::unpack(
+            &data
+        ).unwrap();
+
+        assert_eq!(
+            amount,
+            4_000,
+            "invalid lottery's prize token account balance."
+        );
+        assert_eq!(
+            owner,
+            lottery_account_pda.0,
+            "invalid lottery's prize token account owner."
+        );
+    }
+    // success
+
+    // failure - prize mint already added
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_add_prize_mint(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            prize_mint_account_pubkey,
+            lottery_prize_ata_pubkey,
+            funding_prize_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            1_000
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account,
+                &funding_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidPrizeMintAccount as u32
+                )
+            )
+        );
+    }
+    // failure - prize mint already added
+
+    // failure - non-transferable prize mint
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_add_prize_mint(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            non_transferable_prize_mint_account_pubkey,
+            get_associated_token_address_with_program_id(
+                &lottery_account_pda.0,
+                &non_transferable_prize_mint_account_pubkey,
+                &TOKEN_2022_PROGRAM_ID
+            ),
+            funding_transfer_fee_prize_token_account_pubkey,
+            TOKEN_2022_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            5_000
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account,
+                &funding_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::UnsupportedMintExtension as u32
+                )
+            )
+        );
+    }
+    // failure - non-transferable prize mint
+
+    // failure - token program is not the prize mint's owner
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_add_prize_mint(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            transfer_fee_prize_mint_account_pubkey,
+            lottery_transfer_fee_prize_ata_pubkey,
+            funding_transfer_fee_prize_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            5_000
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account,
+                &funding_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidTokenProgramAccount as u32
+                )
+            )
+        );
+    }
+    // failure - token program is not the prize mint's owner
+
+    // success - token-2022 prize mint with a transfer fee
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_add_prize_mint(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            funding_account.pubkey(),
+            transfer_fee_prize_mint_account_pubkey,
+            lottery_transfer_fee_prize_ata_pubkey,
+            funding_transfer_fee_prize_token_account_pubkey,
+            TOKEN_2022_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            5_000
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account,
+                &funding_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { prize_mints, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            prize_mints,
+            vec![
+                (prize_mint_account_pubkey, 4_000),
+                (transfer_fee_prize_mint_account_pubkey, 4_950)
+            ],
+            "invalid lottery's prize mints."
+        );
+
+        let SolanaAccount { data, owner, .. } = ptc
+            .banks_client
+            .get_account(lottery_transfer_fee_prize_ata_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_transfer_fee_prize_ata = StateWithExtensions::<Token2022Account>::unpack(
+            &data
+        ).unwrap();
+
+        assert_eq!(
+            owner,
+            TOKEN_2022_PROGRAM_ID,
+            "invalid lottery's prize token account program."
+        );
+        assert_eq!(
+            lottery_transfer_fee_prize_ata.base.amount,
+            4_950,
+            "invalid lottery's prize token account balance."
+        );
+        assert_eq!(
+            u64::from(lottery_transfer_fee_prize_ata.get_extension::<TransferFeeAmount>().unwrap().withheld_amount),
+            50,
+            "invalid lottery's prize token account withheld fee."
+        );
+    }
+    // success - token-2022 prize mint with a transfer fee
+}
+
+#[tokio::test]
+async fn test_lottery_series_jackpot() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        max_lottery_description_bytes: 10,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add USDC mint account
+    let mut usdc_mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        MintAccount {
+            supply: 10_000_000000,
+            decimals: 6,
+            is_initialized: true,
+            ..MintAccount::default()
+        },
+        usdc_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        config_account.usdc_mint_account,
+        SolanaAccount {
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(0.01),
+            data: usdc_mint_account_data.to_vec(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add USDC mint account
+
+    //////////////////////? add lottery authority (series authority & funding account)
+    let lottery_authority_account = Keypair::new();
+    pt.add_account(
+        lottery_authority_account.pubkey(),
+        SolanaAccount {
+            owner: SYSTEM_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery authority (series authority & funding account)
+
+    //////////////////////? add lottery account
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            lottery_authority_account.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        winners_count: 2,
+        minimum_tickets_amount_required_to_be_sold: 5,
+        tickets_total_amount: 10,
+        protocol_fee: 95_000000,
+        lottery_creation_fee: 5_000000,
+        authority: lottery_authority_account.pubkey(),
+        arbitrary_mint_account_address: Pubkey::new_from_array([1; 32]),
+        lottery_description: String::from("1"),
+        ..Lottery::default()
+    };
+    let mut lottery_account_data = lottery_account.try_to_vec().unwrap();
+    lottery_account_data.extend_from_slice(&[0u8; 2 * 34]);
+
+    pt.add_account(
+        lottery_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(1.0),
+            data: lottery_account_data.clone(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery account
+
+    //////////////////////? add lottery's usdc ata
+    let lottery_usdc_ata_pda = get_associated_token_address(
+        &lottery_account_pda.0,
+        &config_account.usdc_mint_account
+    );
+    let mut lottery_usdc_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            state: TokenAccountState::Initialized,
+            mint: config_account.usdc_mint_account,
+            amount: 1000_000000,
+            owner: lottery_account_pda.0,
+            ..TokenAccount::default()
+        },
+        lottery_usdc_ata_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        lottery_usdc_ata_pda,
+        SolanaAccount {
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(0.01),
+            data: lottery_usdc_ata_data.to_vec(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery's usdc ata
+
+    //////////////////////? add user account & fund receivers usdc token accounts
+    let user_account_auth = Keypair::new();
+    let user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            user_account_auth.pubkey().to_bytes().as_slice(),
+            lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    pt.add_account(
+        user_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(1.0),
+            data: User {
+                discriminator: User::get_discriminator(),
+                canonical_bump: user_account_pda.1,
+                authority: user_account_auth.pubkey(),
+                lottery: lottery_account_pda.0,
+                ..User::default()
+            }.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let creator_usdc_token_account_pubkey = Pubkey::new_unique();
+    let user_usdc_token_account_pubkey = Pubkey::new_unique();
+    for (usdc_token_account_pubkey, owner) in [
+        (creator_usdc_token_account_pubkey, lottery_authority_account.pubkey()),
+        (user_usdc_token_account_pubkey, user_account_auth.pubkey())
+    ] {
+        let mut usdc_token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                state: TokenAccountState::Initialized,
+                mint: config_account.usdc_mint_account,
+                owner,
+                ..TokenAccount::default()
+            },
+            usdc_token_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            usdc_token_account_pubkey,
+            SolanaAccount {
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(0.01),
+                data: usdc_token_account_data.to_vec(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add user account & fund receivers usdc token accounts
+
+    let lottery_series_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_series_account",
+            lottery_authority_account.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("W")).as_slice()
+        ],
+        &lottery_program_id
+    );
+    let series_jackpot_vault_pubkey = get_associated_token_address(
+        &lottery_series_account_pda.0,
+        &config_account.usdc_mint_account
+    );
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid jackpot share
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_create_lottery_series(
+            lottery_series_account_pda.0,
+            lottery_authority_account.pubkey(),
+            lottery_authority_account.pubkey(),
+            config_account.usdc_mint_account,
+            series_jackpot_vault_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            10_001,
+            0,
+            String::from("W")
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidJackpotShare as u32
+                )
+            )
+        );
+    }
+    // failure - invalid jackpot share
+
+    // success - create series
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_create_lottery_series(
+            lottery_series_account_pda.0,
+            lottery_authority_account.pubkey(),
+            lottery_authority_account.pubkey(),
+            config_account.usdc_mint_account,
+            series_jackpot_vault_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            1_000,
+            0,
+            String::from("W")
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_series_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            data.len(),
+            LotterySeries::LEN,
+            "invalid lottery-series account size."
+        );
+
+        let lottery_series = LotterySeries::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            lottery_series,
+            LotterySeries {
+                discriminator: LotterySeries::get_discriminator(),
+                canonical_bump: lottery_series_account_pda.1,
+                authority: lottery_authority_account.pubkey(),
+                series_literal_seed: get_lottery_literal_seed(&String::from("W")),
+                jackpot_share: 1_000,
+                created_at: 50,
+                ..LotterySeries::default()
+            },
+            "invalid lottery-series account."
+        );
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(series_jackpot_vault_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { owner, .. } = TokenAccount::unpack(
+            &data
+        ).unwrap();
+
+        assert_eq!(
+            owner,
+            lottery_series_account_pda.0,
+            "invalid series jackpot vault owner."
+        );
+    }
+    // success - create series
+
+    // success - join series
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_join_lottery_series(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            lottery_authority_account.pubkey(),
+            lottery_series_account_pda.0,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Option<SeriesJackpot> -> 32 + 2 + 4 + 1 + 1 + 1 + 8 + 32
+        assert_eq!(
+            data.len(),
+            lottery_account_data.len() + 81,
+            "invalid lottery account size."
+        );
+
+        let Lottery { series_jackpot, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            series_jackpot,
+            Some(
+                SeriesJackpot {
+                    series: lottery_series_account_pda.0,
+                    jackpot_share: 1_000,
+                    ..SeriesJackpot::default()
+                }
+            ),
+            "invalid lottery's series jackpot."
+        );
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_series_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let LotterySeries { rounds_total_amount, last_round, .. } = LotterySeries::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            rounds_total_amount,
+            1,
+            "invalid lottery-series rounds amount."
+        );
+        assert_eq!(last_round, Some(lottery_account_pda.0), "invalid lottery-series last round.");
+    }
+    // success - join series
+
+    // failure - series already joined
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_join_lottery_series(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            lottery_authority_account.pubkey(),
+            lottery_series_account_pda.0,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            Some(lottery_account_pda.0)
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotterySeriesAlreadyJoined as u32
+                )
+            )
+        );
+    }
+    // failure - series already joined
+
+    // the round ended & its jackpot draw landed on a ticket, the 60 USDC rolled over from previous rounds are reserved for it
+    {
+        let SolanaAccount { mut data, lamports, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut lottery = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+        lottery.is_ended_successfuly = true;
+        lottery.winners = vec![
+            (user_account_pda.0, 0, false),
+            (Pubkey::new_unique(), 1, false)
+        ];
+        lottery.series_jackpot = lottery.series_jackpot.map(|series_jackpot| SeriesJackpot {
+            is_drawn: true,
+            is_won: true,
+            is_amount_reserved: true,
+            amount: 60_000000,
+            ..series_jackpot
+        });
+        lottery.serialize(&mut &mut data[..]).unwrap();
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports,
+                    data,
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let SolanaAccount { mut data, lamports, .. } = ptc
+            .banks_client
+            .get_account(lottery_series_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut lottery_series = LotterySeries::deserialize(
+            &mut &data[..]
+        ).unwrap();
+        lottery_series.reserved_jackpot_amount = 60_000000;
+        lottery_series.serialize(&mut &mut data[..]).unwrap();
+
+        ptc.set_account(
+            &lottery_series_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports,
+                    data,
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let mut series_jackpot_vault_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                state: TokenAccountState::Initialized,
+                mint: config_account.usdc_mint_account,
+                amount: 60_000000,
+                owner: lottery_series_account_pda.0,
+                ..TokenAccount::default()
+            },
+            series_jackpot_vault_data.as_mut_slice()
+        ).unwrap();
+
+        ptc.set_account(
+            &series_jackpot_vault_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(0.01),
+                    data: series_jackpot_vault_data.to_vec(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+    }
+
+    // failure - series accounts are missing
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0,
+            config_account_pda.0,
+            lottery_authority_account.pubkey(),
+            lottery_usdc_ata_pda,
+            creator_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidAmountOfLotterySeriesAccounts as u32
+                )
+            )
+        );
+    }
+    // failure - series accounts are missing
+
+    // success - creator's withdrawal contributes to the jackpot
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0,
+            config_account_pda.0,
+            lottery_authority_account.pubkey(),
+            lottery_usdc_ata_pda,
+            creator_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            TOKEN_STANDARD_PROGRAM_ID,
+            Some(lottery_series_account_pda.0),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(creator_usdc_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &data
+        ).unwrap();
+
+        // (1000 - 100 USDC of protocol fees) * 90%
+        assert_eq!(
+            amount,
+            810_000000,
+            "invalid fund receiver token account balance."
+        );
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(series_jackpot_vault_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &data
+        ).unwrap();
+
+        assert_eq!(
+            amount,
+            150_000000,
+            "invalid series jackpot vault balance."
+        );
+    }
+    // success - creator's withdrawal contributes to the jackpot
+
+    // success - withdraw series jackpot
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_series_jackpot(
+            lottery_account_pda.0,
+            user_account_pda.0,
+            user_account_auth.pubkey(),
+            lottery_series_account_pda.0,
+            series_jackpot_vault_pubkey,
+            user_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account_pda.0
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(user_usdc_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &data
+        ).unwrap();
+
+        // 1st of 2 winners (equal split) of the 60 USDC jackpot reserved when the round was drawn,
+        // the creator's contribution rolls over to the next rounds
+        assert_eq!(
+            amount,
+            30_000000,
+            "invalid fund receiver token account balance."
+        );
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_series_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let LotterySeries { reserved_jackpot_amount, .. } = LotterySeries::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            reserved_jackpot_amount,
+            30_000000,
+            "invalid lottery-series reserved jackpot."
+        );
+    }
+    // success - withdraw series jackpot
+
+    // failure - jackpot already claimed
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 351);
+
+        let instruction = instruction_withdraw_series_jackpot(
+            lottery_account_pda.0,
+            user_account_pda.0,
+            user_account_auth.pubkey(),
+            lottery_series_account_pda.0,
+            series_jackpot_vault_pubkey,
+            user_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account_pda.0
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::JackpotAlreadyClaimed as u32
+                )
+            )
+        );
+    }
+    // failure - jackpot already claimed
+}
+
+#[tokio::test]
+async fn test_lottery_series_rounds() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        lottery_creation_fee: 5_000000,
+        maximum_number_of_winners: 5,
+        minimum_tickets_to_be_sold_in_lottery: 2,
+        maximum_time_for_lottery_account: 1_000,
+        max_lottery_description_bytes: 10,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    pt.add_account(
+        config_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            data: config_account_data,
+            lamports: sol_to_lamports(0.01),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add USDC & prize mint accounts
+    let prize_mint_account_pubkey = Pubkey::new_unique();
+    let other_prize_mint_account_pubkey = Pubkey::new_unique();
+    for mint_account_pubkey in [
+        config_account.usdc_mint_account,
+        prize_mint_account_pubkey,
+        other_prize_mint_account_pubkey
+    ] {
+        let mut mint_account_data = [0u8; MintAccount::LEN];
+        MintAccount::pack(
+            MintAccount {
+                supply: 10_000_000000,
+                decimals: 6,
+                is_initialized: true,
+                ..MintAccount::default()
+            },
+            mint_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            mint_account_pubkey,
+            SolanaAccount {
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(0.01),
+                data: mint_account_data.to_vec(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add USDC & prize mint accounts
+
+    //////////////////////? add series authority & keeper with the series' pre-funded vaults (rents & lottery-creation-fees)
+    let lottery_series_authority_account = Keypair::new();
+    let keeper_account = Keypair::new();
+    for account in [
+        &lottery_series_authority_account,
+        &keeper_account
+    ] {
+        pt.add_account(
+            account.pubkey(),
+            SolanaAccount {
+                owner: SYSTEM_PROGRAM_ID,
+                lamports: sol_to_lamports(1.0),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+
+    let lottery_series_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_series_account",
+            lottery_series_authority_account.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("W")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let series_prize_vault_pda = get_series_prize_vault_account_pda(
+        &lottery_series_account_pda.0,
+        &lottery_program_id
+    ).unwrap();
+
+    pt.add_account(
+        series_prize_vault_pda.0,
+        SolanaAccount {
+            owner: SYSTEM_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let authority_prize_token_account_pubkey = Pubkey::new_unique();
+    let series_usdc_vault_pubkey = get_associated_token_address(
+        &series_prize_vault_pda.0,
+        &config_account.usdc_mint_account
+    );
+    for (token_account_pubkey, mint, owner) in [
+        (authority_prize_token_account_pubkey, prize_mint_account_pubkey, lottery_series_authority_account.pubkey()),
+        (series_usdc_vault_pubkey, config_account.usdc_mint_account, series_prize_vault_pda.0)
+    ] {
+        let mut token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                state: TokenAccountState::Initialized,
+                mint,
+                owner,
+                amount: 1000_000000,
+                ..TokenAccount::default()
+            },
+            token_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            token_account_pubkey,
+            SolanaAccount {
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(0.01),
+                data: token_account_data.to_vec(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add series authority & keeper with the series' pre-funded vaults (rents & lottery-creation-fees)
+
+    //////////////////////? add lottery-series account
+
+    let mut lottery_series_account_data = LotterySeries {
+        discriminator: LotterySeries::get_discriminator(),
+        canonical_bump: lottery_series_account_pda.1,
+        authority: lottery_series_authority_account.pubkey(),
+        series_literal_seed: get_lottery_literal_seed(&String::from("W")),
+        jackpot_share: 1_000,
+        house_slots: 5,
+        created_at: 10,
+        ..LotterySeries::default()
+    }.try_to_vec().unwrap();
+    lottery_series_account_data.resize(LotterySeries::LEN, 0);
+
+    pt.add_account(
+        lottery_series_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(0.01),
+            data: lottery_series_account_data,
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery-series account
+
+    let series_prize_vault_pubkey = get_associated_token_address(
+        &series_prize_vault_pda.0,
+        &prize_mint_account_pubkey
+    );
+
+    let round_template = RoundTemplate {
+        prize_mint: prize_mint_account_pubkey,
+        fund_amount: 100_000000,
+        ticket_price: 1_000000,
+        winners_count: 2,
+        round_duration: 500,
+        minimum_tickets_amount_required_to_be_sold: 4
+    };
+
+    let get_round_accounts = |round_number: u32| -> (Pubkey, Pubkey, Pubkey) {
+        let (lottery_account_pubkey, _) = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_series_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(
+                    &LotterySeries::get_round_description(&lottery_series_account_pda.0, round_number)
+                ).as_slice()
+            ],
+            &lottery_program_id
+        );
+
+        (
+            lottery_account_pubkey,
+            get_associated_token_address(&lottery_account_pubkey, &config_account.usdc_mint_account),
+            get_associated_token_address(&lottery_account_pubkey, &prize_mint_account_pubkey)
+        )
+    };
+    let (first_round_pubkey, first_round_usdc_ata, first_round_prize_ata) = get_round_accounts(1);
+    let (second_round_pubkey, second_round_usdc_ata, second_round_prize_ata) = get_round_accounts(2);
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - series without a round template
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_start_next_round(
+            lottery_series_account_pda.0,
+            first_round_pubkey,
+            config_account.usdc_mint_account,
+            first_round_usdc_ata,
+            series_usdc_vault_pubkey,
+            prize_mint_account_pubkey,
+            first_round_prize_ata,
+            series_prize_vault_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&keeper_account.pubkey()),
+            &[
+                &keeper_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::RoundTemplateNotFound as u32
+                )
+            )
+        );
+    }
+    // failure - series without a round template
+
+    // failure - invalid series authority
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_set_lottery_series_round_template(
+            lottery_series_account_pda.0,
+            keeper_account.pubkey(),
+            keeper_account.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            round_template
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&keeper_account.pubkey()),
+            &[
+                &keeper_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryAccountAuthority as u32
+                )
+            )
+        );
+    }
+    // failure - invalid series authority
+
+    // success - set round template & fund the series' prize vault
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_set_lottery_series_round_template(
+            lottery_series_account_pda.0,
+            lottery_series_authority_account.pubkey(),
+            lottery_series_authority_account.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            round_template
+        );
+
+        let fund_instruction = spl_token::instruction::transfer_checked(
+            &TOKEN_STANDARD_PROGRAM_ID,
+            &authority_prize_token_account_pubkey,
+            &prize_mint_account_pubkey,
+            &series_prize_vault_pubkey,
+            &lottery_series_authority_account.pubkey(),
+            &[],
+            250_000000,
+            6
+        ).unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction,
+                fund_instruction
+            ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_series_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_series_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_series = LotterySeries::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(lottery_series.round_template, Some(round_template), "invalid round template.");
+        assert_eq!(lottery_series.prize_vault_canonical_bump, series_prize_vault_pda.1, "invalid prize vault bump.");
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(series_prize_vault_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { owner, amount, .. } = TokenAccount::unpack(
+            &data
+        ).unwrap();
+
+        assert_eq!(owner, series_prize_vault_pda.0, "invalid series prize vault owner.");
+        assert_eq!(amount, 250_000000, "invalid series prize vault balance.");
+    }
+    // success - set round template & fund the series' prize vault
+
+    // failure - prize mint of the template can't be changed
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_set_lottery_series_round_template(
+            lottery_series_account_pda.0,
+            lottery_series_authority_account.pubkey(),
+            lottery_series_authority_account.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            RoundTemplate {
+                prize_mint: other_prize_mint_account_pubkey,
+                ..round_template
+            }
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_series_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::SeriesPrizeMintLocked as u32
+                )
+            )
+        );
+    }
+    // failure - prize mint of the template can't be changed
+
+    // failure - round's account isn't seeded by the next round number
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 100);
+
+        let instruction = instruction_start_next_round(
+            lottery_series_account_pda.0,
+            second_round_pubkey,
+            config_account.usdc_mint_account,
+            second_round_usdc_ata,
+            series_usdc_vault_pubkey,
+            prize_mint_account_pubkey,
+            second_round_prize_ata,
+            series_prize_vault_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&keeper_account.pubkey()),
+            &[
+                &keeper_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::InvalidSeeds
+            )
+        );
+    }
+    // failure - round's account isn't seeded by the next round number
+
+    // success - start the first round
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 100);
+
+        let SolanaAccount { lamports: keeper_old_balance, .. } = ptc
+            .banks_client
+            .get_account(keeper_account.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let instruction = instruction_start_next_round(
+            lottery_series_account_pda.0,
+            first_round_pubkey,
+            config_account.usdc_mint_account,
+            first_round_usdc_ata,
+            series_usdc_vault_pubkey,
+            prize_mint_account_pubkey,
+            first_round_prize_ata,
+            series_prize_vault_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&keeper_account.pubkey()),
+            &[
+                &keeper_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(first_round_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_account = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(lottery_account.authority, lottery_series_authority_account.pubkey(), "invalid round authority.");
+        assert_eq!(lottery_account.arbitrary_mint_account_address, prize_mint_account_pubkey, "invalid round prize mint.");
+        assert_eq!(lottery_account.fund_amount, 100_000000, "invalid round fund amount.");
+        assert_eq!(lottery_account.ticket_price, 1_000000, "invalid round ticket price.");
+        assert_eq!(lottery_account.winners_count, 2, "invalid round winners count.");
+        assert_eq!(lottery_account.minimum_tickets_amount_required_to_be_sold, 4, "invalid round minimum tickets.");
+        assert_eq!(lottery_account.lottery_creation_fee, 5_000000, "invalid round creation fee.");
+        assert_eq!(lottery_account.starting_time, 100, "invalid round starting time.");
+        assert_eq!(lottery_account.ending_time, 600, "invalid round ending time.");
+        assert_eq!(lottery_account.initial_bytes as usize, data.len(), "invalid round initial bytes.");
+        assert_eq!(
+            lottery_account.series_jackpot,
+            Some(
+                SeriesJackpot {
+                    series: lottery_series_account_pda.0,
+                    jackpot_share: 1_000,
+                    house_slots: 5,
+                    ..SeriesJackpot::default()
+                }
+            ),
+            "invalid round series jackpot."
+        );
+
+        for (token_account_pubkey, expected_amount) in [
+            (first_round_prize_ata, 100_000000),
+            (first_round_usdc_ata, 5_000000),
+            (series_prize_vault_pubkey, 150_000000),
+            (series_usdc_vault_pubkey, 995_000000)
+        ] {
+            let SolanaAccount { data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount, .. } = TokenAccount::unpack(
+                &data
+            ).unwrap();
+
+            assert_eq!(amount, expected_amount, "invalid token account balance.");
+        };
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_series_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_series = LotterySeries::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(lottery_series.rounds_total_amount, 1, "invalid rounds total amount.");
+        assert_eq!(lottery_series.last_round, Some(first_round_pubkey), "invalid last round.");
+
+        // the series-prize-vault pda paid the rents, the keeper only the transaction fee
+        let rent = ptc
+            .banks_client
+            .get_rent()
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(first_round_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(series_prize_vault_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            lamports,
+            sol_to_lamports(1.0) - rent.minimum_balance(data.len()) - 2 * rent.minimum_balance(TokenAccount::LEN),
+            "invalid series-prize-vault pda balance."
+        );
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(keeper_account.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(lamports, keeper_old_balance - 5_000, "invalid keeper balance.");
+    }
+    // success - start the first round
+
+    // failure - previous round's account is missing
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 700);
+
+        let instruction = instruction_start_next_round(
+            lottery_series_account_pda.0,
+            second_round_pubkey,
+            config_account.usdc_mint_account,
+            second_round_usdc_ata,
+            series_usdc_vault_pubkey,
+            prize_mint_account_pubkey,
+            second_round_prize_ata,
+            series_prize_vault_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&keeper_account.pubkey()),
+            &[
+                &keeper_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidPreviousRoundAccount as u32
+                )
+            )
+        );
+    }
+    // failure - previous round's account is missing
+
+    // failure - previous round has not ended
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 599);
+
+        let instruction = instruction_start_next_round(
+            lottery_series_account_pda.0,
+            second_round_pubkey,
+            config_account.usdc_mint_account,
+            second_round_usdc_ata,
+            series_usdc_vault_pubkey,
+            prize_mint_account_pubkey,
+            second_round_prize_ata,
+            series_prize_vault_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            Some(first_round_pubkey)
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&keeper_account.pubkey()),
+            &[
+                &keeper_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::PreviousRoundNotEnded as u32
+                )
+            )
+        );
+    }
+    // failure - previous round has not ended
+
+    // success - start the second round once the first one has ended
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 600);
+
+        let instruction = instruction_start_next_round(
+            lottery_series_account_pda.0,
+            second_round_pubkey,
+            config_account.usdc_mint_account,
+            second_round_usdc_ata,
+            series_usdc_vault_pubkey,
+            prize_mint_account_pubkey,
+            second_round_prize_ata,
+            series_prize_vault_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            Some(first_round_pubkey)
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&keeper_account.pubkey()),
+            &[
+                &keeper_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(second_round_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_account = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(lottery_account.starting_time, 600, "invalid round starting time.");
+        assert_eq!(lottery_account.ending_time, 1_100, "invalid round ending time.");
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(series_prize_vault_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &data
+        ).unwrap();
+
+        assert_eq!(amount, 50_000000, "invalid series prize vault balance.");
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_series_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_series = LotterySeries::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(lottery_series.rounds_total_amount, 2, "invalid rounds total amount.");
+        assert_eq!(lottery_series.last_round, Some(second_round_pubkey), "invalid last round.");
+    }
+    // success - start the second round once the first one has ended
+
+    // failure - a joined lottery starts before the last round ends & success - it starts once the last round ends
+    let joined_lottery_pubkey = Pubkey::new_unique();
+    let mut joined_lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        authority: lottery_series_authority_account.pubkey(),
+        starting_time: 1_000,
+        ending_time: 1_500,
+        lottery_description: String::from("J"),
+        winners_count: 2,
+        ..Lottery::default()
+    };
+    joined_lottery_account.initial_bytes = joined_lottery_account.try_to_vec().unwrap().len() as u64;
+    for (starting_time, expected_error) in [
+        (1_000, Some(LotteryError::PreviousRoundNotEnded)),
+        (1_100, None)
+    ] {
+        joined_lottery_account.starting_time = starting_time;
+        ptc.set_account(
+            &joined_lottery_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: sol_to_lamports(1.0),
+                    data: joined_lottery_account.try_to_vec().unwrap(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 700);
+
+        let instruction = instruction_join_lottery_series(
+            joined_lottery_pubkey,
+            lottery_series_authority_account.pubkey(),
+            lottery_series_authority_account.pubkey(),
+            lottery_series_account_pda.0,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            Some(second_round_pubkey)
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_series_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let result = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await;
+
+        match expected_error {
+            Some(expected_error) => {
+                assert_eq!(
+                    result.unwrap_err().unwrap(),
+                    TransactionError::InstructionError(
+                        0,
+                        InstructionError::Custom(
+                            expected_error as u32
+                        )
+                    )
+                );
+            },
+            None => {
+                result.unwrap();
+
+                let SolanaAccount { data, .. } = ptc
+                    .banks_client
+                    .get_account(lottery_series_account_pda.0)
+                    .await
+                    .unwrap()
+                    .unwrap();
+
+                let lottery_series = LotterySeries::deserialize(
+                    &mut &data[..]
+                ).unwrap();
+
+                assert_eq!(lottery_series.rounds_total_amount, 3, "invalid rounds total amount.");
+                assert_eq!(lottery_series.last_round, Some(joined_lottery_pubkey), "invalid last round.");
+            }
+        };
+    };
+    // failure - a joined lottery starts before the last round ends & success - it starts once the last round ends
+
+    // failure - the next round waits for the joined round
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 1_200);
+
+        let (fourth_round_pubkey, fourth_round_usdc_ata, fourth_round_prize_ata) = get_round_accounts(4);
+        let instruction = instruction_start_next_round(
+            lottery_series_account_pda.0,
+            fourth_round_pubkey,
+            config_account.usdc_mint_account,
+            fourth_round_usdc_ata,
+            series_usdc_vault_pubkey,
+            prize_mint_account_pubkey,
+            fourth_round_prize_ata,
+            series_prize_vault_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            Some(joined_lottery_pubkey)
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&keeper_account.pubkey()),
+            &[
+                &keeper_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::PreviousRoundNotEnded as u32
+                )
+            )
+        );
+    }
+    // failure - the next round waits for the joined round
+}
+
+#[tokio::test]
+async fn test_reveal_lottery_secret() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        lottery_creation_fee: 5_000000, // 5 USDC
+        maximum_number_of_winners: 10,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        maximum_time_for_lottery_account: 1000,
+        minimum_tickets_to_be_sold_in_lottery: 20,
+        max_lottery_description_bytes: 300,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add lottery account
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let secret = [7u8; 32];
+    let lottery_authority_account = Keypair::new();
+    let lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        lottery_creation_fee: 5_000000,
+        starting_time: 100,
+        ending_time: 200,
+        ticket_price: 5_000000, // 5 USDC
+        authority: lottery_authority_account.pubkey(),
+        tickets_total_amount: 3,
+        minimum_tickets_amount_required_to_be_sold: 3,
+        randomness_commitment: Some(
+            solana_sdk::hash::hashv(&[ lottery_account_pda.0.as_ref(), secret.as_slice() ]).to_bytes()
+        ),
+        lottery_description: String::from("1"),
+        ..Lottery::default()
+    };
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: lottery_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+
+    //////////////////////? add vrf lottery account & its randomness-request account (already requested)
+    let vrf_lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("2")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let vrf_lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: vrf_lottery_account_pda.1,
+        lottery_creation_fee: 5_000000,
+        starting_time: 100,
+        ending_time: 200,
+        ticket_price: 5_000000, // 5 USDC
+        authority: lottery_authority_account.pubkey(),
+        tickets_total_amount: 3,
+        minimum_tickets_amount_required_to_be_sold: 3,
+        randomness_source: RandomnessSource::VrfOracle,
+        randomness_commitment: Some(
+            solana_sdk::hash::hashv(&[ vrf_lottery_account_pda.0.as_ref(), secret.as_slice() ]).to_bytes()
+        ),
+        lottery_description: String::from("2"),
+        ..Lottery::default()
+    };
+
+    pt.add_account(
+        vrf_lottery_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: vrf_lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let randomness_request_account_pda = Pubkey::find_program_address(
+        &[
+            b"randomness_request_account",
+            vrf_lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    pt.add_account(
+        randomness_request_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: RandomnessRequest::new(
+                randomness_request_account_pda.1,
+                vrf_lottery_account_pda.0,
+                Pubkey::new_unique(),
+                250,
+                [9u8; 32]
+            ).try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add vrf lottery account & its randomness-request account (already requested)
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - lottery is not ended yet
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            150
+        );
+
+        let instruction = instruction_reveal_lottery_secret(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            None,
+            secret
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotteryWasNotSuccessfull as u32
+                )
+            )
+        );
+    }
+    // failure - lottery is not ended yet
+
+    // failure - invalid lottery authority
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            250
+        );
+
+        let fake_lottery_authority_account = Keypair::new();
+        let instruction = instruction_reveal_lottery_secret(
+            lottery_account_pda.0,
+            fake_lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            None,
+            secret
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &fake_lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryAccountAuthority as u32
+                )
+            )
+        );
+    }
+    // failure - invalid lottery authority
+
+    // failure - invalid secret
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            250
+        );
+
+        let instruction = instruction_reveal_lottery_secret(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            None,
+            [8u8; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidSecret as u32
+                )
+            )
+        );
+    }
+    // failure - invalid secret
+
+    // failure - secret reveal is timed out
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            200 + 86_400
+        );
+
+        let instruction = instruction_reveal_lottery_secret(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            None,
+            secret
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::SecretRevealTimedOut as u32
+                )
+            )
+        );
+    }
+    // failure - secret reveal is timed out
+
+    // failure - randomness of the vrf lottery is already requested
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            250
+        );
+
+        let instruction = instruction_reveal_lottery_secret(
+            vrf_lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            Some(randomness_request_account_pda.0),
+            secret
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::RandomnessAlreadyRequested as u32
+                )
+            )
+        );
+    }
+    // failure - randomness of the vrf lottery is already requested
+
+    // success
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            250
+        );
+
+        let instruction = instruction_reveal_lottery_secret(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            None,
+            secret
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { revealed_secret, is_secret_revealed, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            revealed_secret,
+            secret,
+            "invalid revealed secret."
+        );
+
+        assert_eq!(
+            is_secret_revealed,
+            true,
+            "invalid is_secret_revealed flag."
+        );
+    }
+    // success
+
+    // failure - secret is already revealed
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc,
+            260
+        );
+
+        let instruction = instruction_reveal_lottery_secret(
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            config_account_pda.0,
+            None,
+            secret
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::SecretAlreadyRevealed as u32
+                )
+            )
+        );
+    }
+    // failure - secret is already revealed
+}
+
+#[tokio::test]
+async fn test_end_lottery_and_pick_winners() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        pyth_price_feed_accounts: [
+            Pubkey::from_str("7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE").unwrap(), // SOl
+            Pubkey::from_str("4cSM2e6rvbGQUFiJbqytoVMi5GgghSMr8LwVrT9VPSPo").unwrap(), // BTC
+            Pubkey::from_str("42amVS4KgzR9rA28tkVYqVXjq9Qa8dcZQMbH5EYFX6XC").unwrap() // ETH
+        ],
+        pyth_price_feed_ids: [
+            "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d".to_string(), // SOL
+            "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43".to_string(), // BTC
+            "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace".to_string() // ETH
+        ],
+        maximum_time_of_price_feed_age: 10,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+    
+    //////////////////////? add lottery account
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let mut lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("1"),
+        winners_count: 5,
+        minimum_tickets_amount_required_to_be_sold: 5,
+        tickets_total_amount: 10,
+        ticket_ranges_total_amount: 10,
+        ..Lottery::default()
+    };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64 + (lottery_account.winners_count as u64 * 34);
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: vec![
+            lottery_account.try_to_vec().unwrap(),
+            vec![0u8; lottery_account.winners_count as usize * 34],
+            Pubkey::new_from_array([10; 32]).to_bytes().to_vec(),
+            1u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([5; 32]).to_bytes().to_vec(),
+            2u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([8; 32]).to_bytes().to_vec(),
+            3u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([2; 32]).to_bytes().to_vec(),
+            4u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([1; 32]).to_bytes().to_vec(),
+            5u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([3; 32]).to_bytes().to_vec(),
+            6u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([4; 32]).to_bytes().to_vec(),
+            7u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([9; 32]).to_bytes().to_vec(),
+            8u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([7; 32]).to_bytes().to_vec(),
+            9u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([6; 32]).to_bytes().to_vec(),
+            10u32.to_le_bytes().to_vec()
+        ].concat(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+    
+    //////////////////////? add SOL pyth price feed account
+    let sol_price_feed_account_pubkey = config_account.pyth_price_feed_accounts[0];
+    let sol_price_feed_account = PriceUpdateV2 {
+        verification_level: VerificationLevel::Full,
+        price_message: PriceFeedMessage {
+            publish_time: 300,
+            price: 250,
+            conf: 7,
+            ema_conf: u64::default(),
+            ema_price: i64::default(),
+            exponent: i32::default(),
+            feed_id: get_feed_id_from_hex(
+                &config_account.pyth_price_feed_ids[0]
+            ).unwrap(),
+            prev_publish_time: i64::default()
+        },
+        write_authority: Pubkey::default(),
+        posted_slot: u64::default()
+    };
+
+    let sol_price_feed_solana_account = SolanaAccount {
+        lamports: sol_to_lamports(1.0),
+        owner: PYTH_PRICE_RECEIVER_PROGRAM_ID,
+        data: vec![
+            vec![ 34, 241, 35, 99, 157, 126, 244, 205 ],
+            sol_price_feed_account.try_to_vec().unwrap()
+        ].concat(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        sol_price_feed_account_pubkey,
+        sol_price_feed_solana_account
+    );
+    //////////////////////? add SOL pyth price feed account
+    
+    //////////////////////? add BTC pyth price feed account
+    let btc_price_feed_account_pubkey = config_account.pyth_price_feed_accounts[1];
+    let btc_price_feed_account = PriceUpdateV2 {
+        verification_level: VerificationLevel::Full,
+        price_message: PriceFeedMessage {
+            publish_time: 300,
+            price: 150,
+            conf: 3,
+            ema_conf: u64::default(),
+            ema_price: i64::default(),
+            exponent: i32::default(),
+            feed_id: get_feed_id_from_hex(
+                &config_account.pyth_price_feed_ids[1]
+            ).unwrap(),
+            prev_publish_time: i64::default()
+        },
+        write_authority: Pubkey::default(),
+        posted_slot: u64::default()
+    };
+
+    let btc_price_feed_solana_account = SolanaAccount {
+        lamports: sol_to_lamports(1.0),
+        owner: PYTH_PRICE_RECEIVER_PROGRAM_ID,
+        data: vec![
+            vec![ 34, 241, 35, 99, 157, 126, 244, 205 ],
+            btc_price_feed_account.try_to_vec().unwrap()
+        ].concat(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        btc_price_feed_account_pubkey,
+        btc_price_feed_solana_account
+    );
+    //////////////////////? add BTC pyth price feed account
+    
+    //////////////////////? add ETH pyth price feed account
+    let eth_price_feed_account_pubkey = config_account.pyth_price_feed_accounts[2];
+    let eth_price_feed_account = PriceUpdateV2 {
+        verification_level: VerificationLevel::Full,
+        price_message: PriceFeedMessage {
+            publish_time: 500,
+            price: 250,
+            conf: 5,
+            ema_conf: u64::default(),
+            ema_price: i64::default(),
+            exponent: i32::default(),
+            feed_id: get_feed_id_from_hex(
+                &config_account.pyth_price_feed_ids[2]
+            ).unwrap(),
+            prev_publish_time: i64::default()
+        },
+        write_authority: Pubkey::default(),
+        posted_slot: u64::default()
+    };
+
+    let eth_price_feed_solana_account = SolanaAccount {
+        lamports: sol_to_lamports(1.0),
+        owner: PYTH_PRICE_RECEIVER_PROGRAM_ID,
+        data: vec![
+            vec![ 34, 241, 35, 99, 157, 126, 244, 205 ],
+            eth_price_feed_account.try_to_vec().unwrap()
+        ].concat(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        eth_price_feed_account_pubkey,
+        eth_price_feed_solana_account
+    );
+    //////////////////////? add ETH pyth price feed account
+
+    let mut ptc = pt.start_with_context().await;
+
+    // success
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            sol_price_feed_account_pubkey, 
+            btc_price_feed_account_pubkey, 
+            eth_price_feed_account_pubkey,
+            None,
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[&ptc.payer], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { is_ended_successfuly, winners, random_numbers_info, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            is_ended_successfuly,
+            true,
+            "invalid is_ended_suc.. flag."
+        );
+
+        let RandomNumbersInfo { price_feeds, slot_hash } = random_numbers_info;
+        for (index, (price_feed_account_pubkey, price_feed_account)) in [
+            (sol_price_feed_account_pubkey, &sol_price_feed_account),
+            (btc_price_feed_account_pubkey, &btc_price_feed_account),
+            (eth_price_feed_account_pubkey, &eth_price_feed_account)
+        ].iter().enumerate() {
+            assert_eq!(
+                price_feeds[index],
+                (
+                    *price_feed_account_pubkey,
+                    price_feed_account.price_message.publish_time,
+                    price_feed_account.price_message.price,
+                    price_feed_account.price_message.conf
+                ),
+                "invalid price feed info -> {}", index
+            );
+        };
+
+        assert_ne!(
+            slot_hash.1,
+            [0; 32],
+            "invalid slot hash."
+        );
+
+        // anyone can recompute the draw from the recorded inputs
+        let tickets: [u8; 10] = [ 10, 5, 8, 2, 1, 3, 4, 9, 7, 6 ];
+        let seed = solana_sdk::hash::hash(
+            &random_numbers_info.get_price_feeds_randomness(&lottery_account_pda.0)
+        ).to_bytes();
+        let winners_index = Lottery::get_winners_index(&seed, 10, 5).unwrap();
+        for index in 0..5 {
+            assert_eq!(
+                winners[index].0,
+                Pubkey::new_from_array([tickets[winners_index[index] as usize]; 32]),
+                "invalid winner pubkey -> {}", index
+            );
+
+            assert_eq!(
+                winners[index].1,
+                index as u8,
+                "invalid winner rank -> {}", index
+            );
+
+            assert_eq!(
+                winners[index].2,
+                false,
+                "invalid winner flag -> {}", index
+            );
+        };
+    }   
+    // success
+
+    // failure - lottery already ended
+    {
+        let lottery_account_pda = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                Pubkey::default().to_bytes().as_slice(),
+                get_lottery_literal_seed(&String::from("1")).as_slice()
+            ],
+            &lottery_program_id
+        );
+    
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            lottery_description: String::from("1"),
+            winners_count: 5,
+            minimum_tickets_amount_required_to_be_sold: 5,
+            tickets_total_amount: 10,
+            is_ended_successfuly: true,
+            ..Lottery::default()
+        };
+    
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+    
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            sol_price_feed_account_pubkey, 
+            btc_price_feed_account_pubkey, 
+            eth_price_feed_account_pubkey,
+            None,
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[&ptc.payer], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotteryAlreadyEnded as u32
+                )
+            )
+        );
+    }
+    // failure - lottery already ended
+
+    // failure - lottery was failed
+    {
+        // 1. LotteryState::Failed
+        let lottery_account_pda = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                Pubkey::default().to_bytes().as_slice(),
+                get_lottery_literal_seed(&String::from("1")).as_slice()
+            ],
+            &lottery_program_id
+        );
+    
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            lottery_description: String::from("1"),
+            winners_count: 5,
+            minimum_tickets_amount_required_to_be_sold: 50,
+            tickets_total_amount: 10,
+            ..Lottery::default()
+        };
+    
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+    
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            sol_price_feed_account_pubkey, 
+            btc_price_feed_account_pubkey, 
+            eth_price_feed_account_pubkey,
+            None,
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[&ptc.payer], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotteryWasNotSuccessfull as u32
+                )
+            )
+        );
+
+        // 2. LotteryState::Unknown
+        let lottery_account_pda = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                Pubkey::default().to_bytes().as_slice(),
+                get_lottery_literal_seed(&String::from("1")).as_slice()
+            ],
+            &lottery_program_id
+        );
+    
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            lottery_description: String::from("1"),
+            winners_count: 5,
+            minimum_tickets_amount_required_to_be_sold: 50,
+            tickets_total_amount: 100,
+            ..Lottery::default()
+        };
+    
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+    
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            sol_price_feed_account_pubkey, 
+            btc_price_feed_account_pubkey, 
+            eth_price_feed_account_pubkey,
+            None,
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[&ptc.payer], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotteryWasNotSuccessfull as u32
+                )
+            )
+        );
+
+        // 3. LotteryState::Unknown
+        let lottery_account_pda = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                Pubkey::default().to_bytes().as_slice(),
+                get_lottery_literal_seed(&String::from("1")).as_slice()
+            ],
+            &lottery_program_id
+        );
+    
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            lottery_description: String::from("1"),
+            winners_count: 5,
+            minimum_tickets_amount_required_to_be_sold: 50,
+            tickets_total_amount: 100,
+            ..Lottery::default()
+        };
+    
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+    
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 150);
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            sol_price_feed_account_pubkey, 
+            btc_price_feed_account_pubkey, 
+            eth_price_feed_account_pubkey,
+            None,
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[&ptc.payer], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotteryWasNotSuccessfull as u32
+                )
+            )
+        );
+    }
+    // failure - lottery was failed
+
+    // failure - insufficient random numbers
+    {
+        let lottery_account_pda = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                Pubkey::default().to_bytes().as_slice(),
+                get_lottery_literal_seed(&String::from("1")).as_slice()
+            ],
+            &lottery_program_id
+        );
+    
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            lottery_description: String::from("1"),
+            winners_count: 35,
+            minimum_tickets_amount_required_to_be_sold: 5,
+            tickets_total_amount: 10,
+            ..Lottery::default()
+        };
+    
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+    
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            sol_price_feed_account_pubkey, 
+            btc_price_feed_account_pubkey, 
+            eth_price_feed_account_pubkey,
+            None,
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[&ptc.payer], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InsufficientRandomNumbers as u32
+                )
+            )
+        );
+    }
+    // failure - insufficient random numbers
+
+    // success - settlement bounty paid to the caller
+    {
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            lottery_description: String::from("1"),
+            winners_count: 5,
+            minimum_tickets_amount_required_to_be_sold: 5,
+            tickets_total_amount: 10,
+            ticket_ranges_total_amount: 10,
+            settlement_bounty: Some(SettlementBounty::Lamports(sol_to_lamports(0.01))),
+            ..Lottery::default()
+        };
+        let mut lottery_account = lottery_account;
+        lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64 + (lottery_account.winners_count as u64 * 34);
+
+        let tickets_ledger = [ 10u8, 5, 8, 2, 1, 3, 4, 9, 7, 6 ]
+            .iter()
+            .enumerate()
+            .map(|(index, user)| [
+                Pubkey::new_from_array([*user; 32]).to_bytes().to_vec(),
+                (index as u32 + 1).to_le_bytes().to_vec()
+            ].concat())
+            .collect::<Vec<Vec<u8>>>()
+            .concat();
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: sol_to_lamports(1.01),
+                    data: vec![
+                        lottery_account.try_to_vec().unwrap(),
+                        vec![0u8; lottery_account.winners_count as usize * 34],
+                        tickets_ledger
+                    ].concat(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        // failure - bounty receiver is missing
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            sol_price_feed_account_pubkey, 
+            btc_price_feed_account_pubkey, 
+            eth_price_feed_account_pubkey,
+            None,
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[&ptc.payer], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidAmountOfSettlementBountyAccounts as u32
+                )
+            )
+        );
+
+        let bounty_receiver_account_pubkey = Pubkey::new_unique();
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            sol_price_feed_account_pubkey, 
+            btc_price_feed_account_pubkey, 
+            eth_price_feed_account_pubkey,
+            None,
+            None,
+            Some(bounty_receiver_account_pubkey),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[&ptc.payer], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, lamports, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { is_ended_successfuly, is_settlement_bounty_paid, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(is_ended_successfuly, true, "invalid is_ended_suc.. flag.");
+        assert_eq!(is_settlement_bounty_paid, true, "invalid settlement bounty flag.");
+        assert_eq!(lamports, sol_to_lamports(1.0), "invalid lottery balance.");
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(bounty_receiver_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(lamports, sol_to_lamports(0.01), "invalid bounty receiver balance.");
+    }
+    // success - settlement bounty paid to the caller
+}
+
+#[tokio::test]
+async fn test_settle_failed_lottery() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        ..Config::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            data: config_account.try_to_vec().unwrap(),
+            lamports: sol_to_lamports(0.01),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add USDC mint account
+    let mut usdc_mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        MintAccount {
+            supply: 10_000_000000,
+            decimals: 6,
+            is_initialized: true,
+            ..MintAccount::default()
+        },
+        usdc_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        config_account.usdc_mint_account,
+        SolanaAccount {
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(0.01),
+            data: usdc_mint_account_data.to_vec(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add USDC mint account
+
+    //////////////////////? add failed lotteries (lamports bounty, usdc bounty, no bounty) & the usdc bounty's token accounts
+    let lottery_authority_account_pubkey = Pubkey::new_unique();
+    let get_lottery_account_pda = |lottery_description: &str| Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            lottery_authority_account_pubkey.to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from(lottery_description)).as_slice()
+        ],
+        &lottery_program_id
+    );
+    let lamports_lottery_account_pda = get_lottery_account_pda("L");
+    let usdc_lottery_account_pda = get_lottery_account_pda("U");
+    let no_bounty_lottery_account_pda = get_lottery_account_pda("N");
+
+    for (lottery_account_pda, lottery_description, settlement_bounty, lamports) in [
+        (lamports_lottery_account_pda, "L", Some(SettlementBounty::Lamports(sol_to_lamports(0.01))), sol_to_lamports(1.01)),
+        (usdc_lottery_account_pda, "U", Some(SettlementBounty::Usdc(2_000000)), sol_to_lamports(1.0)),
+        (no_bounty_lottery_account_pda, "N", None, sol_to_lamports(1.0))
+    ] {
+        pt.add_account(
+            lottery_account_pda.0,
+            SolanaAccount {
+                owner: lottery_program_id,
+                lamports,
+                data: Lottery {
+                    discriminator: Lottery::get_discriminator(),
+                    canonical_bump: lottery_account_pda.1,
+                    authority: lottery_authority_account_pubkey,
+                    starting_time: 100,
+                    ending_time: 200,
+                    winners_count: 2,
+                    minimum_tickets_amount_required_to_be_sold: 5,
+                    tickets_total_amount: 3,
+                    lottery_creation_fee: 5_000000,
+                    settlement_bounty,
+                    lottery_description: String::from(lottery_description),
+                    ..Lottery::default()
+                }.try_to_vec().unwrap(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+
+    let usdc_lottery_usdc_ata = get_associated_token_address(
+        &usdc_lottery_account_pda.0,
+        &config_account.usdc_mint_account
+    );
+    let keeper_usdc_token_account_pubkey = Pubkey::new_unique();
+    for (token_account_pubkey, owner, amount) in [
+        (usdc_lottery_usdc_ata, usdc_lottery_account_pda.0, 10_000000),
+        (keeper_usdc_token_account_pubkey, Pubkey::new_unique(), 0)
+    ] {
+        let mut token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                state: TokenAccountState::Initialized,
+                mint: config_account.usdc_mint_account,
+                owner,
+                amount,
+                ..TokenAccount::default()
+            },
+            token_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            token_account_pubkey,
+            SolanaAccount {
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(0.01),
+                data: token_account_data.to_vec(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add failed lotteries (lamports bounty, usdc bounty, no bounty) & the usdc bounty's token accounts
+
+    let keeper_account_pubkey = Pubkey::new_unique();
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - lottery is not failed yet
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 150);
+
+        let instruction = instruction_settle_failed_lottery(
+            lamports_lottery_account_pda.0,
+            config_account_pda.0,
+            keeper_account_pubkey,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryState as u32
+                )
+            )
+        );
+    }
+    // failure - lottery is not failed yet
+
+    // failure - lottery without settlement bounty
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_settle_failed_lottery(
+            no_bounty_lottery_account_pda.0,
+            config_account_pda.0,
+            keeper_account_pubkey,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::SettlementBountyNotFound as u32
+                )
+            )
+        );
+    }
+    // failure - lottery without settlement bounty
+
+    // success - lamports bounty
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_settle_failed_lottery(
+            lamports_lottery_account_pda.0,
+            config_account_pda.0,
+            keeper_account_pubkey,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, lamports, .. } = ptc
+            .banks_client
+            .get_account(lamports_lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { is_settlement_bounty_paid, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(is_settlement_bounty_paid, true, "invalid settlement bounty flag.");
+        assert_eq!(lamports, sol_to_lamports(1.0), "invalid lottery balance.");
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(keeper_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(lamports, sol_to_lamports(0.01), "invalid keeper balance.");
+    }
+    // success - lamports bounty
+
+    // failure - settlement bounty already paid
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 251);
+
+        let instruction = instruction_settle_failed_lottery(
+            lamports_lottery_account_pda.0,
+            config_account_pda.0,
+            keeper_account_pubkey,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::SettlementBountyAlreadyPaid as u32
+                )
+            )
+        );
+    }
+    // failure - settlement bounty already paid
+
+    // failure - usdc bounty accounts are missing
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_settle_failed_lottery(
+            usdc_lottery_account_pda.0,
+            config_account_pda.0,
+            keeper_usdc_token_account_pubkey,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidAmountOfSettlementBountyAccounts as u32
+                )
+            )
+        );
+    }
+    // failure - usdc bounty accounts are missing
+
+    // success - usdc bounty
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_settle_failed_lottery(
+            usdc_lottery_account_pda.0,
+            config_account_pda.0,
+            keeper_usdc_token_account_pubkey,
+            Some((config_account.usdc_mint_account, TOKEN_STANDARD_PROGRAM_ID))
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        for (token_account_pubkey, expected_amount) in [
+            (usdc_lottery_usdc_ata, 8_000000),
+            (keeper_usdc_token_account_pubkey, 2_000000)
+        ] {
+            let SolanaAccount { data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount, .. } = TokenAccount::unpack(
+                &data
+            ).unwrap();
+
+            assert_eq!(amount, expected_amount, "invalid token account balance.");
+        };
+    }
+    // success - usdc bounty
+}
+
+#[tokio::test]
+async fn test_request_and_fulfil_randomness() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    // local vrf secret key standing in for the vrf oracle
+    let vrf_oracle_secret_key = [5u8; 32];
+    let vrf_oracle_authority = get_vrf_public_key(&vrf_oracle_secret_key);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        pyth_price_feed_ids: [
+            "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d".to_string(), // SOL
+            "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43".to_string(), // BTC
+            "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace".to_string() // ETH
+        ],
+        vrf_oracle_authority,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add lottery account
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let mut lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("1"),
+        winners_count: 2,
+        minimum_tickets_amount_required_to_be_sold: 3,
+        tickets_total_amount: 4,
+        ticket_ranges_total_amount: 4,
+        randomness_source: RandomnessSource::VrfOracle,
+        ..Lottery::default()
+    };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64 + (lottery_account.winners_count as u64 * 34);
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: vec![
+            lottery_account.try_to_vec().unwrap(),
+            vec![0u8; lottery_account.winners_count as usize * 34],
+            Pubkey::new_from_array([1; 32]).to_bytes().to_vec(),
+            1u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([2; 32]).to_bytes().to_vec(),
+            2u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([3; 32]).to_bytes().to_vec(),
+            3u32.to_le_bytes().to_vec(),
+            Pubkey::new_from_array([4; 32]).to_bytes().to_vec(),
+            4u32.to_le_bytes().to_vec()
+        ].concat(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+
+    let randomness_request_account_pda = Pubkey::find_program_address(
+        &[
+            b"randomness_request_account",
+            lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - missing randomness-request account
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0,
+            config_account_pda.0,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            None,
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidAmountOfRandomnessRequestAccounts as u32
+                )
+            )
+        );
+    }
+    // failure - missing randomness-request account
+
+    // failure - randomness is not requested yet
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0,
+            config_account_pda.0,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            Some(randomness_request_account_pda.0),
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::IncorrectProgramId
+            )
+        );
+    }
+    // failure - randomness is not requested yet
+
+    // failure - lottery is not ended yet
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
         change_clock_sysvar(&ptc, 150);
 
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 250_i64;
-        let ending_time = 500_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            Pubkey::new_unique(),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
+        let instruction = instruction_request_randomness(
+            randomness_request_account_pda.0,
+            lottery_account_pda.0,
+            ptc.payer.pubkey(),
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotteryWasNotSuccessfull as u32
+                )
+            )
+        );
+    }
+    // failure - lottery is not ended yet
+
+    // success - request randomness
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 250);
+
+        let instruction = instruction_request_randomness(
+            randomness_request_account_pda.0,
+            lottery_account_pda.0,
+            ptc.payer.pubkey(),
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, owner, .. } = ptc
+            .banks_client
+            .get_account(randomness_request_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            owner,
+            lottery_program_id,
+            "invalid randomness-request account's owner."
+        );
+
+        let randomness_request = RandomnessRequest::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            randomness_request.discriminator,
+            RandomnessRequest::get_discriminator(),
+            "invalid randomness-request discriminator."
+        );
+
+        assert_eq!(
+            randomness_request.canonical_bump,
+            randomness_request_account_pda.1,
+            "invalid randomness-request canonical bump."
+        );
+
+        assert_eq!(
+            randomness_request.lottery,
+            lottery_account_pda.0,
+            "invalid randomness-request lottery."
+        );
+
+        assert_eq!(
+            randomness_request.oracle_authority,
+            vrf_oracle_authority,
+            "invalid randomness-request oracle authority."
+        );
+
+        assert_eq!(
+            randomness_request.requested_at,
+            250,
+            "invalid randomness-request requested_at."
+        );
+
+        assert_eq!(
+            randomness_request.request_seed,
+            solana_sdk::hash::hashv(&[
+                lottery_account_pda.0.to_bytes().as_slice(),
+                [1u8, 2, 3, 4].map(|ticket| [ [ticket; 32].as_slice(), (ticket as u32).to_le_bytes().as_slice() ].concat()).concat().as_slice(),
+                [0u8; 32].as_slice()
+            ]).to_bytes(),
+            "invalid randomness-request seed."
+        );
+
+        assert_eq!(
+            randomness_request.is_fulfilled,
+            false,
+            "invalid randomness-request is_fulfilled flag."
+        );
+    }
+    // success - request randomness
+
+    let SolanaAccount { data, .. } = ptc
+        .banks_client
+        .get_account(randomness_request_account_pda.0)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let message = RandomnessRequest::deserialize(
+        &mut &data[..]
+    ).unwrap().get_message(&randomness_request_account_pda.0);
+
+    let proof = prove_vrf(&vrf_oracle_secret_key, &message);
+    let randomness: [u8; 32] = verify_vrf_proof(&vrf_oracle_authority, &message, &proof).unwrap()[..32].try_into().unwrap();
+
+    // failure - proof is not made by the vrf oracle
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction_fulfil_randomness(
+                    randomness_request_account_pda.0,
+                    lottery_account_pda.0,
+                    config_account_pda.0,
+                    prove_vrf(&[6u8; 32], &message)
+                )
+            ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidRandomnessProof as u32
+                )
+            )
+        );
+    }
+    // failure - proof is not made by the vrf oracle
+
+    // failure - proof is not made over the request's message
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction_fulfil_randomness(
+                    randomness_request_account_pda.0,
+                    lottery_account_pda.0,
+                    config_account_pda.0,
+                    prove_vrf(&vrf_oracle_secret_key, &[1u8; 64])
+                )
+            ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidRandomnessProof as u32
+                )
+            )
+        );
+    }
+    // failure - proof is not made over the request's message
+
+    // failure - randomness fulfilment is timed out
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 200 + 2 * 86_400);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction_fulfil_randomness(
+                    randomness_request_account_pda.0,
+                    lottery_account_pda.0,
+                    config_account_pda.0,
+                    proof
+                )
+            ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::RandomnessFulfilmentTimedOut as u32
+                )
+            )
+        );
+    }
+    // failure - randomness fulfilment is timed out
+
+    // failure - randomness is not fulfilled in time, the lottery is failed (tickets are refunded)
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0,
+            config_account_pda.0,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            Some(randomness_request_account_pda.0),
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotteryWasNotSuccessfull as u32
+                )
+            )
+        );
+    }
+    // failure - randomness is not fulfilled in time, the lottery is failed (tickets are refunded)
+
+    // success - fulfil randomness
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 260);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction_fulfil_randomness(
+                    randomness_request_account_pda.0,
+                    lottery_account_pda.0,
+                    config_account_pda.0,
+                    proof
+                )
+            ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(randomness_request_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let randomness_request = RandomnessRequest::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            randomness_request.is_fulfilled,
+            true,
+            "invalid randomness-request is_fulfilled flag."
+        );
+
+        assert_eq!(
+            randomness_request.fulfilled_at,
+            260,
+            "invalid randomness-request fulfilled_at."
+        );
+
+        assert_eq!(
+            randomness_request.randomness,
+            randomness,
+            "invalid randomness."
+        );
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { is_randomness_fulfilled, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            is_randomness_fulfilled,
+            true,
+            "invalid is_randomness_fulfilled flag."
+        );
+    }
+    // success - fulfil randomness
+
+    // failure - randomness is already fulfilled
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction_fulfil_randomness(
+                    randomness_request_account_pda.0,
+                    lottery_account_pda.0,
+                    config_account_pda.0,
+                    proof
+                )
+            ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::RandomnessAlreadyFulfilled as u32
+                )
+            )
+        );
+    }
+    // failure - randomness is already fulfilled
+
+    // success - end lottery with the vrf randomness
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 270);
+
+        let instruction = instruction_end_lottery_and_pick_winners(
+            lottery_account_pda.0,
+            config_account_pda.0,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            Some(randomness_request_account_pda.0),
+            None,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { is_ended_successfuly, winners, random_numbers_info, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            is_ended_successfuly,
+            true,
+            "invalid is_ended_suc.. flag."
+        );
+
+        let seed = solana_sdk::hash::hash(&randomness).to_bytes();
+        let winners_index = Lottery::get_winners_index(&seed, 4, 2).unwrap();
+        for index in 0..2 {
+            assert_eq!(
+                winners[index].0,
+                Pubkey::new_from_array([winners_index[index] as u8 + 1; 32]),
+                "invalid winner pubkey -> {}", index
+            );
+        };
+
+        assert_eq!(
+            random_numbers_info.price_feeds[0],
+            (
+                randomness_request_account_pda.0,
+                260,
+                0,
+                0
+            ),
+            "invalid random numbers info."
+        );
+    }
+    // success - end lottery with the vrf randomness
+}
+
+#[tokio::test]
+async fn test_withdraw_succeed_lottery() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        lottery_creation_fee: 5_000000, // 5 USDC
+        maximum_number_of_winners: 10,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        maximum_time_for_lottery_account: 1000,
+        minimum_tickets_to_be_sold_in_lottery: 20,
+        max_lottery_description_bytes: 10,
+        sol_treasury: Pubkey::new_from_array([7; 32]),
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add lottery_account authority
+    let lottery_authority_account = Keypair::new();
+    pt.add_account(
+        lottery_authority_account.pubkey(),
+        SolanaAccount {
+            owner: SYSTEM_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery_account authority
+    
+    //////////////////////? add lottery account
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            lottery_authority_account.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("1"),
+        winners_count: 5,
+        minimum_tickets_amount_required_to_be_sold: 5,
+        tickets_total_amount: 10,
+        protocol_fee: 95_000000,
+        lottery_creation_fee: 5_000000,
+        authority: lottery_authority_account.pubkey(),
+        is_ended_successfuly: true,
+        ..Lottery::default()
+    };
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: lottery_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+
+    //////////////////////? add USDC mint account
+    let usdc_mint_account = MintAccount {
+        supply: 1000_000000,
+        decimals: 6,
+        is_initialized: true,
+        ..MintAccount::default()
+    };
+
+    let mut usdc_mint_account_data: [u8; MintAccount::LEN] = [0; MintAccount::LEN];
+    MintAccount::pack(
+        usdc_mint_account,
+        usdc_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    let uscd_mint_solana_account = SolanaAccount {
+        lamports: sol_to_lamports(0.01),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        data: usdc_mint_account_data.to_vec(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account.usdc_mint_account,
+        uscd_mint_solana_account
+    );
+    //////////////////////? add USDC mint account
+    
+    //////////////////////? add fund receiver USDC token account
+    let funding_usdc_token_account_pubkey = Pubkey::new_from_array([1; 32]);
+    let funding_usdc_token_account = TokenAccount {
+        mint: config_account.usdc_mint_account,
+        amount: 0_000000,
+        state: TokenAccountState::Initialized,
+        ..TokenAccount::default()
+    };
+
+    let mut funding_usdc_token_account_data: [u8; TokenAccount::LEN] = [0; TokenAccount::LEN];
+    TokenAccount::pack(
+        funding_usdc_token_account,
+        funding_usdc_token_account_data.as_mut_slice()
+    ).unwrap();
+
+    let funding_usdc_token_solana_account = SolanaAccount {
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        data: funding_usdc_token_account_data.to_vec(),
+        lamports: sol_to_lamports(0.01),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        funding_usdc_token_account_pubkey,
+        funding_usdc_token_solana_account
+    );
+    //////////////////////? add fund receiver USDC token account
+    
+    //////////////////////? add lottery's usdc ata
+    let lottery_ata_pda = get_associated_token_address(
+        &lottery_account_pda.0,
+        &config_account.usdc_mint_account
+    );
+    let lottery_ata = TokenAccount {
+        state: TokenAccountState::Initialized,
+        mint: config_account.usdc_mint_account,
+        amount: 1000_000000,
+        owner: lottery_account_pda.0,
+        ..TokenAccount::default()
+    };
+
+    let mut lottery_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        lottery_ata,
+        lottery_ata_data.as_mut_slice()
+    ).unwrap();
+
+    let lottery_ata_solana_account = SolanaAccount {
+        owner: spl_token::ID,
+        data: lottery_ata_data.to_vec(),
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_ata_pda,
+        lottery_ata_solana_account
+    );
+    //////////////////////? add lottery's usdc ata
+    
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid USDC mint account
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            lottery_ata_pda, 
+            funding_usdc_token_account_pubkey, 
+            Pubkey::default(), 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidUsdcMintAccount as u32
+                )
+            )
+        );
+    }
+    // failure - invalid USDC mint account
+
+    // failure - invalid lottery state
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 35);
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            lottery_ata_pda, 
+            funding_usdc_token_account_pubkey, 
+            config_account.usdc_mint_account, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::LotteryWasNotSuccessfull as u32
+                )
+            )
+        );
+    }
+    // failure - invalid lottery state
+
+    // failure - trying to withdraw funds twice
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            lottery_ata_pda, 
+            funding_usdc_token_account_pubkey, 
+            config_account.usdc_mint_account, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ 
+                instruction.clone(),
+                instruction
+            ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(
+                    LotteryError::FundsAlreadyWithdrawed as u32
+                )
+            )
+        );
+    }
+    // failure - trying to withdraw funds twice
+
+    // failure - invalid lottery authority
+    {
+        // 1. lottery authority in not signed the transaction
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let mut instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            lottery_ata_pda, 
+            funding_usdc_token_account_pubkey, 
+            config_account.usdc_mint_account, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+        instruction.accounts[2].is_signer = false;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[ &ptc.payer ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::MissingRequiredSignature
+            )
+        );
+
+        // 2. lottery authority mismatched
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let unkown_signer = Keypair::new();
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            unkown_signer.pubkey(), 
+            lottery_ata_pda, 
+            funding_usdc_token_account_pubkey, 
+            config_account.usdc_mint_account, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[ 
+                &ptc.payer,
+                &unkown_signer
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryAccountAuthority as u32
+                )
+            )
+        );
+    }
+    // failure - invalid lottery authority
+
+    // failure - invalid lottery USDC ata
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            lottery_authority_account.pubkey(), 
+            funding_usdc_token_account_pubkey, 
+            config_account.usdc_mint_account, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[ 
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryAssociatedUsdcTokenAccount as u32
+                )
+            )
+        );
+    }
+    // failure - invalid lottery USDC ata
+    
+    // success
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            lottery_ata_pda, 
+            funding_usdc_token_account_pubkey, 
+            config_account.usdc_mint_account, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(funding_usdc_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { data: lottery_usdc_ata_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_ata_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { is_creator_withdrawed_when_lottery_was_successful, .. } = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(
+            is_creator_withdrawed_when_lottery_was_successful,
+            true,
+            "invalid lottery's flag."
+        );
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &fund_receiver_usdc_token_account_data
+        ).unwrap();
+
+        assert_eq!(
+            amount,
+            900_000000,
+            "invalid fund receiver token account balance."
+        );
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &lottery_usdc_ata_data
+        ).unwrap();
+
+        assert_eq!(
+            amount,
+            100_000000,
+            "invalid lottery ata balance."
+        );
+    }
+    // success
+
+    // failure - trying to withdraw funds before ending lottery and picking winners
+    {
+        let lottery_account_pda = Pubkey::find_program_address(
+            &[
+                b"lottery_account",
+                lottery_authority_account.pubkey().to_bytes().as_slice(),
+                get_lottery_literal_seed(&String::from("1")).as_slice()
+            ],
+            &lottery_program_id
+        );
+    
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            lottery_description: String::from("1"),
+            winners_count: 5,
+            minimum_tickets_amount_required_to_be_sold: 5,
+            tickets_total_amount: 10,
+            protocol_fee: 95_000000,
+            lottery_creation_fee: 5_000000,
+            authority: lottery_authority_account.pubkey(),
+            is_ended_successfuly: false,
+            ..Lottery::default()
+        };
+    
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+    
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            lottery_ata_pda, 
+            funding_usdc_token_account_pubkey, 
+            config_account.usdc_mint_account, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::WinnersNotSelected as u32
+                )
+            )
+        );
+    }
+    // failure - trying to withdraw funds before ending lottery and picking winners
+
+    // success - pool lottery
+    {
+        // 1000 USDC of ticket sales - 100 USDC of protocol fees => 900 USDC pool, 10% for the creator
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            lottery_description: String::from("1"),
+            winners_count: 5,
+            minimum_tickets_amount_required_to_be_sold: 5,
+            tickets_total_amount: 10,
+            protocol_fee: 95_000000,
+            lottery_creation_fee: 5_000000,
+            authority: lottery_authority_account.pubkey(),
+            is_ended_successfuly: true,
+            pool_creator_share: Some(1_000),
+            ..Lottery::default()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+                    data: lottery_account.try_to_vec().unwrap(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let mut lottery_ata_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                amount: 1000_000000,
+                ..lottery_ata
+            },
+            lottery_ata_data.as_mut_slice()
+        ).unwrap();
+        ptc.set_account(
+            &lottery_ata_pda,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: spl_token::ID,
+                    data: lottery_ata_data.to_vec(),
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let mut funding_usdc_token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            funding_usdc_token_account,
+            funding_usdc_token_account_data.as_mut_slice()
+        ).unwrap();
+        ptc.set_account(
+            &funding_usdc_token_account_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    data: funding_usdc_token_account_data.to_vec(),
+                    lamports: sol_to_lamports(0.01),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            lottery_ata_pda, 
+            funding_usdc_token_account_pubkey, 
+            config_account.usdc_mint_account, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(funding_usdc_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery {
+            is_creator_withdrawed_when_lottery_was_successful,
+            pool_amount,
+            ..
+        } = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(
+            is_creator_withdrawed_when_lottery_was_successful,
+            true,
+            "invalid lottery's flag."
+        );
+        assert_eq!(
+            pool_amount,
+            900_000000,
+            "invalid lottery's pool amount."
+        );
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &fund_receiver_usdc_token_account_data
+        ).unwrap();
+
+        assert_eq!(
+            amount,
+            90_000000,
+            "invalid fund receiver token account balance."
+        );
+    }
+    // success - pool lottery
+
+    // success - tickets paid in SOL, their protocol fee goes to the sol treasury
+    {
+        // 0.08 SOL of ticket sales, 0.002 SOL of protocol fee
+        let lottery_account = Lottery {
+            discriminator: Lottery::get_discriminator(),
+            canonical_bump: lottery_account_pda.1,
+            starting_time: 100,
+            ending_time: 200,
+            lottery_description: String::from("1"),
+            winners_count: 5,
+            minimum_tickets_amount_required_to_be_sold: 5,
+            tickets_total_amount: 10,
+            protocol_fee: 95_000000,
+            lottery_creation_fee: 5_000000,
+            authority: lottery_authority_account.pubkey(),
+            is_ended_successfuly: true,
+            sol_payments_amount: 80_000_000,
+            sol_protocol_fee: 2_000_000,
+            ..Lottery::default()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+                    data: lottery_account.try_to_vec().unwrap(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let mut lottery_ata_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                amount: 1000_000000,
+                ..lottery_ata
+            },
+            lottery_ata_data.as_mut_slice()
+        ).unwrap();
+        ptc.set_account(
+            &lottery_ata_pda,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: spl_token::ID,
+                    data: lottery_ata_data.to_vec(),
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let (sol_payments_vault_pda, _) = get_sol_payments_vault_account_pda(
+            &lottery_account_pda.0,
+            &lottery_program_id
+        ).unwrap();
+
+        let rent = ptc.banks_client.get_rent().await.unwrap();
+        ptc.set_account(
+            &sol_payments_vault_pda,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: rent.minimum_balance(0) + 80_000_000,
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let fund_receiver_sol_account = Keypair::new().pubkey();
+
+        // failure - invalid sol treasury account
+        {
+            ptc
+                .get_new_latest_blockhash()
+                .await
+                .unwrap();
+            change_clock_sysvar(&ptc, 350);
+
+            let instruction = instruction_withdraw_succeed_lottery(
+                lottery_account_pda.0, 
+                config_account_pda.0, 
+                lottery_authority_account.pubkey(), 
+                lottery_ata_pda, 
+                funding_usdc_token_account_pubkey, 
+                config_account.usdc_mint_account, 
+                TOKEN_STANDARD_PROGRAM_ID,
+                None,
+                Some((fund_receiver_sol_account, lottery_authority_account.pubkey()))
+            );
+
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ], 
+                Some(&ptc.payer.pubkey()), 
+                &[
+                    &ptc.payer,
+                    &lottery_authority_account
+                ], 
+                ptc.last_blockhash
+            );
+
+            let error = ptc
+                .banks_client
+                .process_transaction(tx)
+                .await
+                .unwrap_err()
+                .unwrap();
+
+            assert_eq!(
+                error,
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(
+                        LotteryError::InvalidSolTreasuryAccount as u32
+                    )
+                )
+            );
+        }
+        // failure - invalid sol treasury account
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = instruction_withdraw_succeed_lottery(
+            lottery_account_pda.0, 
+            config_account_pda.0, 
+            lottery_authority_account.pubkey(), 
+            lottery_ata_pda, 
+            funding_usdc_token_account_pubkey, 
+            config_account.usdc_mint_account, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            Some((fund_receiver_sol_account, config_account.sol_treasury))
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let fund_receiver_sol_solana_account = ptc
+            .banks_client
+            .get_account(fund_receiver_sol_account)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let sol_treasury_solana_account = ptc
+            .banks_client
+            .get_account(config_account.sol_treasury)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let sol_payments_vault_account = ptc
+            .banks_client
+            .get_account(sol_payments_vault_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            fund_receiver_sol_solana_account.lamports,
+            78_000_000,
+            "invalid fund-receiver sol account balance."
+        );
+
+        assert_eq!(
+            sol_treasury_solana_account.lamports,
+            2_000_000,
+            "invalid sol treasury balance."
+        );
+
+        assert_eq!(
+            sol_payments_vault_account.lamports,
+            rent.minimum_balance(0),
+            "invalid sol-payments-vault balance."
+        );
+    }
+    // success - tickets paid in SOL, their protocol fee goes to the sol treasury
+}
+
+#[tokio::test]
+async fn test_withdraw_lottery_winners() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        lottery_creation_fee: 5_000000, // 5 USDC
+        maximum_number_of_winners: 10,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        maximum_time_for_lottery_account: 1000,
+        minimum_tickets_to_be_sold_in_lottery: 20,
+        max_lottery_description_bytes: 10,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+    
+    //////////////////////? add user account authority
+    let user_account_auth = Keypair::new();
+    pt.add_account(
+        user_account_auth.pubkey(),
+        SolanaAccount {
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add user account authority
+    
+    //////////////////////? add lottery account
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            user_account_auth.pubkey().to_bytes().as_slice(),
+            &lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let mut lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("1"),
+        winners_count: 5,
+        minimum_tickets_amount_required_to_be_sold: 5,
+        tickets_total_amount: 10,
+        protocol_fee: 95_000000,
+        lottery_creation_fee: 5_000000,
+        is_ended_successfuly: true,
+        arbitrary_mint_account_address: Pubkey::new_from_array([5; 32]),
+        winners: vec![
+            (user_account_pda.0, 0, false),
+            (Pubkey::new_from_array([56; 32]), 1, false),
+            (user_account_pda.0, 2, false),
+            (Pubkey::new_from_array([58; 32]), 3, false),
+            (user_account_pda.0, 4, false)
+        ],
+        fund_amount: 1000_000000,
+        ..Lottery::default()
+    };
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: lottery_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+    
+    //////////////////////? add user account
+    let user_account = User {
+        discriminator: User::get_discriminator(),
+        canonical_bump: user_account_pda.1,
+        authority: user_account_auth.pubkey(),
+        lottery: lottery_account_pda.0,
+        ..User::default()
+    };
+
+    let user_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: sol_to_lamports(1.0),
+        data: user_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+    
+    pt.add_account(
+        user_account_pda.0,
+        user_solana_account
+    );
+    //////////////////////? add user account
+    
+    //////////////////////? add unkown user account
+    let unknown_user_account_auth = Keypair::new();
+    let unknown_user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            unknown_user_account_auth.pubkey().to_bytes().as_slice(),
+            &lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let unknown_user_account = User {
+        discriminator: User::get_discriminator(),
+        canonical_bump: unknown_user_account_pda.1,
+        authority: unknown_user_account_auth.pubkey(),
+        lottery: lottery_account_pda.0,
+        ..User::default()
+    };
+
+    let unknown_user_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: sol_to_lamports(1.0),
+        data: unknown_user_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+    
+    pt.add_account(
+        unknown_user_account_pda.0,
+        unknown_user_solana_account
+    );
+    //////////////////////? add unkown user account
+
+    //////////////////////? add Arbitrary mint account
+    let arbitrary_mint_account = MintAccount {
+        supply: 1000_000000,
+        decimals: 6,
+        is_initialized: true,
+        ..MintAccount::default()
+    };
+
+    let mut arbitrary_mint_account_data: [u8; MintAccount::LEN] = [0; MintAccount::LEN];
+    MintAccount::pack(
+        arbitrary_mint_account,
+        arbitrary_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    let arbitrary_mint_solana_account = SolanaAccount {
+        lamports: sol_to_lamports(0.01),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        data: arbitrary_mint_account_data.to_vec(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account.arbitrary_mint_account_address,
+        arbitrary_mint_solana_account
+    );
+    //////////////////////? add Arbitrary mint account
+    
+    //////////////////////? add fund receiver Arbitrary token account
+    let funding_arbitrary_token_account_pubkey = Pubkey::new_from_array([1; 32]);
+    let funding_arbitrary_token_account = TokenAccount {
+        mint: lottery_account.arbitrary_mint_account_address,
+        amount: 0_000000,
+        state: TokenAccountState::Initialized,
+        ..TokenAccount::default()
+    };
+
+    let mut funding_arbitrary_token_account_data: [u8; TokenAccount::LEN] = [0; TokenAccount::LEN];
+    TokenAccount::pack(
+        funding_arbitrary_token_account,
+        funding_arbitrary_token_account_data.as_mut_slice()
+    ).unwrap();
+
+    let funding_arbitrary_token_solana_account = SolanaAccount {
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        data: funding_arbitrary_token_account_data.to_vec(),
+        lamports: sol_to_lamports(0.01),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        funding_arbitrary_token_account_pubkey,
+        funding_arbitrary_token_solana_account
+    );
+    //////////////////////? add fund receiver Arbitrary token account
+    
+    //////////////////////? add lottery's Arbitrary ata
+    let lottery_ata_pda = get_associated_token_address(
+        &lottery_account_pda.0,
+        &lottery_account.arbitrary_mint_account_address
+    );
+    let lottery_ata = TokenAccount {
+        state: TokenAccountState::Initialized,
+        mint: lottery_account.arbitrary_mint_account_address,
+        amount: 1000_000000,
+        owner: lottery_account_pda.0,
+        ..TokenAccount::default()
+    };
+
+    let mut lottery_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        lottery_ata,
+        lottery_ata_data.as_mut_slice()
+    ).unwrap();
+
+    let lottery_ata_solana_account = SolanaAccount {
+        owner: spl_token::ID,
+        data: lottery_ata_data.to_vec(),
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_ata_pda,
+        lottery_ata_solana_account
+    );
+    //////////////////////? add lottery's Arbitrary ata
+    
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid arbitrary mint account
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            lottery_ata_pda, 
+            funding_arbitrary_token_account_pubkey, 
+            Pubkey::new_unique(), 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![],
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidArbitraryMintAccount as u32
+                )
+            )
+        );
+    }
+    // failure - invalid arbitrary mint account
+
+    // failure - invalid lottery arbitrary ata
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            Pubkey::new_unique(), 
+            funding_arbitrary_token_account_pubkey, 
+            lottery_account.arbitrary_mint_account_address, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![],
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount as u32
+                )
+            )
+        );
+    }
+    // failure - invalid lottery arbitrary ata
+
+    // failure - winner not found
+    {
+        // 1. winner tries to double call the instruction
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            lottery_ata_pda, 
+            funding_arbitrary_token_account_pubkey, 
+            lottery_account.arbitrary_mint_account_address, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![],
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction.clone(),
+                instruction
+            ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(
+                    LotteryError::WinnerNotFound as u32
+                )
+            )
+        );
+
+        // 2. unknown user (which is not one of the winners) calls the instruction
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            unknown_user_account_pda.0, 
+            unknown_user_account_auth.pubkey(), 
+            lottery_ata_pda, 
+            funding_arbitrary_token_account_pubkey, 
+            lottery_account.arbitrary_mint_account_address, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![],
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &unknown_user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::WinnerNotFound as u32
+                )
+            )
+        );
+    }
+    // failure - winner not found
+
+    // success
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            lottery_ata_pda, 
+            funding_arbitrary_token_account_pubkey, 
+            lottery_account.arbitrary_mint_account_address, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![],
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: lottery_data_account, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+    
+        let SolanaAccount { data: fund_receiver_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(funding_arbitrary_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { data: lottery_ata_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_ata_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { winners, .. } = Lottery::deserialize(
+            &mut &lottery_data_account[..]
+        ).unwrap();
+
+        assert_eq!(
+            winners[0].2,
+            true,
+            "invalid winners[0].2"
+        );
+        assert_eq!(
+            winners[2].2,
+            true,
+            "invalid winners[2].2"
+        );
+        assert_eq!(
+            winners[4].2,
+            true,
+            "invalid winners[4].2"
+        );
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &fund_receiver_token_account_data
+        ).unwrap();
+
+        assert_eq!(
+            amount,
+            600_000000,
+            "invalid fund receiver token account balance."
+        );
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &lottery_ata_data
+        ).unwrap();
+
+        assert_eq!(
+            amount,
+            400_000000,
+            "invalid lottery ata balance."
+        );
+    }
+    // success
+
+    // success - prize table
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        // 1st 40% - 2nd 25% - 3rd 15% - 4th 10% - 5th 10%, the rounding dust goes to the 1st rank
+        lottery_account.fund_amount = 400_000001;
+        lottery_account.prize_table = Some(vec![ 4_000, 2_500, 1_500, 1_000, 1_000 ]);
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            lottery_ata_pda, 
+            funding_arbitrary_token_account_pubkey, 
+            lottery_account.arbitrary_mint_account_address, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![],
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: fund_receiver_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(funding_arbitrary_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &fund_receiver_token_account_data
+        ).unwrap();
+
+        // (160_000000 + 1) + 60_000000 + 40_000000
+        assert_eq!(
+            amount,
+            600_000000 + 260_000001,
+            "invalid fund receiver token account balance."
+        );
+    }
+    // success - prize table
+    // success - prize mints
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let prize_mint_account_pubkey = Pubkey::new_unique();
+        lottery_account.fund_amount = 100_000000;
+        lottery_account.prize_mints = vec![ (prize_mint_account_pubkey, 1_000) ];
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        let mut prize_mint_account_data = [0u8; MintAccount::LEN];
+        MintAccount::pack(
+            MintAccount {
+                supply: 1_000,
+                decimals: 0,
+                is_initialized: true,
+                ..MintAccount::default()
+            },
+            prize_mint_account_data.as_mut_slice()
+        ).unwrap();
+
+        ptc.set_account(
+            &prize_mint_account_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: prize_mint_account_data.to_vec(),
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let lottery_prize_ata_pubkey = get_associated_token_address(
+            &lottery_account_pda.0,
+            &prize_mint_account_pubkey
+        );
+        let mut lottery_prize_ata_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                amount: 1_000,
+                owner: lottery_account_pda.0,
+                state: TokenAccountState::Initialized,
+                mint: prize_mint_account_pubkey,
+                ..TokenAccount::default()
+            },
+            lottery_prize_ata_data.as_mut_slice()
+        ).unwrap();
+
+        ptc.set_account(
+            &lottery_prize_ata_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: lottery_prize_ata_data.to_vec(),
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let fund_receiver_prize_token_account_pubkey = Pubkey::new_unique();
+        let mut fund_receiver_prize_token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                amount: 0,
+                owner: user_account_auth.pubkey(),
+                state: TokenAccountState::Initialized,
+                mint: prize_mint_account_pubkey,
+                ..TokenAccount::default()
+            },
+            fund_receiver_prize_token_account_data.as_mut_slice()
+        ).unwrap();
+
+        ptc.set_account(
+            &fund_receiver_prize_token_account_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: fund_receiver_prize_token_account_data.to_vec(),
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            lottery_ata_pda, 
+            funding_arbitrary_token_account_pubkey, 
+            lottery_account.arbitrary_mint_account_address, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![
+                (
+                    prize_mint_account_pubkey,
+                    lottery_prize_ata_pubkey,
+                    fund_receiver_prize_token_account_pubkey
+                )
+            ],
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: fund_receiver_prize_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_prize_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &fund_receiver_prize_token_account_data
+        ).unwrap();
+
+        // ranks 1st, 3rd & 5th => 400 + 150 + 100
+        assert_eq!(
+            amount,
+            650,
+            "invalid fund receiver prize token account balance."
+        );
+
+        // success - the last winner empties & closes the prize token account
+        {
+            ptc
+                .get_new_latest_blockhash()
+                .await
+                .unwrap();
+
+            let mut lottery_account = lottery_account.clone();
+            lottery_account.fund_amount = 0;
+            lottery_account.prize_token_accounts_amount = 1;
+            lottery_account.winners[1].2 = true;
+            lottery_account.winners[3].2 = true;
+            ptc.set_account(
+                &lottery_account_pda.0,
+                &SolanaSharedDataAccount::from(
+                    SolanaAccount {
+                        owner: lottery_program_id,
+                        lamports: sol_to_lamports(1.0),
+                        data: lottery_account.try_to_vec().unwrap(),
+                        ..SolanaAccount::default()
+                    }
+                )
+            );
+
+            let mut lottery_prize_ata_data = [0u8; TokenAccount::LEN];
+            TokenAccount::pack(
+                TokenAccount {
+                    amount: 650,
+                    owner: lottery_account_pda.0,
+                    state: TokenAccountState::Initialized,
+                    mint: prize_mint_account_pubkey,
+                    ..TokenAccount::default()
+                },
+                lottery_prize_ata_data.as_mut_slice()
+            ).unwrap();
+
+            ptc.set_account(
+                &lottery_prize_ata_pubkey,
+                &SolanaSharedDataAccount::from(
+                    SolanaAccount {
+                        data: lottery_prize_ata_data.to_vec(),
+                        owner: TOKEN_STANDARD_PROGRAM_ID,
+                        lamports: sol_to_lamports(1.0),
+                        ..SolanaAccount::default()
+                    }
+                )
+            );
+
+            let instruction = instruction_withdraw_lottery_winners(
+                lottery_account_pda.0, 
+                user_account_pda.0, 
+                user_account_auth.pubkey(), 
+                lottery_ata_pda, 
+                funding_arbitrary_token_account_pubkey, 
+                lottery_account.arbitrary_mint_account_address, 
+                TOKEN_STANDARD_PROGRAM_ID, 
+                config_account_pda.0,
+                vec![
+                    (
+                        prize_mint_account_pubkey,
+                        lottery_prize_ata_pubkey,
+                        fund_receiver_prize_token_account_pubkey
+                    )
+                ],
+                None,
+                None
+            );
+
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ], 
+                Some(&ptc.payer.pubkey()), 
+                &[
+                    &ptc.payer,
+                    &user_account_auth
+                ], 
+                ptc.last_blockhash
+            );
+
+            ptc
+                .banks_client
+                .process_transaction(tx)
+                .await
+                .unwrap();
+
+            let lottery_prize_ata = ptc
+                .banks_client
+                .get_account(lottery_prize_ata_pubkey)
+                .await
+                .unwrap();
+
+            assert_eq!(lottery_prize_ata, None, "lottery's prize token account is not closed.");
+
+            let SolanaAccount { data, lamports, .. } = ptc
+                .banks_client
+                .get_account(lottery_account_pda.0)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let Lottery { prize_token_accounts_amount, .. } = Lottery::deserialize(
+                &mut &data[..]
+            ).unwrap();
+
+            assert_eq!(prize_token_accounts_amount, 0, "invalid lottery account's prize_token_accounts_amount.");
+            assert_eq!(lamports, sol_to_lamports(2.0), "invalid lottery account's balance.");
+        }
+        // success - the last winner empties & closes the prize token account
+    }
+    // success - prize mints
+    // success - nft prizes
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        // one nft per rank, the user holds the 1st, 3rd & 5th ranks
+        let nft_prize_mint_accounts: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        lottery_account.fund_amount = 0;
+        lottery_account.prize_mints = vec![];
+        lottery_account.nft_prizes = Some(nft_prize_mint_accounts.clone());
+        lottery_account.prize_token_accounts_amount = 5;
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        let mut nft_prizes_accounts: Vec<(Pubkey, Pubkey, Pubkey)> = Vec::new();
+        for nft_prize_mint_account in nft_prize_mint_accounts.iter() {
+            let mut nft_prize_mint_account_data = [0u8; MintAccount::LEN];
+            MintAccount::pack(
+                MintAccount {
+                    supply: 1,
+                    decimals: 0,
+                    is_initialized: true,
+                    ..MintAccount::default()
+                },
+                nft_prize_mint_account_data.as_mut_slice()
+            ).unwrap();
+
+            ptc.set_account(
+                nft_prize_mint_account,
+                &SolanaSharedDataAccount::from(
+                    SolanaAccount {
+                        data: nft_prize_mint_account_data.to_vec(),
+                        owner: TOKEN_STANDARD_PROGRAM_ID,
+                        lamports: sol_to_lamports(1.0),
+                        ..SolanaAccount::default()
+                    }
+                )
+            );
+
+            let lottery_nft_ata_pubkey = get_associated_token_address(
+                &lottery_account_pda.0,
+                nft_prize_mint_account
+            );
+            let fund_receiver_nft_token_account_pubkey = Pubkey::new_unique();
+
+            for (token_account_pubkey, owner, amount) in [
+                (lottery_nft_ata_pubkey, lottery_account_pda.0, 1),
+                (fund_receiver_nft_token_account_pubkey, user_account_auth.pubkey(), 0)
+            ] {
+                let mut token_account_data = [0u8; TokenAccount::LEN];
+                TokenAccount::pack(
+                    TokenAccount {
+                        amount,
+                        owner,
+                        state: TokenAccountState::Initialized,
+                        mint: *nft_prize_mint_account,
+                        ..TokenAccount::default()
+                    },
+                    token_account_data.as_mut_slice()
+                ).unwrap();
+
+                ptc.set_account(
+                    &token_account_pubkey,
+                    &SolanaSharedDataAccount::from(
+                        SolanaAccount {
+                            data: token_account_data.to_vec(),
+                            owner: TOKEN_STANDARD_PROGRAM_ID,
+                            lamports: sol_to_lamports(1.0),
+                            ..SolanaAccount::default()
+                        }
+                    )
+                );
+            };
+
+            nft_prizes_accounts.push(
+                (
+                    *nft_prize_mint_account,
+                    lottery_nft_ata_pubkey,
+                    fund_receiver_nft_token_account_pubkey
+                )
+            );
+        };
+
+        // failure - nft of another rank
+        {
+            let instruction = instruction_withdraw_lottery_winners(
+                lottery_account_pda.0, 
+                user_account_pda.0, 
+                user_account_auth.pubkey(), 
+                lottery_ata_pda, 
+                funding_arbitrary_token_account_pubkey, 
+                lottery_account.arbitrary_mint_account_address, 
+                TOKEN_STANDARD_PROGRAM_ID, 
+                config_account_pda.0,
+                vec![ nft_prizes_accounts[0], nft_prizes_accounts[1], nft_prizes_accounts[4] ],
+                None,
+                None
+            );
+
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ], 
+                Some(&ptc.payer.pubkey()), 
+                &[
+                    &ptc.payer,
+                    &user_account_auth
+                ], 
+                ptc.last_blockhash
+            );
+
+            let error = ptc
+                .banks_client
+                .process_transaction(tx)
+                .await
+                .unwrap_err()
+                .unwrap();
+
+            assert_eq!(
+                error,
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(
+                        LotteryError::InvalidNftPrizeMintAccount as u32
+                    )
+                )
+            );
+        }
+        // failure - nft of another rank
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            lottery_ata_pda, 
+            funding_arbitrary_token_account_pubkey, 
+            lottery_account.arbitrary_mint_account_address, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![ nft_prizes_accounts[0], nft_prizes_accounts[2], nft_prizes_accounts[4] ],
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        for (rank, (_, _, fund_receiver_nft_token_account_pubkey)) in nft_prizes_accounts.iter().enumerate() {
+            let SolanaAccount { data, .. } = ptc
+                .banks_client
+                .get_account(*fund_receiver_nft_token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount, .. } = TokenAccount::unpack(
+                &data
+            ).unwrap();
+
+            assert_eq!(
+                amount,
+                if rank % 2 == 0 { 1 } else { 0 },
+                "invalid fund receiver nft token account balance."
+            );
+        };
+
+        // the withdrawn nfts' token accounts are closed, their rent_exempts go to the lottery account
+        for (rank, (_, lottery_nft_ata_pubkey, _)) in nft_prizes_accounts.iter().enumerate() {
+            let lottery_nft_ata = ptc
+                .banks_client
+                .get_account(*lottery_nft_ata_pubkey)
+                .await
+                .unwrap();
+
+            assert_eq!(lottery_nft_ata.is_none(), rank % 2 == 0, "invalid lottery's nft token account state.");
+        };
+
+        let SolanaAccount { data, lamports, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { prize_token_accounts_amount, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(prize_token_accounts_amount, 2, "invalid lottery account's prize_token_accounts_amount.");
+        assert_eq!(lamports, sol_to_lamports(4.0), "invalid lottery account's balance.");
+    }
+    // success - nft prizes
+    // success - sol prize
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let (
+            prize_vault_account,
+            prize_vault_canonical_bump
+        ) = get_prize_vault_account_pda(
+            &lottery_account_pda.0,
+            &lottery_program_id
+        ).unwrap();
+
+        lottery_account.nft_prizes = None;
+        lottery_account.sol_prize_amount = sol_to_lamports(5.0);
+        lottery_account.prize_vault_canonical_bump = prize_vault_canonical_bump;
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        let prize_vault_rent_exempt = (
+            (ACCOUNT_STORAGE_OVERHEAD * DEFAULT_LAMPORTS_PER_BYTE_YEAR) as f64 * DEFAULT_EXEMPTION_THRESHOLD
+        ) as u64;
+        ptc.set_account(
+            &prize_vault_account,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: sol_to_lamports(5.0) + prize_vault_rent_exempt,
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let fund_receiver_sol_prize_account = Pubkey::new_unique();
+        ptc.set_account(
+            &fund_receiver_sol_prize_account,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: SYSTEM_PROGRAM_ID,
+                    lamports: sol_to_lamports(0.1),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            lottery_ata_pda, 
+            funding_arbitrary_token_account_pubkey, 
+            lottery_account.arbitrary_mint_account_address, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![],
+            Some(fund_receiver_sol_prize_account),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_sol_prize_account)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // ranks 1st, 3rd & 5th => 2.0 + 0.75 + 0.5 SOL
+        assert_eq!(
+            lamports,
+            sol_to_lamports(0.1) + sol_to_lamports(3.25),
+            "invalid fund receiver sol prize balance."
+        );
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(prize_vault_account)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            lamports,
+            sol_to_lamports(1.75) + prize_vault_rent_exempt,
+            "invalid prize-vault balance."
+        );
+    }
+    // success - sol prize
+    // success - pool
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        // 1100 USDC of ticket sales - 100 USDC of protocol fees => 1000 USDC pool, 10% for the creator
+        lottery_account.fund_amount = 0;
+        lottery_account.sol_prize_amount = 0;
+        lottery_account.pool_creator_share = Some(1_000);
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        let mut usdc_mint_account_data = [0u8; MintAccount::LEN];
+        MintAccount::pack(
+            MintAccount {
+                supply: 10_000_000000,
+                decimals: 6,
+                is_initialized: true,
+                ..MintAccount::default()
+            },
+            usdc_mint_account_data.as_mut_slice()
+        ).unwrap();
+        ptc.set_account(
+            &config_account.usdc_mint_account,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(0.01),
+                    data: usdc_mint_account_data.to_vec(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let lottery_usdc_ata_pda = get_associated_token_address(
+            &lottery_account_pda.0,
+            &config_account.usdc_mint_account
+        );
+        let mut lottery_usdc_ata_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                state: TokenAccountState::Initialized,
+                mint: config_account.usdc_mint_account,
+                amount: 1100_000000,
+                owner: lottery_account_pda.0,
+                ..TokenAccount::default()
+            },
+            lottery_usdc_ata_data.as_mut_slice()
+        ).unwrap();
+        ptc.set_account(
+            &lottery_usdc_ata_pda,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(0.01),
+                    data: lottery_usdc_ata_data.to_vec(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let fund_receiver_usdc_token_account_pubkey = Pubkey::new_unique();
+        let mut fund_receiver_usdc_token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                state: TokenAccountState::Initialized,
+                mint: config_account.usdc_mint_account,
+                owner: user_account_auth.pubkey(),
+                ..TokenAccount::default()
+            },
+            fund_receiver_usdc_token_account_data.as_mut_slice()
+        ).unwrap();
+        ptc.set_account(
+            &fund_receiver_usdc_token_account_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(0.01),
+                    data: fund_receiver_usdc_token_account_data.to_vec(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        // failure - missing pool accounts
+        {
+            let instruction = instruction_withdraw_lottery_winners(
+                lottery_account_pda.0, 
+                user_account_pda.0, 
+                user_account_auth.pubkey(), 
+                lottery_ata_pda, 
+                funding_arbitrary_token_account_pubkey, 
+                lottery_account.arbitrary_mint_account_address, 
+                TOKEN_STANDARD_PROGRAM_ID, 
+                config_account_pda.0,
+                vec![],
+                None,
+                None
+            );
+
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ], 
+                Some(&ptc.payer.pubkey()), 
+                &[
+                    &ptc.payer,
+                    &user_account_auth
+                ], 
+                ptc.last_blockhash
+            );
+
+            let error = ptc
+                .banks_client
+                .process_transaction(tx)
+                .await
+                .unwrap_err()
+                .unwrap();
+
+            assert_eq!(
+                error,
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(
+                        LotteryError::InvalidAmountOfPoolAccounts as u32
+                    )
+                )
+            );
+        }
+        // failure - missing pool accounts
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            lottery_ata_pda, 
+            funding_arbitrary_token_account_pubkey, 
+            lottery_account.arbitrary_mint_account_address, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![],
+            None,
+            Some(
+                (
+                    config_account.usdc_mint_account,
+                    lottery_usdc_ata_pda,
+                    fund_receiver_usdc_token_account_pubkey
+                )
+            )
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_usdc_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &fund_receiver_usdc_token_account_data
+        ).unwrap();
+
+        // 900 USDC prize => ranks 1st, 3rd & 5th => 360 + 135 + 90 USDC
+        assert_eq!(
+            amount,
+            585_000000,
+            "invalid fund receiver usdc token account balance."
+        );
+
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { pool_amount, .. } = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(
+            pool_amount,
+            1000_000000,
+            "invalid lottery's pool amount."
+        );
+    }
+    // success - pool
+
+    // success - token-2022 arbitrary mint with a 1% transfer fee, the winner receives its prizes minus the fee
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let token_2022_arbitrary_mint_account_pubkey = Pubkey::new_unique();
+        ptc.set_account(
+            &token_2022_arbitrary_mint_account_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: pack_token_2022_mint_account(
+                        Token2022MintAccount {
+                            supply: 1000_000000,
+                            decimals: 6,
+                            is_initialized: true,
+                            ..Token2022MintAccount::default()
+                        },
+                        &[ ExtensionType::TransferFeeConfig ],
+                        100
+                    ),
+                    owner: TOKEN_2022_PROGRAM_ID,
+                    lamports: sol_to_lamports(0.01),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        // the lottery received (and recorded) 990 tokens of a 1000 tokens fund, 198 tokens per rank
+        let lottery_token_2022_ata_pubkey = get_associated_token_address_with_program_id(
+            &lottery_account_pda.0,
+            &token_2022_arbitrary_mint_account_pubkey,
+            &TOKEN_2022_PROGRAM_ID
+        );
+        let fund_receiver_token_2022_account_pubkey = Pubkey::new_unique();
+        for (token_account_pubkey, owner, amount) in [
+            (lottery_token_2022_ata_pubkey, lottery_account_pda.0, 990_000000),
+            (fund_receiver_token_2022_account_pubkey, user_account_auth.pubkey(), 0)
+        ] {
+            ptc.set_account(
+                &token_account_pubkey,
+                &SolanaSharedDataAccount::from(
+                    SolanaAccount {
+                        data: pack_token_2022_token_account(
+                            Token2022Account {
+                                amount,
+                                owner,
+                                state: Token2022AccountState::Initialized,
+                                mint: token_2022_arbitrary_mint_account_pubkey,
+                                ..Token2022Account::default()
+                            },
+                            &[ ExtensionType::TransferFeeAmount ]
+                        ),
+                        owner: TOKEN_2022_PROGRAM_ID,
+                        lamports: sol_to_lamports(0.01),
+                        ..SolanaAccount::default()
+                    }
+                )
+            );
+        };
+
+        lottery_account.arbitrary_mint_account_address = token_2022_arbitrary_mint_account_pubkey;
+        lottery_account.fund_amount = 990_000000;
+        lottery_account.prize_table = None;
+        lottery_account.prize_mints = vec![];
+        lottery_account.nft_prizes = None;
+        lottery_account.prize_token_accounts_amount = 0;
+        lottery_account.sol_prize_amount = 0;
+        lottery_account.pool_creator_share = None;
+        lottery_account.pool_amount = 0;
+        lottery_account.winners
+            .iter_mut()
+            .for_each(|winner| winner.2 = false);
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        let instruction = instruction_withdraw_lottery_winners(
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            lottery_token_2022_ata_pubkey, 
+            fund_receiver_token_2022_account_pubkey, 
+            token_2022_arbitrary_mint_account_pubkey, 
+            TOKEN_STANDARD_PROGRAM_ID, 
+            config_account_pda.0,
+            vec![],
+            None,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        // ranks 1st, 3rd & 5th => 594 tokens sent, 1% withheld by the mint
+        for (token_account_pubkey, expected_balance, message) in [
+            (fund_receiver_token_2022_account_pubkey, 594_000000 - 5_940000, "invalid fund receiver token account balance."),
+            (lottery_token_2022_ata_pubkey, 990_000000 - 594_000000, "invalid lottery ata balance.")
+        ] {
+            let SolanaAccount { data: token_account_data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(
+                StateWithExtensions::<Token2022Account>::unpack(&token_account_data).unwrap().base.amount,
+                expected_balance,
+                "{}",
+                message
+            );
+        };
+    }
+    // success - token-2022 arbitrary mint with a 1% transfer fee, the winner receives its prizes minus the fee
+}
+
+#[tokio::test]
+async fn test_distribute_prizes() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        ..Config::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            data: config_account.try_to_vec().unwrap(),
+            lamports: sol_to_lamports(0.01),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add user accounts (2 winners & an unknown user)
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let users_account_auth = [ Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique() ];
+    let users_account_pda = users_account_auth.map(|user_account_auth| Pubkey::find_program_address(
+        &[
+            b"user_account",
+            user_account_auth.to_bytes().as_slice(),
+            lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    ));
+
+    for (user_account_auth, user_account_pda) in users_account_auth.iter().zip(users_account_pda.iter()) {
+        pt.add_account(
+            user_account_pda.0,
+            SolanaAccount {
+                owner: lottery_program_id,
+                lamports: sol_to_lamports(0.01),
+                data: User {
+                    discriminator: User::get_discriminator(),
+                    canonical_bump: user_account_pda.1,
+                    authority: *user_account_auth,
+                    lottery: lottery_account_pda.0,
+                    ..User::default()
+                }.try_to_vec().unwrap(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add user accounts (2 winners & an unknown user)
+
+    //////////////////////? add lottery account
+    let lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("1"),
+        winners_count: 3,
+        minimum_tickets_amount_required_to_be_sold: 5,
+        tickets_total_amount: 10,
+        is_ended_successfuly: true,
+        arbitrary_mint_account_address: Pubkey::new_from_array([5; 32]),
+        winners: vec![
+            (users_account_pda[0].0, 0, false),
+            (users_account_pda[1].0, 1, true),
+            (users_account_pda[0].0, 2, false)
+        ],
+        fund_amount: 900_000000,
+        ..Lottery::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery account
+
+    //////////////////////? add Arbitrary mint account & lottery's Arbitrary ata
+    let mut arbitrary_mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        MintAccount {
+            supply: 900_000000,
+            decimals: 6,
+            is_initialized: true,
+            ..MintAccount::default()
+        },
+        arbitrary_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        lottery_account.arbitrary_mint_account_address,
+        SolanaAccount {
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(0.01),
+            data: arbitrary_mint_account_data.to_vec(),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let lottery_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &lottery_account.arbitrary_mint_account_address
+    );
+    let mut lottery_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            state: TokenAccountState::Initialized,
+            mint: lottery_account.arbitrary_mint_account_address,
+            amount: 900_000000,
+            owner: lottery_account_pda.0,
+            ..TokenAccount::default()
+        },
+        lottery_ata_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        lottery_ata_pubkey,
+        SolanaAccount {
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(0.01),
+            data: lottery_ata_data.to_vec(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add Arbitrary mint account & lottery's Arbitrary ata
+
+    // winner's accounts -> (user account, user-account's authority, authority's arbitrary ata)
+    let users_winner_accounts = users_account_auth
+        .iter()
+        .zip(users_account_pda.iter())
+        .map(|(user_account_auth, user_account_pda)| (
+            user_account_pda.0,
+            *user_account_auth,
+            get_associated_token_address(
+                user_account_auth,
+                &lottery_account.arbitrary_mint_account_address
+            )
+        ))
+        .collect::<Vec<(Pubkey, Pubkey, Pubkey)>>();
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - invalid amount of winners accounts
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let mut instruction = instruction_distribute_prizes(
+            lottery_account_pda.0,
+            ptc.payer.pubkey(),
+            lottery_ata_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            vec![ users_winner_accounts[0], users_winner_accounts[1] ]
+        );
+        instruction.accounts.pop();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidAmountOfWinnersAccounts as u32
+                )
+            )
+        );
+    }
+    // failure - invalid amount of winners accounts
+
+    // failure - unknown user & invalid winner's ata
+    for (winners_accounts, expected_error) in [
+        (vec![ users_winner_accounts[2] ], LotteryError::WinnerNotFound),
+        (
+            vec![ (users_winner_accounts[0].0, users_winner_accounts[0].1, Pubkey::new_unique()) ],
+            LotteryError::InvalidWinnerAssociatedTokenAccount
+        )
+    ] {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_distribute_prizes(
+            lottery_account_pda.0,
+            ptc.payer.pubkey(),
+            lottery_ata_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            winners_accounts
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    expected_error as u32
+                )
+            )
+        );
+    };
+    // failure - unknown user & invalid winner's ata
+
+    // success - unclaimed ranks paid, withdrawn winner skipped
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_distribute_prizes(
+            lottery_account_pda.0,
+            ptc.payer.pubkey(),
+            lottery_ata_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            vec![ users_winner_accounts[0], users_winner_accounts[1] ]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { winners, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(
+            winners.iter().all(|(_, _, is_withdrawed)| *is_withdrawed == true),
+            true,
+            "invalid winners' withdraw flags."
+        );
+
+        for (token_account_pubkey, expected_amount) in [
+            (lottery_ata_pubkey, 300_000000),
+            (users_winner_accounts[0].2, 600_000000)
+        ] {
+            let SolanaAccount { data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount, .. } = TokenAccount::unpack(
+                &data
+            ).unwrap();
+
+            assert_eq!(amount, expected_amount, "invalid token account balance.");
+        };
+
+        assert_eq!(
+            ptc
+                .banks_client
+                .get_account(users_winner_accounts[1].2)
+                .await
+                .unwrap()
+                .is_none(),
+            true,
+            "withdrawn winner's ata shouldn't be created."
+        );
+    }
+    // success - unclaimed ranks paid, withdrawn winner skipped
+
+    // failure - prizes besides the fund are not distributable
+    {
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: sol_to_lamports(1.0),
+                    data: Lottery {
+                        sol_prize_amount: sol_to_lamports(1.0),
+                        ..lottery_account.clone()
+                    }.try_to_vec().unwrap(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_distribute_prizes(
+            lottery_account_pda.0,
+            ptc.payer.pubkey(),
+            lottery_ata_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            spl_associated_token_account::ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0,
+            vec![ users_winner_accounts[0] ]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[&ptc.payer],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::PrizesNotDistributable as u32
+                )
+            )
+        );
+    }
+    // failure - prizes besides the fund are not distributable
+}
+
+#[tokio::test]
+async fn test_reclaim_unclaimed_prizes() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add treasury account & its authority
+    let treasury_authority = Keypair::new();
+    let treasury_account_pubkey = Pubkey::new_unique();
+    let usdc_mint_account_pubkey = Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+    let mut treasury_account_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            state: TokenAccountState::Initialized,
+            mint: usdc_mint_account_pubkey,
+            owner: treasury_authority.pubkey(),
+            ..TokenAccount::default()
+        },
+        treasury_account_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        treasury_account_pubkey,
+        SolanaAccount {
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(0.01),
+            data: treasury_account_data.to_vec(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add treasury account & its authority
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: usdc_mint_account_pubkey,
+        treasury: treasury_account_pubkey,
+        prize_claim_window: 1000,
+        unclaimed_prizes_receiver: UnclaimedPrizesReceiver::Creator,
+        ..Config::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            data: config_account.try_to_vec().unwrap(),
+            lamports: sol_to_lamports(0.01),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add lottery account (rank 0 withdrawn, rank 1 unclaimed), its prize-vault & the unclaimed winner's user account
+    let prize_mint_account_pubkey = Pubkey::new_unique();
+    let nft_prize_mint_accounts = [ Pubkey::new_unique(), Pubkey::new_unique() ];
+
+    let lottery_auth = Keypair::new();
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            lottery_auth.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let prize_vault_pda = get_prize_vault_account_pda(
+        &lottery_account_pda.0,
+        &lottery_program_id
+    ).unwrap();
+
+    let user_account_auth = Keypair::new();
+    let user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            user_account_auth.pubkey().to_bytes().as_slice(),
+            lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let mut lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        authority: lottery_auth.pubkey(),
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("1"),
+        winners_count: 2,
+        minimum_tickets_amount_required_to_be_sold: 5,
+        tickets_total_amount: 10,
+        is_ended_successfuly: true,
+        is_creator_withdrawed_when_lottery_was_successful: true,
+        is_protocol_fee_claimed: true,
+        arbitrary_mint_account_address: Pubkey::new_from_array([5; 32]),
+        winners: vec![
+            (Pubkey::new_from_array([56; 32]), 0, true),
+            (user_account_pda.0, 1, false)
+        ],
+        fund_amount: 200_000000,
+        sol_prize_amount: sol_to_lamports(1.0),
+        prize_vault_canonical_bump: prize_vault_pda.1,
+        prize_mints: vec![ (prize_mint_account_pubkey, 1_000) ],
+        nft_prizes: Some(nft_prize_mint_accounts.to_vec()),
+        prize_token_accounts_amount: 2, // rank 0's nft token account is closed by its withdrawal
+        ..Lottery::default()
+    };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+
+    pt.add_account(
+        lottery_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        }
+    );
+
+    // rank 1's sol prize is left in the prize-vault
+    let prize_vault_rent_exempt = (
+        (ACCOUNT_STORAGE_OVERHEAD * DEFAULT_LAMPORTS_PER_BYTE_YEAR) as f64 * DEFAULT_EXEMPTION_THRESHOLD
+    ) as u64;
+    pt.add_account(
+        prize_vault_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(0.5) + prize_vault_rent_exempt,
+            ..SolanaAccount::default()
+        }
+    );
+
+    pt.add_account(
+        user_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(0.01),
+            data: User {
+                discriminator: User::get_discriminator(),
+                canonical_bump: user_account_pda.1,
+                authority: user_account_auth.pubkey(),
+                lottery: lottery_account_pda.0,
+                ..User::default()
+            }.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery account (rank 0 withdrawn, rank 1 unclaimed), its prize-vault & the unclaimed winner's user account
+
+    //////////////////////? add mint accounts & token accounts
+    for (mint_account_pubkey, decimals) in [
+        (lottery_account.arbitrary_mint_account_address, 6),
+        (usdc_mint_account_pubkey, 6),
+        (prize_mint_account_pubkey, 6),
+        (nft_prize_mint_accounts[1], 0)
+    ] {
+        let mut mint_account_data = [0u8; MintAccount::LEN];
+        MintAccount::pack(
+            MintAccount {
+                supply: 1000_000000,
+                decimals,
+                is_initialized: true,
+                ..MintAccount::default()
+            },
+            mint_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            mint_account_pubkey,
+            SolanaAccount {
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(0.01),
+                data: mint_account_data.to_vec(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+
+    let lottery_arbitrary_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &lottery_account.arbitrary_mint_account_address
+    );
+    let lottery_usdc_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &usdc_mint_account_pubkey
+    );
+    let lottery_prize_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &prize_mint_account_pubkey
+    );
+    let lottery_nft_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &nft_prize_mint_accounts[1]
+    );
+    let fund_receiver_arbitrary_token_account_pubkey = Pubkey::new_unique();
+    let fund_receiver_usdc_token_account_pubkey = Pubkey::new_unique();
+    let fund_receiver_prize_token_account_pubkey = Pubkey::new_unique();
+    let fund_receiver_nft_token_account_pubkey = Pubkey::new_unique();
+
+    for (token_account_pubkey, mint, owner, amount) in [
+        (lottery_arbitrary_ata_pubkey, lottery_account.arbitrary_mint_account_address, lottery_account_pda.0, 100_000000),
+        (lottery_usdc_ata_pubkey, usdc_mint_account_pubkey, lottery_account_pda.0, 0),
+        (fund_receiver_arbitrary_token_account_pubkey, lottery_account.arbitrary_mint_account_address, treasury_authority.pubkey(), 0),
+        (fund_receiver_usdc_token_account_pubkey, usdc_mint_account_pubkey, lottery_auth.pubkey(), 0),
+        (lottery_prize_ata_pubkey, prize_mint_account_pubkey, lottery_account_pda.0, 500),
+        (fund_receiver_prize_token_account_pubkey, prize_mint_account_pubkey, treasury_authority.pubkey(), 0),
+        (lottery_nft_ata_pubkey, nft_prize_mint_accounts[1], lottery_account_pda.0, 1),
+        (fund_receiver_nft_token_account_pubkey, nft_prize_mint_accounts[1], treasury_authority.pubkey(), 0)
+    ] {
+        let mut token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                state: TokenAccountState::Initialized,
+                mint,
+                owner,
+                amount,
+                ..TokenAccount::default()
+            },
+            token_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            token_account_pubkey,
+            SolanaAccount {
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(0.01),
+                data: token_account_data.to_vec(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add mint accounts & token accounts
+
+    let fund_receiver_rent_exempt_account_pubkey = Pubkey::new_unique();
+    let fund_receiver_sol_prize_account_pubkey = Pubkey::new_unique();
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - claim window not closed & invalid reclaimer
+    for (current_time, reclaimer, expected_error) in [
+        (1100, &lottery_auth, LotteryError::ClaimWindowNotClosed),
+        (1300, &treasury_authority, LotteryError::InvalidUnclaimedPrizesReclaimer)
+    ] {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, current_time);
+
+        let instruction = instruction_reclaim_unclaimed_prizes(
+            lottery_account_pda.0,
+            reclaimer.pubkey(),
+            fund_receiver_rent_exempt_account_pubkey,
+            lottery_arbitrary_ata_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account_pda.0,
+            treasury_account_pubkey,
+            vec![],
+            vec![],
+            Some(fund_receiver_sol_prize_account_pubkey),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                reclaimer
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    expected_error as u32
+                )
+            )
+        );
+    };
+    // failure - claim window not closed & invalid reclaimer
+
+    let close_lottery_instruction = instruction_close_lottery_account_and_usdc_token_account(
+        config_account_pda.0,
+        lottery_account_pda.0,
+        lottery_auth.pubkey(),
+        usdc_mint_account_pubkey,
+        lottery_usdc_ata_pubkey,
+        fund_receiver_usdc_token_account_pubkey,
+        fund_receiver_rent_exempt_account_pubkey,
+        TOKEN_STANDARD_PROGRAM_ID,
+        true,
+        None
+    );
+
+    // failure - close lottery before reclaiming the unclaimed prizes
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 1300);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ close_lottery_instruction.clone() ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::SucceedLotteryNotSettled as u32
+                )
+            )
+        );
+    }
+    // failure - close lottery before reclaiming the unclaimed prizes
+
+    // success - treasury reclaims (config policy)
+    {
+        ptc.set_account(
+            &config_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: sol_to_lamports(0.01),
+                    data: Config {
+                        unclaimed_prizes_receiver: UnclaimedPrizesReceiver::Treasury,
+                        ..config_account.clone()
+                    }.try_to_vec().unwrap(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 1300);
+
+        let instruction = instruction_reclaim_unclaimed_prizes(
+            lottery_account_pda.0,
+            treasury_authority.pubkey(),
+            fund_receiver_rent_exempt_account_pubkey,
+            lottery_arbitrary_ata_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account_pda.0,
+            treasury_account_pubkey,
+            vec![ (prize_mint_account_pubkey, lottery_prize_ata_pubkey, fund_receiver_prize_token_account_pubkey) ],
+            vec![ (nft_prize_mint_accounts[1], lottery_nft_ata_pubkey, fund_receiver_nft_token_account_pubkey) ],
+            Some(fund_receiver_sol_prize_account_pubkey),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &treasury_authority
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { winners, is_unclaimed_prizes_reclaimed, prize_token_accounts_amount, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(is_unclaimed_prizes_reclaimed, true, "invalid unclaimed prizes reclaimed flag.");
+        assert_eq!(prize_token_accounts_amount, 0, "invalid lottery account's prize_token_accounts_amount.");
+        assert_eq!(
+            winners.iter().all(|(_, _, is_withdrawed)| *is_withdrawed == true),
+            true,
+            "invalid winners' withdraw flags."
+        );
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_arbitrary_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &data
+        ).unwrap();
+
+        assert_eq!(amount, 100_000000, "invalid fund receiver balance.");
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_sol_prize_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(lamports, sol_to_lamports(0.5), "invalid fund receiver sol prize balance.");
+
+        for (fund_receiver_token_account_pubkey, expected_amount) in [
+            (fund_receiver_prize_token_account_pubkey, 500),
+            (fund_receiver_nft_token_account_pubkey, 1)
+        ] {
+            let SolanaAccount { data, .. } = ptc
+                .banks_client
+                .get_account(fund_receiver_token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount, .. } = TokenAccount::unpack(
+                &data
+            ).unwrap();
+
+            assert_eq!(amount, expected_amount, "invalid fund receiver prize balance.");
+        };
+
+        for closed_token_account_pubkey in [ lottery_arbitrary_ata_pubkey, lottery_prize_ata_pubkey, lottery_nft_ata_pubkey ] {
+            assert_eq!(
+                ptc
+                    .banks_client
+                    .get_account(closed_token_account_pubkey)
+                    .await
+                    .unwrap()
+                    .is_none(),
+                true,
+                "lottery's ata should be closed."
+            );
+        };
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_rent_exempt_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(lamports, sol_to_lamports(0.03), "invalid rent exempt receiver balance.");
+    }
+    // success - treasury reclaims (config policy)
+
+    // failure - unclaimed prizes already reclaimed
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 1301);
+
+        let instruction = instruction_reclaim_unclaimed_prizes(
+            lottery_account_pda.0,
+            treasury_authority.pubkey(),
+            fund_receiver_rent_exempt_account_pubkey,
+            lottery_arbitrary_ata_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account_pda.0,
+            treasury_account_pubkey,
+            vec![],
+            vec![],
+            Some(fund_receiver_sol_prize_account_pubkey),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &treasury_authority
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::UnclaimedPrizesAlreadyReclaimed as u32
+                )
+            )
+        );
+    }
+    // failure - unclaimed prizes already reclaimed
+
+    // success - unclaimed winner closes its user account & the lottery (and its prize-vault) is closed
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 1301);
+
+        let instruction = instruction_withdraw_and_close_succeed_user(
+            lottery_account_pda.0,
+            user_account_pda.0,
+            user_account_auth.pubkey(),
+            user_account_auth.pubkey(),
+            user_account_auth.pubkey(),
+            config_account_pda.0
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction, close_lottery_instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &user_account_auth,
+                &lottery_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        for closed_account_pubkey in [ user_account_pda.0, lottery_account_pda.0, lottery_usdc_ata_pubkey, prize_vault_pda.0 ] {
+            assert_eq!(
+                ptc
+                    .banks_client
+                    .get_account(closed_account_pubkey)
+                    .await
+                    .unwrap()
+                    .is_none(),
+                true,
+                "account should be closed."
+            );
+        };
+    }
+    // success - unclaimed winner closes its user account & the lottery (and its prize-vault) is closed
+}
+
+#[tokio::test]
+async fn test_close_lottery_arbitrary_token_account() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let usdc_mint_account_pubkey = Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: usdc_mint_account_pubkey,
+        ..Config::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            data: config_account.try_to_vec().unwrap(),
+            lamports: sol_to_lamports(0.01),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add lottery account (rank 1 not withdrawn yet) & the 1st winner's user account
+    let lottery_auth = Keypair::new();
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            lottery_auth.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let user_account_auth = Keypair::new();
+    let user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            user_account_auth.pubkey().to_bytes().as_slice(),
+            lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let prize_mint_account_pubkey = Pubkey::new_unique();
+    let mut lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        authority: lottery_auth.pubkey(),
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("1"),
+        winners_count: 3,
+        minimum_tickets_amount_required_to_be_sold: 5,
+        tickets_total_amount: 10,
+        is_ended_successfuly: true,
+        is_creator_withdrawed_when_lottery_was_successful: true,
+        is_protocol_fee_claimed: true,
+        arbitrary_mint_account_address: Pubkey::new_from_array([5; 32]),
+        winners: vec![
+            (user_account_pda.0, 0, true),
+            (Pubkey::new_from_array([56; 32]), 1, false),
+            (Pubkey::new_from_array([57; 32]), 2, true)
+        ],
+        fund_amount: 100_000000,
+        prize_mints: vec![ (prize_mint_account_pubkey, 999) ],
+        prize_token_accounts_amount: 1,
+        ..Lottery::default()
+    };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+
+    pt.add_account(
+        lottery_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(1.0),
+            data: lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        }
+    );
+
+    pt.add_account(
+        user_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(0.01),
+            data: User {
+                discriminator: User::get_discriminator(),
+                canonical_bump: user_account_pda.1,
+                authority: user_account_auth.pubkey(),
+                lottery: lottery_account_pda.0,
+                ..User::default()
+            }.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery account (rank 1 not withdrawn yet) & the 1st winner's user account
+
+    //////////////////////? add mint accounts & token accounts (1 arbitrary token & 1 prize token of rounding dust)
+    for mint_account_pubkey in [ lottery_account.arbitrary_mint_account_address, usdc_mint_account_pubkey, prize_mint_account_pubkey ] {
+        let mut mint_account_data = [0u8; MintAccount::LEN];
+        MintAccount::pack(
+            MintAccount {
+                supply: 1000_000000,
+                decimals: 6,
+                is_initialized: true,
+                ..MintAccount::default()
+            },
+            mint_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            mint_account_pubkey,
+            SolanaAccount {
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(0.01),
+                data: mint_account_data.to_vec(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+
+    let lottery_arbitrary_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &lottery_account.arbitrary_mint_account_address
+    );
+    let lottery_usdc_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &usdc_mint_account_pubkey
+    );
+    let lottery_prize_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &prize_mint_account_pubkey
+    );
+    let fund_receiver_arbitrary_token_account_pubkey = Pubkey::new_unique();
+    let fund_receiver_usdc_token_account_pubkey = Pubkey::new_unique();
+    let fund_receiver_prize_token_account_pubkey = Pubkey::new_unique();
+
+    for (token_account_pubkey, mint, owner, amount) in [
+        (lottery_arbitrary_ata_pubkey, lottery_account.arbitrary_mint_account_address, lottery_account_pda.0, 1),
+        (lottery_usdc_ata_pubkey, usdc_mint_account_pubkey, lottery_account_pda.0, 0),
+        (fund_receiver_arbitrary_token_account_pubkey, lottery_account.arbitrary_mint_account_address, lottery_auth.pubkey(), 0),
+        (fund_receiver_usdc_token_account_pubkey, usdc_mint_account_pubkey, lottery_auth.pubkey(), 0),
+        (lottery_prize_ata_pubkey, prize_mint_account_pubkey, lottery_account_pda.0, 1),
+        (fund_receiver_prize_token_account_pubkey, prize_mint_account_pubkey, lottery_auth.pubkey(), 0)
+    ] {
+        let mut token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                state: TokenAccountState::Initialized,
+                mint,
+                owner,
+                amount,
+                ..TokenAccount::default()
+            },
+            token_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            token_account_pubkey,
+            SolanaAccount {
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(0.01),
+                data: token_account_data.to_vec(),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add mint accounts & token accounts (1 arbitrary token & 1 prize token of rounding dust)
+
+    let fund_receiver_rent_exempt_account_pubkey = Pubkey::new_unique();
+
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - failed lottery, its arbitrary ata is closed by "WithdrawFailedLottery"
+    {
+        let failed_lottery_account = Lottery {
+            tickets_total_amount: 1,
+            is_ended_successfuly: false,
+            is_creator_withdrawed_when_lottery_was_failed: true,
+            winners: Vec::new(),
+            ..lottery_account.clone()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: sol_to_lamports(1.0),
+                    data: failed_lottery_account.try_to_vec().unwrap(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_close_lottery_arbitrary_token_account(
+            lottery_account_pda.0,
+            lottery_auth.pubkey(),
+            lottery_arbitrary_ata_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            fund_receiver_rent_exempt_account_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account_pda.0,
+            vec![]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::ArbitraryTokenAccountStillInUse as u32
+                )
+            )
+        );
+
+        // back to the succeeded lottery
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: sol_to_lamports(1.0),
+                    data: lottery_account.try_to_vec().unwrap(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+    }
+    // failure - failed lottery, its arbitrary ata is closed by "WithdrawFailedLottery"
+
+    // failure - a winner has not withdrawn yet & invalid lottery authority
+    for (lottery_authority, expected_error) in [
+        (&lottery_auth, LotteryError::ArbitraryTokenAccountStillInUse),
+        (&user_account_auth, LotteryError::InvalidLotteryAccountAuthority)
+    ] {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_close_lottery_arbitrary_token_account(
+            lottery_account_pda.0,
+            lottery_authority.pubkey(),
+            lottery_arbitrary_ata_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            fund_receiver_rent_exempt_account_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account_pda.0,
+            vec![]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                lottery_authority
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    expected_error as u32
+                )
+            )
+        );
+    };
+    // failure - a winner has not withdrawn yet & invalid lottery authority
+
+    // success - every winner withdrawn, the dust is swept & the arbitrary & prize atas closed
+    {
+        lottery_account.winners[1].2 = true;
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: sol_to_lamports(1.0),
+                    data: lottery_account.try_to_vec().unwrap(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 350);
+
+        let instruction = instruction_close_lottery_arbitrary_token_account(
+            lottery_account_pda.0,
+            lottery_auth.pubkey(),
+            lottery_arbitrary_ata_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            fund_receiver_rent_exempt_account_pubkey,
+            lottery_account.arbitrary_mint_account_address,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account_pda.0,
+            vec![
+                (
+                    prize_mint_account_pubkey,
+                    lottery_prize_ata_pubkey,
+                    fund_receiver_prize_token_account_pubkey
+                )
+            ]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { is_arbitrary_token_account_closed, prize_token_accounts_amount, .. } = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+
+        assert_eq!(is_arbitrary_token_account_closed, true, "invalid arbitrary token account closed flag.");
+        assert_eq!(prize_token_accounts_amount, 0, "invalid lottery account's prize_token_accounts_amount.");
+
+        for fund_receiver_token_account_pubkey in [ fund_receiver_arbitrary_token_account_pubkey, fund_receiver_prize_token_account_pubkey ] {
+            let SolanaAccount { data, .. } = ptc
+                .banks_client
+                .get_account(fund_receiver_token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount, .. } = TokenAccount::unpack(
+                &data
+            ).unwrap();
+
+            assert_eq!(amount, 1, "invalid fund receiver balance.");
+        };
+
+        for closed_token_account_pubkey in [ lottery_arbitrary_ata_pubkey, lottery_prize_ata_pubkey ] {
+            assert_eq!(
+                ptc
+                    .banks_client
+                    .get_account(closed_token_account_pubkey)
+                    .await
+                    .unwrap()
+                    .is_none(),
+                true,
+                "lottery's ata should be closed."
+            );
+        };
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_rent_exempt_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(lamports, sol_to_lamports(0.02), "invalid rent exempt receiver balance.");
+    }
+    // success - every winner withdrawn, the dust is swept & the arbitrary & prize atas closed
+
+    // failure - a prize token account is still open
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 351);
+
+        let SolanaAccount { data, lamports, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut unsettled_lottery_account = Lottery::deserialize(
+            &mut &data[..]
+        ).unwrap();
+        unsettled_lottery_account.prize_token_accounts_amount = 1;
+
+        let mut unsettled_lottery_account_data = data.clone();
+        unsettled_lottery_account.serialize(
+            &mut &mut unsettled_lottery_account_data[..]
+        ).unwrap();
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports,
+                    data: unsettled_lottery_account_data,
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let instruction = instruction_close_lottery_account_and_usdc_token_account(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            lottery_auth.pubkey(),
+            usdc_mint_account_pubkey,
+            lottery_usdc_ata_pubkey,
+            fund_receiver_usdc_token_account_pubkey,
+            fund_receiver_rent_exempt_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            false,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::SucceedLotteryNotSettled as u32
+                )
+            )
+        );
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports,
+                    data,
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+    }
+    // failure - a prize token account is still open
+
+    // success - the withdrawn winner closes its user account & the lottery is closed
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 351);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction_withdraw_and_close_succeed_user(
+                    lottery_account_pda.0,
+                    user_account_pda.0,
+                    user_account_auth.pubkey(),
+                    user_account_auth.pubkey(),
+                    user_account_auth.pubkey(),
+                    config_account_pda.0
+                ),
+                instruction_close_lottery_account_and_usdc_token_account(
+                    config_account_pda.0,
+                    lottery_account_pda.0,
+                    lottery_auth.pubkey(),
+                    usdc_mint_account_pubkey,
+                    lottery_usdc_ata_pubkey,
+                    fund_receiver_usdc_token_account_pubkey,
+                    fund_receiver_rent_exempt_account_pubkey,
+                    TOKEN_STANDARD_PROGRAM_ID,
+                    false,
+                    None
+                )
+            ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &user_account_auth,
+                &lottery_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        for closed_account_pubkey in [ user_account_pda.0, lottery_account_pda.0, lottery_usdc_ata_pubkey ] {
+            assert_eq!(
+                ptc
+                    .banks_client
+                    .get_account(closed_account_pubkey)
+                    .await
+                    .unwrap()
+                    .is_none(),
+                true,
+                "account should be closed."
+            );
+        };
+    }
+    // success - the withdrawn winner closes its user account & the lottery is closed
+}
+
+#[tokio::test]
+async fn test_withdraw_failed_lottery() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+    
+    //////////////////////? add lottery account
+    let lottery_account_auth = Keypair::new();
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            lottery_account_auth.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+    
+    let lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        tickets_total_amount: 45,
+        minimum_tickets_amount_required_to_be_sold: 50,
+        lottery_description: String::from("1"),
+        authority: lottery_account_auth.pubkey(),
+        lottery_creation_fee: 10_000000, // 10 USDCs
+        starting_time: 200,
+        ending_time: 350,
+        arbitrary_mint_account_address: Pubkey::new_from_array([1; 32]),
+        ..Lottery::default()
+    };
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: lottery_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+    
+    //////////////////////? add UDSC mint account
+    let usdc_mint_account = MintAccount {
+        supply: 100_000000,
+        decimals: 6,
+        is_initialized: true,
+        ..MintAccount::default()
+    };
+
+    let mut usdc_mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        usdc_mint_account,
+        usdc_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    let usdc_mint_solana_account = SolanaAccount {
+        data: usdc_mint_account_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account.usdc_mint_account,
+        usdc_mint_solana_account
+    );
+    //////////////////////? add USDC mint account
+    
+    //////////////////////? add Arbitrary mint account
+    let arbitrary_mint_account_pubkey = Pubkey::new_from_array([1; 32]);
+    let arbitrary_mint_account = MintAccount {
+        supply: 500_000,
+        decimals: 3,
+        is_initialized: true,
+        ..MintAccount::default()
+    };
+
+    let mut arbitrary_mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        arbitrary_mint_account,
+        arbitrary_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    let arbitrary_mint_solana_account = SolanaAccount {
+        data: arbitrary_mint_account_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        arbitrary_mint_account_pubkey,
+        arbitrary_mint_solana_account
+    );
+    //////////////////////? add Arbitrary mint account
+    
+    //////////////////////? add lottery's USDC ata
+    let lottery_usdc_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0, 
+        &config_account.usdc_mint_account
+    );
+    let lottery_usdc_ata = TokenAccount {
+        amount: 55_000000,
+        owner: lottery_account_pda.0,
+        state: TokenAccountState::Initialized,
+        mint: config_account.usdc_mint_account,
+        ..TokenAccount::default()
+    };
+
+    let mut lottery_usdc_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        lottery_usdc_ata,
+        lottery_usdc_ata_data.as_mut_slice()
+    ).unwrap();
+
+    let lottery_usdc_ata_solana_account = SolanaAccount {
+        data: lottery_usdc_ata_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_usdc_ata_pubkey,
+        lottery_usdc_ata_solana_account
+    );
+    //////////////////////? add lottery's USDC ata
+    
+    //////////////////////? add lottery's Arbitrary ata
+    let lottery_arbitrary_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0, 
+        &arbitrary_mint_account_pubkey
+    );
+    let lottery_arbitrary_ata = TokenAccount {
+        amount: 350_000,
+        owner: lottery_account_pda.0,
+        state: TokenAccountState::Initialized,
+        mint: lottery_account.arbitrary_mint_account_address,
+        ..TokenAccount::default()
+    };
+
+    let mut lottery_arbitrary_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        lottery_arbitrary_ata,
+        lottery_arbitrary_ata_data.as_mut_slice()
+    ).unwrap();
+
+    let lottery_arbitrary_ata_solana_account = SolanaAccount {
+        data: lottery_arbitrary_ata_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: 2039280,
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_arbitrary_ata_pubkey,
+        lottery_arbitrary_ata_solana_account
+    );
+    //////////////////////? add lottery's Arbitrary ata
+    
+    //////////////////////? add fund receiver, USDC token account
+    let fund_receiver_usdc_token_account_pubkey = Pubkey::new_from_array([2; 32]);
+    let fund_receiver_usdc_token_account = TokenAccount {
+        amount: 0_000000,
+        owner: Pubkey::new_unique(),
+        state: TokenAccountState::Initialized,
+        mint: config_account.usdc_mint_account,
+        ..TokenAccount::default()
+    };
+
+    let mut fund_receiver_usdc_token_account_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        fund_receiver_usdc_token_account,
+        fund_receiver_usdc_token_account_data.as_mut_slice()
+    ).unwrap();
+
+    let fund_receiver_usdc_token_solana_account = SolanaAccount {
+        data: fund_receiver_usdc_token_account_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        fund_receiver_usdc_token_account_pubkey,
+        fund_receiver_usdc_token_solana_account
+    );
+    //////////////////////? add fund receiver, USDC token account
+    
+    //////////////////////? add fund receiver, Arbitrary token account
+    let fund_receiver_arbitrary_token_account_pubkey = Pubkey::new_from_array([3; 32]);
+    let fund_receiver_arbitrary_token_account = TokenAccount {
+        amount: 0_000,
+        owner: Pubkey::new_unique(),
+        state: TokenAccountState::Initialized,
+        mint: lottery_account.arbitrary_mint_account_address,
+        ..TokenAccount::default()
+    };
+
+    let mut fund_receiver_arbitrary_token_account_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        fund_receiver_arbitrary_token_account,
+        fund_receiver_arbitrary_token_account_data.as_mut_slice()
+    ).unwrap();
+
+    let fund_receiver_arbitrary_token_solana_account = SolanaAccount {
+        data: fund_receiver_arbitrary_token_account_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        fund_receiver_arbitrary_token_account_pubkey,
+        fund_receiver_arbitrary_token_solana_account
+    );
+    //////////////////////? add fund receiver, Arbitrary token account
+    
+    //////////////////////? add fund receiver, rent-exempt's lamports account
+    let fund_receiver_rent_exempt_lamports = Pubkey::new_from_array([4; 32]);
+    pt.add_account(
+        fund_receiver_rent_exempt_lamports,
+        SolanaAccount {
+            owner: SYSTEM_PROGRAM_ID,
+            lamports: sol_to_lamports(0.1),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add fund receiver, rent-exempt's lamports account
+    
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - funds already withdrawed
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 555);
+
+        let instruction = instruction_withdraw_failed_lottery(
+            config_account_pda.0, 
+            lottery_account_pda.0, 
+            lottery_account_auth.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_account.arbitrary_mint_account_address, 
+            lottery_usdc_ata_pubkey, 
+            lottery_arbitrary_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            fund_receiver_arbitrary_token_account_pubkey, 
+            fund_receiver_rent_exempt_lamports, 
             TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+            vec![],
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ 
+                instruction.clone(),
+                instruction
+            ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_account_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(
+                    LotteryError::FundsAlreadyWithdrawed as u32
+                )
+            )
+        );
+    }
+    // failure - funds already withdrawed
+
+    // failure - invalid lottery account auth
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 555);
+
+        let unkown_account = Keypair::new();
+        let instruction = instruction_withdraw_failed_lottery(
+            config_account_pda.0, 
+            lottery_account_pda.0, 
+            unkown_account.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_account.arbitrary_mint_account_address, 
+            lottery_usdc_ata_pubkey, 
+            lottery_arbitrary_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            fund_receiver_arbitrary_token_account_pubkey, 
+            fund_receiver_rent_exempt_lamports, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            vec![],
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -4078,8 +16761,7 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &unkown_account
             ],
             ptc.last_blockhash
         );
@@ -4096,139 +16778,206 @@ async fn test_create_and_initialize_lottery_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidLotteryAssociatedUsdcTokenAccount as u32
+                    LotteryError::InvalidLotteryAccountAuthority as u32
                 )
             )
         );
-        
-        // 2. invalid arbitrary ata
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 150);
+    }
+    // failure - invalid lottery account auth
 
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 250_i64;
-        let ending_time = 500_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            Pubkey::new_unique(),
-            funding_arbitrary_token_account_pubkey,
+    // success
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 555);
+
+        // let instruction_1 = spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+        //     &ptc.payer.pubkey(), 
+        //     &fund_receiver_usdc_token_account_pubkey, 
+        //     &config_account.usdc_mint_account, 
+        //     &TOKEN_STANDARD_PROGRAM_ID
+        // );
+
+        // let instruction_2 = spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+        //     &ptc.payer.pubkey(), 
+        //     &fund_receiver_arbitrary_token_account_pubkey, 
+        //     &lottery_account.arbitrary_mint_account_address, 
+        //     &TOKEN_STANDARD_PROGRAM_ID
+        // );
+
+        let instruction = instruction_withdraw_failed_lottery(
+            config_account_pda.0, 
+            lottery_account_pda.0, 
+            lottery_account_auth.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_account.arbitrary_mint_account_address, 
+            lottery_usdc_ata_pubkey, 
+            lottery_arbitrary_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            fund_receiver_arbitrary_token_account_pubkey, 
+            fund_receiver_rent_exempt_lamports, 
             TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+            vec![],
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ],
+            &[
+                // instruction_1,
+                // instruction_2,
+                instruction
+            ],
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &lottery_account_auth
             ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Lottery { is_creator_withdrawed_when_lottery_was_failed, .. } = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
         assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount as u32
-                )
-            )
+            is_creator_withdrawed_when_lottery_was_failed,
+            true,
+            "lottery account was not updated."
+        );
+
+        let SolanaAccount { data: lottery_usdc_ata_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_usdc_ata_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let error = ptc
+            .banks_client
+            .get_account(lottery_arbitrary_ata_pubkey)
+            .await
+            .unwrap();
+        if error.is_some() {
+            panic!("Account must be closed and we cannot get its data from blockchain.");
+        };
+
+        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_usdc_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { data: fund_receiver_arbitrary_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_arbitrary_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_rent_exempt_lamports)
+            .await
+            .unwrap()
+            .unwrap();
+        let rent_exempt_lamports = (
+            (
+                (ACCOUNT_STORAGE_OVERHEAD + TokenAccount::LEN as u64) * DEFAULT_LAMPORTS_PER_BYTE_YEAR
+            ) as f64 * DEFAULT_EXEMPTION_THRESHOLD
+        ) as u64;
+
+        assert_eq!(
+            lamports,
+            rent_exempt_lamports + sol_to_lamports(0.1),
+            "invalid rent_exempt receiver lamport balance."
+        );
+
+        let TokenAccount { amount: lottery_usdc_ata_balance, .. } = TokenAccount::unpack(
+            &lottery_usdc_ata_data
+        ).unwrap();
+
+        let TokenAccount { amount: fund_receiver_usdc_token_account_balance, .. } = TokenAccount::unpack(
+            &fund_receiver_usdc_token_account_data
+        ).unwrap();
+
+        let TokenAccount { amount: fund_receiver_arbitrary_token_account_balance, .. } = TokenAccount::unpack(
+            &fund_receiver_arbitrary_token_account_data
+        ).unwrap();
+
+        assert_eq!(
+            55_000000 - 10_000000,
+            lottery_usdc_ata_balance,
+            "invalid lottery_usdc_ata_balance."
+        );
+
+        assert_eq!(
+            fund_receiver_usdc_token_account_balance,
+            10_000000,
+            "invalid fund_receiver_usdc_token_account_balance."
+        );
+
+        assert_eq!(
+            fund_receiver_arbitrary_token_account_balance,
+            350_000,
+            "invalid fund_receiver_arbitrary_token_account_balance."
         );
     }
-    // failure - invalid lottery's ata for usdc and arbitrary token accounts
+    // success
 
-    // faliure - invalid usdc mint account
+    // failure - lottery is not in correct state
     {
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 150);
+        let mut new_lottery_account = lottery_account.clone();
+        new_lottery_account.minimum_tickets_amount_required_to_be_sold = 80;
+        new_lottery_account.tickets_total_amount = 99;
 
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 250_i64;
-        let ending_time = 500_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            Pubkey::new_unique(),
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
+        ptc.set_account(
+           &lottery_account_pda.0,
+           &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: new_lottery_account.try_to_vec().unwrap(),
+                    owner: LOTTERY_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+           )
+        );
+
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 555);
+
+        let instruction = instruction_withdraw_failed_lottery(
+            config_account_pda.0, 
+            lottery_account_pda.0, 
+            lottery_account_auth.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_account.arbitrary_mint_account_address, 
+            lottery_usdc_ata_pubkey, 
+            lottery_arbitrary_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            fund_receiver_arbitrary_token_account_pubkey, 
+            fund_receiver_rent_exempt_lamports, 
             TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
+            vec![],
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -4236,8 +16985,7 @@ async fn test_create_and_initialize_lottery_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
+                &lottery_account_auth
             ],
             ptc.last_blockhash
         );
@@ -4254,264 +17002,409 @@ async fn test_create_and_initialize_lottery_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidUsdcMintAccount as u32
+                    LotteryError::InvalidLotteryState as u32
                 )
             )
         );
     }
-    // faliure - invalid usdc mint account
-    
-    // success
-    {
-        let lottery_description = String::from("AABBCC");
-        let lottery_account = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&lottery_description).as_slice()
-            ],
-            &lottery_program_id
-        ).0;
-        
-        change_clock_sysvar(&ptc, 550);
+    // failure - lottery is not in correct state
 
-        let fund_amount = 100_u64;
-        let winners_count = 1_u8;
-        let starting_time = 1000_i64;
-        let ending_time = 1350_i64;
-        let minimum_tickets_amount_required_to_be_sold = 25u32;
-        let ticket_price = 1_000000u64;
-        let maximum_number_of_tickets_per_user = None;
-        
-        let instruction = instruction_create_and_initialize_lottery_account(
-            lottery_account,
-            lottery_authority_account.pubkey(),
-            funding_account.pubkey(),
-            config_account.usdc_mint_account,
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            ),
-            funding_usdc_token_account_pubkey,
-            arbitrary_mint_account_addr,
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
-            ),
-            funding_arbitrary_token_account_pubkey,
-            TOKEN_STANDARD_PROGRAM_ID,
-            spl_associated_token_account::ID,
-            SYSTEM_PROGRAM_ID,
-            config_account_pda.0,
-            fund_amount,
-            winners_count,
-            starting_time,
-            ending_time,
-            minimum_tickets_amount_required_to_be_sold,
-            ticket_price,
-            maximum_number_of_tickets_per_user,
-            lottery_description
-        );
+    // prize mints
+    {
+        let prize_mint_account_pubkey = Pubkey::new_from_array([5; 32]);
+        let mut new_lottery_account = lottery_account.clone();
+        new_lottery_account.prize_mints = vec![ (prize_mint_account_pubkey, 70_000) ];
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ instruction ],
-            Some(&ptc.payer.pubkey()),
-            &[
-                &ptc.payer,
-                &funding_account,
-                &lottery_authority_account
-            ],
-            ptc.last_blockhash
+        ptc.set_account(
+           &lottery_account_pda.0,
+           &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: new_lottery_account.try_to_vec().unwrap(),
+                    owner: LOTTERY_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+           )
         );
 
-        ptc
-            .banks_client
-            .process_transaction(tx)
-            .await
-            .unwrap();
-
-        let SolanaAccount { data: lottery_account_data, owner: lottery_account_owner, .. } = ptc
-            .banks_client
-            .get_account(lottery_account)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let lottery = Lottery::deserialize(
-            &mut &lottery_account_data[..]
+        let mut lottery_arbitrary_ata_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            lottery_arbitrary_ata,
+            lottery_arbitrary_ata_data.as_mut_slice()
         ).unwrap();
 
-        assert_eq!(
-            lottery_account_owner,
-            lottery_program_id,
-            "Invalid lottery-account's owner"
-        );
-
-        assert_eq!(
-            starting_time,
-            lottery.starting_time,
-            "Invalid starting-time."
-        );
-
-        assert_eq!(
-            ending_time,
-            lottery.ending_time,
-            "Invalid ending-time."
-        );
-
-        assert_eq!(
-            fund_amount,
-            lottery.fund_amount,
-            "Invalid fund-amount."
+        ptc.set_account(
+            &lottery_arbitrary_ata_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: lottery_arbitrary_ata_data.to_vec(),
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: 2039280,
+                    ..SolanaAccount::default()
+                }
+            )
         );
 
-        assert_eq!(
-            winners_count,
-            lottery.winners_count,
-            "Invalid winners-count."
-        );
+        let mut prize_mint_account_data = [0u8; MintAccount::LEN];
+        MintAccount::pack(
+            MintAccount {
+                supply: 100_000,
+                decimals: 2,
+                is_initialized: true,
+                ..MintAccount::default()
+            },
+            prize_mint_account_data.as_mut_slice()
+        ).unwrap();
 
-        assert_eq!(
-            maximum_number_of_tickets_per_user,
-            lottery.maximum_number_of_tickets_per_user,
-            "Invalid max number of tickets per user."
+        ptc.set_account(
+            &prize_mint_account_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: prize_mint_account_data.to_vec(),
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
         );
 
-        assert_eq!(
-            ticket_price,
-            lottery.ticket_price,
-            "Invalid ticket price."
-        );
+        let lottery_prize_ata_pubkey = get_associated_token_address(
+            &lottery_account_pda.0,
+            &prize_mint_account_pubkey
+        );
+        let mut lottery_prize_ata_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                amount: 70_000,
+                owner: lottery_account_pda.0,
+                state: TokenAccountState::Initialized,
+                mint: prize_mint_account_pubkey,
+                ..TokenAccount::default()
+            },
+            lottery_prize_ata_data.as_mut_slice()
+        ).unwrap();
 
-        assert_eq!(
-            minimum_tickets_amount_required_to_be_sold,
-            lottery.minimum_tickets_amount_required_to_be_sold,
-            "Invalid min tickets amount required to be sold."
+        ptc.set_account(
+            &lottery_prize_ata_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: lottery_prize_ata_data.to_vec(),
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: 2039280,
+                    ..SolanaAccount::default()
+                }
+            )
         );
 
-        assert_eq!(
-            Lottery::get_discriminator(),
-            lottery.discriminator,
-            "invalid lottery account discriminator."
-        );
+        let fund_receiver_prize_token_account_pubkey = Pubkey::new_from_array([6; 32]);
+        let mut fund_receiver_prize_token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                amount: 0,
+                owner: Pubkey::new_unique(),
+                state: TokenAccountState::Initialized,
+                mint: prize_mint_account_pubkey,
+                ..TokenAccount::default()
+            },
+            fund_receiver_prize_token_account_data.as_mut_slice()
+        ).unwrap();
 
-        assert_eq!(
-            lottery.is_creator_withdrawed_when_lottery_was_failed,
-            bool::default(),
-            "invalid is_creator_withdrawed_when_lottery_was_failed flag."
+        ptc.set_account(
+            &fund_receiver_prize_token_account_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: fund_receiver_prize_token_account_data.to_vec(),
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
         );
 
-        assert_eq!(
-            lottery.is_creator_withdrawed_when_lottery_was_successful,
-            bool::default(),
-            "invalid is_creator_withdrawed_when_lottery_was_successful."
-        );
+        // failure - missing prize mint accounts
+        {
+            ptc
+                .get_new_latest_blockhash()
+                .await
+                .unwrap();
+            change_clock_sysvar(&ptc, 555);
+
+            let instruction = instruction_withdraw_failed_lottery(
+                config_account_pda.0, 
+                lottery_account_pda.0, 
+                lottery_account_auth.pubkey(), 
+                config_account.usdc_mint_account, 
+                lottery_account.arbitrary_mint_account_address, 
+                lottery_usdc_ata_pubkey, 
+                lottery_arbitrary_ata_pubkey, 
+                fund_receiver_usdc_token_account_pubkey, 
+                fund_receiver_arbitrary_token_account_pubkey, 
+                fund_receiver_rent_exempt_lamports, 
+                TOKEN_STANDARD_PROGRAM_ID,
+                vec![],
+                None
+            );
 
-        assert_eq!(
-            lottery.is_ended_successfuly,
-            bool::default(),
-            "invalid is_ended_successfuly flag."
-        );
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ],
+                Some(&ptc.payer.pubkey()),
+                &[
+                    &ptc.payer,
+                    &lottery_account_auth
+                ],
+                ptc.last_blockhash
+            );
 
-        assert_eq!(
-            lottery.is_protocol_fee_claimed,
-            bool::default(),
-            "invalid is_protocol_fee_claimed flag."
-        );
+            let error = ptc
+                .banks_client
+                .process_transaction(tx)
+                .await
+                .unwrap_err()
+                .unwrap();
 
-        assert_eq!(
-            lottery.protocol_fee,
-            u64::default(),
-            "invalid protocol fee in lottery_account."
-        );
+            assert_eq!(
+                error,
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(
+                        LotteryError::InvalidAmountOfPrizeMintAccounts as u32
+                    )
+                )
+            );
+        }
+        // failure - missing prize mint accounts
+
+        // success - prize mints
+        {
+            ptc
+                .get_new_latest_blockhash()
+                .await
+                .unwrap();
+            change_clock_sysvar(&ptc, 555);
+
+            let instruction = instruction_withdraw_failed_lottery(
+                config_account_pda.0, 
+                lottery_account_pda.0, 
+                lottery_account_auth.pubkey(), 
+                config_account.usdc_mint_account, 
+                lottery_account.arbitrary_mint_account_address, 
+                lottery_usdc_ata_pubkey, 
+                lottery_arbitrary_ata_pubkey, 
+                fund_receiver_usdc_token_account_pubkey, 
+                fund_receiver_arbitrary_token_account_pubkey, 
+                fund_receiver_rent_exempt_lamports, 
+                TOKEN_STANDARD_PROGRAM_ID,
+                vec![
+                    (
+                        prize_mint_account_pubkey,
+                        lottery_prize_ata_pubkey,
+                        fund_receiver_prize_token_account_pubkey
+                    )
+                ],
+                None
+            );
 
-        let lottery_usdc_associated_token_account = ptc.banks_client.get_account(
-            get_associated_token_address(
-                &lottery_account,
-                &config_account.usdc_mint_account
-            )
-        ).await.unwrap().unwrap();
+            let tx = Transaction::new_signed_with_payer(
+                &[ instruction ],
+                Some(&ptc.payer.pubkey()),
+                &[
+                    &ptc.payer,
+                    &lottery_account_auth
+                ],
+                ptc.last_blockhash
+            );
 
-        assert_eq!(
-            lottery_usdc_associated_token_account.owner,
-            TOKEN_STANDARD_PROGRAM_ID,
-            "invalid owner for usdc token account."
-        );
+            ptc
+                .banks_client
+                .process_transaction(tx)
+                .await
+                .unwrap();
+
+            let error = ptc
+                .banks_client
+                .get_account(lottery_prize_ata_pubkey)
+                .await
+                .unwrap();
+            if error.is_some() {
+                panic!("Prize token account must be closed and we cannot get its data from blockchain.");
+            };
+
+            let SolanaAccount { data: fund_receiver_prize_token_account_data, .. } = ptc
+                .banks_client
+                .get_account(fund_receiver_prize_token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount: fund_receiver_prize_token_account_balance, .. } = TokenAccount::unpack(
+                &fund_receiver_prize_token_account_data
+            ).unwrap();
 
-        let usdc_token_account = TokenAccount::unpack(
-            &mut &lottery_usdc_associated_token_account.data
+            assert_eq!(
+                fund_receiver_prize_token_account_balance,
+                70_000,
+                "invalid fund_receiver_prize_token_account_balance."
+            );
+        }
+        // success - prize mints
+    }
+    // prize mints
+    // success - sol prize
+    {
+        let (
+            prize_vault_account,
+            prize_vault_canonical_bump
+        ) = get_prize_vault_account_pda(
+            &lottery_account_pda.0,
+            &lottery_program_id
         ).unwrap();
 
-        assert_eq!(
-            usdc_token_account.state,
-            TokenAccountState::Initialized,
-            "invalid usdc-token-account's state."
+        let mut new_lottery_account = lottery_account.clone();
+        new_lottery_account.sol_prize_amount = sol_to_lamports(2.0);
+        new_lottery_account.prize_vault_canonical_bump = prize_vault_canonical_bump;
+
+        ptc.set_account(
+           &lottery_account_pda.0,
+           &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: new_lottery_account.try_to_vec().unwrap(),
+                    owner: LOTTERY_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+           )
         );
 
-        assert_eq!(
-            usdc_token_account.mint,
-            config_account.usdc_mint_account,
-            "invalid mint account for usdc-token-account."
-        );
+        let mut lottery_arbitrary_ata_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            lottery_arbitrary_ata,
+            lottery_arbitrary_ata_data.as_mut_slice()
+        ).unwrap();
 
-        assert_eq!(
-            usdc_token_account.amount,
-            config_account.lottery_creation_fee,
-            "invalid usdc token balance."
+        ptc.set_account(
+            &lottery_arbitrary_ata_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: lottery_arbitrary_ata_data.to_vec(),
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: 2039280,
+                    ..SolanaAccount::default()
+                }
+            )
         );
 
-        assert_eq!(
-            usdc_token_account.owner,
-            lottery_account,
-            "invalid owner for usdc token account."
+        let prize_vault_rent_exempt = (
+            (ACCOUNT_STORAGE_OVERHEAD * DEFAULT_LAMPORTS_PER_BYTE_YEAR) as f64 * DEFAULT_EXEMPTION_THRESHOLD
+        ) as u64;
+        ptc.set_account(
+            &prize_vault_account,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: LOTTERY_PROGRAM_ID,
+                    lamports: sol_to_lamports(2.0) + prize_vault_rent_exempt,
+                    ..SolanaAccount::default()
+                }
+            )
         );
 
-        let lottery_arbitrary_associated_token_account = ptc.banks_client.get_account(
-            get_associated_token_address(
-                &lottery_account,
-                &arbitrary_mint_account_addr
+        let fund_receiver_sol_prize_account = Pubkey::new_unique();
+        ptc.set_account(
+            &fund_receiver_sol_prize_account,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: SYSTEM_PROGRAM_ID,
+                    lamports: sol_to_lamports(0.1),
+                    ..SolanaAccount::default()
+                }
             )
-        ).await.unwrap().unwrap();
-
-        assert_eq!(
-            lottery_arbitrary_associated_token_account.owner,
-            TOKEN_STANDARD_PROGRAM_ID,
-            "invalid owner for arbitrary token account."
         );
 
-        let arbitrary_token_account = TokenAccount::unpack(
-            &mut &lottery_arbitrary_associated_token_account.data
-        ).unwrap();
+        let SolanaAccount { lamports: fund_receiver_rent_exempt_old_balance, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_rent_exempt_lamports)
+            .await
+            .unwrap()
+            .unwrap();
 
-        assert_eq!(
-            arbitrary_token_account.state,
-            TokenAccountState::Initialized,
-            "invalid arbitrary-token-account's state."
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 555);
+
+        let instruction = instruction_withdraw_failed_lottery(
+            config_account_pda.0, 
+            lottery_account_pda.0, 
+            lottery_account_auth.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_account.arbitrary_mint_account_address, 
+            lottery_usdc_ata_pubkey, 
+            lottery_arbitrary_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            fund_receiver_arbitrary_token_account_pubkey, 
+            fund_receiver_rent_exempt_lamports, 
+            TOKEN_STANDARD_PROGRAM_ID,
+            vec![],
+            Some(fund_receiver_sol_prize_account)
         );
 
-        assert_eq!(
-            arbitrary_token_account.mint,
-            arbitrary_mint_account_addr,
-            "invalid mint account for arbitrary-token-account."
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_account_auth
+            ],
+            ptc.last_blockhash
         );
 
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let prize_vault = ptc
+            .banks_client
+            .get_account(prize_vault_account)
+            .await
+            .unwrap();
+        if prize_vault.is_some() {
+            panic!("Prize-vault must be closed and we cannot get its data from blockchain.");
+        };
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_sol_prize_account)
+            .await
+            .unwrap()
+            .unwrap();
+
         assert_eq!(
-            arbitrary_token_account.amount,
-            fund_amount,
-            "invalid arbitrary token balance."
+            lamports,
+            sol_to_lamports(0.1) + sol_to_lamports(2.0),
+            "invalid fund receiver sol prize balance."
         );
 
+        // arbitrary token account's & prize-vault's rent-exempts
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_rent_exempt_lamports)
+            .await
+            .unwrap()
+            .unwrap();
+
         assert_eq!(
-            arbitrary_token_account.owner,
-            lottery_account,
-            "invalid owner for arbitrary token account."
+            lamports,
+            fund_receiver_rent_exempt_old_balance + 2039280 + prize_vault_rent_exempt,
+            "invalid rent_exempt receiver lamport balance."
         );
     }
-    // success
+    // success - sol prize
 }
 
 #[tokio::test]
-async fn test_change_lottery_ticket_price() {
+async fn test_withdraw_and_close_failed_user() {
     let lottery_program_id = LOTTERY_PROGRAM_ID;
     let mut pt = setup_program_test(lottery_program_id);
 
@@ -4526,12 +17419,7 @@ async fn test_change_lottery_ticket_price() {
     let config_account = Config {
         discriminator: Config::get_discriminator(),
         canonical_bump: config_account_pda.1,
-        lottery_creation_fee: 5_000000, // 5 USDC
-        maximum_number_of_winners: 10,
-        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
-        maximum_time_for_lottery_account: 1000,
-        minimum_tickets_to_be_sold_in_lottery: 20,
-        max_lottery_description_bytes: 300,
+        usdc_mint_account: Pubkey::new_from_array([1; 32]),
         ..Config::default()
     };
     let config_account_data = config_account.try_to_vec().unwrap();
@@ -4559,244 +17447,290 @@ async fn test_change_lottery_ticket_price() {
         &lottery_program_id
     );
 
-    let lottery_authority_account = Keypair::new();
-    let lottery_account = Lottery {
+    let mut lottery_account = Lottery {
         discriminator: Lottery::get_discriminator(),
         canonical_bump: lottery_account_pda.1,
-        lottery_creation_fee: 5_000000,
-        protocol_fee: 300_000000,
         starting_time: 100,
         ending_time: 200,
-        ticket_price: 5_000000, // 5 USDC
-        authority: lottery_authority_account.pubkey(),
         lottery_description: String::from("1"),
+        minimum_tickets_amount_required_to_be_sold: 100,
+        tickets_total_amount: 90,
         ..Lottery::default()
     };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: vec![
+            lottery_account.try_to_vec().unwrap(),
+            vec![0u8; 72]
+        ].concat(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+    
+    //////////////////////? add user account
+    let user_account_auth = Keypair::new();
+    pt.add_account(
+        user_account_auth.pubkey(),
+        SolanaAccount {
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            user_account_auth.pubkey().to_bytes().as_slice(),
+            &lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let user_account = User {
+        discriminator: User::get_discriminator(),
+        canonical_bump: user_account_pda.1,
+        authority: user_account_auth.pubkey(),
+        lottery: lottery_account_pda.0,
+        total_rent_exempt_paied: 100000,
+        total_tickets_acquired: 10,
+        total_ticket_ranges_acquired: 2, // 2 * (32 + 4) bytes of the ticket ledger
+        total_tickets_value: 100_000000, // USDC
+        ..User::default()
+    };
+
+    let user_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: sol_to_lamports(1.0),
+        data: user_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+    
+    pt.add_account(
+        user_account_pda.0,
+        user_solana_account
+    );
+    //////////////////////? add user account
+    
+    //////////////////////? add UDSC mint account
+    let usdc_mint_account = MintAccount {
+        supply: 1000_000000,
+        decimals: 6,
+        is_initialized: true,
+        ..MintAccount::default()
+    };
+
+    let mut usdc_mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        usdc_mint_account,
+        usdc_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    let usdc_mint_solana_account = SolanaAccount {
+        data: usdc_mint_account_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account.usdc_mint_account,
+        usdc_mint_solana_account
+    );
+    //////////////////////? add USDC mint account
+    
+    //////////////////////? add lottery's USDC ata
+    let lottery_usdc_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0, 
+        &config_account.usdc_mint_account
+    );
+    let lottery_usdc_ata = TokenAccount {
+        amount: 1000_000000,
+        owner: lottery_account_pda.0,
+        state: TokenAccountState::Initialized,
+        mint: config_account.usdc_mint_account,
+        ..TokenAccount::default()
+    };
+
+    let mut lottery_usdc_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        lottery_usdc_ata,
+        lottery_usdc_ata_data.as_mut_slice()
+    ).unwrap();
+
+    let lottery_usdc_ata_solana_account = SolanaAccount {
+        data: lottery_usdc_ata_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_usdc_ata_pubkey,
+        lottery_usdc_ata_solana_account
+    );
+    //////////////////////? add lottery's USDC ata
+    
+    //////////////////////? add fund receiver, USDC token account
+    let fund_receiver_usdc_token_account_pubkey = Pubkey::new_from_array([2; 32]);
+    let fund_receiver_usdc_token_account = TokenAccount {
+        amount: 0_000000,
+        owner: user_account_auth.pubkey(),
+        state: TokenAccountState::Initialized,
+        mint: config_account.usdc_mint_account,
+        ..TokenAccount::default()
+    };
 
-    let lottey_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-        data: lottery_account.try_to_vec().unwrap(),
+    let mut fund_receiver_usdc_token_account_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        fund_receiver_usdc_token_account,
+        fund_receiver_usdc_token_account_data.as_mut_slice()
+    ).unwrap();
+
+    let fund_receiver_usdc_token_solana_account = SolanaAccount {
+        data: fund_receiver_usdc_token_account_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(1.0),
         ..SolanaAccount::default()
     };
 
     pt.add_account(
-        lottery_account_pda.0,
-        lottey_solana_account
+        fund_receiver_usdc_token_account_pubkey,
+        fund_receiver_usdc_token_solana_account
     );
-    //////////////////////? add lottery account
+    //////////////////////? add fund receiver, USDC token account
     
     let mut ptc = pt.start_with_context().await;
 
-    // failure - invalid lottery state
+    // success
     {
-        // lottery is ended
-        ptc
-            .get_new_latest_blockhash()
+        ptc.
+            get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(
-            &ptc,
-            300
-        );
+        change_clock_sysvar(&ptc, 350);
 
-        let new_ticket_price = 10_000000u64;
-        let instruction = instruction_change_lottery_ticket_price(
-            lottery_account_pda.0, 
-            lottery_authority_account.pubkey(), 
+        let instruction = instruction_withdraw_and_close_failed_user(
             config_account_pda.0, 
-            new_ticket_price // USDC
+            lottery_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_usdc_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None,
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_authority_account
-            ], 
+                &user_account_auth
+            ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
-            .unwrap();
-
-        assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::InvalidLotteryState as u32
-                )
-            )
-        );
-    }
-    // failure - invalid lottery state
-
-    // failure - invalid lottery's new ticket-price
-    {
-        // lottery is started
-        ptc
-            .get_new_latest_blockhash()
-            .await
             .unwrap();
-        change_clock_sysvar(
-            &ptc,
-            120
-        );
-
-        let new_ticket_price = 5_000000u64;
-        let instruction = instruction_change_lottery_ticket_price(
-            lottery_account_pda.0,
-            lottery_authority_account.pubkey(),
-            config_account_pda.0,
-            new_ticket_price
-        );
-
-        let tx = Transaction::new_signed_with_payer(
-            &[
-                instruction
-            ],
-            Some(&ptc.payer.pubkey()),
-            &[
-                &ptc.payer,
-                &lottery_authority_account
-            ],
-            ptc.last_blockhash
-        );
 
         let error = ptc
             .banks_client
-            .process_transaction(tx)
+            .get_account(user_account_pda.0)
             .await
-            .unwrap_err()
             .unwrap();
+        if error.is_some() {
+            panic!("Account must be closed.");
+        };
 
-        assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::InvalidTicketPrice as u32
-                )
-            )
-        );
-    }
-    // failure - invalid lottery's new ticket-price
-
-    // success
-    {
-        // lottery is started
-        ptc
-            .get_new_latest_blockhash()
+        let SolanaAccount { lamports: lottery_account_lamport_balance, data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
             .await
+            .unwrap()
             .unwrap();
-        change_clock_sysvar(
-            &ptc,
-            120
-        );
-
-        let new_ticket_price = 10_000000u64;
-        let instruction = instruction_change_lottery_ticket_price(
-            lottery_account_pda.0, 
-            lottery_authority_account.pubkey(), 
-            config_account_pda.0, 
-            new_ticket_price // USDC
+        
+        assert_eq!(
+            lottery_account_lamport_balance,
+            sol_to_lamports(1.0) - 100000,
+            "invalid lottery account lamport balance."
         );
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[
-                &ptc.payer,
-                &lottery_authority_account
-            ], 
-            ptc.last_blockhash
+        assert_eq!(
+            data.len(),
+            lottery_account.try_to_vec().unwrap().len(),
+            "invalid lottery account data size."
         );
 
-        ptc
-            .banks_client
-            .process_transaction(tx)
-            .await
-            .unwrap();
-
-        let SolanaAccount { data, .. } = ptc
+        let SolanaAccount { lamports: user_account_auth_lamport_balance, .. } = ptc
             .banks_client
-            .get_account(lottery_account_pda.0)
+            .get_account(user_account_auth.pubkey())
             .await
             .unwrap()
             .unwrap();
 
-        let Lottery { ticket_price, .. } = Lottery::deserialize(
-            &mut &data[..]
-        ).unwrap();
-
         assert_eq!(
-            ticket_price,
-            new_ticket_price,
-            "1. invalid new lottery's ticket price."
+            user_account_auth_lamport_balance,
+            sol_to_lamports(1.0) +
+            100000 + // tickets rent exempt lamports
+            sol_to_lamports(1.0), // user account's lamport balance
+            "invalid user account authority's lamport balance."
         );
 
-        // 2. lottery is not started yet
-        ptc
-            .get_new_latest_blockhash()
+        let SolanaAccount { data: lottery_usdc_ata_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_usdc_ata_pubkey)
             .await
+            .unwrap()
             .unwrap();
-        change_clock_sysvar(
-            &ptc,
-            65
-        );
 
-        let new_ticket_price = 15_000000u64;
-        let instruction = instruction_change_lottery_ticket_price(
-            lottery_account_pda.0, 
-            lottery_authority_account.pubkey(), 
-            config_account_pda.0, 
-            new_ticket_price // USDC
-        );
+        let TokenAccount { amount: lottery_usdc_ata_balance, .. } = TokenAccount::unpack(
+            &lottery_usdc_ata_data
+        ).unwrap();
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[
-                &ptc.payer,
-                &lottery_authority_account
-            ], 
-            ptc.last_blockhash
+        assert_eq!(
+            lottery_usdc_ata_balance,
+            1000_000000 - 100_000000,
+            "invalid lottery USDC ata token balance."
         );
 
-        ptc
-            .banks_client
-            .process_transaction(tx)
-            .await
-            .unwrap();
-
-        let SolanaAccount { data, .. } = ptc
+        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
             .banks_client
-            .get_account(lottery_account_pda.0)
+            .get_account(fund_receiver_usdc_token_account_pubkey)
             .await
             .unwrap()
             .unwrap();
 
-        let Lottery { ticket_price, .. } = Lottery::deserialize(
-            &mut &data[..]
+        let TokenAccount { amount: fund_receiver_usdc_token_account_balance, .. } = TokenAccount::unpack(
+            &fund_receiver_usdc_token_account_data
         ).unwrap();
 
         assert_eq!(
-            ticket_price,
-            new_ticket_price,
-            "2. invalid new lottery's ticket price."
+            fund_receiver_usdc_token_account_balance,
+            100_000000,
+            "invalid fund receiver usdc token account balance."
         );
     }
     // success
 }
 
 #[tokio::test]
-async fn test_end_lottery_and_pick_winners() {
+async fn test_close_lottery_account_usdc_token_account() {
     let lottery_program_id = LOTTERY_PROGRAM_ID;
     let mut pt = setup_program_test(lottery_program_id);
 
@@ -4811,17 +17745,7 @@ async fn test_end_lottery_and_pick_winners() {
     let config_account = Config {
         discriminator: Config::get_discriminator(),
         canonical_bump: config_account_pda.1,
-        pyth_price_feed_accounts: [
-            Pubkey::from_str("7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE").unwrap(), // SOl
-            Pubkey::from_str("4cSM2e6rvbGQUFiJbqytoVMi5GgghSMr8LwVrT9VPSPo").unwrap(), // BTC
-            Pubkey::from_str("42amVS4KgzR9rA28tkVYqVXjq9Qa8dcZQMbH5EYFX6XC").unwrap() // ETH
-        ],
-        pyth_price_feed_ids: [
-            "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d".to_string(), // SOL
-            "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43".to_string(), // BTC
-            "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace".to_string() // ETH
-        ],
-        maximum_time_of_price_feed_age: 10,
+        usdc_mint_account: Pubkey::new_from_array([1; 32]),
         ..Config::default()
     };
     let config_account_data = config_account.try_to_vec().unwrap();
@@ -4840,11 +17764,21 @@ async fn test_end_lottery_and_pick_winners() {
     //////////////////////? add config account
     
     //////////////////////? add lottery account
+    let lottery_auth = Keypair::new();
+    pt.add_account(
+        lottery_auth.pubkey(),
+        SolanaAccount {
+            lamports: sol_to_lamports(1.0),
+            owner: SYSTEM_PROGRAM_ID,
+            ..SolanaAccount::default()
+        }
+    );
+
     let lottery_account_pda = Pubkey::find_program_address(
         &[
             b"lottery_account",
-            Pubkey::default().to_bytes().as_slice(),
-            get_lottery_literal_seed(&String::from("1")).as_slice()
+            lottery_auth.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("lottery for fun!")).as_slice()
         ],
         &lottery_program_id
     );
@@ -4854,30 +17788,22 @@ async fn test_end_lottery_and_pick_winners() {
         canonical_bump: lottery_account_pda.1,
         starting_time: 100,
         ending_time: 200,
-        lottery_description: String::from("1"),
-        winners_count: 5,
-        minimum_tickets_amount_required_to_be_sold: 5,
-        tickets_total_amount: 10,
+        lottery_description: String::from("lottery for fun!"),
+        minimum_tickets_amount_required_to_be_sold: 100,
+        tickets_total_amount: 90,
+        is_creator_withdrawed_when_lottery_was_failed: true,
+        winners_count: 10,
+        authority: lottery_auth.pubkey(),
         ..Lottery::default()
     };
-    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64 + (lottery_account.winners_count as u64 * 33);
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64 + 330;
 
     let lottey_solana_account = SolanaAccount {
         owner: lottery_program_id,
         lamports: solana_sdk::native_token::sol_to_lamports(1.0),
         data: vec![
             lottery_account.try_to_vec().unwrap(),
-            vec![0u8; lottery_account.winners_count as usize * 33],
-            Pubkey::new_from_array([10; 32]).to_bytes().to_vec(),
-            Pubkey::new_from_array([5; 32]).to_bytes().to_vec(),
-            Pubkey::new_from_array([8; 32]).to_bytes().to_vec(),
-            Pubkey::new_from_array([2; 32]).to_bytes().to_vec(),
-            Pubkey::new_from_array([1; 32]).to_bytes().to_vec(),
-            Pubkey::new_from_array([3; 32]).to_bytes().to_vec(),
-            Pubkey::new_from_array([4; 32]).to_bytes().to_vec(),
-            Pubkey::new_from_array([9; 32]).to_bytes().to_vec(),
-            Pubkey::new_from_array([7; 32]).to_bytes().to_vec(),
-            Pubkey::new_from_array([6; 32]).to_bytes().to_vec()
+            vec![0u8; 330]
         ].concat(),
         ..SolanaAccount::default()
     };
@@ -4888,136 +17814,243 @@ async fn test_end_lottery_and_pick_winners() {
     );
     //////////////////////? add lottery account
     
-    //////////////////////? add SOL pyth price feed account
-    let sol_price_feed_account_pubkey = config_account.pyth_price_feed_accounts[0];
-    let sol_price_feed_account = PriceUpdateV2 {
-        verification_level: VerificationLevel::Full,
-        price_message: PriceFeedMessage {
-            publish_time: 300,
-            price: 250,
-            conf: u64::default(),
-            ema_conf: u64::default(),
-            ema_price: i64::default(),
-            exponent: i32::default(),
-            feed_id: get_feed_id_from_hex(
-                &config_account.pyth_price_feed_ids[0]
-            ).unwrap(),
-            prev_publish_time: i64::default()
-        },
-        write_authority: Pubkey::default(),
-        posted_slot: u64::default()
+    //////////////////////? add UDSC mint account
+    let usdc_mint_account = MintAccount {
+        supply: 1000_000000,
+        decimals: 6,
+        is_initialized: true,
+        ..MintAccount::default()
     };
 
-    let sol_price_feed_solana_account = SolanaAccount {
+    let mut usdc_mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        usdc_mint_account,
+        usdc_mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    let usdc_mint_solana_account = SolanaAccount {
+        data: usdc_mint_account_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
         lamports: sol_to_lamports(1.0),
-        owner: PYTH_PRICE_RECEIVER_PROGRAM_ID,
-        data: vec![
-            vec![ 34, 241, 35, 99, 157, 126, 244, 205 ],
-            sol_price_feed_account.try_to_vec().unwrap()
-        ].concat(),
         ..SolanaAccount::default()
     };
 
     pt.add_account(
-        sol_price_feed_account_pubkey,
-        sol_price_feed_solana_account
+        config_account.usdc_mint_account,
+        usdc_mint_solana_account
     );
-    //////////////////////? add SOL pyth price feed account
+    //////////////////////? add USDC mint account
     
-    //////////////////////? add BTC pyth price feed account
-    let btc_price_feed_account_pubkey = config_account.pyth_price_feed_accounts[1];
-    let btc_price_feed_account = PriceUpdateV2 {
-        verification_level: VerificationLevel::Full,
-        price_message: PriceFeedMessage {
-            publish_time: 300,
-            price: 150,
-            conf: u64::default(),
-            ema_conf: u64::default(),
-            ema_price: i64::default(),
-            exponent: i32::default(),
-            feed_id: get_feed_id_from_hex(
-                &config_account.pyth_price_feed_ids[1]
-            ).unwrap(),
-            prev_publish_time: i64::default()
-        },
-        write_authority: Pubkey::default(),
-        posted_slot: u64::default()
+    //////////////////////? add lottery's USDC ata
+    let lottery_usdc_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0, 
+        &config_account.usdc_mint_account
+    );
+    let lottery_usdc_ata = TokenAccount {
+        amount: 10_000000,
+        owner: lottery_account_pda.0,
+        state: TokenAccountState::Initialized,
+        mint: config_account.usdc_mint_account,
+        ..TokenAccount::default()
     };
 
-    let btc_price_feed_solana_account = SolanaAccount {
+    let mut lottery_usdc_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        lottery_usdc_ata,
+        lottery_usdc_ata_data.as_mut_slice()
+    ).unwrap();
+
+    let lottery_usdc_ata_solana_account = SolanaAccount {
+        data: lottery_usdc_ata_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
         lamports: sol_to_lamports(1.0),
-        owner: PYTH_PRICE_RECEIVER_PROGRAM_ID,
-        data: vec![
-            vec![ 34, 241, 35, 99, 157, 126, 244, 205 ],
-            btc_price_feed_account.try_to_vec().unwrap()
-        ].concat(),
         ..SolanaAccount::default()
     };
 
     pt.add_account(
-        btc_price_feed_account_pubkey,
-        btc_price_feed_solana_account
+        lottery_usdc_ata_pubkey,
+        lottery_usdc_ata_solana_account
     );
-    //////////////////////? add BTC pyth price feed account
+    //////////////////////? add lottery's USDC ata
     
-    //////////////////////? add ETH pyth price feed account
-    let eth_price_feed_account_pubkey = config_account.pyth_price_feed_accounts[2];
-    let eth_price_feed_account = PriceUpdateV2 {
-        verification_level: VerificationLevel::Full,
-        price_message: PriceFeedMessage {
-            publish_time: 500,
-            price: 250,
-            conf: u64::default(),
-            ema_conf: u64::default(),
-            ema_price: i64::default(),
-            exponent: i32::default(),
-            feed_id: get_feed_id_from_hex(
-                &config_account.pyth_price_feed_ids[2]
-            ).unwrap(),
-            prev_publish_time: i64::default()
-        },
-        write_authority: Pubkey::default(),
-        posted_slot: u64::default()
+    //////////////////////? add fund receiver, USDC token account
+    let fund_receiver_usdc_token_account_pubkey = Pubkey::new_from_array([2; 32]);
+    let fund_receiver_usdc_token_account = TokenAccount {
+        amount: 0_000000,
+        state: TokenAccountState::Initialized,
+        mint: config_account.usdc_mint_account,
+        ..TokenAccount::default()
+    };
+
+    let mut fund_receiver_usdc_token_account_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        fund_receiver_usdc_token_account,
+        fund_receiver_usdc_token_account_data.as_mut_slice()
+    ).unwrap();
+
+    let fund_receiver_usdc_token_solana_account = SolanaAccount {
+        data: fund_receiver_usdc_token_account_data.to_vec(),
+        owner: TOKEN_STANDARD_PROGRAM_ID,
+        lamports: sol_to_lamports(1.0),
+        ..SolanaAccount::default()
     };
 
-    let eth_price_feed_solana_account = SolanaAccount {
-        lamports: sol_to_lamports(1.0),
-        owner: PYTH_PRICE_RECEIVER_PROGRAM_ID,
-        data: vec![
-            vec![ 34, 241, 35, 99, 157, 126, 244, 205 ],
-            eth_price_feed_account.try_to_vec().unwrap()
-        ].concat(),
-        ..SolanaAccount::default()
-    };
+    pt.add_account(
+        fund_receiver_usdc_token_account_pubkey,
+        fund_receiver_usdc_token_solana_account
+    );
+    //////////////////////? add fund receiver, USDC token account
+    
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - lottery invalid state
+    {
+        // 1. LotteryState::Unknown
+        ptc.
+            get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 50);
+
+        let instruction = instruction_close_lottery_account_and_usdc_token_account(
+            config_account_pda.0, 
+            lottery_account_pda.0, 
+            lottery_auth.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_usdc_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            lottery_auth.pubkey(), 
+            TOKEN_STANDARD_PROGRAM_ID,
+            false,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction.clone() ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryState as u32
+                )
+            )
+        );
+
+        // 2. LotteryState::Successful
+        ptc.
+            get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 450);
+
+        lottery_account.minimum_tickets_amount_required_to_be_sold = 100;
+        lottery_account.tickets_total_amount = 150;
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: LOTTERY_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    data: vec![
+                        lottery_account.try_to_vec().unwrap(),
+                        vec![0u8; 330]
+                    ].concat(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::SucceedLotteryNotSettled as u32
+                )
+            )
+        );
 
-    pt.add_account(
-        eth_price_feed_account_pubkey,
-        eth_price_feed_solana_account
-    );
-    //////////////////////? add ETH pyth price feed account
-    
-    let mut ptc = pt.start_with_context().await;
+        // return to default
+        lottery_account.minimum_tickets_amount_required_to_be_sold = 100;
+        lottery_account.tickets_total_amount = 90;
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: LOTTERY_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    data: vec![
+                        lottery_account.try_to_vec().unwrap(),
+                        vec![0u8; 330]
+                    ].concat(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+    }
+    // failure - lottery invalid state
 
     // success
     {
-        ptc
-            .get_new_latest_blockhash()
+        ptc.
+            get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 250);
+        change_clock_sysvar(&ptc, 350);
 
-        let instruction = instruction_end_lottery_and_pick_winners(
-            lottery_account_pda.0, 
+        let instruction = instruction_close_lottery_account_and_usdc_token_account(
             config_account_pda.0, 
-            sol_price_feed_account_pubkey, 
-            btc_price_feed_account_pubkey, 
-            eth_price_feed_account_pubkey
+            lottery_account_pda.0, 
+            lottery_auth.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_usdc_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            lottery_auth.pubkey(), 
+            TOKEN_STANDARD_PROGRAM_ID,
+            false,
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[&ptc.payer], 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_auth
+            ],
             ptc.last_blockhash
         );
 
@@ -5027,118 +18060,100 @@ async fn test_end_lottery_and_pick_winners() {
             .await
             .unwrap();
 
-        let SolanaAccount { data, .. } = ptc
+        let error = ptc
             .banks_client
             .get_account(lottery_account_pda.0)
             .await
-            .unwrap()
             .unwrap();
+        if error.is_some() {
+            panic!("Lottery account must be closed.");
+        };
 
-        let Lottery { is_ended_successfuly, winners, random_numbers_info, .. } = Lottery::deserialize(
-            &mut &data[..]
-        ).unwrap();
-
-        assert_eq!(
-            is_ended_successfuly,
-            true,
-            "invalid is_ended_suc.. flag."
-        );
-
-        let winners_index: [u8; 5] = [ 3, 6, 4, 2, 8 ];
-        for index in 0..5 {
-            assert_eq!(
-                winners[index].0,
-                Pubkey::new_from_array([winners_index[index]; 32]),
-                "invalid winner pubkey -> {} - {}", index, winners_index[index]
-            );
-
-            assert_eq!(
-                winners[index].1,
-                false,
-                "invalid winner flag -> {}", index
-            );
+        let error = ptc
+            .banks_client
+            .get_account(lottery_usdc_ata_pubkey)
+            .await
+            .unwrap();
+        if error.is_some() {
+            panic!("Lottery USDC ata account must be closed.");
         };
 
-        let (
-            price_feed_account,
-            price_publish_time,
-            price 
-        ) = random_numbers_info;
+        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_usdc_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
 
-        assert_eq!(
-            price_feed_account,
-            btc_price_feed_account_pubkey,
-            "invalid price feed account pubkey."
-        );
+        let TokenAccount { amount: fund_receiver_usdc_token_account_balance, .. } = TokenAccount::unpack(
+            &fund_receiver_usdc_token_account_data
+        ).unwrap();
 
         assert_eq!(
-            price_publish_time,
-            btc_price_feed_account.price_message.publish_time,
-            "invalid price feed publish time."
+            fund_receiver_usdc_token_account_balance,
+            10_000000,
+            "invalid fund receiver usdc token account balance."
         );
 
+        let SolanaAccount { lamports: fund_receiver_rent_exempt_lamports_balance , ..} = ptc
+            .banks_client
+            .get_account(lottery_auth.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+
         assert_eq!(
-            price,
-            btc_price_feed_account.price_message.price,
-            "invalid price."
+            fund_receiver_rent_exempt_lamports_balance,
+            sol_to_lamports(1.0) + sol_to_lamports(1.0) + sol_to_lamports(1.0),
+            "invalid rent exempt fund receiver lamport balance."
         );
-    }   
+    }
     // success
 
-    // failure - lottery already ended
+    // failure - lottery owner must first close Arbitrary ata
     {
-        let lottery_account_pda = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                Pubkey::default().to_bytes().as_slice(),
-                get_lottery_literal_seed(&String::from("1")).as_slice()
-            ],
-            &lottery_program_id
-        );
-    
-        let lottery_account = Lottery {
-            discriminator: Lottery::get_discriminator(),
-            canonical_bump: lottery_account_pda.1,
-            starting_time: 100,
-            ending_time: 200,
-            lottery_description: String::from("1"),
-            winners_count: 5,
-            minimum_tickets_amount_required_to_be_sold: 5,
-            tickets_total_amount: 10,
-            is_ended_successfuly: true,
-            ..Lottery::default()
-        };
-    
-        let lottey_solana_account = SolanaAccount {
-            owner: lottery_program_id,
-            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-            data: lottery_account.try_to_vec().unwrap(),
-            ..SolanaAccount::default()
-        };
-    
+        ptc.
+            get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(&ptc, 450);
+
+        lottery_account.is_creator_withdrawed_when_lottery_was_failed = false;
         ptc.set_account(
             &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(lottey_solana_account)
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: LOTTERY_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    data: vec![
+                        lottery_account.try_to_vec().unwrap(),
+                        vec![0u8; 330]
+                    ].concat(),
+                    ..SolanaAccount::default()
+                }
+            )
         );
 
-        ptc
-            .get_new_latest_blockhash()
-            .await
-            .unwrap();
-        change_clock_sysvar(&ptc, 250);
-
-        let instruction = instruction_end_lottery_and_pick_winners(
-            lottery_account_pda.0, 
+        let instruction = instruction_close_lottery_account_and_usdc_token_account(
             config_account_pda.0, 
-            sol_price_feed_account_pubkey, 
-            btc_price_feed_account_pubkey, 
-            eth_price_feed_account_pubkey
+            lottery_account_pda.0, 
+            lottery_auth.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_usdc_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            lottery_auth.pubkey(), 
+            TOKEN_STANDARD_PROGRAM_ID,
+            false,
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[&ptc.payer], 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_auth
+            ],
             ptc.last_blockhash
         );
 
@@ -5154,67 +18169,74 @@ async fn test_end_lottery_and_pick_winners() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::LotteryAlreadyEnded as u32
+                    LotteryError::FirstCloseLotteryArbitrartAssociatedTokenAccount as u32
                 )
             )
         );
-    }
-    // failure - lottery already ended
 
-    // failure - lottery was failed
-    {
-        // 1. LotteryState::Failed
-        let lottery_account_pda = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                Pubkey::default().to_bytes().as_slice(),
-                get_lottery_literal_seed(&String::from("1")).as_slice()
-            ],
-            &lottery_program_id
-        );
-    
-        let lottery_account = Lottery {
-            discriminator: Lottery::get_discriminator(),
-            canonical_bump: lottery_account_pda.1,
-            starting_time: 100,
-            ending_time: 200,
-            lottery_description: String::from("1"),
-            winners_count: 5,
-            minimum_tickets_amount_required_to_be_sold: 50,
-            tickets_total_amount: 10,
-            ..Lottery::default()
-        };
-    
-        let lottey_solana_account = SolanaAccount {
-            owner: lottery_program_id,
-            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-            data: lottery_account.try_to_vec().unwrap(),
-            ..SolanaAccount::default()
-        };
-    
+        // return to default
+        lottery_account.is_creator_withdrawed_when_lottery_was_failed = true;
         ptc.set_account(
             &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(lottey_solana_account)
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: LOTTERY_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    data: vec![
+                        lottery_account.try_to_vec().unwrap(),
+                        vec![0u8; 330]
+                    ].concat(),
+                    ..SolanaAccount::default()
+                }
+            )
         );
+    }
+    // failure - lottery owner must first close Arbitrary ata
 
-        ptc
-            .get_new_latest_blockhash()
+    // failure - users didn't claim their funds from lottery account
+    {
+        ptc.
+            get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 250);
+        change_clock_sysvar(&ptc, 450);
 
-        let instruction = instruction_end_lottery_and_pick_winners(
-            lottery_account_pda.0, 
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: LOTTERY_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.1),
+                    data: vec![
+                        lottery_account.try_to_vec().unwrap(),
+                        vec![0u8; 330],
+                        vec![0u8, 96]
+                    ].concat(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let instruction = instruction_close_lottery_account_and_usdc_token_account(
             config_account_pda.0, 
-            sol_price_feed_account_pubkey, 
-            btc_price_feed_account_pubkey, 
-            eth_price_feed_account_pubkey
+            lottery_account_pda.0, 
+            lottery_auth.pubkey(), 
+            config_account.usdc_mint_account, 
+            lottery_usdc_ata_pubkey, 
+            fund_receiver_usdc_token_account_pubkey, 
+            lottery_auth.pubkey(), 
+            TOKEN_STANDARD_PROGRAM_ID,
+            false,
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[&ptc.payer], 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_auth
+            ],
             ptc.last_blockhash
         );
 
@@ -5230,63 +18252,209 @@ async fn test_end_lottery_and_pick_winners() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::LotteryWasNotSuccessfull as u32
+                    LotteryError::CannotCloseAccounts as u32
                 )
             )
         );
+    }
+    // failure - users didn't claim their funds from lottery account
+}
 
-        // 2. LotteryState::Unknown
-        let lottery_account_pda = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                Pubkey::default().to_bytes().as_slice(),
-                get_lottery_literal_seed(&String::from("1")).as_slice()
-            ],
-            &lottery_program_id
-        );
-    
-        let lottery_account = Lottery {
-            discriminator: Lottery::get_discriminator(),
-            canonical_bump: lottery_account_pda.1,
-            starting_time: 100,
-            ending_time: 200,
-            lottery_description: String::from("1"),
-            winners_count: 5,
-            minimum_tickets_amount_required_to_be_sold: 50,
-            tickets_total_amount: 100,
-            ..Lottery::default()
+#[tokio::test]
+async fn test_cancel_lottery() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        treasury: Pubkey::new_from_array([5; 32]),
+        lottery_cancellation_penalty: 2_000000, // 2 USDCs
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add lottery account
+    let lottery_account_auth = Keypair::new();
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            lottery_account_auth.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        minimum_tickets_amount_required_to_be_sold: 50,
+        lottery_description: String::from("1"),
+        authority: lottery_account_auth.pubkey(),
+        lottery_creation_fee: 10_000000, // 10 USDCs
+        starting_time: 200,
+        ending_time: 350,
+        arbitrary_mint_account_address: Pubkey::new_from_array([1; 32]),
+        ..Lottery::default()
+    };
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: lottery_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+
+    //////////////////////? add UDSC & Arbitrary mint accounts
+    let arbitrary_mint_account_pubkey = Pubkey::new_from_array([1; 32]);
+    for (mint_account_pubkey, decimals) in [
+        (config_account.usdc_mint_account, 6u8),
+        (arbitrary_mint_account_pubkey, 3)
+    ] {
+        let mint_account = MintAccount {
+            supply: 100_000000,
+            decimals,
+            is_initialized: true,
+            ..MintAccount::default()
         };
-    
-        let lottey_solana_account = SolanaAccount {
-            owner: lottery_program_id,
-            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-            data: lottery_account.try_to_vec().unwrap(),
-            ..SolanaAccount::default()
+
+        let mut mint_account_data = [0u8; MintAccount::LEN];
+        MintAccount::pack(
+            mint_account,
+            mint_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            mint_account_pubkey,
+            SolanaAccount {
+                data: mint_account_data.to_vec(),
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(1.0),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add UDSC & Arbitrary mint accounts
+
+    //////////////////////? add token accounts
+    let lottery_usdc_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &config_account.usdc_mint_account
+    );
+    let lottery_arbitrary_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &arbitrary_mint_account_pubkey
+    );
+    let fund_receiver_usdc_token_account_pubkey = Pubkey::new_from_array([2; 32]);
+    let fund_receiver_arbitrary_token_account_pubkey = Pubkey::new_from_array([3; 32]);
+
+    for (token_account_pubkey, mint, owner, amount) in [
+        (lottery_usdc_ata_pubkey, config_account.usdc_mint_account, lottery_account_pda.0, 10_000000u64),
+        (lottery_arbitrary_ata_pubkey, arbitrary_mint_account_pubkey, lottery_account_pda.0, 350_000),
+        (fund_receiver_usdc_token_account_pubkey, config_account.usdc_mint_account, Pubkey::new_unique(), 0),
+        (fund_receiver_arbitrary_token_account_pubkey, arbitrary_mint_account_pubkey, Pubkey::new_unique(), 0),
+        (config_account.treasury, config_account.usdc_mint_account, Pubkey::new_unique(), 0)
+    ] {
+        let token_account = TokenAccount {
+            amount,
+            owner,
+            state: TokenAccountState::Initialized,
+            mint,
+            ..TokenAccount::default()
         };
-    
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(lottey_solana_account)
+
+        let mut token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            token_account,
+            token_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            token_account_pubkey,
+            SolanaAccount {
+                data: token_account_data.to_vec(),
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: 2039280,
+                ..SolanaAccount::default()
+            }
         );
+    };
+    //////////////////////? add token accounts
+
+    //////////////////////? add fund receiver, rent-exempt's lamports account
+    let fund_receiver_rent_exempt_lamports = Pubkey::new_from_array([4; 32]);
+    pt.add_account(
+        fund_receiver_rent_exempt_lamports,
+        SolanaAccount {
+            owner: SYSTEM_PROGRAM_ID,
+            lamports: sol_to_lamports(0.1),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add fund receiver, rent-exempt's lamports account
 
+    let mut ptc = pt.start_with_context().await;
+
+    // failure - lottery is started
+    {
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 50);
+        change_clock_sysvar(&ptc, 250);
 
-        let instruction = instruction_end_lottery_and_pick_winners(
-            lottery_account_pda.0, 
-            config_account_pda.0, 
-            sol_price_feed_account_pubkey, 
-            btc_price_feed_account_pubkey, 
-            eth_price_feed_account_pubkey
+        let instruction = instruction_cancel_lottery(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            lottery_account_auth.pubkey(),
+            config_account.usdc_mint_account,
+            arbitrary_mint_account_pubkey,
+            lottery_usdc_ata_pubkey,
+            lottery_arbitrary_ata_pubkey,
+            fund_receiver_usdc_token_account_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            config_account.treasury,
+            fund_receiver_rent_exempt_lamports,
+            TOKEN_STANDARD_PROGRAM_ID,
+            vec![],
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[&ptc.payer], 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_account_auth
+            ],
             ptc.last_blockhash
         );
 
@@ -5302,63 +18470,45 @@ async fn test_end_lottery_and_pick_winners() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::LotteryWasNotSuccessfull as u32
+                    LotteryError::InvalidLotteryState as u32
                 )
-            )
-        );
-
-        // 3. LotteryState::Unknown
-        let lottery_account_pda = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                Pubkey::default().to_bytes().as_slice(),
-                get_lottery_literal_seed(&String::from("1")).as_slice()
-            ],
-            &lottery_program_id
-        );
-    
-        let lottery_account = Lottery {
-            discriminator: Lottery::get_discriminator(),
-            canonical_bump: lottery_account_pda.1,
-            starting_time: 100,
-            ending_time: 200,
-            lottery_description: String::from("1"),
-            winners_count: 5,
-            minimum_tickets_amount_required_to_be_sold: 50,
-            tickets_total_amount: 100,
-            ..Lottery::default()
-        };
-    
-        let lottey_solana_account = SolanaAccount {
-            owner: lottery_program_id,
-            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-            data: lottery_account.try_to_vec().unwrap(),
-            ..SolanaAccount::default()
-        };
-    
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(lottey_solana_account)
+            )
         );
+    }
+    // failure - lottery is started
 
+    // failure - invalid treasury account
+    {
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
         change_clock_sysvar(&ptc, 150);
 
-        let instruction = instruction_end_lottery_and_pick_winners(
-            lottery_account_pda.0, 
-            config_account_pda.0, 
-            sol_price_feed_account_pubkey, 
-            btc_price_feed_account_pubkey, 
-            eth_price_feed_account_pubkey
+        let instruction = instruction_cancel_lottery(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            lottery_account_auth.pubkey(),
+            config_account.usdc_mint_account,
+            arbitrary_mint_account_pubkey,
+            lottery_usdc_ata_pubkey,
+            lottery_arbitrary_ata_pubkey,
+            fund_receiver_usdc_token_account_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            fund_receiver_usdc_token_account_pubkey,
+            fund_receiver_rent_exempt_lamports,
+            TOKEN_STANDARD_PROGRAM_ID,
+            vec![],
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[&ptc.payer], 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_account_auth
+            ],
             ptc.last_blockhash
         );
 
@@ -5374,91 +18524,114 @@ async fn test_end_lottery_and_pick_winners() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::LotteryWasNotSuccessfull as u32
+                    LotteryError::InvalidTreasuryAccount as u32
                 )
             )
         );
     }
-    // failure - lottery was failed
+    // failure - invalid treasury account
 
-    // failure - insufficient random numbers
+    // success
     {
-        let lottery_account_pda = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                Pubkey::default().to_bytes().as_slice(),
-                get_lottery_literal_seed(&String::from("1")).as_slice()
-            ],
-            &lottery_program_id
-        );
-    
-        let lottery_account = Lottery {
-            discriminator: Lottery::get_discriminator(),
-            canonical_bump: lottery_account_pda.1,
-            starting_time: 100,
-            ending_time: 200,
-            lottery_description: String::from("1"),
-            winners_count: 35,
-            minimum_tickets_amount_required_to_be_sold: 5,
-            tickets_total_amount: 10,
-            ..Lottery::default()
-        };
-    
-        let lottey_solana_account = SolanaAccount {
-            owner: lottery_program_id,
-            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-            data: lottery_account.try_to_vec().unwrap(),
-            ..SolanaAccount::default()
-        };
-    
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(lottey_solana_account)
-        );
-
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 250);
+        change_clock_sysvar(&ptc, 150);
 
-        let instruction = instruction_end_lottery_and_pick_winners(
-            lottery_account_pda.0, 
-            config_account_pda.0, 
-            sol_price_feed_account_pubkey, 
-            btc_price_feed_account_pubkey, 
-            eth_price_feed_account_pubkey
+        let instruction = instruction_cancel_lottery(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            lottery_account_auth.pubkey(),
+            config_account.usdc_mint_account,
+            arbitrary_mint_account_pubkey,
+            lottery_usdc_ata_pubkey,
+            lottery_arbitrary_ata_pubkey,
+            fund_receiver_usdc_token_account_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            config_account.treasury,
+            fund_receiver_rent_exempt_lamports,
+            TOKEN_STANDARD_PROGRAM_ID,
+            vec![],
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[&ptc.payer], 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_account_auth
+            ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
+        let mut token_accounts_amount = vec![];
+        for token_account_pubkey in [
+            fund_receiver_usdc_token_account_pubkey,
+            fund_receiver_arbitrary_token_account_pubkey,
+            config_account.treasury
+        ] {
+            let SolanaAccount { data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            token_accounts_amount.push(
+                TokenAccount::unpack(&data).unwrap().amount
+            );
+        };
+
         assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::InsufficientRandomNumbers as u32
-                )
-            )
+            token_accounts_amount,
+            vec![ 8_000000, 350_000, 2_000000 ],
+            "invalid refunded creation-fee, arbitrary tokens or cancellation penalty."
+        );
+
+        for closed_account_pubkey in [
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            lottery_arbitrary_ata_pubkey
+        ] {
+            let closed_account = ptc
+                .banks_client
+                .get_account(closed_account_pubkey)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                closed_account,
+                None,
+                "account is not closed."
+            );
+        };
+
+        let SolanaAccount { lamports, .. } = ptc
+            .banks_client
+            .get_account(fund_receiver_rent_exempt_lamports)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            lamports,
+            sol_to_lamports(0.1) + sol_to_lamports(1.0) + 2 * 2039280,
+            "invalid fund receiver's rent-exempt lamports."
         );
     }
-    // failure - insufficient random numbers
+    // success
 }
 
 #[tokio::test]
-async fn test_withdraw_succeed_lottery() {
+async fn test_withdraw_and_close_succeed_user() {
     let lottery_program_id = LOTTERY_PROGRAM_ID;
     let mut pt = setup_program_test(lottery_program_id);
 
@@ -5473,12 +18646,7 @@ async fn test_withdraw_succeed_lottery() {
     let config_account = Config {
         discriminator: Config::get_discriminator(),
         canonical_bump: config_account_pda.1,
-        lottery_creation_fee: 5_000000, // 5 USDC
-        maximum_number_of_winners: 10,
-        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
-        maximum_time_for_lottery_account: 1000,
-        minimum_tickets_to_be_sold_in_lottery: 20,
-        max_lottery_description_bytes: 10,
+        usdc_mint_account: Pubkey::new_from_array([1; 32]),
         ..Config::default()
     };
     let config_account_data = config_account.try_to_vec().unwrap();
@@ -5495,49 +18663,37 @@ async fn test_withdraw_succeed_lottery() {
         config_solana_account
     );
     //////////////////////? add config account
-
-    //////////////////////? add lottery_account authority
-    let lottery_authority_account = Keypair::new();
-    pt.add_account(
-        lottery_authority_account.pubkey(),
-        SolanaAccount {
-            owner: SYSTEM_PROGRAM_ID,
-            lamports: sol_to_lamports(1.0),
-            ..SolanaAccount::default()
-        }
-    );
-    //////////////////////? add lottery_account authority
     
     //////////////////////? add lottery account
     let lottery_account_pda = Pubkey::find_program_address(
         &[
             b"lottery_account",
-            lottery_authority_account.pubkey().to_bytes().as_slice(),
+            Pubkey::default().to_bytes().as_slice(),
             get_lottery_literal_seed(&String::from("1")).as_slice()
         ],
         &lottery_program_id
     );
 
-    let lottery_account = Lottery {
+    let mut lottery_account = Lottery {
         discriminator: Lottery::get_discriminator(),
         canonical_bump: lottery_account_pda.1,
         starting_time: 100,
         ending_time: 200,
         lottery_description: String::from("1"),
-        winners_count: 5,
-        minimum_tickets_amount_required_to_be_sold: 5,
-        tickets_total_amount: 10,
-        protocol_fee: 95_000000,
-        lottery_creation_fee: 5_000000,
-        authority: lottery_authority_account.pubkey(),
+        minimum_tickets_amount_required_to_be_sold: 100,
+        tickets_total_amount: 900,
         is_ended_successfuly: true,
         ..Lottery::default()
     };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
 
     let lottey_solana_account = SolanaAccount {
         owner: lottery_program_id,
         lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-        data: lottery_account.try_to_vec().unwrap(),
+        data: vec![
+            lottery_account.try_to_vec().unwrap(),
+            vec![0u8; 72]
+        ].concat(),
         ..SolanaAccount::default()
     };
 
@@ -5546,121 +18702,95 @@ async fn test_withdraw_succeed_lottery() {
         lottey_solana_account
     );
     //////////////////////? add lottery account
-
-    //////////////////////? add USDC mint account
-    let usdc_mint_account = MintAccount {
-        supply: 1000_000000,
-        decimals: 6,
-        is_initialized: true,
-        ..MintAccount::default()
-    };
-
-    let mut usdc_mint_account_data: [u8; MintAccount::LEN] = [0; MintAccount::LEN];
-    MintAccount::pack(
-        usdc_mint_account,
-        usdc_mint_account_data.as_mut_slice()
-    ).unwrap();
-
-    let uscd_mint_solana_account = SolanaAccount {
-        lamports: sol_to_lamports(0.01),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        data: usdc_mint_account_data.to_vec(),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        config_account.usdc_mint_account,
-        uscd_mint_solana_account
-    );
-    //////////////////////? add USDC mint account
     
-    //////////////////////? add fund receiver USDC token account
-    let funding_usdc_token_account_pubkey = Pubkey::new_from_array([1; 32]);
-    let funding_usdc_token_account = TokenAccount {
-        mint: config_account.usdc_mint_account,
-        amount: 0_000000,
-        state: TokenAccountState::Initialized,
-        ..TokenAccount::default()
-    };
-
-    let mut funding_usdc_token_account_data: [u8; TokenAccount::LEN] = [0; TokenAccount::LEN];
-    TokenAccount::pack(
-        funding_usdc_token_account,
-        funding_usdc_token_account_data.as_mut_slice()
-    ).unwrap();
-
-    let funding_usdc_token_solana_account = SolanaAccount {
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        data: funding_usdc_token_account_data.to_vec(),
-        lamports: sol_to_lamports(0.01),
-        ..SolanaAccount::default()
-    };
-
+    //////////////////////? add user account
+    let user_account_auth = Keypair::new();
     pt.add_account(
-        funding_usdc_token_account_pubkey,
-        funding_usdc_token_solana_account
+        user_account_auth.pubkey(),
+        SolanaAccount {
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
     );
-    //////////////////////? add fund receiver USDC token account
-    
-    //////////////////////? add lottery's usdc ata
-    let lottery_ata_pda = get_associated_token_address(
-        &lottery_account_pda.0,
-        &config_account.usdc_mint_account
+
+    let user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            user_account_auth.pubkey().to_bytes().as_slice(),
+            &lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
     );
-    let lottery_ata = TokenAccount {
-        state: TokenAccountState::Initialized,
-        mint: config_account.usdc_mint_account,
-        amount: 1000_000000,
-        owner: lottery_account_pda.0,
-        ..TokenAccount::default()
-    };
 
-    let mut lottery_ata_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        lottery_ata,
-        lottery_ata_data.as_mut_slice()
-    ).unwrap();
+    let user_account = User {
+        discriminator: User::get_discriminator(),
+        canonical_bump: user_account_pda.1,
+        authority: user_account_auth.pubkey(),
+        lottery: lottery_account_pda.0,
+        total_rent_exempt_paied: 100000,
+        total_tickets_acquired: 10,
+        total_ticket_ranges_acquired: 2, // 2 * (32 + 4) bytes of the ticket ledger
+        total_tickets_value: 100_000000, // USDC
+        ..User::default()
+    };
 
-    let lottery_ata_solana_account = SolanaAccount {
-        owner: spl_token::ID,
-        data: lottery_ata_data.to_vec(),
+    let user_solana_account = SolanaAccount {
+        owner: lottery_program_id,
         lamports: sol_to_lamports(1.0),
+        data: user_account.try_to_vec().unwrap(),
         ..SolanaAccount::default()
     };
-
+    
     pt.add_account(
-        lottery_ata_pda,
-        lottery_ata_solana_account
+        user_account_pda.0,
+        user_solana_account
     );
-    //////////////////////? add lottery's usdc ata
+    //////////////////////? add user account
     
     let mut ptc = pt.start_with_context().await;
 
-    // failure - invalid USDC mint account
+    // failure - user is one of the winners
     {
-        ptc
-            .get_new_latest_blockhash()
+        ptc.
+            get_new_latest_blockhash()
             .await
             .unwrap();
         change_clock_sysvar(&ptc, 350);
 
-        let instruction = instruction_withdraw_succeed_lottery(
+        lottery_account.winners.push(
+            (user_account_pda.0, 0, false)
+        );
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: vec![
+                lottery_account.try_to_vec().unwrap(),
+                vec![0u8; 72]
+            ].concat(),
+            ..SolanaAccount::default()
+        };
+    
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
+
+        let instruction = instruction_withdraw_and_close_succeed_user(
             lottery_account_pda.0, 
-            config_account_pda.0, 
-            lottery_authority_account.pubkey(), 
-            lottery_ata_pda, 
-            funding_usdc_token_account_pubkey, 
-            Pubkey::default(), 
-            TOKEN_STANDARD_PROGRAM_ID
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(),
+            config_account_pda.0
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_authority_account
-            ], 
+                &user_account_auth
+            ],
             ptc.last_blockhash
         );
 
@@ -5676,176 +18806,219 @@ async fn test_withdraw_succeed_lottery() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidUsdcMintAccount as u32
+                    LotteryError::UserIsOneOfTheWinners as u32
                 )
             )
         );
+
+        // return to default
+        lottery_account.winners = vec![];
+        let lottey_solana_account = SolanaAccount {
+            owner: lottery_program_id,
+            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+            data: vec![
+                lottery_account.try_to_vec().unwrap(),
+                vec![0u8; 72]
+            ].concat(),
+            ..SolanaAccount::default()
+        };
+
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottey_solana_account)
+        );
     }
-    // failure - invalid USDC mint account
+    // failure - user is one of the winners
 
-    // failure - invalid lottery state
+    // success
     {
-        ptc
-            .get_new_latest_blockhash()
+        ptc.
+            get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 35);
+        change_clock_sysvar(&ptc, 350);
 
-        let instruction = instruction_withdraw_succeed_lottery(
+        let instruction = instruction_withdraw_and_close_succeed_user(
             lottery_account_pda.0, 
-            config_account_pda.0, 
-            lottery_authority_account.pubkey(), 
-            lottery_ata_pda, 
-            funding_usdc_token_account_pubkey, 
-            config_account.usdc_mint_account, 
-            TOKEN_STANDARD_PROGRAM_ID
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(),
+            config_account_pda.0
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_authority_account
-            ], 
+                &user_account_auth
+            ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
-        assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::LotteryWasNotSuccessfull as u32
-                )
-            )
-        );
-    }
-    // failure - invalid lottery state
-
-    // failure - trying to withdraw funds twice
-    {
-        ptc
-            .get_new_latest_blockhash()
+        let error = ptc
+            .banks_client
+            .get_account(user_account_pda.0)
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
+        if error.is_some() {
+            panic!("Account must be closed.");
+        };
 
-        let instruction = instruction_withdraw_succeed_lottery(
-            lottery_account_pda.0, 
-            config_account_pda.0, 
-            lottery_authority_account.pubkey(), 
-            lottery_ata_pda, 
-            funding_usdc_token_account_pubkey, 
-            config_account.usdc_mint_account, 
-            TOKEN_STANDARD_PROGRAM_ID
+        let SolanaAccount { lamports: lottery_account_lamport_balance, data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+        
+        assert_eq!(
+            lottery_account_lamport_balance,
+            sol_to_lamports(1.0) - 100000,
+            "invalid lottery account lamport balance."
         );
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ 
-                instruction.clone(),
-                instruction
-            ], 
-            Some(&ptc.payer.pubkey()), 
-            &[
-                &ptc.payer,
-                &lottery_authority_account
-            ], 
-            ptc.last_blockhash
+        assert_eq!(
+            data.len(),
+            lottery_account.try_to_vec().unwrap().len(),
+            "invalid lottery account data size."
         );
 
-        let error = ptc
+        let SolanaAccount { lamports: user_account_auth_lamport_balance, .. } = ptc
             .banks_client
-            .process_transaction(tx)
+            .get_account(user_account_auth.pubkey())
             .await
-            .unwrap_err()
+            .unwrap()
             .unwrap();
 
         assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                1,
-                InstructionError::Custom(
-                    LotteryError::FundsAlreadyWithdrawed as u32
-                )
-            )
+            user_account_auth_lamport_balance,
+            sol_to_lamports(1.0) +
+            100000 + // tickets rent exempt lamports
+            sol_to_lamports(1.0), // user account's lamport balance
+            "invalid user account authority's lamport balance."
         );
     }
-    // failure - trying to withdraw funds twice
+    // success
+}
+////////////////////////////////////// Lottery Instructions
 
-    // failure - invalid lottery authority
-    {
-        // 1. lottery authority in not signed the transaction
-        ptc
-            .get_new_latest_blockhash()
-            .await
-            .unwrap();
-        change_clock_sysvar(&ptc, 350);
+////////////////////////////////////// User Instructions
+#[tokio::test]
+async fn test_create_and_initialize_user_account() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
 
-        let mut instruction = instruction_withdraw_succeed_lottery(
-            lottery_account_pda.0, 
-            config_account_pda.0, 
-            lottery_authority_account.pubkey(), 
-            lottery_ata_pda, 
-            funding_usdc_token_account_pubkey, 
-            config_account.usdc_mint_account, 
-            TOKEN_STANDARD_PROGRAM_ID
-        );
-        instruction.accounts[2].is_signer = false;
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[ &ptc.payer ], 
-            ptc.last_blockhash
-        );
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        lottery_creation_fee: 5_000000, // 5 USDC
+        maximum_number_of_winners: 10,
+        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        maximum_time_for_lottery_account: 1000,
+        minimum_tickets_to_be_sold_in_lottery: 20,
+        max_lottery_description_bytes: 300,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+    
+    //////////////////////? add lottery account
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        lottery_creation_fee: 5_000000,
+        protocol_fee: 300_000000,
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("1"),
+        ..Lottery::default()
+    };
 
-        let error = ptc
-            .banks_client
-            .process_transaction(tx)
-            .await
-            .unwrap_err()
-            .unwrap();
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: lottery_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
 
-        assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::MissingRequiredSignature
-            )
-        );
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+    
+    let mut ptc = pt.start_with_context().await;
 
-        // 2. lottery authority mismatched
+    // failure - invalid lottery state
+    {
+        change_clock_sysvar(&ptc, 35);
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
 
-        let unkown_signer = Keypair::new();
-        let instruction = instruction_withdraw_succeed_lottery(
-            lottery_account_pda.0, 
-            config_account_pda.0, 
-            unkown_signer.pubkey(), 
-            lottery_ata_pda, 
-            funding_usdc_token_account_pubkey, 
-            config_account.usdc_mint_account, 
-            TOKEN_STANDARD_PROGRAM_ID
+        let user_account_authority = Keypair::new();
+        let user_account_pda = Pubkey::find_program_address(
+            &[
+                b"user_account",
+                user_account_authority.pubkey().to_bytes().as_slice(),
+                lottery_account_pda.0.to_bytes().as_slice()
+            ],
+            &lottery_program_id
         );
-
+    
+        let instruction = instruction_create_and_initialize_user_account(
+            user_account_pda.0,
+            user_account_authority.pubkey(),
+            ptc.payer.pubkey(), 
+            lottery_account_pda.0,
+            SYSTEM_PROGRAM_ID, 
+            config_account_pda.0
+        );
+    
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[ 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
                 &ptc.payer,
-                &unkown_signer
+                &user_account_authority
             ], 
             ptc.last_blockhash
         );
@@ -5862,37 +19035,46 @@ async fn test_withdraw_succeed_lottery() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidLotteryAccountAuthority as u32
+                    LotteryError::InvalidLotteryState as u32
                 )
             )
         );
     }
-    // failure - invalid lottery authority
+    // failure - invalid lottery state
 
-    // failure - invalid lottery USDC ata
+    // failure - invalid seeds 
     {
+        change_clock_sysvar(&ptc, 150);
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
 
-        let instruction = instruction_withdraw_succeed_lottery(
-            lottery_account_pda.0, 
-            config_account_pda.0, 
-            lottery_authority_account.pubkey(), 
-            lottery_authority_account.pubkey(), 
-            funding_usdc_token_account_pubkey, 
-            config_account.usdc_mint_account, 
-            TOKEN_STANDARD_PROGRAM_ID
+        let user_account_authority = Keypair::new();
+        let user_account_pda = Pubkey::find_program_address(
+            &[
+                b"user_accounT",
+                user_account_authority.pubkey().to_bytes().as_slice(),
+                lottery_account_pda.0.to_bytes().as_slice()
+            ],
+            &lottery_program_id
         );
-
+    
+        let instruction = instruction_create_and_initialize_user_account(
+            user_account_pda.0,
+            user_account_authority.pubkey(),
+            ptc.payer.pubkey(), 
+            lottery_account_pda.0,
+            SYSTEM_PROGRAM_ID, 
+            config_account_pda.0
+        );
+    
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
-            &[ 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
                 &ptc.payer,
-                &lottery_authority_account
+                &user_account_authority
             ], 
             ptc.last_blockhash
         );
@@ -5908,188 +19090,161 @@ async fn test_withdraw_succeed_lottery() {
             error,
             TransactionError::InstructionError(
                 0,
-                InstructionError::Custom(
-                    LotteryError::InvalidLotteryAssociatedUsdcTokenAccount as u32
-                )
+                InstructionError::InvalidSeeds
             )
         );
     }
-    // failure - invalid lottery USDC ata
-    
-    // success
+    // failure - invalid seeds
+
+    // failure - trying to create user_account twice
     {
+        change_clock_sysvar(&ptc, 150);
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
 
-        let instruction = instruction_withdraw_succeed_lottery(
-            lottery_account_pda.0, 
-            config_account_pda.0, 
-            lottery_authority_account.pubkey(), 
-            lottery_ata_pda, 
-            funding_usdc_token_account_pubkey, 
-            config_account.usdc_mint_account, 
-            TOKEN_STANDARD_PROGRAM_ID
+        let user_account_authority = Keypair::new();
+        let user_account_pda = Pubkey::find_program_address(
+            &[
+                b"user_account",
+                user_account_authority.pubkey().to_bytes().as_slice(),
+                lottery_account_pda.0.to_bytes().as_slice()
+            ],
+            &lottery_program_id
+        );
+
+        let instruction = instruction_create_and_initialize_user_account(
+            user_account_pda.0,
+            user_account_authority.pubkey(),
+            ptc.payer.pubkey(), 
+            lottery_account_pda.0,
+            SYSTEM_PROGRAM_ID, 
+            config_account_pda.0
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ 
+                instruction.clone(),
+                instruction
+            ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_authority_account
+                &user_account_authority
             ], 
             ptc.last_blockhash
         );
 
-        ptc
+        let error = ptc
             .banks_client
             .process_transaction(tx)
             .await
+            .unwrap_err()
             .unwrap();
 
-        let SolanaAccount { data: lottery_account_data, .. } = ptc
-            .banks_client
-            .get_account(lottery_account_pda.0)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
-            .banks_client
-            .get_account(funding_usdc_token_account_pubkey)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let SolanaAccount { data: lottery_usdc_ata_data, .. } = ptc
-            .banks_client
-            .get_account(lottery_ata_pda)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let Lottery { is_creator_withdrawed_when_lottery_was_successful, .. } = Lottery::deserialize(
-            &mut &lottery_account_data[..]
-        ).unwrap();
-
-        assert_eq!(
-            is_creator_withdrawed_when_lottery_was_successful,
-            true,
-            "invalid lottery's flag."
-        );
-
-        let TokenAccount { amount, .. } = TokenAccount::unpack(
-            &fund_receiver_usdc_token_account_data
-        ).unwrap();
-
-        assert_eq!(
-            amount,
-            900_000000,
-            "invalid fund receiver token account balance."
-        );
-
-        let TokenAccount { amount, .. } = TokenAccount::unpack(
-            &lottery_usdc_ata_data
-        ).unwrap();
-
         assert_eq!(
-            amount,
-            100_000000,
-            "invalid lottery ata balance."
-        );
-    }
-    // success
-
-    // failure - trying to withdraw funds before ending lottery and picking winners
-    {
-        let lottery_account_pda = Pubkey::find_program_address(
-            &[
-                b"lottery_account",
-                lottery_authority_account.pubkey().to_bytes().as_slice(),
-                get_lottery_literal_seed(&String::from("1")).as_slice()
-            ],
-            &lottery_program_id
-        );
-    
-        let lottery_account = Lottery {
-            discriminator: Lottery::get_discriminator(),
-            canonical_bump: lottery_account_pda.1,
-            starting_time: 100,
-            ending_time: 200,
-            lottery_description: String::from("1"),
-            winners_count: 5,
-            minimum_tickets_amount_required_to_be_sold: 5,
-            tickets_total_amount: 10,
-            protocol_fee: 95_000000,
-            lottery_creation_fee: 5_000000,
-            authority: lottery_authority_account.pubkey(),
-            is_ended_successfuly: false,
-            ..Lottery::default()
-        };
-    
-        let lottey_solana_account = SolanaAccount {
-            owner: lottery_program_id,
-            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-            data: lottery_account.try_to_vec().unwrap(),
-            ..SolanaAccount::default()
-        };
-    
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(lottey_solana_account)
+            error,
+            TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(
+                    LotteryError::AccountMustBeRaw as u32
+                )
+                // InstructionError::Custom(
+                //     solana_sdk::system_instruction::SystemError::AccountAlreadyInUse as u32
+                // )
+            )
         );
+    }
+    // failure - trying to create user_account twice
 
+    // success
+    {
+        change_clock_sysvar(&ptc, 150);
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
 
-        let instruction = instruction_withdraw_succeed_lottery(
-            lottery_account_pda.0, 
-            config_account_pda.0, 
-            lottery_authority_account.pubkey(), 
-            lottery_ata_pda, 
-            funding_usdc_token_account_pubkey, 
-            config_account.usdc_mint_account, 
-            TOKEN_STANDARD_PROGRAM_ID
+        let user_account_authority = Keypair::new();
+        let user_account_pda = Pubkey::find_program_address(
+            &[
+                b"user_account",
+                user_account_authority.pubkey().to_bytes().as_slice(),
+                lottery_account_pda.0.to_bytes().as_slice()
+            ],
+            &lottery_program_id
+        );
+
+        let instruction = instruction_create_and_initialize_user_account(
+            user_account_pda.0,
+            user_account_authority.pubkey(),
+            ptc.payer.pubkey(), 
+            lottery_account_pda.0,
+            SYSTEM_PROGRAM_ID, 
+            config_account_pda.0
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_authority_account
+                &user_account_authority
             ], 
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
+        let SolanaAccount { data: user_account_data, owner: user_account_owner, .. } = ptc
+            .banks_client
+            .get_account(user_account_pda.0)
+            .await.unwrap().unwrap();
+
         assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::WinnersNotSelected as u32
-                )
-            )
+            user_account_owner,
+            LOTTERY_PROGRAM_ID,
+            "invalid user_account's owner."
+        );
+
+        assert_eq!(
+            User::LEN,
+            user_account_data.len(),
+            "invalid user_account's data len."
+        );
+
+        let User { authority, lottery, created_at, .. } = User::deserialize(
+            &mut &user_account_data[..]
+        ).unwrap();
+
+        assert_eq!(
+            authority,
+            user_account_authority.pubkey(),
+            "invalid user_account's authority."
+        );
+
+        assert_eq!(
+            lottery,
+            lottery_account_pda.0,
+            "invalid user_account's lottery account."
+        );
+
+        assert_eq!(
+            created_at,
+            150,
+            "invalid created_at timestamp."
         );
     }
-    // failure - trying to withdraw funds before ending lottery and picking winners
+    // success
 }
 
 #[tokio::test]
-async fn test_withdraw_lottery_winners() {
+async fn test_buy_tickets() {
     let lottery_program_id = LOTTERY_PROGRAM_ID;
     let mut pt = setup_program_test(lottery_program_id);
 
@@ -6106,10 +19261,11 @@ async fn test_withdraw_lottery_winners() {
         canonical_bump: config_account_pda.1,
         lottery_creation_fee: 5_000000, // 5 USDC
         maximum_number_of_winners: 10,
-        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
+        usdc_mint_account: Pubkey::new_from_array([1; 32]),
         maximum_time_for_lottery_account: 1000,
         minimum_tickets_to_be_sold_in_lottery: 20,
-        max_lottery_description_bytes: 10,
+        max_lottery_description_bytes: 300,
+        lottery_tickets_fee: 2.5,
         ..Config::default()
     };
     let config_account_data = config_account.try_to_vec().unwrap();
@@ -6127,17 +19283,6 @@ async fn test_withdraw_lottery_winners() {
     );
     //////////////////////? add config account
     
-    //////////////////////? add user account authority
-    let user_account_auth = Keypair::new();
-    pt.add_account(
-        user_account_auth.pubkey(),
-        SolanaAccount {
-            lamports: sol_to_lamports(1.0),
-            ..SolanaAccount::default()
-        }
-    );
-    //////////////////////? add user account authority
-    
     //////////////////////? add lottery account
     let lottery_account_pda = Pubkey::find_program_address(
         &[
@@ -6148,38 +19293,18 @@ async fn test_withdraw_lottery_winners() {
         &lottery_program_id
     );
 
-    let user_account_pda = Pubkey::find_program_address(
-        &[
-            b"user_account",
-            user_account_auth.pubkey().to_bytes().as_slice(),
-            &lottery_account_pda.0.to_bytes().as_slice()
-        ],
-        &lottery_program_id
-    );
-
-    let lottery_account = Lottery {
+    let mut lottery_account = Lottery {
         discriminator: Lottery::get_discriminator(),
         canonical_bump: lottery_account_pda.1,
         starting_time: 100,
         ending_time: 200,
         lottery_description: String::from("1"),
-        winners_count: 5,
-        minimum_tickets_amount_required_to_be_sold: 5,
-        tickets_total_amount: 10,
-        protocol_fee: 95_000000,
-        lottery_creation_fee: 5_000000,
-        is_ended_successfuly: true,
-        arbitrary_mint_account_address: Pubkey::new_from_array([5; 32]),
-        winners: vec![
-            (user_account_pda.0, false),
-            (Pubkey::new_from_array([56; 32]), false),
-            (user_account_pda.0, false),
-            (Pubkey::new_from_array([58; 32]), false),
-            (user_account_pda.0, false)
-        ],
-        fund_amount: 1000_000000,
+        ticket_price: 10_000000,
+        maximum_number_of_tickets_per_user: Some(100),
+        maximum_total_tickets: Some(50),
         ..Lottery::default()
     };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
 
     let lottey_solana_account = SolanaAccount {
         owner: lottery_program_id,
@@ -6195,6 +19320,24 @@ async fn test_withdraw_lottery_winners() {
     //////////////////////? add lottery account
     
     //////////////////////? add user account
+    let user_account_auth = Keypair::new();
+    pt.add_account(
+        user_account_auth.pubkey(),
+        SolanaAccount {
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            user_account_auth.pubkey().to_bytes().as_slice(),
+            &lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
     let user_account = User {
         discriminator: User::get_discriminator(),
         canonical_bump: user_account_pda.1,
@@ -6216,113 +19359,145 @@ async fn test_withdraw_lottery_winners() {
     );
     //////////////////////? add user account
     
-    //////////////////////? add unkown user account
-    let unknown_user_account_auth = Keypair::new();
-    let unknown_user_account_pda = Pubkey::find_program_address(
-        &[
-            b"user_account",
-            unknown_user_account_auth.pubkey().to_bytes().as_slice(),
-            &lottery_account_pda.0.to_bytes().as_slice()
-        ],
-        &lottery_program_id
-    );
-
-    let unknown_user_account = User {
-        discriminator: User::get_discriminator(),
-        canonical_bump: unknown_user_account_pda.1,
-        authority: unknown_user_account_auth.pubkey(),
-        lottery: lottery_account_pda.0,
-        ..User::default()
+    //////////////////////? add mint account
+    let mint_account_pubkey = Pubkey::new_from_array([1; 32]);
+    let mint_account = MintAccount {
+        supply: 100_000000,
+        decimals: 6,
+        is_initialized: true,
+        ..MintAccount::default()
     };
 
-    let unknown_user_solana_account = SolanaAccount {
-        owner: lottery_program_id,
+    let mut mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        mint_account,
+        mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    let mint_solana_account = SolanaAccount {
+        owner: spl_token::ID,
+        data: mint_account_data.to_vec(),
         lamports: sol_to_lamports(1.0),
-        data: unknown_user_account.try_to_vec().unwrap(),
         ..SolanaAccount::default()
     };
-    
+
     pt.add_account(
-        unknown_user_account_pda.0,
-        unknown_user_solana_account
+        mint_account_pubkey,
+        mint_solana_account
     );
-    //////////////////////? add unkown user account
-
-    //////////////////////? add Arbitrary mint account
-    let arbitrary_mint_account = MintAccount {
-        supply: 1000_000000,
-        decimals: 6,
-        is_initialized: true,
-        ..MintAccount::default()
+    //////////////////////? add mint account
+    
+    //////////////////////? add lottery ata
+    let lottery_ata_pda = get_associated_token_address(
+        &lottery_account_pda.0,
+        &mint_account_pubkey
+    );
+    let lottery_ata = TokenAccount {
+        state: TokenAccountState::Initialized,
+        mint: mint_account_pubkey,
+        ..TokenAccount::default()
     };
 
-    let mut arbitrary_mint_account_data: [u8; MintAccount::LEN] = [0; MintAccount::LEN];
-    MintAccount::pack(
-        arbitrary_mint_account,
-        arbitrary_mint_account_data.as_mut_slice()
+    let mut lottery_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        lottery_ata,
+        lottery_ata_data.as_mut_slice()
     ).unwrap();
 
-    let arbitrary_mint_solana_account = SolanaAccount {
-        lamports: sol_to_lamports(0.01),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        data: arbitrary_mint_account_data.to_vec(),
+    let lottery_ata_solana_account = SolanaAccount {
+        owner: spl_token::ID,
+        data: lottery_ata_data.to_vec(),
+        lamports: sol_to_lamports(1.0),
         ..SolanaAccount::default()
     };
 
     pt.add_account(
-        lottery_account.arbitrary_mint_account_address,
-        arbitrary_mint_solana_account
+        lottery_ata_pda,
+        lottery_ata_solana_account
     );
-    //////////////////////? add Arbitrary mint account
+    //////////////////////? add lottery ata
     
-    //////////////////////? add fund receiver Arbitrary token account
-    let funding_arbitrary_token_account_pubkey = Pubkey::new_from_array([1; 32]);
-    let funding_arbitrary_token_account = TokenAccount {
-        mint: lottery_account.arbitrary_mint_account_address,
-        amount: 0_000000,
+    //////////////////////? add funding token account
+    let funding_token_account_pubeky = Pubkey::new_from_array([2; 32]);
+    let funding_token_account = TokenAccount {
         state: TokenAccountState::Initialized,
+        mint: mint_account_pubkey,
+        owner: user_account_auth.pubkey(),
+        amount: 100_000000,
         ..TokenAccount::default()
     };
 
-    let mut funding_arbitrary_token_account_data: [u8; TokenAccount::LEN] = [0; TokenAccount::LEN];
+    let mut funding_token_account_data = [0u8; TokenAccount::LEN];
     TokenAccount::pack(
-        funding_arbitrary_token_account,
-        funding_arbitrary_token_account_data.as_mut_slice()
+        funding_token_account,
+        funding_token_account_data.as_mut_slice()
     ).unwrap();
 
-    let funding_arbitrary_token_solana_account = SolanaAccount {
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        data: funding_arbitrary_token_account_data.to_vec(),
-        lamports: sol_to_lamports(0.01),
+    let funding_token_solana_account = SolanaAccount {
+        owner: spl_token::ID,
+        data: funding_token_account_data.to_vec(),
+        lamports: sol_to_lamports(1.0),
         ..SolanaAccount::default()
     };
 
     pt.add_account(
-        funding_arbitrary_token_account_pubkey,
-        funding_arbitrary_token_solana_account
+        funding_token_account_pubeky,
+        funding_token_solana_account
     );
-    //////////////////////? add fund receiver Arbitrary token account
+    //////////////////////? add funding token account
     
-    //////////////////////? add lottery's Arbitrary ata
-    let lottery_ata_pda = get_associated_token_address(
-        &lottery_account_pda.0,
-        &lottery_account.arbitrary_mint_account_address
+    //////////////////////? add unknown lottery account
+    let unknown_lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("2")).as_slice()
+        ],
+        &lottery_program_id
     );
-    let lottery_ata = TokenAccount {
+    
+    let mut unkown_lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: unknown_lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("2"),
+        ticket_price: 10_000000,
+        ..Lottery::default()
+    };
+    unkown_lottery_account.initial_bytes = unkown_lottery_account.try_to_vec().unwrap().len() as u64;
+    
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: unkown_lottery_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+    
+    pt.add_account(
+        unknown_lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add unknown lottery account
+
+    //////////////////////? add unkown lottery ata
+    let unkown_lottery_ata_pda = get_associated_token_address(
+        &unknown_lottery_account_pda.0,
+        &mint_account_pubkey
+    );
+    let unkown_lottery_ata = TokenAccount {
         state: TokenAccountState::Initialized,
-        mint: lottery_account.arbitrary_mint_account_address,
-        amount: 1000_000000,
-        owner: lottery_account_pda.0,
+        mint: mint_account_pubkey,
         ..TokenAccount::default()
     };
 
-    let mut lottery_ata_data = [0u8; TokenAccount::LEN];
+    let mut unkown_lottery_ata_data = [0u8; TokenAccount::LEN];
     TokenAccount::pack(
-        lottery_ata,
-        lottery_ata_data.as_mut_slice()
+        unkown_lottery_ata,
+        unkown_lottery_ata_data.as_mut_slice()
     ).unwrap();
 
-    let lottery_ata_solana_account = SolanaAccount {
+    let unkown_lottery_ata_solana_account = SolanaAccount {
         owner: spl_token::ID,
         data: lottery_ata_data.to_vec(),
         lamports: sol_to_lamports(1.0),
@@ -6330,82 +19505,165 @@ async fn test_withdraw_lottery_winners() {
     };
 
     pt.add_account(
-        lottery_ata_pda,
-        lottery_ata_solana_account
+        unkown_lottery_ata_pda,
+        unkown_lottery_ata_solana_account
     );
-    //////////////////////? add lottery's Arbitrary ata
+    //////////////////////? add unkown lottery ata
     
     let mut ptc = pt.start_with_context().await;
 
-    // failure - invalid arbitrary mint account
+    // failure - invalid user's tickets amount
     {
+        change_clock_sysvar(
+            &ptc, 
+            150
+        );
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
 
-        let instruction = instruction_withdraw_lottery_winners(
+        let instruction_1 = instruction_buy_ticket(
+            config_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
             lottery_account_pda.0, 
+            lottery_ata_pda, 
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            105,
+            10_000000, // 10 USDC
+            false
+        );
+
+        let instruction_2 = instruction_buy_ticket(
+            config_account_pda.0, 
             user_account_pda.0, 
             user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
+            lottery_account_pda.0, 
             lottery_ata_pda, 
-            funding_arbitrary_token_account_pubkey, 
-            Pubkey::new_unique(), 
-            TOKEN_STANDARD_PROGRAM_ID, 
-            config_account_pda.0
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            5,
+            10_000000,
+            false
         );
 
-        let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
+        let instruction_3 = instruction_buy_ticket(
+            config_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
+            lottery_account_pda.0, 
+            lottery_ata_pda, 
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            98,
+            10_000000,
+            false
+        );
+
+        let tx_1 = Transaction::new_signed_with_payer(
+            &[
+                instruction_1
+            ], 
             Some(&ptc.payer.pubkey()), 
             &[
                 &ptc.payer,
-                &user_account_auth
+               &user_account_auth
             ], 
             ptc.last_blockhash
         );
 
-        let error = ptc
+        let tx_2 = Transaction::new_signed_with_payer(
+            &[
+                instruction_2,
+                instruction_3
+            ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+               &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
+
+        let error_tx1 = ptc
             .banks_client
-            .process_transaction(tx)
+            .process_transaction(tx_1)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        let error_tx2 = ptc
+            .banks_client
+            .process_transaction(tx_2)
             .await
             .unwrap_err()
             .unwrap();
 
         assert_eq!(
-            error,
+            error_tx1,
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidArbitraryMintAccount as u32
+                    LotteryError::MaxTicketsAmountViolated as u32
+                )
+            )
+        );
+
+        assert_eq!(
+            error_tx2,
+            TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(
+                    LotteryError::MaxTicketsAmountViolated as u32
                 )
             )
         );
     }
-    // failure - invalid arbitrary mint account
+    // failure - invalid user's tickets amount
 
-    // failure - invalid lottery arbitrary ata
+    // failure - invalid tickets amount
     {
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
+        change_clock_sysvar(
+            &ptc, 
+            150
+        );
 
-        let instruction = instruction_withdraw_lottery_winners(
-            lottery_account_pda.0, 
+        let instruction = instruction_buy_ticket(
+            config_account_pda.0, 
             user_account_pda.0, 
             user_account_auth.pubkey(), 
-            Pubkey::new_unique(), 
-            funding_arbitrary_token_account_pubkey, 
-            lottery_account.arbitrary_mint_account_address, 
-            TOKEN_STANDARD_PROGRAM_ID, 
-            config_account_pda.0
+            user_account_auth.pubkey(), 
+            lottery_account_pda.0, 
+            lottery_ata_pda, 
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            0,
+            10_000000,
+            false
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
+            &[
+                instruction.clone(),
+                instruction
+            ], 
             Some(&ptc.payer.pubkey()), 
             &[
                 &ptc.payer,
@@ -6426,38 +19684,42 @@ async fn test_withdraw_lottery_winners() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidLotteryArbitraryAssociatedTokenAccount as u32
+                    LotteryError::InvalidTicketAmount as u32
                 )
             )
         );
     }
-    // failure - invalid lottery arbitrary ata
+    // failure - invalid tickets amount
 
-    // failure - winner not found
+    // failure - maximum total tickets exceeded
     {
-        // 1. winner tries to double call the instruction
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
+        change_clock_sysvar(
+            &ptc, 
+            150
+        );
 
-        let instruction = instruction_withdraw_lottery_winners(
-            lottery_account_pda.0, 
+        let instruction = instruction_buy_ticket(
+            config_account_pda.0, 
             user_account_pda.0, 
             user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
+            lottery_account_pda.0, 
             lottery_ata_pda, 
-            funding_arbitrary_token_account_pubkey, 
-            lottery_account.arbitrary_mint_account_address, 
-            TOKEN_STANDARD_PROGRAM_ID, 
-            config_account_pda.0
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            60,
+            10_000000,
+            false
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[
-                instruction.clone(),
-                instruction
-            ], 
+            &[ instruction ], 
             Some(&ptc.payer.pubkey()), 
             &[
                 &ptc.payer,
@@ -6476,37 +19738,52 @@ async fn test_withdraw_lottery_winners() {
         assert_eq!(
             error,
             TransactionError::InstructionError(
-                1,
+                0,
                 InstructionError::Custom(
-                    LotteryError::WinnerNotFound as u32
+                    LotteryError::MaximumTotalTicketsExceeded as u32
                 )
             )
         );
+    }
+    // failure - maximum total tickets exceeded
 
-        // 2. unknown user (which is not one of the winners) calls the instruction
+    // failure - lottery is in invalid state
+    {
+        // 1. lottery is not started yet
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
+        change_clock_sysvar(
+            &ptc, 
+            50
+        );
 
-        let instruction = instruction_withdraw_lottery_winners(
+        let instruction = instruction_buy_ticket(
+            config_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
             lottery_account_pda.0, 
-            unknown_user_account_pda.0, 
-            unknown_user_account_auth.pubkey(), 
             lottery_ata_pda, 
-            funding_arbitrary_token_account_pubkey, 
-            lottery_account.arbitrary_mint_account_address, 
-            TOKEN_STANDARD_PROGRAM_ID, 
-            config_account_pda.0
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            5,
+            10_000000,
+            false
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
+            &[
+                instruction.clone(),
+                instruction
+            ], 
             Some(&ptc.payer.pubkey()), 
             &[
                 &ptc.payer,
-                &unknown_user_account_auth
+                &user_account_auth
             ], 
             ptc.last_blockhash
         );
@@ -6523,34 +19800,42 @@ async fn test_withdraw_lottery_winners() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::WinnerNotFound as u32
+                    LotteryError::InvalidLotteryState as u32
                 )
             )
         );
-    }
-    // failure - winner not found
 
-    // success
-    {
+        // 2. lottery is ended
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
+        change_clock_sysvar(
+            &ptc, 
+            350
+        );
 
-        let instruction = instruction_withdraw_lottery_winners(
-            lottery_account_pda.0, 
+        let instruction = instruction_buy_ticket(
+            config_account_pda.0, 
             user_account_pda.0, 
             user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
+            lottery_account_pda.0, 
             lottery_ata_pda, 
-            funding_arbitrary_token_account_pubkey, 
-            lottery_account.arbitrary_mint_account_address, 
-            TOKEN_STANDARD_PROGRAM_ID, 
-            config_account_pda.0
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            5,
+            10_000000,
+            false
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
+            &[
+                instruction.clone(),
+                instruction
+            ], 
             Some(&ptc.payer.pubkey()), 
             &[
                 &ptc.payer,
@@ -6559,371 +19844,170 @@ async fn test_withdraw_lottery_winners() {
             ptc.last_blockhash
         );
 
-        ptc
+        let error = ptc
             .banks_client
             .process_transaction(tx)
             .await
+            .unwrap_err()
             .unwrap();
 
-        let SolanaAccount { data: lottery_data_account, .. } = ptc
-            .banks_client
-            .get_account(lottery_account_pda.0)
-            .await
-            .unwrap()
-            .unwrap();
-    
-        let SolanaAccount { data: fund_receiver_token_account_data, .. } = ptc
-            .banks_client
-            .get_account(funding_arbitrary_token_account_pubkey)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let SolanaAccount { data: lottery_ata_data, .. } = ptc
-            .banks_client
-            .get_account(lottery_ata_pda)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let Lottery { winners, .. } = Lottery::deserialize(
-            &mut &lottery_data_account[..]
-        ).unwrap();
-
-        assert_eq!(
-            winners[0].1,
-            true,
-            "invalid winners[0].1"
-        );
-        assert_eq!(
-            winners[2].1,
-            true,
-            "invalid winners[2].1"
-        );
-        assert_eq!(
-            winners[4].1,
-            true,
-            "invalid winners[4].1"
-        );
-
-        let TokenAccount { amount, .. } = TokenAccount::unpack(
-            &fund_receiver_token_account_data
-        ).unwrap();
-
-        assert_eq!(
-            amount,
-            600_000000,
-            "invalid fund receiver token account balance."
-        );
-
-        let TokenAccount { amount, .. } = TokenAccount::unpack(
-            &lottery_ata_data
-        ).unwrap();
-
         assert_eq!(
-            amount,
-            400_000000,
-            "invalid lottery ata balance."
-        );
-    }
-    // success
-}
-
-#[tokio::test]
-async fn test_withdraw_failed_lottery() {
-    let lottery_program_id = LOTTERY_PROGRAM_ID;
-    let mut pt = setup_program_test(lottery_program_id);
-
-    //////////////////////? add config account
-    let config_account_pda = Pubkey::find_program_address(
-        &[
-            b"solottery_program_config_account"
-        ],
-        &lottery_program_id
-    );
-
-    let config_account = Config {
-        discriminator: Config::get_discriminator(),
-        canonical_bump: config_account_pda.1,
-        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
-        ..Config::default()
-    };
-    let config_account_data = config_account.try_to_vec().unwrap();
-
-    let config_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        data: config_account_data,
-        lamports: sol_to_lamports(0.0009),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        config_account_pda.0,
-        config_solana_account
-    );
-    //////////////////////? add config account
-    
-    //////////////////////? add lottery account
-    let lottery_account_auth = Keypair::new();
-    let lottery_account_pda = Pubkey::find_program_address(
-        &[
-            b"lottery_account",
-            lottery_account_auth.pubkey().to_bytes().as_slice(),
-            get_lottery_literal_seed(&String::from("1")).as_slice()
-        ],
-        &lottery_program_id
-    );
-    
-    let lottery_account = Lottery {
-        discriminator: Lottery::get_discriminator(),
-        canonical_bump: lottery_account_pda.1,
-        tickets_total_amount: 45,
-        minimum_tickets_amount_required_to_be_sold: 50,
-        lottery_description: String::from("1"),
-        authority: lottery_account_auth.pubkey(),
-        lottery_creation_fee: 10_000000, // 10 USDCs
-        starting_time: 200,
-        ending_time: 350,
-        arbitrary_mint_account_address: Pubkey::new_from_array([1; 32]),
-        ..Lottery::default()
-    };
-
-    let lottey_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-        data: lottery_account.try_to_vec().unwrap(),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        lottery_account_pda.0,
-        lottey_solana_account
-    );
-    //////////////////////? add lottery account
-    
-    //////////////////////? add UDSC mint account
-    let usdc_mint_account = MintAccount {
-        supply: 100_000000,
-        decimals: 6,
-        is_initialized: true,
-        ..MintAccount::default()
-    };
-
-    let mut usdc_mint_account_data = [0u8; MintAccount::LEN];
-    MintAccount::pack(
-        usdc_mint_account,
-        usdc_mint_account_data.as_mut_slice()
-    ).unwrap();
-
-    let usdc_mint_solana_account = SolanaAccount {
-        data: usdc_mint_account_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        config_account.usdc_mint_account,
-        usdc_mint_solana_account
-    );
-    //////////////////////? add USDC mint account
-    
-    //////////////////////? add Arbitrary mint account
-    let arbitrary_mint_account_pubkey = Pubkey::new_from_array([1; 32]);
-    let arbitrary_mint_account = MintAccount {
-        supply: 500_000,
-        decimals: 3,
-        is_initialized: true,
-        ..MintAccount::default()
-    };
-
-    let mut arbitrary_mint_account_data = [0u8; MintAccount::LEN];
-    MintAccount::pack(
-        arbitrary_mint_account,
-        arbitrary_mint_account_data.as_mut_slice()
-    ).unwrap();
-
-    let arbitrary_mint_solana_account = SolanaAccount {
-        data: arbitrary_mint_account_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        arbitrary_mint_account_pubkey,
-        arbitrary_mint_solana_account
-    );
-    //////////////////////? add Arbitrary mint account
-    
-    //////////////////////? add lottery's USDC ata
-    let lottery_usdc_ata_pubkey = get_associated_token_address(
-        &lottery_account_pda.0, 
-        &config_account.usdc_mint_account
-    );
-    let lottery_usdc_ata = TokenAccount {
-        amount: 55_000000,
-        owner: lottery_account_pda.0,
-        state: TokenAccountState::Initialized,
-        mint: config_account.usdc_mint_account,
-        ..TokenAccount::default()
-    };
-
-    let mut lottery_usdc_ata_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        lottery_usdc_ata,
-        lottery_usdc_ata_data.as_mut_slice()
-    ).unwrap();
-
-    let lottery_usdc_ata_solana_account = SolanaAccount {
-        data: lottery_usdc_ata_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryState as u32
+                )
+            )
+        );
+    }
+    // failure - lottery is in invalid state
 
-    pt.add_account(
-        lottery_usdc_ata_pubkey,
-        lottery_usdc_ata_solana_account
-    );
-    //////////////////////? add lottery's USDC ata
-    
-    //////////////////////? add lottery's Arbitrary ata
-    let lottery_arbitrary_ata_pubkey = get_associated_token_address(
-        &lottery_account_pda.0, 
-        &arbitrary_mint_account_pubkey
-    );
-    let lottery_arbitrary_ata = TokenAccount {
-        amount: 350_000,
-        owner: lottery_account_pda.0,
-        state: TokenAccountState::Initialized,
-        mint: lottery_account.arbitrary_mint_account_address,
-        ..TokenAccount::default()
-    };
+    // failure - invalid user account
+    {
+        // 1. invalid user_account's authority
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc, 
+            150
+        );
 
-    let mut lottery_arbitrary_ata_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        lottery_arbitrary_ata,
-        lottery_arbitrary_ata_data.as_mut_slice()
-    ).unwrap();
+        let unknown_account = Keypair::new();
+        let instruction = instruction_buy_ticket(
+            config_account_pda.0, 
+            user_account_pda.0, 
+            unknown_account.pubkey(), 
+            unknown_account.pubkey(), 
+            lottery_account_pda.0, 
+            lottery_ata_pda, 
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            5,
+            10_000000,
+            false
+        );
 
-    let lottery_arbitrary_ata_solana_account = SolanaAccount {
-        data: lottery_arbitrary_ata_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: 2039280,
-        ..SolanaAccount::default()
-    };
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction.clone(),
+                instruction
+            ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &unknown_account
+            ], 
+            ptc.last_blockhash
+        );
 
-    pt.add_account(
-        lottery_arbitrary_ata_pubkey,
-        lottery_arbitrary_ata_solana_account
-    );
-    //////////////////////? add lottery's Arbitrary ata
-    
-    //////////////////////? add fund receiver, USDC token account
-    let fund_receiver_usdc_token_account_pubkey = Pubkey::new_from_array([2; 32]);
-    let fund_receiver_usdc_token_account = TokenAccount {
-        amount: 0_000000,
-        owner: Pubkey::new_unique(),
-        state: TokenAccountState::Initialized,
-        mint: config_account.usdc_mint_account,
-        ..TokenAccount::default()
-    };
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
 
-    let mut fund_receiver_usdc_token_account_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        fund_receiver_usdc_token_account,
-        fund_receiver_usdc_token_account_data.as_mut_slice()
-    ).unwrap();
+        if !(
+            error == TransactionError::InstructionError(0, InstructionError::InvalidSeeds) ||
+            error == TransactionError::InstructionError(0, InstructionError::Custom(LotteryError::FailedToFindProgramAddress as u32))
+        ) {
+            panic!("1. invalidSeeds Or failedToFindProgramAddress");
+        };
 
-    let fund_receiver_usdc_token_solana_account = SolanaAccount {
-        data: fund_receiver_usdc_token_account_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
+        // 2. invalid lottery_account
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+        change_clock_sysvar(
+            &ptc, 
+            150
+        );
 
-    pt.add_account(
-        fund_receiver_usdc_token_account_pubkey,
-        fund_receiver_usdc_token_solana_account
-    );
-    //////////////////////? add fund receiver, USDC token account
-    
-    //////////////////////? add fund receiver, Arbitrary token account
-    let fund_receiver_arbitrary_token_account_pubkey = Pubkey::new_from_array([3; 32]);
-    let fund_receiver_arbitrary_token_account = TokenAccount {
-        amount: 0_000,
-        owner: Pubkey::new_unique(),
-        state: TokenAccountState::Initialized,
-        mint: lottery_account.arbitrary_mint_account_address,
-        ..TokenAccount::default()
-    };
+        let instruction = instruction_buy_ticket(
+            config_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
+            unknown_lottery_account_pda.0, 
+            unkown_lottery_ata_pda, 
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            5,
+            10_000000,
+            false
+        );
 
-    let mut fund_receiver_arbitrary_token_account_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        fund_receiver_arbitrary_token_account,
-        fund_receiver_arbitrary_token_account_data.as_mut_slice()
-    ).unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                instruction.clone(),
+                instruction
+            ], 
+            Some(&ptc.payer.pubkey()), 
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ], 
+            ptc.last_blockhash
+        );
 
-    let fund_receiver_arbitrary_token_solana_account = SolanaAccount {
-        data: fund_receiver_arbitrary_token_account_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
 
-    pt.add_account(
-        fund_receiver_arbitrary_token_account_pubkey,
-        fund_receiver_arbitrary_token_solana_account
-    );
-    //////////////////////? add fund receiver, Arbitrary token account
-    
-    //////////////////////? add fund receiver, rent-exempt's lamports account
-    let fund_receiver_rent_exempt_lamports = Pubkey::new_from_array([4; 32]);
-    pt.add_account(
-        fund_receiver_rent_exempt_lamports,
-        SolanaAccount {
-            owner: SYSTEM_PROGRAM_ID,
-            lamports: sol_to_lamports(0.1),
-            ..SolanaAccount::default()
-        }
-    );
-    //////////////////////? add fund receiver, rent-exempt's lamports account
-    
-    let mut ptc = pt.start_with_context().await;
+        if !(
+            error == TransactionError::InstructionError(0, InstructionError::InvalidSeeds) ||
+            error == TransactionError::InstructionError(0, InstructionError::Custom(LotteryError::FailedToFindProgramAddress as u32))
+        ) {
+            panic!("2. invalidSeeds Or failedToFindProgramAddress");
+        };
+    }
+    // failure - invalid user account
 
-    // failure - funds already withdrawed
+    // failure - expected ticket price is violated
     {
+        change_clock_sysvar(
+            &ptc, 
+            150
+        );
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 555);
 
-        let instruction = instruction_withdraw_failed_lottery(
+        let instruction = instruction_buy_ticket(
             config_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
             lottery_account_pda.0, 
-            lottery_account_auth.pubkey(), 
-            config_account.usdc_mint_account, 
-            lottery_account.arbitrary_mint_account_address, 
-            lottery_usdc_ata_pubkey, 
-            lottery_arbitrary_ata_pubkey, 
-            fund_receiver_usdc_token_account_pubkey, 
-            fund_receiver_arbitrary_token_account_pubkey, 
-            fund_receiver_rent_exempt_lamports, 
-            TOKEN_STANDARD_PROGRAM_ID
+            lottery_ata_pda, 
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            5,
+            5_000000,
+            false
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ 
-                instruction.clone(),
-                instruction
-            ],
-            Some(&ptc.payer.pubkey()),
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
             &[
                 &ptc.payer,
-                &lottery_account_auth
-            ],
+               &user_account_auth
+            ], 
             ptc.last_blockhash
         );
 
@@ -6937,114 +20021,328 @@ async fn test_withdraw_failed_lottery() {
         assert_eq!(
             error,
             TransactionError::InstructionError(
-                1,
+                0,
                 InstructionError::Custom(
-                    LotteryError::FundsAlreadyWithdrawed as u32
+                    LotteryError::ExpectedTicketPriceViolated as u32
                 )
             )
         );
     }
-    // failure - funds already withdrawed
-
-    // failure - invalid lottery account auth
+    // failure - expected ticket price is violated
+    
+    // success
     {
+        change_clock_sysvar(
+            &ptc, 
+            150
+        );
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 555);
 
-        let unkown_account = Keypair::new();
-        let instruction = instruction_withdraw_failed_lottery(
+        let instruction = instruction_buy_ticket(
             config_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
             lottery_account_pda.0, 
-            unkown_account.pubkey(), 
-            config_account.usdc_mint_account, 
-            lottery_account.arbitrary_mint_account_address, 
-            lottery_usdc_ata_pubkey, 
-            lottery_arbitrary_ata_pubkey, 
-            fund_receiver_usdc_token_account_pubkey, 
-            fund_receiver_arbitrary_token_account_pubkey, 
-            fund_receiver_rent_exempt_lamports, 
-            TOKEN_STANDARD_PROGRAM_ID
+            lottery_ata_pda, 
+            funding_token_account_pubeky, 
+            mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            spl_token::ID, 
+            5,
+            10_000000,
+            false
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ],
-            Some(&ptc.payer.pubkey()),
+            &[
+                instruction.clone(),
+                instruction
+            ], 
+            Some(&ptc.payer.pubkey()), 
             &[
                 &ptc.payer,
-                &unkown_account
-            ],
+               &user_account_auth
+            ], 
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { data: user_account_data, .. } = ptc
+            .banks_client
+            .get_account(user_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { data: funding_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(funding_token_account_pubeky)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let SolanaAccount { data: lottery_ata_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_ata_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        let user_account = User::deserialize(
+            &mut &user_account_data[..]
+        ).unwrap();
+
+        let funding_token_account = TokenAccount::unpack(
+            &funding_token_account_data
+        ).unwrap();
+
+        let lottery_ata = TokenAccount::unpack(
+            &lottery_ata_data
+        ).unwrap();
+
+        // check lottery account
         assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::InvalidLotteryAccountAuthority as u32
-                )
-            )
+            lottery_account.tickets_total_amount,
+            10,
+            "invalid tickets total amounts."
+        );
+
+        assert_eq!(
+            lottery_account.protocol_fee,
+            2_500000,
+            "invalid protocol fee amounts."
+        );
+
+        assert_eq!(
+            lottery_account.ticket_ranges_total_amount,
+            2,
+            "invalid ticket ranges total amounts."
+        );
+
+        // a single ticket-range (user's pda-account pubkey, cumulative end index) per purchase
+        assert_eq!(
+            lottery_account_data.len(),
+            lottery_account.initial_bytes as usize + (2 * (size_of::<Pubkey>() + size_of::<u32>())),
+            "invalid lottery_account's data length."
+        );
+
+        let mut lottery_account_d = lottery_account_data.clone();
+        let mut lottery_account_balance = 1_000000000;
+        let lottery_account_info = &solana_sdk::account_info::AccountInfo {
+            lamports: Rc::new(RefCell::new(&mut lottery_account_balance)),
+            owner: &lottery_program_id,
+            key: &Pubkey::new_unique(),
+            data: Rc::new(RefCell::new(&mut lottery_account_d)),
+            rent_epoch: solana_sdk::clock::Epoch::default(),
+            is_signer: false,
+            is_writable: false,
+            executable: false
+        };
+        for index in 0..10 {
+            assert_eq!(
+                Lottery::get_ticket(
+                    lottery_account_info,
+                    index
+                ).unwrap(),
+                user_account_pda.0,
+                "invalid user_account's ticket."
+            );
+        };
+
+        let total_tickets_rent_exempt = solana_sdk::rent::DEFAULT_EXEMPTION_THRESHOLD as u64 * 
+            ( solana_sdk::rent::DEFAULT_LAMPORTS_PER_BYTE_YEAR * ( 2 * (size_of::<Pubkey>() + size_of::<u32>()) as u64 ) );
+
+        let lottery_account_balance = sol_to_lamports(1.0) + total_tickets_rent_exempt;
+
+        assert_eq!(
+            lottery_account_balance,
+            ptc.banks_client.get_balance(lottery_account_pda.0).await.unwrap(),
+            "invalid lottery_account's lamport balance."
+        );
+        // check lottery account
+
+        // check user account
+        assert_eq!(
+            user_account.total_rent_exempt_paied,
+            total_tickets_rent_exempt,
+            "invalid user_account's rent_exempt paied."
+        );
+
+        assert_eq!(
+            user_account.total_tickets_value,
+            10 * 10_000000,
+            "invalid user_account's total_tickets_value."
         );
-    }
-    // failure - invalid lottery account auth
 
+        assert_eq!(
+            user_account.total_tickets_acquired,
+            10,
+            "invalid user_account's total_tickets_acquired."
+        );
+
+        assert_eq!(
+            user_account.total_ticket_ranges_acquired,
+            2,
+            "invalid user_account's total_ticket_ranges_acquired."
+        );
+        // check user account
+
+        // check funding token account balance
+        assert_eq!(
+            funding_token_account.amount,
+            0_000000,
+            "invalid funding token account balance."
+        );
+        // check funding token account balance
+
+        // check lottery's ata balance
+        assert_eq!(
+            lottery_ata.amount,
+            100_000000,
+            "invalid lottery's ata token balance."
+        );
+        // check lottery's ata balance
+    }
     // success
+
+    // success - token-2022 usdc mint with a 1% transfer fee, the buyer pays the fee on top of the tickets price
     {
+        change_clock_sysvar(
+            &ptc, 
+            150
+        );
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 555);
 
-        // let instruction_1 = spl_associated_token_account::instruction::create_associated_token_account_idempotent(
-        //     &ptc.payer.pubkey(), 
-        //     &fund_receiver_usdc_token_account_pubkey, 
-        //     &config_account.usdc_mint_account, 
-        //     &TOKEN_STANDARD_PROGRAM_ID
-        // );
+        let token_2022_usdc_mint_account_pubkey = Pubkey::new_unique();
+        ptc.set_account(
+            &token_2022_usdc_mint_account_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: pack_token_2022_mint_account(
+                        Token2022MintAccount {
+                            supply: 100_000000,
+                            decimals: 6,
+                            is_initialized: true,
+                            ..Token2022MintAccount::default()
+                        },
+                        &[ ExtensionType::TransferFeeConfig ],
+                        100
+                    ),
+                    owner: TOKEN_2022_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
 
-        // let instruction_2 = spl_associated_token_account::instruction::create_associated_token_account_idempotent(
-        //     &ptc.payer.pubkey(), 
-        //     &fund_receiver_arbitrary_token_account_pubkey, 
-        //     &lottery_account.arbitrary_mint_account_address, 
-        //     &TOKEN_STANDARD_PROGRAM_ID
-        // );
+        ptc.set_account(
+            &config_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    data: Config {
+                        usdc_mint_account: token_2022_usdc_mint_account_pubkey,
+                        ..config_account.clone()
+                    }.try_to_vec().unwrap(),
+                    lamports: sol_to_lamports(0.0009),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
 
-        let instruction = instruction_withdraw_failed_lottery(
+        let lottery_token_2022_ata_pubkey = get_associated_token_address_with_program_id(
+            &lottery_account_pda.0,
+            &token_2022_usdc_mint_account_pubkey,
+            &TOKEN_2022_PROGRAM_ID
+        );
+        ptc.set_account(
+            &lottery_token_2022_ata_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: pack_token_2022_token_account(
+                        Token2022Account {
+                            state: Token2022AccountState::Initialized,
+                            mint: token_2022_usdc_mint_account_pubkey,
+                            owner: lottery_account_pda.0,
+                            ..Token2022Account::default()
+                        },
+                        &[ ExtensionType::TransferFeeAmount ]
+                    ),
+                    owner: TOKEN_2022_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let funding_token_2022_account_pubkey = Pubkey::new_unique();
+        ptc.set_account(
+            &funding_token_2022_account_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: pack_token_2022_token_account(
+                        Token2022Account {
+                            state: Token2022AccountState::Initialized,
+                            mint: token_2022_usdc_mint_account_pubkey,
+                            owner: user_account_auth.pubkey(),
+                            amount: 100_000000,
+                            ..Token2022Account::default()
+                        },
+                        &[ ExtensionType::TransferFeeAmount ]
+                    ),
+                    owner: TOKEN_2022_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let instruction = instruction_buy_ticket(
             config_account_pda.0, 
+            user_account_pda.0, 
+            user_account_auth.pubkey(), 
+            user_account_auth.pubkey(), 
             lottery_account_pda.0, 
-            lottery_account_auth.pubkey(), 
-            config_account.usdc_mint_account, 
-            lottery_account.arbitrary_mint_account_address, 
-            lottery_usdc_ata_pubkey, 
-            lottery_arbitrary_ata_pubkey, 
-            fund_receiver_usdc_token_account_pubkey, 
-            fund_receiver_arbitrary_token_account_pubkey, 
-            fund_receiver_rent_exempt_lamports, 
-            TOKEN_STANDARD_PROGRAM_ID
+            lottery_token_2022_ata_pubkey, 
+            funding_token_2022_account_pubkey, 
+            token_2022_usdc_mint_account_pubkey, 
+            SYSTEM_PROGRAM_ID, 
+            TOKEN_2022_PROGRAM_ID, 
+            5,
+            10_000000,
+            false
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[
-                // instruction_1,
-                // instruction_2,
-                instruction
-            ],
-            Some(&ptc.payer.pubkey()),
+            &[ instruction ], 
+            Some(&ptc.payer.pubkey()), 
             &[
                 &ptc.payer,
-                &lottery_account_auth
-            ],
+               &user_account_auth
+            ], 
             ptc.last_blockhash
         );
 
@@ -7061,132 +20359,402 @@ async fn test_withdraw_failed_lottery() {
             .unwrap()
             .unwrap();
 
-        let Lottery { is_creator_withdrawed_when_lottery_was_failed, .. } = Lottery::deserialize(
-            &mut &lottery_account_data[..]
-        ).unwrap();
-
-        assert_eq!(
-            is_creator_withdrawed_when_lottery_was_failed,
-            true,
-            "lottery account was not updated."
-        );
-
-        let SolanaAccount { data: lottery_usdc_ata_data, .. } = ptc
+        let SolanaAccount { data: user_account_data, .. } = ptc
             .banks_client
-            .get_account(lottery_usdc_ata_pubkey)
+            .get_account(user_account_pda.0)
             .await
             .unwrap()
             .unwrap();
 
-        let error = ptc
+        let SolanaAccount { data: funding_token_account_data, .. } = ptc
             .banks_client
-            .get_account(lottery_arbitrary_ata_pubkey)
+            .get_account(funding_token_2022_account_pubkey)
             .await
+            .unwrap()
             .unwrap();
-        if error.is_some() {
-            panic!("Account must be closed and we cannot get its data from blockchain.");
-        };
 
-        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
+        let SolanaAccount { data: lottery_ata_data, .. } = ptc
             .banks_client
-            .get_account(fund_receiver_usdc_token_account_pubkey)
+            .get_account(lottery_token_2022_ata_pubkey)
             .await
             .unwrap()
             .unwrap();
 
-        let SolanaAccount { data: fund_receiver_arbitrary_token_account_data, .. } = ptc
-            .banks_client
-            .get_account(fund_receiver_arbitrary_token_account_pubkey)
-            .await
-            .unwrap()
-            .unwrap();
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        let user_account = User::deserialize(
+            &mut &user_account_data[..]
+        ).unwrap();
+
+        assert_eq!(
+            lottery_account.tickets_total_amount,
+            15,
+            "invalid tickets total amounts."
+        );
+
+        assert_eq!(
+            lottery_account.protocol_fee,
+            2_500000 + 1_250000,
+            "invalid protocol fee amounts."
+        );
+
+        assert_eq!(
+            user_account.total_tickets_value,
+            15 * 10_000000,
+            "invalid user_account's total_tickets_value."
+        );
+
+        // 50 USDC + the 1% transfer fee of the pre-fee amount (50.505051 USDC)
+        assert_eq!(
+            StateWithExtensions::<Token2022Account>::unpack(&funding_token_account_data).unwrap().base.amount,
+            100_000000 - 50_505051,
+            "invalid funding token account balance."
+        );
+
+        assert_eq!(
+            StateWithExtensions::<Token2022Account>::unpack(&lottery_ata_data).unwrap().base.amount,
+            50_000000,
+            "invalid lottery's ata token balance."
+        );
+    }
+    // success - token-2022 usdc mint with a 1% transfer fee, the buyer pays the fee on top of the tickets price
+}
+////////////////////////////////////// User Instructions
+#[tokio::test]
+async fn test_buy_tickets_with_oracle_conversion() {
+    let lottery_program_id = LOTTERY_PROGRAM_ID;
+    let mut pt = setup_program_test(lottery_program_id);
+
+    //////////////////////? add config account
+    let config_account_pda = Pubkey::find_program_address(
+        &[
+            b"solottery_program_config_account"
+        ],
+        &lottery_program_id
+    );
+
+    let config_account = Config {
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: Pubkey::new_from_array([1; 32]),
+        pyth_price_feed_accounts: [
+            Pubkey::from_str("7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE").unwrap(), // SOl
+            Pubkey::from_str("4cSM2e6rvbGQUFiJbqytoVMi5GgghSMr8LwVrT9VPSPo").unwrap(), // BTC
+            Pubkey::from_str("42amVS4KgzR9rA28tkVYqVXjq9Qa8dcZQMbH5EYFX6XC").unwrap() // ETH
+        ],
+        pyth_price_feed_ids: [
+            "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d".to_string(), // SOL
+            "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43".to_string(), // BTC
+            "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace".to_string() // ETH
+        ],
+        maximum_time_of_price_feed_age: 10,
+        lottery_tickets_fee: 2.5,
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
+
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        config_account_pda.0,
+        config_solana_account
+    );
+    //////////////////////? add config account
+
+    //////////////////////? add lottery_account authority
+    let lottery_authority_account = Keypair::new();
+    pt.add_account(
+        lottery_authority_account.pubkey(),
+        SolanaAccount {
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery_account authority
+
+    //////////////////////? add lottery account
+    let lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            lottery_authority_account.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let mut lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("1"),
+        ticket_price: 10_000000, // 10 USDC
+        minimum_tickets_amount_required_to_be_sold: 20,
+        maximum_number_of_tickets_per_user: Some(100),
+        authority: lottery_authority_account.pubkey(),
+        ..Lottery::default()
+    };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+
+    let lottey_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+        data: lottery_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        lottery_account_pda.0,
+        lottey_solana_account
+    );
+    //////////////////////? add lottery account
+
+    //////////////////////? add user account
+    let user_account_auth = Keypair::new();
+    pt.add_account(
+        user_account_auth.pubkey(),
+        SolanaAccount {
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            user_account_auth.pubkey().to_bytes().as_slice(),
+            &lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+
+    let user_account = User {
+        discriminator: User::get_discriminator(),
+        canonical_bump: user_account_pda.1,
+        authority: user_account_auth.pubkey(),
+        lottery: lottery_account_pda.0,
+        ..User::default()
+    };
+
+    let user_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        lamports: sol_to_lamports(1.0),
+        data: user_account.try_to_vec().unwrap(),
+        ..SolanaAccount::default()
+    };
+
+    pt.add_account(
+        user_account_pda.0,
+        user_solana_account
+    );
+    //////////////////////? add user account
+
+    //////////////////////? add mint account
+    let mint_account_pubkey = Pubkey::new_from_array([1; 32]);
+    let mint_account = MintAccount {
+        supply: 100_000000,
+        decimals: 6,
+        is_initialized: true,
+        ..MintAccount::default()
+    };
+
+    let mut mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        mint_account,
+        mint_account_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        mint_account_pubkey,
+        SolanaAccount {
+            owner: spl_token::ID,
+            data: mint_account_data.to_vec(),
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add mint account
+
+    //////////////////////? add lottery ata
+    let lottery_ata_pda = get_associated_token_address(
+        &lottery_account_pda.0,
+        &mint_account_pubkey
+    );
+    let lottery_ata = TokenAccount {
+        state: TokenAccountState::Initialized,
+        mint: mint_account_pubkey,
+        owner: lottery_account_pda.0,
+        ..TokenAccount::default()
+    };
+
+    let mut lottery_ata_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        lottery_ata,
+        lottery_ata_data.as_mut_slice()
+    ).unwrap();
+
+    pt.add_account(
+        lottery_ata_pda,
+        SolanaAccount {
+            owner: spl_token::ID,
+            data: lottery_ata_data.to_vec(),
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add lottery ata
+
+    //////////////////////? add fund-receiver token account
+    let fund_receiver_token_account_pubkey = Pubkey::new_from_array([2; 32]);
+    let fund_receiver_token_account = TokenAccount {
+        state: TokenAccountState::Initialized,
+        mint: mint_account_pubkey,
+        owner: user_account_auth.pubkey(),
+        ..TokenAccount::default()
+    };
+
+    let mut fund_receiver_token_account_data = [0u8; TokenAccount::LEN];
+    TokenAccount::pack(
+        fund_receiver_token_account,
+        fund_receiver_token_account_data.as_mut_slice()
+    ).unwrap();
 
-        let SolanaAccount { lamports, .. } = ptc
-            .banks_client
-            .get_account(fund_receiver_rent_exempt_lamports)
-            .await
-            .unwrap()
-            .unwrap();
-        let rent_exempt_lamports = (
-            (
-                (ACCOUNT_STORAGE_OVERHEAD + TokenAccount::LEN as u64) * DEFAULT_LAMPORTS_PER_BYTE_YEAR
-            ) as f64 * DEFAULT_EXEMPTION_THRESHOLD
-        ) as u64;
+    pt.add_account(
+        fund_receiver_token_account_pubkey,
+        SolanaAccount {
+            owner: spl_token::ID,
+            data: fund_receiver_token_account_data.to_vec(),
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add fund-receiver token account
+
+    //////////////////////? add SOL & BTC pyth price feed accounts
+    for (price_feed_index, price) in [ (0usize, 250_00000000i64), (1, 60_000_00000000) ] {
+        let price_feed_account = PriceUpdateV2 {
+            verification_level: VerificationLevel::Full,
+            price_message: PriceFeedMessage {
+                publish_time: 150,
+                price, // USD
+                conf: 7,
+                ema_conf: u64::default(),
+                ema_price: i64::default(),
+                exponent: -8,
+                feed_id: get_feed_id_from_hex(
+                    &config_account.pyth_price_feed_ids[price_feed_index]
+                ).unwrap(),
+                prev_publish_time: i64::default()
+            },
+            write_authority: Pubkey::default(),
+            posted_slot: u64::default()
+        };
 
-        assert_eq!(
-            lamports,
-            rent_exempt_lamports + sol_to_lamports(0.1),
-            "invalid rent_exempt receiver lamport balance."
+        pt.add_account(
+            config_account.pyth_price_feed_accounts[price_feed_index],
+            SolanaAccount {
+                lamports: sol_to_lamports(1.0),
+                owner: PYTH_PRICE_RECEIVER_PROGRAM_ID,
+                data: vec![
+                    vec![ 34, 241, 35, 99, 157, 126, 244, 205 ],
+                    price_feed_account.try_to_vec().unwrap()
+                ].concat(),
+                ..SolanaAccount::default()
+            }
         );
+    };
+    //////////////////////? add SOL & BTC pyth price feed accounts
 
-        let TokenAccount { amount: lottery_usdc_ata_balance, .. } = TokenAccount::unpack(
-            &lottery_usdc_ata_data
-        ).unwrap();
+    let (sol_payments_vault_pda, _) = get_sol_payments_vault_account_pda(
+        &lottery_account_pda.0,
+        &lottery_program_id
+    ).unwrap();
 
-        let TokenAccount { amount: fund_receiver_usdc_token_account_balance, .. } = TokenAccount::unpack(
-            &fund_receiver_usdc_token_account_data
-        ).unwrap();
+    let mut ptc = pt.start_with_context().await;
 
-        let TokenAccount { amount: fund_receiver_arbitrary_token_account_balance, .. } = TokenAccount::unpack(
-            &fund_receiver_arbitrary_token_account_data
-        ).unwrap();
+    // 2 tickets -> 20 USDC -> 0.08 SOL (SOL = 250 USD)
+    let tickets_lamports = 80_000_000u64;
 
-        assert_eq!(
-            55_000000 - 10_000000,
-            lottery_usdc_ata_balance,
-            "invalid lottery_usdc_ata_balance."
+    // failure - invalid sol price feed account
+    {
+        change_clock_sysvar(
+            &ptc,
+            150
         );
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-        assert_eq!(
-            fund_receiver_usdc_token_account_balance,
+        let instruction = instruction_buy_ticket_with_oracle_conversion(
+            config_account_pda.0,
+            user_account_pda.0,
+            user_account_auth.pubkey(),
+            user_account_auth.pubkey(),
+            lottery_account_pda.0,
+            mint_account_pubkey,
+            config_account.pyth_price_feed_accounts[1],
+            SYSTEM_PROGRAM_ID,
+            2,
             10_000000,
-            "invalid fund_receiver_usdc_token_account_balance."
+            tickets_lamports
         );
 
-        assert_eq!(
-            fund_receiver_arbitrary_token_account_balance,
-            350_000,
-            "invalid fund_receiver_arbitrary_token_account_balance."
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ],
+            ptc.last_blockhash
         );
-    }
-    // success
 
-    // failure - lottery is not in correct state
-    {
-        let mut new_lottery_account = lottery_account.clone();
-        new_lottery_account.minimum_tickets_amount_required_to_be_sold = 80;
-        new_lottery_account.tickets_total_amount = 99;
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
 
-        ptc.set_account(
-           &lottery_account_pda.0,
-           &SolanaSharedDataAccount::from(
-                SolanaAccount {
-                    data: new_lottery_account.try_to_vec().unwrap(),
-                    owner: LOTTERY_PROGRAM_ID,
-                    lamports: sol_to_lamports(1.0),
-                    ..SolanaAccount::default()
-                }
-           )
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidSolPriceFeedAccount as u32
+                )
+            )
         );
+    }
+    // failure - invalid sol price feed account
 
+    // failure - maximum spend exceeded
+    {
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 555);
 
-        let instruction = instruction_withdraw_failed_lottery(
-            config_account_pda.0, 
-            lottery_account_pda.0, 
-            lottery_account_auth.pubkey(), 
-            config_account.usdc_mint_account, 
-            lottery_account.arbitrary_mint_account_address, 
-            lottery_usdc_ata_pubkey, 
-            lottery_arbitrary_ata_pubkey, 
-            fund_receiver_usdc_token_account_pubkey, 
-            fund_receiver_arbitrary_token_account_pubkey, 
-            fund_receiver_rent_exempt_lamports, 
-            TOKEN_STANDARD_PROGRAM_ID
+        let instruction = instruction_buy_ticket_with_oracle_conversion(
+            config_account_pda.0,
+            user_account_pda.0,
+            user_account_auth.pubkey(),
+            user_account_auth.pubkey(),
+            lottery_account_pda.0,
+            mint_account_pubkey,
+            config_account.pyth_price_feed_accounts[0],
+            SYSTEM_PROGRAM_ID,
+            2,
+            10_000000,
+            tickets_lamports - 1
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -7194,7 +20762,7 @@ async fn test_withdraw_failed_lottery() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_account_auth
+                &user_account_auth
             ],
             ptc.last_blockhash
         );
@@ -7211,238 +20779,221 @@ async fn test_withdraw_failed_lottery() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidLotteryState as u32
+                    LotteryError::MaximumSpendExceeded as u32
                 )
             )
         );
     }
-    // failure - lottery is not in correct state
-}
+    // failure - maximum spend exceeded
 
-#[tokio::test]
-async fn test_withdraw_and_close_failed_user() {
-    let lottery_program_id = LOTTERY_PROGRAM_ID;
-    let mut pt = setup_program_test(lottery_program_id);
+    // success
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-    //////////////////////? add config account
-    let config_account_pda = Pubkey::find_program_address(
-        &[
-            b"solottery_program_config_account"
-        ],
-        &lottery_program_id
-    );
+        let instruction = instruction_buy_ticket_with_oracle_conversion(
+            config_account_pda.0,
+            user_account_pda.0,
+            user_account_auth.pubkey(),
+            user_account_auth.pubkey(),
+            lottery_account_pda.0,
+            mint_account_pubkey,
+            config_account.pyth_price_feed_accounts[0],
+            SYSTEM_PROGRAM_ID,
+            2,
+            10_000000,
+            tickets_lamports
+        );
 
-    let config_account = Config {
-        discriminator: Config::get_discriminator(),
-        canonical_bump: config_account_pda.1,
-        usdc_mint_account: Pubkey::new_from_array([1; 32]),
-        ..Config::default()
-    };
-    let config_account_data = config_account.try_to_vec().unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &user_account_auth
+            ],
+            ptc.last_blockhash
+        );
 
-    let config_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        data: config_account_data,
-        lamports: sol_to_lamports(0.0009),
-        ..SolanaAccount::default()
-    };
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
 
-    pt.add_account(
-        config_account_pda.0,
-        config_solana_account
-    );
-    //////////////////////? add config account
-    
-    //////////////////////? add lottery account
-    let lottery_account_pda = Pubkey::find_program_address(
-        &[
-            b"lottery_account",
-            Pubkey::default().to_bytes().as_slice(),
-            get_lottery_literal_seed(&String::from("1")).as_slice()
-        ],
-        &lottery_program_id
-    );
+        let user_account = User::deserialize(
+            &mut &ptc.banks_client.get_account(user_account_pda.0).await.unwrap().unwrap().data[..]
+        ).unwrap();
 
-    let mut lottery_account = Lottery {
-        discriminator: Lottery::get_discriminator(),
-        canonical_bump: lottery_account_pda.1,
-        starting_time: 100,
-        ending_time: 200,
-        lottery_description: String::from("1"),
-        minimum_tickets_amount_required_to_be_sold: 100,
-        tickets_total_amount: 90,
-        ..Lottery::default()
-    };
-    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+        let lottery_account = Lottery::deserialize(
+            &mut &ptc.banks_client.get_account(lottery_account_pda.0).await.unwrap().unwrap().data[..]
+        ).unwrap();
 
-    let lottey_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-        data: vec![
-            lottery_account.try_to_vec().unwrap(),
-            vec![0u8; 320]
-        ].concat(),
-        ..SolanaAccount::default()
-    };
+        let sol_payments_vault_account = ptc
+            .banks_client
+            .get_account(sol_payments_vault_pda)
+            .await
+            .unwrap()
+            .unwrap();
 
-    pt.add_account(
-        lottery_account_pda.0,
-        lottey_solana_account
-    );
-    //////////////////////? add lottery account
-    
-    //////////////////////? add user account
-    let user_account_auth = Keypair::new();
-    pt.add_account(
-        user_account_auth.pubkey(),
-        SolanaAccount {
-            lamports: sol_to_lamports(1.0),
-            ..SolanaAccount::default()
-        }
-    );
+        let rent = ptc.banks_client.get_rent().await.unwrap();
+
+        // check user account
+        assert_eq!(
+            user_account.total_tickets_acquired,
+            2,
+            "invalid user_account's total_tickets_acquired."
+        );
+
+        assert_eq!(
+            user_account.total_tickets_value,
+            20_000000,
+            "invalid user_account's total_tickets_value."
+        );
 
-    let user_account_pda = Pubkey::find_program_address(
-        &[
-            b"user_account",
-            user_account_auth.pubkey().to_bytes().as_slice(),
-            &lottery_account_pda.0.to_bytes().as_slice()
-        ],
-        &lottery_program_id
-    );
+        assert_eq!(
+            user_account.total_converted_tickets_value,
+            20_000000,
+            "invalid user_account's total_converted_tickets_value."
+        );
 
-    let user_account = User {
-        discriminator: User::get_discriminator(),
-        canonical_bump: user_account_pda.1,
-        authority: user_account_auth.pubkey(),
-        lottery: lottery_account_pda.0,
-        total_rent_exempt_paied: 100000,
-        total_tickets_acquired: 10,
-        total_tickets_value: 100_000000, // USDC
-        ..User::default()
-    };
+        assert_eq!(
+            user_account.total_tickets_lamports,
+            tickets_lamports,
+            "invalid user_account's total_tickets_lamports."
+        );
+        // check user account
 
-    let user_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        lamports: sol_to_lamports(1.0),
-        data: user_account.try_to_vec().unwrap(),
-        ..SolanaAccount::default()
-    };
-    
-    pt.add_account(
-        user_account_pda.0,
-        user_solana_account
-    );
-    //////////////////////? add user account
-    
-    //////////////////////? add UDSC mint account
-    let usdc_mint_account = MintAccount {
-        supply: 1000_000000,
-        decimals: 6,
-        is_initialized: true,
-        ..MintAccount::default()
-    };
+        // check lottery account
+        assert_eq!(
+            lottery_account.tickets_total_amount,
+            2,
+            "invalid lottery_account's tickets_total_amount."
+        );
 
-    let mut usdc_mint_account_data = [0u8; MintAccount::LEN];
-    MintAccount::pack(
-        usdc_mint_account,
-        usdc_mint_account_data.as_mut_slice()
-    ).unwrap();
+        assert_eq!(
+            lottery_account.sol_payments_amount,
+            tickets_lamports,
+            "invalid lottery_account's sol_payments_amount."
+        );
 
-    let usdc_mint_solana_account = SolanaAccount {
-        data: usdc_mint_account_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
+        assert_eq!(
+            lottery_account.sol_protocol_fee,
+            2_000_000,
+            "invalid lottery_account's sol_protocol_fee."
+        );
+        // check lottery account
 
-    pt.add_account(
-        config_account.usdc_mint_account,
-        usdc_mint_solana_account
-    );
-    //////////////////////? add USDC mint account
-    
-    //////////////////////? add lottery's USDC ata
-    let lottery_usdc_ata_pubkey = get_associated_token_address(
-        &lottery_account_pda.0, 
-        &config_account.usdc_mint_account
-    );
-    let lottery_usdc_ata = TokenAccount {
-        amount: 1000_000000,
-        owner: lottery_account_pda.0,
-        state: TokenAccountState::Initialized,
-        mint: config_account.usdc_mint_account,
-        ..TokenAccount::default()
-    };
+        // check sol-payments-vault balance
+        assert_eq!(
+            sol_payments_vault_account.lamports,
+            rent.minimum_balance(0) + tickets_lamports,
+            "invalid sol-payments-vault balance."
+        );
+        // check sol-payments-vault balance
+    }
+    // success
 
-    let mut lottery_usdc_ata_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        lottery_usdc_ata,
-        lottery_usdc_ata_data.as_mut_slice()
-    ).unwrap();
+    // failure - failed lottery is closed before its sol payments are refunded
+    {
+        change_clock_sysvar(
+            &ptc,
+            250
+        );
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-    let lottery_usdc_ata_solana_account = SolanaAccount {
-        data: lottery_usdc_ata_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
+        // the creator's withdrawal of a failed lottery is done
+        let mut lottery_solana_account = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
 
-    pt.add_account(
-        lottery_usdc_ata_pubkey,
-        lottery_usdc_ata_solana_account
-    );
-    //////////////////////? add lottery's USDC ata
-    
-    //////////////////////? add fund receiver, USDC token account
-    let fund_receiver_usdc_token_account_pubkey = Pubkey::new_from_array([2; 32]);
-    let fund_receiver_usdc_token_account = TokenAccount {
-        amount: 0_000000,
-        owner: user_account_auth.pubkey(),
-        state: TokenAccountState::Initialized,
-        mint: config_account.usdc_mint_account,
-        ..TokenAccount::default()
-    };
+        let mut lottery_account = Lottery::deserialize(
+            &mut &lottery_solana_account.data[..]
+        ).unwrap();
+        lottery_account.is_creator_withdrawed_when_lottery_was_failed = true;
+        lottery_account.serialize(
+            &mut &mut lottery_solana_account.data[..]
+        ).unwrap();
 
-    let mut fund_receiver_usdc_token_account_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        fund_receiver_usdc_token_account,
-        fund_receiver_usdc_token_account_data.as_mut_slice()
-    ).unwrap();
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(lottery_solana_account)
+        );
 
-    let fund_receiver_usdc_token_solana_account = SolanaAccount {
-        data: fund_receiver_usdc_token_account_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
+        let instruction = instruction_close_lottery_account_and_usdc_token_account(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            mint_account_pubkey,
+            lottery_ata_pda,
+            fund_receiver_token_account_pubkey,
+            lottery_authority_account.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            false,
+            None
+        );
 
-    pt.add_account(
-        fund_receiver_usdc_token_account_pubkey,
-        fund_receiver_usdc_token_solana_account
-    );
-    //////////////////////? add fund receiver, USDC token account
-    
-    let mut ptc = pt.start_with_context().await;
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
+        );
 
-    // success
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::SolPaymentsNotRefunded as u32
+                )
+            )
+        );
+    }
+    // failure - failed lottery is closed before its sol payments are refunded
+
+    // success - failed lottery refunds the tickets in SOL
     {
-        ptc.
-            get_new_latest_blockhash()
+
+        ptc
+            .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
+
+        let fund_receiver_sol_account = Keypair::new().pubkey();
 
         let instruction = instruction_withdraw_and_close_failed_user(
-            config_account_pda.0, 
-            lottery_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            config_account.usdc_mint_account, 
-            lottery_usdc_ata_pubkey, 
-            fund_receiver_usdc_token_account_pubkey, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            TOKEN_STANDARD_PROGRAM_ID
+            config_account_pda.0,
+            lottery_account_pda.0,
+            user_account_pda.0,
+            user_account_auth.pubkey(),
+            mint_account_pubkey,
+            lottery_ata_pda,
+            fund_receiver_token_account_pubkey,
+            user_account_auth.pubkey(),
+            user_account_auth.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            Some(fund_receiver_sol_account),
+            None,
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -7461,88 +21012,111 @@ async fn test_withdraw_and_close_failed_user() {
             .await
             .unwrap();
 
-        let error = ptc
+        let fund_receiver_sol_solana_account = ptc
             .banks_client
-            .get_account(user_account_pda.0)
+            .get_account(fund_receiver_sol_account)
             .await
+            .unwrap()
             .unwrap();
-        if error.is_some() {
-            panic!("Account must be closed.");
-        };
 
-        let SolanaAccount { lamports: lottery_account_lamport_balance, data, .. } = ptc
+        let sol_payments_vault_account = ptc
             .banks_client
-            .get_account(lottery_account_pda.0)
+            .get_account(sol_payments_vault_pda)
             .await
             .unwrap()
             .unwrap();
-        
+
+        let rent = ptc.banks_client.get_rent().await.unwrap();
+
         assert_eq!(
-            lottery_account_lamport_balance,
-            sol_to_lamports(1.0) - 100000,
-            "invalid lottery account lamport balance."
+            fund_receiver_sol_solana_account.lamports,
+            tickets_lamports,
+            "invalid fund-receiver sol account balance."
         );
 
         assert_eq!(
-            data.len(),
-            lottery_account.try_to_vec().unwrap().len(),
-            "invalid lottery account data size."
+            sol_payments_vault_account.lamports,
+            rent.minimum_balance(0),
+            "invalid sol-payments-vault balance."
         );
 
-        let SolanaAccount { lamports: user_account_auth_lamport_balance, .. } = ptc
-            .banks_client
-            .get_account(user_account_auth.pubkey())
+        let lottery_account = Lottery::deserialize(
+            &mut &ptc.banks_client.get_account(lottery_account_pda.0).await.unwrap().unwrap().data[..]
+        ).unwrap();
+
+        assert_eq!(
+            lottery_account.sol_payments_amount,
+            0,
+            "invalid lottery_account's sol_payments_amount."
+        );
+    }
+    // success - failed lottery refunds the tickets in SOL
+
+    // success - closing the lottery closes the sol-payments vault
+    {
+        ptc
+            .get_new_latest_blockhash()
             .await
-            .unwrap()
             .unwrap();
 
-        assert_eq!(
-            user_account_auth_lamport_balance,
-            sol_to_lamports(1.0) +
-            100000 + // tickets rent exempt lamports
-            sol_to_lamports(1.0), // user account's lamport balance
-            "invalid user account authority's lamport balance."
+        let fund_receiver_rent_exempt_account = Keypair::new().pubkey();
+
+        let lottery_balance = ptc.banks_client.get_balance(lottery_account_pda.0).await.unwrap();
+        let lottery_ata_balance = ptc.banks_client.get_balance(lottery_ata_pda).await.unwrap();
+        let sol_payments_vault_balance = ptc.banks_client.get_balance(sol_payments_vault_pda).await.unwrap();
+
+        let instruction = instruction_close_lottery_account_and_usdc_token_account(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            lottery_authority_account.pubkey(),
+            mint_account_pubkey,
+            lottery_ata_pda,
+            fund_receiver_token_account_pubkey,
+            fund_receiver_rent_exempt_account,
+            TOKEN_STANDARD_PROGRAM_ID,
+            false,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_authority_account
+            ],
+            ptc.last_blockhash
         );
 
-        let SolanaAccount { data: lottery_usdc_ata_data, .. } = ptc
+        ptc
             .banks_client
-            .get_account(lottery_usdc_ata_pubkey)
+            .process_transaction(tx)
             .await
-            .unwrap()
             .unwrap();
 
-        let TokenAccount { amount: lottery_usdc_ata_balance, .. } = TokenAccount::unpack(
-            &lottery_usdc_ata_data
-        ).unwrap();
-
-        assert_eq!(
-            lottery_usdc_ata_balance,
-            1000_000000 - 100_000000,
-            "invalid lottery USDC ata token balance."
-        );
-
-        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
+        let sol_payments_vault_account = ptc
             .banks_client
-            .get_account(fund_receiver_usdc_token_account_pubkey)
+            .get_account(sol_payments_vault_pda)
             .await
-            .unwrap()
             .unwrap();
 
-        let TokenAccount { amount: fund_receiver_usdc_token_account_balance, .. } = TokenAccount::unpack(
-            &fund_receiver_usdc_token_account_data
-        ).unwrap();
+        assert_eq!(
+            sol_payments_vault_account,
+            None,
+            "sol-payments-vault is not closed."
+        );
 
         assert_eq!(
-            fund_receiver_usdc_token_account_balance,
-            100_000000,
-            "invalid fund receiver usdc token account balance."
+            ptc.banks_client.get_balance(fund_receiver_rent_exempt_account).await.unwrap(),
+            lottery_balance + lottery_ata_balance + sol_payments_vault_balance,
+            "invalid fund-receiver rent_exempt account balance."
         );
     }
-    // success
+    // success - closing the lottery closes the sol-payments vault
 }
 
 #[tokio::test]
-async fn test_close_lottery_account_usdc_token_account() {
+async fn test_buy_ticket_for() {
     let lottery_program_id = LOTTERY_PROGRAM_ID;
     let mut pt = setup_program_test(lottery_program_id);
 
@@ -7574,23 +21148,13 @@ async fn test_close_lottery_account_usdc_token_account() {
         config_solana_account
     );
     //////////////////////? add config account
-    
-    //////////////////////? add lottery account
-    let lottery_auth = Keypair::new();
-    pt.add_account(
-        lottery_auth.pubkey(),
-        SolanaAccount {
-            lamports: sol_to_lamports(1.0),
-            owner: SYSTEM_PROGRAM_ID,
-            ..SolanaAccount::default()
-        }
-    );
 
+    //////////////////////? add lottery account
     let lottery_account_pda = Pubkey::find_program_address(
         &[
             b"lottery_account",
-            lottery_auth.pubkey().to_bytes().as_slice(),
-            get_lottery_literal_seed(&String::from("lottery for fun!")).as_slice()
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
         ],
         &lottery_program_id
     );
@@ -7600,23 +21164,19 @@ async fn test_close_lottery_account_usdc_token_account() {
         canonical_bump: lottery_account_pda.1,
         starting_time: 100,
         ending_time: 200,
-        lottery_description: String::from("lottery for fun!"),
+        lottery_description: String::from("1"),
+        ticket_price: 10_000000,
         minimum_tickets_amount_required_to_be_sold: 100,
-        tickets_total_amount: 90,
-        is_creator_withdrawed_when_lottery_was_failed: true,
-        winners_count: 10,
-        authority: lottery_auth.pubkey(),
+        maximum_number_of_tickets_per_user: Some(10),
+        maximum_total_tickets: Some(50),
         ..Lottery::default()
     };
-    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64 + 330;
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
 
     let lottey_solana_account = SolanaAccount {
         owner: lottery_program_id,
         lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-        data: vec![
-            lottery_account.try_to_vec().unwrap(),
-            vec![0u8; 330]
-        ].concat(),
+        data: lottery_account.try_to_vec().unwrap(),
         ..SolanaAccount::default()
     };
 
@@ -7625,8 +21185,32 @@ async fn test_close_lottery_account_usdc_token_account() {
         lottey_solana_account
     );
     //////////////////////? add lottery account
-    
-    //////////////////////? add UDSC mint account
+
+    //////////////////////? add beneficiary & sponsors
+    let beneficiary = Keypair::new();
+    let sponsor = Keypair::new();
+    let other_sponsor = Keypair::new();
+    for wallet in [&beneficiary, &sponsor, &other_sponsor] {
+        pt.add_account(
+            wallet.pubkey(),
+            SolanaAccount {
+                lamports: sol_to_lamports(1.0),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+
+    let user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            beneficiary.pubkey().to_bytes().as_slice(),
+            &lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
+    //////////////////////? add beneficiary & sponsors
+
+    //////////////////////? add USDC mint account
     let usdc_mint_account = MintAccount {
         supply: 1000_000000,
         decimals: 6,
@@ -7640,107 +21224,270 @@ async fn test_close_lottery_account_usdc_token_account() {
         usdc_mint_account_data.as_mut_slice()
     ).unwrap();
 
-    let usdc_mint_solana_account = SolanaAccount {
-        data: usdc_mint_account_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
-
     pt.add_account(
         config_account.usdc_mint_account,
-        usdc_mint_solana_account
+        SolanaAccount {
+            data: usdc_mint_account_data.to_vec(),
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
     );
     //////////////////////? add USDC mint account
-    
-    //////////////////////? add lottery's USDC ata
+
+    //////////////////////? add USDC token accounts (lottery's ata, beneficiary, sponsors)
     let lottery_usdc_ata_pubkey = get_associated_token_address(
-        &lottery_account_pda.0, 
+        &lottery_account_pda.0,
         &config_account.usdc_mint_account
     );
-    let lottery_usdc_ata = TokenAccount {
-        amount: 10_000000,
-        owner: lottery_account_pda.0,
-        state: TokenAccountState::Initialized,
-        mint: config_account.usdc_mint_account,
-        ..TokenAccount::default()
-    };
+    let beneficiary_usdc_token_account_pubkey = Pubkey::new_from_array([2; 32]);
+    let sponsor_usdc_token_account_pubkey = Pubkey::new_from_array([3; 32]);
+    let other_sponsor_usdc_token_account_pubkey = Pubkey::new_from_array([4; 32]);
+
+    for (token_account_pubkey, owner, amount) in [
+        (lottery_usdc_ata_pubkey, lottery_account_pda.0, 0_000000),
+        (beneficiary_usdc_token_account_pubkey, beneficiary.pubkey(), 20_000000),
+        (sponsor_usdc_token_account_pubkey, sponsor.pubkey(), 100_000000),
+        (other_sponsor_usdc_token_account_pubkey, other_sponsor.pubkey(), 100_000000)
+    ] {
+        let token_account = TokenAccount {
+            amount,
+            owner,
+            state: TokenAccountState::Initialized,
+            mint: config_account.usdc_mint_account,
+            ..TokenAccount::default()
+        };
 
-    let mut lottery_usdc_ata_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        lottery_usdc_ata,
-        lottery_usdc_ata_data.as_mut_slice()
-    ).unwrap();
+        let mut token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            token_account,
+            token_account_data.as_mut_slice()
+        ).unwrap();
 
-    let lottery_usdc_ata_solana_account = SolanaAccount {
-        data: lottery_usdc_ata_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
+        pt.add_account(
+            token_account_pubkey,
+            SolanaAccount {
+                data: token_account_data.to_vec(),
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(1.0),
+                ..SolanaAccount::default()
+            }
+        );
     };
+    //////////////////////? add USDC token accounts (lottery's ata, beneficiary, sponsors)
 
-    pt.add_account(
-        lottery_usdc_ata_pubkey,
-        lottery_usdc_ata_solana_account
+    //////////////////////? add token-2022 USDC (1% transfer fee) lottery, mint & token accounts (lottery's ata, beneficiary, sponsor)
+    let token_2022_lottery_account_pda = Pubkey::find_program_address(
+        &[
+            b"lottery_account",
+            Pubkey::default().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("2")).as_slice()
+        ],
+        &lottery_program_id
     );
-    //////////////////////? add lottery's USDC ata
-    
-    //////////////////////? add fund receiver, USDC token account
-    let fund_receiver_usdc_token_account_pubkey = Pubkey::new_from_array([2; 32]);
-    let fund_receiver_usdc_token_account = TokenAccount {
-        amount: 0_000000,
-        state: TokenAccountState::Initialized,
-        mint: config_account.usdc_mint_account,
-        ..TokenAccount::default()
+
+    let mut token_2022_lottery_account = Lottery {
+        discriminator: Lottery::get_discriminator(),
+        canonical_bump: token_2022_lottery_account_pda.1,
+        starting_time: 100,
+        ending_time: 200,
+        lottery_description: String::from("2"),
+        ticket_price: 10_000000,
+        minimum_tickets_amount_required_to_be_sold: 100,
+        ..Lottery::default()
     };
+    token_2022_lottery_account.initial_bytes = token_2022_lottery_account.try_to_vec().unwrap().len() as u64;
 
-    let mut fund_receiver_usdc_token_account_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        fund_receiver_usdc_token_account,
-        fund_receiver_usdc_token_account_data.as_mut_slice()
-    ).unwrap();
+    pt.add_account(
+        token_2022_lottery_account_pda.0,
+        SolanaAccount {
+            owner: lottery_program_id,
+            lamports: sol_to_lamports(1.0),
+            data: token_2022_lottery_account.try_to_vec().unwrap(),
+            ..SolanaAccount::default()
+        }
+    );
 
-    let fund_receiver_usdc_token_solana_account = SolanaAccount {
-        data: fund_receiver_usdc_token_account_data.to_vec(),
-        owner: TOKEN_STANDARD_PROGRAM_ID,
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
+    let token_2022_user_account_pda = Pubkey::find_program_address(
+        &[
+            b"user_account",
+            beneficiary.pubkey().to_bytes().as_slice(),
+            token_2022_lottery_account_pda.0.to_bytes().as_slice()
+        ],
+        &lottery_program_id
+    );
 
+    let token_2022_usdc_mint_account_pubkey = Pubkey::new_unique();
     pt.add_account(
-        fund_receiver_usdc_token_account_pubkey,
-        fund_receiver_usdc_token_solana_account
+        token_2022_usdc_mint_account_pubkey,
+        SolanaAccount {
+            data: pack_token_2022_mint_account(
+                Token2022MintAccount {
+                    supply: 1000_000000,
+                    decimals: 6,
+                    is_initialized: true,
+                    ..Token2022MintAccount::default()
+                },
+                &[ ExtensionType::TransferFeeConfig ],
+                100
+            ),
+            owner: TOKEN_2022_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
     );
-    //////////////////////? add fund receiver, USDC token account
-    
+
+    let token_2022_lottery_usdc_ata_pubkey = get_associated_token_address_with_program_id(
+        &token_2022_lottery_account_pda.0,
+        &token_2022_usdc_mint_account_pubkey,
+        &TOKEN_2022_PROGRAM_ID
+    );
+    let token_2022_beneficiary_usdc_token_account_pubkey = Pubkey::new_unique();
+    let token_2022_sponsor_usdc_token_account_pubkey = Pubkey::new_unique();
+
+    for (token_account_pubkey, owner, amount) in [
+        (token_2022_lottery_usdc_ata_pubkey, token_2022_lottery_account_pda.0, 0_000000),
+        (token_2022_beneficiary_usdc_token_account_pubkey, beneficiary.pubkey(), 0_000000),
+        (token_2022_sponsor_usdc_token_account_pubkey, sponsor.pubkey(), 100_000000)
+    ] {
+        pt.add_account(
+            token_account_pubkey,
+            SolanaAccount {
+                data: pack_token_2022_token_account(
+                    Token2022Account {
+                        amount,
+                        owner,
+                        state: Token2022AccountState::Initialized,
+                        mint: token_2022_usdc_mint_account_pubkey,
+                        ..Token2022Account::default()
+                    },
+                    &[ ExtensionType::TransferFeeAmount ]
+                ),
+                owner: TOKEN_2022_PROGRAM_ID,
+                lamports: sol_to_lamports(1.0),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add token-2022 USDC (1% transfer fee) lottery, mint & token accounts (lottery's ata, beneficiary, sponsor)
+
     let mut ptc = pt.start_with_context().await;
 
-    // failure - lottery invalid state
+    // success - sponsor buys tickets for the beneficiary, the user account is created
     {
-        // 1. LotteryState::Unknown
-        ptc.
-            get_new_latest_blockhash()
+        change_clock_sysvar(
+            &ptc,
+            150
+        );
+        ptc
+            .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 50);
 
-        let instruction = instruction_close_lottery_account_and_usdc_token_account(
-            config_account_pda.0, 
-            lottery_account_pda.0, 
-            lottery_auth.pubkey(), 
-            config_account.usdc_mint_account, 
-            lottery_usdc_ata_pubkey, 
-            fund_receiver_usdc_token_account_pubkey, 
-            lottery_auth.pubkey(), 
-            TOKEN_STANDARD_PROGRAM_ID
+        let instruction = instruction_buy_ticket_for(
+            config_account_pda.0,
+            user_account_pda.0,
+            beneficiary.pubkey(),
+            sponsor.pubkey(),
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            sponsor_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_STANDARD_PROGRAM_ID,
+            3,
+            10_000000
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &sponsor
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: user_account_data, .. } = ptc
+            .banks_client
+            .get_account(user_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let user_account = User::deserialize(
+            &mut &user_account_data[..]
+        ).unwrap();
+
+        assert_eq!(user_account.authority, beneficiary.pubkey(), "invalid user account's authority.");
+        assert_eq!(user_account.sponsor, Some(sponsor.pubkey()), "invalid user account's sponsor.");
+        assert_eq!(user_account.total_tickets_acquired, 3, "invalid user account's total_tickets_acquired.");
+        assert_eq!(user_account.total_tickets_value, 30_000000, "invalid user account's total_tickets_value.");
+        assert_eq!(user_account.total_sponsored_tickets_value, 30_000000, "invalid user account's total_sponsored_tickets_value.");
+
+        let SolanaAccount { data: sponsor_usdc_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(sponsor_usdc_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount: sponsor_usdc_token_account_balance, .. } = TokenAccount::unpack(
+            &sponsor_usdc_token_account_data
+        ).unwrap();
+
+        assert_eq!(
+            sponsor_usdc_token_account_balance,
+            100_000000 - 30_000000,
+            "invalid sponsor usdc token account balance."
+        );
+    }
+    // success - sponsor buys tickets for the beneficiary, the user account is created
+
+    // failure - beneficiary is the lottery's authority
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let lottery_authority_user_account_pda = Pubkey::find_program_address(
+            &[
+                b"user_account",
+                Pubkey::default().to_bytes().as_slice(),
+                &lottery_account_pda.0.to_bytes().as_slice()
+            ],
+            &lottery_program_id
+        );
+
+        let instruction = instruction_buy_ticket_for(
+            config_account_pda.0,
+            lottery_authority_user_account_pda.0,
+            Pubkey::default(),
+            sponsor.pubkey(),
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            sponsor_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_STANDARD_PROGRAM_ID,
+            1,
+            10_000000
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction.clone() ],
+            &[ instruction ],
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_auth
+                &sponsor
             ],
             ptc.last_blockhash
         );
@@ -7757,33 +21504,33 @@ async fn test_close_lottery_account_usdc_token_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidLotteryState as u32
+                    LotteryError::InvalidUser as u32
                 )
             )
         );
+    }
+    // failure - beneficiary is the lottery's authority
 
-        // 2. LotteryState::Successful
-        ptc.
-            get_new_latest_blockhash()
+    // failure - user account is sponsored by another sponsor
+    {
+        ptc
+            .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 450);
 
-        lottery_account.minimum_tickets_amount_required_to_be_sold = 100;
-        lottery_account.tickets_total_amount = 150;
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(
-                SolanaAccount {
-                    owner: LOTTERY_PROGRAM_ID,
-                    lamports: sol_to_lamports(1.0),
-                    data: vec![
-                        lottery_account.try_to_vec().unwrap(),
-                        vec![0u8; 330]
-                    ].concat(),
-                    ..SolanaAccount::default()
-                }
-            )
+        let instruction = instruction_buy_ticket_for(
+            config_account_pda.0,
+            user_account_pda.0,
+            beneficiary.pubkey(),
+            other_sponsor.pubkey(),
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            other_sponsor_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_STANDARD_PROGRAM_ID,
+            1,
+            10_000000
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -7791,7 +21538,7 @@ async fn test_close_lottery_account_usdc_token_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_auth
+                &other_sponsor
             ],
             ptc.last_blockhash
         );
@@ -7808,48 +21555,33 @@ async fn test_close_lottery_account_usdc_token_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidLotteryState as u32
+                    LotteryError::SponsorMismatch as u32
                 )
             )
         );
-
-        // return to default
-        lottery_account.minimum_tickets_amount_required_to_be_sold = 100;
-        lottery_account.tickets_total_amount = 90;
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(
-                SolanaAccount {
-                    owner: LOTTERY_PROGRAM_ID,
-                    lamports: sol_to_lamports(1.0),
-                    data: vec![
-                        lottery_account.try_to_vec().unwrap(),
-                        vec![0u8; 330]
-                    ].concat(),
-                    ..SolanaAccount::default()
-                }
-            )
-        );
     }
-    // failure - lottery invalid state
+    // failure - user account is sponsored by another sponsor
 
-    // success
+    // failure - invalid user's tickets amount
     {
-        ptc.
-            get_new_latest_blockhash()
+        ptc
+            .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
 
-        let instruction = instruction_close_lottery_account_and_usdc_token_account(
-            config_account_pda.0, 
-            lottery_account_pda.0, 
-            lottery_auth.pubkey(), 
-            config_account.usdc_mint_account, 
-            lottery_usdc_ata_pubkey, 
-            fund_receiver_usdc_token_account_pubkey, 
-            lottery_auth.pubkey(), 
-            TOKEN_STANDARD_PROGRAM_ID
+        let instruction = instruction_buy_ticket_for(
+            config_account_pda.0,
+            user_account_pda.0,
+            beneficiary.pubkey(),
+            sponsor.pubkey(),
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            sponsor_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_STANDARD_PROGRAM_ID,
+            8,
+            10_000000
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -7857,100 +21589,51 @@ async fn test_close_lottery_account_usdc_token_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_auth
+                &sponsor
             ],
             ptc.last_blockhash
         );
 
-        ptc
-            .banks_client
-            .process_transaction(tx)
-            .await
-            .unwrap();
-
-        let error = ptc
-            .banks_client
-            .get_account(lottery_account_pda.0)
-            .await
-            .unwrap();
-        if error.is_some() {
-            panic!("Lottery account must be closed.");
-        };
-
         let error = ptc
             .banks_client
-            .get_account(lottery_usdc_ata_pubkey)
-            .await
-            .unwrap();
-        if error.is_some() {
-            panic!("Lottery USDC ata account must be closed.");
-        };
-
-        let SolanaAccount { data: fund_receiver_usdc_token_account_data, .. } = ptc
-            .banks_client
-            .get_account(fund_receiver_usdc_token_account_pubkey)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let TokenAccount { amount: fund_receiver_usdc_token_account_balance, .. } = TokenAccount::unpack(
-            &fund_receiver_usdc_token_account_data
-        ).unwrap();
-
-        assert_eq!(
-            fund_receiver_usdc_token_account_balance,
-            10_000000,
-            "invalid fund receiver usdc token account balance."
-        );
-
-        let SolanaAccount { lamports: fund_receiver_rent_exempt_lamports_balance , ..} = ptc
-            .banks_client
-            .get_account(lottery_auth.pubkey())
+            .process_transaction(tx)
             .await
-            .unwrap()
+            .unwrap_err()
             .unwrap();
 
         assert_eq!(
-            fund_receiver_rent_exempt_lamports_balance,
-            sol_to_lamports(1.0) + sol_to_lamports(1.0) + sol_to_lamports(1.0),
-            "invalid rent exempt fund receiver lamport balance."
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::MaxTicketsAmountViolated as u32
+                )
+            )
         );
     }
-    // success
+    // failure - invalid user's tickets amount
 
-    // failure - lottery owner must first close Arbitrary ata
+    // success - beneficiary buys tickets with its own funds
     {
-        ptc.
-            get_new_latest_blockhash()
+        ptc
+            .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 450);
-
-        lottery_account.is_creator_withdrawed_when_lottery_was_failed = false;
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(
-                SolanaAccount {
-                    owner: LOTTERY_PROGRAM_ID,
-                    lamports: sol_to_lamports(1.0),
-                    data: vec![
-                        lottery_account.try_to_vec().unwrap(),
-                        vec![0u8; 330]
-                    ].concat(),
-                    ..SolanaAccount::default()
-                }
-            )
-        );
 
-        let instruction = instruction_close_lottery_account_and_usdc_token_account(
-            config_account_pda.0, 
-            lottery_account_pda.0, 
-            lottery_auth.pubkey(), 
-            config_account.usdc_mint_account, 
-            lottery_usdc_ata_pubkey, 
-            fund_receiver_usdc_token_account_pubkey, 
-            lottery_auth.pubkey(), 
-            TOKEN_STANDARD_PROGRAM_ID
+        let instruction = instruction_buy_ticket(
+            config_account_pda.0,
+            user_account_pda.0,
+            beneficiary.pubkey(),
+            beneficiary.pubkey(),
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            beneficiary_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_STANDARD_PROGRAM_ID,
+            2,
+            10_000000,
+            false
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -7958,80 +21641,44 @@ async fn test_close_lottery_account_usdc_token_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_auth
+                &beneficiary
             ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
-
-        assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::FirstCloseLotteryArbitrartAssociatedTokenAccount as u32
-                )
-            )
-        );
-
-        // return to default
-        lottery_account.is_creator_withdrawed_when_lottery_was_failed = true;
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(
-                SolanaAccount {
-                    owner: LOTTERY_PROGRAM_ID,
-                    lamports: sol_to_lamports(1.0),
-                    data: vec![
-                        lottery_account.try_to_vec().unwrap(),
-                        vec![0u8; 330]
-                    ].concat(),
-                    ..SolanaAccount::default()
-                }
-            )
-        );
     }
-    // failure - lottery owner must first close Arbitrary ata
+    // success - beneficiary buys tickets with its own funds
 
-    // failure - users didn't claim their funds from lottery account
+    // failure - failed lottery, missing sponsor's usdc token account
     {
-        ptc.
-            get_new_latest_blockhash()
+        change_clock_sysvar(
+            &ptc,
+            250
+        );
+        ptc
+            .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 450);
-
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(
-                SolanaAccount {
-                    owner: LOTTERY_PROGRAM_ID,
-                    lamports: sol_to_lamports(1.1),
-                    data: vec![
-                        lottery_account.try_to_vec().unwrap(),
-                        vec![0u8; 330],
-                        vec![0u8, 96]
-                    ].concat(),
-                    ..SolanaAccount::default()
-                }
-            )
-        );
-
-        let instruction = instruction_close_lottery_account_and_usdc_token_account(
-            config_account_pda.0, 
-            lottery_account_pda.0, 
-            lottery_auth.pubkey(), 
-            config_account.usdc_mint_account, 
-            lottery_usdc_ata_pubkey, 
-            fund_receiver_usdc_token_account_pubkey, 
-            lottery_auth.pubkey(), 
-            TOKEN_STANDARD_PROGRAM_ID
+
+        let instruction = instruction_withdraw_and_close_failed_user(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            user_account_pda.0,
+            beneficiary.pubkey(),
+            config_account.usdc_mint_account,
+            lottery_usdc_ata_pubkey,
+            beneficiary_usdc_token_account_pubkey,
+            beneficiary.pubkey(),
+            beneficiary.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None,
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -8039,7 +21686,7 @@ async fn test_close_lottery_account_usdc_token_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &lottery_auth
+                &beneficiary
             ],
             ptc.last_blockhash
         );
@@ -8056,165 +21703,173 @@ async fn test_close_lottery_account_usdc_token_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::CannotCloseAccounts as u32
+                    LotteryError::InvalidAmountOfSponsorAccounts as u32
                 )
             )
         );
     }
-    // failure - users didn't claim their funds from lottery account
-}
+    // failure - failed lottery, missing sponsor's usdc token account
 
-#[tokio::test]
-async fn test_withdraw_and_close_succeed_user() {
-    let lottery_program_id = LOTTERY_PROGRAM_ID;
-    let mut pt = setup_program_test(lottery_program_id);
+    // failure - failed lottery, sponsored tickets refunded to a non-sponsor token account
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-    //////////////////////? add config account
-    let config_account_pda = Pubkey::find_program_address(
-        &[
-            b"solottery_program_config_account"
-        ],
-        &lottery_program_id
-    );
+        let instruction = instruction_withdraw_and_close_failed_user(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            user_account_pda.0,
+            beneficiary.pubkey(),
+            config_account.usdc_mint_account,
+            lottery_usdc_ata_pubkey,
+            beneficiary_usdc_token_account_pubkey,
+            beneficiary.pubkey(),
+            beneficiary.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            Some(beneficiary_usdc_token_account_pubkey),
+            None
+        );
 
-    let config_account = Config {
-        discriminator: Config::get_discriminator(),
-        canonical_bump: config_account_pda.1,
-        usdc_mint_account: Pubkey::new_from_array([1; 32]),
-        ..Config::default()
-    };
-    let config_account_data = config_account.try_to_vec().unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &beneficiary
+            ],
+            ptc.last_blockhash
+        );
 
-    let config_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        data: config_account_data,
-        lamports: sol_to_lamports(0.0009),
-        ..SolanaAccount::default()
-    };
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
 
-    pt.add_account(
-        config_account_pda.0,
-        config_solana_account
-    );
-    //////////////////////? add config account
-    
-    //////////////////////? add lottery account
-    let lottery_account_pda = Pubkey::find_program_address(
-        &[
-            b"lottery_account",
-            Pubkey::default().to_bytes().as_slice(),
-            get_lottery_literal_seed(&String::from("1")).as_slice()
-        ],
-        &lottery_program_id
-    );
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidSponsorUsdcTokenAccount as u32
+                )
+            )
+        );
+    }
+    // failure - failed lottery, sponsored tickets refunded to a non-sponsor token account
 
-    let mut lottery_account = Lottery {
-        discriminator: Lottery::get_discriminator(),
-        canonical_bump: lottery_account_pda.1,
-        starting_time: 100,
-        ending_time: 200,
-        lottery_description: String::from("1"),
-        minimum_tickets_amount_required_to_be_sold: 100,
-        tickets_total_amount: 900,
-        is_ended_successfuly: true,
-        ..Lottery::default()
-    };
-    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+    // success - failed lottery refunds the sponsored tickets to the sponsor
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-    let lottey_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-        data: vec![
-            lottery_account.try_to_vec().unwrap(),
-            vec![0u8; 320]
-        ].concat(),
-        ..SolanaAccount::default()
-    };
+        let instruction = instruction_withdraw_and_close_failed_user(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            user_account_pda.0,
+            beneficiary.pubkey(),
+            config_account.usdc_mint_account,
+            lottery_usdc_ata_pubkey,
+            beneficiary_usdc_token_account_pubkey,
+            beneficiary.pubkey(),
+            beneficiary.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            Some(sponsor_usdc_token_account_pubkey),
+            None
+        );
 
-    pt.add_account(
-        lottery_account_pda.0,
-        lottey_solana_account
-    );
-    //////////////////////? add lottery account
-    
-    //////////////////////? add user account
-    let user_account_auth = Keypair::new();
-    pt.add_account(
-        user_account_auth.pubkey(),
-        SolanaAccount {
-            lamports: sol_to_lamports(1.0),
-            ..SolanaAccount::default()
-        }
-    );
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &beneficiary
+            ],
+            ptc.last_blockhash
+        );
 
-    let user_account_pda = Pubkey::find_program_address(
-        &[
-            b"user_account",
-            user_account_auth.pubkey().to_bytes().as_slice(),
-            &lottery_account_pda.0.to_bytes().as_slice()
-        ],
-        &lottery_program_id
-    );
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
 
-    let user_account = User {
-        discriminator: User::get_discriminator(),
-        canonical_bump: user_account_pda.1,
-        authority: user_account_auth.pubkey(),
-        lottery: lottery_account_pda.0,
-        total_rent_exempt_paied: 100000,
-        total_tickets_acquired: 10,
-        total_tickets_value: 100_000000, // USDC
-        ..User::default()
-    };
+        for (token_account_pubkey, expected_balance, message) in [
+            (sponsor_usdc_token_account_pubkey, 100_000000, "invalid sponsor usdc token account balance."),
+            (beneficiary_usdc_token_account_pubkey, 20_000000, "invalid beneficiary usdc token account balance."),
+            (lottery_usdc_ata_pubkey, 0, "invalid lottery USDC ata token balance.")
+        ] {
+            let SolanaAccount { data: token_account_data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount, .. } = TokenAccount::unpack(
+                &token_account_data
+            ).unwrap();
+
+            assert_eq!(amount, expected_balance, "{}", message);
+        };
 
-    let user_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        lamports: sol_to_lamports(1.0),
-        data: user_account.try_to_vec().unwrap(),
-        ..SolanaAccount::default()
-    };
-    
-    pt.add_account(
-        user_account_pda.0,
-        user_solana_account
-    );
-    //////////////////////? add user account
-    
-    let mut ptc = pt.start_with_context().await;
+        let error = ptc
+            .banks_client
+            .get_account(user_account_pda.0)
+            .await
+            .unwrap();
+        if error.is_some() {
+            panic!("Account must be closed.");
+        };
+    }
+    // success - failed lottery refunds the sponsored tickets to the sponsor
 
-    // failure - user is one of the winners
+    // success - token-2022 usdc mint with a 1% transfer fee, the sponsor pays the fee on top of the tickets price
     {
-        ptc.
-            get_new_latest_blockhash()
+        change_clock_sysvar(
+            &ptc,
+            150
+        );
+        ptc
+            .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
 
-        lottery_account.winners.push(
-            (user_account_pda.0, false)
-        );
-        let lottey_solana_account = SolanaAccount {
-            owner: lottery_program_id,
-            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-            data: vec![
-                lottery_account.try_to_vec().unwrap(),
-                vec![0u8; 320]
-            ].concat(),
-            ..SolanaAccount::default()
-        };
-    
         ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(lottey_solana_account)
+            &config_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    data: Config {
+                        usdc_mint_account: token_2022_usdc_mint_account_pubkey,
+                        ..config_account.clone()
+                    }.try_to_vec().unwrap(),
+                    lamports: sol_to_lamports(0.0009),
+                    ..SolanaAccount::default()
+                }
+            )
         );
 
-        let instruction = instruction_withdraw_and_close_succeed_user(
-            lottery_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(),
-            config_account_pda.0
+        let instruction = instruction_buy_ticket_for(
+            config_account_pda.0,
+            token_2022_user_account_pda.0,
+            beneficiary.pubkey(),
+            sponsor.pubkey(),
+            token_2022_lottery_account_pda.0,
+            token_2022_lottery_usdc_ata_pubkey,
+            token_2022_sponsor_usdc_token_account_pubkey,
+            token_2022_usdc_mint_account_pubkey,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_2022_PROGRAM_ID,
+            2,
+            10_000000
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -8222,62 +21877,78 @@ async fn test_withdraw_and_close_succeed_user() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &user_account_auth
+                &sponsor
             ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
-        assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::UserIsOneOfTheWinners as u32
-                )
-            )
-        );
+        let SolanaAccount { data: user_account_data, .. } = ptc
+            .banks_client
+            .get_account(token_2022_user_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
 
-        // return to default
-        lottery_account.winners = vec![];
-        let lottey_solana_account = SolanaAccount {
-            owner: lottery_program_id,
-            lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-            data: vec![
-                lottery_account.try_to_vec().unwrap(),
-                vec![0u8; 320]
-            ].concat(),
-            ..SolanaAccount::default()
-        };
+        let user_account = User::deserialize(
+            &mut &user_account_data[..]
+        ).unwrap();
 
-        ptc.set_account(
-            &lottery_account_pda.0,
-            &SolanaSharedDataAccount::from(lottey_solana_account)
-        );
+        assert_eq!(user_account.total_tickets_value, 20_000000, "invalid user account's total_tickets_value.");
+        assert_eq!(user_account.total_sponsored_tickets_value, 20_000000, "invalid user account's total_sponsored_tickets_value.");
+
+        // 20 USDC + the 1% transfer fee of the pre-fee amount (20.202021 USDC)
+        for (token_account_pubkey, expected_balance, message) in [
+            (token_2022_sponsor_usdc_token_account_pubkey, 100_000000 - 20_202021, "invalid sponsor usdc token account balance."),
+            (token_2022_lottery_usdc_ata_pubkey, 20_000000, "invalid lottery USDC ata token balance.")
+        ] {
+            let SolanaAccount { data: token_account_data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(
+                StateWithExtensions::<Token2022Account>::unpack(&token_account_data).unwrap().base.amount,
+                expected_balance,
+                "{}",
+                message
+            );
+        };
     }
-    // failure - user is one of the winners
+    // success - token-2022 usdc mint with a 1% transfer fee, the sponsor pays the fee on top of the tickets price
 
-    // success
+    // success - token-2022 usdc mint, failed lottery refunds the sponsored tickets minus the transfer fee
     {
-        ptc.
-            get_new_latest_blockhash()
+        change_clock_sysvar(
+            &ptc,
+            250
+        );
+        ptc
+            .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(&ptc, 350);
 
-        let instruction = instruction_withdraw_and_close_succeed_user(
-            lottery_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(),
-            config_account_pda.0
+        let instruction = instruction_withdraw_and_close_failed_user(
+            config_account_pda.0,
+            token_2022_lottery_account_pda.0,
+            token_2022_user_account_pda.0,
+            beneficiary.pubkey(),
+            token_2022_usdc_mint_account_pubkey,
+            token_2022_lottery_usdc_ata_pubkey,
+            token_2022_beneficiary_usdc_token_account_pubkey,
+            beneficiary.pubkey(),
+            beneficiary.pubkey(),
+            TOKEN_2022_PROGRAM_ID,
+            None,
+            Some(token_2022_sponsor_usdc_token_account_pubkey),
+            None
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -8285,7 +21956,7 @@ async fn test_withdraw_and_close_succeed_user() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &user_account_auth
+                &beneficiary
             ],
             ptc.last_blockhash
         );
@@ -8296,56 +21967,40 @@ async fn test_withdraw_and_close_succeed_user() {
             .await
             .unwrap();
 
+        for (token_account_pubkey, expected_balance, message) in [
+            (token_2022_sponsor_usdc_token_account_pubkey, 100_000000 - 20_202021 + 19_800000, "invalid sponsor usdc token account balance."),
+            (token_2022_beneficiary_usdc_token_account_pubkey, 0, "invalid beneficiary usdc token account balance."),
+            (token_2022_lottery_usdc_ata_pubkey, 0, "invalid lottery USDC ata token balance.")
+        ] {
+            let SolanaAccount { data: token_account_data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(
+                StateWithExtensions::<Token2022Account>::unpack(&token_account_data).unwrap().base.amount,
+                expected_balance,
+                "{}",
+                message
+            );
+        };
+
         let error = ptc
             .banks_client
-            .get_account(user_account_pda.0)
+            .get_account(token_2022_user_account_pda.0)
             .await
             .unwrap();
         if error.is_some() {
             panic!("Account must be closed.");
         };
-
-        let SolanaAccount { lamports: lottery_account_lamport_balance, data, .. } = ptc
-            .banks_client
-            .get_account(lottery_account_pda.0)
-            .await
-            .unwrap()
-            .unwrap();
-        
-        assert_eq!(
-            lottery_account_lamport_balance,
-            sol_to_lamports(1.0) - 100000,
-            "invalid lottery account lamport balance."
-        );
-
-        assert_eq!(
-            data.len(),
-            lottery_account.try_to_vec().unwrap().len(),
-            "invalid lottery account data size."
-        );
-
-        let SolanaAccount { lamports: user_account_auth_lamport_balance, .. } = ptc
-            .banks_client
-            .get_account(user_account_auth.pubkey())
-            .await
-            .unwrap()
-            .unwrap();
-
-        assert_eq!(
-            user_account_auth_lamport_balance,
-            sol_to_lamports(1.0) +
-            100000 + // tickets rent exempt lamports
-            sol_to_lamports(1.0), // user account's lamport balance
-            "invalid user account authority's lamport balance."
-        );
     }
-    // success
+    // success - token-2022 usdc mint, failed lottery refunds the sponsored tickets minus the transfer fee
 }
-////////////////////////////////////// Lottery Instructions
 
-////////////////////////////////////// User Instructions
 #[tokio::test]
-async fn test_create_and_initialize_user_account() {
+async fn test_transfer_tickets() {
     let lottery_program_id = LOTTERY_PROGRAM_ID;
     let mut pt = setup_program_test(lottery_program_id);
 
@@ -8360,12 +22015,7 @@ async fn test_create_and_initialize_user_account() {
     let config_account = Config {
         discriminator: Config::get_discriminator(),
         canonical_bump: config_account_pda.1,
-        lottery_creation_fee: 5_000000, // 5 USDC
-        maximum_number_of_winners: 10,
-        usdc_mint_account: Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap(),
-        maximum_time_for_lottery_account: 1000,
-        minimum_tickets_to_be_sold_in_lottery: 20,
-        max_lottery_description_bytes: 300,
+        usdc_mint_account: Pubkey::new_from_array([1; 32]),
         ..Config::default()
     };
     let config_account_data = config_account.try_to_vec().unwrap();
@@ -8382,8 +22032,8 @@ async fn test_create_and_initialize_user_account() {
         config_solana_account
     );
     //////////////////////? add config account
-    
-    //////////////////////? add lottery account
+
+    //////////////////////? add users
     let lottery_account_pda = Pubkey::find_program_address(
         &[
             b"lottery_account",
@@ -8393,21 +22043,91 @@ async fn test_create_and_initialize_user_account() {
         &lottery_program_id
     );
 
-    let lottery_account = Lottery {
+    let sender = Keypair::new();
+    let receiver = Keypair::new();
+    let other_user = Keypair::new();
+
+    let mut user_accounts_pda: Vec<(Pubkey, u8)> = Vec::new();
+    for (user_account_auth, total_tickets_acquired) in [
+        (&sender, 3),
+        (&receiver, 0),
+        (&other_user, 1)
+    ] {
+        pt.add_account(
+            user_account_auth.pubkey(),
+            SolanaAccount {
+                lamports: sol_to_lamports(1.0),
+                ..SolanaAccount::default()
+            }
+        );
+
+        let user_account_pda = Pubkey::find_program_address(
+            &[
+                b"user_account",
+                user_account_auth.pubkey().to_bytes().as_slice(),
+                &lottery_account_pda.0.to_bytes().as_slice()
+            ],
+            &lottery_program_id
+        );
+
+        let user_account = User {
+            discriminator: User::get_discriminator(),
+            canonical_bump: user_account_pda.1,
+            authority: user_account_auth.pubkey(),
+            lottery: lottery_account_pda.0,
+            total_tickets_acquired,
+            total_ticket_ranges_acquired: if total_tickets_acquired > 0 { 1 } else { 0 },
+            total_tickets_value: total_tickets_acquired as u64 * 10_000000,
+            total_rent_exempt_paied: if total_tickets_acquired > 0 { 1000 } else { 0 },
+            ..User::default()
+        };
+
+        pt.add_account(
+            user_account_pda.0,
+            SolanaAccount {
+                owner: lottery_program_id,
+                lamports: sol_to_lamports(1.0),
+                data: vec![
+                    user_account.try_to_vec().unwrap(),
+                    vec![0u8; User::LEN - user_account.try_to_vec().unwrap().len()]
+                ].concat(),
+                ..SolanaAccount::default()
+            }
+        );
+
+        user_accounts_pda.push(user_account_pda);
+    };
+    let (sender_user_account_pda, receiver_user_account_pda, other_user_account_pda) = (
+        user_accounts_pda[0].0,
+        user_accounts_pda[1].0,
+        user_accounts_pda[2].0
+    );
+    //////////////////////? add users
+
+    //////////////////////? add lottery account
+    let mut lottery_account = Lottery {
         discriminator: Lottery::get_discriminator(),
         canonical_bump: lottery_account_pda.1,
-        lottery_creation_fee: 5_000000,
-        protocol_fee: 300_000000,
         starting_time: 100,
         ending_time: 200,
         lottery_description: String::from("1"),
+        ticket_price: 10_000000,
+        maximum_number_of_tickets_per_user: Some(2),
+        tickets_total_amount: 4,
+        ticket_ranges_total_amount: 2,
         ..Lottery::default()
     };
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
 
+    // ticket ledger -> sender [0, 3), other user [3, 4)
     let lottey_solana_account = SolanaAccount {
         owner: lottery_program_id,
         lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-        data: lottery_account.try_to_vec().unwrap(),
+        data: vec![
+            lottery_account.try_to_vec().unwrap(),
+            (sender_user_account_pda, 3u32).try_to_vec().unwrap(),
+            (other_user_account_pda, 4u32).try_to_vec().unwrap()
+        ].concat(),
         ..SolanaAccount::default()
     };
 
@@ -8416,43 +22136,37 @@ async fn test_create_and_initialize_user_account() {
         lottey_solana_account
     );
     //////////////////////? add lottery account
-    
+
     let mut ptc = pt.start_with_context().await;
 
-    // failure - invalid lottery state
+    // failure - receiver's maximum tickets amount violated
     {
-        change_clock_sysvar(&ptc, 35);
+        change_clock_sysvar(
+            &ptc,
+            150
+        );
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
 
-        let user_account_authority = Keypair::new();
-        let user_account_pda = Pubkey::find_program_address(
-            &[
-                b"user_account",
-                user_account_authority.pubkey().to_bytes().as_slice(),
-                lottery_account_pda.0.to_bytes().as_slice()
-            ],
-            &lottery_program_id
-        );
-    
-        let instruction = instruction_create_and_initialize_user_account(
-            user_account_pda.0,
-            user_account_authority.pubkey(),
-            ptc.payer.pubkey(), 
+        let instruction = instruction_transfer_tickets(
+            config_account_pda.0,
             lottery_account_pda.0,
-            SYSTEM_PROGRAM_ID, 
-            config_account_pda.0
+            sender_user_account_pda,
+            sender.pubkey(),
+            receiver_user_account_pda,
+            SYSTEM_PROGRAM_ID,
+            3
         );
-    
+
         let tx = Transaction::new_signed_with_payer(
             &[ instruction ],
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &user_account_authority
-            ], 
+                &sender
+            ],
             ptc.last_blockhash
         );
 
@@ -8468,104 +22182,37 @@ async fn test_create_and_initialize_user_account() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::InvalidLotteryState as u32
+                    LotteryError::MaxTicketsAmountViolated as u32
                 )
             )
         );
     }
-    // failure - invalid lottery state
+    // failure - receiver's maximum tickets amount violated
 
-    // failure - invalid seeds 
+    // failure - sender is the receiver
     {
-        change_clock_sysvar(&ptc, 150);
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
 
-        let user_account_authority = Keypair::new();
-        let user_account_pda = Pubkey::find_program_address(
-            &[
-                b"user_accounT",
-                user_account_authority.pubkey().to_bytes().as_slice(),
-                lottery_account_pda.0.to_bytes().as_slice()
-            ],
-            &lottery_program_id
-        );
-    
-        let instruction = instruction_create_and_initialize_user_account(
-            user_account_pda.0,
-            user_account_authority.pubkey(),
-            ptc.payer.pubkey(), 
+        let instruction = instruction_transfer_tickets(
+            config_account_pda.0,
             lottery_account_pda.0,
-            SYSTEM_PROGRAM_ID, 
-            config_account_pda.0
+            sender_user_account_pda,
+            sender.pubkey(),
+            sender_user_account_pda,
+            SYSTEM_PROGRAM_ID,
+            1
         );
-    
+
         let tx = Transaction::new_signed_with_payer(
             &[ instruction ],
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &user_account_authority
-            ], 
-            ptc.last_blockhash
-        );
-
-        let error = ptc
-            .banks_client
-            .process_transaction(tx)
-            .await
-            .unwrap_err()
-            .unwrap();
-
-        assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::InvalidSeeds
-            )
-        );
-    }
-    // failure - invalid seeds
-
-    // failure - trying to create user_account twice
-    {
-        change_clock_sysvar(&ptc, 150);
-        ptc
-            .get_new_latest_blockhash()
-            .await
-            .unwrap();
-
-        let user_account_authority = Keypair::new();
-        let user_account_pda = Pubkey::find_program_address(
-            &[
-                b"user_account",
-                user_account_authority.pubkey().to_bytes().as_slice(),
-                lottery_account_pda.0.to_bytes().as_slice()
-            ],
-            &lottery_program_id
-        );
-
-        let instruction = instruction_create_and_initialize_user_account(
-            user_account_pda.0,
-            user_account_authority.pubkey(),
-            ptc.payer.pubkey(), 
-            lottery_account_pda.0,
-            SYSTEM_PROGRAM_ID, 
-            config_account_pda.0
-        );
-
-        let tx = Transaction::new_signed_with_payer(
-            &[ 
-                instruction.clone(),
-                instruction
+                &sender
             ],
-            Some(&ptc.payer.pubkey()),
-            &[
-                &ptc.payer,
-                &user_account_authority
-            ], 
             ptc.last_blockhash
         );
 
@@ -8579,43 +22226,30 @@ async fn test_create_and_initialize_user_account() {
         assert_eq!(
             error,
             TransactionError::InstructionError(
-                1,
+                0,
                 InstructionError::Custom(
-                    LotteryError::AccountMustBeRaw as u32
+                    LotteryError::InvalidTicketsReceiver as u32
                 )
-                // InstructionError::Custom(
-                //     solana_sdk::system_instruction::SystemError::AccountAlreadyInUse as u32
-                // )
             )
         );
     }
-    // failure - trying to create user_account twice
+    // failure - sender is the receiver
 
-    // success
+    // success - 2 of the sender's 3 tickets, its ticket-range is split
     {
-        change_clock_sysvar(&ptc, 150);
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
 
-        let user_account_authority = Keypair::new();
-        let user_account_pda = Pubkey::find_program_address(
-            &[
-                b"user_account",
-                user_account_authority.pubkey().to_bytes().as_slice(),
-                lottery_account_pda.0.to_bytes().as_slice()
-            ],
-            &lottery_program_id
-        );
-
-        let instruction = instruction_create_and_initialize_user_account(
-            user_account_pda.0,
-            user_account_authority.pubkey(),
-            ptc.payer.pubkey(), 
+        let instruction = instruction_transfer_tickets(
+            config_account_pda.0,
             lottery_account_pda.0,
-            SYSTEM_PROGRAM_ID, 
-            config_account_pda.0
+            sender_user_account_pda,
+            sender.pubkey(),
+            receiver_user_account_pda,
+            SYSTEM_PROGRAM_ID,
+            2
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -8623,8 +22257,8 @@ async fn test_create_and_initialize_user_account() {
             Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &user_account_authority
-            ], 
+                &sender
+            ],
             ptc.last_blockhash
         );
 
@@ -8634,50 +22268,130 @@ async fn test_create_and_initialize_user_account() {
             .await
             .unwrap();
 
-        let SolanaAccount { data: user_account_data, owner: user_account_owner, .. } = ptc
+        let rent = ptc.banks_client.get_rent().await.unwrap();
+        let ticket_range_rent_exempt = (rent.lamports_per_byte_year * 36) * rent.exemption_threshold as u64;
+
+        let SolanaAccount { data: lottery_account_data, lamports: lottery_account_lamports, .. } = ptc
             .banks_client
-            .get_account(user_account_pda.0)
-            .await.unwrap().unwrap();
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let updated_lottery_account = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
 
+        assert_eq!(updated_lottery_account.tickets_total_amount, 4, "invalid lottery's tickets_total_amount.");
+        assert_eq!(updated_lottery_account.ticket_ranges_total_amount, 3, "invalid lottery's ticket_ranges_total_amount.");
         assert_eq!(
-            user_account_owner,
-            LOTTERY_PROGRAM_ID,
-            "invalid user_account's owner."
+            lottery_account_lamports,
+            sol_to_lamports(1.0) + ticket_range_rent_exempt,
+            "invalid lottery account lamport balance."
         );
 
+        // ticket ledger -> sender [0, 1), receiver [1, 3), other user [3, 4)
         assert_eq!(
-            User::LEN,
-            user_account_data.len(),
-            "invalid user_account's data len."
+            lottery_account_data[lottery_account.initial_bytes as usize..].to_vec(),
+            vec![
+                (sender_user_account_pda, 1u32).try_to_vec().unwrap(),
+                (receiver_user_account_pda, 3u32).try_to_vec().unwrap(),
+                (other_user_account_pda, 4u32).try_to_vec().unwrap()
+            ].concat(),
+            "invalid ticket ledger."
         );
 
-        let User { authority, lottery, created_at, .. } = User::deserialize(
-            &mut &user_account_data[..]
+        let SolanaAccount { data: sender_user_account_data, .. } = ptc
+            .banks_client
+            .get_account(sender_user_account_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let sender_user_account = User::deserialize(
+            &mut &sender_user_account_data[..]
+        ).unwrap();
+
+        assert_eq!(sender_user_account.total_tickets_acquired, 1, "invalid sender's total_tickets_acquired.");
+        assert_eq!(sender_user_account.total_ticket_ranges_acquired, 1, "invalid sender's total_ticket_ranges_acquired.");
+        assert_eq!(sender_user_account.total_tickets_value, 10_000000, "invalid sender's total_tickets_value.");
+        assert_eq!(sender_user_account.total_rent_exempt_paied, 1000, "invalid sender's total_rent_exempt_paied.");
+
+        let SolanaAccount { data: receiver_user_account_data, .. } = ptc
+            .banks_client
+            .get_account(receiver_user_account_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let receiver_user_account = User::deserialize(
+            &mut &receiver_user_account_data[..]
         ).unwrap();
 
+        assert_eq!(receiver_user_account.total_tickets_acquired, 2, "invalid receiver's total_tickets_acquired.");
+        assert_eq!(receiver_user_account.total_ticket_ranges_acquired, 1, "invalid receiver's total_ticket_ranges_acquired.");
+        assert_eq!(receiver_user_account.total_tickets_value, 20_000000, "invalid receiver's total_tickets_value.");
         assert_eq!(
-            authority,
-            user_account_authority.pubkey(),
-            "invalid user_account's authority."
+            receiver_user_account.total_rent_exempt_paied,
+            ticket_range_rent_exempt,
+            "invalid receiver's total_rent_exempt_paied."
         );
+    }
+    // success - 2 of the sender's 3 tickets, its ticket-range is split
 
-        assert_eq!(
-            lottery,
+    // failure - lottery ended
+    {
+        change_clock_sysvar(
+            &ptc,
+            250
+        );
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = instruction_transfer_tickets(
+            config_account_pda.0,
             lottery_account_pda.0,
-            "invalid user_account's lottery account."
+            sender_user_account_pda,
+            sender.pubkey(),
+            other_user_account_pda,
+            SYSTEM_PROGRAM_ID,
+            1
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &sender
+            ],
+            ptc.last_blockhash
         );
 
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
         assert_eq!(
-            created_at,
-            150,
-            "invalid created_at timestamp."
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryState as u32
+                )
+            )
         );
     }
-    // success
+    // failure - lottery ended
 }
 
 #[tokio::test]
-async fn test_buy_tickets() {
+async fn test_ticket_tokens() {
     let lottery_program_id = LOTTERY_PROGRAM_ID;
     let mut pt = setup_program_test(lottery_program_id);
 
@@ -8690,472 +22404,776 @@ async fn test_buy_tickets() {
     );
 
     let config_account = Config {
-        discriminator: Config::get_discriminator(),
-        canonical_bump: config_account_pda.1,
-        lottery_creation_fee: 5_000000, // 5 USDC
-        maximum_number_of_winners: 10,
-        usdc_mint_account: Pubkey::new_from_array([1; 32]),
-        maximum_time_for_lottery_account: 1000,
-        minimum_tickets_to_be_sold_in_lottery: 20,
-        max_lottery_description_bytes: 300,
-        lottery_tickets_fee: 2.5,
-        ..Config::default()
-    };
-    let config_account_data = config_account.try_to_vec().unwrap();
-
-    let config_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        data: config_account_data,
-        lamports: sol_to_lamports(0.0009),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        config_account_pda.0,
-        config_solana_account
-    );
-    //////////////////////? add config account
-    
-    //////////////////////? add lottery account
-    let lottery_account_pda = Pubkey::find_program_address(
-        &[
-            b"lottery_account",
-            Pubkey::default().to_bytes().as_slice(),
-            get_lottery_literal_seed(&String::from("1")).as_slice()
-        ],
-        &lottery_program_id
-    );
-
-    let mut lottery_account = Lottery {
-        discriminator: Lottery::get_discriminator(),
-        canonical_bump: lottery_account_pda.1,
-        starting_time: 100,
-        ending_time: 200,
-        lottery_description: String::from("1"),
-        ticket_price: 10_000000,
-        maximum_number_of_tickets_per_user: Some(100),
-        ..Lottery::default()
-    };
-    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
-
-    let lottey_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-        data: lottery_account.try_to_vec().unwrap(),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        lottery_account_pda.0,
-        lottey_solana_account
-    );
-    //////////////////////? add lottery account
-    
-    //////////////////////? add user account
-    let user_account_auth = Keypair::new();
-    pt.add_account(
-        user_account_auth.pubkey(),
-        SolanaAccount {
-            lamports: sol_to_lamports(1.0),
-            ..SolanaAccount::default()
-        }
-    );
-
-    let user_account_pda = Pubkey::find_program_address(
-        &[
-            b"user_account",
-            user_account_auth.pubkey().to_bytes().as_slice(),
-            &lottery_account_pda.0.to_bytes().as_slice()
-        ],
-        &lottery_program_id
-    );
-
-    let user_account = User {
-        discriminator: User::get_discriminator(),
-        canonical_bump: user_account_pda.1,
-        authority: user_account_auth.pubkey(),
-        lottery: lottery_account_pda.0,
-        ..User::default()
-    };
-
-    let user_solana_account = SolanaAccount {
-        owner: lottery_program_id,
-        lamports: sol_to_lamports(1.0),
-        data: user_account.try_to_vec().unwrap(),
-        ..SolanaAccount::default()
-    };
-    
-    pt.add_account(
-        user_account_pda.0,
-        user_solana_account
-    );
-    //////////////////////? add user account
-    
-    //////////////////////? add mint account
-    let mint_account_pubkey = Pubkey::new_from_array([1; 32]);
-    let mint_account = MintAccount {
-        supply: 100_000000,
-        decimals: 6,
-        is_initialized: true,
-        ..MintAccount::default()
-    };
-
-    let mut mint_account_data = [0u8; MintAccount::LEN];
-    MintAccount::pack(
-        mint_account,
-        mint_account_data.as_mut_slice()
-    ).unwrap();
-
-    let mint_solana_account = SolanaAccount {
-        owner: spl_token::ID,
-        data: mint_account_data.to_vec(),
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        mint_account_pubkey,
-        mint_solana_account
-    );
-    //////////////////////? add mint account
-    
-    //////////////////////? add lottery ata
-    let lottery_ata_pda = get_associated_token_address(
-        &lottery_account_pda.0,
-        &mint_account_pubkey
-    );
-    let lottery_ata = TokenAccount {
-        state: TokenAccountState::Initialized,
-        mint: mint_account_pubkey,
-        ..TokenAccount::default()
-    };
-
-    let mut lottery_ata_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        lottery_ata,
-        lottery_ata_data.as_mut_slice()
-    ).unwrap();
-
-    let lottery_ata_solana_account = SolanaAccount {
-        owner: spl_token::ID,
-        data: lottery_ata_data.to_vec(),
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
-
-    pt.add_account(
-        lottery_ata_pda,
-        lottery_ata_solana_account
-    );
-    //////////////////////? add lottery ata
-    
-    //////////////////////? add funding token account
-    let funding_token_account_pubeky = Pubkey::new_from_array([2; 32]);
-    let funding_token_account = TokenAccount {
-        state: TokenAccountState::Initialized,
-        mint: mint_account_pubkey,
-        owner: user_account_auth.pubkey(),
-        amount: 100_000000,
-        ..TokenAccount::default()
-    };
-
-    let mut funding_token_account_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        funding_token_account,
-        funding_token_account_data.as_mut_slice()
-    ).unwrap();
+        discriminator: Config::get_discriminator(),
+        canonical_bump: config_account_pda.1,
+        usdc_mint_account: Pubkey::new_from_array([1; 32]),
+        treasury: Pubkey::new_from_array([4; 32]),
+        ..Config::default()
+    };
+    let config_account_data = config_account.try_to_vec().unwrap();
 
-    let funding_token_solana_account = SolanaAccount {
-        owner: spl_token::ID,
-        data: funding_token_account_data.to_vec(),
-        lamports: sol_to_lamports(1.0),
+    let config_solana_account = SolanaAccount {
+        owner: lottery_program_id,
+        data: config_account_data,
+        lamports: sol_to_lamports(0.0009),
         ..SolanaAccount::default()
     };
 
     pt.add_account(
-        funding_token_account_pubeky,
-        funding_token_solana_account
+        config_account_pda.0,
+        config_solana_account
     );
-    //////////////////////? add funding token account
-    
-    //////////////////////? add unknown lottery account
-    let unknown_lottery_account_pda = Pubkey::find_program_address(
+    //////////////////////? add config account
+
+    //////////////////////? add lottery account
+    let lottery_account_auth = Keypair::new();
+    pt.add_account(
+        lottery_account_auth.pubkey(),
+        SolanaAccount {
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+
+    let lottery_account_pda = Pubkey::find_program_address(
         &[
             b"lottery_account",
-            Pubkey::default().to_bytes().as_slice(),
-            get_lottery_literal_seed(&String::from("2")).as_slice()
+            lottery_account_auth.pubkey().to_bytes().as_slice(),
+            get_lottery_literal_seed(&String::from("1")).as_slice()
         ],
         &lottery_program_id
     );
-    
-    let mut unkown_lottery_account = Lottery {
+
+    let mut lottery_account = Lottery {
         discriminator: Lottery::get_discriminator(),
-        canonical_bump: unknown_lottery_account_pda.1,
+        canonical_bump: lottery_account_pda.1,
+        authority: lottery_account_auth.pubkey(),
         starting_time: 100,
         ending_time: 200,
-        lottery_description: String::from("2"),
+        lottery_description: String::from("1"),
         ticket_price: 10_000000,
+        minimum_tickets_amount_required_to_be_sold: 100,
+        maximum_number_of_tickets_per_user: Some(5),
         ..Lottery::default()
     };
-    unkown_lottery_account.initial_bytes = unkown_lottery_account.try_to_vec().unwrap().len() as u64;
-    
+    lottery_account.initial_bytes = lottery_account.try_to_vec().unwrap().len() as u64;
+
     let lottey_solana_account = SolanaAccount {
         owner: lottery_program_id,
         lamports: solana_sdk::native_token::sol_to_lamports(1.0),
-        data: unkown_lottery_account.try_to_vec().unwrap(),
+        data: lottery_account.try_to_vec().unwrap(),
         ..SolanaAccount::default()
     };
-    
+
     pt.add_account(
-        unknown_lottery_account_pda.0,
+        lottery_account_pda.0,
         lottey_solana_account
     );
-    //////////////////////? add unknown lottery account
 
-    //////////////////////? add unkown lottery ata
-    let unkown_lottery_ata_pda = get_associated_token_address(
-        &unknown_lottery_account_pda.0,
-        &mint_account_pubkey
+    let ticket_mint_account_pda = get_ticket_mint_account_pda(
+        &lottery_account_pda.0,
+        &lottery_program_id
+    ).unwrap();
+    //////////////////////? add lottery account
+
+    //////////////////////? add users (buyer & secondary buyer)
+    let buyer = Keypair::new();
+    let secondary_buyer = Keypair::new();
+
+    let mut user_accounts_pda: Vec<(Pubkey, u8)> = Vec::new();
+    for user_account_auth in [&buyer, &secondary_buyer] {
+        pt.add_account(
+            user_account_auth.pubkey(),
+            SolanaAccount {
+                lamports: sol_to_lamports(1.0),
+                ..SolanaAccount::default()
+            }
+        );
+
+        let user_account_pda = Pubkey::find_program_address(
+            &[
+                b"user_account",
+                user_account_auth.pubkey().to_bytes().as_slice(),
+                &lottery_account_pda.0.to_bytes().as_slice()
+            ],
+            &lottery_program_id
+        );
+
+        let user_account = User {
+            discriminator: User::get_discriminator(),
+            canonical_bump: user_account_pda.1,
+            authority: user_account_auth.pubkey(),
+            lottery: lottery_account_pda.0,
+            ..User::default()
+        };
+
+        pt.add_account(
+            user_account_pda.0,
+            SolanaAccount {
+                owner: lottery_program_id,
+                lamports: sol_to_lamports(1.0),
+                data: vec![
+                    user_account.try_to_vec().unwrap(),
+                    vec![0u8; User::LEN - user_account.try_to_vec().unwrap().len()]
+                ].concat(),
+                ..SolanaAccount::default()
+            }
+        );
+
+        user_accounts_pda.push(user_account_pda);
+    };
+    let (buyer_user_account_pda, secondary_buyer_user_account_pda) = (
+        user_accounts_pda[0].0,
+        user_accounts_pda[1].0
     );
-    let unkown_lottery_ata = TokenAccount {
-        state: TokenAccountState::Initialized,
-        mint: mint_account_pubkey,
-        ..TokenAccount::default()
+
+    let buyer_ticket_token_account_pubkey = get_associated_token_address(
+        &buyer.pubkey(),
+        &ticket_mint_account_pda.0
+    );
+    let secondary_buyer_ticket_token_account_pubkey = get_associated_token_address(
+        &secondary_buyer.pubkey(),
+        &ticket_mint_account_pda.0
+    );
+
+    // holds ticket tokens without a user account
+    let token_holder = Keypair::new();
+    let token_holder_ticket_token_account_pubkey = get_associated_token_address(
+        &token_holder.pubkey(),
+        &ticket_mint_account_pda.0
+    );
+    //////////////////////? add users (buyer & secondary buyer)
+
+    //////////////////////? add USDC mint account
+    let usdc_mint_account = MintAccount {
+        supply: 1000_000000,
+        decimals: 6,
+        is_initialized: true,
+        ..MintAccount::default()
     };
 
-    let mut unkown_lottery_ata_data = [0u8; TokenAccount::LEN];
-    TokenAccount::pack(
-        unkown_lottery_ata,
-        unkown_lottery_ata_data.as_mut_slice()
+    let mut usdc_mint_account_data = [0u8; MintAccount::LEN];
+    MintAccount::pack(
+        usdc_mint_account,
+        usdc_mint_account_data.as_mut_slice()
     ).unwrap();
 
-    let unkown_lottery_ata_solana_account = SolanaAccount {
-        owner: spl_token::ID,
-        data: lottery_ata_data.to_vec(),
-        lamports: sol_to_lamports(1.0),
-        ..SolanaAccount::default()
-    };
+    pt.add_account(
+        config_account.usdc_mint_account,
+        SolanaAccount {
+            data: usdc_mint_account_data.to_vec(),
+            owner: TOKEN_STANDARD_PROGRAM_ID,
+            lamports: sol_to_lamports(1.0),
+            ..SolanaAccount::default()
+        }
+    );
+    //////////////////////? add USDC mint account
+
+    //////////////////////? add USDC token accounts (lottery's ata, buyer, token holder, treasury)
+    let lottery_usdc_ata_pubkey = get_associated_token_address(
+        &lottery_account_pda.0,
+        &config_account.usdc_mint_account
+    );
+    let buyer_usdc_token_account_pubkey = Pubkey::new_from_array([2; 32]);
+    let token_holder_usdc_token_account_pubkey = Pubkey::new_from_array([3; 32]);
+
+    for (token_account_pubkey, owner, amount) in [
+        (lottery_usdc_ata_pubkey, lottery_account_pda.0, 0_000000),
+        (buyer_usdc_token_account_pubkey, buyer.pubkey(), 100_000000),
+        (token_holder_usdc_token_account_pubkey, token_holder.pubkey(), 0_000000),
+        (config_account.treasury, Pubkey::new_unique(), 0_000000)
+    ] {
+        let token_account = TokenAccount {
+            amount,
+            owner,
+            state: TokenAccountState::Initialized,
+            mint: config_account.usdc_mint_account,
+            ..TokenAccount::default()
+        };
+
+        let mut token_account_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            token_account,
+            token_account_data.as_mut_slice()
+        ).unwrap();
+
+        pt.add_account(
+            token_account_pubkey,
+            SolanaAccount {
+                data: token_account_data.to_vec(),
+                owner: TOKEN_STANDARD_PROGRAM_ID,
+                lamports: sol_to_lamports(1.0),
+                ..SolanaAccount::default()
+            }
+        );
+    };
+    //////////////////////? add USDC token accounts (lottery's ata, buyer, token holder, treasury)
+
+    let mut ptc = pt.start_with_context().await;
+
+    // success - lottery's authority enables ticket tokens before the lottery starts
+    {
+        change_clock_sysvar(
+            &ptc,
+            50
+        );
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = instruction_enable_ticket_tokens(
+            lottery_account_pda.0,
+            lottery_account_auth.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_account_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(lottery_account.is_tickets_tokenized, true, "invalid lottery account's is_tickets_tokenized.");
+        assert_eq!(lottery_account.ticket_mint_canonical_bump, ticket_mint_account_pda.1, "invalid lottery account's ticket_mint_canonical_bump.");
+
+        let SolanaAccount { data: ticket_mint_account_data, owner, .. } = ptc
+            .banks_client
+            .get_account(ticket_mint_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let ticket_mint_account = MintAccount::unpack(
+            &ticket_mint_account_data
+        ).unwrap();
+
+        assert_eq!(owner, TOKEN_STANDARD_PROGRAM_ID, "invalid ticket mint account's owner.");
+        assert_eq!(ticket_mint_account.decimals, 0, "invalid ticket mint account's decimals.");
+        assert_eq!(
+            ticket_mint_account.mint_authority,
+            COption::Some(ticket_mint_account_pda.0),
+            "invalid ticket mint account's mint_authority."
+        );
+    }
+    // success - lottery's authority enables ticket tokens before the lottery starts
+
+    // failure - ticket tokens are already enabled
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = instruction_enable_ticket_tokens(
+            lottery_account_pda.0,
+            lottery_account_auth.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            SYSTEM_PROGRAM_ID,
+            config_account_pda.0
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_account_auth
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::TicketTokensAlreadyEnabled as u32
+                )
+            )
+        );
+    }
+    // failure - ticket tokens are already enabled
+
+    // failure - missing ticket token accounts
+    {
+        change_clock_sysvar(
+            &ptc,
+            150
+        );
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = instruction_buy_ticket(
+            config_account_pda.0,
+            buyer_user_account_pda,
+            buyer.pubkey(),
+            buyer.pubkey(),
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            buyer_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_STANDARD_PROGRAM_ID,
+            4,
+            10_000000,
+            false
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &buyer
+            ],
+            ptc.last_blockhash
+        );
+
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
 
-    pt.add_account(
-        unkown_lottery_ata_pda,
-        unkown_lottery_ata_solana_account
-    );
-    //////////////////////? add unkown lottery ata
-    
-    let mut ptc = pt.start_with_context().await;
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidAmountOfTicketTokenAccounts as u32
+                )
+            )
+        );
+    }
+    // failure - missing ticket token accounts
 
-    // failure - invalid user's tickets amount
+    // success - ticket tokens are minted to the buyer instead of registering the tickets
     {
-        change_clock_sysvar(
-            &ptc, 
-            150
-        );
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
 
-        let instruction_1 = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            lottery_account_pda.0, 
-            lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            105,
-            10_000000 // 10 USDC
+        let instruction = instruction_buy_ticket(
+            config_account_pda.0,
+            buyer_user_account_pda,
+            buyer.pubkey(),
+            buyer.pubkey(),
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            buyer_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_STANDARD_PROGRAM_ID,
+            4,
+            10_000000,
+            true
         );
 
-        let instruction_2 = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            lottery_account_pda.0, 
-            lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            5,
-            10_000000
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &buyer
+            ],
+            ptc.last_blockhash
         );
 
-        let instruction_3 = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            lottery_account_pda.0, 
-            lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            98,
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: buyer_ticket_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(buyer_ticket_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &buyer_ticket_token_account_data
+        ).unwrap();
+
+        assert_eq!(amount, 4, "invalid buyer's ticket token account balance.");
+
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(lottery_account.tickets_total_amount, 0, "invalid lottery account's tickets_total_amount.");
+        assert_eq!(lottery_account.ticket_tokens_outstanding, 4, "invalid lottery account's ticket_tokens_outstanding.");
+        assert_eq!(lottery_account.ticket_tokens_value, 40_000000, "invalid lottery account's ticket_tokens_value.");
+
+        let SolanaAccount { data: user_account_data, .. } = ptc
+            .banks_client
+            .get_account(buyer_user_account_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let user_account = User::deserialize(
+            &mut &user_account_data[..]
+        ).unwrap();
+
+        assert_eq!(user_account.total_tickets_acquired, 0, "invalid user account's total_tickets_acquired.");
+        assert_eq!(user_account.total_tickets_value, 0, "invalid user account's total_tickets_value.");
+    }
+    // success - ticket tokens are minted to the buyer instead of registering the tickets
+
+    // failure - tokenized lottery, ticket tokens are only minted by "BuyTicket"
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = instruction_buy_ticket_for(
+            config_account_pda.0,
+            secondary_buyer_user_account_pda,
+            secondary_buyer.pubkey(),
+            buyer.pubkey(),
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            buyer_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_STANDARD_PROGRAM_ID,
+            1,
             10_000000
         );
 
-        let tx_1 = Transaction::new_signed_with_payer(
-            &[
-                instruction_1
-            ], 
-            Some(&ptc.payer.pubkey()), 
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-               &user_account_auth
-            ], 
+                &buyer
+            ],
             ptc.last_blockhash
         );
 
-        let tx_2 = Transaction::new_signed_with_payer(
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
+
+        assert_eq!(
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::InvalidLotteryType as u32
+                )
+            )
+        );
+    }
+    // failure - tokenized lottery, ticket tokens are only minted by "BuyTicket"
+
+    // success - ticket tokens are traded & redeemed by the secondary buyer
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction_1 = create_associated_token_account(
+            &secondary_buyer.pubkey(),
+            &secondary_buyer.pubkey(),
+            &ticket_mint_account_pda.0,
+            &TOKEN_STANDARD_PROGRAM_ID
+        );
+
+        let instruction_2 = transfer_spl_checked(
+            &TOKEN_STANDARD_PROGRAM_ID,
+            &buyer_ticket_token_account_pubkey,
+            &ticket_mint_account_pda.0,
+            &secondary_buyer_ticket_token_account_pubkey,
+            &buyer.pubkey(),
+            &[],
+            1,
+            0
+        ).unwrap();
+
+        let instruction_3 = instruction_redeem_ticket_tokens(
+            config_account_pda.0,
+            secondary_buyer_user_account_pda,
+            secondary_buyer.pubkey(),
+            lottery_account_pda.0,
+            secondary_buyer_ticket_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            SYSTEM_PROGRAM_ID,
+            1
+        );
+
+        let tx = Transaction::new_signed_with_payer(
             &[
+                instruction_1,
                 instruction_2,
                 instruction_3
-            ], 
-            Some(&ptc.payer.pubkey()), 
+            ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-               &user_account_auth
-            ], 
+                &buyer,
+                &secondary_buyer
+            ],
             ptc.last_blockhash
         );
 
-        let error_tx1 = ptc
+        ptc
             .banks_client
-            .process_transaction(tx_1)
+            .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
-        let error_tx2 = ptc
+        let SolanaAccount { data: user_account_data, .. } = ptc
+            .banks_client
+            .get_account(secondary_buyer_user_account_pda)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let user_account = User::deserialize(
+            &mut &user_account_data[..]
+        ).unwrap();
+
+        assert_eq!(user_account.total_tickets_acquired, 1, "invalid user account's total_tickets_acquired.");
+        assert_eq!(user_account.total_ticket_ranges_acquired, 1, "invalid user account's total_ticket_ranges_acquired.");
+        assert_eq!(user_account.total_tickets_value, 10_000000, "invalid user account's total_tickets_value.");
+
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
+
+        assert_eq!(lottery_account.tickets_total_amount, 1, "invalid lottery account's tickets_total_amount.");
+        assert_eq!(lottery_account.ticket_tokens_outstanding, 3, "invalid lottery account's ticket_tokens_outstanding.");
+        assert_eq!(lottery_account.ticket_tokens_value, 30_000000, "invalid lottery account's ticket_tokens_value.");
+        assert_eq!(
+            lottery_account_data.len(),
+            lottery_account.initial_bytes as usize + 36,
+            "invalid lottery account's data length."
+        );
+    }
+    // success - ticket tokens are traded & redeemed by the secondary buyer
+
+    // success - buyer redeems some of its ticket tokens
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
+
+        let instruction = instruction_redeem_ticket_tokens(
+            config_account_pda.0,
+            buyer_user_account_pda,
+            buyer.pubkey(),
+            lottery_account_pda.0,
+            buyer_ticket_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            SYSTEM_PROGRAM_ID,
+            2
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &buyer
+            ],
+            ptc.last_blockhash
+        );
+
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
+
+        let SolanaAccount { data: buyer_ticket_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(buyer_ticket_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &buyer_ticket_token_account_data
+        ).unwrap();
+
+        assert_eq!(amount, 1, "invalid buyer's ticket token account balance.");
+
+        let SolanaAccount { data: user_account_data, .. } = ptc
             .banks_client
-            .process_transaction(tx_2)
+            .get_account(buyer_user_account_pda)
             .await
-            .unwrap_err()
+            .unwrap()
             .unwrap();
 
-        assert_eq!(
-            error_tx1,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::MaxTicketsAmountViolated as u32
-                )
-            )
-        );
+        let user_account = User::deserialize(
+            &mut &user_account_data[..]
+        ).unwrap();
 
-        assert_eq!(
-            error_tx2,
-            TransactionError::InstructionError(
-                1,
-                InstructionError::Custom(
-                    LotteryError::MaxTicketsAmountViolated as u32
-                )
-            )
-        );
+        assert_eq!(user_account.total_tickets_acquired, 2, "invalid user account's total_tickets_acquired.");
+        assert_eq!(user_account.total_tickets_value, 20_000000, "invalid user account's total_tickets_value.");
     }
-    // failure - invalid user's tickets amount
+    // success - buyer redeems some of its ticket tokens
 
-    // failure - invalid tickets amount
+    // success - buyer buys a ticket token & sends it to a holder without user account
     {
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(
-            &ptc, 
-            150
+
+        let instruction_1 = instruction_buy_ticket(
+            config_account_pda.0,
+            buyer_user_account_pda,
+            buyer.pubkey(),
+            buyer.pubkey(),
+            lottery_account_pda.0,
+            lottery_usdc_ata_pubkey,
+            buyer_usdc_token_account_pubkey,
+            config_account.usdc_mint_account,
+            SYSTEM_PROGRAM_ID,
+            TOKEN_STANDARD_PROGRAM_ID,
+            1,
+            10_000000,
+            true
         );
 
-        let instruction = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            lottery_account_pda.0, 
-            lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            0,
-            10_000000
+        let instruction_2 = create_associated_token_account(
+            &buyer.pubkey(),
+            &token_holder.pubkey(),
+            &ticket_mint_account_pda.0,
+            &TOKEN_STANDARD_PROGRAM_ID
         );
 
+        let instruction_3 = transfer_spl_checked(
+            &TOKEN_STANDARD_PROGRAM_ID,
+            &buyer_ticket_token_account_pubkey,
+            &ticket_mint_account_pda.0,
+            &token_holder_ticket_token_account_pubkey,
+            &buyer.pubkey(),
+            &[],
+            1,
+            0
+        ).unwrap();
+
         let tx = Transaction::new_signed_with_payer(
             &[
-                instruction.clone(),
-                instruction
-            ], 
-            Some(&ptc.payer.pubkey()), 
+                instruction_1,
+                instruction_2,
+                instruction_3
+            ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &user_account_auth
-            ], 
+                &buyer
+            ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
-        assert_eq!(
-            error,
-            TransactionError::InstructionError(
-                0,
-                InstructionError::Custom(
-                    LotteryError::InvalidTicketAmount as u32
-                )
-            )
-        );
+        let SolanaAccount { data: token_holder_ticket_token_account_data, .. } = ptc
+            .banks_client
+            .get_account(token_holder_ticket_token_account_pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &token_holder_ticket_token_account_data
+        ).unwrap();
+
+        assert_eq!(amount, 1, "invalid token holder's ticket token account balance.");
     }
-    // failure - invalid tickets amount
+    // success - buyer buys a ticket token & sends it to a holder without user account
 
-    // failure - lottery is in invalid state
+    // failure - ticket tokens are refunded only when the lottery fails
     {
-        // 1. lottery is not started yet
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(
-            &ptc, 
-            50
-        );
 
-        let instruction = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            lottery_account_pda.0, 
-            lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            5,
-            10_000000
+        let instruction = instruction_refund_ticket_tokens(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            token_holder.pubkey(),
+            token_holder_ticket_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account.usdc_mint_account,
+            lottery_usdc_ata_pubkey,
+            token_holder_usdc_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[
-                instruction.clone(),
-                instruction
-            ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &user_account_auth
-            ], 
+                &token_holder
+            ],
             ptc.last_blockhash
         );
 
@@ -9175,42 +23193,38 @@ async fn test_buy_tickets() {
                 )
             )
         );
+    }
+    // failure - ticket tokens are refunded only when the lottery fails
 
-        // 2. lottery is ended
+    // failure - ticket tokens can't be redeemed after the lottery ends
+    {
+        change_clock_sysvar(
+            &ptc,
+            250
+        );
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(
-            &ptc, 
-            350
-        );
 
-        let instruction = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            lottery_account_pda.0, 
-            lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            5,
-            10_000000
+        let instruction = instruction_redeem_ticket_tokens(
+            config_account_pda.0,
+            buyer_user_account_pda,
+            buyer.pubkey(),
+            lottery_account_pda.0,
+            buyer_ticket_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            SYSTEM_PROGRAM_ID,
+            1
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[
-                instruction.clone(),
-                instruction
-            ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &user_account_auth
-            ], 
+                &buyer
+            ],
             ptc.last_blockhash
         );
 
@@ -9231,150 +23245,117 @@ async fn test_buy_tickets() {
             )
         );
     }
-    // failure - lottery is in invalid state
+    // failure - ticket tokens can't be redeemed after the lottery ends
 
-    // failure - invalid user account
+    // success - failed lottery burns & refunds the buyer's remaining ticket tokens
     {
-        // 1. invalid user_account's authority
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
-        change_clock_sysvar(
-            &ptc, 
-            150
-        );
 
-        let unknown_account = Keypair::new();
-        let instruction = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            unknown_account.pubkey(), 
-            unknown_account.pubkey(), 
-            lottery_account_pda.0, 
-            lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            5,
-            10_000000
+        let instruction = instruction_withdraw_and_close_failed_user(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            buyer_user_account_pda,
+            buyer.pubkey(),
+            config_account.usdc_mint_account,
+            lottery_usdc_ata_pubkey,
+            buyer_usdc_token_account_pubkey,
+            buyer.pubkey(),
+            buyer.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            None,
+            None,
+            Some(buyer_ticket_token_account_pubkey)
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[
-                instruction.clone(),
-                instruction
-            ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-                &unknown_account
-            ], 
+                &buyer
+            ],
             ptc.last_blockhash
         );
 
-        let error = ptc
+        ptc
             .banks_client
             .process_transaction(tx)
             .await
-            .unwrap_err()
             .unwrap();
 
-        if !(
-            error == TransactionError::InstructionError(0, InstructionError::InvalidSeeds) ||
-            error == TransactionError::InstructionError(0, InstructionError::Custom(LotteryError::FailedToFindProgramAddress as u32))
-        ) {
-            panic!("1. invalidSeeds Or failedToFindProgramAddress");
+        for (token_account_pubkey, expected_balance, message) in [
+            (buyer_ticket_token_account_pubkey, 0, "invalid buyer's ticket token account balance."),
+            (buyer_usdc_token_account_pubkey, 100_000000 - 20_000000, "invalid buyer usdc token account balance."),
+            (lottery_usdc_ata_pubkey, 20_000000, "invalid lottery USDC ata token balance.")
+        ] {
+            let SolanaAccount { data: token_account_data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount, .. } = TokenAccount::unpack(
+                &token_account_data
+            ).unwrap();
+
+            assert_eq!(amount, expected_balance, "{}", message);
         };
 
-        // 2. invalid lottery_account
-        ptc
-            .get_new_latest_blockhash()
+        let SolanaAccount { data: lottery_account_data, .. } = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
             .await
+            .unwrap()
             .unwrap();
-        change_clock_sysvar(
-            &ptc, 
-            150
-        );
 
-        let instruction = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            unknown_lottery_account_pda.0, 
-            unkown_lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            5,
-            10_000000
-        );
+        let lottery_account = Lottery::deserialize(
+            &mut &lottery_account_data[..]
+        ).unwrap();
 
-        let tx = Transaction::new_signed_with_payer(
-            &[
-                instruction.clone(),
-                instruction
-            ], 
-            Some(&ptc.payer.pubkey()), 
-            &[
-                &ptc.payer,
-                &user_account_auth
-            ], 
-            ptc.last_blockhash
-        );
+        assert_eq!(lottery_account.ticket_tokens_outstanding, 1, "invalid lottery account's ticket_tokens_outstanding.");
+        assert_eq!(lottery_account.ticket_tokens_value, 10_000000, "invalid lottery account's ticket_tokens_value.");
 
-        let error = ptc
+        let user_account = ptc
             .banks_client
-            .process_transaction(tx)
+            .get_account(buyer_user_account_pda)
             .await
-            .unwrap_err()
             .unwrap();
-
-        if !(
-            error == TransactionError::InstructionError(0, InstructionError::InvalidSeeds) ||
-            error == TransactionError::InstructionError(0, InstructionError::Custom(LotteryError::FailedToFindProgramAddress as u32))
-        ) {
-            panic!("2. invalidSeeds Or failedToFindProgramAddress");
+        if user_account.is_some() {
+            panic!("Account must be closed.");
         };
     }
-    // failure - invalid user account
+    // success - failed lottery burns & refunds the buyer's remaining ticket tokens
 
-    // failure - expected ticket price is violated
-    {
-        change_clock_sysvar(
-            &ptc, 
-            150
-        );
+    // failure - ticket token account of another holder
+    {
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
 
-        let instruction = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            lottery_account_pda.0, 
-            lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            5,
-            5_000000
+        let instruction = instruction_refund_ticket_tokens(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            secondary_buyer.pubkey(),
+            token_holder_ticket_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account.usdc_mint_account,
+            lottery_usdc_ata_pubkey,
+            buyer_usdc_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[ instruction ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-               &user_account_auth
-            ], 
+                &secondary_buyer
+            ],
             ptc.last_blockhash
         );
 
@@ -9390,49 +23371,39 @@ async fn test_buy_tickets() {
             TransactionError::InstructionError(
                 0,
                 InstructionError::Custom(
-                    LotteryError::ExpectedTicketPriceViolated as u32
+                    LotteryError::InvalidTicketTokenAccount as u32
                 )
             )
         );
     }
-    // failure - expected ticket price is violated
-    
-    // success
+    // failure - ticket token account of another holder
+
+    // success - failed lottery burns & refunds the ticket tokens of a holder without user account
     {
-        change_clock_sysvar(
-            &ptc, 
-            150
-        );
         ptc
             .get_new_latest_blockhash()
             .await
             .unwrap();
 
-        let instruction = instruction_buy_ticket(
-            config_account_pda.0, 
-            user_account_pda.0, 
-            user_account_auth.pubkey(), 
-            user_account_auth.pubkey(), 
-            lottery_account_pda.0, 
-            lottery_ata_pda, 
-            funding_token_account_pubeky, 
-            mint_account_pubkey, 
-            SYSTEM_PROGRAM_ID, 
-            spl_token::ID, 
-            5,
-            10_000000
+        let instruction = instruction_refund_ticket_tokens(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            token_holder.pubkey(),
+            token_holder_ticket_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID,
+            config_account.usdc_mint_account,
+            lottery_usdc_ata_pubkey,
+            token_holder_usdc_token_account_pubkey,
+            TOKEN_STANDARD_PROGRAM_ID
         );
 
         let tx = Transaction::new_signed_with_payer(
-            &[
-                instruction.clone(),
-                instruction
-            ], 
-            Some(&ptc.payer.pubkey()), 
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
             &[
                 &ptc.payer,
-               &user_account_auth
-            ], 
+                &token_holder
+            ],
             ptc.last_blockhash
         );
 
@@ -9442,6 +23413,25 @@ async fn test_buy_tickets() {
             .await
             .unwrap();
 
+        for (token_account_pubkey, expected_balance, message) in [
+            (token_holder_ticket_token_account_pubkey, 0, "invalid token holder's ticket token account balance."),
+            (token_holder_usdc_token_account_pubkey, 10_000000, "invalid token holder usdc token account balance."),
+            (lottery_usdc_ata_pubkey, 10_000000, "invalid lottery USDC ata token balance.")
+        ] {
+            let SolanaAccount { data: token_account_data, .. } = ptc
+                .banks_client
+                .get_account(token_account_pubkey)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let TokenAccount { amount, .. } = TokenAccount::unpack(
+                &token_account_data
+            ).unwrap();
+
+            assert_eq!(amount, expected_balance, "{}", message);
+        };
+
         let SolanaAccount { data: lottery_account_data, .. } = ptc
             .banks_client
             .get_account(lottery_account_pda.0)
@@ -9449,133 +23439,172 @@ async fn test_buy_tickets() {
             .unwrap()
             .unwrap();
 
-        let SolanaAccount { data: user_account_data, .. } = ptc
-            .banks_client
-            .get_account(user_account_pda.0)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let SolanaAccount { data: funding_token_account_data, .. } = ptc
-            .banks_client
-            .get_account(funding_token_account_pubeky)
-            .await
-            .unwrap()
-            .unwrap();
-
-        let SolanaAccount { data: lottery_ata_data, .. } = ptc
-            .banks_client
-            .get_account(lottery_ata_pda)
-            .await
-            .unwrap()
-            .unwrap();
-
         let lottery_account = Lottery::deserialize(
             &mut &lottery_account_data[..]
         ).unwrap();
 
-        let user_account = User::deserialize(
-            &mut &user_account_data[..]
-        ).unwrap();
+        assert_eq!(lottery_account.ticket_tokens_outstanding, 0, "invalid lottery account's ticket_tokens_outstanding.");
+        assert_eq!(lottery_account.ticket_tokens_value, 0, "invalid lottery account's ticket_tokens_value.");
+    }
+    // success - failed lottery burns & refunds the ticket tokens of a holder without user account
 
-        let funding_token_account = TokenAccount::unpack(
-            &funding_token_account_data
-        ).unwrap();
+    // an abandoned holder never refunds its ticket token
+    {
+        let lottery_account = Lottery {
+            is_tickets_tokenized: true,
+            ticket_tokens_outstanding: 1,
+            ticket_tokens_value: 10_000000,
+            is_creator_withdrawed_when_lottery_was_failed: true,
+            ..lottery_account.clone()
+        };
 
-        let lottery_ata = TokenAccount::unpack(
-            &lottery_ata_data
+        ptc.set_account(
+            &lottery_account_pda.0,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    owner: lottery_program_id,
+                    lamports: solana_sdk::native_token::sol_to_lamports(1.0),
+                    data: lottery_account.try_to_vec().unwrap(),
+                    ..SolanaAccount::default()
+                }
+            )
+        );
+
+        let mut lottery_usdc_ata_data = [0u8; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                amount: 10_000000,
+                owner: lottery_account_pda.0,
+                state: TokenAccountState::Initialized,
+                mint: config_account.usdc_mint_account,
+                ..TokenAccount::default()
+            },
+            lottery_usdc_ata_data.as_mut_slice()
         ).unwrap();
 
-        // check lottery account
-        assert_eq!(
-            lottery_account.tickets_total_amount,
-            10,
-            "invalid tickets total amounts."
+        ptc.set_account(
+            &lottery_usdc_ata_pubkey,
+            &SolanaSharedDataAccount::from(
+                SolanaAccount {
+                    data: lottery_usdc_ata_data.to_vec(),
+                    owner: TOKEN_STANDARD_PROGRAM_ID,
+                    lamports: sol_to_lamports(1.0),
+                    ..SolanaAccount::default()
+                }
+            )
         );
+    }
 
-        assert_eq!(
-            lottery_account.protocol_fee,
-            2_500000,
-            "invalid protocol fee amounts."
-        );
+    // failure - ticket tokens of the failed lottery are not refunded yet
+    {
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-        assert_eq!(
-            lottery_account_data.len(),
-            lottery_account.initial_bytes as usize + (10 * size_of::<Pubkey>()),
-            "invalid lottery_account's data length."
+        let instruction = instruction_close_lottery_account_and_usdc_token_account(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            lottery_account_auth.pubkey(),
+            config_account.usdc_mint_account,
+            lottery_usdc_ata_pubkey,
+            buyer_usdc_token_account_pubkey,
+            lottery_account_auth.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            false,
+            None
         );
 
-        let mut lottery_account_d = lottery_account_data.clone();
-        let mut lottery_account_balance = 1_000000000;
-        let lottery_account_info = &solana_sdk::account_info::AccountInfo {
-            lamports: Rc::new(RefCell::new(&mut lottery_account_balance)),
-            owner: &lottery_program_id,
-            key: &Pubkey::new_unique(),
-            data: Rc::new(RefCell::new(&mut lottery_account_d)),
-            rent_epoch: solana_sdk::clock::Epoch::default(),
-            is_signer: false,
-            is_writable: false,
-            executable: false
-        };
-        for index in 0..10 {
-            assert_eq!(
-                Lottery::get_ticket(
-                    lottery_account_info,
-                    index
-                ).unwrap(),
-                user_account_pda.0,
-                "invalid user_account's ticket."
-            );
-        };
-
-        let total_tickets_rent_exempt = solana_sdk::rent::DEFAULT_EXEMPTION_THRESHOLD as u64 * 
-            ( solana_sdk::rent::DEFAULT_LAMPORTS_PER_BYTE_YEAR * ( 10 * size_of::<Pubkey>() as u64 ) );
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_account_auth
+            ],
+            ptc.last_blockhash
+        );
 
-        let lottery_account_balance = sol_to_lamports(1.0) + total_tickets_rent_exempt;
+        let error = ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err()
+            .unwrap();
 
         assert_eq!(
-            lottery_account_balance,
-            ptc.banks_client.get_balance(lottery_account_pda.0).await.unwrap(),
-            "invalid lottery_account's lamport balance."
+            error,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(
+                    LotteryError::TicketTokensOutstanding as u32
+                )
+            )
         );
-        // check lottery account
+    }
+    // failure - ticket tokens of the failed lottery are not refunded yet
 
-        // check user account
-        assert_eq!(
-            user_account.total_rent_exempt_paied,
-            total_tickets_rent_exempt,
-            "invalid user_account's rent_exempt paied."
+    // success - after the refund window, closing the lottery sweeps the usdc of the abandoned ticket tokens to the treasury
+    {
+        // ending_time + 30 days
+        change_clock_sysvar(
+            &ptc,
+            200 + 2_592_000
         );
+        ptc
+            .get_new_latest_blockhash()
+            .await
+            .unwrap();
 
-        assert_eq!(
-            user_account.total_tickets_value,
-            10 * 10_000000,
-            "invalid user_account's total_tickets_value."
+        let instruction = instruction_close_lottery_account_and_usdc_token_account(
+            config_account_pda.0,
+            lottery_account_pda.0,
+            lottery_account_auth.pubkey(),
+            config_account.usdc_mint_account,
+            lottery_usdc_ata_pubkey,
+            buyer_usdc_token_account_pubkey,
+            lottery_account_auth.pubkey(),
+            TOKEN_STANDARD_PROGRAM_ID,
+            false,
+            Some(config_account.treasury)
         );
 
-        assert_eq!(
-            user_account.total_tickets_acquired,
-            10,
-            "invalid user_account's total_tickets_acquired."
+        let tx = Transaction::new_signed_with_payer(
+            &[ instruction ],
+            Some(&ptc.payer.pubkey()),
+            &[
+                &ptc.payer,
+                &lottery_account_auth
+            ],
+            ptc.last_blockhash
         );
-        // check user account
 
-        // check funding token account balance
-        assert_eq!(
-            funding_token_account.amount,
-            0_000000,
-            "invalid funding token account balance."
-        );
-        // check funding token account balance
+        ptc
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap();
 
-        // check lottery's ata balance
-        assert_eq!(
-            lottery_ata.amount,
-            100_000000,
-            "invalid lottery's ata token balance."
-        );
-        // check lottery's ata balance
+        let SolanaAccount { data: treasury_data, .. } = ptc
+            .banks_client
+            .get_account(config_account.treasury)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let TokenAccount { amount, .. } = TokenAccount::unpack(
+            &treasury_data
+        ).unwrap();
+
+        assert_eq!(amount, 10_000000, "invalid treasury usdc token account balance.");
+
+        let lottery_solana_account = ptc
+            .banks_client
+            .get_account(lottery_account_pda.0)
+            .await
+            .unwrap();
+
+        assert_eq!(lottery_solana_account, None, "lottery account is not closed.");
     }
-    // success
+    // success - after the refund window, closing the lottery sweeps the usdc of the abandoned ticket tokens to the treasury
 }
-////////////////////////////////////// User Instructions
\ No newline at end of file